@@ -125,13 +125,18 @@ impl AnimatedStyleAttribute
         attr.initialize_enter(entity, world);
     }
 
-    pub fn apply(&self, current_state: &AnimationState, ui_style: &mut UiStyle)
+    /// Applies the attribute.
+    ///
+    /// If `completed` is true, then [`AnimatedAttributeObject::on_complete`] will be called after the attribute
+    /// is applied.
+    pub fn apply(&self, current_state: &AnimationState, completed: bool, ui_style: &mut UiStyle)
     {
         ui_style
             .entity_commands()
             .queue(ApplyAnimatedStyleAttribute {
                 attr: self.attr.clone(),
                 current_state: current_state.clone(),
+                completed,
             });
     }
 }
@@ -197,13 +202,21 @@ pub struct ApplyAnimatedStyleAttribute
 {
     pub attr: Arc<dyn AnimatedAttributeObject>,
     pub current_state: AnimationState,
+    pub completed: bool,
 }
 
 impl EntityCommand for ApplyAnimatedStyleAttribute
 {
     fn apply(self, id: Entity, world: &mut World)
     {
+        let completed_style = match (self.completed, self.current_state.result()) {
+            (true, AnimationResult::Hold(style)) => Some(*style),
+            _ => None,
+        };
         self.attr.apply(id, world, self.current_state);
+        if let Some(style) = completed_style {
+            self.attr.on_complete(id, world, style);
+        }
     }
 }
 
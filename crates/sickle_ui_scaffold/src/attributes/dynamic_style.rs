@@ -10,7 +10,8 @@ impl Plugin for DynamicStylePlugin
 {
     fn build(&self, app: &mut App)
     {
-        app.configure_sets(PostUpdate, DynamicStylePostUpdate.before(UiSystem::Prepare))
+        app.init_resource::<ReducedMotion>()
+            .configure_sets(PostUpdate, DynamicStylePostUpdate.before(UiSystem::Prepare))
             .add_systems(
                 PostUpdate,
                 (
@@ -30,6 +31,12 @@ impl Plugin for DynamicStylePlugin
 #[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
 pub struct DynamicStylePostUpdate;
 
+/// Resource that controls whether animated attributes are tweened.
+///
+/// When enabled, animated attributes snap to the end of their transitions and state loops don't play.
+#[derive(Resource, Default, Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ReducedMotion(pub bool);
+
 fn update_dynamic_style_static_attributes(
     mut q_styles: Query<(Entity, &mut DynamicStyle), Changed<DynamicStyle>>,
     mut commands: Commands,
@@ -148,6 +155,7 @@ fn update_dynamic_style_on_stopwatch_change(
 )
 {
     let world_ptr: *const World = std::ptr::from_ref(p.p0());
+    let reduced_motion = p.p0().resource::<ReducedMotion>().0;
 
     for (entity, mut style, interaction, stopwatch) in p.p1().iter_mut() {
         let style_changed = style.is_changed();
@@ -161,8 +169,15 @@ fn update_dynamic_style_on_stopwatch_change(
                 continue;
             };
 
+            // The animation completed if it transitioned to a held state this tick.
+            let mut completed = false;
             if let Some(stopwatch) = stopwatch {
-                controller.update(interaction, stopwatch.0.elapsed_secs());
+                match reduced_motion {
+                    true => controller.snap(interaction),
+                    false => controller.update(interaction, stopwatch.0.elapsed_secs()),
+                }
+                completed =
+                    controller.dirty() && matches!(controller.current_state().result(), AnimationResult::Hold(_));
             }
 
             if style_changed || controller.dirty() {
@@ -189,7 +204,7 @@ fn update_dynamic_style_on_stopwatch_change(
                     *attribute = init_attribute;
                 }
 
-                attribute.apply(controller.current_state(), &mut commands.style(target));
+                attribute.apply(controller.current_state(), completed, &mut commands.style(target));
             }
 
             if !controller.is_entered() {
@@ -83,6 +83,9 @@ impl DynamicStyleAttribute
     }
 }
 
+/// Elapsed time used to snap animations to the end of their transitions.
+const SNAP_ELAPSED_SECS: f32 = 1_000_000.;
+
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum EnterState
 {
@@ -123,7 +126,20 @@ impl DynamicStyleController
         Self { animation, current_state: starting_state, ..default() }
     }
 
-    pub fn update(&mut self, flux_interaction: &FluxInteraction, mut elapsed: f32)
+    pub fn update(&mut self, flux_interaction: &FluxInteraction, elapsed: f32)
+    {
+        self.update_inner(flux_interaction, elapsed, true);
+    }
+
+    /// Updates the controller to the end of its current transition, ignoring state loops.
+    ///
+    /// Used when [`ReducedMotion`] is enabled.
+    pub fn snap(&mut self, flux_interaction: &FluxInteraction)
+    {
+        self.update_inner(flux_interaction, SNAP_ELAPSED_SECS, false);
+    }
+
+    fn update_inner(&mut self, flux_interaction: &FluxInteraction, mut elapsed: f32, looping: bool)
     {
         // TODO: `enter` animation is currently played when a style animation different from
         // the previous one is requested. This means that playing the enter animation is *contextual*
@@ -150,6 +166,7 @@ impl DynamicStyleController
         };
         let loop_tween = match entering {
             EnterState::Unspecified | EnterState::Entering => None,
+            EnterState::Entered if !looping => None,
             EnterState::Entered => self.animation.to_loop_tween(&flux_interaction),
         };
 
@@ -49,6 +49,9 @@ pub trait AnimatedAttributeObject: Any + DynClone + Debug + Send + Sync + 'stati
 
     /// Applies the attribute to the target entity.
     fn apply(&self, entity: Entity, world: &mut World, state: AnimationState);
+
+    /// Called after [`Self::apply`] when the attribute's animation has settled on `style`.
+    fn on_complete(&self, _entity: Entity, _world: &mut World, _style: InteractionStyle) {}
 }
 
 //-------------------------------------------------------------------------------------------------------------------
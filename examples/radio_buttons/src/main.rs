@@ -5,6 +5,8 @@
 
 use bevy::prelude::*;
 use bevy::window::WindowTheme;
+use bevy_cobweb::prelude::*;
+use bevy_cobweb_ui::builtin::widgets::radio_button::*;
 use bevy_cobweb_ui::prelude::*;
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -20,19 +22,19 @@ fn build_ui(mut c: Commands, mut s: SceneBuilder)
 
         // Insert radio buttons.
         h.edit("radio_frame", |h| {
-            for (i, option) in OPTIONS.iter().enumerate() {
-                h.spawn_scene_and_edit(("main.cob", "button"), |h| {
-                    // Set display text when a button is selected.
-                    h.on_select(move |mut e: TextEditor| {
-                        write_text!(e, display_text, "Selected: {}", option);
-                    });
+            // Track the selected option, starting with the first option.
+            h.insert_radio_group(Some(OPTIONS[0])).on_radio_selection::<&'static str, _, _, _>(
+                move |id: TargetId, mut e: TextEditor, selection: Reactive<RadioGroupSelection<&'static str>>| {
+                    let option = selection.get(*id)?.value().result()?;
+                    write_text!(e, display_text, "Selected: {}", option);
+                    OK
+                },
+            );
 
-                    h.get("text").update_text(format!("{}", option));
-                    // Select the first option.
-                    if i == 0 {
-                        let entity = h.id();
-                        h.react().entity_event(entity, Select);
-                    }
+            for option in OPTIONS.iter() {
+                h.spawn_scene_and_edit(("main.cob", "button"), |h| {
+                    h.radio_value(*option);
+                    h.get("text").update_text(*option);
                 });
             }
         });
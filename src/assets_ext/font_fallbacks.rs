@@ -0,0 +1,351 @@
+use std::collections::HashSet;
+
+use bevy::ecs::world::Command;
+use bevy::prelude::*;
+use bevy::text::CosmicFontSystem;
+use bevy::ui::UiSystem;
+use bevy_cobweb::prelude::*;
+
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn register_font_fallbacks(In(chains): In<Vec<FontFallbackChain>>, mut fallbacks: ResMut<FontFallbacks>)
+{
+    for chain in chains {
+        // Re-activate the script in case the chain changed.
+        fallbacks.activated.remove(&chain.script);
+        match fallbacks.chains.iter_mut().find(|(s, _)| *s == chain.script) {
+            Some((_, families)) => *families = chain.families,
+            None => fallbacks.chains.push((chain.script, chain.families)),
+        }
+    }
+
+    // Existing text may contain the scripts.
+    fallbacks.rescan = true;
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn register_emoji_fonts(
+    In(paths): In<Vec<String>>,
+    asset_server: Res<AssetServer>,
+    mut fallbacks: ResMut<FontFallbacks>,
+)
+{
+    for path in paths {
+        fallbacks.add_fallback_font(asset_server.load(path));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Loads fallback chains for scripts that appear in text.
+fn activate_font_fallbacks(
+    mut c: Commands,
+    asset_server: Res<AssetServer>,
+    mut fallbacks: ResMut<FontFallbacks>,
+    mut font_map: ResMut<FontMap>,
+    all_texts: Query<Ref<Text>>,
+    all_spans: Query<Ref<TextSpan>>,
+)
+{
+    if fallbacks.chains.len() == fallbacks.activated.len() {
+        fallbacks.rescan = false;
+        return;
+    }
+
+    let rescan = fallbacks.rescan;
+    fallbacks.rescan = false;
+    let mut scripts = HashSet::new();
+    let texts = all_texts
+        .iter()
+        .filter(|t| rescan || t.is_changed())
+        .map(|t| t.into_inner().as_str());
+    let spans = all_spans
+        .iter()
+        .filter(|t| rescan || t.is_changed())
+        .map(|t| t.into_inner().as_str());
+    for text in texts.chain(spans) {
+        scripts.extend(text.chars().filter_map(FontScript::of));
+    }
+
+    let fallbacks = fallbacks.as_mut();
+    for (script, families) in fallbacks.chains.iter() {
+        if !scripts.contains(script) || !fallbacks.activated.insert(*script) {
+            continue;
+        }
+
+        for family in families.iter() {
+            let handles = font_map.get_or_load_family(family, &asset_server);
+            if !handles.is_empty() {
+                fallbacks.pending.extend(handles);
+                continue;
+            }
+
+            #[cfg(feature = "system_fonts")]
+            {
+                c.queue(LoadSystemFonts { families: vec![family.0.clone()], fallback: true });
+                fallbacks.needs_refresh = true;
+            }
+
+            #[cfg(not(feature = "system_fonts"))]
+            {
+                let _ = &mut c;
+                tracing::warn!("failed loading font fallback {family:?} for {script:?}; the font family is not \
+                    registered (use RegisterFontFamilies, or enable the system_fonts feature to load installed fonts)");
+            }
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Adds loaded fallback fonts to the text system, and refreshes text so missing glyphs can be replaced.
+fn add_font_fallbacks(
+    mut fallbacks: ResMut<FontFallbacks>,
+    fonts: Res<Assets<Font>>,
+    mut font_system: ResMut<CosmicFontSystem>,
+    mut layouts: Query<&mut TextLayout>,
+)
+{
+    let fallbacks = fallbacks.as_mut();
+    let mut idx = 0;
+    while idx < fallbacks.pending.len() {
+        let handle = &fallbacks.pending[idx];
+        let Some(font) = fonts.get(handle) else {
+            idx += 1;
+            continue;
+        };
+        if fallbacks.added.insert(handle.id()) {
+            font_system.0.db_mut().load_font_data((*font.data).clone());
+            fallbacks.needs_refresh = true;
+        }
+        fallbacks.pending.swap_remove(idx);
+    }
+
+    if !fallbacks.needs_refresh {
+        return;
+    }
+    fallbacks.needs_refresh = false;
+    for mut layout in layouts.iter_mut() {
+        layout.set_changed();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A writing system, used to select font fallbacks.
+///
+/// See [`RegisterFontFallbacks`].
+#[derive(Reflect, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum FontScript
+{
+    /// Latin letters, including accented letters.
+    Latin,
+    Greek,
+    Cyrillic,
+    Armenian,
+    Hebrew,
+    Arabic,
+    Devanagari,
+    Bengali,
+    Tamil,
+    Thai,
+    /// Korean.
+    Hangul,
+    /// Japanese hiragana and katakana. Kanji are [`Self::Cjk`].
+    Japanese,
+    /// CJK ideographs, symbols, and full-width forms.
+    Cjk,
+    /// Pictographs, emoji, and dingbats.
+    Emoji,
+}
+
+impl FontScript
+{
+    /// Gets the script of a character.
+    ///
+    /// Returns `None` for characters that don't belong to a specific script, like digits, punctuation, and
+    /// whitespace.
+    pub fn of(c: char) -> Option<Self>
+    {
+        let script = match c as u32 {
+            0x41..=0x5A | 0x61..=0x7A | 0xC0..=0x24F | 0x1E00..=0x1EFF => Self::Latin,
+            0x370..=0x3FF | 0x1F00..=0x1FFF => Self::Greek,
+            0x400..=0x52F => Self::Cyrillic,
+            0x530..=0x58F => Self::Armenian,
+            0x590..=0x5FF => Self::Hebrew,
+            0x600..=0x6FF | 0x750..=0x77F | 0xFB50..=0xFDFF | 0xFE70..=0xFEFF => Self::Arabic,
+            0x900..=0x97F => Self::Devanagari,
+            0x980..=0x9FF => Self::Bengali,
+            0xB80..=0xBFF => Self::Tamil,
+            0xE00..=0xE7F => Self::Thai,
+            0x1100..=0x11FF | 0x3130..=0x318F | 0xAC00..=0xD7AF => Self::Hangul,
+            0x3040..=0x30FF => Self::Japanese,
+            0x2E80..=0x2FDF | 0x3000..=0x303F | 0x3400..=0x4DBF | 0x4E00..=0x9FFF | 0xF900..=0xFAFF => Self::Cjk,
+            0xFF00..=0xFFEF | 0x20000..=0x2FA1F => Self::Cjk,
+            0x2600..=0x27BF | 0x1F300..=0x1FAFF => Self::Emoji,
+            _ => return None,
+        };
+        Some(script)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource that stores font fallback chains registered with [`RegisterFontFallbacks`].
+#[derive(Resource, Default)]
+pub struct FontFallbacks
+{
+    /// Chains in registration order.
+    chains: Vec<(FontScript, Vec<FontFamily>)>,
+    /// Scripts whose chains have been loaded.
+    activated: HashSet<FontScript>,
+    /// Fonts waiting to be added to the text system.
+    pending: Vec<Handle<Font>>,
+    /// Fonts that were added to the text system.
+    added: HashSet<AssetId<Font>>,
+    /// Indicates all text should be scanned for scripts.
+    rescan: bool,
+    /// Indicates text should be re-rendered with new fallbacks.
+    needs_refresh: bool,
+}
+
+impl FontFallbacks
+{
+    /// Gets the fallback chain for a script.
+    pub fn get(&self, script: FontScript) -> Option<&[FontFamily]>
+    {
+        self.chains
+            .iter()
+            .find(|(s, _)| *s == script)
+            .map(|(_, families)| families.as_slice())
+    }
+
+    /// Returns `true` if the chain for a script was loaded.
+    ///
+    /// Chains are loaded the first time their script appears in text.
+    pub fn is_activated(&self, script: FontScript) -> bool
+    {
+        self.activated.contains(&script)
+    }
+
+    /// Adds a font to the text system as a fallback once it is loaded.
+    ///
+    /// This can be used to register fallback fonts that aren't part of a font family (see
+    /// [`RegisterEmojiFonts`]).
+    pub fn add_fallback_font(&mut self, handle: Handle<Font>)
+    {
+        if self.added.contains(&handle.id()) || self.pending.contains(&handle) {
+            return;
+        }
+        self.pending.push(handle);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A chain of font families used as fallbacks for a script.
+#[derive(Reflect, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FontFallbackChain
+{
+    pub script: FontScript,
+    /// Font families in order of preference.
+    pub families: Vec<FontFamily>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Loadable command for registering font fallback chains per script.
+///
+/// When a script first appears in text, its chain is loaded and added to the text system, which uses the fonts
+/// automatically when a glyph is missing from a text's font. Fonts earlier in a chain are preferred. Chains are
+/// loaded on demand, so large fonts (e.g. for CJK) are only loaded if they are needed.
+///
+/// Families are loaded from [`FontMap`] (see [`RegisterFontFamilies`]). With the `system_fonts` feature,
+/// families that aren't registered are loaded from fonts installed on the user's platform (see
+/// [`LoadSystemFonts`]).
+///
+/// The registered chains can be accessed via [`FontFallbacks`].
+///
+/// Example:
+/**
+```rust
+#commands
+RegisterFontFallbacks[
+    {script:Latin families:["Noto Sans"]}
+    {script:Cjk families:["Noto Sans CJK JP" "Microsoft YaHei" "PingFang SC"]}
+    {script:Hangul families:["Noto Sans CJK KR" "Malgun Gothic"]}
+]
+```
+*/
+#[derive(Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RegisterFontFallbacks(pub Vec<FontFallbackChain>);
+
+impl Command for RegisterFontFallbacks
+{
+    fn apply(self, world: &mut World)
+    {
+        world.syscall(self.0, register_font_fallbacks);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Loadable command for registering color emoji fonts as fallbacks in the text system.
+///
+/// Fonts are loaded from asset paths and are used automatically by the text system when a glyph is missing from
+/// a text's font. Color glyphs (COLR, CBDT, and sbix tables) are rendered with their own colors instead of the
+/// text color (see [`TextColor`]), so emoji in chat text and player names render correctly.
+///
+/// Fonts installed on the user's platform can be used for emoji with [`RegisterFontFallbacks`] and the
+/// [`FontScript::Emoji`] script.
+///
+/// Example:
+/**
+```rust
+#commands
+RegisterEmojiFonts["fonts/NotoColorEmoji.ttf"]
+```
+*/
+#[derive(Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RegisterEmojiFonts(pub Vec<String>);
+
+impl Command for RegisterEmojiFonts
+{
+    fn apply(self, world: &mut World)
+    {
+        world.syscall(self.0, register_emoji_fonts);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct FontFallbacksPlugin;
+
+impl Plugin for FontFallbacksPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_resource::<FontFallbacks>()
+            .register_command_type::<RegisterFontFallbacks>()
+            .register_command_type::<RegisterEmojiFonts>()
+            .add_systems(
+                PostUpdate,
+                (activate_font_fallbacks, add_font_fallbacks)
+                    .chain()
+                    .before(UiSystem::Prepare),
+            );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -431,6 +431,26 @@ impl FontMap
         true
     }
 
+    /// Gets or loads all fonts in a font family.
+    ///
+    /// Returns an empty list if the family was not registered. See [`RegisterFontFamilies`].
+    pub fn get_or_load_family(&mut self, family: &FontFamily, asset_server: &AssetServer) -> Vec<Handle<Font>>
+    {
+        let Some(variants) = self.families.get(&**family) else { return Vec::default() };
+        variants
+            .iter()
+            .map(|v| {
+                Self::get_or_load_impl(
+                    &mut self.pending,
+                    &mut self.cached_fonts,
+                    &self.localization_fonts,
+                    v,
+                    asset_server,
+                )
+            })
+            .collect()
+    }
+
     /// Adds a new set of [`LocalizedFonts`](`LocalizedFont`).
     ///
     /// Will automatically renegotiate languages and emit [`FontMapLoaded`] if appropriate.
@@ -569,6 +589,22 @@ impl FontMap
         entry.clone()
     }
 
+    /// Gets the request for a font that was loaded with [`Self::get_or_load`] or [`LoadFonts`].
+    ///
+    /// Returns `None` if the font is not a registered main font (e.g. if it's a localization fallback).
+    pub fn get_request(&self, font: AssetId<Font>) -> Option<FontRequest>
+    {
+        let (path, _) = self.cached_fonts.iter().find(|(_, handle)| handle.id() == font)?;
+        self.families.iter().find_map(|(family, variants)| {
+            variants.iter().find(|v| v.path == *path).map(|v| FontRequest {
+                family: FontFamily(family.clone()),
+                width: v.width,
+                style: v.style,
+                weight: v.weight,
+            })
+        })
+    }
+
     /// Gets a font handle for the requested font, or loads and caches the font if it's unloaded.
     ///
     /// Returns a default handle if there are no eligible fonts. See [`RegisterFontFamilies`].
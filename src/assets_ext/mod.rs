@@ -3,13 +3,19 @@
 use crate as bevy_cobweb_ui;
 
 mod audio;
+mod font_fallbacks;
 mod fonts;
 mod images;
 mod plugin;
+#[cfg(feature = "system_fonts")]
+mod system_fonts;
 mod texture_atlases;
 
 pub use audio::*;
+pub use font_fallbacks::*;
 pub use fonts::*;
 pub use images::*;
 pub(crate) use plugin::*;
+#[cfg(feature = "system_fonts")]
+pub use system_fonts::*;
 pub use texture_atlases::*;
@@ -14,7 +14,11 @@ impl Plugin for AssetsExtPlugin
             .add_plugins(AudioLoadPlugin)
             .add_plugins(ImageLoadPlugin)
             .add_plugins(FontLoadPlugin)
+            .add_plugins(FontFallbacksPlugin)
             .add_plugins(TextureAtlasLoadPlugin);
+
+        #[cfg(feature = "system_fonts")]
+        app.add_plugins(SystemFontsPlugin);
     }
 }
 
@@ -0,0 +1,219 @@
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+use bevy::text::CosmicFontSystem;
+use bevy_cobweb::prelude::*;
+use smol_str::SmolStr;
+
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn font_width_from_stretch(stretch: fontdb::Stretch) -> FontWidth
+{
+    match stretch {
+        fontdb::Stretch::UltraCondensed => FontWidth::UltraCondensed,
+        fontdb::Stretch::ExtraCondensed => FontWidth::ExtraCondensed,
+        fontdb::Stretch::Condensed => FontWidth::Condensed,
+        fontdb::Stretch::SemiCondensed => FontWidth::Width(0.875),
+        fontdb::Stretch::Normal => FontWidth::Normal,
+        fontdb::Stretch::SemiExpanded => FontWidth::SemiExpanded,
+        fontdb::Stretch::Expanded => FontWidth::Expanded,
+        fontdb::Stretch::ExtraExpanded => FontWidth::ExtraExpanded,
+        fontdb::Stretch::UltraExpanded => FontWidth::UltraExpanded,
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn font_style_from_style(style: fontdb::Style) -> FontStyle
+{
+    match style {
+        fontdb::Style::Normal => FontStyle::Normal,
+        fontdb::Style::Italic => FontStyle::Italic,
+        fontdb::Style::Oblique => FontStyle::Oblique(None),
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Returns `true` if the face is the last face in its font file.
+///
+/// Bevy uses the last face in a font collection when loading a font, so other faces in collections can't be
+/// registered as font assets.
+fn is_last_face_in_source(db: &fontdb::Database, face: &fontdb::FaceInfo) -> bool
+{
+    let fontdb::Source::File(path) = &face.source else { return face.index == 0 };
+    !db.faces().any(|other| {
+        other.index > face.index && matches!(&other.source, fontdb::Source::File(other_path) if other_path == path)
+    })
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn load_system_fonts(
+    In(request): In<LoadSystemFonts>,
+    mut system_fonts: ResMut<SystemFonts>,
+    mut font_map: ResMut<FontMap>,
+    mut fonts: ResMut<Assets<Font>>,
+    mut font_system: Option<ResMut<CosmicFontSystem>>,
+)
+{
+    let system_fonts = system_fonts.as_mut();
+    let db = system_fonts.db.get_or_insert_with(|| {
+        let mut db = fontdb::Database::new();
+        db.load_system_fonts();
+        tracing::info!("discovered {} system font faces", db.len());
+        db
+    });
+
+    for family in request.families.iter() {
+        let faces: Vec<&fontdb::FaceInfo> = db
+            .faces()
+            .filter(|face| {
+                face.families
+                    .iter()
+                    .any(|(name, _)| name.eq_ignore_ascii_case(family))
+            })
+            .collect();
+        if faces.is_empty() {
+            tracing::warn!("failed loading system font family {family:?}; no installed fonts found");
+            continue;
+        }
+
+        for face in faces {
+            if system_fonts.loaded.contains_key(&face.id) {
+                continue;
+            }
+            let Some(data) = db.with_face_data(face.id, |data, _| data.to_vec()) else {
+                tracing::warn!("failed loading system font {:?}; could not read font data", face.post_script_name);
+                continue;
+            };
+
+            // Make glyphs available to text as a fallback.
+            // - Font collections contain all their faces, so they only need to be added once.
+            if let (true, Some(font_system)) = (request.fallback, font_system.as_mut()) {
+                let new_source = match &face.source {
+                    fontdb::Source::File(path) => system_fonts.fallback_sources.insert(path.clone()),
+                    _ => true,
+                };
+                if new_source {
+                    font_system.0.db_mut().load_font_data(data.clone());
+                }
+            }
+
+            if !is_last_face_in_source(db, face) {
+                system_fonts.loaded.insert(face.id, None);
+                continue;
+            }
+            let font = match Font::try_from_bytes(data) {
+                Ok(font) => font,
+                Err(err) => {
+                    tracing::warn!("failed loading system font {:?}; {err:?}", face.post_script_name);
+                    continue;
+                }
+            };
+            let handle = fonts.add(font);
+            let request = FontRequest {
+                family: FontFamily::new(family.clone()),
+                width: font_width_from_stretch(face.stretch),
+                style: font_style_from_style(face.style),
+                weight: FontWeight::Weight(face.weight.0),
+            };
+            font_map.manual_insert(handle.clone(), request);
+            system_fonts.loaded.insert(face.id, Some(handle));
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource that caches fonts discovered on the user's platform.
+#[derive(Resource, Default)]
+struct SystemFonts
+{
+    /// Lazily initialized because discovering system fonts scans the filesystem.
+    db: Option<fontdb::Database>,
+    /// Faces that have been loaded. Faces that aren't the last in a font collection are only used as fallbacks.
+    loaded: HashMap<fontdb::ID, Option<Handle<Font>>>,
+    /// Font files that have been added to the text system as fallbacks.
+    fallback_sources: HashSet<PathBuf>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Loadable command for loading font families installed on the user's platform.
+///
+/// Requires the `system_fonts` feature.
+///
+/// Matching fonts are registered in [`FontMap`] with their family name (case-insensitive) and attributes, so they
+/// can be used like fonts registered with [`RegisterFontFamilies`] (e.g. in [`TextLine::font`]). If
+/// [`Self::fallback`] is set, the fonts are also used automatically by the text system when a glyph is missing
+/// from a text's font. This is useful for user-generated text in scripts your shipped fonts don't cover (e.g.
+/// player names).
+///
+/// System fonts are discovered the first time this command is applied. Families that aren't installed are
+/// skipped with a warning, so list fallbacks for each platform you support.
+///
+/// Only the last font in font collection files (e.g. `.ttc`) is registered in [`FontMap`], but all fonts in
+/// the collection are used as fallbacks.
+///
+/// Example:
+/**
+```rust
+#commands
+LoadSystemFonts{ families: ["Noto Sans CJK JP" "Microsoft YaHei" "PingFang SC"] }
+```
+*/
+#[derive(Reflect, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LoadSystemFonts
+{
+    /// Font families to load.
+    pub families: Vec<SmolStr>,
+    /// Whether to use loaded fonts as fallbacks for missing glyphs.
+    ///
+    /// Defaults to `true`.
+    #[reflect(default = "LoadSystemFonts::default_fallback")]
+    pub fallback: bool,
+}
+
+impl LoadSystemFonts
+{
+    fn default_fallback() -> bool
+    {
+        true
+    }
+}
+
+impl Default for LoadSystemFonts
+{
+    fn default() -> Self
+    {
+        Self { families: Vec::default(), fallback: Self::default_fallback() }
+    }
+}
+
+impl Command for LoadSystemFonts
+{
+    fn apply(self, world: &mut World)
+    {
+        world.syscall(self, load_system_fonts);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct SystemFontsPlugin;
+
+impl Plugin for SystemFontsPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_resource::<SystemFonts>()
+            .register_command_type::<LoadSystemFonts>();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -17,20 +17,36 @@ fn load_texture_atlas_layouts(
 {
     for loaded_layout in layouts.drain(..) {
         let layout = loaded_layout.get_layout();
-        map.insert(loaded_layout.texture, loaded_layout.alias, &mut layout_assets, layout);
+        map.insert_named(
+            loaded_layout.texture,
+            loaded_layout.alias,
+            &mut layout_assets,
+            layout,
+            loaded_layout.names,
+        );
     }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
 
+#[derive(Debug)]
+struct TextureAtlasLayoutEntry
+{
+    handle: Handle<TextureAtlasLayout>,
+    /// [ name : index ]
+    names: HashMap<String, usize>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Resource that stores handles to [`TextureAtlasLayouts`](TextureAtlasLayout).
 ///
-/// Values can be loaded via [`LoadTextureAtlasLayouts`].
+/// Values can be loaded via [`RegisterTextureAtlases`].
 #[derive(Resource, Default)]
 pub struct TextureAtlasLayoutMap
 {
-    /// [ texture : [ alias : layout handle ] ]
-    map: HashMap<String, HashMap<String, Handle<TextureAtlasLayout>>>,
+    /// [ texture : [ alias : layout entry ] ]
+    map: HashMap<String, HashMap<String, TextureAtlasLayoutEntry>>,
 }
 
 impl TextureAtlasLayoutMap
@@ -46,10 +62,40 @@ impl TextureAtlasLayoutMap
         layout: TextureAtlasLayout,
     )
     {
-        self.map
-            .entry(texture)
-            .or_default()
-            .insert(alias, assets.add(layout));
+        self.insert_named(texture, alias, assets, layout, Vec::default());
+    }
+
+    /// Inserts a layout entry with names for its indices.
+    ///
+    /// `names[i]` is the name of index `i` in the layout. Empty names are ignored.
+    pub fn insert_named(
+        &mut self,
+        texture: String,
+        alias: String,
+        assets: &mut Assets<TextureAtlasLayout>,
+        layout: TextureAtlasLayout,
+        names: Vec<String>,
+    )
+    {
+        let num_textures = layout.textures.len();
+        let mut name_map = HashMap::default();
+        for (index, name) in names.into_iter().enumerate() {
+            if name.is_empty() {
+                continue;
+            }
+            if index >= num_textures {
+                tracing::warn!("ignoring name {name:?} for index {index} in texture atlas layout {alias:?} of                     {texture:?}; the layout only has {num_textures} textures");
+                continue;
+            }
+            if let Some(prev) = name_map.insert(name.clone(), index) {
+                tracing::warn!("overwriting index {prev} with index {index} for name {name:?} in texture atlas                     layout {alias:?} of {texture:?}");
+            }
+        }
+
+        self.map.entry(texture).or_default().insert(
+            alias,
+            TextureAtlasLayoutEntry { handle: assets.add(layout), names: name_map },
+        );
     }
 
     /// Gets a handle from the map.
@@ -57,12 +103,42 @@ impl TextureAtlasLayoutMap
     /// Returns `Handle::default` if the layout was not found.
     pub fn get(&self, texture: impl AsRef<str>, alias: impl AsRef<str>) -> Handle<TextureAtlasLayout>
     {
-        self.map
-            .get(texture.as_ref())
-            .and_then(|l| l.get(alias.as_ref()))
-            .cloned()
+        self.get_entry(texture.as_ref(), alias.as_ref())
+            .map(|e| e.handle.clone())
             .unwrap_or_default()
     }
+
+    /// Gets the index of a named texture in a layout.
+    pub fn index(&self, texture: impl AsRef<str>, alias: impl AsRef<str>, name: impl AsRef<str>) -> Option<usize>
+    {
+        self.get_entry(texture.as_ref(), alias.as_ref())
+            .and_then(|e| e.names.get(name.as_ref()))
+            .copied()
+    }
+
+    /// Gets a [`TextureAtlas`] for a [`TextureAtlasReference`].
+    ///
+    /// If the reference has a [`name`](TextureAtlasReference::name), then the index is looked up by name. Falls
+    /// back to [`TextureAtlasReference::index`] if the name is not registered.
+    pub fn get_atlas(&self, texture: impl AsRef<str>, reference: &TextureAtlasReference) -> TextureAtlas
+    {
+        let texture = texture.as_ref();
+        let index = match reference.name.as_ref() {
+            Some(name) => self
+                .index(texture, &reference.alias, name)
+                .unwrap_or_else(|| {
+                    tracing::warn!("failed getting index for name {name:?} in texture atlas layout {:?} of                         {texture:?}; the name is not registered", reference.alias);
+                    reference.index
+                }),
+            None => reference.index,
+        };
+        TextureAtlas { layout: self.get(texture, &reference.alias), index }
+    }
+
+    fn get_entry(&self, texture: &str, alias: &str) -> Option<&TextureAtlasLayoutEntry>
+    {
+        self.map.get(texture).and_then(|l| l.get(alias))
+    }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -73,7 +149,7 @@ impl TextureAtlasLayoutMap
 ///
 /// Includes an `alias`, which can be used by [`TextureAtlasReference`] to access the layout.
 ///
-/// See [`LoadTextureAtlasLayouts`].
+/// See [`RegisterTextureAtlases`].
 #[derive(Reflect, Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LoadedTextureAtlasLayout
 {
@@ -88,6 +164,11 @@ pub struct LoadedTextureAtlasLayout
     pub padding: Option<UVec2>,
     #[reflect(default)]
     pub offset: Option<UVec2>,
+    /// Names for textures in the layout, so they can be referenced by name with [`TextureAtlasReference::name`].
+    ///
+    /// `names[i]` is the name of index `i`. Empty names are ignored.
+    #[reflect(default)]
+    pub names: Vec<String>,
 }
 
 impl LoadedTextureAtlasLayout
@@ -103,8 +184,48 @@ impl LoadedTextureAtlasLayout
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Loadable command for registering texture atlas layouts.
+///
+/// The registered layouts can be accessed via [`TextureAtlasLayoutMap`].
+///
+/// Example:
+/**
+```rust
+#commands
+RegisterTextureAtlases[
+    {
+        texture: "images/icons.png"
+        alias: "icons"
+        tile_size: (32, 32)
+        columns: 4
+        rows: 1
+        padding: (2, 2)
+        names: ["play" "pause" "" "settings"]
+    }
+]
+
+#scenes
+"settings_button"
+    LoadedImageNode{image:"images/icons.png" atlas:{alias:"icons" name:"settings"}}
+```
+*/
+#[derive(Reflect, Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RegisterTextureAtlases(pub Vec<LoadedTextureAtlasLayout>);
+
+impl Command for RegisterTextureAtlases
+{
+    fn apply(self, world: &mut World)
+    {
+        world.syscall(self.0, load_texture_atlas_layouts);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Loadable command for registering texture altases that need to be pre-loaded.
 ///
+/// Equivalent to [`RegisterTextureAtlases`].
+///
 /// The loaded atlases can be accessed via [`TextureAtlasLayoutMap`].
 #[derive(Reflect, Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LoadTextureAtlasLayouts(pub Vec<LoadedTextureAtlasLayout>);
@@ -126,6 +247,7 @@ impl Plugin for TextureAtlasLoadPlugin
     fn build(&self, app: &mut App)
     {
         app.init_resource::<TextureAtlasLayoutMap>()
+            .register_command_type::<RegisterTextureAtlases>()
             .register_command_type::<LoadTextureAtlasLayouts>()
             .register_type::<LoadedTextureAtlasLayout>();
     }
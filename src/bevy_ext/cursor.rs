@@ -15,64 +15,69 @@ struct CursorSource
 {
     primary: Option<LoadableCursor>,
     temporary: Option<LoadableCursor>,
+    /// Entities with active [`TempCursor`]s, in the order they were activated.
+    stack: Vec<Entity>,
+    /// Whether the last cursor set was a temporary cursor.
+    temporary_applied: bool,
 }
 
 impl CursorSource
 {
     /// Returns a cursor if either the primary or temporary is set.
     ///
+    /// If there is no primary cursor, returns the default cursor once after temporary cursors end so the window
+    /// cursor doesn't get stuck on a temporary.
+    ///
     /// Clears the temporary.
     fn get_next_cursor(&mut self, img_map: &mut ImageMap, asset_server: &AssetServer) -> Option<CursorIcon>
     {
-        let cursor = self.temporary.take().or_else(|| self.primary.clone())?;
+        if let Some(temporary) = self.temporary.take() {
+            self.temporary_applied = true;
+            return temporary.into_cursor_icon(img_map, asset_server);
+        }
+        if std::mem::take(&mut self.temporary_applied) && self.primary.is_none() {
+            return Some(CursorIcon::default());
+        }
 
-        Some(cursor.into_cursor_icon(img_map, asset_server)?)
+        self.primary.clone()?.into_cursor_icon(img_map, asset_server)
     }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
 
-/// Iterates available `TempCursors` to extract the current temp cursor.
-fn get_temp_cursor(mut source: ResMut<CursorSource>, temps: Query<(Entity, &TempCursor)>)
+/// Updates the stack of active `TempCursors` and extracts the current temp cursor.
+///
+/// The highest-priority cursor wins. If there are multiple cursors with the highest priority, the most recently
+/// activated one wins.
+fn get_temp_cursor(
+    mut source: ResMut<CursorSource>,
+    mut removed: RemovedComponents<TempCursor>,
+    temps: Query<(Entity, Ref<TempCursor>)>,
+)
 {
-    // Look for highest priority non-None cursor.
-    let mut found: Option<(u8, Entity, &LoadableCursor)> = None;
-    let mut found_second: Option<(u8, Entity, &LoadableCursor)> = None;
-    for (entity, temp) in temps
-        .iter()
-        .filter(|(_, t)| !matches!(t.cursor, LoadableCursor::None))
-    {
-        let Some((prio, _, _)) = &found else {
-            found = Some((temp.priority, entity, &temp.cursor));
-            continue;
-        };
-
-        if temp.priority < *prio {
-            continue;
-        }
-
-        if temp.priority == *prio {
-            found_second = Some((temp.priority, entity, &temp.cursor));
-            continue;
+    // Update the stack.
+    for entity in removed.read() {
+        source.stack.retain(|e| *e != entity);
+    }
+    for (entity, temp) in temps.iter().filter(|(_, t)| t.is_changed()) {
+        source.stack.retain(|e| *e != entity);
+        if !matches!(temp.cursor, LoadableCursor::None) {
+            source.stack.push(entity);
         }
-
-        found = Some((temp.priority, entity, &temp.cursor));
     }
 
-    // Warn if there is a conflict.
-    if let Some((entity, second)) = found_second.and_then(|(prio, e, s)| {
-        if prio >= found.unwrap().0 {
-            return Some((e, s));
+    // Look for the highest priority cursor, preferring the most recent.
+    let mut found: Option<&TempCursor> = None;
+    for (_, temp) in source.stack.iter().rev().filter_map(|e| temps.get(*e).ok()) {
+        if found.is_some_and(|f| f.priority >= temp.priority) {
+            continue;
         }
-        None
-    }) {
-        warn_once!("multiple TempCursor instances detected (first: {:?} {:?}, second: {:?} {:?}); only one can be used at a \
-            time; this warning only prints once", found.unwrap().1, found.unwrap().2, entity, second);
+        found = Some(temp.into_inner());
     }
 
     // Set the cursor.
-    if let Some((_, _, cursor)) = found {
-        source.temporary = Some(cursor.clone());
+    if let Some(found) = found {
+        source.temporary = Some(found.cursor.clone());
     }
 }
 
@@ -194,7 +199,10 @@ impl Command for PrimaryCursor
 //-------------------------------------------------------------------------------------------------------------------
 
 /// Component that tries to set [`CursorIcon`] on all windows of the app every tick. Set the value to
-/// [`LoadableCursor::None`]` to disable it.
+/// [`LoadableCursor::None`] to disable it.
+///
+/// Active temp cursors form a stack. When a temp cursor is disabled or removed, the next cursor in the stack is
+/// restored, or the [`PrimaryCursor`] (or default cursor) if the stack is empty.
 ///
 /// To set a long-term 'primary cursor', use the [`PrimaryCursor`] command.
 ///
@@ -207,9 +215,12 @@ impl Command for PrimaryCursor
 )]
 pub struct TempCursor
 {
-    /// Higher priority cursors will override lower priority cursors.
+    /// Higher priority cursors will override lower priority cursors. If multiple cursors have the same priority,
+    /// the most recently activated one is used.
     ///
-    /// Used as a hack so press cursors won't be overridden by hover cursors when moving off an element.
+    /// [`ResponsiveCursor`] uses priority `1` for hover cursors and `2` for press cursors, so press cursors won't
+    /// be overridden by hover cursors when moving off an element. Use a higher priority for cursors that should
+    /// override interactions (e.g. a 'busy' cursor).
     pub priority: u8,
     pub cursor: LoadableCursor,
 }
@@ -246,7 +257,7 @@ impl ResponsiveAttribute for TempCursor {}
 /// Instruction that sets [`TempCursor`] on the entity when it is hovered or pressed.
 ///
 /// Note that this should usually be paired with a [`PrimaryCursor`] command for the default cursor. Otherwise
-/// the cursor can get 'stuck' on responsive cursor values.
+/// the system default cursor will be restored when interactions end.
 // TODO: There is a bug where if you only have `hover` set, then the hover cursor will be maintained when you
 // press and drag away from the entity until you release.
 #[derive(Reflect, Default, Debug, Clone, PartialEq)]
@@ -0,0 +1,268 @@
+use bevy::ecs::system::SystemParam;
+use bevy::picking::events::pointer_events;
+use bevy::picking::focus::{update_focus, HoverMap};
+use bevy::picking::PickSet;
+use bevy::prelude::*;
+
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Finds the highest blocking layers of visible [`InputRoots`](InputRoot).
+fn update_input_arbiter(
+    mut arbiter: ResMut<InputArbiter>,
+    roots: Query<(Entity, &InputRoot, Option<&InheritedVisibility>)>,
+)
+{
+    let mut pointer: Option<(Entity, i32)> = None;
+    let mut keyboard: Option<(Entity, i32)> = None;
+
+    for (entity, root, visibility) in roots.iter() {
+        if visibility.is_some_and(|v| !v.get()) {
+            continue;
+        }
+        if root.block_pointer && pointer.is_none_or(|(_, layer)| root.layer > layer) {
+            pointer = Some((entity, root.layer));
+        }
+        if root.block_keyboard && keyboard.is_none_or(|(_, layer)| root.layer > layer) {
+            keyboard = Some((entity, root.layer));
+        }
+    }
+
+    // Avoid spurious change detection.
+    if arbiter.pointer != pointer || arbiter.keyboard != keyboard {
+        *arbiter = InputArbiter { pointer, keyboard };
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Removes hovered entities that are blocked by an [`InputRoot`], so pointer events aren't sent to them.
+fn filter_blocked_hovers(mut hover_map: ResMut<HoverMap>, blocking: InputBlockingParam)
+{
+    if blocking.arbiter.pointer.is_none() {
+        return;
+    }
+
+    for hovered in hover_map.values_mut() {
+        hovered.retain(|entity, _| blocking.pointer_allowed(*entity));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource that resolves overlapping [`InputRoots`](InputRoot).
+///
+/// The visible root with the highest layer that blocks a kind of input wins. Input of that kind is only allowed
+/// for nodes in roots with the same or higher layers.
+///
+/// Updated in `PreUpdate` before pointer events are emitted.
+#[derive(Resource, Default, Debug)]
+pub struct InputArbiter
+{
+    pointer: Option<(Entity, i32)>,
+    keyboard: Option<(Entity, i32)>,
+}
+
+impl InputArbiter
+{
+    /// Gets the root that blocks pointer input, and its layer.
+    pub fn pointer_blocker(&self) -> Option<(Entity, i32)>
+    {
+        self.pointer
+    }
+
+    /// Gets the root that blocks keyboard input, and its layer.
+    pub fn keyboard_blocker(&self) -> Option<(Entity, i32)>
+    {
+        self.keyboard
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// System parameter for checking if entities are blocked by [`InputRoots`](InputRoot).
+///
+/// Pointer blocking is applied automatically. Systems that read keyboard input on behalf of an entity (e.g. a
+/// focused text input) should check [`Self::keyboard_allowed`].
+#[derive(SystemParam)]
+pub struct InputBlockingParam<'w, 's>
+{
+    arbiter: Res<'w, InputArbiter>,
+    roots: Query<'w, 's, &'static InputRoot>,
+    parents: Query<'w, 's, &'static Parent>,
+}
+
+impl InputBlockingParam<'_, '_>
+{
+    /// Gets the layer of the nearest [`InputRoot`] of an entity (including itself).
+    ///
+    /// Returns `None` if the entity is not in an input root.
+    pub fn layer(&self, entity: Entity) -> Option<i32>
+    {
+        let mut current = entity;
+        loop {
+            if let Ok(root) = self.roots.get(current) {
+                return Some(root.layer);
+            }
+            let Ok(parent) = self.parents.get(current) else { return None };
+            current = **parent;
+        }
+    }
+
+    /// Returns `true` if pointer input is not blocked for an entity.
+    pub fn pointer_allowed(&self, entity: Entity) -> bool
+    {
+        self.allowed(self.arbiter.pointer, entity)
+    }
+
+    /// Returns `true` if keyboard input is not blocked for an entity.
+    pub fn keyboard_allowed(&self, entity: Entity) -> bool
+    {
+        self.allowed(self.arbiter.keyboard, entity)
+    }
+
+    fn allowed(&self, blocker: Option<(Entity, i32)>, entity: Entity) -> bool
+    {
+        let Some((_, blocking_layer)) = blocker else { return true };
+        self.layer(entity)
+            .is_some_and(|layer| layer >= blocking_layer)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction that marks an entity (usually a scene root) as an input root, for layering e.g. a pause menu
+/// above a HUD.
+///
+/// Overlapping input roots are resolved by layer in the [`InputArbiter`]. When a visible root blocks pointer or
+/// keyboard input, that input is swallowed for all nodes that aren't in roots with the same or higher layer.
+/// Entities outside input roots are always below blocking roots. A root that doesn't block is pass-through: its
+/// nodes can still be blocked by higher roots, but it doesn't affect other roots.
+///
+/// Pointer events are filtered from `bevy_picking` hover data. Keyboard blocking must be checked with
+/// [`InputBlockingParam::keyboard_allowed`]; built-in widgets like text inputs do this already.
+///
+/// Example (COB):
+/**
+```rust
+#scenes
+"pause_menu"
+    AbsoluteNode{width:100vw height:100vh}
+    GlobalZIndex(10)
+    InputRoot{layer:10 block_pointer:true block_keyboard:true}
+```
+*/
+#[derive(Reflect, Component, Default, Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct InputRoot
+{
+    /// Layer of the root. Roots in higher layers are not blocked by roots in lower layers.
+    #[reflect(default)]
+    pub layer: i32,
+    /// Swallows pointer input that doesn't hit nodes in this root or in roots with the same or higher layer.
+    #[reflect(default)]
+    pub block_pointer: bool,
+    /// Swallows keyboard input for nodes that aren't in this root or in roots with the same or higher layer.
+    #[reflect(default)]
+    pub block_keyboard: bool,
+}
+
+impl InputRoot
+{
+    /// Makes an input root that blocks pointer and keyboard input.
+    pub fn modal(layer: i32) -> Self
+    {
+        Self { layer, block_pointer: true, block_keyboard: true }
+    }
+
+    /// Makes an input root that doesn't block input.
+    pub fn pass_through(layer: i32) -> Self
+    {
+        Self { layer, block_pointer: false, block_keyboard: false }
+    }
+}
+
+impl Instruction for InputRoot
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        emut.insert(self);
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        emut.remove::<Self>();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction that marks an entity as an [`InputRoot`] that blocks pointer input from reaching lower layers.
+///
+/// Shorthand for `InputRoot{layer:N block_pointer:true}`, for modals and popovers that should swallow clicks
+/// outside of them without needing a full-screen blocker node. While the entity is visible, pointer input can
+/// only reach nodes in roots with the same or higher layer. Keyboard input is not blocked.
+///
+/// Example (COB):
+/**
+```rust
+#scenes
+"dropdown_popover"
+    AbsoluteNode{top:100%}
+    GlobalZIndex(5)
+    InputLayer(5)
+```
+*/
+#[derive(Reflect, Default, Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct InputLayer(pub i32);
+
+impl Instruction for InputLayer
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        InputRoot { layer: self.0, block_pointer: true, block_keyboard: false }.apply(entity, world);
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        InputRoot::revert(entity, world);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct InputBlockingPlugin;
+
+impl Plugin for InputBlockingPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_resource::<InputArbiter>()
+            .register_instruction_type::<InputRoot>()
+            .register_instruction_type::<InputLayer>()
+            .add_systems(
+                PreUpdate,
+                (
+                    update_input_arbiter.before(update_focus),
+                    filter_blocked_hovers
+                        .after(update_focus)
+                        .before(pointer_events),
+                )
+                    .in_set(PickSet::Focus),
+            );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
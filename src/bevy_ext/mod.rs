@@ -1,11 +1,13 @@
 mod cursor;
 mod fonts;
+mod input_blocking;
 mod picking;
 mod plugin;
 mod texture_atlases;
 
 pub use cursor::*;
 pub use fonts::*;
+pub use input_blocking::*;
 pub use picking::*;
 pub(crate) use plugin::*;
 pub use texture_atlases::*;
@@ -180,7 +180,7 @@ impl Into<SpriteImageMode> for LoadedImageMode
 /// Used to create a [`TextureAtlas`] by accessing a [`TextureAtlasLayout`] by reference via
 /// [`TextureAtlasLayoutMap`].
 ///
-/// See [`LoadedTextureAtlasLayout`].
+/// See [`RegisterTextureAtlases`].
 #[derive(Reflect, Default, Debug, Clone, PartialEq)]
 #[cfg_attr(
     feature = "serde",
@@ -190,12 +190,18 @@ impl Into<SpriteImageMode> for LoadedImageMode
 pub struct TextureAtlasReference
 {
     /// The index into the atlas for the desired sprite.
+    ///
+    /// Ignored if [`Self::name`] is set and registered.
+    #[reflect(default)]
     pub index: usize,
     /// The alias of the [`TextureAtlasLayout`] that is referenced.
     ///
     /// Note that to get a layout handle from [`TextureAtlasLayoutMap`] you also need the texture, which we assume
     /// is stored adjacent to this atlas reference.
     pub alias: String,
+    /// The name of the desired sprite, registered with [`LoadedTextureAtlasLayout::names`].
+    #[reflect(default)]
+    pub name: Option<String>,
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -4,5 +4,6 @@
 
 mod fonts;
 mod plugin;
+mod shaders;
 
 pub(crate) use plugin::*;
@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 
 use super::fonts::BuiltInFontsPlugin;
+use super::shaders::BuiltInShadersPlugin;
 
 //-------------------------------------------------------------------------------------------------------------------
 
@@ -10,7 +11,8 @@ impl Plugin for BuiltinAssetsPlugin
 {
     fn build(&self, app: &mut App)
     {
-        app.add_plugins(BuiltInFontsPlugin);
+        app.add_plugins(BuiltInFontsPlugin)
+            .add_plugins(BuiltInShadersPlugin);
     }
 }
 
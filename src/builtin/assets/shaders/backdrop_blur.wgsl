@@ -0,0 +1,36 @@
+#import bevy_ui::ui_vertex_output::UiVertexOutput
+
+@group(1) @binding(0)
+var<uniform> tint: vec4<f32>;
+// x: blur radius in physical pixels, zw: physical size of the render target
+@group(1) @binding(1)
+var<uniform> blur: vec4<f32>;
+@group(1) @binding(2)
+var backdrop_texture: texture_2d<f32>;
+@group(1) @binding(3)
+var backdrop_sampler: sampler;
+
+const TAPS: i32 = 3;
+
+@fragment
+fn fragment(in: UiVertexOutput) -> @location(0) vec4<f32> {
+    // The backdrop covers the whole render target, so it is sampled at the fragment's screen position.
+    let uv = in.position.xy / blur.zw;
+    let texel = 1.0 / blur.zw;
+    let radius = max(blur.x, 0.0);
+    let sigma = max(radius * 0.5, 0.001);
+
+    var color = vec3<f32>(0.0);
+    var total = 0.0;
+    for (var x = -TAPS; x <= TAPS; x++) {
+        for (var y = -TAPS; y <= TAPS; y++) {
+            let offset = vec2<f32>(f32(x), f32(y)) * (radius / f32(TAPS));
+            let weight = exp(-dot(offset, offset) / (2.0 * sigma * sigma));
+            color += textureSample(backdrop_texture, backdrop_sampler, uv + offset * texel).rgb * weight;
+            total += weight;
+        }
+    }
+    color /= total;
+
+    return vec4<f32>(mix(color, tint.rgb, tint.a), 1.0);
+}
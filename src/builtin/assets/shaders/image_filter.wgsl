@@ -0,0 +1,42 @@
+#import bevy_ui::ui_vertex_output::UiVertexOutput
+
+@group(1) @binding(0)
+var<uniform> tint: vec4<f32>;
+// x: saturation, y: brightness, z: contrast
+@group(1) @binding(1)
+var<uniform> adjustments: vec4<f32>;
+// xy: uv of the top-left corner, zw: uv of the bottom-right corner
+@group(1) @binding(2)
+var<uniform> uv_rect: vec4<f32>;
+@group(1) @binding(3)
+var image_texture: texture_2d<f32>;
+@group(1) @binding(4)
+var image_sampler: sampler;
+// xy: tiling, zw: offset
+@group(1) @binding(5)
+var<uniform> uv_transform: vec4<f32>;
+// x: rotation in radians
+@group(1) @binding(6)
+var<uniform> uv_rotation: vec4<f32>;
+
+@fragment
+fn fragment(in: UiVertexOutput) -> @location(0) vec4<f32> {
+    // Rotate around the center, then tile and offset. Coordinates wrap around within the texture region.
+    let c = cos(uv_rotation.x);
+    let s = sin(uv_rotation.x);
+    let centered = in.uv - 0.5;
+    let rotated = vec2<f32>(c * centered.x - s * centered.y, s * centered.x + c * centered.y) + 0.5;
+    let local = fract(rotated * uv_transform.xy + uv_transform.zw);
+    let uv = mix(uv_rect.xy, uv_rect.zw, local);
+    let sampled = textureSample(image_texture, image_sampler, uv);
+    var color = sampled.rgb * tint.rgb;
+
+    // Saturation
+    let luminance = dot(color, vec3<f32>(0.2126, 0.7152, 0.0722));
+    color = mix(vec3<f32>(luminance), color, adjustments.x);
+
+    // Brightness and contrast
+    color = (color - 0.5) * adjustments.z + 0.5 + adjustments.y;
+
+    return vec4<f32>(clamp(color, vec3<f32>(0.0), vec3<f32>(1.0)), sampled.a * tint.a);
+}
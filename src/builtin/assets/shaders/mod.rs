@@ -0,0 +1,17 @@
+use bevy::asset::embedded_asset;
+use bevy::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct BuiltInShadersPlugin;
+
+impl Plugin for BuiltInShadersPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        embedded_asset!(app, "src/builtin/assets", "backdrop_blur.wgsl");
+        embedded_asset!(app, "src/builtin/assets", "image_filter.wgsl");
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
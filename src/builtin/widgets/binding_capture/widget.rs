@@ -0,0 +1,390 @@
+use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
+use smol_str::SmolStr;
+
+use crate::builtin::widgets::keybinding_hint::*;
+use crate::prelude::*;
+use crate::sickle::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Gets a display label for a key code (e.g. `KeyA` -> `A`).
+fn key_label(key: KeyCode) -> String
+{
+    let label = format!("{key:?}");
+    match label.strip_prefix("Key").or_else(|| label.strip_prefix("Digit")) {
+        Some(stripped) if stripped.len() == 1 => stripped.into(),
+        _ => label,
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Starts capturing input when a [`BindingCapture`] is clicked.
+///
+/// Observes clicks on the binding capture. Propagation stops here so enclosing binding captures ignore the click.
+fn start_binding_capture(
+    mut event: Trigger<Pointer<Click>>,
+    mut c: Commands,
+    ps: PseudoStateParam,
+    mut active: ResMut<ActiveBindingCapture>,
+)
+{
+    if event.button != PointerButton::Primary {
+        return;
+    }
+    event.propagate(false);
+
+    active.set(&mut c, &ps, Some(event.entity()));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Captures the next keyboard or gamepad input for the active [`BindingCapture`].
+fn handle_binding_capture_input(
+    mut c: Commands,
+    ps: PseudoStateParam,
+    mut active: ResMut<ActiveBindingCapture>,
+    settings: Res<BindingCaptureSettings>,
+    keys: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    captures: Query<&BindingCapture>,
+    blocking: InputBlockingParam,
+)
+{
+    let Some(entity) = active.get() else { return };
+    if !blocking.keyboard_allowed(entity) {
+        return;
+    }
+    let Ok(capture) = captures.get(entity) else {
+        active.set(&mut c, &ps, None);
+        return;
+    };
+
+    // Cancel on cancel keys.
+    if keys.any_just_pressed(settings.cancel_keys.iter().copied()) {
+        active.set(&mut c, &ps, None);
+        c.react()
+            .entity_event(entity, BindingCaptureCancelled { binding: capture.binding.clone() });
+        return;
+    }
+
+    // Find the first input that isn't reserved.
+    let input = keys
+        .get_just_pressed()
+        .find(|k| !settings.reserved_keys.contains(k))
+        .map(|k| CapturedInput::Key(*k))
+        .or_else(|| {
+            gamepads
+                .iter()
+                .flat_map(|g| g.get_just_pressed())
+                .find(|b| !settings.reserved_buttons.contains(b))
+                .map(|b| CapturedInput::GamepadButton(*b))
+        });
+    let Some(input) = input else { return };
+
+    active.set(&mut c, &ps, None);
+    c.entity(entity).insert(CapturedBindingLabel(input.label()));
+    c.react().entity_event(
+        entity,
+        BindingCaptured { binding: capture.binding.clone(), input },
+    );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn apply_binding_capture(entity: Entity, world: &mut World)
+{
+    let Some(capture) = world.get::<BindingCapture>(entity) else { return };
+    let labels = match world.get::<CapturedBindingLabel>(entity) {
+        Some(captured) => vec![captured.0.clone()],
+        None => world
+            .resource::<Keymap>()
+            .get_keys(&capture.binding)
+            .unwrap_or_default(),
+    };
+    let scene = SceneRef::new(&capture.file, &capture.scene);
+
+    // Skip rebuilding if nothing changed.
+    if let Some(computed) = world.get::<ComputedBindingCapture>(entity) {
+        if computed.scene == scene && computed.labels == labels {
+            return;
+        }
+    }
+
+    // Clean up old keycaps.
+    if let Some(computed) = world.entity_mut(entity).take::<ComputedBindingCapture>() {
+        despawn_keycaps(world, computed.spawned);
+    }
+
+    // Spawn new keycaps.
+    let spawned = spawn_labeled_keycaps(world, entity, scene.clone(), &labels);
+
+    world
+        .entity_mut(entity)
+        .insert(ComputedBindingCapture { scene, labels, spawned });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn refresh_binding_captures(
+    mut c: Commands,
+    keymap: Res<Keymap>,
+    captures: Query<Entity, With<BindingCapture>>,
+    changed_captures: Query<Entity, Or<(Changed<BindingCapture>, Changed<CapturedBindingLabel>)>>,
+)
+{
+    let refresh = |c: &mut Commands, entity: Entity| {
+        c.queue(move |world: &mut World| apply_binding_capture(entity, world));
+    };
+
+    // Captured inputs are displayed until the keymap changes.
+    match keymap.is_changed() {
+        true => captures.iter().for_each(|e| {
+            c.entity(e).remove::<CapturedBindingLabel>();
+            refresh(&mut c, e);
+        }),
+        false => changed_captures.iter().for_each(|e| refresh(&mut c, e)),
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Label of the input most recently captured by a [`BindingCapture`].
+#[derive(Component, Debug)]
+struct CapturedBindingLabel(String);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Holds the observer that starts capturing when a [`BindingCapture`] is clicked.
+#[derive(Component, Debug)]
+struct BindingCaptureObserver(Entity);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Component)]
+struct ComputedBindingCapture
+{
+    scene: SceneRef,
+    labels: Vec<String>,
+    spawned: Vec<Entity>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// An input captured by a [`BindingCapture`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum CapturedInput
+{
+    /// A keyboard key.
+    Key(KeyCode),
+    /// A button on any gamepad.
+    GamepadButton(GamepadButton),
+}
+
+impl CapturedInput
+{
+    /// Gets a display label for the input.
+    pub fn label(&self) -> String
+    {
+        match self {
+            Self::Key(key) => key_label(*key),
+            Self::GamepadButton(button) => format!("{button:?}"),
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reactive entity event sent to a [`BindingCapture`] when it captures an input.
+///
+/// Update [`Keymap`] (and your input system) in response to apply the new binding.
+#[derive(Debug, Clone)]
+pub struct BindingCaptured
+{
+    /// The binding being captured.
+    pub binding: String,
+    /// The captured input.
+    pub input: CapturedInput,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reactive entity event sent to a [`BindingCapture`] when capturing is cancelled with a
+/// [cancel key](BindingCaptureSettings::cancel_keys).
+#[derive(Debug, Clone)]
+pub struct BindingCaptureCancelled
+{
+    /// The binding being captured.
+    pub binding: String,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource with settings for [`BindingCaptures`](BindingCapture).
+#[derive(Resource, Debug, Clone)]
+pub struct BindingCaptureSettings
+{
+    /// Keys that cancel capturing. Defaults to `[Escape]`.
+    pub cancel_keys: Vec<KeyCode>,
+    /// Keys that are ignored while capturing.
+    pub reserved_keys: Vec<KeyCode>,
+    /// Gamepad buttons that are ignored while capturing.
+    pub reserved_buttons: Vec<GamepadButton>,
+}
+
+impl Default for BindingCaptureSettings
+{
+    fn default() -> Self
+    {
+        Self {
+            cancel_keys: vec![KeyCode::Escape],
+            reserved_keys: vec![],
+            reserved_buttons: vec![],
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource that tracks the [`BindingCapture`] that is currently capturing input.
+///
+/// Other input handlers can check this to avoid reacting to inputs that are being captured.
+#[derive(Resource, Default, Debug)]
+pub struct ActiveBindingCapture
+{
+    active: Option<Entity>,
+}
+
+impl ActiveBindingCapture
+{
+    /// Gets the binding capture that is capturing input.
+    pub fn get(&self) -> Option<Entity>
+    {
+        self.active
+    }
+
+    /// Sets the binding capture that is capturing input.
+    ///
+    /// Updates the [`BINDING_CAPTURE_ACTIVE_PSEUDO_STATE`] on the previous and new entities.
+    pub fn set(&mut self, c: &mut Commands, ps: &PseudoStateParam, active: Option<Entity>)
+    {
+        if self.active == active {
+            return;
+        }
+        if let Some(prev) = self.active {
+            ps.try_remove(c, prev, BINDING_CAPTURE_ACTIVE_PSEUDO_STATE.clone());
+        }
+        if let Some(next) = active {
+            ps.try_insert(c, next, BINDING_CAPTURE_ACTIVE_PSEUDO_STATE.clone());
+        }
+        self.active = active;
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Pseudo state added to a [`BindingCapture`] while it is capturing input.
+///
+/// It can be used in COB as `Custom("Capturing")`.
+pub const BINDING_CAPTURE_ACTIVE_PSEUDO_STATE: PseudoState = PseudoState::Custom(SmolStr::new_static("Capturing"));
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction loadable for a widget that captures the next keyboard or gamepad input, for rebinding inputs in
+/// settings screens.
+///
+/// Clicking the node starts capturing. The next input that isn't
+/// [reserved](BindingCaptureSettings::reserved_keys) sends a [`BindingCaptured`] entity event to the node, and a
+/// [cancel key](BindingCaptureSettings::cancel_keys) sends [`BindingCaptureCancelled`]. While capturing, the node
+/// has the [`BINDING_CAPTURE_ACTIVE_PSEUDO_STATE`], which can be used to display a prompt.
+///
+/// The binding's current keys from [`Keymap`] are displayed with keycap scenes, the same way as
+/// [`KeybindingHint`]. A captured input is displayed until [`Keymap`] changes.
+///
+/// Example (COB):
+/**
+```rust
+#scenes
+"jump_binding"
+    FlexNode{flex_direction:Row}
+    BindingCapture{binding:"jump" file:"ui/hints.cob" scene:"keycap"}
+    Multi<Responsive<BackgroundColor>>[
+        {idle:#222222}
+        {state:[Custom("Capturing")] idle:#555555}
+    ]
+```
+*/
+#[derive(Reflect, Component, Default, Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct BindingCapture
+{
+    /// The binding to capture.
+    pub binding: String,
+    /// File of the keycap scene.
+    pub file: String,
+    /// Keycap scene spawned for each displayed key.
+    pub scene: String,
+}
+
+impl Instruction for BindingCapture
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        validate_instruction_scene_ref::<Self>(world, SceneRef::new(&self.file, &self.scene));
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        let has_observer = emut.contains::<BindingCaptureObserver>();
+        emut.insert(self);
+        if !has_observer {
+            let observer = world
+                .spawn(Observer::new(start_binding_capture).with_entity(entity))
+                .id();
+            world
+                .entity_mut(entity)
+                .insert(BindingCaptureObserver(observer));
+        }
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        {
+            let mut active = world.resource_mut::<ActiveBindingCapture>();
+            if active.active == Some(entity) {
+                active.active = None;
+            }
+        }
+
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        let observer = emut.take::<BindingCaptureObserver>();
+        emut.remove::<(Self, CapturedBindingLabel)>();
+        emut.remove_pseudo_state(BINDING_CAPTURE_ACTIVE_PSEUDO_STATE.clone());
+        if let Some(BindingCaptureObserver(observer)) = observer {
+            world.despawn(observer);
+        }
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        let Some(computed) = emut.take::<ComputedBindingCapture>() else { return };
+        despawn_keycaps(world, computed.spawned);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct CobwebBindingCapturePlugin;
+
+impl Plugin for CobwebBindingCapturePlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_resource::<BindingCaptureSettings>()
+            .init_resource::<ActiveBindingCapture>()
+            .register_instruction_type::<BindingCapture>()
+            .add_systems(Update, handle_binding_capture_input)
+            .add_systems(PostUpdate, refresh_binding_captures.in_set(KeybindingHintUpdateSet));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -41,6 +41,7 @@ impl Instruction for Checkbox
     {
         let Ok(mut emut) = world.get_entity_mut(entity) else { return };
         emut.insert(self);
+        AccessibleRole::Checkbox.apply_default(&mut emut);
 
         if !emut.contains::<CheckboxCallbacks>() {
             let mut on_press = None;
@@ -63,6 +64,7 @@ impl Instruction for Checkbox
     {
         let Ok(mut emut) = world.get_entity_mut(entity) else { return };
         emut.remove::<Self>();
+        AccessibleRole::revert_default(&mut emut);
         if let Some(callbacks) = emut.take::<CheckboxCallbacks>() {
             world.react(move |rc| callbacks.revoke(rc));
         }
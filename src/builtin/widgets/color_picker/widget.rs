@@ -0,0 +1,664 @@
+use bevy::prelude::*;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use bevy_cobweb::prelude::*;
+
+use crate::builtin::widgets::slider::*;
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Number of pixels along each gradient axis of color picker images.
+const GRADIENT_RESOLUTION: u32 = 64;
+/// Size in pixels of checkerboard cells in alpha gradients.
+const CHECKERBOARD_CELL: u32 = 4;
+/// Slider values closer than this are considered equal when syncing slider and color values.
+const SYNC_EPSILON: f32 = 1e-4;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn make_gradient_image(width: u32, height: u32, data: Vec<u8>) -> Image
+{
+    Image::new(
+        Extent3d { width, height, depth_or_array_layers: 1 },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    )
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn slider_values_differ(a: SliderValue, b: SliderValue) -> bool
+{
+    let a = a.to_vec2(SliderAxis::Planar);
+    let b = b.to_vec2(SliderAxis::Planar);
+    (a - b).abs().max_element() > SYNC_EPSILON
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn find_color_picker(
+    entity: Entity,
+    parents: &Query<&Parent>,
+    pickers: &Query<(), With<ComputedColorPicker>>,
+) -> Option<Entity>
+{
+    parents
+        .iter_ancestors(entity)
+        .find(|e| pickers.contains(*e))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn apply_color_picker_part(kind: ColorPickerPartKind, entity: Entity, world: &mut World)
+{
+    if world.get_entity(entity).is_err() {
+        return;
+    }
+
+    Slider { axis: kind.slider_axis(), ..default() }.apply(entity, world);
+
+    let image = world
+        .resource_mut::<Assets<Image>>()
+        .add(kind.generate_gradient(&ColorValue::default()));
+    let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+    emut.insert((
+        ColorPickerPartState { kind, synced: None, image: image.clone(), gradient_key: None },
+        ImageNode::new(image),
+    ));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn revert_color_picker_part(entity: Entity, world: &mut World)
+{
+    Slider::revert(entity, world);
+    let _ = world.get_entity_mut(entity).map(|mut e| {
+        e.remove::<(ColorPickerPartState, ImageNode)>();
+    });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Synchronizes the slider values of color picker parts with their pickers' colors.
+fn sync_color_pickers(
+    mut c: Commands,
+    parents: Query<&Parent>,
+    pickers: Query<(), With<ComputedColorPicker>>,
+    mut parts: Query<(Entity, &mut ColorPickerPartState)>,
+    mut colors: ReactiveMut<ColorValue>,
+    mut sliders: ReactiveMut<SliderValue>,
+)
+{
+    // Apply slider changes to the picker colors.
+    for (entity, mut state) in parts.iter_mut() {
+        let Some(synced) = state.synced else { continue };
+        let Ok(slider) = sliders.get(entity).copied() else { continue };
+        if !slider_values_differ(synced, slider) {
+            continue;
+        }
+        let Some(picker) = find_color_picker(entity, &parents, &pickers) else { continue };
+        let Ok(mut color) = colors.get(picker).copied() else { continue };
+        state.kind.apply_slider_value(&mut color, slider);
+        colors.set_if_neq(&mut c, picker, color);
+        state.synced = Some(slider);
+    }
+
+    // Apply picker colors to the sliders (e.g. when the color is set by the user or by another part).
+    for (entity, mut state) in parts.iter_mut() {
+        let Some(picker) = find_color_picker(entity, &parents, &pickers) else { continue };
+        let Ok(color) = colors.get(picker) else { continue };
+        let Ok(slider) = sliders.get(entity).copied() else { continue };
+        let expected = state.kind.slider_value(color);
+        if state.synced.is_none() || slider_values_differ(expected, slider) {
+            sliders.set_if_neq(&mut c, entity, expected);
+        }
+        state.synced = Some(expected);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Regenerates the gradient images of color picker parts when their pickers' colors change.
+fn refresh_color_picker_gradients(
+    parents: Query<&Parent>,
+    pickers: Query<(), With<ComputedColorPicker>>,
+    colors: Reactive<ColorValue>,
+    mut parts: Query<(Entity, &mut ColorPickerPartState)>,
+    mut images: ResMut<Assets<Image>>,
+)
+{
+    for (entity, mut state) in parts.iter_mut() {
+        let Some(picker) = find_color_picker(entity, &parents, &pickers) else { continue };
+        let Ok(color) = colors.get(picker) else { continue };
+        let key = state.kind.gradient_key(color);
+        if state.gradient_key == Some(key) {
+            continue;
+        }
+        state.gradient_key = Some(key);
+        let Some(image) = images.get_mut(&state.image) else { continue };
+        *image = state.kind.generate_gradient(color);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum ColorPickerPartKind
+{
+    SvBox,
+    Hue
+    {
+        vertical: bool,
+    },
+    Alpha
+    {
+        vertical: bool,
+        checkerboard: bool,
+    },
+}
+
+impl ColorPickerPartKind
+{
+    fn slider_axis(&self) -> SliderAxis
+    {
+        match self {
+            Self::SvBox => SliderAxis::Planar,
+            Self::Hue { vertical } | Self::Alpha { vertical, .. } => match vertical {
+                true => SliderAxis::Y,
+                false => SliderAxis::X,
+            },
+        }
+    }
+
+    fn slider_value(&self, color: &ColorValue) -> SliderValue
+    {
+        match self {
+            Self::SvBox => SliderValue::Planar(color.saturation_value()),
+            Self::Hue { .. } => SliderValue::Single(color.hue_fraction()),
+            Self::Alpha { .. } => SliderValue::Single(color.alpha()),
+        }
+    }
+
+    fn apply_slider_value(&self, color: &mut ColorValue, value: SliderValue)
+    {
+        match self {
+            Self::SvBox => color.set_saturation_value(value.to_vec2(SliderAxis::Planar)),
+            Self::Hue { .. } => color.set_hue_fraction(value.single().unwrap_or_default()),
+            Self::Alpha { .. } => color.set_alpha(value.single().unwrap_or_default()),
+        }
+    }
+
+    /// Gets the parts of a color that the part's gradient depends on.
+    fn gradient_key(&self, color: &ColorValue) -> Vec3
+    {
+        match self {
+            Self::SvBox => Vec3::new(color.0.hue, 0., 0.),
+            Self::Hue { .. } => Vec3::ZERO,
+            Self::Alpha { .. } => Vec3::new(color.0.hue, color.0.saturation, color.0.value),
+        }
+    }
+
+    /// Generates the part's gradient for a color.
+    ///
+    /// Gradients are laid out to match the standard [`SliderDirection`] (left-to-right and bottom-to-top).
+    fn generate_gradient(&self, color: &ColorValue) -> Image
+    {
+        let res = GRADIENT_RESOLUTION;
+        let last = (res - 1) as f32;
+        let mut data = Vec::new();
+
+        match *self {
+            Self::SvBox => {
+                for y in 0..res {
+                    for x in 0..res {
+                        let pixel = Hsva::new(color.0.hue, x as f32 / last, 1. - y as f32 / last, 1.);
+                        data.extend_from_slice(&Srgba::from(pixel).to_u8_array());
+                    }
+                }
+                make_gradient_image(res, res, data)
+            }
+            Self::Hue { vertical } => {
+                for i in 0..res {
+                    let fraction = match vertical {
+                        true => 1. - i as f32 / last,
+                        false => i as f32 / last,
+                    };
+                    let pixel = Hsva::new(fraction * 360., 1., 1., 1.);
+                    data.extend_from_slice(&Srgba::from(pixel).to_u8_array());
+                }
+                match vertical {
+                    true => make_gradient_image(1, res, data),
+                    false => make_gradient_image(res, 1, data),
+                }
+            }
+            Self::Alpha { vertical, checkerboard } => {
+                let base = LinearRgba::from(color.0.with_alpha(1.));
+                let (width, height) = match vertical {
+                    true => (CHECKERBOARD_CELL * 2, res),
+                    false => (res, CHECKERBOARD_CELL * 2),
+                };
+                for y in 0..height {
+                    for x in 0..width {
+                        let alpha = match vertical {
+                            true => 1. - y as f32 / last,
+                            false => x as f32 / last,
+                        };
+                        let pixel = match checkerboard {
+                            true => {
+                                let light = ((x / CHECKERBOARD_CELL) + (y / CHECKERBOARD_CELL)).is_multiple_of(2);
+                                let background = match light {
+                                    true => LinearRgba::from(Srgba::gray(0.8)),
+                                    false => LinearRgba::from(Srgba::gray(0.6)),
+                                };
+                                background.mix(&base, alpha)
+                            }
+                            false => base.with_alpha(alpha),
+                        };
+                        data.extend_from_slice(&Srgba::from(pixel).to_u8_array());
+                    }
+                }
+                make_gradient_image(width, height, data)
+            }
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Component, Debug)]
+struct ColorPickerPartState
+{
+    kind: ColorPickerPartKind,
+    /// The slider value last synchronized with the picker's color.
+    synced: Option<SliderValue>,
+    image: Handle<Image>,
+    /// The color parts used to generate the current gradient image.
+    gradient_key: Option<Vec3>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Component, Default, Debug)]
+struct ComputedColorPicker;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reactive component with the color selected by a color picker.
+///
+/// The color is stored as [`Hsva`] so the hue and saturation are preserved when the color is black or
+/// desaturated.
+///
+/// Defaults to opaque white.
+///
+/// See [`ColorPicker`].
+#[derive(ReactComponent, Debug, Copy, Clone, PartialEq, Reflect)]
+pub struct ColorValue(pub Hsva);
+
+impl ColorValue
+{
+    pub fn new(color: impl Into<Color>) -> Self
+    {
+        Self(Hsva::from(color.into()))
+    }
+
+    /// Gets the color.
+    pub fn color(&self) -> Color
+    {
+        Color::Hsva(self.0)
+    }
+
+    /// Gets the hue in the range `[0.0..1.0]`.
+    pub fn hue_fraction(&self) -> f32
+    {
+        (self.0.hue / 360.).clamp(0., 1.)
+    }
+
+    /// Sets the hue from a value in the range `[0.0..1.0]`.
+    pub fn set_hue_fraction(&mut self, fraction: f32)
+    {
+        self.0.hue = fraction.clamp(0., 1.) * 360.;
+    }
+
+    /// Gets the saturation (`x`) and value (`y`) in the range `[0.0..1.0]`.
+    pub fn saturation_value(&self) -> Vec2
+    {
+        Vec2::new(self.0.saturation, self.0.value).clamp(Vec2::ZERO, Vec2::ONE)
+    }
+
+    /// Sets the saturation (`x`) and value (`y`) from values in the range `[0.0..1.0]`.
+    pub fn set_saturation_value(&mut self, saturation_value: Vec2)
+    {
+        let saturation_value = saturation_value.clamp(Vec2::ZERO, Vec2::ONE);
+        self.0.saturation = saturation_value.x;
+        self.0.value = saturation_value.y;
+    }
+
+    /// Gets the alpha in the range `[0.0..1.0]`.
+    pub fn alpha(&self) -> f32
+    {
+        self.0.alpha.clamp(0., 1.)
+    }
+
+    /// Sets the alpha from a value in the range `[0.0..1.0]`.
+    pub fn set_alpha(&mut self, alpha: f32)
+    {
+        self.0.alpha = alpha.clamp(0., 1.);
+    }
+}
+
+impl Default for ColorValue
+{
+    fn default() -> Self
+    {
+        Self(Hsva::new(0., 0., 1., 1.))
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction loadable for setting up a color picker widget.
+///
+/// This should be placed on the root entity of the color picker. The picker's parts must be descendants of this
+/// entity:
+/// - [`ColorPickerSvBox`]: A box for selecting the saturation and value of the color.
+/// - [`ColorPickerHue`]: A strip for selecting the hue.
+/// - [`ColorPickerAlpha`]: A strip for selecting the alpha.
+///
+/// All parts are optional. Each part is a [`Slider`] with a generated gradient image, so use [`SliderHandle`] on
+/// a child of each part to display the part's handle. Parts are styled like any other node.
+///
+/// Inserts a [`ColorValue`] reactive component to the entity if it doesn't have one. Update the `ColorValue` to
+/// change the selected color, and react to it to get colors selected by the user (see [`ColorPickerWidgetExt`]).
+///
+/// Example:
+/**
+```rust
+"picker"
+    ColorPicker
+    FlexNode{flex_direction:Column row_gap:8px}
+
+    "sv"
+        ColorPickerSvBox
+        FlexNode{width:200px height:200px}
+
+        "handle"
+            SliderHandle
+            AbsoluteNode{width:10px height:10px}
+            Border(2px)
+            BorderColor(#FFFFFF)
+
+    "hue"
+        ColorPickerHue
+        FlexNode{width:200px height:16px}
+
+        "handle"
+            SliderHandle
+            AbsoluteNode{width:6px height:100%}
+            BackgroundColor(#FFFFFF)
+
+    "alpha"
+        ColorPickerAlpha
+        FlexNode{width:200px height:16px}
+
+        "handle"
+            SliderHandle
+            AbsoluteNode{width:6px height:100%}
+            BackgroundColor(#FFFFFF)
+```
+*/
+#[derive(Reflect, Default, Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct ColorPicker;
+
+impl Instruction for ColorPicker
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        let has_value = emut.contains::<React<ColorValue>>();
+        emut.insert(ComputedColorPicker);
+
+        if !has_value {
+            world.react(|rc| rc.insert(entity, ColorValue::default()));
+        }
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.remove::<(ComputedColorPicker, React<ColorValue>)>();
+        });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction loadable for a [`ColorPicker`] part that selects the saturation (horizontal axis) and value
+/// (vertical axis) of the color.
+///
+/// Inserts a [`Slider`] with [`SliderAxis::Planar`] and an [`ImageNode`] with a gradient for the current hue. The
+/// entity should not have its own `ImageNode`.
+#[derive(Reflect, Default, Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct ColorPickerSvBox;
+
+impl Instruction for ColorPickerSvBox
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        apply_color_picker_part(ColorPickerPartKind::SvBox, entity, world);
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        revert_color_picker_part(entity, world);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction loadable for a [`ColorPicker`] part that selects the hue of the color.
+///
+/// Inserts a [`Slider`] and an [`ImageNode`] with a rainbow gradient. The entity should not have its own
+/// `ImageNode`.
+#[derive(Reflect, Default, Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct ColorPickerHue
+{
+    /// Whether the strip is vertical (bottom-to-top) instead of horizontal (left-to-right).
+    ///
+    /// Defaults to `false`.
+    #[reflect(default)]
+    pub vertical: bool,
+}
+
+impl Instruction for ColorPickerHue
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        apply_color_picker_part(ColorPickerPartKind::Hue { vertical: self.vertical }, entity, world);
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        revert_color_picker_part(entity, world);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction loadable for a [`ColorPicker`] part that selects the alpha of the color.
+///
+/// Inserts a [`Slider`] and an [`ImageNode`] with a transparent-to-opaque gradient of the current color. The
+/// entity should not have its own `ImageNode`.
+#[derive(Reflect, Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct ColorPickerAlpha
+{
+    /// Whether the strip is vertical (bottom-to-top) instead of horizontal (left-to-right).
+    ///
+    /// Defaults to `false`.
+    #[reflect(default)]
+    pub vertical: bool,
+    /// Whether to draw the gradient over a checkerboard so transparency is visible.
+    ///
+    /// Defaults to `true`.
+    #[reflect(default = "ColorPickerAlpha::default_checkerboard")]
+    pub checkerboard: bool,
+}
+
+impl ColorPickerAlpha
+{
+    fn default_checkerboard() -> bool
+    {
+        true
+    }
+}
+
+impl Default for ColorPickerAlpha
+{
+    fn default() -> Self
+    {
+        Self { vertical: false, checkerboard: Self::default_checkerboard() }
+    }
+}
+
+impl Instruction for ColorPickerAlpha
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let kind = ColorPickerPartKind::Alpha { vertical: self.vertical, checkerboard: self.checkerboard };
+        apply_color_picker_part(kind, entity, world);
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        revert_color_picker_part(entity, world);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Extension trait for interacting with [`ColorValue`] in a COB scene.
+pub trait ColorPickerWidgetExt
+{
+    /// Adds a callback for initializing the `React<ColorValue>` component on the current entity from world state.
+    /**
+    ```rust
+    ui_builder.initialize_color_picker(
+        |
+            id: TargetId,
+            mut c: Commands,
+            settings: Res<Settings>,
+            mut value: ReactiveMut<ColorValue>,
+        | {
+            value.set_if_neq(&mut c, *id, ColorValue::new(settings.hair_color));
+            OK
+        }
+    );
+    ```
+    */
+    ///
+    /// Equivalent to:
+    /// ```rust
+    /// ui_builder.update_on(entity_insertion::<ColorValue>(entity), callback)
+    /// ```
+    fn initialize_color_picker<M, C, R: CobwebResult>(&mut self, callback: C) -> &mut Self
+    where
+        C: IntoSystem<TargetId, R, M> + Send + Sync + 'static;
+
+    /// Adds a callback for reacting to changes in the `React<ColorValue>` component on the current entity.
+    /**
+    ```rust
+    ui_builder.on_color_picker(
+        |
+            id: TargetId,
+            mut settings: ResMut<Settings>,
+            value: Reactive<ColorValue>,
+        | {
+            settings.hair_color = value.get(*id)?.color();
+            OK
+        }
+    );
+    ```
+    */
+    ///
+    /// Equivalent to:
+    /// ```rust
+    /// ui_builder.update_on(entity_mutation::<ColorValue>(entity), callback)
+    /// ```
+    fn on_color_picker<M, C, R: CobwebResult>(&mut self, callback: C) -> &mut Self
+    where
+        C: IntoSystem<TargetId, R, M> + Send + Sync + 'static;
+}
+
+impl ColorPickerWidgetExt for UiBuilder<'_, Entity>
+{
+    fn initialize_color_picker<M, C, R: CobwebResult>(&mut self, callback: C) -> &mut Self
+    where
+        C: IntoSystem<TargetId, R, M> + Send + Sync + 'static,
+    {
+        self.update_on(entity_insertion::<ColorValue>(self.id()), callback)
+    }
+
+    fn on_color_picker<M, C, R: CobwebResult>(&mut self, callback: C) -> &mut Self
+    where
+        C: IntoSystem<TargetId, R, M> + Send + Sync + 'static,
+    {
+        self.update_on(entity_mutation::<ColorValue>(self.id()), callback)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// System set in `PostUpdate` where color picker widgets are updated.
+#[derive(SystemSet, Debug, Hash, Eq, PartialEq, Copy, Clone)]
+pub struct ColorPickerUpdateSet;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct CobwebColorPickerPlugin;
+
+impl Plugin for CobwebColorPickerPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.register_instruction_type::<ColorPicker>()
+            .register_instruction_type::<ColorPickerSvBox>()
+            .register_instruction_type::<ColorPickerHue>()
+            .register_instruction_type::<ColorPickerAlpha>()
+            .configure_sets(
+                PostUpdate,
+                ColorPickerUpdateSet
+                    .after(FileProcessingSet)
+                    .before(SliderUpdateSet),
+            )
+            .add_systems(
+                PostUpdate,
+                (sync_color_pickers, refresh_color_picker_gradients)
+                    .chain()
+                    .in_set(ColorPickerUpdateSet),
+            );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
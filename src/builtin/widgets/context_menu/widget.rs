@@ -0,0 +1,641 @@
+use bevy::ecs::query::{QueryData, QueryFilter};
+use bevy::picking::PickSet;
+use bevy::prelude::TransformSystem::TransformPropagate;
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+use bevy_cobweb::prelude::*;
+
+use crate::prelude::*;
+use crate::sickle::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Finds the nearest entity (starting with `entity`) that matches a query.
+fn find_nearest<D: QueryData, F: QueryFilter>(
+    entity: Entity,
+    parents: &Query<&Parent>,
+    query: &Query<D, F>,
+) -> Option<Entity>
+{
+    std::iter::once(entity)
+        .chain(parents.iter_ancestors(entity))
+        .find(|e| query.contains(*e))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Spawns a menu scene as a new UI root.
+///
+/// The menu observes clicks and hovers that bubble up from its items.
+fn spawn_menu(
+    c: &mut Commands,
+    s: &mut SceneBuilder,
+    scene: SceneRef,
+    anchor: ContextMenuAnchor,
+    camera: Option<TargetCamera>,
+) -> Option<Entity>
+{
+    let mut spawned = None;
+    c.spawn_scene_and_edit(scene, s, |h| {
+        h.insert(ContextMenuNode { anchor });
+        spawned = Some(h.id());
+    });
+    let menu = spawned?;
+    c.entity(menu)
+        .observe(handle_menu_item_click)
+        .observe(handle_menu_item_hover);
+
+    // Menus are UI roots, so they need to be placed in the same camera as their source.
+    if let Some(camera) = camera {
+        c.entity(menu).try_insert(camera);
+    }
+
+    Some(menu)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Opens context menus on secondary presses, and closes them on presses outside open menus.
+fn handle_context_menu_press(
+    mut c: Commands,
+    mut s: SceneBuilder,
+    mut presses: EventReader<Pointer<Down>>,
+    mut state: ResMut<ContextMenuState>,
+    parents: Query<&Parent>,
+    target_cameras: Query<&TargetCamera>,
+    menus: Query<(), With<ContextMenuNode>>,
+    sources: Query<&ContextMenu>,
+)
+{
+    for press in presses.read() {
+        // Presses inside open menus are handled by menu items.
+        if find_nearest(press.target, &parents, &menus).is_some() {
+            continue;
+        }
+
+        state.close_all(&mut c);
+
+        if press.button != PointerButton::Secondary {
+            continue;
+        }
+        let Some(source) = find_nearest(press.target, &parents, &sources) else { continue };
+        let Ok(config) = sources.get(source) else { continue };
+
+        let root = parents.iter_ancestors(source).last().unwrap_or(source);
+        let camera = target_cameras.get(root).ok().cloned();
+        let anchor = ContextMenuAnchor::Cursor(press.pointer_location.position);
+        let scene = SceneRef::new(&config.file, &config.scene);
+        let Some(menu) = spawn_menu(&mut c, &mut s, scene, anchor, camera.clone()) else { continue };
+
+        state.source = Some(source);
+        state.camera = camera;
+        state.submenu_delay = config.submenu_delay;
+        state.menus.push(OpenMenu { menu, item: None });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Selects menu items and opens submenus when they are clicked.
+fn handle_menu_item_click(
+    event: Trigger<Pointer<Click>>,
+    mut c: Commands,
+    mut s: SceneBuilder,
+    mut state: ResMut<ContextMenuState>,
+    ps: PseudoStateParam,
+    parents: Query<&Parent>,
+    items: Query<(Option<&MenuItem>, Option<&SubMenu>), Or<(With<MenuItem>, With<SubMenu>)>>,
+)
+{
+    if event.button != PointerButton::Primary {
+        return;
+    }
+
+    let Some(item) = find_nearest(event.target, &parents, &items) else { return };
+    let Some(level) = state.menu_level(item, &parents) else { return };
+    if ps.entity_has(item, PseudoState::Disabled) {
+        return;
+    }
+    let Ok((maybe_item, maybe_submenu)) = items.get(item) else { return };
+
+    // Items with submenus open the submenu immediately.
+    if let Some(submenu) = maybe_submenu {
+        state.pending = None;
+        state.open_submenu(&mut c, &mut s, level, item, submenu);
+        return;
+    }
+
+    let Some(menu_item) = maybe_item else { return };
+    if let Some(source) = state.source {
+        c.react()
+            .entity_event(source, MenuItemSelected { name: menu_item.name.clone(), item });
+    }
+    state.close_all(&mut c);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Starts the submenu hover delay when a menu item is hovered.
+fn handle_menu_item_hover(
+    event: Trigger<Pointer<Over>>,
+    mut state: ResMut<ContextMenuState>,
+    parents: Query<&Parent>,
+    items: Query<(), Or<(With<MenuItem>, With<SubMenu>)>>,
+)
+{
+    let Some(item) = find_nearest(event.target, &parents, &items) else { return };
+    let Some(level) = state.menu_level(item, &parents) else { return };
+    if state.pending.as_ref().is_some_and(|p| p.item == item) {
+        return;
+    }
+    let delay = state.submenu_delay;
+    state.pending = Some(PendingMenuHover { item, level, remaining: delay });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Handles Escape, hover delays, and despawned menus and sources.
+fn update_context_menus(
+    mut c: Commands,
+    mut s: SceneBuilder,
+    time: Res<Time>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut state: ResMut<ContextMenuState>,
+    sources: Query<(), With<ContextMenu>>,
+    menus: Query<(), With<ContextMenuNode>>,
+    submenus: Query<&SubMenu>,
+)
+{
+    if state.menus.is_empty() {
+        return;
+    }
+
+    let source_alive = state.source.is_some_and(|s| sources.contains(s));
+    let menus_alive = state.menus.iter().all(|m| menus.contains(m.menu));
+    if keys.just_pressed(KeyCode::Escape) || !source_alive || !menus_alive {
+        state.close_all(&mut c);
+        return;
+    }
+
+    // Update the hover delay.
+    let Some(pending) = state.pending.as_mut() else { return };
+    pending.remaining -= time.delta_secs();
+    if pending.remaining > 0. {
+        return;
+    }
+    let pending = state.pending.take().unwrap();
+
+    match submenus.get(pending.item) {
+        Ok(submenu) => state.open_submenu(&mut c, &mut s, pending.level, pending.item, submenu),
+        Err(_) => state.close_above(&mut c, pending.level),
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Positions menus at their anchors, keeping them inside the camera view.
+///
+/// Menus are UI roots, so we can override their transforms after layout to avoid a frame of lag.
+fn update_context_menu_positions(
+    ui_camera: DefaultUiCamera,
+    cameras: Query<&Camera>,
+    items: Query<(&ComputedNode, &GlobalTransform), Without<ContextMenuNode>>,
+    mut menus: Query<(
+        &ContextMenuNode,
+        &ComputedNode,
+        Option<&TargetCamera>,
+        &mut Node,
+        &mut Transform,
+    )>,
+)
+{
+    for (menu, computed, maybe_camera, mut node, mut transform) in menus.iter_mut() {
+        let Some(camera) = maybe_camera
+            .map(|c| c.entity())
+            .or_else(|| ui_camera.get())
+            .and_then(|e| cameras.get(e).ok())
+        else {
+            continue;
+        };
+        let Some(view) = camera.physical_viewport_size().map(|s| s.as_vec2()) else { continue };
+
+        if node.position_type != PositionType::Absolute {
+            node.position_type = PositionType::Absolute;
+        }
+
+        // Open toward the bottom-right of the anchor, flipping away from the view edges if needed.
+        let size = computed.size();
+        let mut position = match menu.anchor {
+            ContextMenuAnchor::Cursor(cursor) => {
+                let viewport_min = camera
+                    .physical_viewport_rect()
+                    .map(|r| r.min.as_vec2())
+                    .unwrap_or_default();
+                let cursor = cursor * camera.target_scaling_factor().unwrap_or(1.) - viewport_min;
+                let mut position = cursor;
+                if position.x + size.x > view.x {
+                    position.x = cursor.x - size.x;
+                }
+                if position.y + size.y > view.y {
+                    position.y = cursor.y - size.y;
+                }
+                position
+            }
+            ContextMenuAnchor::Item(item) => {
+                let Ok((item_computed, item_transform)) = items.get(item) else { continue };
+                let item = Rect::from_center_size(item_transform.translation().truncate(), item_computed.size());
+                let mut position = Vec2::new(item.max.x, item.min.y);
+                if position.x + size.x > view.x {
+                    position.x = item.min.x - size.x;
+                }
+                position
+            }
+        };
+        position = position.min(view - size).max(Vec2::ZERO);
+
+        let center = position + size * 0.5;
+        if transform.translation.truncate() != center {
+            transform.translation.x = center.x;
+            transform.translation.y = center.y;
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Where a menu is opened.
+#[derive(Debug, Copy, Clone)]
+enum ContextMenuAnchor
+{
+    /// Logical position of the cursor in its render target.
+    Cursor(Vec2),
+    /// The menu item that opened a submenu.
+    Item(Entity),
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Component on the root nodes of open menus.
+#[derive(Component, Debug)]
+struct ContextMenuNode
+{
+    anchor: ContextMenuAnchor,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Debug, Copy, Clone)]
+struct OpenMenu
+{
+    menu: Entity,
+    /// The item that opened this menu if it is a submenu.
+    item: Option<Entity>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct PendingMenuHover
+{
+    item: Entity,
+    /// Level of the menu containing the item.
+    level: usize,
+    remaining: f32,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Tracks the open context menu.
+#[derive(Resource, Default, Debug)]
+struct ContextMenuState
+{
+    /// The entity with the [`ContextMenu`] that opened the menus.
+    source: Option<Entity>,
+    camera: Option<TargetCamera>,
+    submenu_delay: f32,
+    /// Open menus, starting with the root menu. Each menu after the root is a submenu of the previous menu.
+    menus: Vec<OpenMenu>,
+    pending: Option<PendingMenuHover>,
+}
+
+impl ContextMenuState
+{
+    /// Gets the level of the open menu containing an entity.
+    fn menu_level(&self, entity: Entity, parents: &Query<&Parent>) -> Option<usize>
+    {
+        std::iter::once(entity)
+            .chain(parents.iter_ancestors(entity))
+            .find_map(|e| self.menus.iter().position(|m| m.menu == e))
+    }
+
+    /// Closes all menus above `level`.
+    fn close_above(&mut self, c: &mut Commands, level: usize)
+    {
+        let start = (level + 1).min(self.menus.len());
+        for open in self.menus.drain(start..) {
+            if let Some(ec) = c.get_entity(open.menu) {
+                ec.despawn_recursive();
+            }
+        }
+    }
+
+    fn close_all(&mut self, c: &mut Commands)
+    {
+        for open in self.menus.drain(..) {
+            if let Some(ec) = c.get_entity(open.menu) {
+                ec.despawn_recursive();
+            }
+        }
+        self.source = None;
+        self.camera = None;
+        self.pending = None;
+    }
+
+    /// Opens a submenu for an item in the menu at `level`.
+    fn open_submenu(
+        &mut self,
+        c: &mut Commands,
+        s: &mut SceneBuilder,
+        level: usize,
+        item: Entity,
+        submenu: &SubMenu,
+    )
+    {
+        // Do nothing if the submenu is already open.
+        if self
+            .menus
+            .get(level + 1)
+            .is_some_and(|m| m.item == Some(item))
+        {
+            self.close_above(c, level + 1);
+            return;
+        }
+
+        self.close_above(c, level);
+        let scene = SceneRef::new(&submenu.file, &submenu.scene);
+        let Some(menu) = spawn_menu(c, s, scene, ContextMenuAnchor::Item(item), self.camera.clone()) else {
+            return;
+        };
+        self.menus.push(OpenMenu { menu, item: Some(item) });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction loadable for opening a context menu when the entity is pressed with the secondary pointer button
+/// (usually right-click).
+///
+/// The menu scene is spawned as a new UI root at the cursor, and will open toward the top or left of the cursor if
+/// there is no room below or to the right. Menus are positioned by overriding their `Transform` after layout, so
+/// the scene's root node will be made absolutely-positioned. Add a [`GlobalZIndex`] to the scene's root node if
+/// the menu should be drawn above other UI roots.
+///
+/// Only one context menu can be open at a time. The menu is closed when:
+/// - Pressing outside the menu and its submenus.
+/// - Pressing `Escape`.
+/// - Selecting a [`MenuItem`].
+///
+/// When a [`MenuItem`] in the menu is clicked, a [`MenuItemSelected`] entity event is emitted on the entity with
+/// this instruction (see [`ContextMenuWidgetExt::on_menu_item_selected`]). Use [`SubMenu`] on menu items that
+/// should open submenus.
+///
+/// If nested entities have context menus, only the menu of the entity closest to the pressed entity will open.
+///
+/// Example:
+/**
+```rust
+#scenes
+"menu"
+    GlobalZIndex(100)
+    FlexNode{flex_direction:Column}
+    BackgroundColor(#333333)
+
+    "copy"
+        MenuItem{name:"copy"}
+        TextLine{text:"Copy"}
+    "paste"
+        MenuItem{name:"paste"}
+        TextLine{text:"Paste"}
+    "more"
+        SubMenu{file:"ui/menus.cob" scene:"more"}
+        TextLine{text:"More >"}
+
+"more"
+    GlobalZIndex(100)
+    FlexNode{flex_direction:Column}
+    BackgroundColor(#333333)
+
+    "delete"
+        MenuItem{name:"delete"}
+        TextLine{text:"Delete"}
+
+"document"
+    ContextMenu{file:"ui/menus.cob" scene:"menu"}
+```
+*/
+#[derive(Reflect, Component, Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct ContextMenu
+{
+    /// File of the menu scene.
+    pub file: String,
+    /// Menu scene spawned when the entity is pressed with the secondary pointer button.
+    pub scene: String,
+    /// Seconds to hover a [`SubMenu`] item before its submenu opens. Hovering another item for this long will
+    /// close the submenu.
+    ///
+    /// Defaults to `0.25`.
+    #[reflect(default = "ContextMenu::default_submenu_delay")]
+    pub submenu_delay: f32,
+}
+
+impl ContextMenu
+{
+    pub fn new(file: impl Into<String>, scene: impl Into<String>) -> Self
+    {
+        Self {
+            file: file.into(),
+            scene: scene.into(),
+            submenu_delay: Self::default_submenu_delay(),
+        }
+    }
+
+    fn default_submenu_delay() -> f32
+    {
+        0.25
+    }
+}
+
+impl Default for ContextMenu
+{
+    fn default() -> Self
+    {
+        Self::new("", "")
+    }
+}
+
+impl Instruction for ContextMenu
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        validate_instruction_scene_ref::<Self>(world, SceneRef::new(&self.file, &self.scene));
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.insert(self);
+        });
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.remove::<Self>();
+        });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Component loadable for selectable items in a [`ContextMenu`] scene.
+///
+/// Clicking the item emits a [`MenuItemSelected`] entity event on the context menu's source entity and closes the
+/// menu. Items with [`PseudoState::Disabled`] can't be selected.
+#[derive(Reflect, Component, Default, Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct MenuItem
+{
+    /// Name used to identify the item in [`MenuItemSelected`].
+    pub name: String,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction loadable for menu items that open submenus.
+///
+/// The submenu opens to the right of the item (or to the left if there is no room) when the item is clicked or
+/// hovered for [`ContextMenu::submenu_delay`].
+#[derive(Reflect, Component, Default, Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct SubMenu
+{
+    /// File of the submenu scene.
+    pub file: String,
+    /// Submenu scene.
+    pub scene: String,
+}
+
+impl Instruction for SubMenu
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        validate_instruction_scene_ref::<Self>(world, SceneRef::new(&self.file, &self.scene));
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.insert(self);
+        });
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.remove::<Self>();
+        });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Entity event emitted on the entity with a [`ContextMenu`] when one of its [`MenuItems`](MenuItem) is selected.
+#[derive(Debug, Clone)]
+pub struct MenuItemSelected
+{
+    /// The [`MenuItem::name`] of the selected item.
+    pub name: String,
+    /// The selected item entity.
+    pub item: Entity,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Extension trait for reacting to [`ContextMenu`] selections.
+pub trait ContextMenuWidgetExt
+{
+    /// Adds a callback for reacting to [`MenuItemSelected`] events on the current entity.
+    /**
+    ```rust
+    ui_builder.on_menu_item_selected(
+        |event: EntityEvent<MenuItemSelected>, mut document: ResMut<Document>| {
+            let (_, selected) = event.try_read()?;
+            match selected.name.as_str() {
+                "copy" => document.copy(),
+                "paste" => document.paste(),
+                _ => (),
+            }
+            DONE
+        }
+    );
+    ```
+    */
+    ///
+    /// Equivalent to:
+    /// ```rust
+    /// ui_builder.on_event::<MenuItemSelected>().r(callback)
+    /// ```
+    fn on_menu_item_selected<R: CobwebResult, M>(
+        &mut self,
+        callback: impl IntoSystem<(), R, M> + Send + Sync + 'static,
+    ) -> &mut Self;
+}
+
+impl ContextMenuWidgetExt for UiBuilder<'_, Entity>
+{
+    fn on_menu_item_selected<R: CobwebResult, M>(
+        &mut self,
+        callback: impl IntoSystem<(), R, M> + Send + Sync + 'static,
+    ) -> &mut Self
+    {
+        self.on_event::<MenuItemSelected>().r(callback);
+        self
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// System set in `PostUpdate` where context menus are positioned.
+#[derive(SystemSet, Debug, Hash, Eq, PartialEq, Copy, Clone)]
+pub struct ContextMenuUpdateSet;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct CobwebContextMenuPlugin;
+
+impl Plugin for CobwebContextMenuPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_resource::<ContextMenuState>()
+            .register_instruction_type::<ContextMenu>()
+            .register_component_type::<MenuItem>()
+            .register_instruction_type::<SubMenu>()
+            .configure_sets(
+                PostUpdate,
+                ContextMenuUpdateSet
+                    .after(UiSystem::Layout)
+                    .before(TransformPropagate),
+            )
+            .add_systems(PreUpdate, handle_context_menu_press.after(PickSet::Focus))
+            .add_systems(Update, update_context_menus)
+            .add_systems(PostUpdate, update_context_menu_positions.in_set(ContextMenuUpdateSet));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,503 @@
+use bevy::prelude::*;
+use bevy::reflect::{ParsedPath, ReflectRef};
+use bevy::ui::UiSystem;
+use bevy_cobweb::prelude::*;
+use smol_str::SmolStr;
+
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Finds the entity with the [`TemplateData`] that applies to `entity` (either itself or its nearest ancestor).
+fn find_template_data(world: &World, mut entity: Entity) -> Option<Entity>
+{
+    loop {
+        if world.get::<TemplateData>(entity).is_some() {
+            return Some(entity);
+        }
+        entity = world.get::<Parent>(entity)?.get();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn reflect_to_string(value: &dyn PartialReflect) -> String
+{
+    if let Some(val) = value.try_downcast_ref::<String>() {
+        return val.clone();
+    }
+    if let Some(val) = value.try_downcast_ref::<SmolStr>() {
+        return val.to_string();
+    }
+
+    macro_rules! try_to_string {
+        ($($type:ty),*) => {
+            $(
+                if let Some(val) = value.try_downcast_ref::<$type>() {
+                    return val.to_string();
+                }
+            )*
+        };
+    }
+    try_to_string!(bool, f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, char);
+
+    format!("{value:?}")
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn apply_data_binding(entity: Entity, world: &mut World)
+{
+    let Some(binding) = world.get::<DataBinding>(entity) else { return };
+    let Some(data_entity) = find_template_data(world, entity) else {
+        tracing::warn!("failed applying DataBinding to {entity:?}; no TemplateData found on the entity or its \
+            ancestors");
+        return;
+    };
+    let data = world.get::<TemplateData>(data_entity).unwrap();
+
+    // Extract values.
+    let get = |field: &Option<String>, kind: &str| -> Option<&dyn PartialReflect> {
+        let field = field.as_ref()?;
+        let value = data.get_field(field);
+        if value.is_none() {
+            tracing::warn!("failed binding {kind} to {entity:?} from TemplateData on {data_entity:?}; field {field:?} \
+                not found in {:?}", data.get().reflect_type_path());
+        }
+        value
+    };
+    let text = get(&binding.text, "text").map(|value| {
+        let value = reflect_to_string(value);
+        match &binding.format {
+            Some(format) => format.replace("{}", &value),
+            None => value,
+        }
+    });
+    let image = get(&binding.image, "image").map(reflect_to_string);
+    let color = get(&binding.color, "color").and_then(|v| v.try_downcast_ref::<Color>().copied());
+    let text_color = get(&binding.text_color, "text color").and_then(|v| v.try_downcast_ref::<Color>().copied());
+    let visible = get(&binding.visible, "visibility").and_then(|v| v.try_downcast_ref::<bool>().copied());
+
+    // Apply values.
+    if let Some(text) = text {
+        match world.get_mut::<Text>(entity) {
+            Some(mut current) => {
+                if current.0 != text {
+                    current.0 = text;
+                }
+            }
+            None => {
+                world.entity_mut(entity).insert(Text(text));
+            }
+        }
+    }
+    if let Some(image) = image {
+        world.resource_scope(|world: &mut World, mut images: Mut<ImageMap>| {
+            let handle = images.get_or_load(&image, world.resource::<AssetServer>());
+            match world.get_mut::<ImageNode>(entity) {
+                Some(mut node) => {
+                    node.image = handle;
+                }
+                None => {
+                    world.entity_mut(entity).insert(ImageNode::new(handle));
+                }
+            }
+        });
+    }
+    if let Some(color) = color {
+        world.entity_mut(entity).insert(BackgroundColor(color));
+    }
+    if let Some(color) = text_color {
+        world.entity_mut(entity).insert(TextColor(color));
+    }
+    if let Some(visible) = visible {
+        if let Some(mut node) = world.get_mut::<Node>(entity) {
+            match (visible, node.display) {
+                (false, display) if display != Display::None => node.display = Display::None,
+                (true, Display::None) => node.display = Display::Flex,
+                _ => (),
+            }
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn spawn_data_list_items(
+    In((entity, scene, items)): In<(Entity, SceneRef, Vec<TemplateData>)>,
+    mut c: Commands,
+    mut s: SceneBuilder,
+) -> Vec<Entity>
+{
+    let mut spawned = Vec::with_capacity(items.len());
+    for item in items {
+        c.ui_builder(entity)
+            .spawn_scene_and_edit(scene.clone(), &mut s, |h| {
+                h.insert(item);
+                spawned.push(h.id());
+            });
+    }
+    spawned
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn apply_data_list(entity: Entity, world: &mut World)
+{
+    let Some(list) = world.get::<DataList>(entity) else { return };
+    let Some(data_entity) = find_template_data(world, entity) else {
+        tracing::warn!("failed applying DataList to {entity:?}; no TemplateData found on the entity or its \
+            ancestors");
+        return;
+    };
+    let data = world.get::<TemplateData>(data_entity).unwrap();
+
+    // Extract items.
+    let Some(value) = data.get_field(&list.field) else {
+        tracing::warn!("failed applying DataList to {entity:?} from TemplateData on {data_entity:?}; field {:?} \
+            not found in {:?}", list.field, data.get().reflect_type_path());
+        return;
+    };
+    let items: Vec<Box<dyn PartialReflect>> = match value.reflect_ref() {
+        ReflectRef::List(list) => list.iter().map(|i| i.clone_value()).collect(),
+        ReflectRef::Array(array) => array.iter().map(|i| i.clone_value()).collect(),
+        _ => {
+            tracing::warn!("failed applying DataList to {entity:?} from TemplateData on {data_entity:?}; field {:?} \
+                is not a list or array", list.field);
+            return;
+        }
+    };
+    let scene = SceneRef::new(&list.file, &list.scene);
+
+    // Skip rebuilding if nothing changed.
+    let computed = world.get::<ComputedDataList>(entity);
+    if let Some(computed) = computed {
+        if computed.scene == scene
+            && computed.items.len() == items.len()
+            && computed
+                .items
+                .iter()
+                .zip(items.iter())
+                .all(|(a, b)| a.reflect_partial_eq(b.as_ref()).unwrap_or(false))
+        {
+            return;
+        }
+    }
+
+    // Clean up old items.
+    if let Some(mut computed) = world.entity_mut(entity).take::<ComputedDataList>() {
+        for spawned in computed.spawned.drain(..) {
+            if let Ok(emut) = world.get_entity_mut(spawned) {
+                emut.despawn_recursive();
+            }
+        }
+    }
+
+    // Spawn new items.
+    let prev_items = items.iter().map(|i| i.clone_value()).collect();
+    let spawned = world.syscall(
+        (entity, scene.clone(), items.into_iter().map(TemplateData::from_boxed).collect()),
+        spawn_data_list_items,
+    );
+
+    // Items are bound immediately so there is no frame where they are displayed without data.
+    for item in spawned.iter() {
+        refresh_template_bindings(*item, world);
+    }
+
+    world
+        .entity_mut(entity)
+        .insert(ComputedDataList { scene, items: prev_items, spawned });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Re-applies all bindings that read from the [`TemplateData`] on `data_entity`.
+fn refresh_template_bindings(data_entity: Entity, world: &mut World)
+{
+    let mut bindings = Vec::default();
+    let mut lists = Vec::default();
+    iter_descendants_filtered(
+        world,
+        data_entity,
+        // Don't look at descendants that have their own data.
+        |world, entity| entity == data_entity || world.get::<TemplateData>(entity).is_none(),
+        |world, entity| {
+            if world.get::<DataBinding>(entity).is_some() {
+                bindings.push(entity);
+            }
+            if world.get::<DataList>(entity).is_some() {
+                lists.push(entity);
+            }
+        },
+    );
+
+    for entity in bindings {
+        apply_data_binding(entity, world);
+    }
+    for entity in lists {
+        apply_data_list(entity, world);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn refresh_template_data(
+    mut c: Commands,
+    changed_data: Query<Entity, Changed<TemplateData>>,
+    changed_bindings: Query<Entity, Changed<DataBinding>>,
+    changed_lists: Query<Entity, Changed<DataList>>,
+)
+{
+    for entity in changed_data.iter() {
+        c.queue(move |world: &mut World| refresh_template_bindings(entity, world));
+    }
+    for entity in changed_bindings.iter() {
+        c.queue(move |world: &mut World| apply_data_binding(entity, world));
+    }
+    for entity in changed_lists.iter() {
+        c.queue(move |world: &mut World| apply_data_list(entity, world));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Component)]
+struct ComputedDataList
+{
+    scene: SceneRef,
+    items: Vec<Box<dyn PartialReflect>>,
+    spawned: Vec<Entity>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Component with a reflected data payload that can be displayed by [`DataBinding`] and [`DataList`] nodes.
+///
+/// Bindings read from the `TemplateData` on their own entity or the nearest ancestor that has one. Bindings
+/// refresh whenever the `TemplateData` is inserted or mutated.
+///
+/// This is useful for tooltips and 'item cards', where a single scene can display many different items. Use
+/// [`TooltipData`](crate::builtin::widgets::tooltip::TooltipData) to display data in tooltips.
+///
+/// ```rust
+/// #[derive(Reflect)]
+/// struct ItemStat
+/// {
+///     name: String,
+///     value: i32,
+/// }
+///
+/// #[derive(Reflect)]
+/// struct ItemCard
+/// {
+///     title: String,
+///     icon: String,
+///     rarity_color: Color,
+///     stats: Vec<ItemStat>,
+/// }
+///
+/// c.ui_root().spawn_scene_and_edit(("items.cob", "item_card"), &mut s, |h| {
+///     h.insert(TemplateData::new(ItemCard { ... }));
+/// });
+/// ```
+///
+/// With the scenes:
+/// ```rust
+/// // items.cob
+/// #scenes
+/// "item_card"
+///     FlexNode{flex_direction:Column}
+///     "icon"
+///         DataBinding{image:"icon"}
+///     "title"
+///         TextLine
+///         DataBinding{text:"title" text_color:"rarity_color"}
+///     "stats"
+///         FlexNode{flex_direction:Column}
+///         DataList{field:"stats" file:"items.cob" scene:"stat_row"}
+///
+/// "stat_row"
+///     TextLine
+///     DataBinding{text:"value" format:"+{} Strength"}
+/// ```
+#[derive(Component)]
+pub struct TemplateData
+{
+    data: Box<dyn PartialReflect>,
+}
+
+impl TemplateData
+{
+    /// Makes new template data from a reflected value.
+    pub fn new(data: impl PartialReflect) -> Self
+    {
+        Self { data: Box::new(data) }
+    }
+
+    /// Makes new template data from a boxed reflected value.
+    pub fn from_boxed(data: Box<dyn PartialReflect>) -> Self
+    {
+        Self { data }
+    }
+
+    /// Gets the data payload.
+    pub fn get(&self) -> &dyn PartialReflect
+    {
+        self.data.as_ref()
+    }
+
+    /// Gets a field in the data payload.
+    ///
+    /// The field is a reflection path, such as `stats[0].name`. An empty field returns the entire payload.
+    pub fn get_field(&self, field: &str) -> Option<&dyn PartialReflect>
+    {
+        if field.is_empty() {
+            return Some(self.get());
+        }
+        ParsedPath::parse(field)
+            .ok()?
+            .reflect_element(self.data.as_ref())
+            .ok()
+    }
+
+    /// Replaces the data payload.
+    pub fn set(&mut self, data: impl PartialReflect)
+    {
+        self.data = Box::new(data);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction loadable for displaying fields of a [`TemplateData`] payload on a node.
+///
+/// Each binding is a reflection path into the payload (e.g. `title` or `stats[0].value`). An empty path binds to
+/// the entire payload.
+///
+/// Bindings that are not set are ignored.
+#[derive(Reflect, Component, Default, Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct DataBinding
+{
+    /// Field to display as the node's [`Text`].
+    ///
+    /// Strings and primitive values are displayed directly. Other values are displayed with their reflected
+    /// debug representation.
+    #[reflect(default)]
+    pub text: Option<String>,
+    /// Format string for [`Self::text`]. The first `{}` is replaced with the field value.
+    #[reflect(default)]
+    pub format: Option<String>,
+    /// Field with an image path to display in the node's [`ImageNode`].
+    ///
+    /// Images are loaded with [`ImageMap::get_or_load`].
+    #[reflect(default)]
+    pub image: Option<String>,
+    /// [`Color`] field to use as the node's [`BackgroundColor`].
+    #[reflect(default)]
+    pub color: Option<String>,
+    /// [`Color`] field to use as the node's [`TextColor`].
+    #[reflect(default)]
+    pub text_color: Option<String>,
+    /// `bool` field that controls if the node is displayed.
+    ///
+    /// If the field is `false` then the node will be set to [`Display::None`].
+    #[reflect(default)]
+    pub visible: Option<String>,
+}
+
+impl Instruction for DataBinding
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        emut.insert(self);
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.remove::<Self>();
+        });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction loadable for spawning a scene for each entry in a list field of a [`TemplateData`] payload.
+///
+/// Each spawned scene gets a [`TemplateData`] with its list entry, so [`DataBindings`](DataBinding) in the spawned
+/// scene will read from the entry.
+///
+/// Scenes are spawned as children of this node. The node's other children will be placed before list entries,
+/// so it is recommended to use a dedicated container node.
+#[derive(Reflect, Component, Default, Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct DataList
+{
+    /// List or array field in the payload.
+    pub field: String,
+    /// File of the scene to spawn for each entry.
+    pub file: String,
+    /// Scene to spawn for each entry.
+    pub scene: String,
+}
+
+impl Instruction for DataList
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        validate_instruction_scene_ref::<Self>(world, SceneRef::new(&self.file, &self.scene));
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        emut.insert(self);
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        emut.remove::<Self>();
+        let Some(computed) = emut.take::<ComputedDataList>() else { return };
+        for spawned in computed.spawned {
+            if let Ok(emut) = world.get_entity_mut(spawned) {
+                emut.despawn_recursive();
+            }
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// System set in `PostUpdate` where [`TemplateData`] bindings are refreshed.
+#[derive(SystemSet, Debug, Hash, Eq, PartialEq, Copy, Clone)]
+pub struct TemplateDataUpdateSet;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct CobwebDataTemplatePlugin;
+
+impl Plugin for CobwebDataTemplatePlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.register_instruction_type::<DataBinding>()
+            .register_instruction_type::<DataList>()
+            .configure_sets(
+                PostUpdate,
+                TemplateDataUpdateSet
+                    .after(FileProcessingSet)
+                    .before(UiSystem::Prepare),
+            )
+            .add_systems(PostUpdate, refresh_template_data.in_set(TemplateDataUpdateSet));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
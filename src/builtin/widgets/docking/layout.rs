@@ -0,0 +1,563 @@
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistry;
+use bevy_cobweb::prelude::*;
+use smol_str::SmolStr;
+
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// The axis of a [`DockNode::Split`].
+#[derive(Reflect, Default, Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum DockAxis
+{
+    /// Entries are laid out left-to-right.
+    #[default]
+    Horizontal,
+    /// Entries are laid out top-to-bottom.
+    Vertical,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// The region of a tab stack where a dragged tab will be dropped.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum DockZone
+{
+    /// The tab will be added to the tab stack.
+    Center,
+    /// The tab stack will be split, with the tab placed to the left.
+    Left,
+    /// The tab stack will be split, with the tab placed to the right.
+    Right,
+    /// The tab stack will be split, with the tab placed on top.
+    Top,
+    /// The tab stack will be split, with the tab placed on the bottom.
+    Bottom,
+}
+
+impl DockZone
+{
+    /// Gets the zone for a normalized position within a tab stack, where `(0, 0)` is the top left corner.
+    pub fn from_normalized(pos: Vec2) -> Self
+    {
+        if (0.25..=0.75).contains(&pos.x) && (0.25..=0.75).contains(&pos.y) {
+            return Self::Center;
+        }
+
+        let candidates = [
+            (pos.x, Self::Left),
+            (1. - pos.x, Self::Right),
+            (pos.y, Self::Top),
+            (1. - pos.y, Self::Bottom),
+        ];
+        candidates
+            .into_iter()
+            .min_by(|(a, _), (b, _)| a.total_cmp(b))
+            .map(|(_, zone)| zone)
+            .unwrap_or(Self::Center)
+    }
+
+    /// Gets the split axis and whether the new entry should come first.
+    fn split_params(&self) -> Option<(DockAxis, bool)>
+    {
+        match self {
+            Self::Center => None,
+            Self::Left => Some((DockAxis::Horizontal, true)),
+            Self::Right => Some((DockAxis::Horizontal, false)),
+            Self::Top => Some((DockAxis::Vertical, true)),
+            Self::Bottom => Some((DockAxis::Vertical, false)),
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Identifies a tab stack in a [`DockLayout`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum DockTarget
+{
+    /// A tab stack in the docked tree. The path contains split entry indices starting from the root.
+    Docked(Vec<usize>),
+    /// The tab stack of a floating window.
+    Floating(usize),
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A stack of tabs in a [`DockLayout`]. Only the active tab's panel is displayed.
+#[derive(Reflect, Default, Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct DockTabs
+{
+    /// Ids of [`DockPanels`](DockPanel) in this stack.
+    #[reflect(default)]
+    pub tabs: Vec<SmolStr>,
+    /// Index of the active tab.
+    #[reflect(default)]
+    pub active: usize,
+}
+
+impl DockTabs
+{
+    /// Makes a new tab stack with one tab.
+    pub fn new(id: impl Into<SmolStr>) -> Self
+    {
+        Self { tabs: vec![id.into()], active: 0 }
+    }
+
+    /// Gets the id of the active tab.
+    pub fn active_tab(&self) -> Option<&SmolStr>
+    {
+        self.tabs.get(self.active)
+    }
+
+    /// Adds a tab to the end of the stack and makes it active.
+    pub fn push(&mut self, id: SmolStr)
+    {
+        self.tabs.push(id);
+        self.active = self.tabs.len() - 1;
+    }
+
+    fn contains(&self, id: &str) -> bool
+    {
+        self.tabs.iter().any(|t| t == id)
+    }
+
+    fn remove(&mut self, id: &str) -> bool
+    {
+        let Some(pos) = self.tabs.iter().position(|t| t == id) else { return false };
+        self.tabs.remove(pos);
+        if self.active > pos || self.active >= self.tabs.len() {
+            self.active = self.active.saturating_sub(1);
+        }
+        true
+    }
+
+    fn set_active(&mut self, id: &str) -> bool
+    {
+        let Some(pos) = self.tabs.iter().position(|t| t == id) else { return false };
+        self.active = pos;
+        true
+    }
+
+    fn clamp_active(&mut self)
+    {
+        self.active = self.active.min(self.tabs.len().saturating_sub(1));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// An entry in a [`DockNode::Split`].
+#[derive(Reflect, Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct DockSplitEntry
+{
+    /// Share of the split's space given to this entry, relative to the other entries.
+    ///
+    /// Defaults to `1.0`.
+    #[reflect(default = "DockSplitEntry::default_weight")]
+    pub weight: f32,
+    pub node: DockNode,
+}
+
+impl DockSplitEntry
+{
+    fn default_weight() -> f32
+    {
+        1.
+    }
+
+    fn new(node: DockNode) -> Self
+    {
+        Self { weight: Self::default_weight(), node }
+    }
+}
+
+impl Default for DockSplitEntry
+{
+    fn default() -> Self
+    {
+        Self::new(DockNode::default())
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A node in the docked tree of a [`DockLayout`].
+#[derive(Reflect, Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+#[reflect(no_field_bounds)]
+pub enum DockNode
+{
+    Tabs(DockTabs),
+    Split
+    {
+        #[reflect(default)]
+        axis: DockAxis,
+        #[reflect(default)]
+        entries: Vec<DockSplitEntry>,
+    },
+}
+
+impl DockNode
+{
+    /// Gets a node by path.
+    pub fn get(&self, path: &[usize]) -> Option<&DockNode>
+    {
+        let Some((first, rest)) = path.split_first() else { return Some(self) };
+        let Self::Split { entries, .. } = self else { return None };
+        entries.get(*first)?.node.get(rest)
+    }
+
+    /// Gets a node mutably by path.
+    pub fn get_mut(&mut self, path: &[usize]) -> Option<&mut DockNode>
+    {
+        let Some((first, rest)) = path.split_first() else { return Some(self) };
+        let Self::Split { entries, .. } = self else { return None };
+        entries.get_mut(*first)?.node.get_mut(rest)
+    }
+
+    fn find_panel(&self, id: &str, path: &mut Vec<usize>) -> bool
+    {
+        match self {
+            Self::Tabs(tabs) => tabs.contains(id),
+            Self::Split { entries, .. } => {
+                for (idx, entry) in entries.iter().enumerate() {
+                    path.push(idx);
+                    if entry.node.find_panel(id, path) {
+                        return true;
+                    }
+                    path.pop();
+                }
+                false
+            }
+        }
+    }
+
+    /// Removes a panel without collapsing empty tab stacks, so existing paths remain valid.
+    fn remove_panel(&mut self, id: &str) -> bool
+    {
+        match self {
+            Self::Tabs(tabs) => tabs.remove(id),
+            Self::Split { entries, .. } => entries.iter_mut().any(|e| e.node.remove_panel(id)),
+        }
+    }
+
+    fn set_active(&mut self, id: &str) -> bool
+    {
+        match self {
+            Self::Tabs(tabs) => tabs.set_active(id),
+            Self::Split { entries, .. } => entries.iter_mut().any(|e| e.node.set_active(id)),
+        }
+    }
+
+    fn first_tabs_mut(&mut self) -> &mut DockTabs
+    {
+        if matches!(self, Self::Split { entries, .. } if entries.is_empty()) {
+            *self = Self::default();
+        }
+        match self {
+            Self::Tabs(tabs) => tabs,
+            Self::Split { entries, .. } => entries[0].node.first_tabs_mut(),
+        }
+    }
+
+    fn for_each_panel(&self, callback: &mut impl FnMut(&SmolStr))
+    {
+        match self {
+            Self::Tabs(tabs) => tabs.tabs.iter().for_each(callback),
+            Self::Split { entries, .. } => entries.iter().for_each(|e| e.node.for_each_panel(callback)),
+        }
+    }
+
+    /// Removes empty tab stacks, collapses splits with one entry, and merges nested splits that have the same
+    /// axis.
+    ///
+    /// Returns `true` if the node is empty.
+    fn normalize(&mut self) -> bool
+    {
+        match self {
+            Self::Tabs(tabs) => {
+                tabs.clamp_active();
+                tabs.tabs.is_empty()
+            }
+            Self::Split { axis, entries } => {
+                entries.retain_mut(|e| !e.node.normalize());
+
+                // Merge same-axis children into this split, scaling their weights to the parent entry's weight.
+                let mut merged = Vec::with_capacity(entries.len());
+                for entry in entries.drain(..) {
+                    match entry.node {
+                        Self::Split { axis: child_axis, entries: child_entries } if child_axis == *axis => {
+                            let total: f32 = child_entries.iter().map(|e| e.weight.max(0.)).sum();
+                            let count = child_entries.len() as f32;
+                            for mut child in child_entries {
+                                child.weight = match total > 0. {
+                                    true => entry.weight * child.weight.max(0.) / total,
+                                    false => entry.weight / count,
+                                };
+                                merged.push(child);
+                            }
+                        }
+                        node => merged.push(DockSplitEntry { weight: entry.weight, node }),
+                    }
+                }
+                *entries = merged;
+
+                match entries.len() {
+                    0 => {
+                        *self = Self::default();
+                        true
+                    }
+                    1 => {
+                        *self = entries.pop().unwrap().node;
+                        false
+                    }
+                    _ => false,
+                }
+            }
+        }
+    }
+}
+
+impl Default for DockNode
+{
+    fn default() -> Self
+    {
+        Self::Tabs(DockTabs::default())
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A window in a [`DockLayout`] that floats above the docked tree.
+#[derive(Reflect, Default, Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct FloatingDock
+{
+    pub tabs: DockTabs,
+    /// Offset of the window's top-left corner from the top-left corner of the [`DockArea`], in logical pixels.
+    #[reflect(default)]
+    pub position: Vec2,
+    /// Size of the window in logical pixels.
+    #[reflect(default)]
+    pub size: Vec2,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reactive component with the layout of a [`DockArea`].
+///
+/// The layout is plain reflected data, so it can be written directly in COB files as the [`DockArea::layout`]
+/// field. Use [`Self::to_cob`] to persist a layout that was modified at runtime.
+///
+/// Changes to this component will cause the dock area to be rebuilt. You can listen to changes with
+/// `entity_mutation::<DockLayout>(area_entity)`.
+#[derive(ReactComponent, Reflect, Default, Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct DockLayout
+{
+    /// The docked tree.
+    #[reflect(default)]
+    pub root: DockNode,
+    /// Floating windows, in back-to-front order.
+    #[reflect(default)]
+    pub floating: Vec<FloatingDock>,
+}
+
+impl DockLayout
+{
+    /// Finds the tab stack that contains a panel.
+    pub fn find_panel(&self, id: &str) -> Option<DockTarget>
+    {
+        let mut path = Vec::default();
+        if self.root.find_panel(id, &mut path) {
+            return Some(DockTarget::Docked(path));
+        }
+        self.floating
+            .iter()
+            .position(|f| f.tabs.contains(id))
+            .map(DockTarget::Floating)
+    }
+
+    /// Returns `true` if the layout contains the panel.
+    pub fn contains(&self, id: &str) -> bool
+    {
+        self.find_panel(id).is_some()
+    }
+
+    /// Gets the tab stack at a target.
+    pub fn get_tabs(&self, target: &DockTarget) -> Option<&DockTabs>
+    {
+        match target {
+            DockTarget::Docked(path) => match self.root.get(path)? {
+                DockNode::Tabs(tabs) => Some(tabs),
+                DockNode::Split { .. } => None,
+            },
+            DockTarget::Floating(idx) => self.floating.get(*idx).map(|f| &f.tabs),
+        }
+    }
+
+    fn get_tabs_mut(&mut self, target: &DockTarget) -> Option<&mut DockTabs>
+    {
+        match target {
+            DockTarget::Docked(path) => match self.root.get_mut(path)? {
+                DockNode::Tabs(tabs) => Some(tabs),
+                DockNode::Split { .. } => None,
+            },
+            DockTarget::Floating(idx) => self.floating.get_mut(*idx).map(|f| &mut f.tabs),
+        }
+    }
+
+    /// Iterates all panel ids in the layout.
+    pub fn panels(&self) -> Vec<SmolStr>
+    {
+        let mut panels = Vec::default();
+        self.root.for_each_panel(&mut |p| panels.push(p.clone()));
+        self.floating
+            .iter()
+            .for_each(|f| panels.extend(f.tabs.tabs.iter().cloned()));
+        panels
+    }
+
+    /// Makes a panel the active tab of its tab stack.
+    ///
+    /// Returns `false` if the panel is not in the layout.
+    pub fn set_active(&mut self, id: &str) -> bool
+    {
+        self.root.set_active(id) || self.floating.iter_mut().any(|f| f.tabs.set_active(id))
+    }
+
+    /// Adds a panel to the first docked tab stack if it isn't already in the layout.
+    pub fn insert_missing(&mut self, id: impl Into<SmolStr>)
+    {
+        let id = id.into();
+        if self.contains(&id) {
+            return;
+        }
+        let tabs = self.root.first_tabs_mut();
+        tabs.tabs.push(id);
+        tabs.clamp_active();
+    }
+
+    /// Removes a panel from the layout.
+    ///
+    /// Empty tab stacks and floating windows are removed.
+    pub fn remove_panel(&mut self, id: &str) -> bool
+    {
+        let removed = self.remove_panel_in_place(id);
+        self.normalize();
+        removed
+    }
+
+    /// Moves a panel into a target tab stack.
+    ///
+    /// If the zone is not [`DockZone::Center`], then the target will be split and the panel will be inserted in a
+    /// new tab stack. Floating windows can't be split, so all zones are treated as `Center` for them.
+    ///
+    /// Returns `false` if the target doesn't exist or the move would have no effect.
+    pub fn dock_panel(&mut self, id: impl Into<SmolStr>, target: &DockTarget, mut zone: DockZone) -> bool
+    {
+        let id = id.into();
+        if matches!(target, DockTarget::Floating(_)) {
+            zone = DockZone::Center;
+        }
+        let Some(target_tabs) = self.get_tabs(target) else { return false };
+
+        // Check for no-ops.
+        if target_tabs.contains(&id) && (zone == DockZone::Center || target_tabs.tabs.len() == 1) {
+            return self.set_active(&id);
+        }
+
+        // Remove the panel without collapsing anything so the target path stays valid.
+        self.remove_panel_in_place(&id);
+
+        match zone.split_params() {
+            None => {
+                // Safe unwrap: the target was validated above and only its tab list could have changed.
+                self.get_tabs_mut(target).unwrap().push(id);
+            }
+            Some((axis, new_first)) => {
+                let DockTarget::Docked(path) = target else { unreachable!() };
+                let Some(node) = self.root.get_mut(path) else { return false };
+                let prev = std::mem::take(node);
+                let new = DockNode::Tabs(DockTabs::new(id));
+                let entries = match new_first {
+                    true => vec![DockSplitEntry::new(new), DockSplitEntry::new(prev)],
+                    false => vec![DockSplitEntry::new(prev), DockSplitEntry::new(new)],
+                };
+                *node = DockNode::Split { axis, entries };
+            }
+        }
+
+        self.normalize();
+        true
+    }
+
+    /// Moves a panel into a new floating window.
+    ///
+    /// The position is relative to the top-left corner of the [`DockArea`].
+    pub fn float_panel(&mut self, id: impl Into<SmolStr>, position: Vec2, size: Vec2)
+    {
+        let id = id.into();
+        self.remove_panel_in_place(&id);
+        self.floating
+            .push(FloatingDock { tabs: DockTabs::new(id), position, size });
+        self.normalize();
+    }
+
+    /// Serializes the layout as COB data.
+    ///
+    /// The output can be pasted as the value of [`DockArea::layout`] in a COB file.
+    pub fn to_cob(&self, registry: &TypeRegistry) -> CobResult<String>
+    {
+        let loadable = CobLoadable::extract_reflect(self, registry)?;
+        let mut buff = Vec::<u8>::default();
+        let mut serializer = DefaultRawSerializer::new(&mut buff);
+        loadable.write_to(&mut serializer)?;
+        Ok(String::from_utf8_lossy(&buff).trim().into())
+    }
+
+    fn remove_panel_in_place(&mut self, id: &str) -> bool
+    {
+        self.root.remove_panel(id) || self.floating.iter_mut().any(|f| f.tabs.remove(id))
+    }
+
+    fn normalize(&mut self)
+    {
+        self.root.normalize();
+        self.floating.retain_mut(|f| {
+            f.tabs.clamp_active();
+            !f.tabs.tabs.is_empty()
+        });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,5 @@
+mod layout;
+mod widget;
+
+pub use layout::*;
+pub use widget::*;
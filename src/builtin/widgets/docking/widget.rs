@@ -0,0 +1,980 @@
+use bevy::ecs::system::EntityCommand;
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+use bevy::utils::HashMap;
+use bevy_cobweb::prelude::*;
+use smol_str::SmolStr;
+
+use super::*;
+use crate::builtin::widgets::slider::get_camera_scale_factor;
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn get_rect(transform: &GlobalTransform, node: &ComputedNode) -> Rect
+{
+    Rect::from_center_size(transform.translation().truncate(), node.size())
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Converts a logical pointer distance to UI pixels in the area's coordinate space.
+fn pointer_to_ui_scale(
+    ui_camera: &DefaultUiCamera,
+    cameras: &Query<&Camera>,
+    maybe_camera: Option<&TargetCamera>,
+    area_node: &ComputedNode,
+) -> Option<f32>
+{
+    let camera_scale = get_camera_scale_factor(ui_camera, cameras, maybe_camera)?;
+    Some(camera_scale * area_node.inverse_scale_factor())
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn dock_tab_clicked(
+    mut event: Trigger<Pointer<Click>>,
+    mut c: Commands,
+    headers: Query<&DockTabHeader>,
+    mut layouts: ReactiveMut<DockLayout>,
+)
+{
+    event.propagate(false);
+    if event.button != PointerButton::Primary {
+        return;
+    }
+    let Ok(header) = headers.get(event.entity()) else { return };
+    let Ok(layout) = layouts.get_noreact(header.area) else { return };
+    let Some(target) = layout.find_panel(&header.panel) else { return };
+    if layout.get_tabs(&target).and_then(|t| t.active_tab()) == Some(&header.panel) {
+        return;
+    }
+    let _ = layouts
+        .get_mut(&mut c, header.area)
+        .map(|l| l.set_active(&header.panel));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn dock_tab_drag_start(
+    mut event: Trigger<Pointer<DragStart>>,
+    headers: Query<&DockTabHeader>,
+    mut areas: Query<&mut ComputedDockArea>,
+)
+{
+    event.propagate(false);
+    if event.button != PointerButton::Primary {
+        return;
+    }
+    let Ok(header) = headers.get(event.entity()) else { return };
+    let Ok(mut computed) = areas.get_mut(header.area) else { return };
+    computed.drag = Some(DockDrag { panel: header.panel.clone(), hovered: None });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn dock_tab_drag(
+    mut event: Trigger<Pointer<Drag>>,
+    cameras: Query<&Camera>,
+    ui_camera: DefaultUiCamera,
+    headers: Query<&DockTabHeader>,
+    mut areas: Query<(
+        &mut ComputedDockArea,
+        &GlobalTransform,
+        &ComputedNode,
+        Option<&TargetCamera>,
+    )>,
+    stacks: Query<(&GlobalTransform, &ComputedNode), Without<ComputedDockArea>>,
+    mut nodes: Query<&mut Node, Without<ComputedDockArea>>,
+)
+{
+    event.propagate(false);
+    let Ok(header) = headers.get(event.entity()) else { return };
+    let Ok((mut computed, area_transform, area_node, maybe_camera)) = areas.get_mut(header.area) else { return };
+    let Some(camera_scale) = get_camera_scale_factor(&ui_camera, &cameras, maybe_camera) else { return };
+    let computed = &mut *computed;
+    let Some(drag) = &mut computed.drag else { return };
+
+    // Find the tab stack under the pointer. Stacks in floating windows are checked first since they are on top.
+    let pointer_physical = event.pointer_location.position * camera_scale;
+    drag.hovered = None;
+    let mut preview_rect = None;
+    for (stack_entity, target) in computed.stacks.iter().rev() {
+        let Ok((transform, node)) = stacks.get(*stack_entity) else { continue };
+        let rect = get_rect(transform, node);
+        if !rect.contains(pointer_physical) || rect.is_empty() {
+            continue;
+        }
+        let mut zone = DockZone::from_normalized((pointer_physical - rect.min) / rect.size());
+        if matches!(target, DockTarget::Floating(_)) {
+            zone = DockZone::Center;
+        }
+        let half = rect.size() / 2.;
+        preview_rect = Some(match zone {
+            DockZone::Center => rect,
+            DockZone::Left => Rect::from_corners(rect.min, rect.max - Vec2::new(half.x, 0.)),
+            DockZone::Right => Rect::from_corners(rect.min + Vec2::new(half.x, 0.), rect.max),
+            DockZone::Top => Rect::from_corners(rect.min, rect.max - Vec2::new(0., half.y)),
+            DockZone::Bottom => Rect::from_corners(rect.min + Vec2::new(0., half.y), rect.max),
+        });
+        drag.hovered = Some((target.clone(), zone));
+        break;
+    }
+
+    // Update the preview.
+    let Some(preview) = computed.preview else { return };
+    let Ok(mut preview_node) = nodes.get_mut(preview) else { return };
+    let Some(preview_rect) = preview_rect else {
+        preview_node.display = Display::None;
+        return;
+    };
+    let area_min = get_rect(area_transform, area_node).min;
+    let scale = area_node.inverse_scale_factor();
+    preview_node.display = Display::Flex;
+    preview_node.left = Val::Px((preview_rect.min.x - area_min.x) * scale);
+    preview_node.top = Val::Px((preview_rect.min.y - area_min.y) * scale);
+    preview_node.width = Val::Px(preview_rect.width() * scale);
+    preview_node.height = Val::Px(preview_rect.height() * scale);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn dock_tab_drag_end(
+    mut event: Trigger<Pointer<DragEnd>>,
+    mut c: Commands,
+    cameras: Query<&Camera>,
+    ui_camera: DefaultUiCamera,
+    headers: Query<&DockTabHeader>,
+    mut areas: Query<(
+        &mut ComputedDockArea,
+        &GlobalTransform,
+        &ComputedNode,
+        Option<&TargetCamera>,
+    )>,
+    mut nodes: Query<&mut Node, Without<ComputedDockArea>>,
+    mut layouts: ReactiveMut<DockLayout>,
+)
+{
+    event.propagate(false);
+    let Ok(header) = headers.get(event.entity()) else { return };
+    let Ok((mut computed, area_transform, area_node, maybe_camera)) = areas.get_mut(header.area) else { return };
+    let Some(drag) = computed.drag.take() else { return };
+    if let Some(mut preview_node) = computed.preview.and_then(|p| nodes.get_mut(p).ok()) {
+        preview_node.display = Display::None;
+    }
+
+    // Dock into the hovered tab stack.
+    if let Some((target, zone)) = drag.hovered {
+        let Ok(layout) = layouts.get_noreact(header.area) else { return };
+        let mut new_layout = layout.clone();
+        if new_layout.dock_panel(drag.panel, &target, zone) {
+            layouts.set_if_neq(&mut c, header.area, new_layout);
+        }
+        return;
+    }
+
+    // Float the panel if it was dropped inside the dock area but not on a tab stack.
+    let Some(camera_scale) = get_camera_scale_factor(&ui_camera, &cameras, maybe_camera) else { return };
+    let pointer_physical = event.pointer_location.position * camera_scale;
+    let area_rect = get_rect(area_transform, area_node);
+    if !area_rect.contains(pointer_physical) {
+        return;
+    }
+    let scale = area_node.inverse_scale_factor();
+    let size = computed.style.floating_size;
+    let position = (pointer_physical - area_rect.min) * scale - Vec2::new(size.x / 2., 0.);
+    let _ = layouts
+        .get_mut(&mut c, header.area)
+        .map(|l| l.float_panel(drag.panel, position.max(Vec2::ZERO), size));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn dock_separator_drag(
+    mut event: Trigger<Pointer<Drag>>,
+    cameras: Query<&Camera>,
+    ui_camera: DefaultUiCamera,
+    separators: Query<&DockSeparator>,
+    areas: Query<(&ComputedNode, Option<&TargetCamera>), With<ComputedDockArea>>,
+    containers: Query<&ComputedNode, Without<ComputedDockArea>>,
+    mut nodes: Query<&mut Node>,
+)
+{
+    event.propagate(false);
+    let Ok(separator) = separators.get(event.entity()) else { return };
+    let Ok((area_node, maybe_camera)) = areas.get(separator.area) else { return };
+    let Some(scale) = pointer_to_ui_scale(&ui_camera, &cameras, maybe_camera, area_node) else { return };
+    let Ok(container) = containers.get(separator.container) else { return };
+
+    // Compute the weight delta from the pointer delta.
+    let (delta, length) = match separator.axis {
+        DockAxis::Horizontal => (event.delta.x * scale, container.size().x * area_node.inverse_scale_factor()),
+        DockAxis::Vertical => (event.delta.y * scale, container.size().y * area_node.inverse_scale_factor()),
+    };
+    if length <= 0. || separator.total_weight <= 0. {
+        return;
+    }
+    let weight_delta = delta * separator.total_weight / length;
+
+    let Ok([mut prev, mut next]) = nodes.get_many_mut([separator.prev, separator.next]) else { return };
+    let combined = prev.flex_grow + next.flex_grow;
+    let min = (separator.total_weight * 0.05).min(combined / 2.);
+    let new_prev = (prev.flex_grow + weight_delta).clamp(min, combined - min);
+    prev.flex_grow = new_prev;
+    next.flex_grow = combined - new_prev;
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn dock_separator_drag_end(
+    mut event: Trigger<Pointer<DragEnd>>,
+    mut c: Commands,
+    separators: Query<&DockSeparator>,
+    nodes: Query<&Node>,
+    mut layouts: ReactiveMut<DockLayout>,
+)
+{
+    event.propagate(false);
+    let Ok(separator) = separators.get(event.entity()) else { return };
+    let (Ok(prev), Ok(next)) = (nodes.get(separator.prev), nodes.get(separator.next)) else { return };
+    let Ok(layout) = layouts.get_mut(&mut c, separator.area) else { return };
+    let Some(DockNode::Split { entries, .. }) = layout.root.get_mut(&separator.path) else { return };
+    if separator.index + 1 >= entries.len() {
+        return;
+    }
+    entries[separator.index].weight = prev.flex_grow;
+    entries[separator.index + 1].weight = next.flex_grow;
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn dock_floating_drag(
+    mut event: Trigger<Pointer<Drag>>,
+    cameras: Query<&Camera>,
+    ui_camera: DefaultUiCamera,
+    bars: Query<&DockFloatingBar>,
+    areas: Query<(&ComputedNode, Option<&TargetCamera>), With<ComputedDockArea>>,
+    mut nodes: Query<&mut Node>,
+)
+{
+    event.propagate(false);
+    let Ok(bar) = bars.get(event.entity()) else { return };
+    let Ok((area_node, maybe_camera)) = areas.get(bar.area) else { return };
+    let Some(scale) = pointer_to_ui_scale(&ui_camera, &cameras, maybe_camera, area_node) else { return };
+    let Ok(mut window_node) = nodes.get_mut(bar.window) else { return };
+
+    let delta = event.delta * scale;
+    if let Val::Px(left) = &mut window_node.left {
+        *left += delta.x;
+    }
+    if let Val::Px(top) = &mut window_node.top {
+        *top += delta.y;
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn dock_floating_drag_end(
+    mut event: Trigger<Pointer<DragEnd>>,
+    mut c: Commands,
+    bars: Query<&DockFloatingBar>,
+    nodes: Query<&Node>,
+    mut layouts: ReactiveMut<DockLayout>,
+)
+{
+    event.propagate(false);
+    let Ok(bar) = bars.get(event.entity()) else { return };
+    let Ok(window_node) = nodes.get(bar.window) else { return };
+    let (Val::Px(left), Val::Px(top)) = (window_node.left, window_node.top) else { return };
+    let Ok(layout) = layouts.get_mut(&mut c, bar.area) else { return };
+    let Some(floating) = layout.floating.get_mut(bar.index) else { return };
+    floating.position = Vec2::new(left, top);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn add_missing_panel(In((area, id)): In<(Entity, SmolStr)>, mut c: Commands, mut layouts: ReactiveMut<DockLayout>)
+{
+    let Ok(layout) = layouts.get_noreact(area) else { return };
+    if layout.contains(&id) {
+        return;
+    }
+    let _ = layouts
+        .get_mut(&mut c, area)
+        .map(|l| l.insert_missing(id));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn refresh_dock_areas(mut c: Commands, areas: Query<(Entity, Ref<React<DockLayout>>, &ComputedDockArea)>)
+{
+    for (entity, layout, computed) in areas.iter() {
+        if !layout.is_changed() && !computed.dirty {
+            continue;
+        }
+        c.entity(entity).queue(RebuildDockArea);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+struct DockDrag
+{
+    panel: SmolStr,
+    hovered: Option<(DockTarget, DockZone)>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Tracks the entities of a dock area.
+#[derive(Component, Default)]
+struct ComputedDockArea
+{
+    style: DockStyle,
+    /// Registered panel entities.
+    panels: HashMap<SmolStr, Entity>,
+    /// Hidden node where panels are stored when not in the layout.
+    stash: Option<Entity>,
+    /// Top-level nodes spawned by the area (excluding the stash).
+    generated: Vec<Entity>,
+    /// Tab stack nodes and their locations in the layout.
+    stacks: Vec<(Entity, DockTarget)>,
+    preview: Option<Entity>,
+    drag: Option<DockDrag>,
+    /// Set when the area needs to be rebuilt even though the layout didn't change.
+    dirty: bool,
+}
+
+impl ComputedDockArea
+{
+    fn new(style: DockStyle) -> Self
+    {
+        Self { style, dirty: true, ..default() }
+    }
+
+    fn remove_panel_entity(&mut self, entity: Entity) -> bool
+    {
+        let prev_len = self.panels.len();
+        self.panels.retain(|_, e| *e != entity);
+        prev_len != self.panels.len()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Component on tab headers spawned by a [`DockArea`].
+#[derive(Component, Debug)]
+struct DockTabHeader
+{
+    area: Entity,
+    panel: SmolStr,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Component on separators between entries of a [`DockNode::Split`].
+#[derive(Component, Debug)]
+struct DockSeparator
+{
+    area: Entity,
+    container: Entity,
+    axis: DockAxis,
+    total_weight: f32,
+    prev: Entity,
+    next: Entity,
+    path: Vec<usize>,
+    index: usize,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Component on the tab bar of a floating window, used to drag the window.
+#[derive(Component, Debug)]
+struct DockFloatingBar
+{
+    area: Entity,
+    window: Entity,
+    index: usize,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+struct DockBuilder<'a>
+{
+    area: Entity,
+    style: &'a DockStyle,
+    computed: &'a mut ComputedDockArea,
+}
+
+impl DockBuilder<'_>
+{
+    fn spawn_child(&self, world: &mut World, parent: Entity, bundle: impl Bundle) -> Entity
+    {
+        world.spawn(bundle).set_parent(parent).id()
+    }
+
+    fn build_node(&mut self, world: &mut World, parent: Entity, node: &DockNode, path: &mut Vec<usize>, weight: f32)
+        -> Entity
+    {
+        match node {
+            DockNode::Tabs(tabs) => {
+                let stack = self.build_tabs(world, parent, tabs, DockTarget::Docked(path.clone()), None);
+                if let Some(mut stack_node) = world.get_mut::<Node>(stack) {
+                    stack_node.flex_grow = weight;
+                }
+                stack
+            }
+            DockNode::Split { axis, entries } => {
+                let flex_direction = match axis {
+                    DockAxis::Horizontal => FlexDirection::Row,
+                    DockAxis::Vertical => FlexDirection::Column,
+                };
+                let container = self.spawn_child(
+                    world,
+                    parent,
+                    Node {
+                        flex_direction,
+                        flex_grow: weight,
+                        flex_basis: Val::Px(0.),
+                        min_width: Val::Px(0.),
+                        min_height: Val::Px(0.),
+                        ..default()
+                    },
+                );
+
+                let total_weight: f32 = entries.iter().map(|e| e.weight.max(0.)).sum();
+                let mut prev_entity = None;
+                for (idx, entry) in entries.iter().enumerate() {
+                    path.push(idx);
+                    let separator = prev_entity.map(|_| {
+                        let size = Val::Px(self.style.separator_size);
+                        let (width, height) = match axis {
+                            DockAxis::Horizontal => (size, Val::Auto),
+                            DockAxis::Vertical => (Val::Auto, size),
+                        };
+                        self.spawn_child(
+                            world,
+                            container,
+                            (
+                                Node { width, height, flex_shrink: 0., ..default() },
+                                BackgroundColor(self.style.separator_color),
+                            ),
+                        )
+                    });
+                    let child = self.build_node(world, container, &entry.node, path, entry.weight.max(0.));
+                    path.pop();
+
+                    if let (Some(separator), Some(prev)) = (separator, prev_entity) {
+                        world
+                            .entity_mut(separator)
+                            .insert(DockSeparator {
+                                area: self.area,
+                                container,
+                                axis: *axis,
+                                total_weight,
+                                prev,
+                                next: child,
+                                path: path.clone(),
+                                index: idx - 1,
+                            })
+                            .observe(dock_separator_drag)
+                            .observe(dock_separator_drag_end);
+                    }
+                    prev_entity = Some(child);
+                }
+
+                container
+            }
+        }
+    }
+
+    fn build_tabs(
+        &mut self,
+        world: &mut World,
+        parent: Entity,
+        tabs: &DockTabs,
+        target: DockTarget,
+        floating: Option<(Entity, usize)>,
+    ) -> Entity
+    {
+        let style = self.style;
+        let stack = self.spawn_child(
+            world,
+            parent,
+            Node {
+                flex_direction: FlexDirection::Column,
+                flex_grow: 1.,
+                flex_basis: Val::Px(0.),
+                min_width: Val::Px(0.),
+                min_height: Val::Px(0.),
+                ..default()
+            },
+        );
+        let bar = self.spawn_child(
+            world,
+            stack,
+            (
+                Node {
+                    height: Val::Px(style.tab_bar_height),
+                    flex_direction: FlexDirection::Row,
+                    flex_shrink: 0.,
+                    align_items: AlignItems::Stretch,
+                    ..default()
+                },
+                BackgroundColor(style.tab_bar_color),
+            ),
+        );
+        if let Some((window, index)) = floating {
+            world
+                .entity_mut(bar)
+                .insert(DockFloatingBar { area: self.area, window, index })
+                .observe(dock_floating_drag)
+                .observe(dock_floating_drag_end);
+        }
+        let content = self.spawn_child(
+            world,
+            stack,
+            Node {
+                flex_grow: 1.,
+                flex_basis: Val::Px(0.),
+                min_width: Val::Px(0.),
+                min_height: Val::Px(0.),
+                ..default()
+            },
+        );
+
+        for (idx, id) in tabs.tabs.iter().enumerate() {
+            // Panels that haven't been spawned yet are skipped.
+            let Some(panel) = self.computed.panels.get(id).copied() else { continue };
+            let is_active = idx == tabs.active;
+
+            // Tab header.
+            let title = world
+                .get::<DockPanel>(panel)
+                .and_then(|p| p.title.clone())
+                .unwrap_or_else(|| String::from(id.as_str()));
+            let header_color = match is_active {
+                true => style.active_tab_color,
+                false => style.tab_color,
+            };
+            let header = self.spawn_child(
+                world,
+                bar,
+                (
+                    Node {
+                        padding: UiRect::horizontal(Val::Px(style.tab_padding)),
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(header_color),
+                    DockTabHeader { area: self.area, panel: id.clone() },
+                ),
+            );
+            world
+                .entity_mut(header)
+                .observe(dock_tab_clicked)
+                .observe(dock_tab_drag_start)
+                .observe(dock_tab_drag)
+                .observe(dock_tab_drag_end);
+            let text = self.spawn_child(world, header, (Node::default(), PickingBehavior::IGNORE));
+            TextLine { text: title, size: style.tab_text_size, ..default() }.apply(text, world);
+
+            // Panel slot.
+            let display = match is_active {
+                true => Display::Flex,
+                false => Display::None,
+            };
+            let slot = self.spawn_child(
+                world,
+                content,
+                Node {
+                    display,
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.),
+                    height: Val::Percent(100.),
+                    ..default()
+                },
+            );
+            world.entity_mut(panel).set_parent(slot);
+        }
+
+        self.computed.stacks.push((stack, target));
+        stack
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Rebuilds the nodes of a dock area from its current layout.
+struct RebuildDockArea;
+
+impl EntityCommand for RebuildDockArea
+{
+    fn apply(self, area: Entity, world: &mut World)
+    {
+        let Some(layout) = world.get::<React<DockLayout>>(area).map(|l| l.get().clone()) else { return };
+        let Some(mut computed) = world
+            .get_mut::<ComputedDockArea>(area)
+            .map(|mut c| std::mem::take(&mut *c))
+        else {
+            return;
+        };
+        computed.dirty = false;
+        computed.drag = None;
+        computed.stacks.clear();
+
+        // Move panels to the stash so they survive cleanup.
+        computed
+            .panels
+            .retain(|_, panel| world.get_entity(*panel).is_ok());
+        let stash = match computed.stash.filter(|s| world.get_entity(*s).is_ok()) {
+            Some(stash) => stash,
+            None => world
+                .spawn(Node { display: Display::None, ..default() })
+                .set_parent(area)
+                .id(),
+        };
+        computed.stash = Some(stash);
+        for panel in computed.panels.values() {
+            world.entity_mut(*panel).set_parent(stash);
+        }
+
+        // Clean up old nodes.
+        for generated in computed.generated.drain(..) {
+            if let Ok(emut) = world.get_entity_mut(generated) {
+                emut.despawn_recursive();
+            }
+        }
+
+        // Build docked tree.
+        let style = computed.style.clone();
+        let mut builder = DockBuilder { area, style: &style, computed: &mut computed };
+        let root = builder.spawn_child(
+            world,
+            area,
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                ..default()
+            },
+        );
+        builder.build_node(world, root, &layout.root, &mut Vec::default(), 1.);
+        let mut generated = vec![root];
+
+        // Build floating windows.
+        for (idx, floating) in layout.floating.iter().enumerate() {
+            let window = builder.spawn_child(
+                world,
+                area,
+                (
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Px(floating.position.x),
+                        top: Val::Px(floating.position.y),
+                        width: Val::Px(floating.size.x),
+                        height: Val::Px(floating.size.y),
+                        flex_direction: FlexDirection::Column,
+                        ..default()
+                    },
+                    BackgroundColor(style.floating_color),
+                ),
+            );
+            builder.build_tabs(world, window, &floating.tabs, DockTarget::Floating(idx), Some((window, idx)));
+            generated.push(window);
+        }
+
+        // Build preview node last so it's drawn on top.
+        let preview = builder.spawn_child(
+            world,
+            area,
+            (
+                Node {
+                    display: Display::None,
+                    position_type: PositionType::Absolute,
+                    ..default()
+                },
+                BackgroundColor(style.preview_color),
+                PickingBehavior::IGNORE,
+            ),
+        );
+        generated.push(preview);
+        computed.preview = Some(preview);
+        computed.generated = generated;
+
+        if let Some(mut c) = world.get_mut::<ComputedDockArea>(area) {
+            *c = computed;
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Visual settings for the nodes generated by a [`DockArea`].
+#[derive(Reflect, Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct DockStyle
+{
+    /// Defaults to `28.0`.
+    #[reflect(default = "DockStyle::default_tab_bar_height")]
+    pub tab_bar_height: f32,
+    /// Defaults to `16.0`.
+    #[reflect(default = "DockStyle::default_tab_text_size")]
+    pub tab_text_size: f32,
+    /// Horizontal padding inside tab headers. Defaults to `10.0`.
+    #[reflect(default = "DockStyle::default_tab_padding")]
+    pub tab_padding: f32,
+    /// Width of the draggable separators between split entries. Defaults to `4.0`.
+    #[reflect(default = "DockStyle::default_separator_size")]
+    pub separator_size: f32,
+    /// Size of new floating windows. Defaults to `(320.0, 240.0)`.
+    #[reflect(default = "DockStyle::default_floating_size")]
+    pub floating_size: Vec2,
+    #[reflect(default = "DockStyle::default_tab_bar_color")]
+    pub tab_bar_color: Color,
+    #[reflect(default = "DockStyle::default_tab_color")]
+    pub tab_color: Color,
+    #[reflect(default = "DockStyle::default_active_tab_color")]
+    pub active_tab_color: Color,
+    #[reflect(default = "DockStyle::default_separator_color")]
+    pub separator_color: Color,
+    /// Background of floating windows.
+    #[reflect(default = "DockStyle::default_floating_color")]
+    pub floating_color: Color,
+    /// Color of the drop preview shown while dragging a tab.
+    #[reflect(default = "DockStyle::default_preview_color")]
+    pub preview_color: Color,
+}
+
+impl DockStyle
+{
+    fn default_tab_bar_height() -> f32
+    {
+        28.
+    }
+
+    fn default_tab_text_size() -> f32
+    {
+        16.
+    }
+
+    fn default_tab_padding() -> f32
+    {
+        10.
+    }
+
+    fn default_separator_size() -> f32
+    {
+        4.
+    }
+
+    fn default_floating_size() -> Vec2
+    {
+        Vec2::new(320., 240.)
+    }
+
+    fn default_tab_bar_color() -> Color
+    {
+        Color::srgb_u8(30, 30, 34)
+    }
+
+    fn default_tab_color() -> Color
+    {
+        Color::srgb_u8(45, 45, 52)
+    }
+
+    fn default_active_tab_color() -> Color
+    {
+        Color::srgb_u8(70, 70, 82)
+    }
+
+    fn default_separator_color() -> Color
+    {
+        Color::srgb_u8(20, 20, 22)
+    }
+
+    fn default_floating_color() -> Color
+    {
+        Color::srgb_u8(38, 38, 44)
+    }
+
+    fn default_preview_color() -> Color
+    {
+        Color::srgba_u8(90, 140, 230, 90)
+    }
+}
+
+impl Default for DockStyle
+{
+    fn default() -> Self
+    {
+        Self {
+            tab_bar_height: Self::default_tab_bar_height(),
+            tab_text_size: Self::default_tab_text_size(),
+            tab_padding: Self::default_tab_padding(),
+            separator_size: Self::default_separator_size(),
+            floating_size: Self::default_floating_size(),
+            tab_bar_color: Self::default_tab_bar_color(),
+            tab_color: Self::default_tab_color(),
+            active_tab_color: Self::default_active_tab_color(),
+            separator_color: Self::default_separator_color(),
+            floating_color: Self::default_floating_color(),
+            preview_color: Self::default_preview_color(),
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction loadable for setting up a docking area for tool windows.
+///
+/// Inserts a [`DockLayout`] reactive component to the entity. The dock area generates nodes for splits, tab
+/// stacks, and floating windows as absolutely-positioned children of the entity, so the entity's size should be
+/// set independently (e.g. with a `FlexNode` that fills the window).
+///
+/// Tool windows are added with [`DockPanel`] on descendants of the area. Panels are re-parented into the generated
+/// tab stacks.
+///
+/// Tabs can be dragged onto other tab stacks to dock them. Dropping on the center of a stack adds the tab to
+/// that stack, and dropping near an edge splits the stack. Dropping a tab inside the area but outside all stacks
+/// moves it into a floating window. Separators between split entries can be dragged to resize them.
+#[derive(Reflect, Default, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DockArea
+{
+    /// The initial layout. Panels not mentioned in the layout will be added to the first tab stack.
+    #[reflect(default)]
+    pub layout: DockLayout,
+    #[reflect(default)]
+    pub style: DockStyle,
+}
+
+impl Instruction for DockArea
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+
+        let mut layout = self.layout;
+        if let Some(mut computed) = emut.get_mut::<ComputedDockArea>() {
+            computed.style = self.style;
+            computed.dirty = true;
+            computed.panels.keys().for_each(|id| layout.insert_missing(id.clone()));
+        } else {
+            emut.insert(ComputedDockArea::new(self.style));
+        }
+
+        world.react(|rc| rc.insert(entity, layout));
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        emut.remove::<React<DockLayout>>();
+        let Some(computed) = emut.take::<ComputedDockArea>() else { return };
+
+        // Return panels to the area so they aren't despawned.
+        for panel in computed.panels.values() {
+            if let Ok(mut panel) = world.get_entity_mut(*panel) {
+                panel.set_parent(entity);
+            }
+        }
+        for generated in computed.generated.into_iter().chain(computed.stash) {
+            if let Ok(emut) = world.get_entity_mut(generated) {
+                emut.despawn_recursive();
+            }
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction loadable for a tool window in a [`DockArea`].
+///
+/// Must be on a descendant of an entity with `DockArea`. The entity will be re-parented into the area's
+/// generated tab stacks, and only displayed when its tab is active.
+#[derive(Reflect, Component, Default, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct DockPanel
+{
+    /// Identifies the panel in [`DockLayout`].
+    pub id: SmolStr,
+    /// Text displayed in the panel's tab header. Defaults to the panel id.
+    #[reflect(default)]
+    pub title: Option<String>,
+}
+
+impl Instruction for DockPanel
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        let id = self.id.clone();
+        emut.insert(self);
+
+        let Some((area, computed)) = get_ancestor_mut::<ComputedDockArea>(world, entity) else {
+            tracing::warn!("failed adding DockPanel {entity:?} to dock area; no ancestor has DockArea");
+            return;
+        };
+        computed.remove_panel_entity(entity);
+        if let Some(prev) = computed.panels.insert(id.clone(), entity) {
+            tracing::warn!("overwriting dock panel {prev:?} with {entity:?}; panel ids should be unique in a \
+                dock area (id: {id:?})");
+        }
+        computed.dirty = true;
+
+        world.syscall((area, id), add_missing_panel);
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        emut.remove::<Self>();
+
+        // Move the panel to the stash so it won't be despawned when the area is rebuilt.
+        let Some((_, computed)) = get_ancestor_mut::<ComputedDockArea>(world, entity) else { return };
+        if !computed.remove_panel_entity(entity) {
+            return;
+        }
+        computed.dirty = true;
+        let Some(stash) = computed.stash else { return };
+        world.entity_mut(entity).set_parent(stash);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// System set in `PostUpdate` where [`DockAreas`](DockArea) are rebuilt after layout changes.
+#[derive(SystemSet, Debug, Hash, Eq, PartialEq, Copy, Clone)]
+pub struct DockUpdateSet;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct CobwebDockingPlugin;
+
+impl Plugin for CobwebDockingPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.register_type::<DockLayout>()
+            .register_instruction_type::<DockArea>()
+            .register_instruction_type::<DockPanel>()
+            .configure_sets(
+                PostUpdate,
+                DockUpdateSet
+                    .after(FileProcessingSet)
+                    .before(UiSystem::Prepare),
+            )
+            .add_systems(PostUpdate, refresh_dock_areas.in_set(DockUpdateSet));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
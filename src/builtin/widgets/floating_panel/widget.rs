@@ -0,0 +1,744 @@
+use bevy::ecs::query::{QueryData, QueryFilter};
+use bevy::ecs::system::EntityCommand;
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+use bevy_cobweb::prelude::*;
+
+use crate::builtin::widgets::slider::get_camera_scale_factor;
+use crate::prelude::*;
+use crate::sickle::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Finds the nearest entity (starting with `entity`) that matches a query.
+fn find_nearest<D: QueryData, F: QueryFilter>(
+    entity: Entity,
+    parents: &Query<&Parent>,
+    query: &Query<D, F>,
+) -> Option<Entity>
+{
+    std::iter::once(entity)
+        .chain(parents.iter_ancestors(entity))
+        .find(|e| query.contains(*e))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn get_rect(transform: &GlobalTransform, node: &ComputedNode) -> Rect
+{
+    Rect::from_center_size(transform.translation().truncate(), node.size())
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Converts a logical pointer distance to UI pixels in the panel's coordinate space.
+fn pointer_to_ui_scale(
+    ui_camera: &DefaultUiCamera,
+    cameras: &Query<&Camera>,
+    parents: &Query<&Parent>,
+    target_cameras: &Query<&TargetCamera>,
+    panel: Entity,
+    panel_node: &ComputedNode,
+) -> Option<f32>
+{
+    let root = parents.iter_ancestors(panel).last().unwrap_or(panel);
+    let camera_scale = get_camera_scale_factor(ui_camera, cameras, target_cameras.get(root).ok())?;
+    Some(camera_scale * panel_node.inverse_scale_factor())
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Replaces non-pixel position (and optionally size) values of a panel with its current layout values in logical
+/// pixels, so the panel can be moved and resized by drag deltas.
+fn normalize_panel_node(
+    panel: Entity,
+    resize: bool,
+    parents: &Query<&Parent>,
+    layouts: &Query<(&ComputedNode, &GlobalTransform)>,
+    node: &mut Node,
+)
+{
+    let Ok((computed, transform)) = layouts.get(panel) else { return };
+    let rect = get_rect(transform, computed);
+    let parent_min = parents
+        .get(panel)
+        .ok()
+        .and_then(|p| layouts.get(p.get()).ok())
+        .map(|(c, t)| get_rect(t, c).min)
+        .unwrap_or_default();
+    let offset = (rect.min - parent_min) * computed.inverse_scale_factor();
+    let size = rect.size() * computed.inverse_scale_factor();
+
+    if !matches!(node.left, Val::Px(_)) {
+        node.left = Val::Px(offset.x);
+        node.right = Val::Auto;
+    }
+    if !matches!(node.top, Val::Px(_)) {
+        node.top = Val::Px(offset.y);
+        node.bottom = Val::Auto;
+    }
+    if resize {
+        if !matches!(node.width, Val::Px(_)) {
+            node.width = Val::Px(size.x);
+        }
+        if !matches!(node.height, Val::Px(_)) {
+            node.height = Val::Px(size.y);
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resizes one axis of a panel.
+///
+/// `direction` is negative when dragging the leading edge, positive when dragging the trailing edge, and zero if
+/// the axis isn't affected.
+fn resize_axis(position: &mut f32, length: &mut f32, delta: f32, direction: f32, min: f32)
+{
+    if direction > 0. {
+        *length = (*length + delta).max(min);
+    } else if direction < 0. {
+        let new_length = (*length - delta).max(min);
+        *position += *length - new_length;
+        *length = new_length;
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn floating_panel_pressed(
+    event: Trigger<Pointer<Down>>,
+    mut manager: ResMut<FloatingPanelManager>,
+    parents: Query<&Parent>,
+    panels: Query<(), With<FloatingPanel>>,
+)
+{
+    let panel = event.entity();
+    if find_nearest(event.target, &parents, &panels) != Some(panel) {
+        return;
+    }
+    if manager.front() == Some(panel) {
+        return;
+    }
+    manager.bring_to_front(panel);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn floating_panel_close_clicked(
+    event: Trigger<Pointer<Click>>,
+    mut c: Commands,
+    ps: PseudoStateParam,
+    parents: Query<&Parent>,
+    buttons: Query<(), With<FloatingPanelCloseButton>>,
+    panels: Query<(), With<FloatingPanel>>,
+)
+{
+    if event.button != PointerButton::Primary {
+        return;
+    }
+    let panel = event.entity();
+    let Some(button) = find_nearest(event.target, &parents, &buttons) else { return };
+    if find_nearest(button, &parents, &panels) != Some(panel) {
+        return;
+    }
+    if ps.entity_has(button, PseudoState::Disabled) {
+        return;
+    }
+    c.entity(panel).queue(CloseFloatingPanel);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn floating_panel_drag_start(
+    event: Trigger<Pointer<DragStart>>,
+    parents: Query<&Parent>,
+    handles: Query<
+        Has<FloatingPanelResizeHandle>,
+        Or<(With<FloatingPanelTitleBar>, With<FloatingPanelResizeHandle>)>,
+    >,
+    layouts: Query<(&ComputedNode, &GlobalTransform)>,
+    mut panels: Query<&mut Node, With<FloatingPanel>>,
+)
+{
+    if event.button != PointerButton::Primary {
+        return;
+    }
+    let panel = event.entity();
+    let Some(handle) = find_nearest(event.target, &parents, &handles) else { return };
+    let Ok(resize) = handles.get(handle) else { return };
+    if find_nearest(handle, &parents, &panels) != Some(panel) {
+        return;
+    }
+    let Ok(mut node) = panels.get_mut(panel) else { return };
+    normalize_panel_node(panel, resize, &parents, &layouts, &mut node);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn floating_panel_drag(
+    event: Trigger<Pointer<Drag>>,
+    cameras: Query<&Camera>,
+    ui_camera: DefaultUiCamera,
+    parents: Query<&Parent>,
+    target_cameras: Query<&TargetCamera>,
+    handles: Query<
+        Option<&FloatingPanelResizeHandle>,
+        Or<(With<FloatingPanelTitleBar>, With<FloatingPanelResizeHandle>)>,
+    >,
+    mut panels: Query<(&FloatingPanel, &ComputedNode, &mut Node)>,
+)
+{
+    if event.button != PointerButton::Primary {
+        return;
+    }
+    let panel = event.entity();
+    let Some(handle) = find_nearest(event.target, &parents, &handles) else { return };
+    let Ok(maybe_resize) = handles.get(handle) else { return };
+    if find_nearest(handle, &parents, &panels) != Some(panel) {
+        return;
+    }
+    let Ok((config, computed, mut node)) = panels.get_mut(panel) else { return };
+    let Some(scale) = pointer_to_ui_scale(&ui_camera, &cameras, &parents, &target_cameras, panel, computed) else {
+        return;
+    };
+    let delta = event.delta * scale;
+
+    let node = &mut *node;
+    let Some(resize) = maybe_resize else {
+        // Title bars move the panel.
+        if let Val::Px(left) = &mut node.left {
+            *left += delta.x;
+        }
+        if let Val::Px(top) = &mut node.top {
+            *top += delta.y;
+        }
+        return;
+    };
+
+    let direction = resize.direction();
+    if let (Val::Px(left), Val::Px(width)) = (&mut node.left, &mut node.width) {
+        resize_axis(left, width, delta.x, direction.x, config.min_size.x);
+    }
+    if let (Val::Px(top), Val::Px(height)) = (&mut node.top, &mut node.height) {
+        resize_axis(top, height, delta.y, direction.y, config.min_size.y);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn update_floating_panel_z_order(
+    mut c: Commands,
+    mut manager: ResMut<FloatingPanelManager>,
+    mut removed: RemovedComponents<FloatingPanel>,
+    panels: Query<(), With<FloatingPanel>>,
+)
+{
+    for entity in removed.read() {
+        if panels.contains(entity) {
+            continue;
+        }
+        manager.remove(entity);
+    }
+
+    if !manager.is_changed() {
+        return;
+    }
+    for (idx, panel) in manager.order.iter().enumerate() {
+        let Some(mut ec) = c.get_entity(*panel) else { continue };
+        ec.try_insert(GlobalZIndex(manager.base_z_index + idx as i32));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Makes panels absolutely-positioned and keeps them inside their parents (or the camera viewport for root
+/// panels).
+///
+/// Uses sizes from the previous layout update.
+fn clamp_floating_panels(
+    ui_camera: DefaultUiCamera,
+    cameras: Query<&Camera>,
+    parents: Query<&Parent>,
+    target_cameras: Query<&TargetCamera>,
+    computed_nodes: Query<&ComputedNode>,
+    mut panels: Query<(Entity, &FloatingPanel, &ComputedNode, &mut Node)>,
+)
+{
+    for (entity, config, computed, mut node) in panels.iter_mut() {
+        if node.position_type != PositionType::Absolute {
+            node.position_type = PositionType::Absolute;
+        }
+        if !config.clamp || node.display == Display::None {
+            continue;
+        }
+        let size = computed.size() * computed.inverse_scale_factor();
+        if size == Vec2::ZERO {
+            continue;
+        }
+
+        let bounds = match parents.get(entity) {
+            Ok(parent) => {
+                let Ok(parent_node) = computed_nodes.get(parent.get()) else { continue };
+                parent_node.size() * parent_node.inverse_scale_factor()
+            }
+            Err(_) => {
+                let Some(camera_entity) = target_cameras
+                    .get(entity)
+                    .ok()
+                    .map(|t| t.entity())
+                    .or_else(|| ui_camera.get())
+                else {
+                    continue;
+                };
+                let Some(viewport) = cameras
+                    .get(camera_entity)
+                    .ok()
+                    .and_then(|c| c.logical_viewport_size())
+                else {
+                    continue;
+                };
+                viewport
+            }
+        };
+        let max = (bounds - size).max(Vec2::ZERO);
+
+        if let Val::Px(left) = node.left {
+            let clamped = left.clamp(0., max.x);
+            if clamped != left {
+                node.left = Val::Px(clamped);
+            }
+        }
+        if let Val::Px(top) = node.top {
+            let clamped = top.clamp(0., max.y);
+            if clamped != top {
+                node.top = Val::Px(clamped);
+            }
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Private state of a [`FloatingPanel`].
+#[derive(Component, Debug)]
+struct FloatingPanelState
+{
+    /// The panel's display mode before it was closed.
+    restore_display: Display,
+}
+
+impl Default for FloatingPanelState
+{
+    fn default() -> Self
+    {
+        Self { restore_display: Display::Flex }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Pointer observers of a [`FloatingPanel`].
+///
+/// Pointer events on the panel's title bars, resize handles, and close buttons bubble up to these observers.
+#[derive(Component, Debug)]
+struct FloatingPanelObservers([Entity; 4]);
+
+impl FloatingPanelObservers
+{
+    fn new(entity: Entity, world: &mut World) -> Self
+    {
+        Self([
+            world
+                .spawn(Observer::new(floating_panel_pressed).with_entity(entity))
+                .id(),
+            world
+                .spawn(Observer::new(floating_panel_close_clicked).with_entity(entity))
+                .id(),
+            world
+                .spawn(Observer::new(floating_panel_drag_start).with_entity(entity))
+                .id(),
+            world
+                .spawn(Observer::new(floating_panel_drag).with_entity(entity))
+                .id(),
+        ])
+    }
+
+    fn revoke(self, world: &mut World)
+    {
+        for observer in self.0 {
+            world.despawn(observer);
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction loadable for draggable and resizable floating panels (e.g. internal tool windows).
+///
+/// The panel node is made absolutely-positioned. Panels can be moved by dragging a [`FloatingPanelTitleBar`],
+/// resized by dragging [`FloatingPanelResizeHandles`](FloatingPanelResizeHandle), and closed by clicking a
+/// [`FloatingPanelCloseButton`]. These should be descendants of the panel.
+///
+/// Pressing anywhere in a panel brings it to the front. Panel stacking is managed by [`FloatingPanelManager`],
+/// which inserts [`GlobalZIndex`] on panels.
+///
+/// Positions and sizes set with non-pixel values are converted to pixels the first time the panel is dragged or
+/// resized.
+///
+/// Example:
+/**
+```rust
+#scenes
+"panel"
+    FloatingPanel{ min_size: (150.0 100.0) }
+    AbsoluteNode{ left: 50px top: 50px width: 300px height: 200px flex_direction: Column }
+    BackgroundColor(#303030)
+
+    "title_bar"
+        FloatingPanelTitleBar
+        FlexNode{ width: 100% justify_main: SpaceBetween }
+        BackgroundColor(#202020)
+
+        "text"
+            TextLine{ text: "Inspector" }
+
+        "close"
+            FloatingPanelCloseButton
+            FlexNode{ width: 20px height: 20px }
+            BackgroundColor(#a02020)
+
+    "content"
+        FlexNode{ flex_grow: 1 }
+
+    "resize"
+        FloatingPanelResizeHandle(BottomRight)
+        AbsoluteNode{ right: 0px bottom: 0px width: 10px height: 10px }
+```
+*/
+#[derive(Reflect, Component, Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct FloatingPanel
+{
+    /// Minimum size of the panel in logical pixels when resizing.
+    ///
+    /// Defaults to `(100.0, 50.0)`.
+    #[reflect(default = "FloatingPanel::default_min_size")]
+    pub min_size: Vec2,
+    /// Whether to keep the panel inside its parent node (or the camera viewport if the panel is a root node).
+    ///
+    /// Defaults to `true`.
+    #[reflect(default = "FloatingPanel::default_clamp")]
+    pub clamp: bool,
+}
+
+impl FloatingPanel
+{
+    fn default_min_size() -> Vec2
+    {
+        Vec2::new(100., 50.)
+    }
+
+    fn default_clamp() -> bool
+    {
+        true
+    }
+}
+
+impl Default for FloatingPanel
+{
+    fn default() -> Self
+    {
+        Self {
+            min_size: Self::default_min_size(),
+            clamp: Self::default_clamp(),
+        }
+    }
+}
+
+impl Instruction for FloatingPanel
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        emut.insert(self);
+        if !emut.contains::<FloatingPanelState>() {
+            emut.insert(FloatingPanelState::default());
+        }
+        if let Some(mut node) = emut.get_mut::<Node>() {
+            node.position_type = PositionType::Absolute;
+        }
+        if !emut.contains::<FloatingPanelObservers>() {
+            let observers = FloatingPanelObservers::new(entity, world);
+            world.entity_mut(entity).insert(observers);
+        }
+
+        let mut manager = world.resource_mut::<FloatingPanelManager>();
+        if manager.order().contains(&entity) {
+            return;
+        }
+        manager.bring_to_front(entity);
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        if let Ok(mut emut) = world.get_entity_mut(entity) {
+            emut.remove::<(Self, FloatingPanelState, GlobalZIndex)>();
+            if let Some(observers) = emut.take::<FloatingPanelObservers>() {
+                observers.revoke(world);
+            }
+        }
+        world.resource_mut::<FloatingPanelManager>().remove(entity);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Component loadable for the title bar of a [`FloatingPanel`]. Dragging the title bar moves the panel.
+#[derive(Reflect, Component, Default, Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct FloatingPanelTitleBar;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Component loadable for the close button of a [`FloatingPanel`].
+///
+/// Clicking the button applies [`CloseFloatingPanel`] to the panel. Buttons with [`PseudoState::Disabled`] are
+/// ignored.
+#[derive(Reflect, Component, Default, Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct FloatingPanelCloseButton;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Component loadable for resize handles of a [`FloatingPanel`].
+///
+/// The variant indicates which edge or corner of the panel is moved when dragging the handle.
+#[derive(Reflect, Component, Default, Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum FloatingPanelResizeHandle
+{
+    Top,
+    Bottom,
+    Left,
+    Right,
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    #[default]
+    BottomRight,
+}
+
+impl FloatingPanelResizeHandle
+{
+    /// Gets the resize direction on each axis (`-1` for the leading edge, `1` for the trailing edge, `0` for
+    /// none).
+    pub fn direction(&self) -> Vec2
+    {
+        match self {
+            Self::Top => Vec2::new(0., -1.),
+            Self::Bottom => Vec2::new(0., 1.),
+            Self::Left => Vec2::new(-1., 0.),
+            Self::Right => Vec2::new(1., 0.),
+            Self::TopLeft => Vec2::new(-1., -1.),
+            Self::TopRight => Vec2::new(1., -1.),
+            Self::BottomLeft => Vec2::new(-1., 1.),
+            Self::BottomRight => Vec2::new(1., 1.),
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource that tracks the stacking order of [`FloatingPanels`](FloatingPanel).
+///
+/// Panels are given [`GlobalZIndex`] values starting at [`Self::base_z_index`] in back-to-front order. New panels
+/// and pressed panels are moved to the front.
+#[derive(Resource, Debug)]
+pub struct FloatingPanelManager
+{
+    order: Vec<Entity>,
+    /// The [`GlobalZIndex`] of the back-most panel.
+    ///
+    /// Defaults to `50`.
+    pub base_z_index: i32,
+}
+
+impl FloatingPanelManager
+{
+    /// Moves a panel to the front, or adds it to the front if not tracked.
+    pub fn bring_to_front(&mut self, panel: Entity)
+    {
+        self.remove(panel);
+        self.order.push(panel);
+    }
+
+    /// Stops tracking a panel.
+    pub fn remove(&mut self, panel: Entity)
+    {
+        self.order.retain(|e| *e != panel);
+    }
+
+    /// Gets the front-most panel.
+    pub fn front(&self) -> Option<Entity>
+    {
+        self.order.last().copied()
+    }
+
+    /// Gets panels in back-to-front order.
+    pub fn order(&self) -> &[Entity]
+    {
+        &self.order
+    }
+}
+
+impl Default for FloatingPanelManager
+{
+    fn default() -> Self
+    {
+        Self { order: Vec::default(), base_z_index: 50 }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Entity command that closes a [`FloatingPanel`] by setting its display to [`Display::None`].
+///
+/// Emits a [`FloatingPanelClosed`] entity event on the panel if it was open.
+pub struct CloseFloatingPanel;
+
+impl EntityCommand for CloseFloatingPanel
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        if !emut.contains::<FloatingPanel>() {
+            return;
+        }
+        let Some(mut node) = emut.get_mut::<Node>() else { return };
+        if node.display == Display::None {
+            return;
+        }
+        let restore_display = node.display;
+        node.display = Display::None;
+        emut.insert(FloatingPanelState { restore_display });
+
+        world.react(|rc| rc.entity_event(entity, FloatingPanelClosed));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Entity command that re-opens a closed [`FloatingPanel`] and brings it to the front.
+pub struct OpenFloatingPanel;
+
+impl EntityCommand for OpenFloatingPanel
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        if !emut.contains::<FloatingPanel>() {
+            return;
+        }
+        let restore_display = emut
+            .get::<FloatingPanelState>()
+            .map(|s| s.restore_display)
+            .unwrap_or(Display::Flex);
+        if let Some(mut node) = emut.get_mut::<Node>() {
+            if node.display == Display::None {
+                node.display = restore_display;
+            }
+        }
+
+        world
+            .resource_mut::<FloatingPanelManager>()
+            .bring_to_front(entity);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Entity event emitted on a [`FloatingPanel`] when it is closed with [`CloseFloatingPanel`].
+#[derive(Debug, Copy, Clone)]
+pub struct FloatingPanelClosed;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Extension trait for reacting to [`FloatingPanel`] events.
+pub trait FloatingPanelWidgetExt
+{
+    /// Adds a callback for reacting to [`FloatingPanelClosed`] events on the current entity.
+    ///
+    /// Equivalent to:
+    /// ```rust
+    /// ui_builder.on_event::<FloatingPanelClosed>().r(callback)
+    /// ```
+    fn on_floating_panel_closed<R: CobwebResult, M>(
+        &mut self,
+        callback: impl IntoSystem<(), R, M> + Send + Sync + 'static,
+    ) -> &mut Self;
+}
+
+impl FloatingPanelWidgetExt for UiBuilder<'_, Entity>
+{
+    fn on_floating_panel_closed<R: CobwebResult, M>(
+        &mut self,
+        callback: impl IntoSystem<(), R, M> + Send + Sync + 'static,
+    ) -> &mut Self
+    {
+        self.on_event::<FloatingPanelClosed>().r(callback);
+        self
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// System set in `PostUpdate` where floating panels are clamped and stacked.
+#[derive(SystemSet, Debug, Hash, Eq, PartialEq, Copy, Clone)]
+pub struct FloatingPanelUpdateSet;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct CobwebFloatingPanelPlugin;
+
+impl Plugin for CobwebFloatingPanelPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_resource::<FloatingPanelManager>()
+            .register_instruction_type::<FloatingPanel>()
+            .register_component_type::<FloatingPanelTitleBar>()
+            .register_component_type::<FloatingPanelCloseButton>()
+            .register_component_type::<FloatingPanelResizeHandle>()
+            .configure_sets(
+                PostUpdate,
+                FloatingPanelUpdateSet
+                    .after(FileProcessingSet)
+                    .before(UiSystem::Prepare),
+            )
+            .add_systems(
+                PostUpdate,
+                (clamp_floating_panels, update_floating_panel_z_order)
+                    .chain()
+                    .in_set(FloatingPanelUpdateSet),
+            );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
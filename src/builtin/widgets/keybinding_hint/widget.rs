@@ -0,0 +1,365 @@
+use std::any::Any;
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+use bevy_cobweb::prelude::*;
+
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn spawn_keycaps(
+    In((entity, scene, count)): In<(Entity, SceneRef, usize)>,
+    mut c: Commands,
+    mut s: SceneBuilder,
+) -> Vec<Entity>
+{
+    let mut spawned = Vec::with_capacity(count);
+    for _ in 0..count {
+        c.ui_builder(entity)
+            .spawn_scene_and_edit(scene.clone(), &mut s, |h| {
+                spawned.push(h.id());
+            });
+    }
+    spawned
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Writes a key label to the [`KeybindingHintKey`] nodes of a keycap scene.
+fn write_keycap_label(world: &mut World, keycap: Entity, label: &str)
+{
+    let mut targets = Vec::default();
+    iter_descendants_filtered(
+        world,
+        keycap,
+        |_, _| true,
+        |world, entity| {
+            if world.get::<KeybindingHintKey>(entity).is_some() {
+                targets.push(entity);
+            }
+        },
+    );
+    if targets.is_empty() {
+        tracing::warn!("failed writing key label {label:?} to keycap {keycap:?}; the keycap scene has no \
+            KeybindingHintKey node");
+    }
+
+    for target in targets {
+        match world.get_mut::<Text>(target) {
+            Some(mut text) => {
+                if text.0 != label {
+                    text.0 = label.into();
+                }
+            }
+            None => {
+                world.entity_mut(target).insert(Text(label.into()));
+            }
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Spawns a keycap scene as a child of `entity` for each label.
+///
+/// Returns the spawned keycap entities.
+pub(crate) fn spawn_labeled_keycaps(world: &mut World, entity: Entity, scene: SceneRef, labels: &[String])
+    -> Vec<Entity>
+{
+    let spawned = world.syscall((entity, scene, labels.len()), spawn_keycaps);
+    for (keycap, label) in spawned.iter().zip(labels.iter()) {
+        write_keycap_label(world, *keycap, label);
+    }
+    spawned
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Despawns keycaps spawned by [`spawn_labeled_keycaps`].
+pub(crate) fn despawn_keycaps(world: &mut World, keycaps: Vec<Entity>)
+{
+    for keycap in keycaps {
+        if let Ok(emut) = world.get_entity_mut(keycap) {
+            emut.despawn_recursive();
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn apply_keybinding_hint(entity: Entity, world: &mut World)
+{
+    let Some(hint) = world.get::<KeybindingHint>(entity) else { return };
+    let keys = world
+        .resource::<Keymap>()
+        .get_keys(&hint.binding)
+        .unwrap_or_default();
+    let scene = SceneRef::new(&hint.file, &hint.scene);
+
+    // Skip rebuilding if nothing changed.
+    if let Some(computed) = world.get::<ComputedKeybindingHint>(entity) {
+        if computed.scene == scene && computed.keys == keys {
+            return;
+        }
+    }
+
+    // Clean up old keycaps.
+    if let Some(computed) = world.entity_mut(entity).take::<ComputedKeybindingHint>() {
+        despawn_keycaps(world, computed.spawned);
+    }
+
+    // Spawn new keycaps.
+    let spawned = spawn_labeled_keycaps(world, entity, scene.clone(), &keys);
+
+    world
+        .entity_mut(entity)
+        .insert(ComputedKeybindingHint { scene, keys, spawned });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn refresh_keybinding_hints(
+    mut c: Commands,
+    keymap: Res<Keymap>,
+    hints: Query<Entity, With<KeybindingHint>>,
+    changed_hints: Query<Entity, Changed<KeybindingHint>>,
+)
+{
+    let refresh = |c: &mut Commands, entity: Entity| {
+        c.queue(move |world: &mut World| apply_keybinding_hint(entity, world));
+    };
+
+    match keymap.is_changed() {
+        true => hints.iter().for_each(|e| refresh(&mut c, e)),
+        false => changed_hints.iter().for_each(|e| refresh(&mut c, e)),
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Component)]
+struct ComputedKeybindingHint
+{
+    scene: SceneRef,
+    keys: Vec<String>,
+    spawned: Vec<Entity>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Trait for types that provide the current keys of named input bindings to [`Keymap`].
+///
+/// Implement this to connect [`KeybindingHints`](KeybindingHint) to your input system.
+pub trait KeymapProvider: Any + Send + Sync + 'static
+{
+    /// Gets the labels of the keys in a binding (e.g. `["Ctrl", "S"]`).
+    ///
+    /// Returns `None` if the binding is unknown or not bound.
+    fn get_keys(&self, binding: &str) -> Option<Vec<String>>;
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A [`KeymapProvider`] that stores key labels in a table.
+///
+/// This is the default provider in [`Keymap`].
+#[derive(Default, Debug, Clone)]
+pub struct KeymapTable
+{
+    bindings: HashMap<String, Vec<String>>,
+}
+
+impl KeymapTable
+{
+    /// Sets the key labels of a binding.
+    pub fn set(&mut self, binding: impl Into<String>, keys: impl IntoIterator<Item = impl Into<String>>)
+    {
+        self.bindings
+            .insert(binding.into(), keys.into_iter().map(|k| k.into()).collect());
+    }
+
+    /// Sets the key labels of a binding.
+    pub fn with(mut self, binding: impl Into<String>, keys: impl IntoIterator<Item = impl Into<String>>) -> Self
+    {
+        self.set(binding, keys);
+        self
+    }
+
+    /// Removes a binding.
+    pub fn remove(&mut self, binding: &str)
+    {
+        self.bindings.remove(binding);
+    }
+}
+
+impl KeymapProvider for KeymapTable
+{
+    fn get_keys(&self, binding: &str) -> Option<Vec<String>>
+    {
+        self.bindings.get(binding).cloned()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource with the [`KeymapProvider`] used by [`KeybindingHints`](KeybindingHint).
+///
+/// All keybinding hints are refreshed when this resource is mutably accessed, so mutate it when bindings are
+/// remapped.
+///
+/// Defaults to an empty [`KeymapTable`].
+#[derive(Resource)]
+pub struct Keymap
+{
+    provider: Box<dyn KeymapProvider>,
+}
+
+impl Keymap
+{
+    /// Makes a new keymap with the given provider.
+    pub fn new(provider: impl KeymapProvider) -> Self
+    {
+        Self { provider: Box::new(provider) }
+    }
+
+    /// Replaces the keymap provider.
+    pub fn set_provider(&mut self, provider: impl KeymapProvider)
+    {
+        self.provider = Box::new(provider);
+    }
+
+    /// Gets a reference to the provider if it has type `T`.
+    pub fn provider<T: KeymapProvider>(&self) -> Option<&T>
+    {
+        (self.provider.as_ref() as &dyn Any).downcast_ref::<T>()
+    }
+
+    /// Gets a mutable reference to the provider if it has type `T`.
+    ///
+    /// For example, use `keymap.provider_mut::<KeymapTable>()` to remap bindings in the default provider.
+    pub fn provider_mut<T: KeymapProvider>(&mut self) -> Option<&mut T>
+    {
+        (self.provider.as_mut() as &mut dyn Any).downcast_mut::<T>()
+    }
+
+    /// Gets the labels of the keys in a binding.
+    pub fn get_keys(&self, binding: &str) -> Option<Vec<String>>
+    {
+        self.provider.get_keys(binding)
+    }
+}
+
+impl Default for Keymap
+{
+    fn default() -> Self
+    {
+        Self::new(KeymapTable::default())
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction loadable that displays the current keys of an input binding from [`Keymap`].
+///
+/// A keycap scene is spawned as a child of this node for each key in the binding, and the key's label is written
+/// to the [`KeybindingHintKey`] nodes in the keycap scene. Keycaps are respawned when the binding changes. Nothing
+/// is displayed if the binding is not bound.
+///
+/// The node's other children will be placed before the keycaps, so it is recommended to use a dedicated container
+/// node.
+///
+/// Example (COB):
+/**
+```rust
+#scenes
+"keycap"
+    FlexNode{padding:{left:4px right:4px} margin:{right:2px}}
+    BackgroundColor(#444444)
+    BorderRadius(4px)
+
+    "label"
+        KeybindingHintKey
+        TextLine{size:14}
+
+"save_hint"
+    FlexNode{flex_direction:Row}
+    KeybindingHint{binding:"save" file:"ui/hints.cob" scene:"keycap"}
+```
+*/
+#[derive(Reflect, Component, Default, Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct KeybindingHint
+{
+    /// The binding to display.
+    pub binding: String,
+    /// File of the keycap scene.
+    pub file: String,
+    /// Keycap scene spawned for each key.
+    pub scene: String,
+}
+
+impl Instruction for KeybindingHint
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        validate_instruction_scene_ref::<Self>(world, SceneRef::new(&self.file, &self.scene));
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        emut.insert(self);
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        emut.remove::<Self>();
+        let Some(computed) = emut.take::<ComputedKeybindingHint>() else { return };
+        despawn_keycaps(world, computed.spawned);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Loadable component for the node in a [`KeybindingHint`] keycap scene that displays the key label.
+///
+/// The label is written to the node's [`Text`].
+#[derive(Reflect, Component, Default, Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct KeybindingHintKey;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// System set in `PostUpdate` where [`KeybindingHints`](KeybindingHint) are refreshed.
+#[derive(SystemSet, Debug, Hash, Eq, PartialEq, Copy, Clone)]
+pub struct KeybindingHintUpdateSet;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct CobwebKeybindingHintPlugin;
+
+impl Plugin for CobwebKeybindingHintPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_resource::<Keymap>()
+            .register_instruction_type::<KeybindingHint>()
+            .register_component_type::<KeybindingHintKey>()
+            .configure_sets(
+                PostUpdate,
+                KeybindingHintUpdateSet
+                    .after(FileProcessingSet)
+                    .before(UiSystem::Prepare),
+            )
+            .add_systems(PostUpdate, refresh_keybinding_hints.in_set(KeybindingHintUpdateSet));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -2,11 +2,20 @@
 //!
 //! If the `widgets` feature is enabled, then built-in widgets will be automatically loaded and ready to use.
 
+pub mod binding_capture;
 pub mod checkbox;
+pub mod color_picker;
+pub mod context_menu;
+pub mod data_template;
+pub mod docking;
+pub mod floating_panel;
+pub mod keybinding_hint;
 pub mod radio_button;
 pub mod scroll;
 pub mod slider;
-//pub mod tooltip;
+pub mod stat_bar;
+pub mod text_input;
+pub mod tooltip;
 
 mod plugin;
 pub(crate) use plugin::*;
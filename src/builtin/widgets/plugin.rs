@@ -10,12 +10,20 @@ impl Plugin for BuiltinWidgetsPlugin
 {
     fn build(&self, app: &mut App)
     {
-        app.add_plugins(checkbox::CobwebCheckboxPlugin)
+        app.add_plugins(binding_capture::CobwebBindingCapturePlugin)
+            .add_plugins(checkbox::CobwebCheckboxPlugin)
+            .add_plugins(color_picker::CobwebColorPickerPlugin)
+            .add_plugins(context_menu::CobwebContextMenuPlugin)
+            .add_plugins(data_template::CobwebDataTemplatePlugin)
+            .add_plugins(docking::CobwebDockingPlugin)
+            .add_plugins(floating_panel::CobwebFloatingPanelPlugin)
+            .add_plugins(keybinding_hint::CobwebKeybindingHintPlugin)
             .add_plugins(radio_button::CobwebRadioButtonPlugin)
             .add_plugins(scroll::CobwebScrollPlugin)
             .add_plugins(slider::CobwebSliderPlugin)
-            //.add_plugins(slider::CobwebTooltipPlugin)
-            ;
+            .add_plugins(stat_bar::CobwebStatBarPlugin)
+            .add_plugins(text_input::CobwebTextInputPlugin)
+            .add_plugins(tooltip::CobwebTooltipPlugin);
     }
 }
 
@@ -63,7 +63,8 @@ impl RadioButtonHandlers
 ///
 /// Inserts an internal `RadioButtonManager` component to the entity.
 ///
-/// Individual buttons should use [`RadioButton`].
+/// Individual buttons should use [`RadioButton`]. See [`RadioGroupWidgetExt`] for groups where each button has a
+/// value.
 #[derive(Reflect, Default, PartialEq, Copy, Clone)]
 #[cfg_attr(
     feature = "serde",
@@ -110,10 +111,11 @@ impl Instruction for RadioButton
 {
     fn apply(self, entity: Entity, world: &mut World)
     {
-        let Ok(e) = world.get_entity(entity) else { return };
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        AccessibleRole::RadioButton.apply_default(&mut emut);
 
         // Check if there are already radio button handlers on this entity.
-        if e.contains::<RadioButtonHandlers>() {
+        if emut.contains::<RadioButtonHandlers>() {
             return;
         }
 
@@ -170,6 +172,7 @@ impl Instruction for RadioButton
 
         // Cleanup.
         let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        AccessibleRole::revert_default(&mut emut);
         let Some(handlers) = emut.take::<RadioButtonHandlers>() else { return };
         world.react(|rc| handlers.revoke(rc));
     }
@@ -177,6 +180,273 @@ impl Instruction for RadioButton
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Finds the nearest entity with a [`RadioGroupSelection<T>`], starting at the entity itself.
+fn find_radio_group<T: PartialEq + Clone + Send + Sync + 'static>(world: &World, entity: Entity)
+    -> Option<Entity>
+{
+    let mut current = entity;
+    loop {
+        if world
+            .get::<React<RadioGroupSelection<T>>>(current)
+            .is_some()
+        {
+            return Some(current);
+        }
+        current = world.get::<Parent>(current)?.get();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Selects a radio button with the given value in a typed radio group.
+///
+/// If no button has the value yet, then the value is saved and the first button registered with that value will
+/// be selected.
+fn select_radio_value<T: PartialEq + Clone + Send + Sync + 'static>(world: &mut World, group: Entity, value: T)
+{
+    let Some(selection) = world.get::<React<RadioGroupSelection<T>>>(group) else {
+        tracing::warn!("failed selecting radio value in {group:?}; entity does not have RadioGroupSelection<{}>",
+            std::any::type_name::<T>());
+        return;
+    };
+    if selection.entity.is_some() && selection.value.as_ref() == Some(&value) {
+        return;
+    }
+
+    // Find a button with the value.
+    // - Nested groups of the same type are skipped.
+    let mut target = None;
+    iter_descendants_filtered(
+        world,
+        group,
+        |world, entity| entity == group || world.get::<React<RadioGroupSelection<T>>>(entity).is_none(),
+        |world, entity| {
+            if target.is_none()
+                && world
+                    .get::<RadioValue<T>>(entity)
+                    .map(|v| v.0 == value)
+                    .unwrap_or(false)
+            {
+                target = Some(entity);
+            }
+        },
+    );
+
+    match target {
+        Some(target) => {
+            world.react(|rc| rc.entity_event(target, Select));
+        }
+        None => {
+            let Some(mut selection) = world.get_mut::<React<RadioGroupSelection<T>>>(group) else { return };
+            *selection.get_noreact() = RadioGroupSelection { value: Some(value), entity: None };
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Selects a newly-registered radio button if its group is waiting for its value.
+fn register_radio_value<T: PartialEq + Clone + Send + Sync + 'static>(world: &mut World, button: Entity)
+{
+    let Some(value) = world.get::<RadioValue<T>>(button) else { return };
+    let Some(parent) = world.get::<Parent>(button) else { return };
+    let Some(group) = find_radio_group::<T>(world, parent.get()) else {
+        tracing::warn!("failed registering radio value for {button:?}; no ancestor has RadioGroupSelection<{}>",
+            std::any::type_name::<T>());
+        return;
+    };
+    let selection = world.get::<React<RadioGroupSelection<T>>>(group).unwrap();
+    if selection.entity.is_some() || selection.value.as_ref() != Some(&value.0) {
+        return;
+    }
+
+    world.react(|rc| rc.entity_event(button, Select));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Updates the nearest typed radio group when a radio button is selected.
+fn update_radio_group_selection<T: PartialEq + Clone + Send + Sync + 'static>(
+    id: TargetId,
+    mut c: Commands,
+    parents: Query<&Parent>,
+    values: Query<&RadioValue<T>>,
+    mut selections: ReactiveMut<RadioGroupSelection<T>>,
+)
+{
+    let Ok(value) = values.get(*id) else { return };
+    let Some(group) = parents
+        .iter_ancestors(*id)
+        .find(|ancestor| selections.get(*ancestor).is_ok())
+    else {
+        return;
+    };
+    selections.set_if_neq(
+        &mut c,
+        group,
+        RadioGroupSelection { value: Some(value.0.clone()), entity: Some(*id) },
+    );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Component with the value of a radio button in a typed radio group.
+///
+/// See [`RadioGroupWidgetExt::radio_value`].
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct RadioValue<T: PartialEq + Clone + Send + Sync + 'static>(pub T);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reactive component with the selected value of a typed radio group.
+///
+/// See [`RadioGroupWidgetExt::insert_radio_group`].
+#[derive(ReactComponent, Debug, Clone, PartialEq)]
+pub struct RadioGroupSelection<T: PartialEq + Clone + Send + Sync + 'static>
+{
+    value: Option<T>,
+    entity: Option<Entity>,
+}
+
+impl<T: PartialEq + Clone + Send + Sync + 'static> RadioGroupSelection<T>
+{
+    /// Gets the selected value.
+    pub fn value(&self) -> Option<&T>
+    {
+        self.value.as_ref()
+    }
+
+    /// Gets the selected radio button.
+    ///
+    /// Returns `None` if the selected value is waiting for a button with that value to be registered.
+    pub fn entity(&self) -> Option<Entity>
+    {
+        self.entity
+    }
+
+    /// Queues selection of the radio button with the given value in `group`.
+    ///
+    /// If no button has the value yet, then the first button registered with that value will be selected.
+    pub fn select_value(c: &mut Commands, group: Entity, value: &T)
+    {
+        let value = value.clone();
+        c.queue(move |world: &mut World| select_radio_value(world, group, value));
+    }
+}
+
+impl<T: PartialEq + Clone + Send + Sync + 'static> Default for RadioGroupSelection<T>
+{
+    fn default() -> Self
+    {
+        Self { value: None, entity: None }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Extension trait for setting up radio groups where each button has a value of type `T`.
+///
+/// Typed radio groups track the selected value in a [`RadioGroupSelection<T>`] reactive component on the group
+/// entity, so you don't need to capture button entities to read or set the selection.
+///
+/**
+```rust
+h.edit("difficulty", |h| {
+    h.insert_radio_group(Some(settings.difficulty))
+        .on_radio_selection(
+            |id: TargetId, mut settings: ResMut<Settings>, selection: Reactive<RadioGroupSelection<Difficulty>>| {
+                settings.difficulty = *selection.get(*id)?.value().result()?;
+                OK
+            },
+        );
+
+    for difficulty in [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard] {
+        h.spawn_scene_and_edit(("ui/settings.cob", "radio_button"), |h| {
+            h.radio_value(difficulty);
+        });
+    }
+});
+```
+*/
+pub trait RadioGroupWidgetExt
+{
+    /// Sets up the current entity as a typed radio group.
+    ///
+    /// Applies the [`RadioGroup`] instruction and inserts a [`RadioGroupSelection<T>`] reactive component. If
+    /// `initial` is set, then the button with that value will be selected (even if it is spawned later).
+    fn insert_radio_group<T: PartialEq + Clone + Send + Sync + 'static>(
+        &mut self,
+        initial: Option<T>,
+    ) -> &mut Self;
+
+    /// Sets the value of the current radio button in the nearest typed radio group.
+    ///
+    /// Applies the [`RadioButton`] instruction if needed, and inserts a [`RadioValue<T>`] component.
+    fn radio_value<T: PartialEq + Clone + Send + Sync + 'static>(&mut self, value: T) -> &mut Self;
+
+    /// Queues selection of the radio button with the given value in the current typed radio group.
+    ///
+    /// See [`RadioGroupSelection::select_value`].
+    fn select_radio_value<T: PartialEq + Clone + Send + Sync + 'static>(&mut self, value: T) -> &mut Self;
+
+    /// Adds a callback for reacting to changes in the `React<RadioGroupSelection<T>>` component on the current
+    /// entity.
+    ///
+    /// Equivalent to:
+    /// ```rust
+    /// ui_builder.update_on(entity_mutation::<RadioGroupSelection<T>>(entity), callback)
+    /// ```
+    fn on_radio_selection<T, M, C, R>(&mut self, callback: C) -> &mut Self
+    where
+        T: PartialEq + Clone + Send + Sync + 'static,
+        R: CobwebResult,
+        C: IntoSystem<TargetId, R, M> + Send + Sync + 'static;
+}
+
+impl RadioGroupWidgetExt for UiBuilder<'_, Entity>
+{
+    fn insert_radio_group<T: PartialEq + Clone + Send + Sync + 'static>(&mut self, initial: Option<T>)
+        -> &mut Self
+    {
+        let group = self.id();
+        self.apply(RadioGroup);
+        self.insert_reactive(RadioGroupSelection::<T>::default());
+        if let Some(initial) = initial {
+            RadioGroupSelection::select_value(self.commands(), group, &initial);
+        }
+        self
+    }
+
+    fn radio_value<T: PartialEq + Clone + Send + Sync + 'static>(&mut self, value: T) -> &mut Self
+    {
+        let button = self.id();
+        self.apply(RadioButton);
+        self.insert(RadioValue(value));
+        self.reactor(entity_event::<Select>(button), update_radio_group_selection::<T>);
+        self.commands()
+            .queue(move |world: &mut World| register_radio_value::<T>(world, button));
+        self
+    }
+
+    fn select_radio_value<T: PartialEq + Clone + Send + Sync + 'static>(&mut self, value: T) -> &mut Self
+    {
+        let group = self.id();
+        RadioGroupSelection::select_value(self.commands(), group, &value);
+        self
+    }
+
+    fn on_radio_selection<T, M, C, R>(&mut self, callback: C) -> &mut Self
+    where
+        T: PartialEq + Clone + Send + Sync + 'static,
+        R: CobwebResult,
+        C: IntoSystem<TargetId, R, M> + Send + Sync + 'static,
+    {
+        self.update_on(entity_mutation::<RadioGroupSelection<T>>(self.id()), callback)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 pub(crate) struct CobwebRadioButtonPlugin;
 
 impl Plugin for CobwebRadioButtonPlugin
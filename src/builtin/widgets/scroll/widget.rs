@@ -1,14 +1,6 @@
 /*
 unimplemented
-- touch-based scrolling; currently only directly dragging the scrollbar works
-    - TouchEvent
-        - need to track touch id lifetime
-            - record initial scroll position when touch starts
-        - scroll = distance traveled
-        - how to block touch events when elements are pressed in view? and likewise, how to cancel presses on elements when
-        scrolling?
-- mobile kinematic scrolling w/ buffers at top/bottom
-    - https://stackoverflow.com/a/7224899
+- how to cancel presses on elements in the view when drag-scrolling?
 - macos-style 'jump one page on scrollbar press'
     - needs animation framework overhaul or bespoke solution
         - bespoke solution likely best: need to also support pagination via mouse scroll events and gamepad/controller inputs
@@ -52,6 +44,21 @@ use crate::sickle::*;
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Proportion of drag delta past the end of a scroll view that is converted to overscroll.
+const OVERSCROLL_DRAG_FACTOR: f32 = 0.5;
+/// Exponential rate at which overscroll returns to zero (per second).
+const OVERSCROLL_RETURN_RATE: f32 = 12.0;
+/// Exponential rate at which inertia decays while overscrolling (per second).
+const OVERSCROLL_DECELERATION: f32 = 25.0;
+/// Inertial velocity (logical pixels per second) below which inertia stops.
+const MIN_INERTIA_VELOCITY: f32 = 10.0;
+/// Smoothing factor for tracking drag velocity.
+const DRAG_VELOCITY_SMOOTHING: f32 = 0.5;
+/// Max time between the last drag movement and pointer release for inertia to apply (in seconds).
+const INERTIA_RELEASE_WINDOW: f32 = 0.1;
+
+//-------------------------------------------------------------------------------------------------------------------
+
 // fn get_content_size(
 //     view_entity: Entity,
 //     ui_surface: &UiSurface,
@@ -102,6 +109,27 @@ fn cleanup_dead_bases(mut c: Commands, dying: Query<Entity, With<ScrollBaseDying
 ///
 /// Also dispatches `MouseScroll` entity events.
 fn consume_scroll_delta(
+    c: &mut Commands,
+    slider_vals: &mut ReactiveMut<SliderValue>,
+    entity: Entity,
+    correction_factor: f32,
+    scroll_size: f32,
+    unconsumed_delta: f32,
+) -> Option<f32>
+{
+    let unconsumed_delta =
+        shift_scroll_value(c, slider_vals, entity, correction_factor, scroll_size, unconsumed_delta)?;
+    c.react().entity_event(entity, MouseScroll);
+
+    Some(unconsumed_delta)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Shifts a scrollbar's slider value by scroll delta in one direction.
+///
+/// Returns the delta that could not be consumed because the end of the bar was reached.
+fn shift_scroll_value(
     c: &mut Commands,
     slider_vals: &mut ReactiveMut<SliderValue>,
     entity: Entity,
@@ -155,8 +183,6 @@ fn consume_scroll_delta(
         }
     }
 
-    c.react().entity_event(entity, MouseScroll);
-
     Some(unconsumed_delta)
 }
 
@@ -325,6 +351,315 @@ fn apply_mouse_scroll(
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Gets the scrollable size of a scroll base's view and the view's scale factor.
+fn get_scroll_metrics(
+    base_entity: Entity,
+    iter_children: &mut IterChildren,
+    children: &Query<&Children>,
+    views: &Query<(Entity, &ComputedNode), With<ScrollView>>,
+    shims: &Query<&ComputedNode, With<ScrollShim>>,
+) -> Option<(Vec2, f32)>
+{
+    let (view_entity, view_node) = iter_children.search(base_entity, children, |entity| views.get(entity).ok())?;
+    let content_size = get_content_size(view_entity, children, shims)?;
+    let scroll_size = (content_size - view_node.size()).max(Vec2::default());
+    let scale_factor = 1. / view_node.inverse_scale_factor().max(f32::EPSILON);
+
+    Some((scroll_size, scale_factor))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Shifts one axis of a scroll view by a physical-pixel delta, moving into overscroll if the end is reached.
+///
+/// Returns `true` if the end of the scroll view was reached.
+fn shift_kinetic_axis(
+    c: &mut Commands,
+    slider_vals: &mut ReactiveMut<SliderValue>,
+    bar: Entity,
+    scroll_size: f32,
+    mut delta: f32,
+    overscroll: &mut f32,
+    max_overscroll: f32,
+) -> bool
+{
+    if delta == 0. {
+        return false;
+    }
+
+    // Unwind overscroll before scrolling.
+    if *overscroll != 0. && delta.signum() != overscroll.signum() {
+        let unwound = *overscroll + delta;
+        if unwound.signum() == overscroll.signum() {
+            *overscroll = unwound;
+            return false;
+        }
+        *overscroll = 0.;
+        delta = unwound;
+    }
+
+    let leftover = shift_scroll_value(c, slider_vals, bar, 1.0, scroll_size, delta).unwrap_or(delta);
+    if leftover == 0. {
+        return false;
+    }
+
+    // Rubber-band resistance increases as the overscroll approaches its max.
+    if max_overscroll > 0. {
+        let resistance = 1. - (overscroll.abs() / max_overscroll).min(1.);
+        *overscroll =
+            (*overscroll + leftover * OVERSCROLL_DRAG_FACTOR * resistance).clamp(-max_overscroll, max_overscroll);
+    }
+
+    true
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Shifts a scroll view by a physical-pixel delta.
+///
+/// Returns a mask of the axes where the end of the scroll view was reached.
+fn shift_kinetic_scroll(
+    c: &mut Commands,
+    slider_vals: &mut ReactiveMut<SliderValue>,
+    computed_base: &ComputedScrollBase,
+    state: &mut KineticScrollState,
+    scroll_size: Vec2,
+    delta: Vec2,
+    max_overscroll: f32,
+) -> BVec2
+{
+    let mut hit_end = BVec2::FALSE;
+    if let Some(horizontal) = computed_base.horizontal {
+        hit_end.x = shift_kinetic_axis(
+            c,
+            slider_vals,
+            horizontal,
+            scroll_size.x,
+            delta.x,
+            &mut state.overscroll.x,
+            max_overscroll,
+        );
+    }
+    if let Some(vertical) = computed_base.vertical {
+        hit_end.y = shift_kinetic_axis(
+            c,
+            slider_vals,
+            vertical,
+            scroll_size.y,
+            delta.y,
+            &mut state.overscroll.y,
+            max_overscroll,
+        );
+    }
+    hit_end
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn handle_scroll_drag_start(
+    mut event: Trigger<Pointer<DragStart>>,
+    mut bases: Query<(&ScrollBase, &mut KineticScrollState)>,
+)
+{
+    let Ok((scroll_base, mut state)) = bases.get_mut(event.entity()) else { return };
+    if !scroll_base.drag.allows(event.event().pointer_id) {
+        return;
+    }
+    if !scroll_base.allow_multiscroll {
+        event.propagate(false);
+    }
+
+    state.dragging = Some(event.event().pointer_id);
+    state.velocity = Vec2::default();
+    state.frame_drag_delta = Vec2::default();
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn handle_scroll_drag(
+    mut event: Trigger<Pointer<Drag>>,
+    mut c: Commands,
+    time: Res<Time>,
+    mut iter_children: ResMut<IterChildren>,
+    children: Query<&Children>,
+    mut bases: Query<(&ScrollBase, &ComputedScrollBase, &mut KineticScrollState)>,
+    views: Query<(Entity, &ComputedNode), With<ScrollView>>,
+    shims: Query<&ComputedNode, With<ScrollShim>>,
+    mut slider_vals: ReactiveMut<SliderValue>,
+)
+{
+    let base_entity = event.entity();
+    let Ok((scroll_base, computed_base, mut state)) = bases.get_mut(base_entity) else { return };
+    if state.dragging != Some(event.event().pointer_id) {
+        return;
+    }
+    if !scroll_base.allow_multiscroll {
+        event.propagate(false);
+    }
+
+    let Some((scroll_size, scale_factor)) =
+        get_scroll_metrics(base_entity, &mut iter_children, &children, &views, &shims)
+    else {
+        return;
+    };
+
+    // Content follows the pointer, so the scroll delta is opposite the pointer delta.
+    let delta = -event.event().delta * scale_factor;
+    if delta == Vec2::default() {
+        return;
+    }
+    state.frame_drag_delta += delta;
+    state.last_drag_time = time.elapsed_secs();
+    shift_kinetic_scroll(
+        &mut c,
+        &mut slider_vals,
+        computed_base,
+        &mut state,
+        scroll_size,
+        delta,
+        scroll_base.overscroll * scale_factor,
+    );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn handle_scroll_drag_end(
+    mut event: Trigger<Pointer<DragEnd>>,
+    time: Res<Time>,
+    mut bases: Query<(&ScrollBase, &mut KineticScrollState)>,
+)
+{
+    let Ok((scroll_base, mut state)) = bases.get_mut(event.entity()) else { return };
+    if state.dragging != Some(event.event().pointer_id) {
+        return;
+    }
+    if !scroll_base.allow_multiscroll {
+        event.propagate(false);
+    }
+
+    state.dragging = None;
+
+    // If the pointer stopped moving before being released then there should be no inertia.
+    if !scroll_base.inertia || (time.elapsed_secs() - state.last_drag_time) > INERTIA_RELEASE_WINDOW {
+        state.velocity = Vec2::default();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Tracks drag velocity, applies inertial scrolling, and relaxes overscroll.
+fn update_kinetic_scroll(
+    mut c: Commands,
+    time: Res<Time>,
+    mut iter_children: ResMut<IterChildren>,
+    children: Query<&Children>,
+    mut bases: Query<(Entity, &ScrollBase, &ComputedScrollBase, &mut KineticScrollState)>,
+    views: Query<(Entity, &ComputedNode), With<ScrollView>>,
+    shims: Query<&ComputedNode, With<ScrollShim>>,
+    mut slider_vals: ReactiveMut<SliderValue>,
+)
+{
+    let delta_time = time.delta_secs();
+    if delta_time <= 0. {
+        return;
+    }
+
+    for (base_entity, scroll_base, computed_base, mut state) in bases.iter_mut() {
+        if state.is_resting() {
+            continue;
+        }
+        let state = &mut *state;
+
+        // Track drag velocity.
+        if state.dragging.is_some() {
+            let frame_velocity = std::mem::take(&mut state.frame_drag_delta) / delta_time;
+            state.velocity = state.velocity.lerp(frame_velocity, DRAG_VELOCITY_SMOOTHING);
+            continue;
+        }
+
+        let Some((scroll_size, scale_factor)) =
+            get_scroll_metrics(base_entity, &mut iter_children, &children, &views, &shims)
+        else {
+            continue;
+        };
+
+        // Apply inertia.
+        if state.velocity != Vec2::default() {
+            let delta = state.velocity * delta_time;
+            let hit_end = shift_kinetic_scroll(
+                &mut c,
+                &mut slider_vals,
+                computed_base,
+                state,
+                scroll_size,
+                delta,
+                scroll_base.overscroll * scale_factor,
+            );
+
+            let friction = (-scroll_base.friction.max(0.) * delta_time).exp();
+            let overscroll_friction = (-OVERSCROLL_DECELERATION * delta_time).exp();
+            let min_velocity = MIN_INERTIA_VELOCITY * scale_factor;
+            for (velocity, hit_end) in [(&mut state.velocity.x, hit_end.x), (&mut state.velocity.y, hit_end.y)] {
+                *velocity *= if hit_end && scroll_base.overscroll > 0. {
+                    overscroll_friction
+                } else {
+                    friction
+                };
+                if (hit_end && scroll_base.overscroll <= 0.) || velocity.abs() < min_velocity {
+                    *velocity = 0.;
+                }
+            }
+        }
+
+        // Relax overscroll once the axis is no longer moving.
+        let relax = (-OVERSCROLL_RETURN_RATE * delta_time).exp();
+        for (overscroll, velocity) in
+            [(&mut state.overscroll.x, state.velocity.x), (&mut state.overscroll.y, state.velocity.y)]
+        {
+            if *overscroll == 0. || velocity != 0. {
+                continue;
+            }
+            *overscroll *= relax;
+            if overscroll.abs() < 0.5 {
+                *overscroll = 0.;
+            }
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Offsets scroll content by the overscroll amount of its scroll base.
+///
+/// This is post-layout because layout clamps [`ScrollPosition`] to the content bounds.
+fn apply_scroll_overscroll(
+    mut iter_children: ResMut<IterChildren>,
+    children: Query<&Children>,
+    bases: Query<(Entity, &KineticScrollState)>,
+    views: Query<Entity, With<ScrollView>>,
+    mut shims: Query<&mut Transform, With<ScrollShim>>,
+)
+{
+    for (base_entity, state) in bases.iter() {
+        if state.overscroll == Vec2::default() {
+            continue;
+        }
+        let Some(view_entity) = iter_children.search(base_entity, &children, |entity| views.get(entity).ok())
+        else {
+            continue;
+        };
+        let Ok(view_children) = children.get(view_entity) else { continue };
+        for child in view_children.iter() {
+            let Ok(mut transform) = shims.get_mut(*child) else { continue };
+            // Note: UI transforms are in physical pixels, with y pointing down.
+            transform.translation.x -= state.overscroll.x;
+            transform.translation.y -= state.overscroll.y;
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 fn refresh_scroll_position(
     // ui_surface: Res<UiSurface>,
     bases: Query<&ComputedScrollBase>,
@@ -570,6 +905,29 @@ fn refresh_scroll_handles(
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Tracks drag scrolling, inertia, and overscroll for a scroll base.
+///
+/// Velocity and overscroll are in physical pixels, where positive values point toward the end of the scroll view.
+#[derive(Component, Default, Debug)]
+struct KineticScrollState
+{
+    dragging: Option<PointerId>,
+    frame_drag_delta: Vec2,
+    last_drag_time: f32,
+    velocity: Vec2,
+    overscroll: Vec2,
+}
+
+impl KineticScrollState
+{
+    fn is_resting(&self) -> bool
+    {
+        self.dragging.is_none() && self.velocity == Vec2::default() && self.overscroll == Vec2::default()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Marker component for cleaning up dead scrollbases after a hot reload removes ScrollBase from a node.
 #[derive(Component)]
 struct ScrollBaseDying;
@@ -662,6 +1020,27 @@ impl ComputedScrollBase
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Scrolls the nearest scroll widget containing an entity to the end of the given axis.
+///
+/// Does nothing if the scroll widget doesn't have a scroll bar for the axis.
+pub(crate) fn scroll_ancestor_to_end(world: &mut World, entity: Entity, axis: ScrollAxis)
+{
+    let Some((_, computed_base)) = get_ancestor_mut::<ComputedScrollBase>(world, entity) else { return };
+    let bar = match axis {
+        ScrollAxis::X => computed_base.horizontal,
+        ScrollAxis::Y => computed_base.vertical,
+    };
+    let Some(bar) = bar else { return };
+    let Some(mut value) = world.get_mut::<React<SliderValue>>(bar) else { return };
+    if value.single() == Some(1.) {
+        return;
+    }
+    *value.get_noreact() = SliderValue::Single(1.);
+    React::<SliderValue>::trigger_mutation(bar, world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Pseudo state added to a scroll base when its scroll view has horizontally-scrollable content.
 ///
 /// It can be used in COB as `Custom("HorizontalScroll")`.
@@ -677,6 +1056,38 @@ pub const VERTICAL_SCROLL_PSEUDO_STATE: PseudoState = PseudoState::Custom(SmolSt
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Controls which pointers can drag-scroll a [`ScrollBase`].
+#[derive(Reflect, Default, Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum ScrollDrag
+{
+    /// Content can't be dragged.
+    None,
+    /// Content can be dragged with touch pointers.
+    #[default]
+    Touch,
+    /// Content can be dragged with any pointer (e.g. the mouse).
+    All,
+}
+
+impl ScrollDrag
+{
+    fn allows(&self, pointer: PointerId) -> bool
+    {
+        match self {
+            Self::None => false,
+            Self::Touch => pointer.is_touch(),
+            Self::All => true,
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Loadable that sets up the base of a scroll view widget.
 ///
 /// A scroll view widget is composed of a [`ScrollBase`], a [`ScrollView`] (where content goes), and one or two
@@ -684,8 +1095,13 @@ pub const VERTICAL_SCROLL_PSEUDO_STATE: PseudoState = PseudoState::Custom(SmolSt
 ///
 /// In the current version, you must insert a [`ScrollShim`] entity between the `ScrollView` and your scroll
 /// content. This requirement will be removed once `bevy` provides access to the content size of the view node.
+///
+/// Scroll views can be panned by dragging their content (see [`Self::drag`]). When a drag is released, the view
+/// keeps scrolling with inertia that decays based on [`Self::friction`]. Dragging past the ends of the view will
+/// 'rubber-band' up to [`Self::overscroll`] pixels.
 #[derive(Reflect, Component, PartialEq, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[require(KineticScrollState)]
 pub struct ScrollBase
 {
     /// If `true` then [`MouseScrollEvent`] will propagate to lower scroll areas.
@@ -699,6 +1115,27 @@ pub struct ScrollBase
     // TODO: replace this with line size inference?
     #[reflect(default = "ScrollBase::default_line_size")]
     pub line_size: f32,
+    /// Controls which pointers can pan the scroll view by dragging its content.
+    ///
+    /// Defaults to [`ScrollDrag::Touch`].
+    #[reflect(default)]
+    pub drag: ScrollDrag,
+    /// If `true` then the view will keep scrolling after a drag is released.
+    ///
+    /// Defaults to `true`.
+    #[reflect(default = "ScrollBase::default_inertia")]
+    pub inertia: bool,
+    /// Exponential decay rate of inertial scrolling (per second). Higher values stop inertia faster.
+    ///
+    /// Defaults to `4.0`.
+    #[reflect(default = "ScrollBase::default_friction")]
+    pub friction: f32,
+    /// Max distance in logical pixels that content can be dragged past the ends of the view. Overscrolled content
+    /// springs back when released.
+    ///
+    /// Defaults to `0.0` (no overscroll).
+    #[reflect(default)]
+    pub overscroll: f32,
 }
 
 impl ScrollBase
@@ -707,6 +1144,16 @@ impl ScrollBase
     {
         16.0
     }
+
+    fn default_inertia() -> bool
+    {
+        true
+    }
+
+    fn default_friction() -> f32
+    {
+        4.0
+    }
 }
 
 impl Instruction for ScrollBase
@@ -769,6 +1216,10 @@ impl Default for ScrollBase
         Self {
             allow_multiscroll: false,
             line_size: Self::default_line_size(),
+            drag: ScrollDrag::default(),
+            inertia: Self::default_inertia(),
+            friction: Self::default_friction(),
+            overscroll: 0.,
         }
     }
 }
@@ -874,6 +1325,7 @@ impl Instruction for ScrollBar
             axis: self.axis.into(),
             direction,
             bar_press: self.bar_press.clone(),
+            steps: None,
         }
         .apply(entity, world);
 
@@ -1005,7 +1457,11 @@ impl Plugin for CobwebScrollPlugin
                     .before(SliderUpdateSet)
                     .before(TransformPropagate),
             )
+            .register_type::<ScrollDrag>()
             .add_observer(handle_mouse_scroll_event)
+            .add_observer(handle_scroll_drag_start)
+            .add_observer(handle_scroll_drag)
+            .add_observer(handle_scroll_drag_end)
             .add_systems(First, cleanup_dead_bases.after(FileProcessingSet))
             .add_systems(
                 PreUpdate,
@@ -1024,11 +1480,14 @@ impl Plugin for CobwebScrollPlugin
             )
             .add_systems(
                 PostUpdate,
-                (cleanup_dead_bases, refresh_scroll_position)
+                (cleanup_dead_bases, update_kinetic_scroll, refresh_scroll_position)
                     .chain()
                     .in_set(ScrollUpdateSet),
             )
-            .add_systems(PostUpdate, refresh_scroll_handles.in_set(ScrollHandleUpdateSet));
+            .add_systems(
+                PostUpdate,
+                (refresh_scroll_handles, apply_scroll_overscroll).in_set(ScrollHandleUpdateSet),
+            );
     }
 }
 
@@ -1,3 +1,6 @@
+use bevy::input::keyboard::KeyboardInput;
+use bevy::input::ButtonState;
+use bevy::picking::PickSet;
 use bevy::prelude::TransformSystem::TransformPropagate;
 use bevy::prelude::*;
 use bevy::ui::UiSystem;
@@ -13,6 +16,9 @@ use crate::sickle::*;
 const SLIDER_ZOOM_PSEUDO_STATE: PseudoState = PseudoState::Custom(SmolStr::new_static("SliderZoom"));
 const SLIDER_ZOOM_ATTR: &'static str = "sliderzoom";
 
+/// Slider value adjustment for key presses on sliders without steps.
+const DEFAULT_KEY_STEP: f32 = 0.05;
+
 //-------------------------------------------------------------------------------------------------------------------
 
 #[derive(Reflect, PartialEq, Default, Debug, Clone)]
@@ -125,7 +131,7 @@ impl ComputedSlider
 
 //-------------------------------------------------------------------------------------------------------------------
 
-fn get_camera_scale_factor(
+pub(crate) fn get_camera_scale_factor(
     ui_camera: &DefaultUiCamera,
     cameras: &Query<&Camera>,
     maybe_slider_camera: Option<&TargetCamera>,
@@ -271,10 +277,12 @@ fn slider_bar_ptr_down(
         slider.config.axis,
     );
 
-    let target_val = slider
-        .config
-        .direction
-        .flip_direction(standard_val, slider.config.axis);
+    let target_val = slider.config.snap(
+        slider
+            .config
+            .direction
+            .flip_direction(standard_val, slider.config.axis),
+    );
 
     // Update drag reference.
     slider.drag_reference = SliderDragReference { invalid_press: false, offset: Vec2::default() };
@@ -376,10 +384,12 @@ fn slider_bar_drag(
         slider.config.axis,
     );
 
-    let target_val = slider
-        .config
-        .direction
-        .flip_direction(standard_val, slider.config.axis);
+    let target_val = slider.config.snap(
+        slider
+            .config
+            .direction
+            .flip_direction(standard_val, slider.config.axis),
+    );
 
     // Update value.
     React::set_if_neq(&mut slider_value, &mut c, target_val);
@@ -392,15 +402,45 @@ fn slider_bar_drag(
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Computes the transform offset of a slider handle relative to the top-left of the slider bar, for a
+/// 'standard' value.
+fn compute_handle_offset(standard_val: Vec2, axis: SliderAxis, bar_size: Vec2, handle_size: Vec2) -> Vec2
+{
+    let bar_action_size = (bar_size - handle_size).max(Vec2::splat(0.));
+    let mut val_pos = standard_val * bar_action_size;
+    val_pos.y = -(val_pos.y - bar_action_size.y); // Correction because y-axis is down and handle defaults to top of bar.
+    match axis {
+        SliderAxis::X => {
+            let y_offset = (bar_size.y - handle_size.y) / 2.;
+            val_pos.with_y(y_offset)
+        }
+        SliderAxis::Y => {
+            let x_offset = (bar_size.x - handle_size.x) / 2.;
+            val_pos.with_x(x_offset)
+        }
+        SliderAxis::Planar => val_pos,
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 fn update_slider_handle_positions(
     mut iter_children: ResMut<IterChildren>,
-    mut sliders: Query<(&ComputedSlider, &React<SliderValue>, &Node, &ComputedNode, &Children)>,
+    mut sliders: Query<(
+        &ComputedSlider,
+        &React<SliderValue>,
+        &Node,
+        &ComputedNode,
+        &Children,
+        Option<&ComputedSliderTicks>,
+    )>,
     children_q: Query<&Children>,
     handles: Query<(Entity, &ComputedNode), (With<SliderHandle>, Without<ComputedSlider>)>,
+    tick_nodes: Query<&ComputedNode, Without<ComputedSlider>>,
     mut transforms: Query<&mut Transform>,
 )
 {
-    for (slider, slider_value, slider_node, slider_computed_node, children) in sliders.iter_mut() {
+    for (slider, slider_value, slider_node, slider_computed_node, children, maybe_ticks) in sliders.iter_mut() {
         // Skip sliders that won't be displayed.
         // - Note: ViewVisibility updates *after* TransformPropagate, so we can't use it here.
         if slider_node.display == Display::None {
@@ -420,7 +460,6 @@ fn update_slider_handle_positions(
         // Get slider bar and handle sizes (in physical pixels).
         let bar_size = slider_computed_node.size();
         let handle_size = handle_node.size();
-        let bar_action_size = (bar_size - handle_size).max(Vec2::splat(0.));
 
         // Get standardized current value.
         let mut value = slider_value.get().clone();
@@ -429,23 +468,224 @@ fn update_slider_handle_positions(
         let val_vec2 = standard_val.to_vec2(axis);
 
         // Get transform offset between bar and handle.
-        let mut val_pos = val_vec2 * bar_action_size;
-        val_pos.y = -(val_pos.y - bar_action_size.y); // Correction because y-axis is down and handle defaults to top of bar.
-        let transform_offset_corrected = match axis {
-            SliderAxis::X => {
-                let y_offset = (bar_size.y - handle_size.y) / 2.;
-                val_pos.with_y(y_offset)
-            }
-            SliderAxis::Y => {
-                let x_offset = (bar_size.x - handle_size.x) / 2.;
-                val_pos.with_x(x_offset)
-            }
-            SliderAxis::Planar => val_pos,
-        };
+        let transform_offset_corrected = compute_handle_offset(val_vec2, axis, bar_size, handle_size);
 
         // Update handle's position relative to the slider bar.
         // NOTE: This position adjustment may not be 'correct' if the handle isn't a direct child of the slider.
         handle_transform.translation += transform_offset_corrected.extend(0.);
+
+        // Update tick positions.
+        // - Ticks are centered on the handle position for each step.
+        let Some(ticks) = maybe_ticks else { continue };
+        let steps = ticks.steps.max(1) as f32;
+        for (idx, tick_entity) in ticks.spawned.iter().enumerate() {
+            let Ok(tick_node) = tick_nodes.get(*tick_entity) else { continue };
+            let Ok(mut tick_transform) = transforms.get_mut(*tick_entity) else { continue };
+            let tick_val = SliderValue::Single(idx as f32 / steps).to_vec2(axis);
+            let tick_offset = compute_handle_offset(tick_val, axis, bar_size, handle_size)
+                + (handle_size - tick_node.size()) / 2.;
+            tick_transform.translation += tick_offset.extend(0.);
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Focuses sliders when they are pressed, and unfocuses them when anything else is pressed.
+fn update_slider_focus(
+    mut c: Commands,
+    mut presses: EventReader<Pointer<Down>>,
+    ps: PseudoStateParam,
+    mut focus: ResMut<FocusedSlider>,
+    parents: Query<&Parent>,
+    sliders: Query<(), With<ComputedSlider>>,
+)
+{
+    for press in presses.read() {
+        // Find the nearest slider.
+        let target = std::iter::once(press.target)
+            .chain(parents.iter_ancestors(press.target))
+            .find(|e| sliders.contains(*e));
+
+        focus.set(&mut c, &ps, target);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Adjusts the focused slider with keyboard and gamepad input.
+fn handle_slider_keys(
+    mut c: Commands,
+    ps: PseudoStateParam,
+    mut focus: ResMut<FocusedSlider>,
+    mut key_events: EventReader<KeyboardInput>,
+    gamepads: Query<&Gamepad>,
+    mut sliders: Query<(&ComputedSlider, &mut React<SliderValue>)>,
+)
+{
+    // Collect adjustments in 'standard' space, where right and up are positive.
+    let mut adjustment = Vec2::default();
+    let mut jump: Option<f32> = None;
+    let mut unfocus = false;
+    for event in key_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        match event.key_code {
+            KeyCode::ArrowLeft => adjustment.x -= 1.,
+            KeyCode::ArrowRight => adjustment.x += 1.,
+            KeyCode::ArrowDown => adjustment.y -= 1.,
+            KeyCode::ArrowUp => adjustment.y += 1.,
+            KeyCode::Home => jump = Some(0.),
+            KeyCode::End => jump = Some(1.),
+            KeyCode::Escape => unfocus = true,
+            _ => (),
+        }
+    }
+    for gamepad in gamepads.iter() {
+        for (button, direction) in [
+            (GamepadButton::DPadLeft, Vec2::NEG_X),
+            (GamepadButton::DPadRight, Vec2::X),
+            (GamepadButton::DPadDown, Vec2::NEG_Y),
+            (GamepadButton::DPadUp, Vec2::Y),
+        ] {
+            if gamepad.just_pressed(button) {
+                adjustment += direction;
+            }
+        }
+    }
+
+    let Some(focused) = focus.get() else { return };
+    if unfocus {
+        focus.set(&mut c, &ps, None);
+        return;
+    }
+    if adjustment == Vec2::default() && jump.is_none() {
+        return;
+    }
+    let Ok((slider, mut slider_value)) = sliders.get_mut(focused) else {
+        focus.set(&mut c, &ps, None);
+        return;
+    };
+
+    // Adjust the standardized value so keys move the handle in the direction of the key.
+    let config = &slider.config;
+    let mut value = *slider_value.get();
+    value.normalize();
+    let standard_val = config.direction.flip_direction(value, config.axis);
+    let mut val_vec2 = standard_val.to_vec2(config.axis);
+    match jump {
+        Some(jump) => val_vec2 = Vec2::splat(jump),
+        None => val_vec2 += adjustment * config.key_step(),
+    }
+    let new_standard_val = match (config.axis, standard_val) {
+        (SliderAxis::X, SliderValue::Single(_)) => SliderValue::Single(val_vec2.x),
+        (SliderAxis::Y, SliderValue::Single(_)) => SliderValue::Single(val_vec2.y),
+        (SliderAxis::Planar, SliderValue::Single(_)) => SliderValue::Single(val_vec2.x),
+        (_, SliderValue::Planar(_)) => SliderValue::Planar(val_vec2),
+    };
+    let mut new_val = config.snap(
+        config
+            .direction
+            .flip_direction(new_standard_val, config.axis),
+    );
+    new_val.normalize();
+
+    React::set_if_neq(&mut slider_value, &mut c, new_val);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn spawn_slider_ticks(
+    In((entity, scene, count)): In<(Entity, SceneRef, usize)>,
+    mut c: Commands,
+    mut s: SceneBuilder,
+) -> Vec<Entity>
+{
+    let mut spawned = Vec::with_capacity(count);
+    for _ in 0..count {
+        c.ui_builder(entity)
+            .spawn_scene_and_edit(scene.clone(), &mut s, |h| {
+                spawned.push(h.id());
+            });
+    }
+    spawned
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn despawn_slider_ticks(world: &mut World, ticks: Vec<Entity>)
+{
+    for tick in ticks {
+        if let Ok(emut) = world.get_entity_mut(tick) {
+            emut.despawn_recursive();
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn apply_slider_ticks(entity: Entity, world: &mut World)
+{
+    let Some(ticks) = world.get::<SliderTicks>(entity) else { return };
+    let scene = SceneRef::new(&ticks.file, &ticks.scene);
+    let steps = match world
+        .get::<ComputedSlider>(entity)
+        .map(|s| (s.config.axis, s.config.steps))
+    {
+        Some((SliderAxis::X | SliderAxis::Y, Some(steps))) => steps,
+        Some((SliderAxis::Planar, Some(_))) => {
+            tracing::warn!("ignoring SliderTicks on {entity:?}; tick marks are not supported for planar sliders");
+            0
+        }
+        _ => 0,
+    };
+
+    // Skip rebuilding if nothing changed.
+    if let Some(computed) = world.get::<ComputedSliderTicks>(entity) {
+        if computed.scene == scene && computed.steps == steps {
+            return;
+        }
+    }
+
+    // Clean up old ticks.
+    if let Some(computed) = world.entity_mut(entity).take::<ComputedSliderTicks>() {
+        despawn_slider_ticks(world, computed.spawned);
+    }
+
+    // Spawn new ticks.
+    let count = if steps > 0 { steps as usize + 1 } else { 0 };
+    let spawned = world.syscall((entity, scene.clone(), count), spawn_slider_ticks);
+
+    world
+        .entity_mut(entity)
+        .insert(ComputedSliderTicks { scene, steps, spawned });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn refresh_slider_ticks(
+    mut c: Commands,
+    ticks: Query<Entity, Or<(Changed<SliderTicks>, (With<SliderTicks>, Changed<ComputedSlider>))>>,
+)
+{
+    for entity in ticks.iter() {
+        c.queue(move |world: &mut World| apply_slider_ticks(entity, world));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Publishes single-axis slider values to screen readers.
+fn update_slider_accessible_values(
+    mut c: Commands,
+    sliders: Query<(Entity, &React<SliderValue>), Changed<React<SliderValue>>>,
+)
+{
+    for (entity, value) in sliders.iter() {
+        let Some(value) = value.get().single() else { continue };
+        c.entity(entity)
+            .try_insert(AccessibleValue::Number { value: value as f64, min: 0., max: 1. });
     }
 }
 
@@ -498,6 +738,26 @@ impl SliderValue
         }
     }
 
+    /// Snaps the value to the nearest multiple of `1 / steps`.
+    ///
+    /// Does nothing if `steps` is zero.
+    pub fn snap(&mut self, steps: u32)
+    {
+        if steps == 0 {
+            return;
+        }
+        let steps = steps as f32;
+        match self {
+            Self::Single(v) => {
+                *v = (*v * steps).round() / steps;
+            }
+            Self::Planar(v) => {
+                *v = (*v * steps).round() / steps;
+            }
+        }
+        self.normalize();
+    }
+
     pub fn to_vec2(&self, axis: SliderAxis) -> Vec2
     {
         match axis {
@@ -656,7 +916,12 @@ pub enum SliderPress
 /// The primary button of all pointers will be able to drag the slider handle and press the slider bar to move
 /// the handle.
 ///
-/// Use [`SliderHandle`] on the node that will own the slider handle.
+/// Sliders are focused when pressed (see [`FocusedSlider`]). The focused slider can be adjusted with the arrow
+/// keys and gamepad D-pad, and `Home`/`End` move it to the ends of the bar. Each adjustment moves one step if
+/// [`Self::steps`] is set, otherwise 5% of the bar.
+///
+/// Use [`SliderHandle`] on the node that will own the slider handle, and [`SliderTicks`] to add tick marks for
+/// the slider's steps.
 #[derive(Reflect, Default, PartialEq, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Slider
@@ -670,9 +935,35 @@ pub struct Slider
     /// Defaults to [`SliderPress::Jump`].
     #[reflect(default)]
     pub bar_press: SliderPress,
+    /// If set, slider values will snap to multiples of `1 / steps`.
+    ///
+    /// For example, a `0..100` slider that moves in increments of 5 should have 20 steps.
+    ///
+    /// Defaults to `None`.
+    #[reflect(default)]
+    pub steps: Option<u32>,
     // TODO: consider configuring what pointers are allowed to drag the handle and press on the bar
-    // TODO: how to allow 'cursor scroll' or e.g. arrow keys (with keyboard focus?) to move the slider handle?
-    // - this may need to be added via higher-level abstractions
+}
+
+impl Slider
+{
+    /// Snaps a value to the slider's steps.
+    fn snap(&self, mut value: SliderValue) -> SliderValue
+    {
+        if let Some(steps) = self.steps {
+            value.snap(steps);
+        }
+        value
+    }
+
+    /// Gets the amount the slider value changes for each keyboard or gamepad adjustment.
+    fn key_step(&self) -> f32
+    {
+        match self.steps {
+            Some(steps) if steps > 0 => 1. / steps as f32,
+            _ => DEFAULT_KEY_STEP,
+        }
+    }
 }
 
 impl Instruction for Slider
@@ -717,6 +1008,7 @@ impl Instruction for Slider
         });
 
         emut.insert(computed);
+        AccessibleRole::Slider.apply_default(&mut emut);
 
         world.react(|rc| rc.insert(entity, initial_slider_value));
     }
@@ -727,10 +1019,17 @@ impl Instruction for Slider
 
         let Ok(mut emut) = world.get_entity_mut(entity) else { return };
         emut.remove::<React<SliderValue>>();
+        AccessibleRole::revert_default(&mut emut);
         emut.remove_pseudo_state(SLIDER_ZOOM_PSEUDO_STATE.clone());
+        emut.remove_pseudo_state(SLIDER_FOCUSED_PSEUDO_STATE.clone());
         if let Some(computed) = emut.take::<ComputedSlider>() {
             computed.revoke(world);
         }
+
+        let mut focus = world.resource_mut::<FocusedSlider>();
+        if focus.focused == Some(entity) {
+            focus.focused = None;
+        }
     }
 }
 
@@ -759,6 +1058,122 @@ pub struct SliderHandle;
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Instruction loadable for adding tick marks to a [`Slider`] with [`Slider::steps`].
+///
+/// This should be placed on the same entity as the slider. A tick scene will be spawned as a child of the slider
+/// for each step value (including both ends of the bar), and the ticks will be centered on the handle position
+/// for each step. The tick scene's root node should be absolutely-positioned (see [`AbsoluteNode`]).
+///
+/// Ticks are not supported for [`SliderAxis::Planar`] sliders.
+///
+/// Example:
+/**
+```rust
+#scenes
+"tick"
+    AbsoluteNode{width:2px height:8px}
+    BackgroundColor(#808080)
+
+"volume"
+    Slider{steps:10}
+    SliderTicks{file:"ui/slider.cob" scene:"tick"}
+    FlexNode{width:200px height:8px}
+
+    "handle"
+        SliderHandle
+        AbsoluteNode{width:12px height:12px}
+```
+*/
+#[derive(Reflect, Component, Default, Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct SliderTicks
+{
+    /// File of the tick scene.
+    pub file: String,
+    /// Tick scene spawned for each step.
+    pub scene: String,
+}
+
+impl Instruction for SliderTicks
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        validate_instruction_scene_ref::<Self>(world, SceneRef::new(&self.file, &self.scene));
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        emut.insert(self);
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        emut.remove::<Self>();
+        let Some(computed) = emut.take::<ComputedSliderTicks>() else { return };
+        despawn_slider_ticks(world, computed.spawned);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Tracks tick entities spawned by [`SliderTicks`].
+#[derive(Component, Debug)]
+struct ComputedSliderTicks
+{
+    scene: SceneRef,
+    steps: u32,
+    spawned: Vec<Entity>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource that tracks the currently-focused [`Slider`].
+///
+/// Keyboard and gamepad input is applied to the focused slider. Sliders are focused when pressed, and unfocused
+/// when something else is pressed or `Escape` is pressed.
+#[derive(Resource, Default, Debug)]
+pub struct FocusedSlider
+{
+    focused: Option<Entity>,
+}
+
+impl FocusedSlider
+{
+    /// Gets the focused slider.
+    pub fn get(&self) -> Option<Entity>
+    {
+        self.focused
+    }
+
+    /// Sets the focused slider.
+    ///
+    /// Updates the [`SLIDER_FOCUSED_PSEUDO_STATE`] on the previous and new entities.
+    pub fn set(&mut self, c: &mut Commands, ps: &PseudoStateParam, focused: Option<Entity>)
+    {
+        if self.focused == focused {
+            return;
+        }
+        if let Some(prev) = self.focused {
+            ps.try_remove(c, prev, SLIDER_FOCUSED_PSEUDO_STATE.clone());
+        }
+        if let Some(next) = focused {
+            ps.try_insert(c, next, SLIDER_FOCUSED_PSEUDO_STATE.clone());
+        }
+        self.focused = focused;
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Pseudo state added to a [`Slider`] while it is focused.
+///
+/// It can be used in COB as `Custom("Focused")`.
+pub const SLIDER_FOCUSED_PSEUDO_STATE: PseudoState = PseudoState::Custom(SmolStr::new_static("Focused"));
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Extension trait for interacting with [`SliderValue`] in a COB scene.
 pub trait SliderWidgetExt
 {
@@ -854,14 +1269,24 @@ impl Plugin for CobwebSliderPlugin
     {
         // TODO: re-enable once COB scene macros are implemented
         //load_embedded_scene_file!(app, "bevy_cobweb_ui", "src/builtin/widgets/slider", "slider.cob");
-        app.register_instruction_type::<Slider>()
+        app.init_resource::<FocusedSlider>()
+            .register_instruction_type::<Slider>()
             .register_component_type::<SliderHandle>()
+            .register_instruction_type::<SliderTicks>()
             .configure_sets(
                 PostUpdate,
                 SliderUpdateSet
                     .after(UiSystem::Layout)
                     .before(TransformPropagate),
             )
+            .add_systems(PreUpdate, update_slider_focus.after(PickSet::Focus))
+            .add_systems(Update, (handle_slider_keys, update_slider_accessible_values).chain())
+            .add_systems(
+                PostUpdate,
+                refresh_slider_ticks
+                    .after(FileProcessingSet)
+                    .before(UiSystem::Prepare),
+            )
             .add_systems(PostUpdate, update_slider_handle_positions.in_set(SliderUpdateSet));
     }
 }
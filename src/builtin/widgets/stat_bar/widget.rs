@@ -0,0 +1,616 @@
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+use bevy_cobweb::prelude::*;
+
+use crate::prelude::*;
+use crate::sickle::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Upper limit on the number of ticks a stat bar will display.
+const MAX_STAT_BAR_TICKS: usize = 256;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Component)]
+struct ComputedStatBar
+{
+    config: StatBar,
+
+    /// Fraction displayed by the lag fill. `None` until the bar has been updated once.
+    lag_fraction: Option<f32>,
+    /// Fraction of the bar value in the previous update.
+    prev_fraction: f32,
+    /// Seconds remaining before the lag fill starts draining.
+    lag_delay: f32,
+
+    /// Tick entities spawned on the bar.
+    ticks: Vec<Entity>,
+    /// The bar max value used to position the current ticks.
+    ticks_max: f32,
+}
+
+impl ComputedStatBar
+{
+    fn new(config: StatBar) -> Self
+    {
+        Self {
+            config,
+            lag_fraction: None,
+            prev_fraction: 0.,
+            lag_delay: 0.,
+            ticks: Vec::default(),
+            ticks_max: 0.,
+        }
+    }
+
+    fn revoke(self, world: &mut World)
+    {
+        for tick in self.ticks {
+            if let Ok(emut) = world.get_entity_mut(tick) {
+                emut.despawn_recursive();
+            }
+        }
+    }
+
+    /// Updates the lag fraction and returns it.
+    fn update_lag(&mut self, fraction: f32, delta_secs: f32) -> f32
+    {
+        let Some(lag) = self.config.lag else {
+            self.lag_fraction = Some(fraction);
+            self.prev_fraction = fraction;
+            return fraction;
+        };
+
+        let mut lag_fraction = self.lag_fraction.unwrap_or(fraction);
+
+        if fraction >= lag_fraction {
+            // Gains are not lagged.
+            lag_fraction = fraction;
+            self.lag_delay = 0.;
+        } else if fraction < self.prev_fraction {
+            // Each new loss restarts the delay.
+            self.lag_delay = lag.delay_secs.max(0.);
+        } else if self.lag_delay > 0. {
+            self.lag_delay -= delta_secs;
+        } else {
+            lag_fraction = (lag_fraction - lag.drain_rate.max(0.) * delta_secs).max(fraction);
+        }
+
+        self.lag_fraction = Some(lag_fraction);
+        self.prev_fraction = fraction;
+        lag_fraction
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn set_node_if_neq(node: &mut Mut<Node>, callback: impl FnOnce(&mut Node))
+{
+    let mut new_node = (**node).clone();
+    (callback)(&mut new_node);
+    if new_node != **node {
+        **node = new_node;
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn update_stat_bars(
+    time: Res<Time>,
+    mut c: Commands,
+    mut iter_children: ResMut<IterChildren>,
+    mut bars: Query<(Entity, &mut ComputedStatBar, &React<StatBarValue>, Option<&Children>)>,
+    children_q: Query<&Children>,
+    fills: Query<Entity, With<StatBarFill>>,
+    lag_fills: Query<Entity, With<StatBarLagFill>>,
+    mut nodes: Query<&mut Node, Without<ComputedStatBar>>,
+    mut colors: Query<&mut BackgroundColor, Without<ComputedStatBar>>,
+)
+{
+    let delta_secs = time.delta_secs();
+
+    for (bar_entity, mut bar, value, maybe_children) in bars.iter_mut() {
+        let fraction = value.fraction();
+        let lag_fraction = bar.update_lag(fraction, delta_secs);
+        let direction = bar.config.direction;
+
+        if let Some(children) = maybe_children {
+            // Update the main fill.
+            if let Some(fill) = iter_children.search_descendants(children, &children_q, |c| fills.get(c).ok()) {
+                if let Ok(mut node) = nodes.get_mut(fill) {
+                    set_node_if_neq(&mut node, |n| direction.set_fill(n, fraction));
+                }
+                if let Some(color) = bar.config.threshold_color(fraction) {
+                    if let Ok(mut bg) = colors.get_mut(fill) {
+                        bg.set_if_neq(BackgroundColor(color));
+                    } else {
+                        c.entity(fill).try_insert(BackgroundColor(color));
+                    }
+                }
+            }
+
+            // Update the lag fill.
+            if let Some(lag_fill) =
+                iter_children.search_descendants(children, &children_q, |c| lag_fills.get(c).ok())
+            {
+                if let Ok(mut node) = nodes.get_mut(lag_fill) {
+                    set_node_if_neq(&mut node, |n| direction.set_fill(n, lag_fraction));
+                }
+            }
+        }
+
+        // Rebuild ticks if the tick layout changed.
+        let max = value.max;
+        let num_ticks = bar
+            .config
+            .ticks
+            .as_ref()
+            .map(|t| t.count(max))
+            .unwrap_or(0);
+        if num_ticks == bar.ticks.len() && (num_ticks == 0 || bar.ticks_max == max) {
+            continue;
+        }
+
+        for tick in bar.ticks.drain(..) {
+            if let Some(ec) = c.get_entity(tick) {
+                ec.despawn_recursive();
+            }
+        }
+        bar.ticks_max = max;
+
+        let Some(ticks) = bar.config.ticks.clone() else { continue };
+        for idx in 0..num_ticks {
+            let position = ((idx + 1) as f32 * ticks.interval / max).clamp(0., 1.);
+            let mut node = Node { position_type: PositionType::Absolute, ..default() };
+            direction.set_tick(&mut node, position, ticks.width);
+
+            let tick = c
+                .spawn((node, BackgroundColor(ticks.color), StatBarTick, PickingBehavior::IGNORE))
+                .set_parent(bar_entity)
+                .id();
+            bar.ticks.push(tick);
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reactive component with the value displayed by a stat bar (e.g. health, mana, stamina).
+///
+/// Defaults to `current: 1.0, max: 1.0`.
+///
+/// See [`StatBar`].
+#[derive(ReactComponent, Debug, Copy, Clone, PartialEq, Reflect)]
+pub struct StatBarValue
+{
+    pub current: f32,
+    pub max: f32,
+}
+
+impl StatBarValue
+{
+    pub fn new(current: f32, max: f32) -> Self
+    {
+        Self { current, max }
+    }
+
+    /// Gets the filled fraction of the bar in the range `[0.0..1.0]`.
+    ///
+    /// Returns `0.0` if `max` is not positive.
+    pub fn fraction(&self) -> f32
+    {
+        if self.max.is_nan() || self.max <= 0. {
+            return 0.;
+        }
+        (self.current / self.max).clamp(0., 1.)
+    }
+}
+
+impl Default for StatBarValue
+{
+    fn default() -> Self
+    {
+        Self { current: 1., max: 1. }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// The direction a stat bar fills in.
+///
+/// See [`StatBar`].
+#[derive(Reflect, Default, Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum StatBarDirection
+{
+    #[default]
+    LeftToRight,
+    RightToLeft,
+    BottomToTop,
+    TopToBottom,
+}
+
+impl StatBarDirection
+{
+    pub fn is_horizontal(&self) -> bool
+    {
+        matches!(self, Self::LeftToRight | Self::RightToLeft)
+    }
+
+    /// Sizes and positions a fill node for the given fraction.
+    pub fn set_fill(&self, node: &mut Node, fraction: f32)
+    {
+        let fill = Val::Percent(fraction.clamp(0., 1.) * 100.);
+        node.position_type = PositionType::Absolute;
+        if self.is_horizontal() {
+            node.width = fill;
+            node.height = Val::Percent(100.);
+            node.top = Val::Px(0.);
+            node.bottom = Val::Auto;
+        } else {
+            node.width = Val::Percent(100.);
+            node.height = fill;
+            node.left = Val::Px(0.);
+            node.right = Val::Auto;
+        }
+        self.set_start(node, Val::Px(0.));
+    }
+
+    /// Positions a tick node at the given fraction.
+    pub fn set_tick(&self, node: &mut Node, fraction: f32, width: Val)
+    {
+        if self.is_horizontal() {
+            node.width = width;
+            node.height = Val::Percent(100.);
+            node.top = Val::Px(0.);
+        } else {
+            node.width = Val::Percent(100.);
+            node.height = width;
+            node.left = Val::Px(0.);
+        }
+        self.set_start(node, Val::Percent(fraction.clamp(0., 1.) * 100.));
+    }
+
+    fn set_start(&self, node: &mut Node, val: Val)
+    {
+        match self {
+            Self::LeftToRight => {
+                node.left = val;
+                node.right = Val::Auto;
+            }
+            Self::RightToLeft => {
+                node.right = val;
+                node.left = Val::Auto;
+            }
+            Self::BottomToTop => {
+                node.bottom = val;
+                node.top = Val::Auto;
+            }
+            Self::TopToBottom => {
+                node.top = val;
+                node.bottom = Val::Auto;
+            }
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A color applied to a stat bar's fill when the bar's fraction is at or below `below`.
+///
+/// See [`StatBar`].
+#[derive(Reflect, Default, Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct StatBarThreshold
+{
+    /// Fraction of the bar in the range `[0.0..1.0]`.
+    pub below: f32,
+    pub color: Color,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Configures a 'damage lag' fill that trails behind the main fill when the bar value decreases.
+///
+/// See [`StatBar`].
+#[derive(Reflect, Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct StatBarLag
+{
+    /// Seconds to wait after a loss before the lag fill starts draining.
+    ///
+    /// Defaults to `0.5`.
+    #[reflect(default = "StatBarLag::default_delay_secs")]
+    pub delay_secs: f32,
+    /// Fraction of the bar the lag fill drains per second.
+    ///
+    /// Defaults to `0.5`.
+    #[reflect(default = "StatBarLag::default_drain_rate")]
+    pub drain_rate: f32,
+}
+
+impl StatBarLag
+{
+    fn default_delay_secs() -> f32
+    {
+        0.5
+    }
+
+    fn default_drain_rate() -> f32
+    {
+        0.5
+    }
+}
+
+impl Default for StatBarLag
+{
+    fn default() -> Self
+    {
+        Self {
+            delay_secs: Self::default_delay_secs(),
+            drain_rate: Self::default_drain_rate(),
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Configures tick marks that divide a stat bar into segments.
+///
+/// See [`StatBar`].
+#[derive(Reflect, Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct StatBarTicks
+{
+    /// The bar value between ticks (e.g. `100.0` for a tick every 100 health).
+    pub interval: f32,
+    /// Thickness of each tick.
+    ///
+    /// Defaults to `2px`.
+    #[reflect(default = "StatBarTicks::default_width")]
+    pub width: Val,
+    /// Defaults to black.
+    #[reflect(default = "StatBarTicks::default_color")]
+    pub color: Color,
+}
+
+impl StatBarTicks
+{
+    fn default_width() -> Val
+    {
+        Val::Px(2.)
+    }
+
+    fn default_color() -> Color
+    {
+        Color::BLACK
+    }
+
+    /// Gets the number of ticks to display for a bar max value.
+    pub fn count(&self, max: f32) -> usize
+    {
+        if self.interval.is_nan() || self.interval <= 0. || max.is_nan() || max <= 0. {
+            return 0;
+        }
+        let count = ((max / self.interval).ceil() as usize).saturating_sub(1);
+        count.min(MAX_STAT_BAR_TICKS)
+    }
+}
+
+impl Default for StatBarTicks
+{
+    fn default() -> Self
+    {
+        Self {
+            interval: 0.,
+            width: Self::default_width(),
+            color: Self::default_color(),
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction loadable for setting up a stat bar widget (e.g. a health bar).
+///
+/// This should be placed on the entity with the 'background' of the bar.
+///
+/// Inserts a [`StatBarValue`] reactive component to the entity if it doesn't have one. Also inserts an internal
+/// `ComputedStatBar` component. Update the `StatBarValue` to change what the bar displays.
+///
+/// Use [`StatBarFill`] on a descendant node that will display the current value, and [`StatBarLagFill`] on a
+/// descendant node that will display the damage lag (if [`Self::lag`] is set). The lag fill should be placed
+/// before the main fill so it is drawn behind it. Fill nodes are automatically absolutely-positioned and sized.
+///
+/// Example (COB):
+/**
+```rust
+"health"
+    FlexNode{width:200px height:20px}
+    BackgroundColor(#222222)
+    StatBar{
+        ticks:{interval:100}
+        lag:{}
+        thresholds:[{below:0.25 color:#CC0000} {below:1.0 color:#00AA00}]
+    }
+
+    "lag"
+        StatBarLagFill
+        BackgroundColor(#FFFFFF)
+    "fill"
+        StatBarFill
+```
+*/
+#[derive(Reflect, Default, Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct StatBar
+{
+    #[reflect(default)]
+    pub direction: StatBarDirection,
+    /// Tick marks dividing the bar into segments.
+    ///
+    /// Ticks are spawned as children of the bar with the [`StatBarTick`] component.
+    #[reflect(default)]
+    pub ticks: Option<StatBarTicks>,
+    /// Damage lag for the [`StatBarLagFill`] node. If not set, the lag fill will track the main fill.
+    #[reflect(default)]
+    pub lag: Option<StatBarLag>,
+    /// Colors applied to the [`StatBarFill`] node's [`BackgroundColor`] based on the bar's fraction.
+    ///
+    /// The threshold with the lowest `below` value that is `>=` the bar's fraction will be used. If no threshold
+    /// matches, then the fill color will not be changed.
+    #[reflect(default)]
+    pub thresholds: Vec<StatBarThreshold>,
+}
+
+impl StatBar
+{
+    /// Gets the threshold color for a fraction of the bar.
+    pub fn threshold_color(&self, fraction: f32) -> Option<Color>
+    {
+        self.thresholds
+            .iter()
+            .filter(|t| fraction <= t.below)
+            .min_by(|a, b| a.below.total_cmp(&b.below))
+            .map(|t| t.color)
+    }
+}
+
+impl Instruction for StatBar
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+
+        // Clean up the previous configuration (e.g. when hot reloading).
+        let prev = emut.take::<ComputedStatBar>();
+        let has_value = emut.contains::<React<StatBarValue>>();
+        emut.insert(ComputedStatBar::new(self));
+
+        if let Some(prev) = prev {
+            prev.revoke(world);
+        }
+        if !has_value {
+            world.react(|rc| rc.insert(entity, StatBarValue::default()));
+        }
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        emut.remove::<React<StatBarValue>>();
+        if let Some(computed) = emut.take::<ComputedStatBar>() {
+            computed.revoke(world);
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Component loadable for the node that displays a stat bar's current value.
+///
+/// One of the node's ancestors must have a [`StatBar`].
+#[derive(Reflect, Component, Default, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct StatBarFill;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Component loadable for the node that displays a stat bar's 'damage lag'.
+///
+/// One of the node's ancestors must have a [`StatBar`].
+#[derive(Reflect, Component, Default, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct StatBarLagFill;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Marker component for tick nodes spawned by [`StatBar`].
+#[derive(Component, Default, Debug, Copy, Clone)]
+pub struct StatBarTick;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Extension trait for interacting with [`StatBarValue`] in a COB scene.
+pub trait StatBarWidgetExt
+{
+    /// Adds a callback for initializing the `React<StatBarValue>` component on the current entity from world
+    /// state.
+    ///
+    /// Equivalent to:
+    /// ```rust
+    /// ui_builder.update_on(entity_insertion::<StatBarValue>(entity), callback)
+    /// ```
+    fn initialize_stat_bar<M, C, R: CobwebResult>(&mut self, callback: C) -> &mut Self
+    where
+        C: IntoSystem<TargetId, R, M> + Send + Sync + 'static;
+}
+
+impl StatBarWidgetExt for UiBuilder<'_, Entity>
+{
+    fn initialize_stat_bar<M, C, R: CobwebResult>(&mut self, callback: C) -> &mut Self
+    where
+        C: IntoSystem<TargetId, R, M> + Send + Sync + 'static,
+    {
+        self.update_on(entity_insertion::<StatBarValue>(self.id()), callback)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// System set in `PostUpdate` where stat bar widgets are updated.
+#[derive(SystemSet, Debug, Hash, Eq, PartialEq, Copy, Clone)]
+pub struct StatBarUpdateSet;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct CobwebStatBarPlugin;
+
+impl Plugin for CobwebStatBarPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.register_instruction_type::<StatBar>()
+            .register_component_type::<StatBarFill>()
+            .register_component_type::<StatBarLagFill>()
+            .configure_sets(
+                PostUpdate,
+                StatBarUpdateSet
+                    .after(FileProcessingSet)
+                    .after(DynamicStylePostUpdate)
+                    .before(UiSystem::Prepare),
+            )
+            .add_systems(PostUpdate, update_stat_bars.in_set(StatBarUpdateSet));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
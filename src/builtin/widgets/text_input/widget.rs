@@ -0,0 +1,1153 @@
+use std::borrow::Cow;
+
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use bevy::picking::pointer::{PointerId, PointerPress};
+use bevy::picking::PickSet;
+use bevy::prelude::TransformSystem::TransformPropagate;
+use bevy::prelude::*;
+use bevy::render::camera::NormalizedRenderTarget;
+use bevy::text::{ComputedTextBlock, TextLayoutInfo};
+use bevy::ui::UiSystem;
+use bevy::window::{Ime, PrimaryWindow};
+use bevy_cobweb::prelude::*;
+use smol_str::SmolStr;
+
+use crate::builtin::widgets::scroll::*;
+use crate::prelude::*;
+use crate::sickle::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Text displayed at the cursor position of a focused text input.
+const TEXT_INPUT_CARET: &str = "|";
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Marks the text spans that are spawned on a [`TextInputText`] node to display the cursor.
+#[derive(Component, Debug, Copy, Clone, PartialEq, Eq)]
+enum TextInputSpan
+{
+    /// Displays the in-progress IME composition.
+    Preedit,
+    /// Displays the caret.
+    Caret,
+    /// Displays text after the cursor.
+    After,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Marks the node that underlines the in-progress IME composition of a text input.
+#[derive(Component, Debug)]
+struct TextInputUnderline;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn line_start(text: &str, byte: usize) -> usize
+{
+    text[..byte].rfind('\n').map(|i| i + 1).unwrap_or(0)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn line_end(text: &str, byte: usize) -> usize
+{
+    text[byte..]
+        .find('\n')
+        .map(|i| byte + i)
+        .unwrap_or(text.len())
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Gets the byte offset `column` characters after `start`, stopping at the end of the line.
+fn advance_column(text: &str, start: usize, column: usize) -> usize
+{
+    let mut byte = start;
+    for c in text[start..].chars().take(column) {
+        if c == '\n' {
+            break;
+        }
+        byte += c.len_utf8();
+    }
+    byte
+}
+
+/// Replaces all characters except newlines with a mask character.
+fn mask_text(text: &str, mask: char) -> String
+{
+    text.chars()
+        .map(|c| if c == '\n' { c } else { mask })
+        .collect()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Focuses text inputs when they are pressed, and unfocuses them when anything else is pressed.
+fn update_text_input_focus(
+    mut c: Commands,
+    mut presses: EventReader<Pointer<Down>>,
+    ps: PseudoStateParam,
+    mut focus: ResMut<FocusedTextInput>,
+    parents: Query<&Parent>,
+    inputs: Query<(), With<TextInput>>,
+)
+{
+    for press in presses.read() {
+        // Find the nearest text input.
+        let target = std::iter::once(press.target)
+            .chain(parents.iter_ancestors(press.target))
+            .find(|e| inputs.contains(*e));
+
+        focus.set(&mut c, &ps, target);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reveals a masked text input while its [`TextInputRevealButton`] is pressed.
+///
+/// Observes presses on the text input, which bubble up from its reveal buttons.
+fn start_text_input_reveal(
+    event: Trigger<Pointer<Down>>,
+    mut c: Commands,
+    ps: PseudoStateParam,
+    parents: Query<&Parent>,
+    buttons: Query<(), With<TextInputRevealButton>>,
+    inputs: Query<(), With<TextInput>>,
+)
+{
+    if event.button != PointerButton::Primary {
+        return;
+    }
+
+    // Find the reveal button and check that it belongs to this text input.
+    let input = event.entity();
+    let Some(button) = std::iter::once(event.target)
+        .chain(parents.iter_ancestors(event.target))
+        .find(|e| buttons.contains(*e))
+    else {
+        return;
+    };
+    let button_input = std::iter::once(button)
+        .chain(parents.iter_ancestors(button))
+        .find(|e| inputs.contains(*e));
+    if button_input != Some(input) {
+        return;
+    }
+
+    c.entity(input)
+        .insert(TextInputRevealed { pointer: event.pointer_id });
+    ps.try_insert(&mut c, input, TEXT_INPUT_REVEALED_PSEUDO_STATE.clone());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Hides revealed text inputs when the pointer that revealed them is released.
+fn end_text_input_reveals(
+    mut c: Commands,
+    ps: PseudoStateParam,
+    revealed: Query<(Entity, &TextInputRevealed)>,
+    pointers: Query<(&PointerId, &PointerPress)>,
+)
+{
+    for (entity, reveal) in revealed.iter() {
+        let is_pressed = pointers
+            .iter()
+            .any(|(id, press)| *id == reveal.pointer && press.is_primary_pressed());
+        if is_pressed {
+            continue;
+        }
+        c.entity(entity).remove::<TextInputRevealed>();
+        ps.try_remove(&mut c, entity, TEXT_INPUT_REVEALED_PSEUDO_STATE.clone());
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Publishes text input values to screen readers.
+///
+/// Masked text is never published.
+fn update_text_input_accessible_values(
+    mut c: Commands,
+    inputs: Query<
+        (Entity, &TextInput, &React<TextInputValue>),
+        Or<(Changed<TextInput>, Changed<React<TextInputValue>>)>,
+    >,
+)
+{
+    for (entity, config, value) in inputs.iter() {
+        match config.mask {
+            Some(_) => {
+                c.entity(entity).remove::<AccessibleValue>();
+            }
+            None => {
+                c.entity(entity)
+                    .try_insert(AccessibleValue::Text(value.get().0.clone()));
+            }
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Inserts pasted text at a text input's cursor.
+fn paste_into_text_input(
+    In((entity, paste)): In<(Entity, String)>,
+    mut c: Commands,
+    mut inputs: Query<(&TextInput, &mut TextInputCursor)>,
+    mut values: ReactiveMut<TextInputValue>,
+)
+{
+    let Ok((config, mut cursor)) = inputs.get_mut(entity) else { return };
+    let Ok(value) = values.get(entity) else { return };
+
+    // Single-line inputs receive pasted lines separated by spaces.
+    let paste: String = paste
+        .chars()
+        .map(|c| if c == '\n' && !config.multiline { ' ' } else { c })
+        .filter(|c| *c == '\n' || !c.is_control())
+        .collect();
+
+    let mut text = value.0.clone();
+    if cursor.insert(&mut text, &paste, config.max_length) {
+        values.set_if_neq(&mut c, entity, TextInputValue(text));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Applies keyboard input to the focused text input.
+///
+/// Ctrl+C, Ctrl+X, and Ctrl+V (or Cmd on macOS) copy, cut, and paste with the [`Clipboard`]. Since text inputs
+/// don't have selections, copy and cut apply to the entire text. Masked text can't be copied or cut.
+fn handle_text_input_keys(
+    mut c: Commands,
+    mut clipboard: ClipboardCommands,
+    ps: PseudoStateParam,
+    mut focus: ResMut<FocusedTextInput>,
+    mut key_events: EventReader<KeyboardInput>,
+    mut ime_events: EventReader<Ime>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut inputs: Query<(&TextInput, &mut TextInputCursor, &mut TextInputPreedit)>,
+    mut values: ReactiveMut<TextInputValue>,
+    blocking: InputBlockingParam,
+)
+{
+    let Some(entity) = focus.get() else {
+        key_events.clear();
+        ime_events.clear();
+        return;
+    };
+    if !blocking.keyboard_allowed(entity) {
+        key_events.clear();
+        ime_events.clear();
+        return;
+    }
+    let Ok((config, mut cursor, mut preedit)) = inputs.get_mut(entity) else {
+        key_events.clear();
+        ime_events.clear();
+        focus.set(&mut c, &ps, None);
+        return;
+    };
+    let Ok(value) = values.get(entity) else {
+        key_events.clear();
+        ime_events.clear();
+        return;
+    };
+
+    let ctrl = keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+    let command = ctrl || keys.any_pressed([KeyCode::SuperLeft, KeyCode::SuperRight]);
+    let mut text = value.0.clone();
+    let mut submit = false;
+    let mut unfocus = false;
+
+    for event in ime_events.read() {
+        match event {
+            Ime::Preedit { value, cursor: Some(_), .. } => {
+                if preedit.text != *value {
+                    preedit.text.clone_from(value);
+                }
+            }
+            Ime::Preedit { cursor: None, .. } | Ime::Disabled { .. } => {
+                if !preedit.text.is_empty() {
+                    preedit.text.clear();
+                }
+            }
+            Ime::Commit { value, .. } => {
+                cursor.insert(&mut text, value, config.max_length);
+                if !preedit.text.is_empty() {
+                    preedit.text.clear();
+                }
+            }
+            Ime::Enabled { .. } => (),
+        }
+    }
+
+    for event in key_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+
+        // Keys are consumed by the IME while composing.
+        if !preedit.text.is_empty() {
+            continue;
+        }
+
+        match &event.logical_key {
+            Key::Character(chars) if command => match chars.to_lowercase().as_str() {
+                "c" if config.mask.is_none() => {
+                    clipboard.set_text(text.clone());
+                }
+                "x" if config.mask.is_none() => {
+                    clipboard.set_text(std::mem::take(&mut text));
+                    cursor.move_line_end(&text);
+                }
+                "v" => {
+                    clipboard.get_text(move |world, paste| {
+                        let Some(paste) = paste else { return };
+                        world.syscall((entity, paste), paste_into_text_input);
+                    });
+                }
+                _ => (),
+            },
+            Key::Character(chars) if !ctrl => {
+                let chars: String = chars.chars().filter(|c| !c.is_control()).collect();
+                cursor.insert(&mut text, &chars, config.max_length);
+            }
+            Key::Space if !ctrl => {
+                cursor.insert(&mut text, " ", config.max_length);
+            }
+            Key::Enter => {
+                if config.multiline && !ctrl {
+                    cursor.insert(&mut text, "\n", config.max_length);
+                } else {
+                    submit = true;
+                }
+            }
+            Key::Backspace => {
+                cursor.delete_backward(&mut text);
+            }
+            Key::Delete => {
+                cursor.delete_forward(&mut text);
+            }
+            Key::ArrowLeft => cursor.move_left(&text),
+            Key::ArrowRight => cursor.move_right(&text),
+            Key::ArrowUp if config.multiline => cursor.move_up(&text),
+            Key::ArrowDown if config.multiline => cursor.move_down(&text),
+            Key::Home => cursor.move_line_start(&text),
+            Key::End => cursor.move_line_end(&text),
+            Key::Escape => {
+                unfocus = true;
+            }
+            _ => (),
+        }
+    }
+
+    // Keep the end of the text visible while typing at the end of a scrollable text area.
+    let at_end = cursor.is_at_end(&text);
+    let changed = text != value.0;
+    if changed {
+        values.set_if_neq(&mut c, entity, TextInputValue(text));
+    }
+    if changed && config.multiline && at_end {
+        c.queue(move |world: &mut World| scroll_ancestor_to_end(world, entity, ScrollAxis::Y));
+    }
+    if submit {
+        c.react().entity_event(entity, TextInputSubmit);
+    }
+    if unfocus {
+        focus.set(&mut c, &ps, None);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Writes text input values to their display nodes.
+fn refresh_text_inputs(
+    mut c: Commands,
+    mut iter_children: ResMut<IterChildren>,
+    focus: Res<FocusedTextInput>,
+    inputs: Query<(
+        Entity,
+        &TextInput,
+        &TextInputCursor,
+        &TextInputPreedit,
+        &React<TextInputValue>,
+        &Children,
+        Has<TextInputRevealed>,
+    )>,
+    children_q: Query<&Children>,
+    text_nodes: Query<Entity, With<TextInputText>>,
+    line_number_nodes: Query<Entity, With<TextInputLineNumbers>>,
+    mut texts: Query<(&mut Text, &mut TextLayout, &TextFont, &TextColor, Option<&Children>)>,
+    mut spans: Query<(&TextInputSpan, &mut TextSpan, &mut TextFont, &mut TextColor), Without<Text>>,
+)
+{
+    for (entity, config, cursor, preedit, value, children, revealed) in inputs.iter() {
+        let text = value.as_str();
+
+        // Update line numbers.
+        if let Some(numbers_node) =
+            iter_children.search_descendants(children, &children_q, |c| line_number_nodes.get(c).ok())
+        {
+            if let Ok((mut numbers, ..)) = texts.get_mut(numbers_node) {
+                let num_lines = text.split('\n').count();
+                let new_numbers = (1..=num_lines)
+                    .map(|n| n.to_string())
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                if numbers.0 != new_numbers {
+                    numbers.0 = new_numbers;
+                }
+            }
+        }
+
+        // Update text.
+        let Some(text_node) = iter_children.search_descendants(children, &children_q, |c| text_nodes.get(c).ok())
+        else {
+            continue;
+        };
+        let Ok((mut display, mut layout, font, color, maybe_spans)) = texts.get_mut(text_node) else { continue };
+
+        let linebreak = match config.multiline && config.word_wrap {
+            true => LineBreak::WordBoundary,
+            false => LineBreak::NoWrap,
+        };
+        if layout.linebreak != linebreak {
+            layout.linebreak = linebreak;
+        }
+
+        let split = cursor.get().min(text.len());
+        let (before, composing, caret, after) = match focus.get() == Some(entity) && text.is_char_boundary(split) {
+            true => (&text[..split], preedit.get(), TEXT_INPUT_CARET, &text[split..]),
+            false => (text, "", "", ""),
+        };
+
+        // Masked text is never written to display components.
+        let (before, composing, after): (Cow<str>, Cow<str>, Cow<str>) = match config.mask.filter(|_| !revealed) {
+            Some(mask) => (
+                mask_text(before, mask).into(),
+                mask_text(composing, mask).into(),
+                mask_text(after, mask).into(),
+            ),
+            None => (before.into(), composing.into(), after.into()),
+        };
+
+        if display.0 != before {
+            display.0 = before.into_owned();
+        }
+
+        // Update cursor spans.
+        let mut found_spans = false;
+        for span_entity in maybe_spans.into_iter().flatten() {
+            let Ok((span_type, mut span, mut span_font, mut span_color)) = spans.get_mut(*span_entity) else {
+                continue;
+            };
+            found_spans = true;
+            let content = match span_type {
+                TextInputSpan::Preedit => &*composing,
+                TextInputSpan::Caret => caret,
+                TextInputSpan::After => &*after,
+            };
+            if span.0 != content {
+                span.0 = content.into();
+            }
+            if span_font.font != font.font
+                || span_font.font_size != font.font_size
+                || span_font.font_smoothing != font.font_smoothing
+            {
+                *span_font = font.clone();
+            }
+            if span_color.0 != color.0 {
+                *span_color = *color;
+            }
+        }
+
+        // Spans are spawned in order since bevy text spans are displayed in hierarchy order.
+        if !found_spans {
+            for (span_type, content) in [
+                (TextInputSpan::Preedit, &*composing),
+                (TextInputSpan::Caret, caret),
+                (TextInputSpan::After, &*after),
+            ] {
+                c.spawn((span_type, TextSpan::new(content), font.clone(), *color))
+                    .set_parent(text_node);
+            }
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Underlines the IME composition of the focused text input, and manages IME state on windows.
+///
+/// The OS candidate window is positioned at the text input's caret.
+fn refresh_text_input_ime(
+    mut c: Commands,
+    mut iter_children: ResMut<IterChildren>,
+    mut ime_window: Local<Option<Entity>>,
+    focus: Res<FocusedTextInput>,
+    ui_camera: DefaultUiCamera,
+    cameras: Query<&Camera>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    mut windows: Query<&mut Window>,
+    mut inputs: Query<(Entity, &mut TextInputPreedit, &Children, Option<&TargetCamera>)>,
+    children_q: Query<&Children>,
+    text_nodes: Query<
+        (&Parent, &ComputedNode, &GlobalTransform, &TextLayoutInfo, &ComputedTextBlock, &TextFont, &TextColor),
+        With<TextInputText>,
+    >,
+    parent_nodes: Query<(&ComputedNode, &GlobalTransform)>,
+    spans: Query<&TextInputSpan>,
+    mut underlines: Query<(&mut Node, &mut BackgroundColor), With<TextInputUnderline>>,
+)
+{
+    let mut focused_window = None;
+
+    for (entity, mut preedit, children, maybe_camera) in inputs.iter_mut() {
+        let is_focused = focus.get() == Some(entity);
+
+        // Compositions are discarded when focus is lost.
+        if !is_focused && !preedit.text.is_empty() {
+            preedit.text.clear();
+        }
+        if !is_focused || preedit.text.is_empty() {
+            if let Some(underline) = preedit.underline.take() {
+                if let Some(ec) = c.get_entity(underline) {
+                    ec.despawn_recursive();
+                }
+            }
+        }
+        if !is_focused {
+            continue;
+        }
+
+        // Look up the window the text input is displayed in.
+        let camera_entity = maybe_camera.map(|t| t.entity()).or_else(|| ui_camera.get());
+        let Some(camera) = camera_entity.and_then(|e| cameras.get(e).ok()) else { continue };
+        let Some(NormalizedRenderTarget::Window(window_ref)) = camera.target.normalize(primary_window.get_single().ok())
+        else {
+            continue;
+        };
+        focused_window = Some(window_ref.entity());
+
+        // Look up the text node.
+        let Some((parent, node, transform, layout, block, font, color)) =
+            iter_children.search_descendants(children, &children_q, |c| text_nodes.get(c).ok())
+        else {
+            continue;
+        };
+        let text_top_left = transform.translation().truncate() - node.size() / 2.;
+
+        // Find the glyph bounds of the composition and caret spans (in physical pixels relative to the text node).
+        let mut preedit_bounds: Option<Rect> = None;
+        let mut caret_pos: Option<Vec2> = None;
+        for glyph in layout.glyphs.iter() {
+            let Some(span) = block.entities().get(glyph.span_index) else { continue };
+            let min = glyph.position - glyph.size / 2.;
+            let max = glyph.position + glyph.size / 2.;
+            match spans.get(span.entity) {
+                Ok(TextInputSpan::Preedit) => {
+                    let rect = Rect::from_corners(min, max);
+                    preedit_bounds = Some(preedit_bounds.map(|b| b.union(rect)).unwrap_or(rect));
+                }
+                Ok(TextInputSpan::Caret) => {
+                    caret_pos = Some(Vec2::new(min.x, max.y));
+                }
+                _ => (),
+            }
+        }
+
+        // Position the OS candidate window at the caret.
+        if let Ok(mut window) = windows.get_mut(window_ref.entity()) {
+            let caret = caret_pos.unwrap_or(Vec2::new(0., node.size().y));
+            let ime_position = (text_top_left + caret) / window.scale_factor();
+            if window.ime_position != ime_position {
+                window.ime_position = ime_position;
+            }
+        }
+
+        // Underline the composition.
+        let Some(bounds) = preedit_bounds else { continue };
+        let Ok((parent_node, parent_transform)) = parent_nodes.get(parent.get()) else { continue };
+        let parent_top_left = parent_transform.translation().truncate() - parent_node.size() / 2.;
+        let scale = parent_node.inverse_scale_factor();
+        let offset = (text_top_left - parent_top_left + Vec2::new(bounds.min.x, bounds.max.y)) * scale;
+        let underline_node = Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(offset.x),
+            top: Val::Px(offset.y),
+            width: Val::Px(bounds.width() * scale),
+            height: Val::Px((font.font_size / 14.).max(1.)),
+            ..default()
+        };
+
+        match preedit.underline.and_then(|e| underlines.get_mut(e).ok()) {
+            Some((mut existing, mut bg)) => {
+                if *existing != underline_node {
+                    *existing = underline_node;
+                }
+                bg.set_if_neq(BackgroundColor(color.0));
+            }
+            None => {
+                let underline = c
+                    .spawn((TextInputUnderline, underline_node, BackgroundColor(color.0), PickingBehavior::IGNORE))
+                    .set_parent(parent.get())
+                    .id();
+                preedit.underline = Some(underline);
+            }
+        }
+    }
+
+    // Enable IME in the window of the focused text input.
+    if *ime_window != focused_window {
+        if let Some(mut window) = ime_window.and_then(|w| windows.get_mut(w).ok()) {
+            window.ime_enabled = false;
+        }
+        if let Some(mut window) = focused_window.and_then(|w| windows.get_mut(w).ok()) {
+            window.ime_enabled = true;
+        }
+        *ime_window = focused_window;
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reactive component with the text of a [`TextInput`] widget.
+///
+/// Set this to change the text input's contents.
+#[derive(ReactComponent, Default, Debug, Clone, PartialEq, Deref, DerefMut)]
+pub struct TextInputValue(pub String);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Component with the cursor position of a [`TextInput`] widget.
+///
+/// Also provides the editing operations applied by the text input.
+#[derive(Component, Default, Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TextInputCursor
+{
+    /// Byte offset of the cursor in the text.
+    byte: usize,
+}
+
+impl TextInputCursor
+{
+    /// Makes a cursor at the given byte offset.
+    ///
+    /// The offset will be clamped to a valid position when the cursor is used.
+    pub fn new(byte: usize) -> Self
+    {
+        Self { byte }
+    }
+
+    /// Gets the byte offset of the cursor.
+    pub fn get(&self) -> usize
+    {
+        self.byte
+    }
+
+    /// Returns `true` if the cursor is at the end of the text.
+    pub fn is_at_end(&self, text: &str) -> bool
+    {
+        self.byte >= text.len()
+    }
+
+    fn clamp(&mut self, text: &str)
+    {
+        self.byte = self.byte.min(text.len());
+        while !text.is_char_boundary(self.byte) {
+            self.byte -= 1;
+        }
+    }
+
+    /// Inserts a string at the cursor and moves the cursor to the end of the inserted string.
+    ///
+    /// The inserted string is truncated so the text does not exceed `max_length` characters.
+    ///
+    /// Returns `false` if nothing was inserted.
+    pub fn insert(&mut self, text: &mut String, insert: &str, max_length: Option<usize>) -> bool
+    {
+        self.clamp(text);
+        let mut insert = insert;
+        if let Some(max_length) = max_length {
+            let available = max_length.saturating_sub(text.chars().count());
+            if let Some((idx, _)) = insert.char_indices().nth(available) {
+                insert = &insert[..idx];
+            }
+        }
+        if insert.is_empty() {
+            return false;
+        }
+        text.insert_str(self.byte, insert);
+        self.byte += insert.len();
+        true
+    }
+
+    /// Deletes the character before the cursor.
+    ///
+    /// Returns `false` if nothing was deleted.
+    pub fn delete_backward(&mut self, text: &mut String) -> bool
+    {
+        self.clamp(text);
+        let Some(c) = text[..self.byte].chars().next_back() else { return false };
+        self.byte -= c.len_utf8();
+        text.remove(self.byte);
+        true
+    }
+
+    /// Deletes the character after the cursor.
+    ///
+    /// Returns `false` if nothing was deleted.
+    pub fn delete_forward(&mut self, text: &mut String) -> bool
+    {
+        self.clamp(text);
+        if self.byte >= text.len() {
+            return false;
+        }
+        text.remove(self.byte);
+        true
+    }
+
+    /// Moves the cursor back one character.
+    pub fn move_left(&mut self, text: &str)
+    {
+        self.clamp(text);
+        if let Some(c) = text[..self.byte].chars().next_back() {
+            self.byte -= c.len_utf8();
+        }
+    }
+
+    /// Moves the cursor forward one character.
+    pub fn move_right(&mut self, text: &str)
+    {
+        self.clamp(text);
+        if let Some(c) = text[self.byte..].chars().next() {
+            self.byte += c.len_utf8();
+        }
+    }
+
+    /// Moves the cursor to the start of its line.
+    pub fn move_line_start(&mut self, text: &str)
+    {
+        self.clamp(text);
+        self.byte = line_start(text, self.byte);
+    }
+
+    /// Moves the cursor to the end of its line.
+    pub fn move_line_end(&mut self, text: &str)
+    {
+        self.clamp(text);
+        self.byte = line_end(text, self.byte);
+    }
+
+    /// Moves the cursor to the same column in the previous line, or to the start of the text if there is no
+    /// previous line.
+    ///
+    /// Lines are separated by `\n`. Wrapped lines are not considered.
+    pub fn move_up(&mut self, text: &str)
+    {
+        self.clamp(text);
+        let start = line_start(text, self.byte);
+        if start == 0 {
+            self.byte = 0;
+            return;
+        }
+        let column = text[start..self.byte].chars().count();
+        self.byte = advance_column(text, line_start(text, start - 1), column);
+    }
+
+    /// Moves the cursor to the same column in the next line, or to the end of the text if there is no next line.
+    ///
+    /// Lines are separated by `\n`. Wrapped lines are not considered.
+    pub fn move_down(&mut self, text: &str)
+    {
+        self.clamp(text);
+        let end = line_end(text, self.byte);
+        if end == text.len() {
+            self.byte = end;
+            return;
+        }
+        let column = text[line_start(text, self.byte)..self.byte].chars().count();
+        self.byte = advance_column(text, end + 1, column);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Component with the in-progress IME composition of a [`TextInput`] widget (e.g. for CJK input).
+///
+/// The composition is displayed inline at the cursor with an underline, using the font of the
+/// [`TextInputText`] node (e.g. a localized font).
+#[derive(Component, Default, Debug)]
+pub struct TextInputPreedit
+{
+    text: String,
+    /// Node that underlines the composition.
+    underline: Option<Entity>,
+}
+
+impl TextInputPreedit
+{
+    /// Gets the composition text.
+    ///
+    /// Empty if there is no composition.
+    pub fn get(&self) -> &str
+    {
+        &self.text
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource that tracks the currently-focused [`TextInput`].
+///
+/// Keyboard input is applied to the focused text input. Text inputs are focused when pressed, and unfocused
+/// when something else is pressed or `Escape` is pressed.
+#[derive(Resource, Default, Debug)]
+pub struct FocusedTextInput
+{
+    focused: Option<Entity>,
+}
+
+impl FocusedTextInput
+{
+    /// Gets the focused text input.
+    pub fn get(&self) -> Option<Entity>
+    {
+        self.focused
+    }
+
+    /// Sets the focused text input.
+    ///
+    /// Updates the [`TEXT_INPUT_FOCUSED_PSEUDO_STATE`] on the previous and new entities.
+    pub fn set(&mut self, c: &mut Commands, ps: &PseudoStateParam, focused: Option<Entity>)
+    {
+        if self.focused == focused {
+            return;
+        }
+        if let Some(prev) = self.focused {
+            ps.try_remove(c, prev, TEXT_INPUT_FOCUSED_PSEUDO_STATE.clone());
+        }
+        if let Some(next) = focused {
+            ps.try_insert(c, next, TEXT_INPUT_FOCUSED_PSEUDO_STATE.clone());
+        }
+        self.focused = focused;
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Pseudo state added to a [`TextInput`] while it is focused.
+///
+/// It can be used in COB as `Custom("Focused")`.
+pub const TEXT_INPUT_FOCUSED_PSEUDO_STATE: PseudoState = PseudoState::Custom(SmolStr::new_static("Focused"));
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Pseudo state added to a masked [`TextInput`] while it is revealed by a [`TextInputRevealButton`].
+///
+/// It can be used in COB as `Custom("Revealed")`.
+pub const TEXT_INPUT_REVEALED_PSEUDO_STATE: PseudoState = PseudoState::Custom(SmolStr::new_static("Revealed"));
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Holds the observer that reveals a [`TextInput`] when one of its reveal buttons is pressed.
+#[derive(Component, Debug)]
+struct TextInputRevealObserver(Entity);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Marks a [`TextInput`] that is revealed by a [`TextInputRevealButton`].
+#[derive(Component, Debug)]
+struct TextInputRevealed
+{
+    /// The pointer holding the reveal button.
+    pointer: PointerId,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reactive entity event sent to a [`TextInput`] when `Enter` is pressed (or `Ctrl + Enter` in multi-line text
+/// inputs).
+pub struct TextInputSubmit;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction loadable for a text input widget.
+///
+/// Inserts a [`React<TextInputValue>`](TextInputValue) and [`TextInputCursor`] to the entity. The text is
+/// displayed on a descendant with [`TextInputText`], and line numbers can be displayed on a descendant with
+/// [`TextInputLineNumbers`].
+///
+/// Multi-line text areas can be scrolled by placing the text inside a [`ScrollView`]. The scroll view will follow
+/// the end of the text while typing at the end.
+///
+/// Text inputs with a [`mask`](Self::mask) (e.g. passwords) display the mask character instead of the text. The
+/// text can be revealed while a descendant [`TextInputRevealButton`] is held down.
+///
+/// Example (COB):
+/**
+```rust
+"message"
+    TextInput{multiline:true max_length:500}
+    FlexNode{width:300px height:150px}
+    Multi<Responsive<BackgroundColor>>[
+        {idle:#222222}
+        {state:[Custom("Focused")] idle:#333333}
+    ]
+
+    "scroll"
+        ScrollBase
+        FlexNode{width:100% height:100% flex_direction:Row}
+
+        "view"
+            ScrollView
+            FlexNode{height:100% flex_grow:1 clipping:ScrollYClipX}
+
+            "shim"
+                ScrollShim
+                AbsoluteNode{width:100% flex_direction:Column}
+
+                "text"
+                    TextInputText
+                    TextLine{text:""}
+
+        "vertical"
+            ScrollBar{axis:Y}
+            FlexNode{width:8px height:100%}
+
+            "handle"
+                ScrollHandle
+                AbsoluteNode{width:100%}
+                BackgroundColor(#888888)
+```
+*/
+#[derive(Reflect, Component, Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct TextInput
+{
+    /// If `true`, then `Enter` inserts a new line and the arrow keys move between lines. Otherwise `Enter` sends
+    /// [`TextInputSubmit`].
+    ///
+    /// Defaults to `false`.
+    #[reflect(default)]
+    pub multiline: bool,
+    /// If `true`, then lines in multi-line text inputs are wrapped at word boundaries.
+    ///
+    /// Overrides the [`TextLayout`] line break of the [`TextInputText`] node.
+    ///
+    /// Defaults to `true`.
+    #[reflect(default = "TextInput::default_word_wrap")]
+    pub word_wrap: bool,
+    /// The maximum number of characters allowed in the text input.
+    ///
+    /// Defaults to `None`.
+    #[reflect(default)]
+    pub max_length: Option<usize>,
+    /// If set, then each character of the text is displayed as this character (e.g. `"•"` for passwords).
+    ///
+    /// The plain text is never written to the [`TextInputText`] node, unless revealed with a
+    /// [`TextInputRevealButton`].
+    ///
+    /// Defaults to `None`.
+    #[reflect(default)]
+    pub mask: Option<char>,
+}
+
+impl TextInput
+{
+    fn default_word_wrap() -> bool
+    {
+        true
+    }
+}
+
+impl Instruction for TextInput
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+
+        let has_value = emut.contains::<React<TextInputValue>>();
+        let role = match (self.mask, self.multiline) {
+            (Some(_), _) => AccessibleRole::PasswordInput,
+            (None, true) => AccessibleRole::MultilineTextInput,
+            (None, false) => AccessibleRole::TextInput,
+        };
+        role.apply_default(&mut emut);
+        emut.insert(self);
+        if !emut.contains::<TextInputCursor>() {
+            emut.insert((TextInputCursor::default(), TextInputPreedit::default()));
+        }
+        if !emut.contains::<TextInputRevealObserver>() {
+            let observer = world
+                .spawn(Observer::new(start_text_input_reveal).with_entity(entity))
+                .id();
+            world
+                .entity_mut(entity)
+                .insert(TextInputRevealObserver(observer));
+        }
+
+        if !has_value {
+            world.react(|rc| rc.insert(entity, TextInputValue::default()));
+        }
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        let preedit = emut.take::<TextInputPreedit>();
+        let reveal_observer = emut.take::<TextInputRevealObserver>();
+        emut.remove::<(Self, TextInputCursor, TextInputRevealed, React<TextInputValue>)>();
+        AccessibleRole::revert_default(&mut emut);
+        emut.remove_pseudo_state(TEXT_INPUT_FOCUSED_PSEUDO_STATE.clone());
+        emut.remove_pseudo_state(TEXT_INPUT_REVEALED_PSEUDO_STATE.clone());
+
+        if let Some(underline) = preedit.and_then(|p| p.underline) {
+            if let Ok(emut) = world.get_entity_mut(underline) {
+                emut.despawn_recursive();
+            }
+        }
+        if let Some(TextInputRevealObserver(observer)) = reveal_observer {
+            world.despawn(observer);
+        }
+
+        let mut focus = world.resource_mut::<FocusedTextInput>();
+        if focus.focused == Some(entity) {
+            focus.focused = None;
+        }
+    }
+}
+
+impl Default for TextInput
+{
+    fn default() -> Self
+    {
+        Self {
+            multiline: false,
+            word_wrap: Self::default_word_wrap(),
+            max_length: None,
+            mask: None,
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Loadable component for the node that displays a [`TextInput`]'s text.
+///
+/// The node should have a [`Text`] (e.g. with [`TextLine`]). Text spans for the cursor will be spawned as
+/// children of the node.
+#[derive(Reflect, Component, Default, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct TextInputText;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Loadable component for a node that displays a [`TextInput`]'s line numbers.
+///
+/// The node should have a [`Text`] (e.g. with [`TextLine`]). Line numbers count lines separated by `\n`, so they
+/// will not line up with wrapped lines.
+#[derive(Reflect, Component, Default, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct TextInputLineNumbers;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Loadable component for a button that reveals a masked [`TextInput`]'s text while held down.
+///
+/// The button must be a descendant of the text input.
+///
+/// Example (COB):
+/**
+```rust
+"password"
+    TextInput{mask:"•"}
+    FlexNode{width:200px height:30px flex_direction:Row}
+
+    "text"
+        TextInputText
+        TextLine{text:""}
+
+    "reveal"
+        TextInputRevealButton
+        FlexNode{width:24px height:24px}
+        Multi<Responsive<BackgroundColor>>[
+            {idle:#444444}
+            {state:[Custom("Revealed")] idle:#888888}
+        ]
+```
+*/
+#[derive(Reflect, Component, Default, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct TextInputRevealButton;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// System set where text input widgets are updated.
+///
+/// - **Update**: Keyboard input is applied to the focused text input.
+/// - **PostUpdate**: Text input values are written to their display nodes.
+///
+/// IME compositions are positioned after [`TransformPropagate`] in `PostUpdate`, outside this set.
+#[derive(SystemSet, Debug, Hash, Eq, PartialEq, Copy, Clone)]
+pub struct TextInputUpdateSet;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct CobwebTextInputPlugin;
+
+impl Plugin for CobwebTextInputPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_resource::<FocusedTextInput>()
+            .register_instruction_type::<TextInput>()
+            .register_component_type::<TextInputText>()
+            .register_component_type::<TextInputLineNumbers>()
+            .register_component_type::<TextInputRevealButton>()
+            .configure_sets(
+                PostUpdate,
+                TextInputUpdateSet
+                    .after(FileProcessingSet)
+                    .after(DynamicStylePostUpdate)
+                    .before(UiSystem::Prepare),
+            )
+            .add_systems(PreUpdate, update_text_input_focus.after(PickSet::Focus))
+            .add_systems(
+                Update,
+                (handle_text_input_keys, end_text_input_reveals).in_set(TextInputUpdateSet),
+            )
+            .add_systems(
+                PostUpdate,
+                (refresh_text_inputs, update_text_input_accessible_values).in_set(TextInputUpdateSet),
+            )
+            .add_systems(
+                PostUpdate,
+                refresh_text_input_ime
+                    .after(UiSystem::PostLayout)
+                    .after(TransformPropagate),
+            );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -1,40 +1,447 @@
+use std::sync::Arc;
+
+use bevy::prelude::TransformSystem::TransformPropagate;
+use bevy::prelude::*;
+use bevy::render::camera::NormalizedRenderTarget;
+use bevy::ui::UiSystem;
+use bevy::window::PrimaryWindow;
+use bevy::winit::cursor::{CursorIcon, CustomCursor};
+use bevy_cobweb::prelude::*;
+use smallvec::SmallVec;
+use smol_str::SmolStr;
+
+use crate::builtin::widgets::data_template::TemplateData;
+use crate::prelude::*;
+use crate::sickle::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Gets the area of overlap between a tooltip at `position` and a rect.
+fn overlap_area(position: Vec2, size: Vec2, rect: Rect) -> f32
+{
+    let overlap = Rect::from_corners(position, position + size).intersect(rect);
+    if overlap.is_empty() {
+        return 0.;
+    }
+    overlap.width() * overlap.height()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Moves a tooltip's top-left corner so the tooltip is inside the view.
+///
+/// Padding shrinks if the view is too small to include the tooltip and the padding. If the view is too small for
+/// the tooltip then the tooltip's top and left edges will stay inside the view.
+fn clamp_to_view(position: Vec2, size: Vec2, view: Rect, padding: f32) -> Vec2
+{
+    let padding = Vec2::splat(padding.max(0.)).min(((view.size() - size) * 0.5).max(Vec2::ZERO));
+    let min = view.min + padding;
+    let max = view.max - padding - size;
+    position.min(max).max(min)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Finds the custom cursor's rect in the viewport of a camera.
+fn get_cursor_rect(
+    camera: &Camera,
+    primary_window: &Query<Entity, With<PrimaryWindow>>,
+    windows: &Query<(&Window, Option<&CursorIcon>)>,
+    images: &Assets<Image>,
+) -> Option<Rect>
+{
+    let NormalizedRenderTarget::Window(window_ref) = camera.target.normalize(primary_window.get_single().ok())?
+    else {
+        return None;
+    };
+    let (window, maybe_icon) = windows.get(window_ref.entity()).ok()?;
+    let Some(CursorIcon::Custom(CustomCursor::Image { handle, hotspot })) = maybe_icon else { return None };
+    let image = images.get(handle)?;
+
+    let viewport_min = camera
+        .physical_viewport_rect()
+        .map(|r| r.min.as_vec2())
+        .unwrap_or_default();
+    let min = window.physical_cursor_position()? - viewport_min - Vec2::new(hotspot.0 as f32, hotspot.1 as f32);
+    Some(Rect::from_corners(min, min + image.size().as_vec2()))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Starts removing a tooltip.
+fn dismiss_tooltip(c: &mut Commands, ps: &PseudoStateParam, tooltip: Entity, fade_out: bool)
+{
+    if !fade_out {
+        if let Some(ec) = c.get_entity(tooltip) {
+            ec.despawn_recursive();
+        }
+        return;
+    }
+
+    c.entity(tooltip).try_insert(TooltipDying);
+    ps.try_insert(c, tooltip, TOOLTIP_DYING_PSEUDO_STATE);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Spawns and dismisses tooltips based on interactions with their source entities.
+fn update_tooltip_sources(
+    mut c: Commands,
+    mut s: SceneBuilder,
+    ps: PseudoStateParam,
+    parents: Query<&Parent>,
+    target_cameras: Query<&TargetCamera>,
+    tooltips: Query<(), (With<TooltipNode>, Without<TooltipDying>)>,
+    mut sources: Query<(
+        Entity,
+        &WithTooltip,
+        &mut TooltipSourceState,
+        &FluxInteraction,
+        Option<&TooltipData>,
+    )>,
+)
+{
+    for (entity, config, mut state, flux, maybe_data) in sources.iter_mut() {
+        // Pressing the source suppresses the tooltip until the pointer leaves.
+        match *flux {
+            FluxInteraction::Pressed if config.remove_on_press => state.suppressed = true,
+            FluxInteraction::None
+            | FluxInteraction::PointerLeave
+            | FluxInteraction::PressCanceled
+            | FluxInteraction::Disabled => state.suppressed = false,
+            _ => (),
+        }
+
+        let hovered = matches!(
+            *flux,
+            FluxInteraction::PointerEnter | FluxInteraction::Pressed | FluxInteraction::Released
+        );
+        let has_states = config
+            .state
+            .as_ref()
+            .is_none_or(|states| ps.entity_has_all(entity, states.iter()));
+        let show = hovered && has_states && !state.suppressed;
+
+        // Forget tooltips that were despawned elsewhere.
+        if state.tooltip.is_some_and(|t| !tooltips.contains(t)) {
+            state.tooltip = None;
+        }
+
+        match (show, state.tooltip) {
+            (true, None) => {
+                let mut spawned = None;
+                c.spawn_scene_and_edit(SceneRef::new(&config.file, &config.scene), &mut s, |h| {
+                    h.insert((
+                        TooltipNode { source: entity },
+                        PropagateOpacity(0.),
+                        PickingBehavior::IGNORE,
+                    ));
+                    if let Some(data) = maybe_data {
+                        h.insert(data.template_data());
+                    }
+                    spawned = Some(h.id());
+                });
+                let Some(tooltip) = spawned else { continue };
+                state.tooltip = Some(tooltip);
+
+                // Tooltips are UI roots, so they need to be placed in the same camera as the source.
+                let root = parents.iter_ancestors(entity).last().unwrap_or(entity);
+                if let Ok(camera) = target_cameras.get(root) {
+                    c.entity(tooltip).try_insert(camera.clone());
+                }
+
+                // Fade in, and fade out when dying.
+                c.entity(tooltip)
+                    .apply(Animated::<PropagateOpacity> {
+                        enter_ref_override: Some(0.),
+                        idle: 1.,
+                        enter_idle_with: config.fade_in.clone(),
+                        ..default()
+                    })
+                    .apply(Animated::<PropagateOpacity> {
+                        state: Some(SmallVec::from_elem(TOOLTIP_DYING_PSEUDO_STATE, 1)),
+                        idle: 0.,
+                        enter_idle_with: config.fade_out.clone(),
+                        ..default()
+                    })
+                    .on_event::<AnimationComplete<PropagateOpacity>>()
+                    .r(despawn_faded_tooltip);
+            }
+            (false, Some(tooltip)) => {
+                state.tooltip = None;
+                dismiss_tooltip(&mut c, &ps, tooltip, config.fade_out.is_some());
+            }
+            _ => (),
+        }
+    }
+}
 
 //-------------------------------------------------------------------------------------------------------------------
 
-/// Anchor point on a tooltip's parent where the tooltip should be positioned.
+/// Updates the [`TemplateData`] of displayed tooltips when their source's [`TooltipData`] changes.
+fn update_tooltip_data(mut c: Commands, sources: Query<(&TooltipData, &TooltipSourceState), Changed<TooltipData>>)
+{
+    for (data, state) in sources.iter() {
+        let Some(tooltip) = state.tooltip else { continue };
+        c.entity(tooltip).try_insert(data.template_data());
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Positions tooltips relative to their sources.
+///
+/// Tooltips are UI roots, so we can override their transforms after layout to avoid a frame of lag.
+fn update_tooltip_positions(
+    ui_camera: DefaultUiCamera,
+    cameras: Query<&Camera>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    windows: Query<(&Window, Option<&CursorIcon>)>,
+    images: Res<Assets<Image>>,
+    sources: Query<(&WithTooltip, &ComputedNode, &GlobalTransform)>,
+    mut tooltips: Query<(
+        &TooltipNode,
+        &ComputedNode,
+        Option<&TargetCamera>,
+        &mut Node,
+        &mut Transform,
+    )>,
+)
+{
+    for (tooltip, computed, maybe_camera, mut node, mut transform) in tooltips.iter_mut() {
+        let Ok((config, source_computed, source_transform)) = sources.get(tooltip.source) else { continue };
+        let Some(camera) = maybe_camera
+            .map(|c| c.entity())
+            .or_else(|| ui_camera.get())
+            .and_then(|e| cameras.get(e).ok())
+        else {
+            continue;
+        };
+        let Some(view_size) = camera.physical_viewport_size() else { continue };
+
+        if node.position_type != PositionType::Absolute {
+            node.position_type = PositionType::Absolute;
+        }
+
+        let source = Rect::from_center_size(source_transform.translation().truncate(), source_computed.size());
+        let size = computed.size();
+        let view = Rect::from_corners(Vec2::ZERO, view_size.as_vec2());
+        let cursor = config
+            .avoid_cursor
+            .then(|| get_cursor_rect(camera, &primary_window, &windows, &images))
+            .flatten();
+        let position =
+            config.compute_position(source, size, view, computed.inverse_scale_factor().recip(), cursor);
+
+        let center = position + size * 0.5;
+        if transform.translation.truncate() != center {
+            transform.translation.x = center.x;
+            transform.translation.y = center.y;
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Despawns tooltips that finished fading out.
+fn despawn_faded_tooltip(
+    mut c: Commands,
+    event: EntityEvent<AnimationComplete<PropagateOpacity>>,
+    dying: Query<(), With<TooltipDying>>,
+)
+{
+    let (entity, _) = event.read();
+    if !dying.contains(entity) {
+        return;
+    }
+    if let Some(ec) = c.get_entity(entity) {
+        ec.despawn_recursive();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Despawns tooltips that lost their source.
+fn cleanup_tooltips(mut c: Commands, sources: Query<(), With<WithTooltip>>, tooltips: Query<(Entity, &TooltipNode)>)
+{
+    for (entity, tooltip) in tooltips.iter() {
+        if !sources.contains(tooltip.source) {
+            c.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Tracks the tooltip spawned for a [`WithTooltip`] entity.
+#[derive(Component, Default, Debug)]
+struct TooltipSourceState
+{
+    tooltip: Option<Entity>,
+    /// Set when the source is pressed with [`WithTooltip::remove_on_press`].
+    suppressed: bool,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Marker component for tooltip roots.
+#[derive(Component, Debug)]
+struct TooltipNode
+{
+    source: Entity,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Marker component for tooltips that are fading out.
+#[derive(Component, Default, Debug)]
+struct TooltipDying;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Placement of a tooltip relative to its source node.
+#[derive(Debug, Copy, Clone)]
+struct TooltipPlacement
+{
+    /// Point on the source in normalized coordinates (`(0, 0)` is the top-left corner).
+    point: Vec2,
+    /// Point on the tooltip in normalized coordinates that is placed at the anchor point.
+    pivot: Vec2,
+    /// Direction from the anchor point toward the tooltip.
+    outward: Vec2,
+    offset: Vec2,
+}
+
+impl TooltipPlacement
+{
+    fn new(anchor: TooltipAnchor, alignment: TooltipAlignment, offset: Vec2) -> Self
+    {
+        let align = match alignment {
+            TooltipAlignment::Start => 0.,
+            TooltipAlignment::Center => 0.5,
+            TooltipAlignment::End => 1.,
+        };
+        let (point, pivot, outward) = match anchor {
+            TooltipAnchor::TopRight => ((1., 0.), (align, 1.), (0., -1.)),
+            TooltipAnchor::TopCenter => ((0.5, 0.), (align, 1.), (0., -1.)),
+            TooltipAnchor::TopLeft => ((0., 0.), (align, 1.), (0., -1.)),
+            TooltipAnchor::LeftCenter => ((0., 0.5), (1., align), (-1., 0.)),
+            TooltipAnchor::BottomLeft => ((0., 1.), (align, 0.), (0., 1.)),
+            TooltipAnchor::BottomCenter => ((0.5, 1.), (align, 0.), (0., 1.)),
+            TooltipAnchor::BottomRight => ((1., 1.), (align, 0.), (0., 1.)),
+            TooltipAnchor::RightCenter => ((1., 0.5), (0., align), (1., 0.)),
+            TooltipAnchor::CenterAlignTop => ((0.5, 0.5), (align, 0.), (0., 1.)),
+            TooltipAnchor::CenterAlignLeft => ((0.5, 0.5), (0., align), (1., 0.)),
+            TooltipAnchor::CenterAlignBottom => ((0.5, 0.5), (align, 1.), (0., -1.)),
+            TooltipAnchor::CenterAlignRight => ((0.5, 0.5), (1., align), (-1., 0.)),
+        };
+        Self {
+            point: point.into(),
+            pivot: pivot.into(),
+            outward: outward.into(),
+            offset,
+        }
+    }
+
+    /// Mirrors the placement across the source's center.
+    fn flipped(mut self, x: bool, y: bool) -> Self
+    {
+        if x {
+            self.point.x = 1. - self.point.x;
+            self.pivot.x = 1. - self.pivot.x;
+            self.outward.x = -self.outward.x;
+            self.offset.x = -self.offset.x;
+        }
+        if y {
+            self.point.y = 1. - self.point.y;
+            self.pivot.y = 1. - self.pivot.y;
+            self.outward.y = -self.outward.y;
+            self.offset.y = -self.offset.y;
+        }
+        self
+    }
+
+    /// Gets the top-left corner of the tooltip.
+    fn position(&self, source: Rect, size: Vec2) -> Vec2
+    {
+        source.min + self.point * source.size() + self.offset - self.pivot * size
+    }
+
+    /// Moves the tooltip in the outward direction until it no longer overlaps the cursor.
+    fn avoid(&self, mut position: Vec2, size: Vec2, cursor: Rect) -> Vec2
+    {
+        if overlap_area(position, size, cursor) <= 0. {
+            return position;
+        }
+        if self.outward.x < 0. {
+            position.x = cursor.min.x - size.x;
+        } else if self.outward.x > 0. {
+            position.x = cursor.max.x;
+        }
+        if self.outward.y < 0. {
+            position.y = cursor.min.y - size.y;
+        } else if self.outward.y > 0. {
+            position.y = cursor.max.y;
+        }
+        position
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Pseudo state added to tooltips while they fade out.
+///
+/// It can be used in COB as `Custom("Dying")`.
+pub const TOOLTIP_DYING_PSEUDO_STATE: PseudoState = PseudoState::Custom(SmolStr::new_static("Dying"));
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Anchor point on a tooltip's source node where the tooltip should be positioned.
+#[derive(Reflect, Default, Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
 pub enum TooltipAnchor
 {
-    /// Top-right corner of the parent node.
+    /// Top-right corner of the source node.
     TopRight,
-    /// Top-center point on the parent node.
+    /// Top-center point on the source node.
     #[default]
     TopCenter,
-    /// Top-left corner of the parent node.
+    /// Top-left corner of the source node.
     TopLeft,
-    /// Left-center point on the parent node.
+    /// Left-center point on the source node.
     LeftCenter,
-    /// Bottom-left corner of the parent node.
+    /// Bottom-left corner of the source node.
     BottomLeft,
-    /// Bottom-center point on the parent node.
+    /// Bottom-center point on the source node.
     BottomCenter,
-    /// Bottom-right corner of the parent node.
+    /// Bottom-right corner of the source node.
     BottomRight,
-    /// Right-center point on the parent node.
+    /// Right-center point on the source node.
     RightCenter,
-    /// The center of the parent node. The tooltip is aligned on its top edge.
+    /// The center of the source node. The tooltip is aligned on its top edge.
     CenterAlignTop,
-    /// The center of the parent node. The tooltip is aligned on its left edge.
+    /// The center of the source node. The tooltip is aligned on its left edge.
     CenterAlignLeft,
-    /// The center of the parent node. The tooltip is aligned on its bottom edge.
+    /// The center of the source node. The tooltip is aligned on its bottom edge.
     CenterAlignBottom,
-    /// The center of the parent node. The tooltip is aligned on its right edge.
+    /// The center of the source node. The tooltip is aligned on its right edge.
     CenterAlignRight,
 }
 
 //-------------------------------------------------------------------------------------------------------------------
 
-/// A tooltip's alignment on its edge facing the parent node. Without any offset, the anchor point and alignment
+/// A tooltip's alignment on its edge facing the source node. Without any offset, the anchor point and alignment
 /// point will overlap.
+#[derive(Reflect, Default, Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
 pub enum TooltipAlignment
 {
     /// `Top*`/`Bottom*` anchor: left bottom/top corner of the tooltip.
@@ -53,14 +460,58 @@ pub enum TooltipAlignment
 
 /// Component/instruction for setting up a tooltip associated with the current entity.
 ///
-/// The tooltip will spawn as a fresh UI scene when hovering the entity.
-pub struct Tooltip
+/// The tooltip scene is spawned as a new UI root when the entity is hovered, and despawned when the hover ends.
+/// Tooltips are positioned by overriding their `Transform` after layout, so the scene's root node will be made
+/// absolutely-positioned. Tooltips are not pickable, but their descendants are, so consider adding
+/// [`PickingBehavior::IGNORE`] to them. Add a [`GlobalZIndex`] to the scene's root node if the tooltip should
+/// be drawn above other UI roots.
+///
+/// Tooltips fade in and out with [`PropagateOpacity`]. While fading out, tooltips have the
+/// [`TOOLTIP_DYING_PSEUDO_STATE`].
+///
+/// Add [`TooltipData`] to the entity to display a data payload in the tooltip with
+/// [`DataBindings`](crate::builtin::widgets::data_template::DataBinding).
+///
+/// Example:
+/**
+```rust
+#scenes
+"tooltip"
+    GlobalZIndex(100)
+    FlexNode{padding:{top:4px bottom:4px left:6px right:6px}}
+    BackgroundColor(#000000)
+
+    "text"
+        TextLine{text:"Save the game"}
+
+"button"
+    FlexNode{width:100px height:40px}
+    WithTooltip{
+        file:"ui/main_menu.cob" scene:"tooltip"
+        anchor:TopCenter offset:(0.0 -5.0)
+        fade_in:{duration:0.15 delay:0.5} fade_out:{duration:0.15}
+        remove_on_press:true
+    }
+```
+*/
+#[derive(Component, Reflect, Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct WithTooltip
 {
-    /// State the source must have to display this tooltip.
-    #[reflect(Default)]
+    /// File of the tooltip scene.
+    pub file: String,
+    /// Tooltip scene spawned when the entity is hovered.
+    pub scene: String,
+
+    /// States the entity must have to display the tooltip.
+    #[reflect(default)]
     pub state: Option<SmallVec<[PseudoState; 3]>>,
 
-    /// The anchor point on the reference node for the tooltip.
+    /// The anchor point on the source node for the tooltip.
     ///
     /// Defaults to [`TooltipAnchor::TopCenter`].
     #[reflect(default)]
@@ -70,7 +521,8 @@ pub struct Tooltip
     /// Defaults to [`TooltipAlignment::Center`].
     #[reflect(default)]
     pub alignment: TooltipAlignment,
-    /// Offset from the anchor point to the alignment point.
+    /// Offset in logical pixels from the anchor point to the alignment point. Positive `y` moves the tooltip
+    /// down.
     ///
     /// Defaults to no offset.
     #[reflect(default)]
@@ -89,36 +541,38 @@ pub struct Tooltip
     /// Defaults to instantaneous.
     #[reflect(default)]
     pub fade_out: Option<AnimationConfig>,
-    /// If set, then the tooltip will fade-out when the entity is pressed.
+    /// If set, then the tooltip will fade-out when the entity is pressed. It will reappear on the next hover.
     ///
     /// Defaults to `false`.
     #[reflect(default)]
     pub remove_on_press: bool,
 
-    /// If set, then the tooltip will adjust its position to avoid overlap with the cursor if there is no room
-    /// on the reference node to move the cursor so that the tooltip will be fully visible.
+    /// If set, then the tooltip will be moved away from the cursor (in the direction of the anchor) if they
+    /// overlap.
     ///
     /// Cursor avoidance currently only works for custom cursors where the cursor size and hotspot are known. See
     /// [`CursorIcon`].
     ///
     /// Defaults to `true`.
-    //TODO: if no custom cursor, try to get cursor size from raw OS APIs (MacOS, Windows, X11; Wayland likely not supported)
+    //TODO: if no custom cursor, try to get cursor size from raw OS APIs (MacOS, Windows, X11; Wayland likely not
+    // supported)
     #[reflect(default = "WithTooltip::avoid_cursor_default")]
     pub avoid_cursor: bool,
-    /// If set, then the tooltip will be repositioned to stay inside the node's camera view (usually the primary window).
+    /// If set, then the tooltip will be repositioned to stay inside the node's camera view (usually the primary
+    /// window).
     ///
-    /// Repositioning is done by first trying to 'push' the tooltip away from the camera view's edges. If pushing results
-    /// in the tooltip overlapping with the 'offset box' around the edge of the reference node, then
-    /// the anchor point will be flipped away from the overlap.
+    /// Repositioning is done by first trying to 'push' the tooltip away from the camera view's edges. If pushing
+    /// results in the tooltip overlapping with the 'offset box' around the edge of the source node, then the
+    /// anchor point will be flipped away from the overlap.
     ///
-    /// If the camera view is too small to fit the tooltip, then its position will be adjusted so its top and left edges
-    /// stay within the camera view.
+    /// If the camera view is too small to fit the tooltip, then its position will be adjusted so its top and left
+    /// edges stay within the camera view.
     ///
     /// Defaults to `true`.
     #[reflect(default = "WithTooltip::stay_in_camera_default")]
     pub stay_in_camera: bool,
-    /// Minimum distance allowed between the tooltip and the camera edges. Only takes effect if `stay_in_camera`
-    /// is set.
+    /// Minimum distance in logical pixels allowed between the tooltip and the camera edges. Only takes effect if
+    /// `stay_in_camera` is set.
     ///
     /// Will shrink to zero if the camera is too small to include both the tooltip and the padding.
     ///
@@ -129,6 +583,11 @@ pub struct Tooltip
 
 impl WithTooltip
 {
+    pub fn new(file: impl Into<String>, scene: impl Into<String>) -> Self
+    {
+        Self { file: file.into(), scene: scene.into(), ..default() }
+    }
+
     fn avoid_cursor_default() -> bool
     {
         true
@@ -138,96 +597,174 @@ impl WithTooltip
     {
         true
     }
-}
 
-// Need WithTooltipReactor component to insert on reactor entities so they can be cleaned up on WithTooltip revert
-// Reactor entities should be child-ed to the WithTooltip entity
-
-// On tooltip spawn, make Animated<PropagateOpacity> to control the entry, and another tied to the Dying pseudostate
-// for fade-out.
-
-
-/*
-Events:
-- On tooltip activation (after delay).
-- On tooltip deactivation (after delay).
-
-- target entity: WithTooltip instruction
-    - on_pointer_enter, on_pressed: spawn tooltip, add HasTooltip component to hovered entity
-    - on_pointer_leave, on_released, on_press_canceled: remove HasTooltip component, add "Dying" pseudostate
-    (activates Animated<PropagateOpacity>), also add DespawnOnOpacityOut component which waits for PropagateOpacity to
-    reach zero then despawns
-        - DespawnOnOpacityOut checker system should be ordered in Last to make sure PropagateOpacity has a chance to be
-        inserted by the flux framework
-- tooltip
-    - has TooltipParent component
-    - has WindowClamp component
-    - has CenterPosition component
-        - includes absolute offset
-        - includes left/right and top/bottom  
-    - has Animated<PropagateOpacity> with delay for on_enter, and a second entry tied to "Dying" pseudostate
-    - system: update CenterPosition from TooltipParent, if parent is missing then despawn self
-    - system: 
-- demo
-    - make mock-draggable object with tooltip
-    - on trigger DragStart, insert ComputedCenteringDrag component with initial center position
-    - on trigger Drag, update ComputedCenteringDrag with distance traveled
-    - no need to remove ComputedCenteringDrag for the demo
-    - system: convert ComputedCenteringDrag to Transform
-
-- use TargetCamera on current node to detect which window we are in? camera -> render target -> window entity
-*/
+    /// Computes the top-left corner of a tooltip.
+    ///
+    /// All values are in physical pixels in the coordinate space of the camera view (`y` points down).
+    /// - `source`: Rect of the source node.
+    /// - `size`: Size of the tooltip.
+    /// - `view`: Rect of the camera view.
+    /// - `scale_factor`: Scale factor for converting [`Self::offset`] and [`Self::camera_padding`] to physical
+    ///   pixels.
+    /// - `cursor`: Rect of the cursor icon, for cursor avoidance.
+    pub fn compute_position(
+        &self,
+        source: Rect,
+        size: Vec2,
+        view: Rect,
+        scale_factor: f32,
+        cursor: Option<Rect>,
+    ) -> Vec2
+    {
+        let offset = self.offset * scale_factor;
+        let padding = self.camera_padding * scale_factor;
+        let mut placement = TooltipPlacement::new(self.anchor, self.alignment, offset);
+        let mut position = placement.position(source, size);
 
-/*
-// Reference solution from discord user:
+        if self.stay_in_camera {
+            let clamped = clamp_to_view(position, size, view, padding);
 
-use bevy::{math::Vec3A, prelude::*, ui::UiSystem, window::PrimaryWindow};
+            // Flip the tooltip to the other side of the source if pushing makes it overlap the source.
+            let offset_box = Rect::from_corners(source.min - offset.abs(), source.max + offset.abs());
+            let clamped_overlap = overlap_area(clamped, size, offset_box);
+            if clamped_overlap > overlap_area(position, size, offset_box) {
+                let flipped = placement.flipped(clamped.x != position.x, clamped.y != position.y);
+                let flipped_position = clamp_to_view(flipped.position(source, size), size, view, padding);
+                if overlap_area(flipped_position, size, offset_box) < clamped_overlap {
+                    placement = flipped;
+                    position = flipped_position;
+                } else {
+                    position = clamped;
+                }
+            } else {
+                position = clamped;
+            }
+        }
 
-pub fn plugin(app: &mut App) {
-    app.add_systems(
-        PostUpdate,
-        window_clamp.after(TransformSystem::TransformPropagate),
-    );
-    app.add_systems(
-        PostUpdate,
-        center_position
-            .after(UiSystem::Layout)
-            .before(TransformSystem::TransformPropagate),
-    );
+        if let Some(cursor) = cursor {
+            position = placement.avoid(position, size, cursor);
+            if self.stay_in_camera {
+                position = clamp_to_view(position, size, view, padding);
+            }
+        }
+
+        position
+    }
 }
 
-/// UI nodes with this component will position their center at the specified position.
-#[derive(Component)]
-pub struct CenterPosition {
-    pub position: Vec2,
+impl Default for WithTooltip
+{
+    fn default() -> Self
+    {
+        Self {
+            file: String::default(),
+            scene: String::default(),
+            state: None,
+            anchor: TooltipAnchor::default(),
+            alignment: TooltipAlignment::default(),
+            offset: Vec2::ZERO,
+            fade_in: None,
+            fade_out: None,
+            remove_on_press: false,
+            avoid_cursor: Self::avoid_cursor_default(),
+            stay_in_camera: Self::stay_in_camera_default(),
+            camera_padding: 0.,
+        }
+    }
 }
 
-/// UI nodes with this component will be moved to fit within the window size.
-#[derive(Component)]
-pub struct WindowClamp;
+impl Instruction for WithTooltip
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        validate_instruction_scene_ref::<Self>(world, SceneRef::new(&self.file, &self.scene));
+        Interactive.apply(entity, world);
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        emut.insert(self);
+        if !emut.contains::<TooltipSourceState>() {
+            emut.insert(TooltipSourceState::default());
+        }
+    }
 
-pub fn center_position(mut nodes: Query<(&mut Transform, &CenterPosition)>) {
-    for (mut transform, center) in &mut nodes {
-        transform.translation.x = center.position.x;
-        transform.translation.y = center.position.y;
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        emut.remove::<Self>();
+        let Some(state) = emut.take::<TooltipSourceState>() else { return };
+        if let Some(tooltip) = state.tooltip {
+            let _ = world.get_entity_mut(tooltip).map(|e| e.despawn_recursive());
+        }
     }
 }
 
-pub fn window_clamp(
-    mut nodes: Query<(&mut GlobalTransform, &ComputedNode), With<WindowClamp>>,
-    window: Single<&Window, With<PrimaryWindow>>,
-) {
-    let size = window.size();
-    for (mut transform, node) in &mut nodes {
-        let mut affine = transform.affine();
-        let half_size = node.size() / 2.0;
-        let min = (affine.translation.xy() - half_size).min(Vec2::ZERO);
-        let max = size - (affine.translation.xy() + half_size).max(size);
-        affine.translation += Vec3A::from((min + max).extend(0.0));
-        *transform = GlobalTransform::from(affine);
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Component with a reflected data payload for the tooltip of a [`WithTooltip`] entity.
+///
+/// When the tooltip spawns, a [`TemplateData`] with a copy of the payload is inserted on the tooltip's root node,
+/// so [`DataBindings`](crate::builtin::widgets::data_template::DataBinding) in the tooltip scene can display it.
+/// The tooltip's data is updated if this component changes while the tooltip is displayed.
+///
+/// This allows one tooltip scene to be used for many different items.
+///
+/// ```rust
+/// #[derive(Reflect, Clone)]
+/// struct ItemTooltip
+/// {
+///     title: String,
+/// }
+///
+/// c.ui_root().spawn_scene_and_edit(("items.cob", "item_slot"), &mut s, |h| {
+///     h.insert(TooltipData::new(ItemTooltip { title: "Sword".into() }));
+/// });
+/// ```
+///
+/// With the scenes:
+/// ```rust
+/// // items.cob
+/// #scenes
+/// "item_slot"
+///     FlexNode{width:40px height:40px}
+///     WithTooltip{file:"items.cob" scene:"item_tooltip"}
+///
+/// "item_tooltip"
+///     FlexNode{padding:{top:4px bottom:4px left:6px right:6px}}
+///     "title"
+///         TextLine
+///         DataBinding{text:"title"}
+/// ```
+#[derive(Component, Clone)]
+pub struct TooltipData
+{
+    make: Arc<dyn Fn() -> TemplateData + Send + Sync + 'static>,
+}
+
+impl TooltipData
+{
+    /// Makes new tooltip data from a reflected value.
+    pub fn new<T: PartialReflect + Clone>(data: T) -> Self
+    {
+        Self { make: Arc::new(move || TemplateData::new(data.clone())) }
+    }
+
+    /// Makes a [`TemplateData`] with a copy of the payload.
+    pub fn template_data(&self) -> TemplateData
+    {
+        (self.make)()
+    }
+
+    /// Replaces the data payload.
+    pub fn set<T: PartialReflect + Clone>(&mut self, data: T)
+    {
+        *self = Self::new(data);
     }
 }
-*/
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// System set in `PostUpdate` where tooltips are positioned.
+#[derive(SystemSet, Debug, Hash, Eq, PartialEq, Copy, Clone)]
+pub struct TooltipUpdateSet;
 
 //-------------------------------------------------------------------------------------------------------------------
 
@@ -239,14 +776,16 @@ impl Plugin for CobwebTooltipPlugin
     {
         // TODO: re-enable once COB scene macros are implemented
         //load_embedded_scene_file!(app, "bevy_cobweb_ui", "src/builtin/widgets/tooltip", "tooltip.cob");
-        app.register_instruction_type::<TooltipSource>()
-            .register_instruction_type::<Tooltip>()
-            .add_systems(
+        app.register_instruction_type::<WithTooltip>()
+            .configure_sets(
                 PostUpdate,
-                update_tooltip_positions
+                TooltipUpdateSet
                     .after(UiSystem::Layout)
                     .before(TransformPropagate),
-            );
+            )
+            .add_systems(Update, (update_tooltip_sources, update_tooltip_data).chain())
+            .add_systems(PostUpdate, update_tooltip_positions.in_set(TooltipUpdateSet))
+            .add_systems(Last, cleanup_tooltips);
     }
 }
 
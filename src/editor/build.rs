@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use bevy::prelude::*;
-use bevy::reflect::TypeRegistry;
+use bevy::reflect::{ReflectRef, TypeInfo, TypeRegistry};
 use bevy::render::camera::RenderTarget;
 use bevy::window::{EnabledButtons, PrimaryWindow, WindowRef, WindowResolution, WindowTheme};
 use bevy_cobweb::prelude::*;
@@ -18,49 +18,156 @@ struct EditorFileSelection(Option<CobFile>);
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Collects the fields of a struct, tuple struct, tuple, or enum variant.
+///
+/// Returns `None` if the value can't be destructured.
+fn destructure_fields<'a>(
+    value: &'a (dyn PartialReflect + 'static),
+) -> Option<Vec<(String, ReflectStructurePoint, &'a (dyn PartialReflect + 'static))>>
+{
+    let mut fields = vec![];
+    match value.reflect_ref() {
+        ReflectRef::Struct(dyn_struct) => {
+            let Some(TypeInfo::Struct(info)) = value.get_represented_type_info() else { return None };
+            for (idx, field) in dyn_struct.iter_fields().enumerate() {
+                let name = info.field_at(idx)?.name();
+                fields.push((String::from(name), ReflectStructurePoint::Struct(name), field));
+            }
+        }
+        ReflectRef::TupleStruct(dyn_tuplestruct) => {
+            for (idx, field) in dyn_tuplestruct.iter_fields().enumerate() {
+                fields.push((format!("{idx}"), ReflectStructurePoint::TupleStruct(idx), field));
+            }
+        }
+        ReflectRef::Tuple(dyn_tuple) => {
+            for (idx, field) in dyn_tuple.iter_fields().enumerate() {
+                fields.push((format!("{idx}"), ReflectStructurePoint::Tuple(idx), field));
+            }
+        }
+        ReflectRef::Enum(dyn_enum) => {
+            let Some(TypeInfo::Enum(info)) = value.get_represented_type_info() else { return None };
+            let variant_name = info.variant(dyn_enum.variant_name())?.name();
+            for (idx, field) in dyn_enum.iter_fields().enumerate() {
+                let name = field.name().map(String::from).unwrap_or_else(|| format!("{idx}"));
+                fields.push((name, ReflectStructurePoint::Enum(variant_name, idx), field.value()));
+            }
+        }
+        _ => return None,
+    }
+
+    Some(fields)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Tries to spawn a registered widget for a value.
+///
+/// Returns `None` if there is no widget for the value's type.
+fn try_build_widget(
+    h: &mut UiSceneHandle,
+    widgets: &CobWidgetRegistry,
+    editor_ref: &CobEditorRef,
+    value: &(dyn PartialReflect + 'static),
+) -> Option<bool>
+{
+    let type_path = value.get_represented_type_info()?.type_path();
+    let spawn_fn = widgets.get(type_path)?;
+    let content_entity = h.id();
+    let (loader, builder) = h.inner();
+    Some((spawn_fn)(builder.commands(), loader, content_entity, editor_ref, value))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Destructures a value and spawns widgets for its internal values.
+///
+/// Returns `false` if the value can't be destructured.
+fn build_destructured(
+    h: &mut UiSceneHandle,
+    widgets: &CobWidgetRegistry,
+    editor_ref: &CobEditorRef,
+    value: &(dyn PartialReflect + 'static),
+) -> bool
+{
+    let Some(fields) = destructure_fields(value) else { return false };
+
+    // Fieldless enum variants display the variant name.
+    if fields.is_empty() {
+        let ReflectRef::Enum(dyn_enum) = value.reflect_ref() else { return false };
+        let variant = String::from(dyn_enum.variant_name());
+        h.spawn_scene_and_edit(("editor.frame", "value_text"), |h| {
+            h.update(move |id: TargetId, mut e: TextEditor| {
+                write_text!(e, *id, "{}", variant.as_str());
+            });
+        });
+        return true;
+    }
+
+    for (name, point, field) in fields {
+        h.spawn_scene_and_edit(("editor.frame", "field"), |h| {
+            h.get("name")
+                .update(move |id: TargetId, mut e: TextEditor| {
+                    write_text!(e, *id, "{}:", name.as_str());
+                });
+
+            let field_ref = CobEditorRef {
+                structure_path: editor_ref.structure_path.extend(point),
+                ..editor_ref.clone()
+            };
+            h.edit("content", |h| {
+                match try_build_widget(h, widgets, &field_ref, field) {
+                    Some(true) => (),
+                    Some(false) => {
+                        h.spawn_scene(("editor.frame", "unsupported"));
+                    }
+                    None => {
+                        if !build_destructured(h, widgets, &field_ref, field) {
+                            h.spawn_scene(("editor.frame", "destructure_unsupported"));
+                        }
+                    }
+                }
+            });
+        });
+    }
+
+    true
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 fn build_widgets(
     h: &mut UiSceneHandle,
     widgets: &CobWidgetRegistry,
     file_hash: CobFileHash,
     scene_ref: SceneRef,
-    longname: &'static str,
     shortname: &'static str,
     loadable: Box<dyn PartialReflect + 'static>,
     death_signal: DeathSignal,
 )
 {
+    let editor_ref = CobEditorRef {
+        file_hash,
+        scene_ref,
+        loadable_name: shortname,
+        structure_path: ReflectStructurePath { path: Arc::from([]) },
+        death_signal,
+    };
+
     // Check for loadable widget
-    if let Some(spawn_fn) = widgets.get(longname) {
-        let content_entity = h.id();
-        let editor_ref = CobEditorRef {
-            file_hash,
-            scene_ref,
-            loadable_name: shortname,
-            structure_path: ReflectStructurePath { path: Arc::from([]) },
-            death_signal,
-        };
-        let (loader, builder) = h.inner();
-        if !(spawn_fn)(
-            builder.commands(),
-            loader,
-            content_entity,
-            &editor_ref,
-            loadable.as_ref(),
-        ) {
+    if let Some(success) = try_build_widget(h, widgets, &editor_ref, loadable.as_ref()) {
+        if !success {
             h.spawn_scene(("editor.frame", "destructure_unsupported"));
         }
-
         return;
     }
 
-    // Fallback
-    h.spawn_scene(("editor.frame", "destructure_unsupported"));
-
-    // TODO: Destructure and look for widgets for internal values
+    // Fallback: destructure and look for widgets for internal values
     // - TODO: If no widget found for an enum, provide a drop-down.
-    // - TODO: If no widget found for a primitive type, serialize it to COB and display it directly.
     // - TODO: If no widget found for a set, display "<cannot destructure sets>" (you can only add/remove entries,
     //   TODO)
+    if !build_destructured(h, widgets, &editor_ref, loadable.as_ref()) {
+        h.spawn_scene(("editor.frame", "destructure_unsupported"));
+    }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -77,7 +184,7 @@ fn build_loadable(
 {
     // Look up loadable type
     let name = loadable.id.to_canonical(None);
-    let Some((deserializer, _, longname, shortname)) = get_deserializer(registry, name.as_str(), loadables) else {
+    let Some((deserializer, _, _, shortname)) = get_deserializer(registry, name.as_str(), loadables) else {
         h.spawn_scene(("editor.frame", "unsupported"));
         return;
     };
@@ -99,7 +206,7 @@ fn build_loadable(
                     let (signaler, signal) = DeathSignaler::new();
                     h.insert(signaler);
 
-                    build_widgets(h, widgets, file_hash, scene_ref, longname, shortname, reflected, signal);
+                    build_widgets(h, widgets, file_hash, scene_ref, shortname, reflected, signal);
                 });
             }
             Err(_) => {
@@ -134,6 +241,27 @@ fn spawn_scene_layer(
                 write_text!(e, *id, "\"{}\"", ref_path.iter().rev().next().unwrap());
             });
 
+        // Toggle node selection when the name is pressed.
+        let pressed_ref = scene_ref.clone();
+        h.get("name")
+            .on_pressed(move |mut c: Commands, selection: Res<EditorNodeSelection>| {
+                let next = (selection.selected() != Some(&pressed_ref)).then(|| pressed_ref.clone());
+                c.queue(SelectEditorNode(next));
+            });
+
+        // Show the selection state.
+        let selected_ref = scene_ref.clone();
+        h.get("name").update_on(
+            broadcast::<EditorNodeSelected>(),
+            move |id: TargetId, mut c: Commands, selection: Res<EditorNodeSelection>| {
+                if selection.selected() == Some(&selected_ref) {
+                    c.react().entity_event(*id, Select);
+                } else {
+                    c.react().entity_event(*id, Deselect);
+                }
+            },
+        );
+
         // Add entries.
         h.edit("content", |h| {
             for entry in layer.entries.iter() {
@@ -169,12 +297,17 @@ fn build_file_view(In((base_entity, file)): In<(Entity, CobFile)>, mut c: Comman
     // - We do this roundabout via a reactor in order to auto-rebuild when the file data changes.
     let mut ec = c.entity(base_entity);
     ec.update_on(
-        (broadcast::<EditorFileExternalChange>(), broadcast::<EditorFileSaved>()),
+        (
+            broadcast::<EditorFileExternalChange>(),
+            broadcast::<EditorFileSaved>(),
+            broadcast::<EditorFileUndoRedo>(),
+        ),
         move |//
             _: TargetId,
             mut tracked_hash: Local<Option<CobFileHash>>,
             external_change: BroadcastEvent<EditorFileExternalChange>,
             file_saved: BroadcastEvent<EditorFileSaved>,
+            undo_redo: BroadcastEvent<EditorFileUndoRedo>,
             mut c: Commands,
             mut s: SceneBuilder,
             registry: Res<AppTypeRegistry>,
@@ -188,6 +321,12 @@ fn build_file_view(In((base_entity, file)): In<(Entity, CobFile)>, mut c: Comman
                     return;
                 }
             }
+            // Undo/redo changes file data without changing the file hash, so existing widgets display stale values.
+            if let Ok(undo_redo) = undo_redo.try_read() {
+                if undo_redo.file != file {
+                    return;
+                }
+            }
             if let Ok(file_saved) = file_saved.try_read() {
                 // We watch for when the file gets saved. If saving a file makes a new file hash, then
                 // existing widgets are invalid and need to be replaced.
@@ -246,7 +385,8 @@ fn build_file_view(In((base_entity, file)): In<(Entity, CobFile)>, mut c: Comman
                         let CobSection::Scenes(scenes) = s else { return None };
                         Some(scenes)
                     }) {
-                        for scene_layer in scenes_section.scenes.iter() {
+                        // Patches edit scenes owned by other files.
+                        for scene_layer in scenes_section.scenes.iter().filter(|s| s.patch.is_none()) {
                             spawn_scene_layer(
                                 h,
                                 &registry,
@@ -407,6 +547,24 @@ fn build_editor_view(mut c: Commands, mut s: SceneBuilder, camera: Query<Entity,
                     }
                 });
 
+            // Show the file of the selected node.
+            h.update_on(
+                broadcast::<EditorNodeSelected>(),
+                move |//
+                    _: TargetId,
+                    mut c: Commands,
+                    node: Res<EditorNodeSelection>,
+                    selection: Res<EditorFileSelection>//
+                | {
+                    let Some(SceneFile::File(file)) = node.selected().map(|s| &s.file) else { return };
+                    if (**selection).as_ref() == Some(file) {
+                        return;
+                    }
+                    c.react().entity_event(dropdown_entity, Some(file.clone()));
+                    c.react().entity_event(dropdown_entity, Close);
+                },
+            );
+
             // On EditorFileLost (TODO?)
             // - If currently-selected option is not in file list, then send empty file as entity event to self.
             // - if open, close and re-open
@@ -452,51 +610,6 @@ fn build_editor_view(mut c: Commands, mut s: SceneBuilder, camera: Query<Entity,
 
 //-------------------------------------------------------------------------------------------------------------------
 
-// TODO: try to make auto-moving the window smoother
-// - winit supports 'child windows' which should solve these problems. Need to manually construct the window, see
-//   WinitWindow::create_window.
-// TODO: the editor's position does not sync with the window on startup until you move the window
-// - maybe infer it from window starting size + monitor dimensions?
-// TODO: the editor does not sync properly if you shrink the window from the top down
-// TODO: give user option to make the editor be free-floating and resizable and spawn on the left side of
-// the app
-fn refresh_editor_window(
-    primary_win: Query<&Window, (With<PrimaryWindow>, Without<EditorWindow>)>,
-    mut editor_win: Query<&mut Window, (With<EditorWindow>, Without<PrimaryWindow>)>,
-)
-{
-    let primary_window = primary_win.single();
-    let mut editor_window = editor_win.single_mut();
-
-    // Check if the editor's position needs to change.
-    // TODO: incorporate MacOS 'content area' to avoid overlapping with the dock when on left side
-    // - https://stackoverflow.com/a/42898625
-    // - https://github.com/rustunit/bevy_device_lang/blob/main/src/apple.rs
-    let WindowPosition::At(primary_pos) = primary_window.position else { return };
-    let mut desired_pos = primary_pos;
-    desired_pos.x -= (EDITOR_WIDTH * primary_window.resolution.scale_factor()) as i32;
-    desired_pos.x = desired_pos.x.max(0);
-
-    if WindowPosition::At(desired_pos) != editor_window.position {
-        editor_window.position = WindowPosition::At(desired_pos);
-    }
-
-    // Check if the editor's height needs to change.
-    let primary_height = primary_window.resolution.size().y;
-
-    if primary_height != editor_window.resolution.size().y {
-        editor_window.resolution.set(EDITOR_WIDTH, primary_height);
-    }
-}
-
-//-------------------------------------------------------------------------------------------------------------------
-
-// TODO: don't hard-code this, use a resource instead? maybe use resource on startup then allow resizes and
-// any resize change gets saved back the to resource, which can then be saved back to user settings
-const EDITOR_WIDTH: f32 = 300.0;
-
-//-------------------------------------------------------------------------------------------------------------------
-
 /// Marker component for the editor's window.
 #[derive(Component, Debug)]
 pub(crate) struct EditorWindow;
@@ -516,6 +629,12 @@ impl Plugin for CobEditorBuildPlugin
 {
     fn build(&self, app: &mut App)
     {
+        let settings = app
+            .world()
+            .get_resource::<EditorWindowSettings>()
+            .cloned()
+            .unwrap_or_default();
+
         // Get primary window's starting height.
         let mut query = app
             .world_mut()
@@ -524,16 +643,17 @@ impl Plugin for CobEditorBuildPlugin
         let initial_height = primary_window.resolution.size().y;
 
         // Make editor window.
+        let docked = settings.mode == EditorWindowMode::Docked;
         let mut resolution = WindowResolution::new(0., 0.);
-        resolution.set(EDITOR_WIDTH, initial_height); // TODO: don't hard-code the width, and maybe don't fix height to primary?
+        resolution.set(settings.width, initial_height);
         let editor_window = app
             .world_mut()
             .spawn((
                 Window {
-                    title: "Cob Editor".into(),
+                    title: settings.title.clone(),
                     resolution,
-                    resizable: false,
-                    enabled_buttons: EnabledButtons { minimize: false, maximize: false, close: false },
+                    resizable: !docked,
+                    enabled_buttons: EnabledButtons { minimize: !docked, maximize: !docked, close: false },
                     window_theme: Some(WindowTheme::Dark), // TODO: don't hard-code this?
                     ..default()
                 },
@@ -554,6 +674,7 @@ impl Plugin for CobEditorBuildPlugin
 
         app.add_plugins(CobEditorTemplatePlugin)
             .init_resource::<EditorFileSelection>()
+            .insert_resource(settings)
             .add_systems(First, refresh_editor_window)
             .add_systems(OnEnter(LoadState::Done), build_editor_view);
     }
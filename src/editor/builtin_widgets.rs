@@ -0,0 +1,192 @@
+use bevy::prelude::*;
+
+use super::*;
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Value change per logical pixel dragged for unbounded numbers.
+const NUMBER_DRAG_STEP: f32 = 0.1;
+/// Value change per logical pixel dragged for color channels.
+const COLOR_DRAG_STEP: f32 = 0.004;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Tracks the current value of a draggable number field.
+#[derive(Component, Debug)]
+struct EditorDragValue(f32);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Accesses a channel of an sRGBA color.
+type SrgbaChannelFn = fn(&mut Srgba) -> &mut f32;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Tracks the current value of a color widget.
+#[derive(Component, Debug)]
+struct EditorColorValue(Srgba);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Spawns a number field that changes value when its value box is dragged horizontally.
+///
+/// The `on_change` callback is invoked with the new value whenever the value changes.
+fn spawn_drag_field(
+    h: &mut UiSceneHandle,
+    name: &'static str,
+    initial: f32,
+    step: f32,
+    bounds: Option<(f32, f32)>,
+    on_change: impl Fn(&mut Commands, f32) + Send + Sync + 'static,
+)
+{
+    h.spawn_scene_and_edit(("editor.frame", "drag_field"), |h| {
+        let field_entity = h.id();
+        h.insert(EditorDragValue(initial));
+
+        h.get("name")
+            .update(move |id: TargetId, mut e: TextEditor| {
+                write_text!(e, *id, "{}", name);
+            });
+
+        let text_entity = h.get("value::text").id();
+        h.get("value::text")
+            .update(move |id: TargetId, mut e: TextEditor| {
+                write_text!(e, *id, "{:.2}", initial);
+            });
+
+        // Update the value when dragged.
+        h.get("value")
+            .entity_commands()
+            .observe(
+                move |//
+                    mut drag: Trigger<Pointer<Drag>>,
+                    mut c: Commands,
+                    mut values: Query<&mut EditorDragValue>,
+                    mut e: TextEditor//
+                | {
+                    drag.propagate(false);
+                    let Ok(mut value) = values.get_mut(field_entity) else { return };
+                    let mut new_value = value.0 + drag.delta.x * step;
+                    if let Some((min, max)) = bounds {
+                        new_value = new_value.clamp(min, max);
+                    }
+                    if new_value == value.0 {
+                        return;
+                    }
+                    value.0 = new_value;
+                    write_text!(e, text_entity, "{:.2}", new_value);
+                    (on_change)(&mut c, new_value);
+                },
+            );
+    });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Editor widget for `f32` values. Drag the value horizontally to change it.
+struct F32Widget;
+
+impl CobEditorWidget for F32Widget
+{
+    type Value = f32;
+
+    fn try_spawn(
+        c: &mut Commands,
+        s: &mut SceneBuilderInner,
+        parent: Entity,
+        editor_ref: &CobEditorRef,
+        value: &(dyn PartialReflect + 'static),
+    ) -> bool
+    {
+        let Some(initial) = f32::from_reflect(value) else { return false };
+
+        let editor_ref = editor_ref.clone();
+        c.ui_builder(parent)
+            .spawn_scene_and_edit(("editor.frame", "number_widget"), s, |h| {
+                spawn_drag_field(h, "", initial, NUMBER_DRAG_STEP, None, move |c, value| {
+                    c.queue(SubmitPatch { editor_ref: editor_ref.clone(), value: Box::new(value) });
+                });
+            });
+
+        true
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Editor widget for [`Color`] values. The color is edited as sRGBA channels.
+struct ColorWidget;
+
+impl CobEditorWidget for ColorWidget
+{
+    type Value = Color;
+
+    fn try_spawn(
+        c: &mut Commands,
+        s: &mut SceneBuilderInner,
+        parent: Entity,
+        editor_ref: &CobEditorRef,
+        value: &(dyn PartialReflect + 'static),
+    ) -> bool
+    {
+        let Some(initial) = Color::from_reflect(value) else { return false };
+        let initial = initial.to_srgba();
+        let editor_ref = editor_ref.clone();
+
+        c.ui_builder(parent)
+            .spawn_scene_and_edit(("editor.frame", "color_widget"), s, |h| {
+                let widget_entity = h.id();
+                h.insert(EditorColorValue(initial));
+
+                let swatch_entity = h.get("swatch").id();
+                h.get("swatch").insert(BackgroundColor(initial.into()));
+
+                h.edit("channels", |h| {
+                    let channels: [(&'static str, f32, SrgbaChannelFn); 4] = [
+                        ("r", initial.red, |c| &mut c.red),
+                        ("g", initial.green, |c| &mut c.green),
+                        ("b", initial.blue, |c| &mut c.blue),
+                        ("a", initial.alpha, |c| &mut c.alpha),
+                    ];
+
+                    for (name, channel_initial, channel) in channels {
+                        let editor_ref = editor_ref.clone();
+                        let on_change = move |c: &mut Commands, value: f32| {
+                            let editor_ref = editor_ref.clone();
+                            c.queue(move |world: &mut World| {
+                                let Some(mut color) = world.get_mut::<EditorColorValue>(widget_entity) else {
+                                    return;
+                                };
+                                *channel(&mut color.0) = value;
+                                let new_color = Color::Srgba(color.0);
+                                if let Ok(mut swatch) = world.get_entity_mut(swatch_entity) {
+                                    swatch.insert(BackgroundColor(new_color));
+                                }
+                                SubmitPatch { editor_ref, value: Box::new(new_color) }.apply(world);
+                            });
+                        };
+                        spawn_drag_field(h, name, channel_initial, COLOR_DRAG_STEP, Some((0., 1.)), on_change);
+                    }
+                });
+            });
+
+        true
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(super) struct CobBuiltinEditorWidgetsPlugin;
+
+impl Plugin for CobBuiltinEditorWidgetsPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.register_editor_widget::<F32Widget>()
+            .register_editor_widget::<ColorWidget>();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
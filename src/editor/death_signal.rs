@@ -40,10 +40,22 @@ pub(crate) struct DeathSignal
 
 impl DeathSignal
 {
+    /// Makes a signal that is never set.
+    pub(crate) fn alive() -> Self
+    {
+        Self { signal: Arc::new(AtomicBool::new(false)) }
+    }
+
     pub(crate) fn is_dead(&self) -> bool
     {
         self.signal.load(Ordering::Relaxed)
     }
+
+    /// Returns `true` if both signals are paired with the same `DeathSignaler`.
+    pub(crate) fn same_as(&self, other: &Self) -> bool
+    {
+        Arc::ptr_eq(&self.signal, &other.signal)
+    }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
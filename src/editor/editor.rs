@@ -118,6 +118,10 @@ impl CobEditor
                 .broadcast(EditorFileSaved { file: data.file.clone(), hash });
         }
 
+        // Discard undo history, which points into the old data.
+        let file = data.file.clone();
+        c.queue(move |world: &mut World| world.resource_mut::<EditorStack>().remove_file(&file));
+
         // Save new data.
         existing.last_save_hash = hash;
         existing.data = data.clone();
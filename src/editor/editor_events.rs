@@ -41,3 +41,26 @@ pub struct EditorFileSaved
 }
 
 //-------------------------------------------------------------------------------------------------------------------
+
+/// Reactive event broadcasted when an undo or redo changes a file's data in the editor.
+///
+/// See [`EditorCommands`].
+#[derive(Debug, Clone)]
+pub struct EditorFileUndoRedo
+{
+    pub file: CobFile,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reactive event broadcasted when the scene node selected in the editor changes.
+///
+/// See [`SelectEditorNode`].
+#[derive(Debug, Clone)]
+pub struct EditorNodeSelected
+{
+    /// The newly-selected node, or `None` if the selection was cleared.
+    pub scene_ref: Option<SceneRef>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -1,4 +1,217 @@
-//! Tracks information needed to reconstruct an editor's 'view state' on reload. E.g. which tabs are open, which
-//! boxes checked, etc.
+//! Tracks editor changes so they can be undone and redone.
 
-// TODO
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
+
+use super::*;
+use crate::builtin::widgets::text_input::FocusedTextInput;
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Maximum number of changes that can be undone.
+const MAX_EDITOR_STACK_LEN: usize = 256;
+
+/// Changes made by the same widget within this many seconds of each other are merged into one undo step.
+///
+/// This collapses e.g. slider drags into single undo steps.
+const EDITOR_STACK_COALESCE_SECS: f64 = 0.5;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Applies undo/redo keyboard shortcuts.
+///
+/// - `Ctrl + Z`: undo
+/// - `Ctrl + Shift + Z` or `Ctrl + Y`: redo
+fn handle_editor_stack_shortcuts(
+    mut c: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    focused_input: Res<FocusedTextInput>,
+)
+{
+    // Text inputs handle their own keyboard input.
+    if focused_input.get().is_some() {
+        return;
+    }
+    if !keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]) {
+        return;
+    }
+    let shift = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+
+    if keys.just_pressed(KeyCode::KeyZ) {
+        match shift {
+            true => c.redo(),
+            false => c.undo(),
+        }
+    } else if keys.just_pressed(KeyCode::KeyY) {
+        c.redo();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A change made in the editor.
+struct EditorStackEntry
+{
+    /// Points to the changed loadable.
+    ///
+    /// The original structure path of the change is replaced with an empty path, since entries record entire
+    /// loadable values.
+    editor_ref: CobEditorRef,
+    /// Death signal of the widget that made the change. Used to coalesce changes.
+    source: DeathSignal,
+    /// The loadable value before the change.
+    before: Box<dyn PartialReflect + 'static>,
+    /// The loadable value after the change.
+    after: Box<dyn PartialReflect + 'static>,
+    /// Time when the entry was last updated, in seconds.
+    time: f64,
+}
+
+impl EditorStackEntry
+{
+    /// Makes a patch that sets the entry's loadable value.
+    ///
+    /// The file hash is refreshed since entries remain valid when the editor saves a file.
+    fn make_patch(&self, world: &World, value: &(dyn PartialReflect + 'static)) -> Option<SubmitPatch>
+    {
+        let SceneFile::File(file) = &self.editor_ref.scene_ref.file else { return None };
+        let file_hash = world.resource::<CobEditor>().get_file(file)?.last_save_hash;
+        let editor_ref = CobEditorRef {
+            file_hash,
+            death_signal: DeathSignal::alive(),
+            ..self.editor_ref.clone()
+        };
+        Some(SubmitPatch { editor_ref, value: value.clone_value() })
+    }
+
+    fn file(&self) -> Option<&CobFile>
+    {
+        let SceneFile::File(file) = &self.editor_ref.scene_ref.file else { return None };
+        Some(file)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource with the undo and redo stacks for editor changes.
+#[derive(Resource, Default)]
+pub(super) struct EditorStack
+{
+    undo: VecDeque<EditorStackEntry>,
+    redo: Vec<EditorStackEntry>,
+}
+
+impl EditorStack
+{
+    /// Records a change. Clears the redo stack.
+    ///
+    /// Consecutive changes from the same widget are merged if they occur close together.
+    pub(super) fn record(
+        &mut self,
+        editor_ref: &CobEditorRef,
+        before: Box<dyn PartialReflect + 'static>,
+        after: Box<dyn PartialReflect + 'static>,
+        time: f64,
+    )
+    {
+        self.redo.clear();
+
+        if let Some(last) = self.undo.back_mut() {
+            if last.source.same_as(&editor_ref.death_signal)
+                && last.editor_ref.scene_ref == editor_ref.scene_ref
+                && last.editor_ref.loadable_name == editor_ref.loadable_name
+                && time - last.time <= EDITOR_STACK_COALESCE_SECS
+            {
+                last.after = after;
+                last.time = time;
+                return;
+            }
+        }
+
+        if self.undo.len() >= MAX_EDITOR_STACK_LEN {
+            self.undo.pop_front();
+        }
+        self.undo.push_back(EditorStackEntry {
+            editor_ref: CobEditorRef {
+                structure_path: ReflectStructurePath { path: Arc::from([]) },
+                ..editor_ref.clone()
+            },
+            source: editor_ref.death_signal.clone(),
+            before,
+            after,
+            time,
+        });
+    }
+
+    /// Removes all changes to a file.
+    ///
+    /// Used when the file's editor data is replaced by a hot-reloaded change.
+    pub(super) fn remove_file(&mut self, file: &CobFile)
+    {
+        self.undo.retain(|e| e.file() != Some(file));
+        self.redo.retain(|e| e.file() != Some(file));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Undoes the most recent editor change.
+pub(super) fn undo_editor_change(world: &mut World)
+{
+    let Some(entry) = world.resource_mut::<EditorStack>().undo.pop_back() else { return };
+    let Some(patch) = entry.make_patch(world, entry.before.as_ref()) else { return };
+    let file = entry.file().cloned();
+
+    if !apply_editor_patch(world, patch, false) {
+        tracing::warn!("failed undoing editor change for {} in {:?}; discarding the change",
+            entry.editor_ref.loadable_name, entry.editor_ref.scene_ref);
+        return;
+    }
+
+    world.resource_mut::<EditorStack>().redo.push(entry);
+    if let Some(file) = file {
+        world.commands().react().broadcast(EditorFileUndoRedo { file });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Redoes the most recently undone editor change.
+pub(super) fn redo_editor_change(world: &mut World)
+{
+    let Some(entry) = world.resource_mut::<EditorStack>().redo.pop() else { return };
+    let Some(patch) = entry.make_patch(world, entry.after.as_ref()) else { return };
+    let file = entry.file().cloned();
+
+    if !apply_editor_patch(world, patch, false) {
+        tracing::warn!("failed redoing editor change for {} in {:?}; discarding the change",
+            entry.editor_ref.loadable_name, entry.editor_ref.scene_ref);
+        return;
+    }
+
+    // Redone changes are never coalesced with new changes.
+    let mut stack = world.resource_mut::<EditorStack>();
+    stack.undo.push_back(EditorStackEntry { time: f64::NEG_INFINITY, ..entry });
+    if let Some(file) = file {
+        world.commands().react().broadcast(EditorFileUndoRedo { file });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(super) struct CobEditorStackPlugin;
+
+impl Plugin for CobEditorStackPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_resource::<EditorStack>()
+            .add_systems(Update, handle_editor_stack_shortcuts);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
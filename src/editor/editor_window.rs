@@ -0,0 +1,105 @@
+use bevy::prelude::*;
+use bevy::window::{EnabledButtons, PrimaryWindow};
+
+use super::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// TODO: try to make auto-moving the window smoother
+// - winit supports 'child windows' which should solve these problems. Need to manually construct the window, see
+//   WinitWindow::create_window.
+// TODO: the editor's position does not sync with the window on startup until you move the window
+// - maybe infer it from window starting size + monitor dimensions?
+// TODO: the editor does not sync properly if you shrink the window from the top down
+pub(super) fn refresh_editor_window(
+    settings: Res<EditorWindowSettings>,
+    primary_win: Query<&Window, (With<PrimaryWindow>, Without<EditorWindow>)>,
+    mut editor_win: Query<&mut Window, (With<EditorWindow>, Without<PrimaryWindow>)>,
+)
+{
+    let Ok(primary_window) = primary_win.get_single() else { return };
+    let Ok(mut editor_window) = editor_win.get_single_mut() else { return };
+
+    // Update window controls when the mode changes.
+    let docked = settings.mode == EditorWindowMode::Docked;
+    if settings.is_changed() {
+        editor_window.resizable = !docked;
+        editor_window.enabled_buttons = EnabledButtons { minimize: !docked, maximize: !docked, close: false };
+    }
+
+    // Floating windows are controlled by the user.
+    if !docked {
+        return;
+    }
+
+    // Check if the editor's position needs to change.
+    // TODO: incorporate MacOS 'content area' to avoid overlapping with the dock when on left side
+    // - https://stackoverflow.com/a/42898625
+    // - https://github.com/rustunit/bevy_device_lang/blob/main/src/apple.rs
+    let WindowPosition::At(primary_pos) = primary_window.position else { return };
+    let mut desired_pos = primary_pos;
+    desired_pos.x -= (settings.width * primary_window.resolution.scale_factor()) as i32;
+    desired_pos.x = desired_pos.x.max(0);
+
+    if WindowPosition::At(desired_pos) != editor_window.position {
+        editor_window.position = WindowPosition::At(desired_pos);
+    }
+
+    // Check if the editor's size needs to change.
+    let primary_height = primary_window.resolution.size().y;
+
+    if primary_height != editor_window.resolution.size().y || settings.width != editor_window.resolution.size().x {
+        editor_window.resolution.set(settings.width, primary_height);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Controls how the editor's window is positioned relative to the app's primary window.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum EditorWindowMode
+{
+    /// The editor window is attached to the left side of the primary window, and follows it when it moves or
+    /// changes height.
+    #[default]
+    Docked,
+    /// The editor window is a free-floating, resizable OS window. Use this to move the editor to a different
+    /// monitor so it doesn't obscure the game view.
+    Floating,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource for configuring the editor's window.
+///
+/// The editor is always displayed in a separate window with its own camera and UI root. Insert this resource
+/// before adding `CobwebUiPlugin` to control how the window is initially set up. The [`Self::mode`] and
+/// [`Self::width`] can be changed at runtime.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct EditorWindowSettings
+{
+    /// Defaults to [`EditorWindowMode::Docked`].
+    pub mode: EditorWindowMode,
+    /// The width of the window in logical pixels. Only applied continuously in [`EditorWindowMode::Docked`] mode.
+    ///
+    /// Defaults to `300.0`.
+    pub width: f32,
+    /// The window title.
+    ///
+    /// Defaults to `"Cob Editor"`.
+    pub title: String,
+}
+
+impl Default for EditorWindowSettings
+{
+    fn default() -> Self
+    {
+        Self {
+            mode: EditorWindowMode::default(),
+            width: 300.,
+            title: "Cob Editor".into(),
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -3,12 +3,15 @@
 use crate as bevy_cobweb_ui;
 
 mod build;
+mod builtin_widgets;
 mod death_signal;
 mod editor;
 mod editor_commands;
 mod editor_events;
-//mod editor_stack;
+mod editor_stack;
+mod editor_window;
 mod hash_registry;
+mod node_inspector;
 mod plugin;
 mod template;
 mod utils;
@@ -16,12 +19,15 @@ mod widget_interop;
 mod widget_registry;
 
 pub(self) use build::*;
+pub(self) use builtin_widgets::*;
 pub(self) use death_signal::*;
 pub(crate) use editor::*;
 pub use editor_commands::*;
 pub use editor_events::*;
-//pub(self) use editor_stack::*;
+pub(self) use editor_stack::*;
+pub use editor_window::*;
 pub(crate) use hash_registry::*;
+pub use node_inspector::*;
 pub(crate) use plugin::*;
 pub(self) use template::*;
 pub(self) use utils::*;
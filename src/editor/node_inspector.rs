@@ -0,0 +1,135 @@
+use bevy::picking::PickSet;
+use bevy::prelude::*;
+
+use super::*;
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+const HIGHLIGHT_COLOR: Color = Color::srgb(1.0, 0.0, 1.0);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Selects the scene node of an entity that is ctrl-clicked in-game.
+fn select_node_on_ctrl_click(
+    mut c: Commands,
+    mut clicks: EventReader<Pointer<Click>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    parents: Query<&Parent>,
+    scene_buffer: Res<SceneBuffer>,
+    editor: Res<CobEditor>,
+)
+{
+    let ctrl_pressed = keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+
+    for click in clicks.read() {
+        if click.button != PointerButton::Primary || !ctrl_pressed {
+            continue;
+        }
+
+        // Find the nearest entity loaded from a scene node.
+        // - Files that aren't editable (e.g. the editor's own frame) are ignored.
+        let Some(scene_ref) = std::iter::once(click.target)
+            .chain(parents.iter_ancestors(click.target))
+            .find_map(|e| scene_buffer.get_entity_scene_node(e))
+        else {
+            continue;
+        };
+        let SceneFile::File(file) = &scene_ref.file else { continue };
+        if !editor.get_file(file).is_some_and(|f| f.is_editable()) {
+            continue;
+        }
+
+        c.queue(SelectEditorNode(Some(scene_ref.clone())));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Outlines entities loaded with the selected scene node.
+fn refresh_editor_highlights(
+    mut c: Commands,
+    selection: Res<EditorNodeSelection>,
+    scene_buffer: Res<SceneBuffer>,
+    highlighted: Query<(Entity, &EditorHighlight)>,
+    outlines: Query<Option<&Outline>, With<Node>>,
+)
+{
+    let mut targets: Vec<Entity> = selection
+        .selected()
+        .map(|s| scene_buffer.iter_scene_node_entities(s).collect())
+        .unwrap_or_default();
+
+    // Remove stale highlights.
+    for (entity, highlight) in highlighted.iter() {
+        if let Some(idx) = targets.iter().position(|t| *t == entity) {
+            targets.swap_remove(idx);
+            continue;
+        }
+        let mut ec = c.entity(entity);
+        ec.remove::<EditorHighlight>();
+        match highlight.prev {
+            Some(prev) => {
+                ec.insert(prev);
+            }
+            None => {
+                ec.remove::<Outline>();
+            }
+        }
+    }
+
+    // Add new highlights.
+    for target in targets {
+        let Ok(prev) = outlines.get(target) else { continue };
+        c.entity(target).insert((
+            EditorHighlight { prev: prev.copied() },
+            Outline { width: Val::Px(2.), offset: Val::Px(1.), color: HIGHLIGHT_COLOR },
+        ));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Marks an entity outlined by the editor's node inspector.
+#[derive(Component, Debug)]
+struct EditorHighlight
+{
+    /// The outline to restore when the highlight is removed.
+    prev: Option<Outline>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource that tracks the scene node selected in the editor.
+///
+/// Use [`SelectEditorNode`] to change the selection.
+#[derive(Resource, Default, Debug)]
+pub struct EditorNodeSelection
+{
+    pub(super) selected: Option<SceneRef>,
+}
+
+impl EditorNodeSelection
+{
+    /// Gets the selected scene node.
+    pub fn selected(&self) -> Option<&SceneRef>
+    {
+        self.selected.as_ref()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(super) struct CobEditorInspectorPlugin;
+
+impl Plugin for CobEditorInspectorPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_resource::<EditorNodeSelection>()
+            .add_systems(PreUpdate, select_node_on_ctrl_click.after(PickSet::Focus))
+            .add_systems(PostUpdate, refresh_editor_highlights);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
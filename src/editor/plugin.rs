@@ -11,8 +11,11 @@ impl Plugin for CobEditorPlugin
     fn build(&self, app: &mut App)
     {
         app.add_plugins(CobWidgetRegistryPlugin)
+            .add_plugins(CobBuiltinEditorWidgetsPlugin)
             .add_plugins(CobHashRegistryPlugin)
             .add_plugins(CobEditorImplPlugin)
+            .add_plugins(CobEditorInspectorPlugin)
+            .add_plugins(CobEditorStackPlugin)
             .add_plugins(CobEditorBuildPlugin);
     }
 }
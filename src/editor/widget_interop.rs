@@ -11,7 +11,7 @@ use crate::prelude::*;
 //-------------------------------------------------------------------------------------------------------------------
 
 /// A structure point is a specific item inside some container.
-#[derive(Debug)]
+#[derive(Debug, Copy, Clone)]
 pub(super) enum ReflectStructurePoint
 {
     /// Includes the field name.
@@ -103,6 +103,15 @@ pub(super) struct ReflectStructurePath
 
 impl ReflectStructurePath
 {
+    /// Makes a new path that extends this path with another point.
+    pub(super) fn extend(&self, point: ReflectStructurePoint) -> Self
+    {
+        let mut path = Vec::with_capacity(self.path.len() + 1);
+        path.extend(self.path.iter().copied());
+        path.push(point);
+        Self { path: Arc::from(path) }
+    }
+
     /// Finds location in target value to patch in the new value.
     ///
     /// If `path` is empty, then `value` is directly assigned to `target`. Otherwise [`PartialReflect::try_apply`]
@@ -126,13 +135,12 @@ impl ReflectStructurePath
 
         let mut target_part = target.as_mut();
 
-        // TODO: target_part not used
         for point in self.path.iter() {
             let Some(next) = point.destructure(target_part) else { return Err(None) };
             target_part = next;
         }
 
-        target.try_apply(value.as_ref()).map_err(|e| Some(e))
+        target_part.try_apply(value.as_ref()).map_err(Some)
     }
 }
 
@@ -33,7 +33,7 @@ impl CobWidgetRegistry
         }
     }
 
-    pub(super) fn get(&self, longname: &'static str) -> Option<EditorWidgetSpawnFn>
+    pub(super) fn get(&self, longname: &str) -> Option<EditorWidgetSpawnFn>
     {
         self.widgets.get(longname).copied()
     }
@@ -1,7 +1,13 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::Arc;
 
-use bevy::asset::AssetApp;
+use bevy::asset::io::AssetSourceId;
+use bevy::asset::{AssetApp, AssetPath, LoadedUntypedAsset, RecursiveDependencyLoadState};
 use bevy::prelude::*;
+use bevy::tasks::futures_lite::StreamExt;
+use bevy::tasks::{block_on, poll_once, IoTaskPool, Task};
+use smol_str::SmolStr;
 
 use crate::prelude::*;
 
@@ -12,38 +18,331 @@ fn load_cobweb_assets(
     mut files: ResMut<LoadedCobAssetFiles>,
     mut cob_cache: ResMut<CobAssetCache>,
     mut commands_buffer: ResMut<CommandsBuffer>,
+    groups: Res<LoadGroups>,
     asset_server: Res<AssetServer>,
 )
 {
     let presets = files.take_preset_files();
+    let preset_dirs = std::mem::take(&mut files.preset_dirs);
+
+    // Files in load groups may import files loaded up-front, so resolvers need to stay available.
+    #[cfg(not(feature = "hot_reload"))]
+    if !groups.is_empty() {
+        cob_cache.retain_resolvers();
+    }
+    #[cfg(feature = "hot_reload")]
+    let _ = groups;
 
     // Loads presets.
     for file in presets.iter().cloned() {
         files.start_loading(file, &mut cob_cache, &asset_server);
     }
 
+    // Scans preset directories. Files found in them are added to the commands buffer after the presets.
+    for pattern in preset_dirs {
+        files.load_dir(pattern, None, &asset_server);
+    }
+
     // Initialize commands buffer.
     commands_buffer.set_root_file(presets);
 }
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Extension of the COB files that are loaded.
+///
+/// With the `precompiled_cob` feature, only the precompiled `.cobb` files need to be shipped, so directories are
+/// scanned for `.cobb` files. They are still identified by their `.cob` paths.
+#[cfg(feature = "precompiled_cob")]
+const SCANNED_COB_EXTENSION: &str = "cobb";
+#[cfg(not(feature = "precompiled_cob"))]
+const SCANNED_COB_EXTENSION: &str = "cob";
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Finds all COB files in a directory, sorted by path.
+async fn scan_cob_dir(asset_server: AssetServer, pattern: CobDirPattern) -> Vec<CobFile>
+{
+    let path = AssetPath::parse(&pattern.dir);
+    let source_id = path.source().clone_owned();
+    let source = match asset_server.get_source(source_id.clone()) {
+        Ok(source) => source,
+        Err(err) => {
+            tracing::warn!("failed scanning COB directory {:?}; {}", pattern.dir, err);
+            return vec![];
+        }
+    };
+    let reader = source.reader();
+
+    let mut found: Vec<PathBuf> = vec![];
+    let mut dirs = vec![path.path().to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        let mut entries = match reader.read_directory(&dir).await {
+            Ok(entries) => entries,
+            Err(err) => {
+                tracing::warn!("failed scanning COB directory {:?}; {}", dir, err);
+                continue;
+            }
+        };
+        while let Some(entry) = entries.next().await {
+            if entry.extension().is_some_and(|e| e == SCANNED_COB_EXTENSION) {
+                found.push(entry.with_extension("cob"));
+            } else if pattern.recursive && reader.is_directory(&entry).await.unwrap_or(false) {
+                dirs.push(entry);
+            }
+        }
+    }
+
+    // Sort so files are loaded in the same order on all platforms.
+    found.sort();
+    found
+        .iter()
+        .filter_map(|path| {
+            let path = path.to_string_lossy().replace('\\', "/");
+            let path = match &source_id {
+                AssetSourceId::Default => path,
+                AssetSourceId::Name(name) => format!("{name}://{path}"),
+            };
+            CobFile::try_new(&path)
+        })
+        .collect()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Loads files found by directory scans.
+///
+/// Scans finish in any order, so finished scans are buffered and only loaded once all directories registered
+/// before them are scanned. This way commands in directory files are applied in the order directories were
+/// registered.
+fn load_scanned_dirs(
+    mut files: ResMut<LoadedCobAssetFiles>,
+    mut cob_cache: ResMut<CobAssetCache>,
+    commands_buffer: Option<ResMut<CommandsBuffer>>,
+    asset_server: Res<AssetServer>,
+)
+{
+    let files = files.as_mut();
+    for scan in files.dir_scans.iter_mut() {
+        if scan.found.is_none() {
+            scan.found = block_on(poll_once(&mut scan.task));
+        }
+    }
+
+    // Take finished scans in directory order up to the first unfinished scan.
+    files.dir_scans.sort_by_key(|scan| scan.dir);
+    let num_finished = files
+        .dir_scans
+        .iter()
+        .take_while(|scan| scan.found.is_some())
+        .count();
+
+    let mut new_files = vec![];
+    for scan in files.dir_scans.drain(..num_finished) {
+        let dir = &mut files.dirs[scan.dir];
+        dir.scanned = true;
+
+        for file in scan.found.unwrap_or_default() {
+            // Only load files that weren't in the directory the last time it was scanned.
+            if !dir.files.insert(file.clone()) {
+                continue;
+            }
+            let is_new = match &dir.key {
+                Some(prefix) => {
+                    let key = dir.pattern.manifest_key(prefix, &file);
+                    if key.is_none() {
+                        tracing::warn!("loading COB file {:?} in directory {:?} without a manifest key; the file's \
+                            path is not a valid manifest key", file, dir.pattern.dir);
+                    }
+                    cob_cache.register_manifest_key(file.clone(), key)
+                }
+                None => !cob_cache.is_file_registered(&file),
+            };
+            if !is_new {
+                continue;
+            }
+            new_files.push(file);
+        }
+    }
+    if new_files.is_empty() {
+        return;
+    }
+
+    let Some(mut commands_buffer) = commands_buffer else {
+        tracing::warn!("failed loading COB files {:?} found in directories; files can't be loaded after the \
+            initial load", new_files);
+        return;
+    };
+    for file in new_files.iter().cloned() {
+        tracing::info!("loading COB file {} found in directory", file.as_str());
+        files.start_loading(file, &mut cob_cache, &asset_server);
+    }
+    commands_buffer.add_root_files(new_files);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Periodically rescans loaded directories to detect new files.
+#[cfg(feature = "hot_reload")]
+fn rescan_cob_dirs(mut files: ResMut<LoadedCobAssetFiles>, time: Res<Time<Real>>, asset_server: Res<AssetServer>)
+{
+    if !files.rescan_timer.tick(time.delta()).just_finished() {
+        return;
+    }
+    for idx in 0..files.dirs.len() {
+        if files.dir_scans.iter().any(|scan| scan.dir == idx) {
+            continue;
+        }
+        files.start_dir_scan(idx, &asset_server);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Counts how many asset dependencies requested by COB manifests are still loading.
+fn update_dependency_progress(mut files: ResMut<LoadedCobAssetFiles>, asset_server: Res<AssetServer>)
+{
+    let files = files.as_mut();
+    files.pending_dependencies = 0;
+    files.weighted_pending_dependencies = 0.;
+    for dependency in files.dependencies.values() {
+        if !matches!(
+            asset_server.get_recursive_dependency_load_state(dependency.handle.id()),
+            Some(RecursiveDependencyLoadState::NotLoaded | RecursiveDependencyLoadState::Loading)
+        ) {
+            continue;
+        }
+        files.pending_dependencies += 1;
+        files.weighted_pending_dependencies += dependency.weight;
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A non-COB asset requested by COB manifests.
+struct ManifestDependency
+{
+    /// Kept so the asset stays loaded.
+    handle: Handle<LoadedUntypedAsset>,
+    /// Load progress weight.
+    weight: f32,
+    /// Files that requested the asset.
+    files: Vec<CobFile>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A directory of COB files that is loaded.
+struct LoadedCobDir
+{
+    pattern: CobDirPattern,
+    /// Manifest key prefix for files in the directory. Is `None` if loaded via the App extension.
+    key: Option<ManifestKey>,
+    /// Files found in the directory.
+    files: HashSet<CobFile>,
+    /// Set once the directory was scanned the first time.
+    scanned: bool,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A running directory scan.
+struct CobDirScan
+{
+    /// Index into the loaded directories.
+    dir: usize,
+    task: Task<Vec<CobFile>>,
+    /// Files found by the scan. Is `None` until the task finishes.
+    found: Option<Vec<CobFile>>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Stores asset paths for all pre-registered cobweb asset files that should be loaded.
-#[derive(Resource, Default)]
+#[derive(Resource)]
 pub(crate) struct LoadedCobAssetFiles
 {
     preset_files: Vec<CobFile>,
+    preset_dirs: Vec<CobDirPattern>,
+    /// Set once preset files have started loading.
+    initialized: bool,
     handles: HashMap<AssetId<CobAssetFile>, Handle<CobAssetFile>>,
+
+    /// Non-COB assets requested by COB manifests.
+    dependencies: HashMap<Arc<str>, ManifestDependency>,
+    pending_dependencies: usize,
+    weighted_pending_dependencies: f32,
+
+    /// Directories of COB files.
+    dirs: Vec<LoadedCobDir>,
+    dir_scans: Vec<CobDirScan>,
+    #[cfg(feature = "hot_reload")]
+    rescan_timer: Timer,
+}
+
+impl Default for LoadedCobAssetFiles
+{
+    fn default() -> Self
+    {
+        Self {
+            preset_files: Vec::default(),
+            preset_dirs: Vec::default(),
+            initialized: false,
+            handles: HashMap::default(),
+            dependencies: HashMap::default(),
+            pending_dependencies: 0,
+            weighted_pending_dependencies: 0.,
+            dirs: Vec::default(),
+            dir_scans: Vec::default(),
+            #[cfg(feature = "hot_reload")]
+            rescan_timer: Timer::from_seconds(1., TimerMode::Repeating),
+        }
+    }
 }
 
 impl LoadedCobAssetFiles
 {
+    fn add_preset_dir(&mut self, dir: &str)
+    {
+        let pattern = CobDirPattern::try_from_pattern(dir)
+            .unwrap_or_else(|| CobDirPattern::new(dir, true));
+        if self.preset_dirs.contains(&pattern) {
+            return;
+        }
+        tracing::info!("registered COB directory {}", pattern.dir);
+        self.preset_dirs.push(pattern);
+    }
+
+    /// Starts loading all COB files in a directory.
+    ///
+    /// Files are registered with manifest keys derived from `key` (see [`CobDirPattern::manifest_key`]).
+    pub(crate) fn load_dir(&mut self, pattern: CobDirPattern, key: Option<ManifestKey>, asset_server: &AssetServer)
+    {
+        if self.dirs.iter().any(|d| d.pattern == pattern && d.key == key) {
+            return;
+        }
+        self.dirs.push(LoadedCobDir { pattern, key, files: HashSet::default(), scanned: false });
+        self.start_dir_scan(self.dirs.len() - 1, asset_server);
+    }
+
+    fn start_dir_scan(&mut self, dir: usize, asset_server: &AssetServer)
+    {
+        let task = IoTaskPool::get().spawn(scan_cob_dir(asset_server.clone(), self.dirs[dir].pattern.clone()));
+        self.dir_scans.push(CobDirScan { dir, task, found: None });
+    }
+
+    /// Gets the number of directories that haven't been scanned yet.
+    fn num_pending_dirs(&self) -> usize
+    {
+        self.dirs.iter().filter(|d| !d.scanned).count()
+    }
+
     fn add_preset_file(&mut self, file: &str)
     {
         match CobFile::try_new(file) {
             Some(file) => {
                 tracing::info!("registered COB file {}", file.as_str());
-                self.preset_files.push(file);
+                self.add_preset(file);
             }
             None => {
                 tracing::warn!("failed registering COB file {}; does not have '.cob' extension", file)
@@ -51,11 +350,26 @@ impl LoadedCobAssetFiles
         }
     }
 
+    pub(crate) fn add_preset(&mut self, file: CobFile)
+    {
+        if self.preset_files.contains(&file) {
+            return;
+        }
+        self.preset_files.push(file);
+    }
+
     fn take_preset_files(&mut self) -> Vec<CobFile>
     {
+        self.initialized = true;
         std::mem::take(&mut self.preset_files)
     }
 
+    /// Returns `true` if preset files have started loading.
+    pub(crate) fn is_initialized(&self) -> bool
+    {
+        self.initialized
+    }
+
     pub(crate) fn start_loading(
         &mut self,
         file: CobFile,
@@ -63,11 +377,50 @@ impl LoadedCobAssetFiles
         asset_server: &AssetServer,
     )
     {
-        let handle = asset_server.load(String::from(file.as_str()));
+        let handle = asset_server.load(file.asset_path());
         self.handles.insert(handle.id(), handle);
         cob_cache.prepare_file(file);
     }
 
+    /// Starts loading a non-COB asset requested by a COB manifest.
+    ///
+    /// Only records the requesting file if the asset was already requested.
+    pub(crate) fn load_dependency(&mut self, file: &CobFile, path: Arc<str>, weight: f32, asset_server: &AssetServer)
+    {
+        if let Some(dependency) = self.dependencies.get_mut(&path) {
+            if !dependency.files.contains(file) {
+                dependency.files.push(file.clone());
+            }
+            return;
+        }
+        let handle = asset_server.load_untyped(&*path);
+        self.dependencies
+            .insert(path, ManifestDependency { handle, weight, files: vec![file.clone()] });
+    }
+
+    /// Removes a preset file that hasn't started loading.
+    ///
+    /// Returns `false` if the file is not a pending preset file.
+    pub(crate) fn remove_preset(&mut self, file: &CobFile) -> bool
+    {
+        let Some(pos) = self.preset_files.iter().position(|f| f == file) else { return false };
+        self.preset_files.remove(pos);
+        true
+    }
+
+    /// Releases the handle of a file and of manifest assets that were only requested by the file.
+    pub(crate) fn release_file(&mut self, file: &CobFile)
+    {
+        let path = file.asset_path();
+        let path = std::path::Path::new(&path);
+        self.handles
+            .retain(|_, handle| handle.path().is_none_or(|p| p.path() != path));
+        self.dependencies.retain(|_, dependency| {
+            dependency.files.retain(|f| f != file);
+            !dependency.files.is_empty()
+        });
+    }
+
     /// Does not remove the handle in case the asset gets reloaded.
     #[cfg(feature = "hot_reload")]
     pub(crate) fn get_handle(&self, id: AssetId<CobAssetFile>) -> Option<Handle<CobAssetFile>>
@@ -83,6 +436,29 @@ impl LoadedCobAssetFiles
     }
 }
 
+impl AssetLoadProgress for LoadedCobAssetFiles
+{
+    fn pending_assets(&self) -> usize
+    {
+        self.pending_dependencies + self.num_pending_dirs()
+    }
+
+    fn total_assets(&self) -> usize
+    {
+        self.dependencies.len() + self.dirs.len()
+    }
+
+    fn weighted_pending_assets(&self) -> f32
+    {
+        self.weighted_pending_dependencies + self.num_pending_dirs() as f32
+    }
+
+    fn weighted_total_assets(&self) -> f32
+    {
+        self.dependencies.values().map(|d| d.weight).sum::<f32>() + self.dirs.len() as f32
+    }
+}
+
 //-------------------------------------------------------------------------------------------------------------------
 
 /// Extends `App` with methods supporting cob file loading.
@@ -90,6 +466,30 @@ pub trait LoadedCobAssetFilesAppExt
 {
     /// Registers a cobweb asset file to be loaded.
     fn load(&mut self, file: impl AsRef<str>) -> &mut Self;
+
+    /// Registers all cobweb asset files in a directory and its subdirectories to be loaded.
+    ///
+    /// Use a pattern like `"ui/*"` to only load files directly in the directory. Files are loaded in
+    /// lexicographic order after files registered with [`Self::load`] and files in directories registered
+    /// earlier. With the `hot_reload` feature, new files added to the directory are loaded automatically.
+    fn load_dir(&mut self, dir: impl AsRef<str>) -> &mut Self;
+
+    /// Unloads a cobweb asset file.
+    ///
+    /// If the file was registered with [`Self::load`] and hasn't started loading, then it won't be loaded.
+    /// Otherwise [`UnloadCob`] is queued.
+    fn unload(&mut self, file: impl AsRef<str>) -> &mut Self;
+
+    /// Registers a named group of cobweb asset files that are loaded on demand.
+    ///
+    /// Groups are loaded with [`LoadCobGroup`] or [`LoadGroupCommandsExt::load_group`], or automatically with
+    /// [`Self::load_group_in_state`]. Use [`LoadGroups`] or the [`load_group_loaded`] run condition to check if a
+    /// group is loaded.
+    fn load_group<T: AsRef<str>>(&mut self, name: impl Into<SmolStr>, files: impl IntoIterator<Item = T>)
+        -> &mut Self;
+
+    /// Loads a [load group](Self::load_group) when entering `state`, and unloads it when exiting `state`.
+    fn load_group_in_state<S: States>(&mut self, name: impl Into<SmolStr>, state: S) -> &mut Self;
 }
 
 impl LoadedCobAssetFilesAppExt for App
@@ -105,6 +505,64 @@ impl LoadedCobAssetFilesAppExt for App
             .add_preset_file(file.as_ref());
         self
     }
+
+    fn load_dir(&mut self, dir: impl AsRef<str>) -> &mut Self
+    {
+        if !self.world().contains_resource::<LoadedCobAssetFiles>() {
+            self.init_resource::<LoadedCobAssetFiles>();
+        }
+
+        self.world_mut()
+            .resource_mut::<LoadedCobAssetFiles>()
+            .add_preset_dir(dir.as_ref());
+        self
+    }
+
+    fn unload(&mut self, file: impl AsRef<str>) -> &mut Self
+    {
+        self.world_mut()
+            .commands()
+            .queue(UnloadCob::new(file.as_ref()));
+        self
+    }
+
+    fn load_group<T: AsRef<str>>(&mut self, name: impl Into<SmolStr>, files: impl IntoIterator<Item = T>)
+        -> &mut Self
+    {
+        if !self.world().contains_resource::<LoadGroups>() {
+            self.init_resource::<LoadGroups>();
+        }
+
+        let name = name.into();
+        let files = files
+            .into_iter()
+            .filter_map(|file| {
+                let file = file.as_ref();
+                let cob_file = CobFile::try_new(file);
+                if cob_file.is_none() {
+                    tracing::warn!("failed registering COB file {} in load group {:?}; does not have '.cob' \
+                        extension", file, name);
+                }
+                cob_file
+            })
+            .collect();
+        self.world_mut()
+            .resource_mut::<LoadGroups>()
+            .add_group(name, files);
+        self
+    }
+
+    fn load_group_in_state<S: States>(&mut self, name: impl Into<SmolStr>, state: S) -> &mut Self
+    {
+        let name = name.into();
+        let unload_name = name.clone();
+        self.add_systems(OnEnter(state.clone()), move |mut c: Commands| {
+            c.load_group(name.clone());
+        })
+        .add_systems(OnExit(state), move |mut c: Commands| {
+            c.unload_group(unload_name.clone());
+        })
+    }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -120,7 +578,18 @@ impl Plugin for AppLoadExtPlugin
         }
 
         app.init_asset::<CobAssetFile>()
-            .add_systems(PreStartup, load_cobweb_assets);
+            .register_asset_tracker::<LoadedCobAssetFiles>()
+            .add_systems(PreStartup, load_cobweb_assets)
+            .add_systems(PreUpdate, update_dependency_progress.in_set(LoadProgressSet::Prepare))
+            .add_systems(
+                First,
+                load_scanned_dirs
+                    .before(FileProcessingSet)
+                    .run_if(|f: Res<LoadedCobAssetFiles>| !f.dir_scans.is_empty()),
+            );
+
+        #[cfg(feature = "hot_reload")]
+        app.add_systems(First, rescan_cob_dirs.before(load_scanned_dirs));
     }
 }
 
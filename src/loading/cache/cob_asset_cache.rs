@@ -1,9 +1,12 @@
 use std::collections::hash_map::Entry::{Occupied, Vacant};
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
 
 use bevy::prelude::*;
-use bevy::reflect::TypeRegistry;
+use bevy::tasks::{block_on, poll_once, AsyncComputeTaskPool, Task};
+use bevy::utils::Instant;
+use smol_str::SmolStr;
 
 use crate::prelude::*;
 
@@ -26,6 +29,114 @@ struct PreprocessedSceneFile
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// A file being extracted on the async compute task pool.
+#[derive(Debug)]
+struct ExtractionTask
+{
+    file: CobFile,
+    /// Imports for detecting when the file needs to be extracted again.
+    #[cfg(feature = "hot_reload")]
+    imports: HashSet<ManifestKey>,
+    task: Task<ExtractedSceneFile>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A file that was extracted by an extraction task, waiting to be applied.
+#[derive(Debug)]
+struct ExtractedSceneFile
+{
+    file: CobFile,
+    #[cfg(feature = "hot_reload")]
+    imports: HashMap<ManifestKey, CobImportAlias>,
+    /// Un-extracted data cached for re-loading when imports are reloaded.
+    #[cfg(feature = "hot_reload")]
+    data: Cob,
+    #[cfg(feature = "editor")]
+    hash: crate::editor::CobFileHash,
+    /// The theme the file was extracted with. Used to detect theme changes during extraction.
+    #[cfg(feature = "hot_reload")]
+    theme: (SmolStr, bool),
+
+    resolver: CobResolver,
+    constants: Option<Arc<ConstantsMap>>,
+    commands: Vec<(&'static str, ErasedLoadable)>,
+    scenes: ExtractedScenes,
+    errors: Vec<CobLoadError>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resolves and extracts a file.
+///
+/// Runs in an extraction task. The resolver must already contain the file's imports.
+fn extract_scene_file(
+    type_registry: AppTypeRegistry,
+    loadables: LoadableNames,
+    manifest_map: Arc<Mutex<ManifestMap>>,
+    mut preprocessed: PreprocessedSceneFile,
+    mut resolver: CobResolver,
+) -> ExtractedSceneFile
+{
+    let type_registry = type_registry.read();
+    let mut errors = vec![];
+
+    #[cfg(feature = "hot_reload")]
+    let theme = (
+        SmolStr::from(resolver.loadables.themes.active()),
+        resolver.loadables.themes.high_contrast(),
+    );
+
+    // Data must be cloned before extraction, because extraction will modify the value in-place in order
+    // to process definitions. Definitions always need to be re-processed when re-extracting a file.
+    #[cfg(feature = "hot_reload")]
+    let data = preprocessed.data.clone();
+
+    // Process the file.
+    // - This updates the constants/specs maps with info extracted from the file.
+    extract_cob_importables(preprocessed.file.clone(), &mut preprocessed.data, &mut resolver);
+    let constants = resolver.loadables.constants.last_file_constants();
+
+    let commands = extract_cob_commands(
+        &type_registry,
+        &preprocessed.file,
+        &mut preprocessed.data,
+        &loadables,
+        &resolver,
+        &mut errors,
+    );
+
+    let scenes = extract_cob_scenes(
+        &type_registry,
+        &preprocessed.file,
+        &mut preprocessed.data,
+        &preprocessed.imports,
+        &manifest_map,
+        &loadables,
+        &mut resolver,
+        &mut errors,
+    );
+
+    ExtractedSceneFile {
+        file: preprocessed.file,
+        #[cfg(feature = "hot_reload")]
+        imports: preprocessed.imports,
+        #[cfg(feature = "hot_reload")]
+        data,
+        #[cfg(feature = "editor")]
+        hash: preprocessed.hash,
+        #[cfg(feature = "hot_reload")]
+        theme,
+        resolver,
+        constants,
+        commands,
+        scenes,
+        errors,
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 #[derive(Default, Debug)]
 struct ProcessedSceneFile
 {
@@ -77,12 +188,39 @@ pub(crate) struct CobAssetCache
     #[cfg(feature = "hot_reload")]
     preprocessed_set: HashSet<CobFile>,
 
+    /// Tracks files that are being extracted on the async compute task pool.
+    extracting: Vec<ExtractionTask>,
+    /// Tracks extracted files waiting to be applied.
+    extracted: VecDeque<ExtractedSceneFile>,
+    /// Tracks files whose imports were re-processed while they were being extracted.
+    #[cfg(feature = "hot_reload")]
+    stale_extractions: HashSet<CobFile>,
+
     /// Records processed files.
     processed: HashMap<CobFile, ProcessedSceneFile>,
 
-    /// Tracks files that have been processed but not scene-extracted.
+    /// Tracks files that have been processed but whose scenes have not been inserted into the scene buffer.
     #[cfg(feature = "hot_reload")]
-    needs_scene_extraction: HashMap<CobFile, Cob>,
+    needs_scene_extraction: HashMap<CobFile, ExtractedScenes>,
+
+    /// The active theme variant used to resolve theme tokens.
+    active_theme: SmolStr,
+    /// Whether theme tokens resolve their high-contrast variant.
+    high_contrast: bool,
+    /// Indicates themed scene nodes need to be refreshed because the theme changed.
+    #[cfg(not(feature = "hot_reload"))]
+    needs_theme_refresh: bool,
+
+    /// Errors encountered while loading files. Drained into [`CobErrorEvents`](CobErrorEvent).
+    errors: Vec<CobLoadError>,
+
+    /// Constants of files that were processed. Drained into [`CobDefValues`].
+    updated_defs: Vec<(CobFile, Option<Arc<ConstantsMap>>)>,
+
+    /// Indicates processed files should be kept after all files are loaded, so files loaded later can import
+    /// them.
+    #[cfg(not(feature = "hot_reload"))]
+    retain_resolvers: bool,
 }
 
 impl CobAssetCache
@@ -102,20 +240,99 @@ impl CobAssetCache
         self.manifest_map.lock().unwrap()
     }
 
+    /// Records an error encountered while loading files.
+    pub(crate) fn push_error(&mut self, error: CobLoadError)
+    {
+        self.errors.push(error);
+    }
+
+    /// Takes the constants of files that were processed since the last call.
+    pub(crate) fn take_updated_defs(&mut self) -> Vec<(CobFile, Option<Arc<ConstantsMap>>)>
+    {
+        std::mem::take(&mut self.updated_defs)
+    }
+
+    /// Takes errors encountered while loading files.
+    pub(crate) fn take_errors(&mut self) -> Vec<CobLoadError>
+    {
+        std::mem::take(&mut self.errors)
+    }
+
     /// Gets the CobAssetCache's loading progress on startup.
     ///
     /// Returns `(num uninitialized files, num total files)`.
     ///
-    /// Does not include files recursively loaded via manifests.
+    /// Does not include files recursively loaded via manifests. Files waiting to be processed are pending.
     fn loading_progress(&self) -> (usize, usize)
     {
-        (self.pending.len(), self.total_expected_sheets)
+        let pending = self.pending.len() + self.num_preprocessed_pending();
+        (pending.min(self.total_expected_sheets), self.total_expected_sheets)
+    }
+
+    /// Gets the number of files waiting to be processed, including files that are being extracted.
+    pub(crate) fn num_preprocessed_pending(&self) -> usize
+    {
+        self.preprocessed.len() + self.extracting.len() + self.extracted.len()
+    }
+
+    /// Returns `true` if the file is being extracted or waiting to be applied.
+    fn is_file_extracting(&self, file: &CobFile) -> bool
+    {
+        self.extracting.iter().any(|t| t.file == *file) || self.extracted.iter().any(|e| e.file == *file)
     }
 
-    /// Gets the number of files waiting to be processed.
-    pub(super) fn num_preprocessed_pending(&self) -> usize
+    /// Gets the number of files that have not initialized yet.
+    pub(crate) fn num_pending_files(&self) -> usize
     {
-        self.preprocessed.len()
+        self.pending.len()
+    }
+
+    /// Returns `true` if the file has been registered for loading.
+    pub(crate) fn is_file_registered(&self, file: &CobFile) -> bool
+    {
+        self.file_to_manifest_key.contains_key(file)
+    }
+
+    /// Returns `true` if the file is waiting to initialize or to be processed.
+    pub(crate) fn is_file_loading(&self, file: &CobFile) -> bool
+    {
+        self.pending.contains(file)
+            || self.preprocessed.iter().any(|p| p.file == *file)
+            || self.is_file_extracting(file)
+    }
+
+    /// Removes all cached data for a file so it can be unloaded.
+    ///
+    /// The file's manifest key is released, so files that import the file will fail to resolve their imports if
+    /// they reload.
+    pub(crate) fn evict_file(&mut self, file: &CobFile)
+    {
+        let _ = self.pending.remove(file);
+        self.preprocessed.retain(|p| p.file != *file);
+        // Dropping a task cancels it.
+        self.extracting.retain(|t| t.file != *file);
+        self.extracted.retain(|e| e.file != *file);
+        let _ = self.processed.remove(file);
+        #[cfg(feature = "hot_reload")]
+        {
+            let _ = self.preprocessed_set.remove(file);
+            let _ = self.needs_scene_extraction.remove(file);
+            let _ = self.stale_extractions.remove(file);
+        }
+
+        if let Some(Some(manifest_key)) = self.file_to_manifest_key.remove(file) {
+            let mut manifest_map = self.manifest_map();
+            if manifest_map.get(&manifest_key).as_ref() == Some(file) {
+                manifest_map.remove(&manifest_key);
+            }
+        }
+    }
+
+    /// Keeps processed files after all files are loaded, so files loaded on demand can import them.
+    #[cfg(not(feature = "hot_reload"))]
+    pub(crate) fn retain_resolvers(&mut self)
+    {
+        self.retain_resolvers = true;
     }
 
     /// Returns `(hash, data, is_processed)`.
@@ -165,10 +382,16 @@ impl CobAssetCache
                 entry.insert(manifest_key.clone());
 
                 if let Some(new_key) = manifest_key {
-                    if let Some(prev_file) = self.manifest_map().insert(new_key.clone(), file.clone()) {
+                    let prev_file = self.manifest_map().insert(new_key.clone(), file.clone());
+                    if let Some(prev_file) = prev_file {
                         if file != prev_file {
                             tracing::warn!("replacing file for manifest key {:?} (old: {:?}, new: {:?})",
                                 new_key, prev_file, file);
+                            self.errors.push(CobLoadError::ManifestConflict {
+                                file,
+                                message: format!("replaced {:?} for manifest key {:?}", prev_file.as_str(),
+                                    new_key.as_str()),
+                            });
                         }
                     }
                 }
@@ -187,6 +410,11 @@ impl CobAssetCache
                         if *prev_key != new_key {
                             tracing::warn!("changing manifest key for {:?} (old: {:?}, new: {:?})",
                                 file, prev_key, new_key);
+                            self.errors.push(CobLoadError::ManifestConflict {
+                                file: file.clone(),
+                                message: format!("changed manifest key from {:?} to {:?}", prev_key.as_str(),
+                                    new_key.as_str()),
+                            });
                             let prev = prev_key.clone();
                             *prev_key = new_key.clone();
                             self.manifest_map().remove(&prev);
@@ -203,10 +431,16 @@ impl CobAssetCache
                 // self.file_to_manifest_key, but the manifest map will be updated to point to the
                 // new file. If the file is renamed back to its original name, then the manifest
                 // map will be updated again to point to the old file name.
-                if let Some(prev_file) = self.manifest_map().insert(new_key.clone(), file.clone()) {
+                let prev_file = self.manifest_map().insert(new_key.clone(), file.clone());
+                if let Some(prev_file) = prev_file {
                     if file != prev_file {
                         tracing::warn!("replacing file for manifest key {:?} (old: {:?}, new: {:?})",
                             new_key, prev_file, file);
+                        self.errors.push(CobLoadError::ManifestConflict {
+                            file,
+                            message: format!("replaced {:?} for manifest key {:?}", prev_file.as_str(),
+                                new_key.as_str()),
+                        });
                     }
                 }
 
@@ -268,6 +502,11 @@ impl CobAssetCache
                 continue;
             }
 
+            // Check if extracting.
+            if self.is_file_extracting(&import_file) {
+                continue;
+            }
+
             tracing::error!("ignoring file {} that points to untracked file {}; this is a bug",
                 file.as_str(), import.as_str());
             return;
@@ -294,22 +533,111 @@ impl CobAssetCache
         self.preprocessed.push(preprocessed);
     }
 
-    /// Converts a preprocessed file to a processed file.
+    /// Sets the active theme variant for resolving theme tokens.
+    ///
+    /// Scene loadables that use theme tokens will be reapplied with the new theme. If the `hot_reload` feature is
+    /// enabled, then all processed files that use theme tokens are re-processed, which also re-applies their
+    /// commands.
+    pub(crate) fn set_active_theme(&mut self, theme: SmolStr, commands_buffer: Option<&mut CommandsBuffer>)
+    {
+        if self.active_theme == theme {
+            return;
+        }
+        tracing::info!("setting active theme to {:?}", theme.as_str());
+        self.active_theme = theme;
+        self.refresh_themed_files(commands_buffer);
+    }
+
+    /// Sets whether theme tokens resolve their high-contrast variant.
+    ///
+    /// See [`Self::set_active_theme`] for how files are refreshed.
+    pub(crate) fn set_high_contrast(&mut self, high_contrast: bool, commands_buffer: Option<&mut CommandsBuffer>)
+    {
+        if self.high_contrast == high_contrast {
+            return;
+        }
+        tracing::info!("setting high contrast to {high_contrast}");
+        self.high_contrast = high_contrast;
+        self.refresh_themed_files(commands_buffer);
+    }
+
+    /// Re-processes all processed files that use theme tokens.
+    ///
+    /// If the `hot_reload` feature is disabled, then themed scene nodes are refreshed by
+    /// [`Self::take_theme_refresh`] instead.
+    fn refresh_themed_files(&mut self, _commands_buffer: Option<&mut CommandsBuffer>)
+    {
+        #[cfg(not(feature = "hot_reload"))]
+        {
+            self.needs_theme_refresh = true;
+        }
+        #[cfg(feature = "hot_reload")]
+        {
+            let Some(commands_buffer) = _commands_buffer else { return };
+            let needs_rebuild: Vec<CobFile> = self
+                .processed
+                .iter()
+                .filter_map(|(file, processed)| {
+                    if processed.resolver.loadables.themes.is_used() {
+                        return Some(file.clone());
+                    }
+                    None
+                })
+                .collect();
+
+            for needs_rebuild in needs_rebuild {
+                // Dependents of rebuilt files will be rebuilt automatically.
+                commands_buffer.prep_commands_refresh(needs_rebuild.clone());
+                let processed = self.processed.remove(&needs_rebuild).unwrap();
+                self.add_preprocessed_file(
+                    needs_rebuild,
+                    processed.imports,
+                    processed.data,
+                    #[cfg(feature = "editor")]
+                    processed.hash,
+                );
+            }
+        }
+    }
+
+    /// Returns `true` if themed scene nodes need to be refreshed.
+    #[cfg(not(feature = "hot_reload"))]
+    pub(crate) fn needs_theme_refresh(&self) -> bool
+    {
+        self.needs_theme_refresh
+    }
+
+    /// Takes the theme that themed scene nodes should be refreshed with.
+    ///
+    /// Returns `(active theme, high contrast)`.
+    #[cfg(not(feature = "hot_reload"))]
+    pub(crate) fn take_theme_refresh(&mut self) -> (SmolStr, bool)
+    {
+        self.needs_theme_refresh = false;
+        (self.active_theme.clone(), self.high_contrast)
+    }
+
+    /// Starts extracting a preprocessed file on the async compute task pool.
     ///
     /// Assumes all imports are available.
-    fn process_cobweb_asset_file(
+    fn start_extraction(
         &mut self,
-        mut preprocessed: PreprocessedSceneFile,
-        type_registry: &TypeRegistry,
-        loadables: &LoadableRegistry,
-        _c: &mut Commands,
-        commands_buffer: &mut CommandsBuffer,
-        _scene_buffer: &mut SceneBuffer,
-        _scene_loader: &mut SceneBuilder,
+        preprocessed: PreprocessedSceneFile,
+        type_registry: &AppTypeRegistry,
+        loadables: &LoadableNames,
     )
     {
         // Initialize resolver from dependencies.
+        // - The resolver is a snapshot of the imported resolvers, so the task doesn't access the cache.
         let mut resolver = CobResolver::default();
+        resolver
+            .loadables
+            .themes
+            .set_active(self.active_theme.clone());
+        resolver
+            .loadables
+            .themes
+            .set_high_contrast(self.high_contrast);
 
         for (dependency, alias) in preprocessed.imports.iter() {
             let Some(dependency) = self.manifest_map().get(&dependency) else {
@@ -326,61 +654,105 @@ impl CobAssetCache
             resolver.append(alias, &processed.resolver);
         }
 
-        // Prepare to process the file.
-        let mut processed = ProcessedSceneFile::default();
-
+        let file = preprocessed.file.clone();
         #[cfg(feature = "hot_reload")]
-        {
-            processed.imports = preprocessed.imports;
-            // Data must be cloned before extraction, because extraction will modify the value in-place in order
-            // to process definitions. Definitions always need to be re-processed when re-extracting a file.
-            processed.data = preprocessed.data.clone();
+        let imports = preprocessed.imports.keys().cloned().collect();
+        let type_registry = type_registry.clone();
+        let loadables = loadables.clone();
+        let manifest_map = self.manifest_map.clone();
+        let task = AsyncComputeTaskPool::get().spawn(async move {
+            extract_scene_file(type_registry, loadables, manifest_map, preprocessed, resolver)
+        });
+
+        self.extracting.push(ExtractionTask {
+            file,
+            #[cfg(feature = "hot_reload")]
+            imports,
+            task,
+        });
+    }
+
+    /// Moves finished extraction tasks to the extracted queue.
+    fn poll_extraction_tasks(&mut self)
+    {
+        let mut idx = 0;
+        while idx < self.extracting.len() {
+            let Some(extracted) = block_on(poll_once(&mut self.extracting[idx].task)) else {
+                idx += 1;
+                continue;
+            };
+            self.extracting.remove(idx);
+            self.extracted.push_back(extracted);
         }
+    }
 
-        #[cfg(feature = "editor")]
+    /// Converts an extracted file to a processed file.
+    fn apply_extracted_file(
+        &mut self,
+        extracted: ExtractedSceneFile,
+        _loadables: &LoadableRegistry,
+        _c: &mut Commands,
+        commands_buffer: &mut CommandsBuffer,
+        _scene_buffer: &mut SceneBuffer,
+        _scene_loader: &mut SceneBuilder,
+    )
+    {
+        #[cfg(feature = "hot_reload")]
         {
-            processed.hash = preprocessed.hash;
-        }
+            // Discard files that were re-queued while being extracted. They will be extracted again.
+            if self.preprocessed_set.contains(&extracted.file) {
+                let _ = self.stale_extractions.remove(&extracted.file);
+                return;
+            }
 
-        // Process the file.
-        // - This updates the constants/specs maps with info extracted from the file.
-        extract_cob_importables(preprocessed.file.clone(), &mut preprocessed.data, &mut resolver);
+            // Re-extract files whose imports were re-processed, or that use theme tokens and the theme changed,
+            // while being extracted.
+            let is_stale = self.stale_extractions.remove(&extracted.file);
+            let theme_changed = extracted.resolver.loadables.themes.is_used()
+                && (extracted.theme.0 != self.active_theme || extracted.theme.1 != self.high_contrast);
+            if is_stale || theme_changed {
+                commands_buffer.prep_commands_refresh(extracted.file.clone());
+                self.add_preprocessed_file(
+                    extracted.file,
+                    extracted.imports,
+                    extracted.data,
+                    #[cfg(feature = "editor")]
+                    extracted.hash,
+                );
+                return;
+            }
+        }
 
-        extract_cob_commands(
-            type_registry,
-            commands_buffer,
-            preprocessed.file.clone(),
-            &mut preprocessed.data,
-            loadables,
-            &resolver,
-        );
+        self.errors.extend(extracted.errors);
+        self.updated_defs
+            .push((extracted.file.clone(), extracted.constants));
+        commands_buffer.set_file_commands(extracted.file.clone(), extracted.commands);
 
         #[cfg(not(feature = "hot_reload"))]
         {
-            // Extract scenes immediately.
-            extract_cob_scenes(
-                type_registry,
-                _c,
-                _scene_buffer,
-                _scene_loader,
-                preprocessed.file.clone(),
-                preprocessed.data,
-                loadables,
-                &mut resolver,
-            );
+            // Insert scenes immediately.
+            extracted
+                .scenes
+                .insert(_c, _scene_buffer, _scene_loader, _loadables);
         }
         #[cfg(feature = "hot_reload")]
         {
-            // Defer scene extraction until it can be synchronized with loading entities.
+            // Defer scene insertion until it can be synchronized with loading entities.
             self.needs_scene_extraction
-                .insert(preprocessed.file.clone(), preprocessed.data);
+                .insert(extracted.file.clone(), extracted.scenes);
         }
 
-        // Save final maps.
-        processed.resolver = resolver;
-
         // Set fully processed
-        self.processed.insert(preprocessed.file.clone(), processed);
+        let processed = ProcessedSceneFile {
+            resolver: extracted.resolver,
+            #[cfg(feature = "hot_reload")]
+            imports: extracted.imports,
+            #[cfg(feature = "hot_reload")]
+            data: extracted.data,
+            #[cfg(feature = "editor")]
+            hash: extracted.hash,
+        };
+        self.processed.insert(extracted.file.clone(), processed);
 
         // Check for already-processed files that need to rebuild since they depend on this file.
         // TODO: It may be more efficient to cache a map of [file : importers]. Below will be quite expensive
@@ -389,7 +761,7 @@ impl CobAssetCache
         {
             if let Some(manifest_key) = self
                 .file_to_manifest_key
-                .get(&preprocessed.file)
+                .get(&extracted.file)
                 .cloned()
                 .flatten()
             {
@@ -416,91 +788,112 @@ impl CobAssetCache
                         processed.hash,
                     );
                 }
+
+                // Files being extracted with the old version of this file need to be extracted again.
+                for task in self.extracting.iter() {
+                    if task.imports.contains(&manifest_key) {
+                        self.stale_extractions.insert(task.file.clone());
+                    }
+                }
+                for extracted in self.extracted.iter() {
+                    if extracted.imports.contains_key(&manifest_key) {
+                        self.stale_extractions.insert(extracted.file.clone());
+                    }
+                }
             }
         }
     }
 
-    /// Converts preprocessed files to processed files.
+    /// Extracts preprocessed files on the async compute task pool, and converts extracted files to processed
+    /// files.
     ///
     /// Returns `true` if at least one file was processed.
     pub(super) fn process_cobweb_asset_files(
         &mut self,
-        type_registry: &TypeRegistry,
+        type_registry: &AppTypeRegistry,
         loadables: &LoadableRegistry,
         c: &mut Commands,
         commands_buffer: &mut CommandsBuffer,
         scene_buffer: &mut SceneBuffer,
         scene_loader: &mut SceneBuilder,
+        budget: Option<Duration>,
     ) -> bool
     {
-        // Loop preprocessed until nothing can be processed.
+        // Apply extracted files until the budget is used up.
+        // - At least one file is applied per call.
+        self.poll_extraction_tasks();
+
+        let start = Instant::now();
         let mut num_processed = 0;
-        let mut preprocessed = Vec::new();
 
-        while !self.preprocessed.is_empty() {
-            let num_already_processed = num_processed;
-            preprocessed.clear();
-            std::mem::swap(&mut self.preprocessed, &mut preprocessed);
-            #[cfg(feature = "hot_reload")]
-            self.preprocessed_set.clear();
+        while let Some(extracted) = self.extracted.pop_front() {
+            self.apply_extracted_file(extracted, loadables, c, commands_buffer, scene_buffer, scene_loader);
+            num_processed += 1;
+            if budget.is_some_and(|budget| start.elapsed() >= budget) {
+                break;
+            }
+        }
 
-            for preprocessed in preprocessed.drain(..) {
-                // Check if any dependency is not ready.
+        // Start extracting files whose dependencies are ready.
+        let names = loadables.names();
+        let mut preprocessed = std::mem::take(&mut self.preprocessed);
+        #[cfg(feature = "hot_reload")]
+        self.preprocessed_set.clear();
+
+        for preprocessed in preprocessed.drain(..) {
+            // Check if any dependency is not ready, or if the file is still being extracted.
+            let is_ready = !self.is_file_extracting(&preprocessed.file) && {
+                let manifest_map = self.manifest_map.lock().unwrap();
+                preprocessed.imports.keys().all(|i| {
+                    manifest_map
+                        .get(i)
+                        .is_some_and(|i| self.processed.contains_key(&i))
+                })
+            };
+            if !is_ready {
+                #[cfg(feature = "hot_reload")]
                 {
-                    let manifest_map = self.manifest_map.lock().unwrap();
-                    if preprocessed
-                        .imports
-                        .keys()
-                        .any(|i| match manifest_map.get(i) {
-                            Some(i) => !self.processed.contains_key(&i),
-                            None => true,
-                        })
-                    {
-                        #[cfg(feature = "hot_reload")]
-                        {
-                            let is_new = self.preprocessed_set.insert(preprocessed.file.clone());
-                            debug_assert!(is_new);
-                        }
-                        self.preprocessed.push(preprocessed);
-                        continue;
-                    }
+                    let is_new = self.preprocessed_set.insert(preprocessed.file.clone());
+                    debug_assert!(is_new);
                 }
-
-                self.process_cobweb_asset_file(
-                    preprocessed,
-                    type_registry,
-                    loadables,
-                    c,
-                    commands_buffer,
-                    scene_buffer,
-                    scene_loader,
-                );
-                num_processed += 1;
+                self.preprocessed.push(preprocessed);
+                continue;
             }
 
-            // Exit if no changes made.
-            if num_already_processed == num_processed {
-                break;
-            }
+            self.start_extraction(preprocessed, type_registry, &names);
         }
 
         // Check for failed loads.
-        if self.pending.is_empty() && !self.preprocessed.is_empty() {
+        if self.pending.is_empty()
+            && self.extracting.is_empty()
+            && self.extracted.is_empty()
+            && !self.preprocessed.is_empty()
+        {
             for preproc in self.preprocessed.drain(..) {
+                #[cfg(feature = "hot_reload")]
+                self.preprocessed_set.remove(&preproc.file);
                 tracing::error!("discarding COB file {:?} that failed to resolve imports; it either has a \
                     dependency cycle or tries to import unknown manifest keys; note that builtin colors/widgets/etc. \
                     require the corresponding feature to be enabled", preproc.file.as_str());
+                self.errors.push(CobLoadError::BadImport {
+                    file: preproc.file,
+                    message: "failed to resolve imports; there is either a dependency cycle or an unknown \
+                        manifest key"
+                        .into(),
+                });
             }
         }
 
         // Clean up memory once all files are loaded and processed.
         #[cfg(not(feature = "hot_reload"))]
         {
-            if self.pending.is_empty() && self.preprocessed.is_empty() {
+            if self.pending.is_empty() && self.num_preprocessed_pending() == 0 {
                 tracing::info!("done loading (enable hot_reload feature if you want to reload files)");
                 self.pending = HashSet::default();
                 self.preprocessed = Vec::default();
-                self.processed = HashMap::default();
+                if !self.retain_resolvers {
+                    self.processed = HashMap::default();
+                }
             }
         }
 
@@ -510,7 +903,6 @@ impl CobAssetCache
     #[cfg(feature = "hot_reload")]
     pub(crate) fn handle_pending_scene_extraction(
         &mut self,
-        type_registry: &TypeRegistry,
         loadables: &LoadableRegistry,
         c: &mut Commands,
         scene_buffer: &mut SceneBuffer,
@@ -518,27 +910,22 @@ impl CobAssetCache
         #[cfg(feature = "editor")] editor: &mut crate::editor::CobEditor,
     )
     {
-        // Note: We assume it doesn't matter what file order scenes are extracted in.
-        for (file, data) in self.needs_scene_extraction.drain() {
-            let Some(processed) = self.processed.get_mut(&file) else { continue };
-
-            extract_cob_scenes(
-                type_registry,
-                c,
-                scene_buffer,
-                scene_loader,
-                file,
-                data,
-                loadables,
-                &mut processed.resolver,
-            );
+        // Note: We assume it doesn't matter what file order scenes are inserted in, except that files with scene
+        // patches must be inserted after the files they patch.
+        let mut pending: Vec<(CobFile, ExtractedScenes)> = self.needs_scene_extraction.drain().collect();
+        pending.sort_by_key(|(_, scenes)| scenes.has_patches());
+
+        for (file, scenes) in pending {
+            let Some(_processed) = self.processed.get(&file) else { continue };
+
+            scenes.insert(c, scene_buffer, scene_loader, loadables);
 
             // Pass to editor.
             // - We wait until after scene extraction to make sure the editor view synchronizes with the
             // file's cached state.
             #[cfg(feature = "editor")]
             {
-                editor.add_processed(c, processed.hash, &processed.data);
+                editor.add_processed(c, _processed.hash, &_processed.data);
             }
         }
     }
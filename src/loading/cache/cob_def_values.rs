@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use bevy::prelude::*;
+use serde::de::DeserializeOwned;
+
+use super::*;
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource with resolved constant definitions from loaded COB files.
+///
+/// Use this to read designer-authored constants (e.g. spacing scales or timing values) in Rust systems without
+/// duplicating them in code.
+///
+/// Constants are stored after resolution, so constants that reference other constants (including imported
+/// constants) contain final values. Only constants defined in a file can be looked up with that file, not
+/// constants it imported.
+///
+/// This resource is updated when files are loaded, hot reloaded, or unloaded. Use change detection on the
+/// resource (or the [`CobCacheUpdated`] reactive event) to detect updates.
+///
+/// ```rust
+/// fn setup(defs: Res<CobDefValues>)
+/// {
+///     let spacing: f32 = defs.get_as("main.cob", "$spacing").unwrap_or(10.);
+/// }
+/// ```
+#[derive(Resource, Debug)]
+pub struct CobDefValues
+{
+    /// Used to look up files by manifest key.
+    manifest_map: Arc<Mutex<ManifestMap>>,
+    /// [ file : constants ]
+    constants: HashMap<CobFile, Arc<ConstantsMap>>,
+}
+
+impl CobDefValues
+{
+    pub(crate) fn new(manifest_map: Arc<Mutex<ManifestMap>>) -> Self
+    {
+        Self { manifest_map, constants: HashMap::default() }
+    }
+
+    pub(crate) fn set_file(&mut self, file: CobFile, constants: Option<Arc<ConstantsMap>>)
+    {
+        match constants {
+            Some(constants) => {
+                self.constants.insert(file, constants);
+            }
+            None => {
+                self.constants.remove(&file);
+            }
+        }
+    }
+
+    pub(crate) fn evict_file(&mut self, file: &CobFile)
+    {
+        self.constants.remove(file);
+    }
+
+    fn get_file_constants(&self, file: &str) -> Option<&ConstantsMap>
+    {
+        let file = match SceneFile::new(file) {
+            SceneFile::File(file) => file,
+            SceneFile::ManifestKey(key) => self.manifest_map.lock().unwrap().get(&key)?,
+        };
+        self.constants.get(&file).map(|c| &**c)
+    }
+
+    /// Gets a constant definition from a file.
+    ///
+    /// The file can be a file path (e.g. `"main.cob"`) or a manifest key. The constant name can include its `$`
+    /// prefix (e.g. `"$accent"`).
+    pub fn get_constant(&self, file: impl AsRef<str>, name: impl AsRef<str>) -> Option<&CobConstantValue>
+    {
+        let name = name.as_ref();
+        let name = name.strip_prefix('$').unwrap_or(name);
+        self.get_file_constants(file.as_ref())?.get(name)
+    }
+
+    /// Gets the value of a constant definition from a file.
+    ///
+    /// Returns `None` if the constant doesn't exist or is a value group (e.g. `$group = \ a b \`).
+    ///
+    /// See [`Self::get_constant`].
+    pub fn get(&self, file: impl AsRef<str>, name: impl AsRef<str>) -> Option<&CobValue>
+    {
+        match self.get_constant(file, name)? {
+            CobConstantValue::Value(value) => Some(value),
+            CobConstantValue::ValueGroup(_) => None,
+        }
+    }
+
+    /// Gets the value of a constant definition from a file, deserialized to a concrete type.
+    ///
+    /// Logs a warning and returns `None` if the value fails to deserialize.
+    ///
+    /// See [`Self::get`].
+    pub fn get_as<T: DeserializeOwned>(&self, file: impl AsRef<str>, name: impl AsRef<str>) -> Option<T>
+    {
+        let (file, name) = (file.as_ref(), name.as_ref());
+        let value = self.get(file, name)?;
+        match T::deserialize(value) {
+            Ok(value) => Some(value),
+            Err(err) => {
+                tracing::warn!("failed deserializing constant {name:?} in {file:?} as {}: {err:?}",
+                    std::any::type_name::<T>());
+                None
+            }
+        }
+    }
+
+    /// Iterates the names and values of all constant definitions in a file.
+    ///
+    /// Constant names don't include their `$` prefix.
+    pub fn iter_file(&self, file: impl AsRef<str>) -> impl Iterator<Item = (&str, &CobConstantValue)> + '_
+    {
+        self.get_file_constants(file.as_ref())
+            .into_iter()
+            .flat_map(|c| c.iter().map(|(k, v)| (k.as_str(), v)))
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -7,6 +7,7 @@ use crate::prelude::*;
 pub struct CobLoadableResolver
 {
     pub constants: ConstantsResolver,
+    pub themes: ThemeResolver,
 }
 
 impl CobLoadableResolver
@@ -14,16 +15,19 @@ impl CobLoadableResolver
     pub(crate) fn start_new_file(&mut self)
     {
         self.constants.start_new_file();
+        self.themes.start_new_file();
     }
 
     pub(crate) fn end_new_file(&mut self)
     {
         self.constants.end_new_file();
+        self.themes.end_new_file();
     }
 
     pub(crate) fn append(&mut self, alias: &CobImportAlias, to_append: &Self)
     {
         self.constants.append(alias, &to_append.constants);
+        self.themes.append(alias, &to_append.themes);
     }
 }
 
@@ -34,6 +38,8 @@ impl CobLoadableResolver
 pub struct CobSceneResolver
 {
     pub scene_macros: SceneMacrosResolver,
+    /// Scenes that can be extended by other scenes (`"a" extends "b"`).
+    pub scene_bases: SceneMacrosResolver,
 }
 
 impl CobSceneResolver
@@ -41,16 +47,19 @@ impl CobSceneResolver
     pub(crate) fn start_new_file(&mut self)
     {
         self.scene_macros.start_new_file();
+        self.scene_bases.start_new_file();
     }
 
     pub(crate) fn end_new_file(&mut self)
     {
         self.scene_macros.end_new_file();
+        self.scene_bases.end_new_file();
     }
 
     pub(crate) fn append(&mut self, alias: &CobImportAlias, to_append: &Self)
     {
         self.scene_macros.append(alias, &to_append.scene_macros);
+        self.scene_bases.append(alias, &to_append.scene_bases);
     }
 }
 
@@ -139,7 +139,7 @@ const GLOBAL_PSEUDO_FILE: &'static str = "__g.cob";
 /// Manages commands loaded from COB files to ensure they are applied in global order.
 ///
 /// When the `hot_reload` feature is not enabled, this resource will be removed in schedule
-/// `OnExit(LoadState::Loading)`.
+/// `OnExit(LoadState::Loading)` unless load groups are registered.
 #[derive(Resource, Debug)]
 pub(crate) struct CommandsBuffer
 {
@@ -274,6 +274,87 @@ impl CommandsBuffer
         self.set_file_commands(file, vec![]);
     }
 
+    /// Adds descendants to the 'global root' after it was initialized. These should be files loaded on demand
+    /// (e.g. in load groups).
+    pub(crate) fn add_root_files(&mut self, files: Vec<CobFile>)
+    {
+        let global = Self::global_file();
+        let Some(info) = self.hierarchy.get(&global) else {
+            tracing::error!("failed adding root files {:?}; the global file is missing (this is a bug)", files);
+            return;
+        };
+        let mut descendants: Vec<CobFile> = info.descendants.iter().cloned().collect();
+        let new_files: Vec<CobFile> = files
+            .into_iter()
+            .filter(|f| !descendants.contains(f))
+            .collect();
+        if new_files.is_empty() {
+            return;
+        }
+
+        // With hot reloading we can refresh the global file as if it were reloaded.
+        #[cfg(feature = "hot_reload")]
+        {
+            descendants.extend(new_files);
+            self.set_file_descendants(global.clone(), descendants);
+            self.set_file_commands(global, vec![]);
+        }
+
+        #[cfg(not(feature = "hot_reload"))]
+        {
+            for file in new_files.iter() {
+                if let Some(prev) = self.hierarchy.insert(
+                    file.clone(),
+                    FileCommandsInfo {
+                        status: FileStatus::Pending,
+                        parent: FileParent::Parent(global.clone()),
+                        commands: vec![],
+                        descendants: self.empty_descendants.clone(),
+                        is_orphaned: false,
+                        initialized: false,
+                    },
+                ) {
+                    tracing::warn!("duplicate file {:?} registered in commands buffer as a root file, prev info: {:?}",
+                        file, prev);
+                } else {
+                    self.file_counter.add(1);
+                }
+            }
+            descendants.extend(new_files);
+            self.hierarchy.get_mut(&global).unwrap().descendants = Arc::from(descendants);
+
+            // If all commands were applied, then restart traversal from the beginning. Files with no pending
+            // commands will be skipped.
+            if self.traversal_point.is_none() {
+                self.traversal_point = Some(global);
+            }
+        }
+    }
+
+    /// Discards the cached commands of a file that is being unloaded.
+    ///
+    /// The file stays in the hierarchy so its position in the global command order is preserved if it loads
+    /// again. Commands that were already applied are not reverted.
+    pub(crate) fn evict_file(&mut self, file: &CobFile)
+    {
+        let Some(info) = self.hierarchy.get_mut(file) else { return };
+        if info.status != FileStatus::Loaded {
+            tracing::warn!("evicting commands of file {:?} that is not loaded (status: {:?})", file, info.status);
+        }
+
+        let num_pending = info.commands.iter().filter(|c| c.is_pending).count();
+        if !info.is_orphaned {
+            self.command_counter.remove(num_pending);
+        }
+        info.commands = vec![];
+
+        // Mark the file so it can be re-initialized when it loads again.
+        #[cfg(not(feature = "hot_reload"))]
+        {
+            info.initialized = false;
+        }
+    }
+
     /// Tries to update the traversal point to the requested file.
     ///
     /// Will truncate `self.file_order` to the new traversal point.
@@ -355,20 +436,31 @@ impl CommandsBuffer
             return;
         };
 
-        if info.status != FileStatus::Pending {
+        // Files that were unloaded can load again.
+        let is_reloading = info.status == FileStatus::Loaded && !info.initialized;
+        if info.status != FileStatus::Pending && !is_reloading {
             tracing::error!("failed setting descendants {:?} for file {:?}; file was already loaded with descendants \
             {:?} (this is a bug)", descendants, file, info.descendants);
             return;
         }
+        if is_reloading {
+            self.file_counter.add(1);
+        }
 
         // Update status.
         info.status = FileStatus::AwaitingCommands;
         info.initialized = true;
+        let prev_descendants = std::mem::replace(&mut info.descendants, self.empty_descendants.clone());
 
         // Initialize descendants' slots.
         for descendant in descendants.iter() {
             debug_assert!(descendant.as_str() != GLOBAL_PSEUDO_FILE);
 
+            // Descendants of a reloading file keep their slots.
+            if prev_descendants.contains(descendant) {
+                continue;
+            }
+
             if let Some(prev) = self.hierarchy.insert(
                 descendant.clone(),
                 FileCommandsInfo {
@@ -746,6 +838,15 @@ impl CommandsBuffer
         }
         info.status = FileStatus::Loaded;
 
+        // Restart traversal if all commands were applied before this file reloaded. Files with no pending commands
+        // will be skipped.
+        #[cfg(not(feature = "hot_reload"))]
+        {
+            if self.traversal_point.is_none() && info.commands.iter().any(|c| c.is_pending) {
+                self.traversal_point = Some(Self::global_file());
+            }
+        }
+
         // Update traversal point if we have pending commands.
         // - Only needed in `hot_reload` because in non-hot-reload we iterate through the hierarchy exactly once.
         #[cfg(feature = "hot_reload")]
@@ -12,7 +12,7 @@ use crate::prelude::CobImportAlias;
 //-------------------------------------------------------------------------------------------------------------------
 
 // [ identifier : constant value ]
-type ConstantsMap = HashMap<SmolStr, CobConstantValue>;
+pub(crate) type ConstantsMap = HashMap<SmolStr, CobConstantValue>;
 
 //-------------------------------------------------------------------------------------------------------------------
 
@@ -39,6 +39,15 @@ impl ConstantsResolver
         self.stack.push((SmolStr::default(), Arc::new(map)));
     }
 
+    /// Gets the constants of the most recently collected file.
+    pub(crate) fn last_file_constants(&self) -> Option<Arc<ConstantsMap>>
+    {
+        self.stack
+            .last()
+            .filter(|(prefix, _)| prefix.is_empty())
+            .map(|(_, map)| map.clone())
+    }
+
     /// Adds an entry to the new file being collected.
     pub(crate) fn insert(&mut self, file: &CobFile, name: SmolStr, value: CobConstantValue)
     {
@@ -1,4 +1,5 @@
 mod cob_asset_cache;
+mod cob_def_values;
 mod cob_resolver;
 mod commands_buffer;
 mod constants_resolver;
@@ -6,14 +7,18 @@ mod manifest_map;
 mod plugin;
 mod scene_buffer;
 mod scene_macros_resolver;
+mod theme_resolver;
 mod utils;
 
 pub(crate) use cob_asset_cache::*;
+pub use cob_def_values::*;
 pub use cob_resolver::*;
 pub(crate) use commands_buffer::*;
 pub use constants_resolver::*;
 pub(crate) use manifest_map::*;
+pub use plugin::CobProcessingBudget;
 pub(crate) use plugin::*;
 pub use scene_buffer::*;
 pub use scene_macros_resolver::*;
+pub use theme_resolver::*;
 pub(self) use utils::*;
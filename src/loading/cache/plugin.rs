@@ -1,4 +1,5 @@
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 use bevy::prelude::*;
 use bevy_cobweb::prelude::*;
@@ -8,13 +9,26 @@ use crate::prelude::*;
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Sends errors recorded in the cache as [`CobErrorEvents`](CobErrorEvent).
+fn send_cob_cache_errors(c: &mut Commands, cob_cache: &mut CobAssetCache)
+{
+    for error in cob_cache.take_errors() {
+        c.send_event(CobErrorEvent(error));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 fn preprocess_cobweb_asset_files(
+    mut c: Commands,
     asset_server: Res<AssetServer>,
     mut events: EventReader<AssetEvent<CobAssetFile>>,
     mut cob_files: ResMut<LoadedCobAssetFiles>,
     mut assets: ResMut<Assets<CobAssetFile>>,
     mut cob_cache: ResMut<CobAssetCache>,
     mut commands_buffer: ResMut<CommandsBuffer>,
+    mut diagnostics: ResMut<CobDiagnostics>,
+    libraries: Res<CobLibraries>,
 )
 {
     for event in events.read() {
@@ -38,11 +52,18 @@ fn preprocess_cobweb_asset_files(
 
         match asset {
             CobAssetFile::Ignore => continue,
+            CobAssetFile::Failed(error) => {
+                tracing::error!("{error}");
+                c.send_event(CobErrorEvent(error));
+            }
             CobAssetFile::File {
                 #[cfg(feature = "editor")]
                 hash,
                 data,
             } => {
+                // Errors from previous versions of the file are stale.
+                diagnostics.clear_file(data.file.as_str());
+                check_cob_requirements(&data, &libraries, &mut cob_cache);
                 preprocess_cob_file(
                     &asset_server,
                     &mut cob_files,
@@ -56,6 +77,8 @@ fn preprocess_cobweb_asset_files(
         }
     }
 
+    send_cob_cache_errors(&mut c, &mut cob_cache);
+
     // Note: we don't try to handle asset load failures here because a file load failure is assumed to be
     // catastrophic.
 }
@@ -70,20 +93,27 @@ fn process_cobweb_asset_files(
     mut commands_buffer: ResMut<CommandsBuffer>,
     mut scene_buffer: ResMut<SceneBuffer>,
     mut scene_builder: SceneBuilder,
+    mut def_values: ResMut<CobDefValues>,
+    budget: Res<CobProcessingBudget>,
 )
 {
-    let type_registry = types.read();
-
     if cob_cache.process_cobweb_asset_files(
-        &type_registry,
+        &types,
         &loadables,
         &mut c,
         &mut commands_buffer,
         &mut scene_buffer,
         &mut scene_builder,
+        budget.max_time_per_frame,
     ) {
         c.react().broadcast(CobCacheUpdated);
     }
+
+    for (file, constants) in cob_cache.take_updated_defs() {
+        def_values.set_file(file, constants);
+    }
+
+    send_cob_cache_errors(&mut c, &mut cob_cache);
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -120,7 +150,6 @@ fn apply_pending_node_updates_pre(
 /// to a loadable ref.
 #[cfg(feature = "hot_reload")]
 fn apply_pending_node_updates_extract(
-    types: Res<AppTypeRegistry>,
     loadables: Res<LoadableRegistry>,
     mut cob_cache: ResMut<CobAssetCache>,
     mut c: Commands,
@@ -135,10 +164,8 @@ fn apply_pending_node_updates_extract(
         return;
     }
 
-    // Extract scenes from recently loaded files.
-    let type_registry = types.read();
+    // Insert scenes from recently loaded files.
     cob_cache.handle_pending_scene_extraction(
-        &type_registry,
         &loadables,
         &mut c,
         &mut scene_buffer,
@@ -192,11 +219,58 @@ fn cleanup_despawned_loaded_entities(world: &mut World)
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Reapplies themed scene loadables after the theme changes.
+///
+/// Only needed without hot_reload, because otherwise files that use theme tokens are re-processed.
+#[cfg(not(feature = "hot_reload"))]
+fn refresh_themed_scene_nodes(
+    mut c: Commands,
+    mut cob_cache: ResMut<CobAssetCache>,
+    mut scene_buffer: ResMut<SceneBuffer>,
+    loaders: Res<LoadableRegistry>,
+)
+{
+    let (theme, high_contrast) = cob_cache.take_theme_refresh();
+    scene_buffer.refresh_themed_nodes(&theme, high_contrast, &mut c, &loaders);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Stops tracking despawned entities that were loaded with themed scene nodes.
+#[cfg(not(feature = "hot_reload"))]
+fn cleanup_despawned_themed_entities(
+    mut scene_buffer: ResMut<SceneBuffer>,
+    mut removed: RemovedComponents<HasLoadables>,
+)
+{
+    for removed in removed.read() {
+        scene_buffer.remove_themed_entity(removed);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Reactive event broadcasted when the [`CobAssetCache`] has been updated with COB asset data.
 pub struct CobCacheUpdated;
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Resource that limits how long extracted COB files are applied each frame.
+///
+/// COB files are parsed on the asset loading task pool, and their definitions, commands, and scenes are resolved
+/// and extracted on the async compute task pool. Extracted files are then applied to the commands buffer and
+/// scene buffer on the main thread. Files that don't fit in the budget are applied in later frames. At least one
+/// file is applied each frame.
+///
+/// Defaults to `None`, which applies all extracted files in the frame they finish extracting.
+#[derive(Resource, Default, Debug, Copy, Clone, PartialEq)]
+pub struct CobProcessingBudget
+{
+    pub max_time_per_frame: Option<Duration>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// System set in [`First`] where files are processed.
 #[derive(SystemSet, Debug, Hash, Eq, PartialEq, Copy, Clone)]
 pub struct FileProcessingSet;
@@ -214,7 +288,9 @@ impl Plugin for CobAssetCachePlugin
         app.insert_resource(CobAssetCache::new(manifest_map.clone()))
             .register_asset_tracker::<CobAssetCache>()
             .insert_resource(CommandsBuffer::new())
+            .insert_resource(CobDefValues::new(manifest_map.clone()))
             .insert_resource(SceneBuffer::new(manifest_map))
+            .init_resource::<CobProcessingBudget>()
             .add_systems(
                 First,
                 (
@@ -255,10 +331,24 @@ impl Plugin for CobAssetCachePlugin
 
         #[cfg(not(feature = "hot_reload"))]
         {
-            app.configure_sets(First, FileProcessingSet.run_if(in_state(LoadState::Loading)))
-                .add_systems(OnExit(LoadState::Loading), |mut c: Commands| {
-                    c.remove_resource::<CommandsBuffer>();
-                });
+            // Files are processed after the initial load only while load groups are loading.
+            app.configure_sets(
+                First,
+                FileProcessingSet.run_if(in_state(LoadState::Loading).or(|g: Res<LoadGroups>| g.any_loading())),
+            )
+            .add_systems(OnExit(LoadState::Loading), |mut c: Commands, groups: Res<LoadGroups>| {
+                if !groups.is_empty() {
+                    return;
+                }
+                c.remove_resource::<CommandsBuffer>();
+            })
+            .add_systems(
+                First,
+                refresh_themed_scene_nodes
+                    .run_if(|s: Res<CobAssetCache>| s.needs_theme_refresh())
+                    .after(FileProcessingSet),
+            )
+            .add_systems(Last, cleanup_despawned_themed_entities);
         }
 
         #[cfg(feature = "hot_reload")]
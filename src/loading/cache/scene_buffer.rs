@@ -1,15 +1,17 @@
 use std::any::TypeId;
+use std::cmp::Reverse;
 use std::collections::hash_map::Entry::{Occupied, Vacant};
-use std::collections::HashMap;
-#[cfg(feature = "hot_reload")]
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, Mutex, MutexGuard};
 
 use bevy::ecs::world::Command;
 use bevy::prelude::*;
+use bevy::utils::Instant;
 #[cfg(feature = "hot_reload")]
 use bevy_cobweb::prelude::*;
 use smallvec::SmallVec;
+#[cfg(not(feature = "hot_reload"))]
+use smol_str::SmolStr;
 
 use crate::prelude::*;
 
@@ -112,43 +114,36 @@ enum InsertNodeResult
 
 //-------------------------------------------------------------------------------------------------------------------
 
-#[cfg(feature = "hot_reload")]
-struct RevertCommand
-{
-    entity: Entity,
-    reverter: fn(Entity, &mut World),
-}
-
-#[cfg(feature = "hot_reload")]
-impl Command for RevertCommand
-{
-    fn apply(self, world: &mut World)
-    {
-        (self.reverter)(self.entity, world);
-    }
-}
-
-//-------------------------------------------------------------------------------------------------------------------
-
 struct NodeBuildCommand
 {
     callback: fn(&mut World, Entity, ReflectedLoadable, SceneRef),
     entity: Entity,
     scene_ref: SceneRef,
     loadable: ReflectedLoadable,
+    type_id: TypeId,
 }
 
 impl Command for NodeBuildCommand
 {
     fn apply(self, world: &mut World)
     {
-        (self.callback)(world, self.entity, self.loadable, self.scene_ref);
+        let profile_start = world.contains_resource::<CobwebUiProfile>().then(Instant::now);
+
+        match self.loadable {
+            ReflectedLoadable::Bound(bound) => {
+                apply_bound_loadable(world, self.entity, self.callback, bound, self.scene_ref);
+            }
+            loadable => (self.callback)(world, self.entity, loadable, self.scene_ref),
+        }
+
+        if let Some(start) = profile_start {
+            record_loadable_apply(world, self.type_id, start.elapsed());
+        }
     }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
 
-#[cfg(feature = "hot_reload")]
 #[derive(Debug, Default)]
 struct RefreshCtx
 {
@@ -158,7 +153,6 @@ struct RefreshCtx
     needs_updates: Vec<(Entity, NodeInitializer, SceneRef)>,
 }
 
-#[cfg(feature = "hot_reload")]
 impl RefreshCtx
 {
     fn add_revert(&mut self, subscription: SubscriptionRef, type_id: TypeId)
@@ -190,6 +184,16 @@ impl RefreshCtx
             .push((subscription.entity, subscription.initializer, scene_ref.clone()));
     }
 
+    #[cfg(feature = "hot_reload")]
+    fn num_reverts(&self) -> usize
+    {
+        self.needs_revert.iter().map(|(_, type_ids)| type_ids.len()).sum()
+    }
+    #[cfg(feature = "hot_reload")]
+    fn num_updates(&self) -> usize
+    {
+        self.needs_updates.len()
+    }
     fn reverts(&mut self) -> impl Iterator<Item = (Entity, HashSet<TypeId>)> + '_
     {
         self.needs_revert.drain(..)
@@ -211,6 +215,48 @@ struct SubscriptionRef
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// A scene node loadable that uses theme tokens, resolved for each theme variant.
+#[cfg(not(feature = "hot_reload"))]
+#[derive(Debug)]
+pub(crate) struct ThemedLoadable
+{
+    /// The loadable's index in its scene node.
+    pub(crate) index: usize,
+    pub(crate) type_id: TypeId,
+    pub(crate) full_type_name: &'static str,
+    /// [ (theme, high contrast, value) ]
+    /// - Themes that the loadable's tokens don't have values for use the value of the empty theme.
+    pub(crate) variants: Vec<(SmolStr, bool, ReflectedLoadable)>,
+}
+
+#[cfg(not(feature = "hot_reload"))]
+impl ThemedLoadable
+{
+    fn get(&self, theme: &str, high_contrast: bool) -> Option<&ReflectedLoadable>
+    {
+        let find = |theme: &str| {
+            self.variants
+                .iter()
+                .find(|(t, hc, _)| t.as_str() == theme && *hc == high_contrast)
+                .map(|(_, _, value)| value)
+        };
+        find(theme).or_else(|| find(""))
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Tracks the themed loadables of a scene node and the entities loaded with it.
+#[cfg(not(feature = "hot_reload"))]
+#[derive(Debug, Default)]
+struct ThemedSceneNode
+{
+    loadables: Vec<ThemedLoadable>,
+    subscriptions: SmallVec<[SubscriptionRef; 1]>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 #[derive(Resource, Debug, Default)]
 pub struct SceneBuffer
 {
@@ -219,8 +265,8 @@ pub struct SceneBuffer
 
     /// Tracks loadables from all loaded files.
     /// - Note: If a scene node is hot-removed, then this map will *not* be updated. However, the scene's loader
-    /// will correctly update, so new scene spawns won't include dead nodes (and existing scenes will be
-    /// repaired).
+    ///   will correctly update, so new scene spawns won't include dead nodes (and existing scenes will be
+    ///   repaired).
     loadables: HashMap<SceneRef, SmallVec<[ErasedLoadable; 4]>>,
 
     /// Tracks subscriptions to scene paths.
@@ -233,6 +279,15 @@ pub struct SceneBuffer
     /// Records loadables that need to be reverted/updated.
     #[cfg(feature = "hot_reload")]
     refresh_ctx: RefreshCtx,
+
+    /// Tracks scene nodes with loadables that use theme tokens.
+    /// - Without `hot_reload`, files are not re-processed when the theme changes, so themed loadables are
+    /// reapplied from here instead.
+    #[cfg(not(feature = "hot_reload"))]
+    themed_nodes: HashMap<SceneRef, ThemedSceneNode>,
+    /// Tracks entities loaded with themed scene nodes for cleanup.
+    #[cfg(not(feature = "hot_reload"))]
+    themed_subscriptions_rev: HashMap<Entity, SceneRef>,
 }
 
 impl SceneBuffer
@@ -340,7 +395,14 @@ impl SceneBuffer
             return;
         };
 
-        for loadable in loadables.iter() {
+        // Order loadables by priority.
+        // - The sort is stable, so loadables with the same priority are applied in declaration order.
+        let mut order: SmallVec<[usize; 16]> = (0..loadables.len()).collect();
+        if callbacks.has_priorities() {
+            order.sort_by_key(|idx| Reverse(callbacks.get_priority(loadables[*idx].type_id)));
+        }
+
+        for loadable in order.iter().map(|idx| &loadables[*idx]) {
             let Some(callback) = callbacks.get_for_node(loadable.type_id) else {
                 tracing::warn!("found loadable at {:?} that wasn't registered with CobLoadableRegistrationAppExt",
                     scene_ref);
@@ -352,6 +414,7 @@ impl SceneBuffer
                 entity: subscription.entity,
                 scene_ref: scene_ref.clone(),
                 loadable: loadable.loadable.clone(),
+                type_id: loadable.type_id,
             });
         }
 
@@ -405,11 +468,60 @@ impl SceneBuffer
             self.subscriptions_rev
                 .insert(entity, (scene_ref.clone(), initializer));
         }
+        #[cfg(not(feature = "hot_reload"))]
+        {
+            if let Some(themed) = self.themed_nodes.get_mut(&scene_ref) {
+                // Pooled scene nodes are tracked again when they are reused.
+                if self
+                    .themed_subscriptions_rev
+                    .insert(entity, scene_ref.clone())
+                    .is_none()
+                {
+                    themed.subscriptions.push(subscription);
+                }
+            }
+        }
 
         // Load the entity immediately.
         self.build_entity(subscription, scene_ref, callbacks, c);
     }
 
+    /// Re-applies loadables to an entity that may already be tracked (e.g. a node in a pooled scene instance).
+    pub(crate) fn rebuild_entity(
+        &mut self,
+        entity: Entity,
+        scene_ref: SceneRef,
+        initializer: NodeInitializer,
+        callbacks: &LoadableRegistry,
+        c: &mut Commands,
+        #[cfg(feature = "hot_reload")] commands_buffer: &CommandsBuffer,
+    )
+    {
+        // Entities are still subscribed if they weren't despawned, so they only need to be rebuilt.
+        #[cfg(feature = "hot_reload")]
+        {
+            if let Some((scene_ref, _)) = self.subscriptions_rev.get(&entity) {
+                let subscription = SubscriptionRef { entity, initializer };
+                if commands_buffer.is_blocked() {
+                    self.refresh_ctx.add_update(subscription, scene_ref.clone());
+                } else {
+                    self.build_entity(subscription, scene_ref.clone(), callbacks, c);
+                }
+                return;
+            }
+        }
+
+        self.track_entity(
+            entity,
+            scene_ref,
+            initializer,
+            callbacks,
+            c,
+            #[cfg(feature = "hot_reload")]
+            commands_buffer,
+        );
+    }
+
     /// Adds an entity to the tracking context.
     ///
     /// Queues the entity to be loaded. This allows synchronizing a new entity (e.g. a new scene entity) with
@@ -444,6 +556,109 @@ impl SceneBuffer
         self.refresh_ctx.add_update(subscription, scene_ref.clone());
     }
 
+    /// Removes cached loadables for all scene nodes in a file.
+    ///
+    /// Entities that are already loaded keep their loadables.
+    pub(crate) fn evict_file(&mut self, file: &CobFile)
+    {
+        let scene_file = SceneFile::File(file.clone());
+        self.loadables.retain(|scene_ref, _| scene_ref.file != scene_file);
+        #[cfg(not(feature = "hot_reload"))]
+        {
+            self.themed_nodes
+                .retain(|scene_ref, _| scene_ref.file != scene_file);
+            self.themed_subscriptions_rev
+                .retain(|_, scene_ref| scene_ref.file != scene_file);
+        }
+    }
+
+    /// Records a scene node loadable that uses theme tokens so it can be reapplied when the theme changes.
+    #[cfg(not(feature = "hot_reload"))]
+    pub(crate) fn insert_themed_loadable(&mut self, scene_ref: &SceneRef, themed: ThemedLoadable)
+    {
+        let node = self.themed_nodes.entry(scene_ref.clone()).or_default();
+        match node.loadables.iter().position(|l| l.type_id == themed.type_id) {
+            Some(pos) => node.loadables[pos] = themed,
+            None => node.loadables.push(themed),
+        }
+    }
+
+    /// Reapplies themed loadables whose values changed for the given theme.
+    #[cfg(not(feature = "hot_reload"))]
+    pub(crate) fn refresh_themed_nodes(
+        &mut self,
+        theme: &str,
+        high_contrast: bool,
+        c: &mut Commands,
+        callbacks: &LoadableRegistry,
+    )
+    {
+        let mut refresh_ctx = RefreshCtx::default();
+        for (scene_ref, node) in self.themed_nodes.iter() {
+            for themed in node.loadables.iter() {
+                let Some(loadable) = themed.get(theme, high_contrast) else { continue };
+                let res = insert_node_loadable_entry(
+                    &mut self.loadables,
+                    scene_ref,
+                    Some(themed.index),
+                    loadable.clone(),
+                    themed.type_id,
+                    themed.full_type_name,
+                );
+                if res == InsertNodeResult::NoChange {
+                    continue;
+                }
+
+                for subscription in node.subscriptions.iter() {
+                    if res == InsertNodeResult::Changed {
+                        refresh_ctx.add_revert(*subscription, themed.type_id);
+                    }
+                    refresh_ctx.add_update(*subscription, scene_ref.clone());
+                }
+            }
+        }
+
+        for (entity, type_ids) in refresh_ctx.reverts() {
+            for type_id in type_ids {
+                c.queue(RevertLoadable { entity, type_id });
+            }
+        }
+        for (entity, initializer, scene_ref) in refresh_ctx.updates() {
+            self.build_entity(SubscriptionRef { entity, initializer }, scene_ref, callbacks, c);
+        }
+    }
+
+    /// Stops tracking a despawned entity that was loaded with a themed scene node.
+    #[cfg(not(feature = "hot_reload"))]
+    pub(super) fn remove_themed_entity(&mut self, dead_entity: Entity)
+    {
+        let Some(scene_ref) = self.themed_subscriptions_rev.remove(&dead_entity) else { return };
+        let Some(node) = self.themed_nodes.get_mut(&scene_ref) else { return };
+        let Some(dead) = node.subscriptions.iter().position(|s| s.entity == dead_entity) else { return };
+        node.subscriptions.swap_remove(dead);
+    }
+
+    /// Iterates the entities currently loaded with the given scene node.
+    ///
+    /// The scene ref's file must be a file path, not a manifest key.
+    #[cfg(feature = "hot_reload")]
+    pub fn iter_scene_node_entities(&self, scene_ref: &SceneRef) -> impl Iterator<Item = Entity> + '_
+    {
+        self.subscriptions
+            .get(scene_ref)
+            .into_iter()
+            .flat_map(|s| s.iter().map(|s| s.entity))
+    }
+
+    /// Gets the scene node an entity is loaded with.
+    #[cfg(feature = "hot_reload")]
+    pub fn get_entity_scene_node(&self, entity: Entity) -> Option<&SceneRef>
+    {
+        self.subscriptions_rev
+            .get(&entity)
+            .map(|(scene_ref, _)| scene_ref)
+    }
+
     /// Requests that the scene node an entity is subscribed to be reloaded on that entity.
     #[cfg(feature = "hot_reload")]
     pub fn request_reload(&mut self, entity: Entity)
@@ -459,12 +674,18 @@ impl SceneBuffer
     #[cfg(feature = "hot_reload")]
     pub(super) fn apply_pending_node_updates(&mut self, c: &mut Commands, callbacks: &LoadableRegistry)
     {
+        // Profile non-empty refreshes.
+        let num_reverts = self.refresh_ctx.num_reverts();
+        let num_updates = self.refresh_ctx.num_updates();
+        if num_reverts + num_updates > 0 {
+            c.queue(StartProfilingTimer);
+        }
+
         // Revert loadables as needed.
         // - Note: We currently assume the order of reverts doesn't matter.
         for (entity, type_ids) in self.refresh_ctx.reverts() {
             for type_id in type_ids {
-                let Some(reverter) = callbacks.get_for_revert(type_id) else { continue };
-                c.queue(RevertCommand { entity, reverter });
+                c.queue(RevertLoadable { entity, type_id });
             }
         }
 
@@ -473,6 +694,10 @@ impl SceneBuffer
         for (entity, initializer, scene_ref) in needs_updates {
             self.build_entity(SubscriptionRef { entity, initializer }, scene_ref, callbacks, c);
         }
+
+        if num_reverts + num_updates > 0 {
+            c.queue(RecordHotReload { nodes: num_updates, reverts: num_reverts });
+        }
     }
 
     /// Does not clean up subscriptions. We assume subscribed entities will be despawned and cleaned up with
@@ -485,9 +710,11 @@ impl SceneBuffer
         // Revert all loadables on the node.
         let mut loadables = self.loadables.remove(&scene_ref);
         for removed in loadables.as_mut().into_iter().flat_map(|l| l.drain(..)) {
-            let Some(reverter) = callbacks.get_for_revert(removed.type_id) else { continue };
+            if callbacks.get_for_revert(removed.type_id).is_none() {
+                continue;
+            }
             for subscription in subscriptions {
-                c.queue(RevertCommand { entity: subscription.entity, reverter });
+                c.queue(RevertLoadable { entity: subscription.entity, type_id: removed.type_id });
             }
         }
     }
@@ -136,7 +136,9 @@ fn expand_macro_recursive(
                 // from macro call contents.
                 let new_layer = CobSceneLayer {
                     name_fill: CobFill::default(),
+                    patch: None,
                     name: CobSceneNodeName(SmolStr::from(layer_id)),
+                    extends: None,
                     entries: vec![],
                 };
                 result_entries.push(CobSceneLayerEntry::Layer(new_layer));
@@ -218,19 +220,20 @@ impl SceneMacrosResolver
     pub fn expand(&mut self, call: &CobSceneMacroCall) -> Result<Vec<CobSceneLayerEntry>, String>
     {
         let path = call.path.as_str();
-        let mut result_entries = self
-            .get(path)
-            .ok_or_else(|| format!("no scene macro definition at '{path}'"))?
-            .entries
-            .clone();
-
-        self.id_scratch = expand_macro_recursive(
-            std::mem::take(&mut self.id_scratch),
-            &mut result_entries,
-            &call.container.entries,
-        );
-
-        Ok(result_entries)
+        self.expand_entries(path, &call.container.entries)
+            .ok_or_else(|| format!("no scene macro definition at '{path}'"))
+    }
+
+    /// Merges override entries into a copy of the entries stored at `path`.
+    ///
+    /// Returns `None` if there is no entry at `path`.
+    pub fn expand_entries(&mut self, path: &str, entries: &[CobSceneLayerEntry]) -> Option<Vec<CobSceneLayerEntry>>
+    {
+        let mut result_entries = self.get(path)?.entries.clone();
+
+        self.id_scratch = expand_macro_recursive(std::mem::take(&mut self.id_scratch), &mut result_entries, entries);
+
+        Some(result_entries)
     }
 
     pub(crate) fn append(&mut self, alias: &CobImportAlias, to_append: &Self)
@@ -0,0 +1,171 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use smallvec::SmallVec;
+use smol_str::SmolStr;
+
+use super::*;
+use crate::loading::{CobFile, CobValue};
+use crate::prelude::CobImportAlias;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Name of the theme variant that theme tokens resolve to when high contrast is enabled.
+pub const HIGH_CONTRAST_THEME: &str = "high_contrast";
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Values of a theme token for each theme variant, in definition order.
+pub type ThemeTokenVariants = Vec<(SmolStr, CobValue)>;
+
+// [ token name : variants ]
+type ThemeTokensMap = HashMap<SmolStr, ThemeTokenVariants>;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Records a stack of theme token maps, along with the active theme variant.
+///
+/// Tokens are imported the same way as constants, so a token `bg` in a file imported as `ui` is referenced with
+/// `ThemeToken("ui::bg")`.
+#[derive(Default, Debug)]
+pub struct ThemeResolver
+{
+    stack: SmallVec<[(SmolStr, Arc<ThemeTokensMap>); 5]>,
+    new_file: ThemeTokensMap,
+    active: SmolStr,
+    high_contrast: bool,
+    /// Counts token lookups. Used to find files and loadables that must be refreshed when the theme changes.
+    uses: AtomicUsize,
+}
+
+impl ThemeResolver
+{
+    pub(crate) fn start_new_file(&mut self)
+    {
+        self.new_file = HashMap::default();
+    }
+
+    pub(crate) fn end_new_file(&mut self)
+    {
+        let map = std::mem::take(&mut self.new_file);
+        self.stack.push((SmolStr::default(), Arc::new(map)));
+    }
+
+    /// Sets the active theme variant.
+    pub(crate) fn set_active(&mut self, active: SmolStr)
+    {
+        self.active = active;
+    }
+
+    /// Sets whether tokens resolve their [`HIGH_CONTRAST_THEME`] variant.
+    pub(crate) fn set_high_contrast(&mut self, high_contrast: bool)
+    {
+        self.high_contrast = high_contrast;
+    }
+
+    /// Gets the active theme variant.
+    pub fn active(&self) -> &str
+    {
+        self.active.as_str()
+    }
+
+    /// Returns `true` if any token has been looked up in this resolver.
+    #[cfg(feature = "hot_reload")]
+    pub(crate) fn is_used(&self) -> bool
+    {
+        self.num_uses() > 0
+    }
+
+    /// Gets the number of times a token has been looked up in this resolver.
+    pub(crate) fn num_uses(&self) -> usize
+    {
+        self.uses.load(Ordering::Relaxed)
+    }
+
+    /// Gets the names of all theme variants that tokens in this resolver have values for.
+    #[cfg(not(feature = "hot_reload"))]
+    pub(crate) fn variant_names(&self) -> Vec<SmolStr>
+    {
+        let mut names: Vec<SmolStr> = vec![];
+        let maps = std::iter::once(&self.new_file).chain(self.stack.iter().map(|(_, m)| &**m));
+        for (name, _) in maps.flat_map(|m| m.values()).flatten() {
+            if !names.contains(name) {
+                names.push(name.clone());
+            }
+        }
+        names
+    }
+
+    /// Gets whether tokens resolve their [`HIGH_CONTRAST_THEME`] variant.
+    pub(crate) fn high_contrast(&self) -> bool
+    {
+        self.high_contrast
+    }
+
+    /// Adds an entry to the new file being collected.
+    pub(crate) fn insert(&mut self, file: &CobFile, name: SmolStr, variants: ThemeTokenVariants)
+    {
+        match self.new_file.entry(name) {
+            Entry::Vacant(vacant) => {
+                vacant.insert(variants);
+            }
+            Entry::Occupied(mut occupied) => {
+                tracing::warn!("overwriting theme token definition {} in {:?}", occupied.key().as_str(), file);
+                occupied.insert(variants);
+            }
+        }
+    }
+
+    /// Searches backward through the stack until a match is found, then returns the token's value for the active
+    /// theme.
+    ///
+    /// If high contrast is enabled, then the token's [`HIGH_CONTRAST_THEME`] variant is used if it exists. Falls back
+    /// to the token's first variant if it doesn't have a value for the active theme.
+    pub fn get(&self, path: impl AsRef<str>) -> Option<&CobValue>
+    {
+        let path = path.as_ref();
+        let variants = self.new_file.get(path).or_else(|| {
+            self.stack.iter().rev().find_map(|(prefix, m)| {
+                let stripped = path.strip_prefix(prefix.as_str())?;
+                let cleaned = stripped.strip_prefix(DEFS_SEPARATOR).unwrap_or(stripped);
+                m.get(cleaned)
+            })
+        })?;
+        self.uses.fetch_add(1, Ordering::Relaxed);
+
+        variants
+            .iter()
+            .find(|(variant, _)| self.high_contrast && *variant == HIGH_CONTRAST_THEME)
+            .or_else(|| variants.iter().find(|(variant, _)| *variant == self.active))
+            .or_else(|| variants.first())
+            .map(|(_, value)| value)
+    }
+
+    pub(crate) fn append(&mut self, alias: &CobImportAlias, to_append: &Self)
+    {
+        let alias = alias.as_str();
+
+        // Remove duplicate maps in self.
+        for (to_append_prefix, to_append) in to_append.stack.iter() {
+            let new_to_append_prefix = path_to_string(DEFS_SEPARATOR, &[alias, to_append_prefix]);
+            let Some(existing) = self.stack.iter().position(|(prefix, m)| {
+                *prefix == new_to_append_prefix && Arc::as_ptr(m) == Arc::as_ptr(to_append)
+            }) else {
+                continue;
+            };
+            self.stack.remove(existing);
+        }
+
+        // Append.
+        self.stack.reserve(to_append.stack.len());
+        self.stack
+            .extend(to_append.stack.iter().map(|(old_prefix, map)| {
+                let new_prefix = path_to_string(DEFS_SEPARATOR, &[alias, old_prefix]);
+                (new_prefix, map.clone())
+            }));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -36,11 +36,14 @@ fn get_scene_loadable_from_layer<'a, 'b>(
 //-------------------------------------------------------------------------------------------------------------------
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub enum CobSection
 {
     Manifest(CobManifest),
     Import(CobImport),
+    Require(CobRequire),
     Defs(CobDefs),
+    Theme(CobTheme),
     Commands(CobCommands),
     Scenes(CobScenes),
 }
@@ -52,7 +55,9 @@ impl CobSection
         match self {
             Self::Manifest(section) => section.write_to(first_section, writer),
             Self::Import(section) => section.write_to(first_section, writer),
+            Self::Require(section) => section.write_to(first_section, writer),
             Self::Defs(section) => section.write_to(first_section, writer),
+            Self::Theme(section) => section.write_to(first_section, writer),
             Self::Commands(section) => section.write_to(first_section, writer),
             Self::Scenes(section) => section.write_to(first_section, writer),
         }
@@ -69,10 +74,18 @@ impl CobSection
             (Some(section), fill, remaining) => return Ok((Some(Self::Import(section)), fill, remaining)),
             (None, fill, _) => fill,
         };
+        let fill = match CobRequire::try_parse(fill, content)? {
+            (Some(section), fill, remaining) => return Ok((Some(Self::Require(section)), fill, remaining)),
+            (None, fill, _) => fill,
+        };
         let fill = match rc(content, move |c| CobDefs::try_parse(fill, c))? {
             (Some(section), fill, remaining) => return Ok((Some(Self::Defs(section)), fill, remaining)),
             (None, fill, _) => fill,
         };
+        let fill = match rc(content, move |c| CobTheme::try_parse(fill, c))? {
+            (Some(section), fill, remaining) => return Ok((Some(Self::Theme(section)), fill, remaining)),
+            (None, fill, _) => fill,
+        };
         let fill = match rc(content, move |c| CobCommands::try_parse(fill, c))? {
             (Some(section), fill, remaining) => return Ok((Some(Self::Commands(section)), fill, remaining)),
             (None, fill, _) => fill,
@@ -89,6 +102,7 @@ impl CobSection
 //-------------------------------------------------------------------------------------------------------------------
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub struct Cob
 {
     /// Location of the cob file within the project's `assets` directory.
@@ -174,6 +188,15 @@ impl Cob
         })
     }
 
+    /// Returns `true` if any scenes section contains a scene patch.
+    pub fn has_scene_patches(&self) -> bool
+    {
+        self.sections.iter().any(|s| {
+            let CobSection::Scenes(scenes) = s else { return false };
+            scenes.scenes.iter().any(|s| s.patch.is_some())
+        })
+    }
+
     // TODO: This allocates a string to do loadable name checks.
     pub fn get_scene_loadable_mut(&mut self, path: &ScenePath, target_name: &str) -> Option<&mut CobLoadable>
     {
@@ -185,7 +208,7 @@ impl Cob
             let Some(root) = scenes
                 .scenes
                 .iter_mut()
-                .find(|s| s.name.as_str() == root_name)
+                .find(|s| s.patch.is_none() && s.name.as_str() == root_name)
             else {
                 continue;
             };
@@ -0,0 +1,134 @@
+use std::path::Path;
+
+use bincode::Options;
+use thiserror::Error;
+
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Magic bytes at the start of precompiled COB files.
+const COB_BINARY_MAGIC: &[u8; 4] = b"COBB";
+
+/// Version of the precompiled COB format. Must be incremented when the COB data structures or the encoding change.
+const COB_BINARY_VERSION: u16 = 2;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Errors produced when encoding or decoding precompiled COB files.
+#[derive(Debug, Error)]
+pub enum CobBinaryError
+{
+    #[error("missing COBB header")]
+    InvalidHeader,
+    #[error("COBB format version {found} is not supported (expected {expected}); recompile the file")]
+    VersionMismatch
+    {
+        found: u16, expected: u16
+    },
+    #[error("{0}")]
+    Bincode(#[from] bincode::Error),
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+impl Cob
+{
+    /// Encodes the COB data in the precompiled COBB format.
+    ///
+    /// The data is encoded with [`bincode`] after a `COBB` header and format version.
+    ///
+    /// See [`precompile_cob_files`].
+    pub fn to_binary(&self) -> Result<Vec<u8>, CobBinaryError>
+    {
+        let mut bytes = Vec::from(COB_BINARY_MAGIC.as_slice());
+        bytes.extend_from_slice(&COB_BINARY_VERSION.to_le_bytes());
+        bincode::DefaultOptions::new().serialize_into(&mut bytes, self)?;
+        Ok(bytes)
+    }
+
+    /// Decodes COB data from the precompiled COBB format.
+    pub fn from_binary(bytes: &[u8]) -> Result<Self, CobBinaryError>
+    {
+        let Some(bytes) = bytes.strip_prefix(COB_BINARY_MAGIC.as_slice()) else {
+            return Err(CobBinaryError::InvalidHeader);
+        };
+        let Some((version, bytes)) = bytes.split_first_chunk::<2>() else {
+            return Err(CobBinaryError::InvalidHeader);
+        };
+        let version = u16::from_le_bytes(*version);
+        if version != COB_BINARY_VERSION {
+            return Err(CobBinaryError::VersionMismatch { found: version, expected: COB_BINARY_VERSION });
+        }
+        Ok(bincode::DefaultOptions::new().deserialize(bytes)?)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Errors produced by [`precompile_cob_files`].
+#[derive(Debug, Error)]
+pub enum CobPrecompileError
+{
+    #[error("failed accessing {0:?}: {1}")]
+    Io(std::path::PathBuf, std::io::Error),
+    #[error("failed parsing {0:?}")]
+    Parse(String),
+    #[error("failed encoding {0:?}: {1}")]
+    Binary(String, CobBinaryError),
+}
+
+/// Compiles every `.cob` file in an asset directory (and its subdirectories) to a `.cobb` file next to it.
+///
+/// Returns the number of compiled files. Can be called from a build script or a small CLI tool before packaging
+/// release builds that use the `precompiled_cob` feature.
+///
+/// ```rust,ignore
+/// // build.rs
+/// fn main()
+/// {
+///     println!("cargo:rerun-if-changed=assets");
+///     bevy_cobweb_ui::prelude::precompile_cob_files("assets").unwrap();
+/// }
+/// ```
+pub fn precompile_cob_files(assets_dir: impl AsRef<Path>) -> Result<usize, CobPrecompileError>
+{
+    let assets_dir = assets_dir.as_ref();
+    let mut count = 0;
+    let mut dirs = vec![assets_dir.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        let entries = std::fs::read_dir(&dir).map_err(|err| CobPrecompileError::Io(dir.clone(), err))?;
+        for entry in entries {
+            let path = entry
+                .map_err(|err| CobPrecompileError::Io(dir.clone(), err))?
+                .path();
+            if path.is_dir() {
+                dirs.push(path);
+                continue;
+            }
+            if path.extension().is_none_or(|e| e != "cob") {
+                continue;
+            }
+
+            // The file name is the path relative to the asset directory, which is how files are identified when
+            // loaded.
+            let file = path
+                .strip_prefix(assets_dir)
+                .unwrap_or(&path)
+                .to_string_lossy()
+                .replace('\\', "/");
+            let content = std::fs::read_to_string(&path).map_err(|err| CobPrecompileError::Io(path.clone(), err))?;
+            let cob = Cob::parse(Span::new_extra(&content, CobLocationMetadata { file: &file }))
+                .map_err(|_| CobPrecompileError::Parse(file.clone()))?;
+            let bytes = cob
+                .to_binary()
+                .map_err(|err| CobPrecompileError::Binary(file.clone(), err))?;
+            let out = path.with_extension("cobb");
+            std::fs::write(&out, bytes).map_err(|err| CobPrecompileError::Io(out, err))?;
+            count += 1;
+        }
+    }
+    Ok(count)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -18,6 +18,7 @@ use crate::prelude::*;
 ///
 /// Example: `ui/home.cob` for a `home` cobweb asset in `assets/ui`.
 #[derive(Debug, Clone, Deref, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub struct CobFile(Arc<str>);
 
 impl CobFile
@@ -65,6 +66,22 @@ impl CobFile
     {
         &self.0
     }
+
+    /// Gets the asset path that is loaded for this file.
+    ///
+    /// With the `precompiled_cob` feature, this is the precompiled `.cobb` file next to the `.cob` file.
+    pub fn asset_path(&self) -> String
+    {
+        #[cfg(feature = "precompiled_cob")]
+        {
+            format!("{}b", self.as_str())
+        }
+
+        #[cfg(not(feature = "precompiled_cob"))]
+        {
+            String::from(self.as_str())
+        }
+    }
 }
 
 impl Default for CobFile
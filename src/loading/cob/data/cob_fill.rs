@@ -37,6 +37,7 @@ fn invalid_postfill(c: char) -> bool
 /// Includes whitespace (spaces and newlines), comments (line and block comments), and ignored characters (commas
 /// and semicolons).
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub struct CobFill
 {
     // TODO: replace with Cow of string slice
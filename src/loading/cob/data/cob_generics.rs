@@ -12,6 +12,7 @@ use crate::prelude::*;
 //-------------------------------------------------------------------------------------------------------------------
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub struct CobRustPrimitive
 {
     pub fill: CobFill,
@@ -63,6 +64,7 @@ impl CobRustPrimitive
 
 /// Any item that can appear in a generic.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub enum CobGenericItem
 {
     Struct
@@ -231,6 +233,7 @@ impl CobGenericItem
 /// Note that constants and macros are unavailable inside generics.
 // This is currently a newtype for `CobGenericItem`, but may in the future be reworked to include macro params.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub struct CobGenericValue(pub CobGenericItem);
 
 impl CobGenericValue
@@ -268,6 +271,7 @@ impl CobGenericValue
 
 /// Note that constants and macros are unavailable inside generics.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub struct CobGenerics
 {
     /// Each of these values is expected to take care of its own fill.
@@ -10,6 +10,7 @@ use crate::prelude::*;
 //-------------------------------------------------------------------------------------------------------------------
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub struct CobLoadableIdentifier
 {
     pub name: SmolStr,
@@ -89,6 +90,7 @@ impl TryFrom<&'static str> for CobLoadableIdentifier
 
 /// Variant for [`CobLoadable`].
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub enum CobLoadableVariant
 {
     /// Corresponds to a unit struct.
@@ -188,6 +190,7 @@ impl CobLoadableVariant
 //-------------------------------------------------------------------------------------------------------------------
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub struct CobLoadable
 {
     pub fill: CobFill,
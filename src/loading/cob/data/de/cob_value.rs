@@ -44,6 +44,7 @@ impl<'de> serde::Deserializer<'de> for &'de CobValue
             CobValue::None(_) => visitor.visit_none(),
             CobValue::String(s) => visitor.visit_borrowed_str(s.as_str()),
             CobValue::Constant(_) => Err(self.invalid_type(&visitor)),
+            CobValue::Bind(_) => Err(self.invalid_type(&visitor)),
         }
     }
 
@@ -296,6 +297,7 @@ impl CobValue
             CobValue::None(_) => format!("None"),
             CobValue::String(s) => format!("string \"{}\"", s.as_str()),
             CobValue::Constant(constant) => format!("constant ${}", constant.path.as_str()),
+            CobValue::Bind(bind) => format!("binding @bind({})", bind.source.as_str()),
         }
     }
 }
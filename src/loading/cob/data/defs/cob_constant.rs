@@ -12,6 +12,7 @@ use crate::prelude::*;
 
 /// Constant name must be `$` followed by an identifier. Names do not include `a::b::` path segments.
 #[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub struct CobConstantName
 {
     pub name: SmolStr,
@@ -44,6 +45,7 @@ impl CobConstantName
 
 /// Constant paths must be a series of snake-case identifiers separated by `::`. E.g. `$a::b::my_constant`.
 #[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub struct CobConstantPath
 {
     pub path: SmolStr,
@@ -81,6 +83,7 @@ impl CobConstantPath
 
 /// Commands are parsed as loadables.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub enum CobConstantValue
 {
     Value(CobValue),
@@ -155,6 +158,7 @@ impl CobConstantValue
 //-------------------------------------------------------------------------------------------------------------------
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub struct CobConstantDef
 {
     pub start_fill: CobFill,
@@ -211,6 +215,7 @@ impl CobConstantDef
 //-------------------------------------------------------------------------------------------------------------------
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub struct CobConstant
 {
     pub start_fill: CobFill,
@@ -67,6 +67,7 @@ fn try_parse_scene_group(
 
 /// Command that can be used in scene macro invocations to rearrange loadables in the macro's scene content.
 #[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub enum CobSceneMacroCommandType
 {
     /// E.g. `^BorderColor`
@@ -110,6 +111,7 @@ impl CobSceneMacroCommandType
 //-------------------------------------------------------------------------------------------------------------------
 
 #[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub struct CobSceneMacroCommand
 {
     pub start_fill: CobFill,
@@ -156,6 +158,7 @@ impl CobSceneMacroCommand
 
 /// Scene macro name must be `+` followed by a loadable identifier. Names do not include `a::b::` path segments.
 #[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub struct CobSceneMacroName
 {
     pub name: SmolStr,
@@ -188,6 +191,7 @@ impl CobSceneMacroName
 
 /// Scene macro paths must be a series of snake-case identifiers separated by `::`. E.g. `+a::b::my_constant`.
 #[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub struct CobSceneMacroPath
 {
     pub path: SmolStr,
@@ -225,6 +229,7 @@ impl CobSceneMacroPath
 
 /// Scene group for scene macro definitions.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub struct CobSceneMacroValue
 {
     /// Fill before opening `\`.
@@ -283,6 +288,7 @@ impl CobSceneMacroValue
 
 /// Scene group for scene macro invocations.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub struct CobSceneMacroContainer
 {
     pub entries: Vec<CobSceneLayerEntry>,
@@ -333,6 +339,7 @@ impl CobSceneMacroContainer
 //-------------------------------------------------------------------------------------------------------------------
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub struct CobSceneMacroDef
 {
     pub start_fill: CobFill,
@@ -389,6 +396,7 @@ impl CobSceneMacroDef
 //-------------------------------------------------------------------------------------------------------------------
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub struct CobSceneMacroCall
 {
     pub start_fill: CobFill,
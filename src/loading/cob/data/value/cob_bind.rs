@@ -0,0 +1,193 @@
+use nom::bytes::complete::tag;
+use nom::character::complete::char;
+use nom::combinator::recognize;
+use nom::multi::many0_count;
+use nom::sequence::{terminated, tuple};
+use nom::Parser;
+use smol_str::SmolStr;
+
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Binding to a reactive data source, e.g. `@bind(PlayerName)`.
+///
+/// Source names are registered with [`CobBindingAppExt`](crate::prelude::CobBindingAppExt). They must be a series
+/// of identifiers separated by `::`.
+///
+/// Loadables with bindings are deserialized when applied to an entity, using the current values of their
+/// sources. They are re-applied whenever a source changes.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
+pub struct CobBind
+{
+    pub start_fill: CobFill,
+    pub source: SmolStr,
+}
+
+impl CobBind
+{
+    pub fn write_to(&self, writer: &mut impl RawSerializer) -> Result<(), std::io::Error>
+    {
+        self.write_to_with_space(writer, "")
+    }
+
+    pub fn write_to_with_space(&self, writer: &mut impl RawSerializer, space: &str) -> Result<(), std::io::Error>
+    {
+        self.start_fill.write_to_or_else(writer, space)?;
+        writer.write_bytes("@bind(".as_bytes())?;
+        writer.write_bytes(self.source.as_bytes())?;
+        writer.write_bytes(")".as_bytes())?;
+        Ok(())
+    }
+
+    pub fn try_parse(start_fill: CobFill, content: Span) -> Result<(Option<Self>, CobFill, Span), SpanError>
+    {
+        // NOTE: recursion not tested here (not vulnerable)
+
+        let Ok((remaining, _)) = tag::<_, _, ()>("@bind(").parse(content) else {
+            return Ok((None, start_fill, content));
+        };
+        let Ok((remaining, source)) = recognize(tuple((
+            many0_count(terminated(anything_identifier, tag("::"))),
+            anything_identifier,
+        )))
+        .parse(remaining) else {
+            tracing::warn!("binding is missing a source name at {}", get_location(content).as_str());
+            return Err(span_verify_error(content));
+        };
+        let Ok((remaining, _)) = char::<_, ()>(')').parse(remaining) else {
+            tracing::warn!("binding is missing closing ')' at {}", get_location(content).as_str());
+            return Err(span_verify_error(content));
+        };
+        let (end_fill, remaining) = CobFill::parse(remaining);
+
+        let bind = Self { start_fill, source: SmolStr::from(*source.fragment()) };
+        Ok((Some(bind), end_fill, remaining))
+    }
+
+    pub fn recover_fill(&mut self, other: &Self)
+    {
+        self.start_fill.recover(&other.start_fill);
+    }
+
+    pub fn new(source: impl AsRef<str>) -> Self
+    {
+        Self {
+            start_fill: CobFill::default(),
+            source: SmolStr::from(source.as_ref()),
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Calls `visitor` on every value in a loadable, including values nested in containers.
+///
+/// Containers are visited before their contents.
+pub fn visit_loadable_values(loadable: &CobLoadable, visitor: &mut impl FnMut(&CobValue))
+{
+    match &loadable.variant {
+        CobLoadableVariant::Unit => (),
+        CobLoadableVariant::Tuple(tuple) => visit_values(&tuple.entries, visitor),
+        CobLoadableVariant::Array(array) => visit_values(&array.entries, visitor),
+        CobLoadableVariant::Map(map) => visit_map_values(map, visitor),
+        CobLoadableVariant::Enum(cob_enum) => visit_enum_values(cob_enum, visitor),
+    }
+}
+
+fn visit_values(values: &[CobValue], visitor: &mut impl FnMut(&CobValue))
+{
+    for value in values.iter() {
+        visit_value(value, visitor);
+    }
+}
+
+fn visit_value(value: &CobValue, visitor: &mut impl FnMut(&CobValue))
+{
+    (visitor)(value);
+    match value {
+        CobValue::Enum(cob_enum) => visit_enum_values(cob_enum, visitor),
+        CobValue::Array(array) => visit_values(&array.entries, visitor),
+        CobValue::Tuple(tuple) => visit_values(&tuple.entries, visitor),
+        CobValue::Map(map) => visit_map_values(map, visitor),
+        _ => (),
+    }
+}
+
+fn visit_enum_values(cob_enum: &CobEnum, visitor: &mut impl FnMut(&CobValue))
+{
+    match &cob_enum.variant {
+        CobEnumVariant::Unit => (),
+        CobEnumVariant::Tuple(tuple) => visit_values(&tuple.entries, visitor),
+        CobEnumVariant::Array(array) => visit_values(&array.entries, visitor),
+        CobEnumVariant::Map(map) => visit_map_values(map, visitor),
+    }
+}
+
+fn visit_map_values(map: &CobMap, visitor: &mut impl FnMut(&CobValue))
+{
+    for entry in map.entries.iter() {
+        let CobMapEntry::KeyValue(keyvalue) = entry else { continue };
+        if let CobMapKey::Value(key) = &keyvalue.key {
+            visit_value(key, visitor);
+        }
+        visit_value(&keyvalue.value, visitor);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Mutable version of [`visit_loadable_values`].
+pub fn visit_loadable_values_mut(loadable: &mut CobLoadable, visitor: &mut impl FnMut(&mut CobValue))
+{
+    match &mut loadable.variant {
+        CobLoadableVariant::Unit => (),
+        CobLoadableVariant::Tuple(tuple) => visit_values_mut(&mut tuple.entries, visitor),
+        CobLoadableVariant::Array(array) => visit_values_mut(&mut array.entries, visitor),
+        CobLoadableVariant::Map(map) => visit_map_values_mut(map, visitor),
+        CobLoadableVariant::Enum(cob_enum) => visit_enum_values_mut(cob_enum, visitor),
+    }
+}
+
+fn visit_values_mut(values: &mut [CobValue], visitor: &mut impl FnMut(&mut CobValue))
+{
+    for value in values.iter_mut() {
+        visit_value_mut(value, visitor);
+    }
+}
+
+fn visit_value_mut(value: &mut CobValue, visitor: &mut impl FnMut(&mut CobValue))
+{
+    (visitor)(value);
+    match value {
+        CobValue::Enum(cob_enum) => visit_enum_values_mut(cob_enum, visitor),
+        CobValue::Array(array) => visit_values_mut(&mut array.entries, visitor),
+        CobValue::Tuple(tuple) => visit_values_mut(&mut tuple.entries, visitor),
+        CobValue::Map(map) => visit_map_values_mut(map, visitor),
+        _ => (),
+    }
+}
+
+fn visit_enum_values_mut(cob_enum: &mut CobEnum, visitor: &mut impl FnMut(&mut CobValue))
+{
+    match &mut cob_enum.variant {
+        CobEnumVariant::Unit => (),
+        CobEnumVariant::Tuple(tuple) => visit_values_mut(&mut tuple.entries, visitor),
+        CobEnumVariant::Array(array) => visit_values_mut(&mut array.entries, visitor),
+        CobEnumVariant::Map(map) => visit_map_values_mut(map, visitor),
+    }
+}
+
+fn visit_map_values_mut(map: &mut CobMap, visitor: &mut impl FnMut(&mut CobValue))
+{
+    for entry in map.entries.iter_mut() {
+        let CobMapEntry::KeyValue(keyvalue) = entry else { continue };
+        if let CobMapKey::Value(key) = &mut keyvalue.key {
+            visit_value_mut(key, visitor);
+        }
+        visit_value_mut(&mut keyvalue.value, visitor);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -70,6 +70,7 @@ fn parse_hex_u32(input: Span) -> IResult<Span, u32>
 //-------------------------------------------------------------------------------------------------------------------
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub struct CobHexColor
 {
     pub fill: CobFill,
@@ -152,6 +153,7 @@ impl TryFrom<Srgba> for CobHexColor
 //-------------------------------------------------------------------------------------------------------------------
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub enum CobBuiltin
 {
     Color(CobHexColor),
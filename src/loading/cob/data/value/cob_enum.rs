@@ -6,6 +6,7 @@ use crate::prelude::*;
 //-------------------------------------------------------------------------------------------------------------------
 
 #[derive(Debug, Clone, PartialEq, Deref)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub struct CobEnumVariantIdentifier(pub SmolStr);
 
 impl CobEnumVariantIdentifier
@@ -35,6 +36,7 @@ impl From<&str> for CobEnumVariantIdentifier
 //-------------------------------------------------------------------------------------------------------------------
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub enum CobEnumVariant
 {
     Unit,
@@ -109,6 +111,7 @@ impl CobEnumVariant
 //-------------------------------------------------------------------------------------------------------------------
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub struct CobEnum
 {
     pub fill: CobFill,
@@ -151,6 +154,17 @@ impl CobEnum
         self.variant.resolve(resolver)
     }
 
+    /// Returns the token path if this is a `ThemeToken("path")` value.
+    pub fn theme_token_path(&self) -> Option<&str>
+    {
+        if self.id.as_str() != "ThemeToken" {
+            return None;
+        }
+        let CobEnumVariant::Tuple(tuple) = &self.variant else { return None };
+        let [CobValue::String(path)] = tuple.entries.as_slice() else { return None };
+        Some(path.as_str())
+    }
+
     pub fn unit(variant: &str) -> Self
     {
         Self {
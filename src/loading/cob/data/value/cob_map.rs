@@ -7,6 +7,7 @@ use crate::prelude::*;
 //-------------------------------------------------------------------------------------------------------------------
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub enum CobMapKey
 {
     Value(CobValue),
@@ -115,6 +116,7 @@ pub enum CobMapKVParseResult
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub struct CobMapKeyValue
 {
     pub key: CobMapKey,
@@ -217,6 +219,7 @@ pub enum CobMapEntryResult
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub enum CobMapEntry
 {
     KeyValue(CobMapKeyValue),
@@ -330,6 +333,7 @@ impl CobMapEntry
 //-------------------------------------------------------------------------------------------------------------------
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub struct CobMap
 {
     /// Fill before opening `{`.
@@ -154,6 +154,7 @@ fn parse_string(input: Span) -> IResult<Span, SmallVec<[CobStringSegment; 1]>>
 //-------------------------------------------------------------------------------------------------------------------
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub struct CobStringSegment
 {
     /// Spaces at the start of a segment for multiline text.
@@ -221,6 +222,7 @@ impl From<String> for CobStringSegment
 //-------------------------------------------------------------------------------------------------------------------
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub struct CobString
 {
     pub fill: CobFill,
@@ -7,6 +7,7 @@ use crate::prelude::*;
 //-------------------------------------------------------------------------------------------------------------------
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub enum CobValue
 {
     Enum(CobEnum),
@@ -20,6 +21,8 @@ pub enum CobValue
     None(CobNone),
     String(CobString),
     Constant(CobConstant),
+    /// Reactive binding like `@bind(PlayerName)`.
+    Bind(CobBind),
 }
 
 impl CobValue
@@ -62,6 +65,9 @@ impl CobValue
             Self::Constant(val) => {
                 val.write_to_with_space(writer, space)?;
             }
+            Self::Bind(val) => {
+                val.write_to_with_space(writer, space)?;
+            }
         }
         Ok(())
     }
@@ -108,6 +114,10 @@ impl CobValue
             (Some(value), fill, remaining) => return Ok((Some(Self::Constant(value)), fill, remaining)),
             (None, fill, _) => fill,
         };
+        let fill = match rc(content, move |c| CobBind::try_parse(fill, c))? {
+            (Some(value), fill, remaining) => return Ok((Some(Self::Bind(value)), fill, remaining)),
+            (None, fill, _) => fill,
+        };
 
         Ok((None, fill, content))
     }
@@ -145,6 +155,9 @@ impl CobValue
             (Self::Constant(val), Self::Constant(other_val)) => {
                 val.recover_fill(other_val);
             }
+            (Self::Bind(val), Self::Bind(other_val)) => {
+                val.recover_fill(other_val);
+            }
             _ => (),
         }
     }
@@ -155,7 +168,15 @@ impl CobValue
     ) -> Result<Option<&'a [CobValueGroupEntry]>, String>
     {
         match self {
-            Self::Enum(val) => val.resolve(resolver)?,
+            Self::Enum(val) => match val.theme_token_path() {
+                Some(path) => {
+                    let Some(token_val) = resolver.themes.get(path) else {
+                        return Err(format!("theme token lookup failed for {:?}", path));
+                    };
+                    *self = token_val.clone();
+                }
+                None => val.resolve(resolver)?,
+            },
             Self::Array(val) => val.resolve(resolver)?,
             Self::Tuple(val) => val.resolve(resolver)?,
             Self::Map(val) => val.resolve(resolver)?,
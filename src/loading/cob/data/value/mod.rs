@@ -1,4 +1,5 @@
 mod cob_array;
+mod cob_bind;
 mod cob_bool;
 mod cob_builtin;
 mod cob_enum;
@@ -10,6 +11,7 @@ mod cob_tuple;
 mod cob_value;
 
 pub use cob_array::*;
+pub use cob_bind::*;
 pub use cob_bool::*;
 pub use cob_builtin::*;
 pub use cob_enum::*;
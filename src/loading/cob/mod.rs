@@ -1,10 +1,14 @@
 mod cob;
+#[cfg(feature = "precompiled_cob")]
+mod cob_binary;
 mod data;
 mod parsing;
 mod raw_serializer;
 mod sections;
 
 pub use cob::*;
+#[cfg(feature = "precompiled_cob")]
+pub use cob_binary::*;
 pub use data::*;
 pub use parsing::*;
 pub use raw_serializer::*;
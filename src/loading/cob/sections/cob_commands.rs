@@ -7,6 +7,7 @@ use crate::prelude::*;
 
 /// Commands are parsed as loadables.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub struct CobCommandEntry(pub CobLoadable);
 
 impl CobCommandEntry
@@ -45,6 +46,7 @@ impl CobCommandEntry
 //-------------------------------------------------------------------------------------------------------------------
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub struct CobCommands
 {
     pub start_fill: CobFill,
@@ -6,6 +6,7 @@ use crate::prelude::*;
 //-------------------------------------------------------------------------------------------------------------------
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub enum CobDefEntry
 {
     Constant(CobConstantDef),
@@ -60,6 +61,7 @@ impl CobDefEntry
 
 /// Includes constants and macros. A constant is equivalent to a macro with no parameters.
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub struct CobDefs
 {
     pub start_fill: CobFill,
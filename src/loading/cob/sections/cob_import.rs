@@ -13,6 +13,7 @@ use crate::prelude::*;
 //-------------------------------------------------------------------------------------------------------------------
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub enum CobImportAlias
 {
     None,
@@ -73,6 +74,7 @@ impl Default for CobImportAlias
 
 /// {manifest key} as {alias}
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub struct CobImportEntry
 {
     pub entry_fill: CobFill,
@@ -152,6 +154,7 @@ impl Default for CobImportEntry
 //-------------------------------------------------------------------------------------------------------------------
 
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub struct CobImport
 {
     pub start_fill: CobFill,
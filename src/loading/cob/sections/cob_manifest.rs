@@ -1,21 +1,140 @@
 use std::sync::Arc;
 
 use bevy::prelude::Deref;
-use nom::bytes::complete::tag;
+use nom::bytes::complete::{tag, take_until};
 use nom::combinator::recognize;
 use nom::multi::many0_count;
-use nom::sequence::{preceded, tuple};
+use nom::sequence::{delimited, preceded, tuple};
 use nom::Parser;
 
 use crate::prelude::*;
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// A directory of COB files.
+///
+/// Written as `"ui/*"` for `.cob` files in the `ui` directory, or `"ui/**"` to include files in subdirectories.
+/// Use `"*"` or `"**"` for the asset root.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
+pub struct CobDirPattern
+{
+    /// The directory path, without a trailing `/`. Can include an asset source (e.g. `embedded://ui`).
+    pub dir: Arc<str>,
+    /// Whether subdirectories are included.
+    pub recursive: bool,
+}
+
+impl CobDirPattern
+{
+    /// Creates a new pattern for a directory.
+    pub fn new(dir: impl AsRef<str>, recursive: bool) -> Self
+    {
+        let dir = dir.as_ref().trim_end_matches('/');
+        Self { dir: Arc::from(dir), recursive }
+    }
+
+    /// Tries to parse a pattern like `ui/**`.
+    ///
+    /// Fails if the pattern doesn't end with `*` or `**`.
+    pub fn try_from_pattern(pattern: &str) -> Option<Self>
+    {
+        let (dir, recursive) = match pattern.strip_suffix("**") {
+            Some(dir) => (dir, true),
+            None => (pattern.strip_suffix('*')?, false),
+        };
+        if !dir.is_empty() && !dir.ends_with('/') {
+            return None;
+        }
+        let pattern = Self::new(dir, recursive);
+        if pattern.dir.contains('*') {
+            return None;
+        }
+        Some(pattern)
+    }
+
+    /// Gets the manifest key for a file in the directory, by appending the file's path relative to the directory
+    /// to `prefix`.
+    ///
+    /// For example, `ui/menus/main_menu.cob` in `ui/**` with prefix `ui` has the key `ui.menus.main_menu`.
+    ///
+    /// Returns `None` if the file is not in the directory, or if a path segment is not a valid manifest key
+    /// segment (lowercase letters, numbers, and underscores, starting with a letter). Dashes and spaces are
+    /// converted to underscores, and uppercase letters are converted to lowercase.
+    pub fn manifest_key(&self, prefix: &ManifestKey, file: &CobFile) -> Option<ManifestKey>
+    {
+        let relative = match self.dir.is_empty() {
+            true => file.as_str(),
+            false => file.as_str().strip_prefix(&*self.dir)?.strip_prefix('/')?,
+        };
+        let relative = relative.strip_suffix(".cob")?;
+        let mut key = String::from(prefix.as_str());
+        for segment in relative.split('/') {
+            let segment: String = segment
+                .chars()
+                .map(|c| match c {
+                    '-' | ' ' => '_',
+                    c => c.to_ascii_lowercase(),
+                })
+                .collect();
+            let is_valid = segment.starts_with(|c: char| c.is_ascii_lowercase())
+                && segment
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+            if !is_valid {
+                return None;
+            }
+            if !key.is_empty() {
+                key.push('.');
+            }
+            key.push_str(&segment);
+        }
+        Some(ManifestKey::new(key))
+    }
+
+    pub fn write_to(&self, writer: &mut impl RawSerializer) -> Result<(), std::io::Error>
+    {
+        writer.write_bytes("\"".as_bytes())?;
+        if !self.dir.is_empty() {
+            writer.write_bytes(self.dir.as_bytes())?;
+            writer.write_bytes("/".as_bytes())?;
+        }
+        match self.recursive {
+            true => writer.write_bytes("**".as_bytes())?,
+            false => writer.write_bytes("*".as_bytes())?,
+        }
+        writer.write_bytes("\"".as_bytes())?;
+        Ok(())
+    }
+
+    pub fn parse(content: Span) -> Result<(Self, Span), SpanError>
+    {
+        let (remaining, path) = delimited(tag("\""), take_until("\""), tag("\"")).parse(content)?;
+        let Some(pattern) = Self::try_from_pattern(path.fragment()) else {
+            return Err(span_verify_error(content));
+        };
+        if !pattern.dir.is_empty() {
+            if let Err(err) = bevy::asset::AssetPath::try_parse(&pattern.dir) {
+                tracing::warn!("failed parsing COB directory at {}; path is invalid {:?}",
+                    get_location(content).as_str(), err);
+                return Err(span_verify_error(content));
+            }
+        }
+        Ok((pattern, remaining))
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub enum CobManifestFile
 {
     SelfRef,
     File(CobFile),
+    /// All COB files in a directory. Each file's manifest key is the entry's key extended with the file's
+    /// relative path (see [`CobDirPattern::manifest_key`]).
+    Dir(CobDirPattern),
 }
 
 impl CobManifestFile
@@ -29,6 +148,9 @@ impl CobManifestFile
             Self::File(file) => {
                 file.write_to(writer)?;
             }
+            Self::Dir(pattern) => {
+                pattern.write_to(writer)?;
+            }
         }
         Ok(())
     }
@@ -40,6 +162,11 @@ impl CobManifestFile
             return Ok((Self::SelfRef, remaining));
         }
 
+        // Case: directory pattern
+        if let Ok((pattern, remaining)) = CobDirPattern::parse(content) {
+            return Ok((Self::Dir(pattern), remaining));
+        }
+
         // Case: string file path
         let (file, remaining) = CobFile::parse(content)?;
         Ok((Self::File(file), remaining))
@@ -61,6 +188,7 @@ impl Default for CobManifestFile
 ///
 /// Example: `builtin.widgets.radio_button` for a pre-registered radio button COB file.
 #[derive(Debug, Clone, Eq, PartialEq, Deref, Hash)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub struct ManifestKey(pub Arc<str>);
 
 impl ManifestKey
@@ -105,8 +233,110 @@ impl Default for ManifestKey
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// priority {value}
+///
+/// Files with higher priority values start loading before files with lower priority values. The default priority
+/// is `0`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
+pub struct CobManifestPriority
+{
+    pub priority_fill: CobFill,
+    pub value_fill: CobFill,
+    pub value: i32,
+}
+
+impl CobManifestPriority
+{
+    pub fn write_to(&self, writer: &mut impl RawSerializer) -> Result<(), std::io::Error>
+    {
+        self.priority_fill.write_to_or_else(writer, " ")?;
+        writer.write_bytes("priority".as_bytes())?;
+        self.value_fill.write_to_or_else(writer, " ")?;
+        writer.write_bytes(self.value.to_string().as_bytes())?;
+        Ok(())
+    }
+
+    pub fn try_parse(priority_fill: CobFill, content: Span) -> Result<(Option<Self>, CobFill, Span), SpanError>
+    {
+        let Ok((remaining, _)) = tag::<_, _, ()>("priority").parse(content) else {
+            return Ok((None, priority_fill, content));
+        };
+        if priority_fill.len() == 0 || priority_fill.ends_with_newline() {
+            tracing::warn!("manifest priority not on the same line as its entry at {}",
+                get_location(content).as_str());
+            return Err(span_verify_error(content));
+        }
+        let (value_fill, remaining) = CobFill::parse(remaining);
+        if value_fill.len() == 0 || value_fill.ends_with_newline() {
+            tracing::warn!("no fill/whitespace after manifest 'priority' at {}", get_location(remaining).as_str());
+            return Err(span_verify_error(remaining));
+        }
+        let (remaining, value) = nom::character::complete::i32(remaining)?;
+        let (next_fill, remaining) = CobFill::parse(remaining);
+        Ok((Some(Self { priority_fill, value_fill, value }), next_fill, remaining))
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// weight {value}
+///
+/// The weight of an asset in [`LoadProgress::fraction`](crate::prelude::LoadProgress::fraction). The default weight
+/// is `1`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
+pub struct CobManifestWeight
+{
+    pub weight_fill: CobFill,
+    pub value_fill: CobFill,
+    pub value: f32,
+}
+
+impl CobManifestWeight
+{
+    pub fn write_to(&self, writer: &mut impl RawSerializer) -> Result<(), std::io::Error>
+    {
+        self.weight_fill.write_to_or_else(writer, " ")?;
+        writer.write_bytes("weight".as_bytes())?;
+        self.value_fill.write_to_or_else(writer, " ")?;
+        writer.write_bytes(self.value.to_string().as_bytes())?;
+        Ok(())
+    }
+
+    pub fn try_parse(weight_fill: CobFill, content: Span) -> Result<(Option<Self>, CobFill, Span), SpanError>
+    {
+        let Ok((remaining, _)) = tag::<_, _, ()>("weight").parse(content) else {
+            return Ok((None, weight_fill, content));
+        };
+        if weight_fill.len() == 0 || weight_fill.ends_with_newline() {
+            tracing::warn!("manifest weight not on the same line as its entry at {}",
+                get_location(content).as_str());
+            return Err(span_verify_error(content));
+        }
+        let (value_fill, remaining) = CobFill::parse(remaining);
+        if value_fill.len() == 0 || value_fill.ends_with_newline() {
+            tracing::warn!("no fill/whitespace after manifest 'weight' at {}", get_location(remaining).as_str());
+            return Err(span_verify_error(remaining));
+        }
+        let value_start = remaining;
+        let (remaining, value) = nom::number::complete::float(remaining)?;
+        if value < 0. || !value.is_finite() {
+            tracing::warn!("manifest weight at {} is not a non-negative number", get_location(value_start).as_str());
+            return Err(span_verify_error(value_start));
+        }
+        let (next_fill, remaining) = CobFill::parse(remaining);
+        Ok((Some(Self { weight_fill, value_fill, value }), next_fill, remaining))
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// {file} as {key}
+/// {file} as {key} priority {value}
+/// {dir pattern} as {key prefix}
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub struct CobManifestEntry
 {
     pub entry_fill: CobFill,
@@ -114,6 +344,7 @@ pub struct CobManifestEntry
     pub as_fill: CobFill,
     pub key_fill: CobFill,
     pub key: ManifestKey,
+    pub priority: Option<CobManifestPriority>,
 }
 
 impl CobManifestEntry
@@ -126,9 +357,18 @@ impl CobManifestEntry
         writer.write_bytes("as".as_bytes())?;
         self.key_fill.write_to_or_else(writer, " ")?;
         self.key.write_to(writer)?;
+        if let Some(priority) = &self.priority {
+            priority.write_to(writer)?;
+        }
         Ok(())
     }
 
+    /// Gets the entry's load priority.
+    pub fn priority(&self) -> i32
+    {
+        self.priority.as_ref().map(|p| p.value).unwrap_or_default()
+    }
+
     pub fn try_parse(entry_fill: CobFill, content: Span) -> Result<(Option<Self>, CobFill, Span), SpanError>
     {
         let Ok((file, remaining)) = CobManifestFile::parse(content) else {
@@ -151,8 +391,9 @@ impl CobManifestEntry
         }
         let (key, remaining) = ManifestKey::parse(remaining)?;
         let (next_fill, remaining) = CobFill::parse(remaining);
+        let (priority, next_fill, remaining) = CobManifestPriority::try_parse(next_fill, remaining)?;
         Ok((
-            Some(Self { entry_fill, file, as_fill, key_fill, key }),
+            Some(Self { entry_fill, file, as_fill, key_fill, key, priority }),
             next_fill,
             remaining,
         ))
@@ -169,6 +410,94 @@ impl Default for CobManifestEntry
             as_fill: CobFill::new(" "),
             key_fill: CobFill::new(" "),
             key: ManifestKey(Arc::from("")),
+            priority: None,
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// asset {path}
+/// asset {path} weight {value}
+///
+/// A non-COB asset that must be loaded before [`LoadState::Done`](crate::prelude::LoadState::Done).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
+pub struct CobManifestAsset
+{
+    pub entry_fill: CobFill,
+    pub path_fill: CobFill,
+    pub path: Arc<str>,
+    pub weight: Option<CobManifestWeight>,
+}
+
+impl CobManifestAsset
+{
+    pub fn write_to(&self, writer: &mut impl RawSerializer) -> Result<(), std::io::Error>
+    {
+        self.entry_fill.write_to_or_else(writer, "\n")?;
+        writer.write_bytes("asset".as_bytes())?;
+        self.path_fill.write_to_or_else(writer, " ")?;
+        writer.write_bytes("\"".as_bytes())?;
+        writer.write_bytes(self.path.as_bytes())?;
+        writer.write_bytes("\"".as_bytes())?;
+        if let Some(weight) = &self.weight {
+            weight.write_to(writer)?;
+        }
+        Ok(())
+    }
+
+    /// Gets the asset's load progress weight.
+    pub fn weight(&self) -> f32
+    {
+        self.weight.as_ref().map(|w| w.value).unwrap_or(1.)
+    }
+
+    pub fn try_parse(entry_fill: CobFill, content: Span) -> Result<(Option<Self>, CobFill, Span), SpanError>
+    {
+        let Ok((remaining, _)) = tag::<_, _, ()>("asset").parse(content) else {
+            return Ok((None, entry_fill, content));
+        };
+        let (path_fill, remaining) = CobFill::parse(remaining);
+        if path_fill.len() == 0 {
+            // Not an asset entry (e.g. a section keyword that starts with 'asset').
+            return Ok((None, entry_fill, content));
+        }
+        if !entry_fill.ends_with_newline() {
+            tracing::warn!("manifest asset entry doesn't start on a new line at {}", get_location(content).as_str());
+            return Err(span_verify_error(content));
+        }
+        let path_start = remaining;
+        let (remaining, path) = delimited(tag("\""), take_until("\""), tag("\"")).parse(remaining)?;
+        if let Err(err) = bevy::asset::AssetPath::try_parse(path.fragment()) {
+            tracing::warn!("failed parsing manifest asset path at {}; path is invalid {:?}",
+                get_location(path_start).as_str(), err);
+            return Err(span_verify_error(path_start));
+        }
+        if path.ends_with(".cob") {
+            tracing::warn!("failed parsing manifest asset path at {}; COB files should be added as normal \
+                manifest entries", get_location(path_start).as_str());
+            return Err(span_verify_error(path_start));
+        }
+        let (next_fill, remaining) = CobFill::parse(remaining);
+        let (weight, next_fill, remaining) = CobManifestWeight::try_parse(next_fill, remaining)?;
+        Ok((
+            Some(Self { entry_fill, path_fill, path: Arc::from(*path.fragment()), weight }),
+            next_fill,
+            remaining,
+        ))
+    }
+}
+
+impl Default for CobManifestAsset
+{
+    fn default() -> Self
+    {
+        Self {
+            entry_fill: CobFill::new("\n"),
+            path_fill: CobFill::new(" "),
+            path: Arc::from(""),
+            weight: None,
         }
     }
 }
@@ -176,10 +505,13 @@ impl Default for CobManifestEntry
 //-------------------------------------------------------------------------------------------------------------------
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub struct CobManifest
 {
     pub start_fill: CobFill,
     pub entries: Vec<CobManifestEntry>,
+    /// Asset entries must come after file entries.
+    pub assets: Vec<CobManifestAsset>,
 }
 
 impl CobManifest
@@ -192,6 +524,9 @@ impl CobManifest
         for entry in self.entries.iter() {
             entry.write_to(writer)?;
         }
+        for asset in self.assets.iter() {
+            asset.write_to(writer)?;
+        }
         Ok(())
     }
 
@@ -210,7 +545,9 @@ impl CobManifest
         let (mut item_fill, mut remaining) = CobFill::parse(remaining);
         let mut entries = vec![];
 
-        let end_fill = loop {
+        let mut assets = vec![];
+
+        item_fill = loop {
             match CobManifestEntry::try_parse(item_fill, remaining)? {
                 (Some(entry), next_fill, after_entry) => {
                     entries.push(entry);
@@ -224,7 +561,21 @@ impl CobManifest
             }
         };
 
-        let manifest = Self { start_fill, entries };
+        let end_fill = loop {
+            match CobManifestAsset::try_parse(item_fill, remaining)? {
+                (Some(asset), next_fill, after_asset) => {
+                    assets.push(asset);
+                    item_fill = next_fill;
+                    remaining = after_asset;
+                }
+                (None, end_fill, after_end) => {
+                    remaining = after_end;
+                    break end_fill;
+                }
+            }
+        };
+
+        let manifest = Self { start_fill, entries, assets };
         Ok((Some(manifest), end_fill, remaining))
     }
 }
@@ -233,7 +584,11 @@ impl Default for CobManifest
 {
     fn default() -> Self
     {
-        Self { start_fill: CobFill::default(), entries: Vec::default() }
+        Self {
+            start_fill: CobFill::default(),
+            entries: Vec::default(),
+            assets: Vec::default(),
+        }
     }
 }
 
@@ -0,0 +1,174 @@
+use nom::bytes::complete::{tag, take, take_until, take_while1};
+use nom::sequence::delimited;
+use nom::Parser;
+use smol_str::SmolStr;
+
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// {library name string} {version requirement}
+///
+/// Version requirements use [semver](https://docs.rs/semver) syntax, e.g. `>= 1.2`, `^0.3`, `=1.0.4`,
+/// `>=1.2, <2`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
+pub struct CobRequireEntry
+{
+    pub entry_fill: CobFill,
+    pub library: SmolStr,
+    pub requirement_fill: CobFill,
+    /// The requirement as written in the file. Validated when parsed.
+    pub requirement: SmolStr,
+}
+
+impl CobRequireEntry
+{
+    pub fn write_to(&self, writer: &mut impl RawSerializer) -> Result<(), std::io::Error>
+    {
+        self.entry_fill.write_to_or_else(writer, "\n")?;
+        writer.write_bytes("\"".as_bytes())?;
+        writer.write_bytes(self.library.as_bytes())?;
+        writer.write_bytes("\"".as_bytes())?;
+        self.requirement_fill.write_to_or_else(writer, " ")?;
+        writer.write_bytes(self.requirement.as_bytes())?;
+        Ok(())
+    }
+
+    pub fn try_parse(entry_fill: CobFill, content: Span) -> Result<(Option<Self>, CobFill, Span), SpanError>
+    {
+        let Ok((remaining, library)) =
+            delimited(tag::<_, _, ()>("\""), take_until("\""), tag("\"")).parse(content)
+        else {
+            return Ok((None, entry_fill, content));
+        };
+        if !entry_fill.ends_with_newline() {
+            tracing::warn!("require entry doesn't start on a new line at {}", get_location(content).as_str());
+            return Err(span_verify_error(content));
+        }
+        if library.is_empty() || library.contains(char::is_whitespace) {
+            tracing::warn!("failed parsing require entry at {}; library name {:?} is invalid",
+                get_location(content).as_str(), *library.fragment());
+            return Err(span_verify_error(content));
+        }
+        let (requirement_fill, remaining) = CobFill::parse(remaining);
+        if requirement_fill.len() == 0 {
+            tracing::warn!("no fill/whitespace before version requirement at {}",
+                get_location(remaining).as_str());
+            return Err(span_verify_error(remaining));
+        }
+
+        // The requirement ends at the end of the line, but trailing spaces belong to the next fill.
+        let (_, raw) =
+            take_while1(|c: char| c.is_ascii_alphanumeric() || " .,<>=^~*+-".contains(c)).parse(remaining)?;
+        let len = raw.fragment().trim_end().len();
+        let (remaining, requirement) = take(len).parse(remaining)?;
+        if let Err(err) = semver::VersionReq::parse(requirement.fragment()) {
+            tracing::warn!("failed parsing version requirement {:?} at {}; {err}",
+                *requirement.fragment(), get_location(requirement).as_str());
+            return Err(span_verify_error(requirement));
+        }
+
+        let (next_fill, remaining) = CobFill::parse(remaining);
+        Ok((
+            Some(Self {
+                entry_fill,
+                library: SmolStr::from(*library.fragment()),
+                requirement_fill,
+                requirement: SmolStr::from(*requirement.fragment()),
+            }),
+            next_fill,
+            remaining,
+        ))
+    }
+
+    /// Makes a new entry with default spacing.
+    ///
+    /// The requirement is not validated.
+    pub fn new(library: impl AsRef<str>, requirement: impl AsRef<str>) -> Self
+    {
+        Self {
+            library: SmolStr::from(library.as_ref()),
+            requirement: SmolStr::from(requirement.as_ref()),
+            ..Default::default()
+        }
+    }
+
+    /// Parses the version requirement.
+    pub fn version_req(&self) -> Result<semver::VersionReq, semver::Error>
+    {
+        semver::VersionReq::parse(&self.requirement)
+    }
+}
+
+impl Default for CobRequireEntry
+{
+    fn default() -> Self
+    {
+        Self {
+            entry_fill: CobFill::new("\n"),
+            library: SmolStr::default(),
+            requirement_fill: CobFill::new(" "),
+            requirement: SmolStr::new_inline("*"),
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
+pub struct CobRequire
+{
+    pub start_fill: CobFill,
+    pub entries: Vec<CobRequireEntry>,
+}
+
+impl CobRequire
+{
+    pub fn write_to(&self, first_section: bool, writer: &mut impl RawSerializer) -> Result<(), std::io::Error>
+    {
+        let space = if first_section { "" } else { "\n\n" };
+        self.start_fill.write_to_or_else(writer, space)?;
+        writer.write_bytes("#require".as_bytes())?;
+        for entry in self.entries.iter() {
+            entry.write_to(writer)?;
+        }
+        Ok(())
+    }
+
+    pub fn try_parse(start_fill: CobFill, content: Span) -> Result<(Option<Self>, CobFill, Span), SpanError>
+    {
+        let Ok((remaining, _)) = tag::<_, _, ()>("#require").parse(content) else {
+            return Ok((None, start_fill, content));
+        };
+
+        if start_fill.len() != 0 && !start_fill.ends_with_newline() {
+            tracing::warn!("failed parsing require section at {} that doesn't start on newline",
+                get_location(content).as_str());
+            return Err(span_verify_error(content));
+        }
+
+        let (mut item_fill, mut remaining) = CobFill::parse(remaining);
+        let mut entries = vec![];
+
+        let end_fill = loop {
+            match CobRequireEntry::try_parse(item_fill, remaining)? {
+                (Some(entry), next_fill, after_entry) => {
+                    entries.push(entry);
+                    item_fill = next_fill;
+                    remaining = after_entry;
+                }
+                (None, end_fill, after_end) => {
+                    remaining = after_end;
+                    break end_fill;
+                }
+            }
+        };
+
+        let require = Self { start_fill, entries };
+        Ok((Some(require), end_fill, remaining))
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
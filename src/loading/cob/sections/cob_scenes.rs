@@ -2,7 +2,8 @@ use bevy::prelude::Deref;
 use nom::branch::alt;
 use nom::bytes::complete::tag;
 use nom::character::complete::char;
-use nom::combinator::{map, success};
+use nom::combinator::{map, recognize, success};
+use nom::multi::many0_count;
 use nom::sequence::terminated;
 use nom::Parser;
 use smol_str::SmolStr;
@@ -12,6 +13,7 @@ use crate::prelude::*;
 //-------------------------------------------------------------------------------------------------------------------
 
 #[derive(Debug, Clone, PartialEq, Deref)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub struct CobSceneNodeName(pub SmolStr);
 
 impl CobSceneNodeName
@@ -44,8 +46,145 @@ impl CobSceneNodeName
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Base scene reference for scenes that extend other scenes.
+///
+/// Written as `extends "base"` after a scene's root node name. Scenes from imported files can be referenced with
+/// their import alias, e.g. `extends widgets::"button"`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
+pub struct CobSceneExtends
+{
+    /// Fill between the scene name and `extends`.
+    pub start_fill: CobFill,
+    /// Fill between `extends` and the base scene path.
+    pub path_fill: CobFill,
+    /// Path to the base scene, including import alias segments (e.g. `widgets::button`).
+    pub path: SmolStr,
+}
+
+impl CobSceneExtends
+{
+    pub fn write_to(&self, writer: &mut impl RawSerializer) -> Result<(), std::io::Error>
+    {
+        self.start_fill.write_to_or_else(writer, " ")?;
+        writer.write_bytes("extends".as_bytes())?;
+        self.path_fill.write_to_or_else(writer, " ")?;
+        let (prefix, name) = match self.path.rsplit_once("::") {
+            Some((prefix, name)) => (Some(prefix), name),
+            None => (None, self.path.as_str()),
+        };
+        if let Some(prefix) = prefix {
+            writer.write_bytes(prefix.as_bytes())?;
+            writer.write_bytes("::".as_bytes())?;
+        }
+        writer.write_bytes("\"".as_bytes())?;
+        writer.write_bytes(name.as_bytes())?;
+        writer.write_bytes("\"".as_bytes())?;
+        Ok(())
+    }
+
+    pub fn try_parse(start_fill: CobFill, content: Span) -> Result<(Option<Self>, CobFill, Span), SpanError>
+    {
+        let Ok((remaining, _)) = tag::<_, _, ()>("extends").parse(content) else {
+            return Ok((None, start_fill, content));
+        };
+        if start_fill.len() == 0 || start_fill.ends_newline_then_num_spaces().is_some() {
+            tracing::warn!("failed parsing scene extension at {}; `extends` must be on the same line as the \
+                scene name, separated by whitespace", get_location(content).as_str());
+            return Err(span_verify_error(content));
+        }
+
+        let (path_fill, remaining) = CobFill::parse(remaining);
+        if path_fill.len() == 0 || path_fill.ends_newline_then_num_spaces().is_some() {
+            tracing::warn!("failed parsing scene extension at {}; the base scene must be on the same line as \
+                `extends`, separated by whitespace", get_location(content).as_str());
+            return Err(span_verify_error(remaining));
+        }
+        let Ok((remaining, prefix)) =
+            recognize(many0_count(terminated(snake_identifier, tag("::")))).parse(remaining)
+        else {
+            return Err(span_verify_error(remaining));
+        };
+        let Ok((Some(name), remaining)) = CobSceneNodeName::try_parse(remaining) else {
+            tracing::warn!("failed parsing scene extension at {}; expected a base scene name (e.g. \"base\" or \
+                alias::\"base\")", get_location(content).as_str());
+            return Err(span_verify_error(content));
+        };
+
+        let path = SmolStr::from(format!("{}{}", *prefix.fragment(), name.as_str()));
+        let (end_fill, remaining) = CobFill::parse(remaining);
+        Ok((Some(Self { start_fill, path_fill, path }), end_fill, remaining))
+    }
+
+    pub fn recover_fill(&mut self, other: &Self)
+    {
+        self.start_fill.recover(&other.start_fill);
+        self.path_fill.recover(&other.path_fill);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Target file for scene patches.
+///
+/// Written as `patch some.file::"scene"` in place of a scene name, where `some.file` is the manifest key of an
+/// imported file.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
+pub struct CobScenePatch
+{
+    /// Fill between `patch` and the manifest key.
+    pub key_fill: CobFill,
+    /// Manifest key of the file that owns the patched scene.
+    pub key: ManifestKey,
+}
+
+impl CobScenePatch
+{
+    pub fn write_to(&self, writer: &mut impl RawSerializer) -> Result<(), std::io::Error>
+    {
+        writer.write_bytes("patch".as_bytes())?;
+        self.key_fill.write_to_or_else(writer, " ")?;
+        self.key.write_to(writer)?;
+        writer.write_bytes("::".as_bytes())?;
+        Ok(())
+    }
+
+    pub fn try_parse(content: Span) -> Result<(Option<Self>, Span), SpanError>
+    {
+        let Ok((remaining, _)) = tag::<_, _, ()>("patch").parse(content) else { return Ok((None, content)) };
+
+        let (key_fill, remaining) = CobFill::parse(remaining);
+        if key_fill.len() == 0 || key_fill.ends_newline_then_num_spaces().is_some() {
+            tracing::warn!("failed parsing scene patch at {}; the patched file must be on the same line as \
+                `patch`, separated by whitespace", get_location(content).as_str());
+            return Err(span_verify_error(remaining));
+        }
+        let Ok((key, remaining)) = rc(remaining, |rm| ManifestKey::parse(rm)) else {
+            tracing::warn!("failed parsing scene patch at {}; expected a manifest key (e.g. some.file)",
+                get_location(content).as_str());
+            return Err(span_verify_error(remaining));
+        };
+        let Ok((remaining, _)) = tag::<_, _, ()>("::").parse(remaining) else {
+            tracing::warn!("failed parsing scene patch at {}; expected `::` between the manifest key and the \
+                patched scene name (e.g. some.file::\"scene\")", get_location(content).as_str());
+            return Err(span_verify_error(remaining));
+        };
+
+        Ok((Some(Self { key_fill, key }), remaining))
+    }
+
+    pub fn recover_fill(&mut self, other: &Self)
+    {
+        self.key_fill.recover(&other.key_fill);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Full loadable.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub enum CobSceneLayerEntry
 {
     Loadable(CobLoadable),
@@ -118,6 +257,16 @@ impl CobSceneLayerEntry
             (None, fill, _) => fill,
         };
         let fill = match rc(content, move |c| CobSceneLayer::try_parse(fill, c))? {
+            (Some(item), _, _) if item.extends.is_some() => {
+                tracing::warn!("failed parsing scene node at {}; only scene roots can extend other scenes",
+                    get_location(content).as_str());
+                return Err(span_verify_error(content));
+            }
+            (Some(item), _, _) if item.patch.is_some() => {
+                tracing::warn!("failed parsing scene node at {}; only scene roots can be patches",
+                    get_location(content).as_str());
+                return Err(span_verify_error(content));
+            }
             (Some(item), fill, remaining) => return Ok((Some(Self::Layer(item)), fill, remaining)),
             (None, fill, _) => fill,
         };
@@ -183,6 +332,7 @@ impl CobSceneLayerEntry
 //-------------------------------------------------------------------------------------------------------------------
 
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub enum SceneResolveMode
 {
     /// Only resolve scene structure in the current scene layer.
@@ -197,13 +347,18 @@ pub enum SceneResolveMode
 //-------------------------------------------------------------------------------------------------------------------
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub struct CobSceneLayer
 {
     /// Fill before the layer name.
     ///
     /// Whitespace between the name and most recent newline is used to control scene layer depth.
     pub name_fill: CobFill,
+    /// Imported file whose scene this layer patches. Only allowed on scene roots.
+    pub patch: Option<CobScenePatch>,
     pub name: CobSceneNodeName,
+    /// Base scene this layer extends. Only allowed on scene roots.
+    pub extends: Option<CobSceneExtends>,
     pub entries: Vec<CobSceneLayerEntry>,
 }
 
@@ -212,7 +367,13 @@ impl CobSceneLayer
     pub fn write_to(&self, writer: &mut impl RawSerializer) -> Result<(), std::io::Error>
     {
         self.name_fill.write_to_or_else(writer, "\n")?;
+        if let Some(patch) = &self.patch {
+            patch.write_to(writer)?;
+        }
         self.name.write_to(writer)?;
+        if let Some(extends) = &self.extends {
+            extends.write_to(writer)?;
+        }
         for entry in self.entries.iter() {
             entry.write_to(writer)?;
         }
@@ -222,7 +383,13 @@ impl CobSceneLayer
     /// `indent` should be the indent of this layer's id
     pub fn try_parse(name_fill: CobFill, content: Span) -> Result<(Option<Self>, CobFill, Span), SpanError>
     {
-        let (Some(name), remaining) = CobSceneNodeName::try_parse(content)? else {
+        let (patch, name_start) = CobScenePatch::try_parse(content)?;
+        let (Some(name), remaining) = CobSceneNodeName::try_parse(name_start)? else {
+            if patch.is_some() {
+                tracing::warn!("failed parsing scene patch at {}; expected a scene name after the patched file \
+                    (e.g. some.file::\"scene\")", get_location(content).as_str());
+                return Err(span_verify_error(name_start));
+            }
             return Ok((None, name_fill, content));
         };
 
@@ -233,12 +400,20 @@ impl CobSceneLayer
             return Err(span_verify_error(content));
         };
 
+        // Check for a base scene.
+        let (item_fill, remaining) = CobFill::parse(remaining);
+        let (extends, mut item_fill, mut remaining) = CobSceneExtends::try_parse(item_fill, remaining)?;
+        if patch.is_some() && extends.is_some() {
+            tracing::warn!("failed parsing scene patch at {}; scene patches cannot extend other scenes",
+                get_location(content).as_str());
+            return Err(span_verify_error(content));
+        }
+
         // Get content indent from first item_fill.
-        let (mut item_fill, mut remaining) = CobFill::parse(remaining);
         let Some(content_indent) = item_fill.ends_newline_then_num_spaces() else {
             if remaining.fragment().len() == 0 {
                 // End-of-file
-                return Ok((Some(Self { name_fill, name, entries: vec![] }), item_fill, remaining));
+                return Ok((Some(Self { name_fill, patch, name, extends, entries: vec![] }), item_fill, remaining));
             }
             tracing::warn!("failed parsing scene at {}; first item after a node name isn't on a separate line",
                 get_location(remaining));
@@ -264,12 +439,18 @@ impl CobSceneLayer
             }
         };
 
-        Ok((Some(Self { name_fill, name, entries }), end_fill, remaining))
+        Ok((Some(Self { name_fill, patch, name, extends, entries }), end_fill, remaining))
     }
 
     pub fn recover_fill(&mut self, other: &Self)
     {
         self.name_fill.recover(&other.name_fill);
+        if let (Some(patch), Some(other_patch)) = (&mut self.patch, &other.patch) {
+            patch.recover_fill(other_patch);
+        }
+        if let (Some(extends), Some(other_extends)) = (&mut self.extends, &other.extends) {
+            extends.recover_fill(other_extends);
+        }
         for (entry, other) in self.entries.iter_mut().zip(other.entries.iter()) {
             entry.recover_fill(other);
         }
@@ -280,6 +461,24 @@ impl CobSceneLayer
         Self::resolve_entries_impl(self.name.as_str(), &mut self.entries, resolver, resolve_mode)
     }
 
+    /// Merges this layer's entries into its base scene if it extends one.
+    ///
+    /// The layer's entries are treated like the contents of a scene macro invocation, so they can override
+    /// loadables, add or rearrange nodes, and use scene macro commands. The `extends` field is consumed.
+    pub fn resolve_extends(&mut self, resolver: &mut CobResolver) -> Result<(), String>
+    {
+        let Some(extends) = self.extends.take() else { return Ok(()) };
+
+        Self::resolve_entries_impl(self.name.as_str(), &mut self.entries, resolver, SceneResolveMode::SceneOnly)?;
+        self.entries = resolver
+            .scenes
+            .scene_bases
+            .expand_entries(extends.path.as_str(), &self.entries)
+            .ok_or_else(|| format!("no base scene at '{}' for scene {}", extends.path.as_str(), self.name.as_str()))?;
+
+        Ok(())
+    }
+
     pub fn resolve_entries_impl(
         name: &str,
         entries: &mut Vec<CobSceneLayerEntry>,
@@ -326,6 +525,7 @@ impl CobSceneLayer
 //-------------------------------------------------------------------------------------------------------------------
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
 pub struct CobScenes
 {
     pub start_fill: CobFill,
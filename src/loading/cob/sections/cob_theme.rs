@@ -0,0 +1,73 @@
+use nom::bytes::complete::tag;
+use nom::Parser;
+
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Section of theme token definitions.
+///
+/// Each entry maps a token name to a map of `{theme_variant: value}`, e.g. `$bg = {light:#FFFFFF dark:#111111}`.
+/// Tokens are referenced in loadables with `ThemeToken("bg")`, which resolves to the value for the active theme.
+#[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "precompiled_cob", derive(serde::Serialize, serde::Deserialize))]
+pub struct CobTheme
+{
+    pub start_fill: CobFill,
+    pub entries: Vec<CobConstantDef>,
+}
+
+impl CobTheme
+{
+    pub fn write_to(&self, first_section: bool, writer: &mut impl RawSerializer) -> Result<(), std::io::Error>
+    {
+        let space = if first_section { "" } else { "\n\n" };
+        self.start_fill.write_to_or_else(writer, space)?;
+        writer.write_bytes("#theme".as_bytes())?;
+        for entry in self.entries.iter() {
+            entry.write_to_with_space(writer, "\n")?;
+        }
+        Ok(())
+    }
+
+    pub fn try_parse(start_fill: CobFill, content: Span) -> Result<(Option<Self>, CobFill, Span), SpanError>
+    {
+        let Ok((remaining, _)) = tag::<_, _, ()>("#theme").parse(content) else {
+            return Ok((None, start_fill, content));
+        };
+
+        if start_fill.len() != 0 && !start_fill.ends_with_newline() {
+            tracing::warn!("failed parsing theme section at {} that doesn't start on newline",
+                get_location(content).as_str());
+            return Err(span_verify_error(content));
+        }
+
+        let (mut item_fill, mut remaining) = CobFill::parse(remaining);
+        let mut entries = vec![];
+
+        let end_fill = loop {
+            let starts_newline = item_fill.ends_with_newline();
+            match rc(remaining, move |rm| CobConstantDef::try_parse(item_fill, rm))? {
+                (Some(entry), next_fill, after_entry) => {
+                    if !starts_newline {
+                        tracing::warn!("theme entry doesn't start on a new line at {}",
+                            get_location(remaining).as_str());
+                        return Err(span_verify_error(remaining));
+                    }
+                    entries.push(entry);
+                    item_fill = next_fill;
+                    remaining = after_entry;
+                }
+                (None, end_fill, after_end) => {
+                    remaining = after_end;
+                    break end_fill;
+                }
+            }
+        };
+
+        let theme = CobTheme { start_fill, entries };
+        Ok((Some(theme), end_fill, remaining))
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -2,10 +2,14 @@ mod cob_commands;
 mod cob_defs;
 mod cob_import;
 mod cob_manifest;
+mod cob_require;
 mod cob_scenes;
+mod cob_theme;
 
 pub use cob_commands::*;
 pub use cob_defs::*;
 pub use cob_import::*;
 pub use cob_manifest::*;
+pub use cob_require::*;
 pub use cob_scenes::*;
+pub use cob_theme::*;
@@ -35,6 +35,37 @@ impl AssetLoader for CobAssetLoader
             AssetSourceId::Name(name) => format!("{}://{}", *name, &*file),
         };
 
+        // Decode precompiled files.
+        // - Precompiled files are identified by their original `.cob` file.
+        #[cfg(feature = "precompiled_cob")]
+        if let Some(file) = file.strip_suffix(".cobb") {
+            let file = format!("{file}.cob");
+            let mut bytes = vec![];
+            reader.read_to_end(&mut bytes).await?;
+            let mut data = match Cob::from_binary(&bytes) {
+                Ok(data) => data,
+                Err(err) => {
+                    return Ok(CobAssetFile::Failed(CobLoadError::Parse {
+                        file,
+                        line: 0,
+                        column: 0,
+                        message: err.to_string(),
+                    }))
+                }
+            };
+            data.file = CobFile::try_new(&file).unwrap_or(data.file);
+
+            #[cfg(not(feature = "editor"))]
+            {
+                return Ok(CobAssetFile::File { data });
+            }
+
+            #[cfg(feature = "editor")]
+            {
+                return Ok(CobAssetFile::File { hash: CobFileHash::new(&bytes), data });
+            }
+        }
+
         // Read the file.
         let mut string = String::default();
         reader.read_to_string(&mut string).await?;
@@ -51,19 +82,10 @@ impl AssetLoader for CobAssetLoader
         }
 
         // Parse the raw file data.
+        // - Parsing errors are returned as assets so they can be reported with their locations.
         let data = match Cob::parse(Span::new_extra(&string, CobLocationMetadata { file: file.as_str() })) {
             Ok(data) => data,
-            Err(nom::Err::Error(err)) | Err(nom::Err::Failure(err)) => {
-                let nom::error::Error { input, code } = err;
-                return Err(CobAssetLoaderError::CobParsing(
-                    format!("error at {}: {:?}", get_location(input).as_str(), code),
-                ));
-            }
-            Err(nom::Err::Incomplete(err)) => {
-                return Err(CobAssetLoaderError::CobParsing(
-                    format!("insufficient data in {}: {:?}", file.as_str(), err),
-                ));
-            }
+            Err(err) => return Ok(CobAssetFile::Failed(CobLoadError::from_parse_error(&file, err))),
         };
 
         #[cfg(not(feature = "editor"))]
@@ -79,7 +101,15 @@ impl AssetLoader for CobAssetLoader
 
     fn extensions(&self) -> &[&str]
     {
-        &[".cob"]
+        #[cfg(feature = "precompiled_cob")]
+        {
+            &[".cob", ".cobb"]
+        }
+
+        #[cfg(not(feature = "precompiled_cob"))]
+        {
+            &[".cob"]
+        }
     }
 }
 
@@ -93,9 +123,6 @@ pub enum CobAssetLoaderError
     /// An [IO Error](std::io::Error).
     #[error("Could not read the CobAssetFile file: {0}")]
     Io(#[from] std::io::Error),
-    /// A COB Error.
-    #[error("Could not parse the CobAssetFile data: {0}")]
-    CobParsing(String),
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -107,6 +134,8 @@ pub(crate) enum CobAssetFile
 {
     /// Used to ignore files saved by the editor (when the "editor" feature is enabled).
     Ignore,
+    /// A file that failed to parse.
+    Failed(CobLoadError),
     /// A parsed file.
     File
     {
@@ -0,0 +1,397 @@
+use std::any::TypeId;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use bevy::ecs::component::ComponentId;
+use bevy::ecs::world::{Command, DeferredWorld};
+use bevy::prelude::*;
+use bevy::reflect::serde::TypedReflectDeserializer;
+use bevy_cobweb::prelude::*;
+use serde::de::DeserializeSeed;
+use serde::Serialize;
+use smol_str::SmolStr;
+
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reads the current value of a binding source for an entity.
+type BindingReader = Arc<dyn Fn(&World, Entity) -> Option<CobValue> + Send + Sync + 'static>;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+struct BindingSource
+{
+    read: BindingReader,
+    /// Finds the entity that provides the source's data to a bound entity.
+    ///
+    /// Only used for component bindings.
+    provider: Option<fn(&World, Entity) -> Option<Entity>>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource, Default)]
+struct CobBindingRegistry
+{
+    sources: HashMap<SmolStr, BindingSource>,
+    /// [ source : entities with bound loadables that use the source ]
+    bound: HashMap<SmolStr, HashSet<Entity>>,
+}
+
+impl CobBindingRegistry
+{
+    fn insert(&mut self, name: &str, source: BindingSource)
+    {
+        if self.sources.insert(SmolStr::from(name), source).is_some() {
+            tracing::warn!("overwriting COB binding source {name:?}");
+        }
+    }
+
+    /// Indexes an entity's bound loadables by their sources.
+    fn track(&mut self, entity: Entity, tracked: &CobBoundLoadables)
+    {
+        self.untrack(entity);
+        for source in tracked.entries.iter().flat_map(|e| e.bound.sources.iter()) {
+            self.bound.entry(source.clone()).or_default().insert(entity);
+        }
+    }
+
+    fn untrack(&mut self, entity: Entity)
+    {
+        self.bound.retain(|_, entities| {
+            entities.remove(&entity);
+            !entities.is_empty()
+        });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A loadable whose value contains [`CobBind`] values.
+///
+/// Bound loadables are deserialized when applied to an entity.
+#[derive(Debug)]
+pub(crate) struct CobBoundLoadable
+{
+    pub(crate) type_id: TypeId,
+    pub(crate) loadable: CobLoadable,
+    sources: Vec<SmolStr>,
+}
+
+impl CobBoundLoadable
+{
+    /// Returns a bound loadable if `loadable` contains any bindings.
+    pub(crate) fn try_new(type_id: TypeId, loadable: &CobLoadable) -> Option<Self>
+    {
+        let mut sources = vec![];
+        visit_loadable_values(loadable, &mut |value| {
+            let CobValue::Bind(bind) = value else { return };
+            if !sources.contains(&bind.source) {
+                sources.push(bind.source.clone());
+            }
+        });
+
+        if sources.is_empty() {
+            return None;
+        }
+
+        Some(Self { type_id, loadable: loadable.clone(), sources })
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Clone)]
+struct BoundLoadableEntry
+{
+    callback: fn(&mut World, Entity, ReflectedLoadable, SceneRef),
+    scene_ref: SceneRef,
+    bound: Arc<CobBoundLoadable>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Tracks bound loadables applied to an entity.
+#[derive(Component, Default)]
+#[component(on_remove = on_remove_bound_loadables)]
+struct CobBoundLoadables
+{
+    entries: Vec<BoundLoadableEntry>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn on_remove_bound_loadables(mut world: DeferredWorld, entity: Entity, _: ComponentId)
+{
+    let Some(mut registry) = world.get_resource_mut::<CobBindingRegistry>() else { return };
+    registry.untrack(entity);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn find_provider<C: ReactComponent>(world: &World, mut entity: Entity) -> Option<Entity>
+{
+    loop {
+        if world.get::<React<C>>(entity).is_some() {
+            return Some(entity);
+        }
+        entity = world.get::<Parent>(entity)?.get();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Substitutes binding values into a bound loadable and applies it to the entity.
+fn refresh_bound_loadable(world: &mut World, entity: Entity, entry: BoundLoadableEntry)
+{
+    if !world.entities().contains(entity) {
+        return;
+    }
+
+    // Substitute bindings.
+    let mut loadable = entry.bound.loadable.clone();
+    let mut missing = false;
+    {
+        let Some(bindings) = world.get_resource::<CobBindingRegistry>() else { return };
+        visit_loadable_values_mut(&mut loadable, &mut |value| {
+            let CobValue::Bind(bind) = value else { return };
+            let Some(source) = bindings.sources.get(&bind.source) else {
+                tracing::warn!("failed applying binding @bind({}) to {entity:?} at {:?}, no binding source with \
+                    that name was registered", bind.source.as_str(), entry.scene_ref);
+                missing = true;
+                return;
+            };
+            // If the source has no data for this entity then we wait until data is available.
+            let Some(new_value) = (source.read)(world, entity) else {
+                missing = true;
+                return;
+            };
+            *value = new_value;
+        });
+    }
+    if missing {
+        return;
+    }
+
+    // Deserialize.
+    let reflected = {
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+        let Some(registration) = type_registry.get(entry.bound.type_id) else { return };
+        match TypedReflectDeserializer::new(registration, &type_registry).deserialize(&loadable) {
+            Ok(value) => ReflectedLoadable::Value(Arc::new(value)),
+            Err(err) => ReflectedLoadable::DeserializationFailed(Arc::new(err)),
+        }
+    };
+
+    (entry.callback)(world, entity, reflected, entry.scene_ref);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Applies a bound loadable to an entity and tracks it so it will be re-applied when its sources change.
+pub(crate) fn apply_bound_loadable(
+    world: &mut World,
+    entity: Entity,
+    callback: fn(&mut World, Entity, ReflectedLoadable, SceneRef),
+    bound: Arc<CobBoundLoadable>,
+    scene_ref: SceneRef,
+)
+{
+    let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+    let entry = BoundLoadableEntry { callback, scene_ref, bound };
+    let mut tracked = emut.entry::<CobBoundLoadables>().or_default();
+    match tracked
+        .entries
+        .iter()
+        .position(|e| e.bound.type_id == entry.bound.type_id)
+    {
+        Some(pos) => tracked.entries[pos] = entry.clone(),
+        None => tracked.entries.push(entry.clone()),
+    }
+    reindex_bound_entity(world, entity);
+
+    refresh_bound_loadable(world, entity, entry);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Stops tracking a bound loadable on an entity.
+pub(crate) fn remove_bound_loadable(world: &mut World, entity: Entity, type_id: TypeId)
+{
+    let Some(mut tracked) = world.get_mut::<CobBoundLoadables>(entity) else { return };
+    tracked.entries.retain(|e| e.bound.type_id != type_id);
+    reindex_bound_entity(world, entity);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Updates the binding source index after an entity's bound loadables changed.
+fn reindex_bound_entity(world: &mut World, entity: Entity)
+{
+    if !world.contains_resource::<CobBindingRegistry>() {
+        return;
+    }
+    world.resource_scope(|world, mut registry: Mut<CobBindingRegistry>| {
+        let Some(tracked) = world.get::<CobBoundLoadables>(entity) else { return };
+        registry.track(entity, tracked);
+    });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Re-applies bound loadables that use a binding source.
+struct RefreshCobBindings
+{
+    source: SmolStr,
+    /// The entity whose data changed, for component bindings.
+    changed: Option<Entity>,
+}
+
+impl Command for RefreshCobBindings
+{
+    fn apply(self, world: &mut World)
+    {
+        let Some(registry) = world.get_resource::<CobBindingRegistry>() else { return };
+        let Some(provider) = registry.sources.get(&self.source).map(|s| s.provider) else { return };
+        let Some(bound) = registry.bound.get(&self.source) else { return };
+
+        // Only entities with loadables bound to the source are visited.
+        let mut refreshes = vec![];
+        for entity in bound.iter().copied() {
+            let Some(tracked) = world.get::<CobBoundLoadables>(entity) else { continue };
+            if let (Some(provider), Some(changed)) = (provider, self.changed) {
+                if (provider)(world, entity) != Some(changed) {
+                    continue;
+                }
+            }
+
+            refreshes.extend(
+                tracked
+                    .entries
+                    .iter()
+                    .filter(|e| e.bound.sources.contains(&self.source))
+                    .map(|e| (entity, e.clone())),
+            );
+        }
+
+        for (entity, entry) in refreshes {
+            refresh_bound_loadable(world, entity, entry);
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Extends `App` with methods for registering sources for `@bind(Source)` values in COB files.
+///
+/// When a loadable containing bindings is applied to an entity, each binding is replaced with the current value of
+/// its source. The loadable is re-applied whenever one of its sources changes.
+///
+/// Bindings are only supported in `#scenes` sections.
+pub trait CobBindingAppExt
+{
+    /// Registers a binding source that reads from the reactive resource `R`.
+    ///
+    /// Loadables using the source are re-applied when `R` is mutated.
+    fn register_resource_binding<R: ReactResource, V: Serialize + 'static>(
+        &mut self,
+        name: impl AsRef<str>,
+        getter: fn(&R) -> V,
+    ) -> &mut Self;
+
+    /// Registers a binding source that reads from the nearest [`React<C>`] component on the bound entity or one of
+    /// its ancestors.
+    ///
+    /// Loadables using the source are re-applied when that component is inserted or mutated. A loadable won't be
+    /// applied until the component can be found.
+    fn register_component_binding<C: ReactComponent, V: Serialize + 'static>(
+        &mut self,
+        name: impl AsRef<str>,
+        getter: fn(&C) -> V,
+    ) -> &mut Self;
+}
+
+impl CobBindingAppExt for App
+{
+    fn register_resource_binding<R: ReactResource, V: Serialize + 'static>(
+        &mut self,
+        name: impl AsRef<str>,
+        getter: fn(&R) -> V,
+    ) -> &mut Self
+    {
+        let name = SmolStr::from(name.as_ref());
+        let read_name = name.clone();
+        let read = Arc::new(move |world: &World, _: Entity| -> Option<CobValue> {
+            let resource = world.get_react_resource::<R>()?;
+            CobValue::extract(&(getter)(resource))
+                .inspect_err(|err| {
+                    tracing::warn!("failed extracting value for COB binding source {read_name:?}: {err:?}");
+                })
+                .ok()
+        });
+
+        self.init_resource::<CobBindingRegistry>()
+            .world_mut()
+            .resource_mut::<CobBindingRegistry>()
+            .insert(&name, BindingSource { read, provider: None });
+
+        self.react(|rc| {
+            rc.on_persistent(resource_mutation::<R>(), move |mut c: Commands| {
+                c.queue(RefreshCobBindings { source: name.clone(), changed: None });
+            })
+        });
+
+        self
+    }
+
+    fn register_component_binding<C: ReactComponent, V: Serialize + 'static>(
+        &mut self,
+        name: impl AsRef<str>,
+        getter: fn(&C) -> V,
+    ) -> &mut Self
+    {
+        let name = SmolStr::from(name.as_ref());
+        let read_name = name.clone();
+        let read = Arc::new(move |world: &World, entity: Entity| -> Option<CobValue> {
+            let provider = find_provider::<C>(world, entity)?;
+            let component = world.get::<React<C>>(provider)?;
+            CobValue::extract(&(getter)(component.get()))
+                .inspect_err(|err| {
+                    tracing::warn!("failed extracting value for COB binding source {read_name:?}: {err:?}");
+                })
+                .ok()
+        });
+
+        self.init_resource::<CobBindingRegistry>()
+            .world_mut()
+            .resource_mut::<CobBindingRegistry>()
+            .insert(&name, BindingSource { read, provider: Some(find_provider::<C>) });
+
+        self.react(|rc| {
+            rc.on_persistent(
+                (insertion::<C>(), mutation::<C>()),
+                move |insertion: InsertionEvent<C>, mutation: MutationEvent<C>, mut c: Commands| {
+                    let Ok(changed) = insertion.get().or_else(|_| mutation.get()) else { return };
+                    c.queue(RefreshCobBindings { source: name.clone(), changed: Some(changed) });
+                },
+            )
+        });
+
+        self
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct CobBindingsPlugin;
+
+impl Plugin for CobBindingsPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_resource::<CobBindingRegistry>();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,265 @@
+use bevy::asset::AssetLoadFailedEvent;
+use bevy::prelude::*;
+use thiserror::Error;
+
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Converts COB file read failures into [`CobErrorEvents`](CobErrorEvent).
+fn handle_cob_asset_load_failures(mut c: Commands, mut events: EventReader<AssetLoadFailedEvent<CobAssetFile>>)
+{
+    for event in events.read() {
+        c.send_event(CobErrorEvent(CobLoadError::Io {
+            file: event.path.to_string(),
+            message: event.error.to_string(),
+        }));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn update_cob_diagnostics(mut diagnostics: ResMut<CobDiagnostics>, mut events: EventReader<CobErrorEvent>)
+{
+    for event in events.read() {
+        diagnostics.errors.push(event.0.clone());
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Fails the initial load if errors were encountered in [strict mode](CobStrictMode).
+fn fail_load_in_strict_mode(
+    strict: Res<CobStrictMode>,
+    diagnostics: Res<CobDiagnostics>,
+    mut next: ResMut<NextState<LoadState>>,
+)
+{
+    if !strict.enabled || diagnostics.is_empty() {
+        return;
+    }
+
+    tracing::error!("failed loading COB files in strict mode; encountered {} error(s), see CobDiagnostics",
+        diagnostics.errors().len());
+    next.set(LoadState::Failed);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A failure encountered while loading COB files.
+///
+/// Failures are also logged.
+///
+/// See [`CobErrorEvent`] and [`CobDiagnostics`].
+#[non_exhaustive]
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum CobLoadError
+{
+    /// A COB file failed to parse.
+    #[error("failed parsing {file:?} at line {line}, column {column}: {message}")]
+    Parse
+    {
+        file: String,
+        /// The line and column are `0` if the location is unknown.
+        line: u32,
+        column: usize,
+        message: String,
+    },
+    /// A COB file could not be read.
+    #[error("failed reading {file:?}: {message}")]
+    Io
+    {
+        file: String, message: String
+    },
+    /// A loadable in a COB file is not registered in the app.
+    #[error("unknown loadable {name:?} at {path:?} in {file:?}")]
+    UnknownLoadable
+    {
+        file: CobFile, path: ScenePath, name: String
+    },
+    /// A loadable in a COB file failed to deserialize (e.g. it has unknown fields or values with the wrong
+    /// types).
+    #[error("invalid loadable {name:?} at {path:?} in {file:?}: {message}")]
+    InvalidLoadable
+    {
+        file: CobFile,
+        path: ScenePath,
+        name: String,
+        message: String,
+    },
+    /// Definitions (e.g. constants or macros) used by a loadable or scene node could not be resolved.
+    #[error("unresolved definitions at {path:?} in {file:?}: {message}")]
+    UnresolvedDefs
+    {
+        file: CobFile, path: ScenePath, message: String
+    },
+    /// A COB file imports or patches something that can't be resolved.
+    #[error("bad import in {file:?}: {message}")]
+    BadImport
+    {
+        file: CobFile, message: String
+    },
+    /// A COB file requires a [`CobLibrary`] that isn't registered, or has an incompatible version.
+    #[error("unmet requirement {library:?} {requirement} in {file:?}: {message}")]
+    UnmetRequirement
+    {
+        file: CobFile,
+        library: String,
+        requirement: String,
+        message: String,
+    },
+    /// Manifest entries map a key to multiple files, or a file to multiple keys.
+    #[error("manifest conflict for {file:?}: {message}")]
+    ManifestConflict
+    {
+        file: CobFile, message: String
+    },
+}
+
+impl CobLoadError
+{
+    /// Makes a [`Self::Parse`] error from a COB parsing error.
+    pub(crate) fn from_parse_error(file: &str, err: SpanError) -> Self
+    {
+        match err {
+            nom::Err::Error(err) | nom::Err::Failure(err) => {
+                let nom::error::Error { input, code } = err;
+                Self::Parse {
+                    file: file.into(),
+                    line: input.location_line(),
+                    column: input.get_utf8_column(),
+                    message: format!("{:?}", code),
+                }
+            }
+            nom::Err::Incomplete(err) => Self::Parse {
+                file: file.into(),
+                line: 0,
+                column: 0,
+                message: format!("insufficient data: {:?}", err),
+            },
+        }
+    }
+
+    /// Gets the file that caused the error.
+    pub fn file(&self) -> &str
+    {
+        match self {
+            Self::Parse { file, .. } | Self::Io { file, .. } => file.as_str(),
+            Self::UnknownLoadable { file, .. }
+            | Self::InvalidLoadable { file, .. }
+            | Self::UnresolvedDefs { file, .. }
+            | Self::BadImport { file, .. }
+            | Self::UnmetRequirement { file, .. }
+            | Self::ManifestConflict { file, .. } => file.as_str(),
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Event sent when a [`CobLoadError`] is encountered.
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct CobErrorEvent(pub CobLoadError);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource that records [`CobLoadErrors`](CobLoadError).
+///
+/// Errors are recorded in `First` after they are sent as [`CobErrorEvents`](CobErrorEvent). With `hot_reload`, a
+/// file's errors are cleared when the file is reloaded.
+#[derive(Resource, Default, Debug)]
+pub struct CobDiagnostics
+{
+    errors: Vec<CobLoadError>,
+}
+
+impl CobDiagnostics
+{
+    /// Returns `true` if there are no recorded errors.
+    pub fn is_empty(&self) -> bool
+    {
+        self.errors.is_empty()
+    }
+
+    /// Gets all recorded errors in the order they were encountered.
+    pub fn errors(&self) -> &[CobLoadError]
+    {
+        &self.errors
+    }
+
+    /// Iterates errors recorded for a file.
+    pub fn iter_file<'a>(&'a self, file: &'a str) -> impl Iterator<Item = &'a CobLoadError> + 'a
+    {
+        self.errors.iter().filter(move |e| e.file() == file)
+    }
+
+    /// Removes errors recorded for a file.
+    pub fn clear_file(&mut self, file: &str)
+    {
+        self.errors.retain(|e| e.file() != file);
+    }
+
+    /// Removes all recorded errors.
+    pub fn clear(&mut self)
+    {
+        self.errors.clear();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource that controls strict mode for loading COB files.
+///
+/// In strict mode, any [`CobLoadError`] encountered while in [`LoadState::Loading`] (e.g. unknown loadables or
+/// loadables with wrong fields or value types) moves the app to [`LoadState::Failed`] instead of skipping the
+/// affected loadables. This is useful for catching typos in COB files deterministically, e.g. in CI.
+///
+/// Strict mode is enabled with [`CobwebUiPlugin::strict`] or [`CobStrictModePlugin`].
+#[derive(Resource, Default, Debug, Copy, Clone)]
+pub struct CobStrictMode
+{
+    pub enabled: bool,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Plugin that enables [`CobStrictMode`].
+///
+/// Must be added after [`CobwebUiPlugin`].
+pub struct CobStrictModePlugin;
+
+impl Plugin for CobStrictModePlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.insert_resource(CobStrictMode { enabled: true });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct CobDiagnosticsPlugin;
+
+impl Plugin for CobDiagnosticsPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_resource::<CobDiagnostics>()
+            .init_resource::<CobStrictMode>()
+            .add_event::<CobErrorEvent>()
+            .add_systems(
+                First,
+                (handle_cob_asset_load_failures, update_cob_diagnostics)
+                    .chain()
+                    .after(FileProcessingSet),
+            )
+            .add_systems(
+                PreUpdate,
+                fail_load_in_strict_mode
+                    .after(LoadProgressSet::Check)
+                    .run_if(in_state(LoadState::Loading)),
+            );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::sync::Mutex;
 
 use bevy::prelude::*;
 use bevy::reflect::TypeRegistry;
@@ -24,25 +25,34 @@ pub(crate) fn preprocess_cob_file(
 
     // Extract manifest and import sections.
     let mut manifest = vec![];
+    let mut dirs = vec![];
+    let mut assets = vec![];
     let mut imports: HashMap<ManifestKey, CobImportAlias> = HashMap::default();
 
     for section in data.sections.iter() {
         match section {
-            CobSection::Manifest(section) => extract_manifest_section(&data.file, section, &mut manifest),
+            CobSection::Manifest(section) => {
+                extract_manifest_section(&data.file, section, &mut manifest, &mut dirs, &mut assets)
+            }
             CobSection::Import(section) => extract_import_section(section, &mut imports),
             _ => (),
         }
     }
 
-    // Register manifest keys.
-    let mut descendants = vec![];
-    for (other_file, manifest_key) in manifest {
-        // Cache file for commands buffer.
-        // - We skip any self reference in the manifest.
-        if other_file != data.file {
-            descendants.push(other_file.clone());
-        }
+    // Cache files for commands buffer.
+    // - We skip any self reference in the manifest.
+    // - Command order follows manifest order regardless of load priority.
+    let descendants = manifest
+        .iter()
+        .filter(|(other_file, _, _)| *other_file != data.file)
+        .map(|(other_file, _, _)| other_file.clone())
+        .collect();
 
+    // Register manifest keys.
+    // - Higher-priority files are requested from the asset server first (the sort is stable so equal priorities
+    //   keep manifest order).
+    manifest.sort_by_key(|(_, _, priority)| std::cmp::Reverse(*priority));
+    for (other_file, manifest_key, _) in manifest {
         // Continue if this file has been registered before.
         if !cob_cache.register_manifest_key(other_file.clone(), Some(manifest_key)) {
             continue;
@@ -52,6 +62,17 @@ pub(crate) fn preprocess_cob_file(
         cob_files.start_loading(other_file, cob_cache, asset_server);
     }
 
+    // Scan manifest directories.
+    // - Files found in directories are loaded as root files once the scans finish.
+    for (pattern, manifest_key) in dirs {
+        cob_files.load_dir(pattern, Some(manifest_key), asset_server);
+    }
+
+    // Load non-COB asset dependencies.
+    for (asset, weight) in assets {
+        cob_files.load_dependency(&data.file, asset, weight, asset_server);
+    }
+
     // Update this file in the commands buffer.
     commands_buffer.set_file_descendants(data.file.clone(), descendants);
 
@@ -67,7 +88,7 @@ pub(crate) fn preprocess_cob_file(
 
 //-------------------------------------------------------------------------------------------------------------------
 
-/// Extracts importable values (defs sections).
+/// Extracts importable values (defs sections, theme tokens, and extendable scenes).
 ///
 /// This is semi-destructive, because definitions will be removed and inserted to appropriate maps/buffers.
 pub(crate) fn extract_cob_importables(file: CobFile, data: &mut Cob, resolver: &mut CobResolver)
@@ -79,68 +100,86 @@ pub(crate) fn extract_cob_importables(file: CobFile, data: &mut Cob, resolver: &
     for section in data.sections.iter_mut() {
         match section {
             CobSection::Defs(section) => extract_defs_section(&file, section, resolver),
+            CobSection::Theme(section) => extract_theme_section(&file, section, resolver),
             _ => (),
         }
     }
 
+    // Scenes are cached after all defs are available.
+    for section in data.sections.iter() {
+        let CobSection::Scenes(section) = section else { continue };
+        extract_scene_bases(&file, section, resolver);
+    }
+
     resolver.end_new_file();
 }
 
 //-------------------------------------------------------------------------------------------------------------------
 
 /// Extracts commands from a `Cob`. Commands are updated in-place when resolving defs.
+///
+/// The commands should be added to the [`CommandsBuffer`] with [`CommandsBuffer::set_file_commands`].
 pub(crate) fn extract_cob_commands(
     type_registry: &TypeRegistry,
-    commands_buffer: &mut CommandsBuffer,
-    file: CobFile,
+    file: &CobFile,
     data: &mut Cob,
-    loadables: &LoadableRegistry,
+    loadables: &LoadableNames,
     resolver: &CobResolver,
-)
+    errors: &mut Vec<CobLoadError>,
+) -> Vec<(&'static str, ErasedLoadable)>
 {
     let mut commands = vec![];
 
     for section in data.sections.iter_mut() {
         match section {
             CobSection::Commands(section) => {
-                extract_commands_section(type_registry, &mut commands, &file, section, loadables, resolver)
+                extract_commands_section(type_registry, &mut commands, file, section, loadables, resolver, errors)
             }
             _ => (),
         }
     }
 
-    commands_buffer.set_file_commands(file, commands);
+    commands
 }
 
 //-------------------------------------------------------------------------------------------------------------------
 
 /// Extracts scenes from a `Cob`. Scene nodes are updated in-place when resolving defs.
+///
+/// Scene patches are applied to scenes in imported files after the file's own scenes are extracted.
+///
+/// The scenes should be inserted into the [`SceneBuffer`] with [`ExtractedScenes::insert`].
 pub(crate) fn extract_cob_scenes(
     type_registry: &TypeRegistry,
-    c: &mut Commands,
-    scene_buffer: &mut SceneBuffer,
-    scene_builder: &mut SceneBuilder,
-    file: CobFile,
-    mut data: Cob,
-    loadables: &LoadableRegistry,
+    file: &CobFile,
+    data: &mut Cob,
+    imports: &HashMap<ManifestKey, CobImportAlias>,
+    manifest_map: &Mutex<ManifestMap>,
+    loadables: &LoadableNames,
     resolver: &mut CobResolver,
-)
+    errors: &mut Vec<CobLoadError>,
+) -> ExtractedScenes
 {
+    let mut extracted = ExtractedScenes::default();
+
     for section in data.sections.iter_mut() {
         match section {
             CobSection::Scenes(section) => extract_scenes(
                 type_registry,
-                c,
-                scene_buffer,
-                scene_builder,
-                &file,
+                &mut extracted,
+                file,
                 section,
+                imports,
+                manifest_map,
                 loadables,
                 resolver,
+                errors,
             ),
             _ => (),
         }
     }
+
+    extracted
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -10,8 +10,9 @@ pub(super) fn extract_commands_section(
     commands: &mut Vec<(&'static str, ErasedLoadable)>,
     file: &CobFile,
     section: &mut CobCommands,
-    loadables: &LoadableRegistry,
+    loadables: &LoadableNames,
     resolver: &CobResolver,
+    errors: &mut Vec<CobLoadError>,
 )
 {
     if section.entries.is_empty() {
@@ -29,7 +30,7 @@ pub(super) fn extract_commands_section(
 
         // Get the loadable's longname.
         let Some((short_name, long_name, type_id, deserializer)) =
-            get_loadable_meta(type_registry, file, &mock_path, shortname.as_str(), loadables)
+            get_loadable_meta(type_registry, file, &mock_path, shortname.as_str(), loadables, errors)
         else {
             continue;
         };
@@ -47,11 +48,17 @@ pub(super) fn extract_commands_section(
         if let Err(err) = loadable.resolve(&resolver.loadables) {
             tracing::warn!("failed extracting command {:?} in {:?}; error resolving defs: {:?}",
                 short_name, file, err.as_str());
+            errors.push(CobLoadError::UnresolvedDefs {
+                file: file.clone(),
+                path: mock_path.clone(),
+                message: format!("failed resolving command {short_name:?}; {err}"),
+            });
             continue;
         }
 
         // Get the commands's value.
-        let command_value = get_loadable_value(deserializer, loadable);
+        let command_value =
+            get_loadable_value(deserializer, type_id, loadable, file, &mock_path, short_name, errors);
 
         // Save the command.
         commands.push((long_name, ErasedLoadable { type_id, loadable: command_value }));
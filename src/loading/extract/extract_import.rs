@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::sync::Mutex;
 
 use crate::prelude::*;
 
@@ -12,3 +13,24 @@ pub(super) fn extract_import_section(section: &CobImport, imports: &mut HashMap<
 }
 
 //-------------------------------------------------------------------------------------------------------------------
+
+/// Looks up the file and import alias targeted by a scene patch.
+///
+/// Patched files must be imported so their scenes are extracted before the patch is applied.
+pub(super) fn get_scene_patch_target<'a>(
+    patch: &CobScenePatch,
+    imports: &'a HashMap<ManifestKey, CobImportAlias>,
+    manifest_map: &Mutex<ManifestMap>,
+) -> Result<(CobFile, &'a CobImportAlias), String>
+{
+    let Some(alias) = imports.get(&patch.key) else {
+        return Err(format!("patched file {:?} is not imported", patch.key.as_str()));
+    };
+    let Some(file) = manifest_map.lock().unwrap().get(&patch.key) else {
+        return Err(format!("patched file {:?} is not in the manifest", patch.key.as_str()));
+    };
+
+    Ok((file, alias))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
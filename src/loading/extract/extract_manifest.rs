@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use crate::prelude::*;
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -5,25 +7,40 @@ use crate::prelude::*;
 pub(super) fn extract_manifest_section(
     file: &CobFile,
     section: &CobManifest,
-    manifests: &mut Vec<(CobFile, ManifestKey)>,
+    manifests: &mut Vec<(CobFile, ManifestKey, i32)>,
+    dirs: &mut Vec<(CobDirPattern, ManifestKey)>,
+    assets: &mut Vec<(Arc<str>, f32)>,
 )
 {
     for entry in section.entries.iter() {
         let entry_file = match &entry.file {
             CobManifestFile::SelfRef => file.clone(),
             CobManifestFile::File(entry_file) => entry_file.clone(),
+            CobManifestFile::Dir(pattern) => {
+                if !dirs.iter().any(|(other, _)| other == pattern) {
+                    dirs.push((pattern.clone(), entry.key.clone()));
+                }
+                continue;
+            }
         };
 
         if manifests
             .iter()
-            .any(|(other_file, _)| entry_file == *other_file)
+            .any(|(other_file, _, _)| entry_file == *other_file)
         {
             tracing::warn!("ignoring duplicate file {:?} in manifest of {:?}",
                 entry_file, file);
             continue;
         }
 
-        manifests.push((entry_file, entry.key.clone()));
+        manifests.push((entry_file, entry.key.clone(), entry.priority()));
+    }
+
+    for asset in section.assets.iter() {
+        if assets.iter().any(|(path, _)| *path == asset.path) {
+            continue;
+        }
+        assets.push((asset.path.clone(), asset.weight()));
     }
 }
 
@@ -1,90 +1,361 @@
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
 use bevy::prelude::Commands;
+#[cfg(not(feature = "hot_reload"))]
+use bevy::reflect::serde::TypedReflectDeserializer;
 use bevy::reflect::TypeRegistry;
+#[cfg(not(feature = "hot_reload"))]
+use smol_str::SmolStr;
 
 use super::*;
 use crate::prelude::*;
 
 //-------------------------------------------------------------------------------------------------------------------
 
-fn handle_loadable(
-    id_scratch: String,
-    seen_shortnames: &mut Vec<&'static str>,
-    type_registry: &TypeRegistry,
+/// A loadable extracted from a scene node.
+#[derive(Debug)]
+struct ExtractedLoadable
+{
+    /// The loadable's index in its scene node.
+    index: usize,
+    value: ReflectedLoadable,
+    type_id: TypeId,
+    full_type_name: &'static str,
+    /// Theme variants of the loadable if it uses theme tokens.
+    #[cfg(not(feature = "hot_reload"))]
+    themed: Option<ThemedLoadable>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Contents of a scene node whose layer was resolved.
+#[derive(Debug)]
+struct ExtractedLayer
+{
+    /// The number of entries in the node's COB layer.
+    size: usize,
+    loadables: Vec<ExtractedLoadable>,
+    children: Vec<ExtractedSceneNode>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A scene node extracted from a COB file.
+#[derive(Debug)]
+struct ExtractedSceneNode
+{
+    path: ScenePath,
+    /// `None` if the node's layer failed to resolve. The node is still prepared in the scene buffer.
+    layer: Option<ExtractedLayer>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Scenes extracted from a COB file that are waiting to be inserted into the [`SceneBuffer`].
+///
+/// Scenes are extracted in extraction tasks, and then inserted on the main thread with
+/// [`ExtractedScenes::insert`].
+#[derive(Debug, Default)]
+pub(crate) struct ExtractedScenes
+{
+    /// [ (scene, root node) ]
+    /// - Scene patches are stored after the file's own scenes.
+    scenes: Vec<(SceneRef, ExtractedSceneNode)>,
+    has_patches: bool,
+}
+
+impl ExtractedScenes
+{
+    /// Returns `true` if the scenes include patches of scenes in other files.
+    #[cfg(feature = "hot_reload")]
+    pub(crate) fn has_patches(&self) -> bool
+    {
+        self.has_patches
+    }
+
+    /// Inserts the extracted scenes into the scene buffer and scene builder.
+    pub(crate) fn insert(
+        self,
+        c: &mut Commands,
+        scene_buffer: &mut SceneBuffer,
+        scene_builder: &mut SceneBuilderInner,
+        loadables: &LoadableRegistry,
+    )
+    {
+        let mut scene_registry = scene_builder.take_scene_registry();
+
+        for (scene_ref, root) in self.scenes {
+            let scene_layer = scene_registry.get_or_insert(scene_ref.clone());
+            insert_scene_node(c, scene_buffer, scene_builder, scene_layer, &scene_ref, root, loadables);
+        }
+
+        scene_builder.return_scene_registry(scene_registry);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn insert_scene_node(
+    _c: &mut Commands,
     scene_buffer: &mut SceneBuffer,
+    _scene_builder: &mut SceneBuilderInner,
+    scene_layer: &mut SceneLayer,
+    scene: &SceneRef,
+    node: ExtractedSceneNode,
+    loadables: &LoadableRegistry,
+)
+{
+    // Prep the node.
+    let scene_location = SceneRef { file: scene.file.clone(), path: node.path };
+    scene_buffer.prepare_scene_node(scene_location.clone());
+
+    let Some(layer) = node.layer else { return };
+
+    // Begin layer update.
+    scene_layer.start_update(layer.size);
+
+    // Add loadables.
+    #[cfg(feature = "hot_reload")]
+    let num_loadables = layer.loadables.len();
+
+    for loadable in layer.loadables {
+        scene_buffer.insert_loadable(
+            &scene_location,
+            Some(loadable.index),
+            loadable.value,
+            loadable.type_id,
+            loadable.full_type_name,
+        );
+        #[cfg(not(feature = "hot_reload"))]
+        if let Some(themed) = loadable.themed {
+            scene_buffer.insert_themed_loadable(&scene_location, themed);
+        }
+    }
+
+    #[cfg(feature = "hot_reload")]
+    scene_buffer.end_loadable_insertion(&scene_location, num_loadables);
+
+    // Add layers.
+    for child in layer.children {
+        // Save this node in the scene.
+        let child_layer = match scene_layer.insert(&child.path) {
+            #[cfg(feature = "hot_reload")]
+            SceneLayerInsertionResult::NoChange(child_layer) => child_layer,
+            #[cfg(feature = "hot_reload")]
+            SceneLayerInsertionResult::Updated(index, child_layer) => {
+                _scene_builder.handle_rearranged_scene_node(_c, scene, &scene_location.path, &child.path, index);
+                child_layer
+            }
+            SceneLayerInsertionResult::Added(_index, child_layer) => {
+                #[cfg(feature = "hot_reload")]
+                {
+                    _scene_builder.handle_inserted_scene_node(
+                        _c,
+                        scene,
+                        &scene_location.path,
+                        &child.path,
+                        _index,
+                    );
+                }
+                child_layer
+            }
+        };
+
+        insert_scene_node(_c, scene_buffer, _scene_builder, child_layer, scene, child, loadables);
+    }
+
+    // End layer update and handle removed nodes.
+    for SceneLayerData { id, .. } in scene_layer.end_update() {
+        #[cfg(feature = "hot_reload")]
+        {
+            _scene_builder.cleanup_deleted_scene_node(_c, scene_buffer, loadables, scene, &id);
+        }
+        #[cfg(not(feature = "hot_reload"))]
+        {
+            let _ = loadables;
+            tracing::error!("scene node {:?} unexpectedly removed from {:?} while parsing scene (this is a bug)",
+                id, scene);
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Context and scratch buffers for extracting the scenes of a file.
+struct SceneExtractor<'a>
+{
+    type_registry: &'a TypeRegistry,
+    loadables: &'a LoadableNames,
+    errors: &'a mut Vec<CobLoadError>,
+    id_scratch: String,
+    seen_shortnames: Vec<&'static str>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn handle_loadable(
+    ex: &mut SceneExtractor,
+    extracted: &mut Vec<ExtractedLoadable>,
     file: &CobFile,
     current_path: &ScenePath,
     loadable: &mut CobLoadable,
-    loadables: &LoadableRegistry,
-    resolver: &CobLoadableResolver,
-) -> String
+    resolver: &mut CobLoadableResolver,
+)
 {
     // Get the loadable's longname.
-    let id_scratch = loadable.id.to_canonical(Some(id_scratch));
-    let Some((short_name, long_name, type_id, deserializer)) =
-        get_loadable_meta(type_registry, file, current_path, id_scratch.as_str(), loadables)
-    else {
-        return id_scratch;
+    ex.id_scratch = loadable
+        .id
+        .to_canonical(Some(std::mem::take(&mut ex.id_scratch)));
+    let Some((short_name, long_name, type_id, deserializer)) = get_loadable_meta(
+        ex.type_registry,
+        file,
+        current_path,
+        ex.id_scratch.as_str(),
+        ex.loadables,
+        ex.errors,
+    ) else {
+        return;
     };
 
     // Check for duplicate.
-    if seen_shortnames.iter().any(|other| *other == short_name) {
+    if ex.seen_shortnames.iter().any(|other| *other == short_name) {
         tracing::warn!("ignoring duplicate loadable {} at {:?} in {:?}; use Multi<{}> instead",
             short_name, current_path, file, short_name);
-        return id_scratch;
+        return;
     }
 
+    // Cache the unresolved loadable in case it uses theme tokens.
+    #[cfg(not(feature = "hot_reload"))]
+    let (unresolved, theme_uses) = (loadable.clone(), resolver.themes.num_uses());
+
     // Resolve defs.
     if let Err(err) = loadable.resolve(resolver) {
         tracing::warn!("failed extracting loadable {:?} at {:?} in {:?}; error resolving defs: {:?}",
             short_name, current_path, file, err.as_str());
-        return id_scratch;
+        ex.errors.push(CobLoadError::UnresolvedDefs {
+            file: file.clone(),
+            path: current_path.clone(),
+            message: format!("failed resolving loadable {short_name:?}; {err}"),
+        });
+        return;
     }
 
     // Get the loadable's value.
-    let loadable_value = get_loadable_value(deserializer, loadable);
+    let loadable_value = get_loadable_value(
+        deserializer,
+        type_id,
+        loadable,
+        file,
+        current_path,
+        short_name,
+        ex.errors,
+    );
 
     // Save this loadable.
-    let loadable_index = seen_shortnames.len();
-    seen_shortnames.push(short_name);
+    let loadable_index = ex.seen_shortnames.len();
+    ex.seen_shortnames.push(short_name);
 
-    scene_buffer.insert_loadable(
-        &SceneRef {
-            file: SceneFile::File(file.clone()),
-            path: current_path.clone(),
-        },
-        Some(loadable_index),
-        loadable_value,
+    // Without hot reloading, files aren't re-processed when the theme changes, so themed loadables are resolved
+    // for every theme up front.
+    #[cfg(not(feature = "hot_reload"))]
+    let themed = (resolver.themes.num_uses() != theme_uses).then(|| ThemedLoadable {
+        index: loadable_index,
         type_id,
-        long_name,
-    );
+        full_type_name: long_name,
+        variants: resolve_theme_variants(
+            ex.type_registry,
+            file,
+            current_path,
+            short_name,
+            type_id,
+            &unresolved,
+            resolver,
+        ),
+    });
 
-    id_scratch
+    extracted.push(ExtractedLoadable {
+        index: loadable_index,
+        value: loadable_value,
+        type_id,
+        full_type_name: long_name,
+        #[cfg(not(feature = "hot_reload"))]
+        themed,
+    });
 }
 
 //-------------------------------------------------------------------------------------------------------------------
 
-fn handle_scene_node(
-    mut id_scratch: String,
-    seen_shortnames: &mut Vec<&'static str>,
+/// Resolves a loadable for each theme variant its tokens might have values for, with and without high contrast.
+///
+/// Errors were already reported when the loadable was resolved for the active theme, so they are ignored here.
+#[cfg(not(feature = "hot_reload"))]
+fn resolve_theme_variants(
     type_registry: &TypeRegistry,
-    c: &mut Commands,
-    scene_buffer: &mut SceneBuffer,
-    scene_builder: &mut SceneBuilderInner,
-    scene_layer: &mut SceneLayer,
+    file: &CobFile,
+    current_path: &ScenePath,
+    short_name: &str,
+    type_id: TypeId,
+    unresolved: &CobLoadable,
+    resolver: &mut CobLoadableResolver,
+) -> Vec<(SmolStr, bool, ReflectedLoadable)>
+{
+    let Some(registration) = type_registry.get(type_id) else { return vec![] };
+    let active = SmolStr::from(resolver.themes.active());
+    let high_contrast = resolver.themes.high_contrast();
+
+    // The empty theme gets the fallback values of tokens.
+    let mut themes = resolver.themes.variant_names();
+    themes.push(Default::default());
+
+    let mut variants = Vec::with_capacity(themes.len() * 2);
+    let mut ignored_errors = vec![];
+    for theme in themes {
+        for hc in [false, true] {
+            resolver.themes.set_active(theme.clone());
+            resolver.themes.set_high_contrast(hc);
+            let mut loadable = unresolved.clone();
+            if loadable.resolve(resolver).is_err() {
+                continue;
+            }
+            let deserializer = TypedReflectDeserializer::new(registration, type_registry);
+            let value = get_loadable_value(
+                deserializer,
+                type_id,
+                &loadable,
+                file,
+                current_path,
+                short_name,
+                &mut ignored_errors,
+            );
+            variants.push((theme.clone(), hc, value));
+        }
+    }
+
+    resolver.themes.set_active(active);
+    resolver.themes.set_high_contrast(high_contrast);
+    variants
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn handle_scene_node(
+    ex: &mut SceneExtractor,
+    children: &mut Vec<ExtractedSceneNode>,
     scene: &SceneRef,
     parent_path: &ScenePath,
     cob_layer: &mut CobSceneLayer,
-    loadables: &LoadableRegistry,
     resolver: &mut CobResolver,
     anonymous_count: &mut usize,
-) -> String
+)
 {
     // If node is anonymous, give it a unique name.
     let layer_name = if cob_layer.name.as_str() == "" {
-        id_scratch.clear();
-        let _ = write!(&mut id_scratch, "_{}", *anonymous_count);
+        ex.id_scratch.clear();
+        let _ = write!(&mut ex.id_scratch, "_{}", *anonymous_count);
         *anonymous_count += 1;
-        id_scratch.as_str()
+        ex.id_scratch.as_str()
     } else {
         cob_layer.name.as_str()
     };
@@ -92,94 +363,59 @@ fn handle_scene_node(
     let Some(node_path) = parent_path.extend_single(layer_name) else {
         tracing::error!("failed parsing scene node {:?} at {:?} in {:?}, node ID is a multi-segment path, only \
             single-segment node ids are allowed in scene definitions", layer_name, parent_path, scene.file);
-        return id_scratch;
-    };
-
-    // Save this node in the scene.
-    let child_layer = match scene_layer.insert(&node_path) {
-        #[cfg(feature = "hot_reload")]
-        SceneLayerInsertionResult::NoChange(child_layer) => child_layer,
-        #[cfg(feature = "hot_reload")]
-        SceneLayerInsertionResult::Updated(index, child_layer) => {
-            scene_builder.handle_rearranged_scene_node(c, scene, parent_path, &node_path, index);
-            child_layer
-        }
-        SceneLayerInsertionResult::Added(_index, child_layer) => {
-            #[cfg(feature = "hot_reload")]
-            {
-                scene_builder.handle_inserted_scene_node(c, scene, parent_path, &node_path, _index);
-            }
-            child_layer
-        }
+        return;
     };
 
     // Parse the child layer of this node.
-    extract_scene_layer(
-        id_scratch,
-        seen_shortnames,
-        type_registry,
-        c,
-        scene_buffer,
-        scene_builder,
-        child_layer,
-        scene,
-        &node_path,
-        cob_layer,
-        loadables,
-        resolver,
-    )
+    children.push(extract_scene_layer(ex, scene, node_path, cob_layer, resolver));
 }
 
 //-------------------------------------------------------------------------------------------------------------------
 
 fn extract_scene_layer(
-    mut id_scratch: String,
-    seen_shortnames: &mut Vec<&'static str>,
-    type_registry: &TypeRegistry,
-    c: &mut Commands,
-    scene_buffer: &mut SceneBuffer,
-    scene_builder: &mut SceneBuilderInner,
-    scene_layer: &mut SceneLayer,
+    ex: &mut SceneExtractor,
     scene: &SceneRef,
-    current_path: &ScenePath,
+    current_path: ScenePath,
     cob_layer: &mut CobSceneLayer,
-    loadables: &LoadableRegistry,
     resolver: &mut CobResolver,
-) -> String
+) -> ExtractedSceneNode
 {
-    // Prep the node.
-    let scene_location = SceneRef { file: scene.file.clone(), path: current_path.clone() };
-    scene_buffer.prepare_scene_node(scene_location.clone());
-
     // Resolve the scene layer.
     if let Err(err) = cob_layer.resolve(resolver, SceneResolveMode::OneLayerSceneOnly) {
         tracing::warn!("failed extracting scene layer {:?} at {:?} in {:?}; error resolving defs: {:?}",
             cob_layer.name.as_str(), current_path, scene.file, err.as_str());
-        return id_scratch;
+        if let Some(file) = scene.file.file() {
+            ex.errors.push(CobLoadError::UnresolvedDefs {
+                file: file.clone(),
+                path: current_path.clone(),
+                message: format!("failed resolving scene layer; {err}"),
+            });
+        }
+        return ExtractedSceneNode { path: current_path, layer: None };
     }
 
-    // Begin layer update.
-    scene_layer.start_update(cob_layer.entries.len());
+    let mut layer = ExtractedLayer {
+        size: cob_layer.entries.len(),
+        loadables: vec![],
+        children: vec![],
+    };
 
     // Add loadables.
-    seen_shortnames.clear();
+    ex.seen_shortnames.clear();
 
     for entry in cob_layer.entries.iter_mut() {
         match entry {
             CobSceneLayerEntry::Loadable(loadable) => {
-                id_scratch = handle_loadable(
-                    id_scratch,
-                    seen_shortnames,
-                    type_registry,
-                    scene_buffer,
+                handle_loadable(
+                    ex,
+                    &mut layer.loadables,
                     scene
                         .file
                         .file()
                         .expect("all SceneFile should contain CobFile in scene extraction"),
-                    current_path,
+                    &current_path,
                     loadable,
-                    loadables,
-                    &resolver.loadables,
+                    &mut resolver.loadables,
                 );
             }
             // Do this one after we are done using the `seen_shortnames` buffer.
@@ -195,26 +431,17 @@ fn extract_scene_layer(
         }
     }
 
-    #[cfg(feature = "hot_reload")]
-    scene_buffer.end_loadable_insertion(&scene_location, seen_shortnames.len());
-
     // Add layers.
     let mut anonymous_count = 0;
     for entry in cob_layer.entries.iter_mut() {
         match entry {
             CobSceneLayerEntry::Layer(next_cob_layer) => {
-                id_scratch = handle_scene_node(
-                    id_scratch,
-                    seen_shortnames,
-                    type_registry,
-                    c,
-                    scene_buffer,
-                    scene_builder,
-                    scene_layer,
+                handle_scene_node(
+                    ex,
+                    &mut layer.children,
                     scene,
-                    current_path,
+                    &current_path,
                     next_cob_layer,
-                    loadables,
                     resolver,
                     &mut anonymous_count,
                 );
@@ -223,20 +450,113 @@ fn extract_scene_layer(
         }
     }
 
-    // End layer update and handle removed nodes.
-    for SceneLayerData { id, .. } in scene_layer.end_update() {
-        #[cfg(feature = "hot_reload")]
-        {
-            scene_builder.cleanup_deleted_scene_node(c, scene_buffer, loadables, scene, &id);
-        }
-        #[cfg(not(feature = "hot_reload"))]
+    ExtractedSceneNode { path: current_path, layer: Some(layer) }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Caches the scenes in a section so they can be extended by other scenes.
+pub(super) fn extract_scene_bases(file: &CobFile, section: &CobScenes, resolver: &mut CobResolver)
+{
+    for cob_layer in section.scenes.iter().filter(|s| s.patch.is_none()) {
+        let mut cob_layer = cob_layer.clone();
+
+        // Full-resolve the scene so it can be pasted into extending scenes.
+        if let Err(err) = cob_layer
+            .resolve_extends(resolver)
+            .and_then(|_| cob_layer.resolve(resolver, SceneResolveMode::Full))
         {
-            tracing::error!("scene node {:?} unexpectedly removed from {:?} while parsing scene (this is a bug)",
-                id, scene);
+            tracing::warn!("failed caching scene {:?} in {:?} for extension; error resolving defs: {:?}",
+                cob_layer.name.as_str(), file, err.as_str());
+            continue;
         }
+
+        let CobSceneLayer { name, entries, .. } = cob_layer;
+        resolver.scenes.scene_bases.insert(
+            file,
+            name.0,
+            CobSceneMacroValue {
+                start_fill: CobFill::default(),
+                entries,
+                end_fill: CobFill::default(),
+            },
+        );
     }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
 
-    id_scratch
+/// Applies a scene patch to a scene in an imported file.
+///
+/// The patched scene is rebuilt from the imported file's scene definition, so patches from different files do not
+/// stack.
+fn apply_scene_patch(
+    ex: &mut SceneExtractor,
+    extracted: &mut ExtractedScenes,
+    file: &CobFile,
+    patch_layer: &mut CobSceneLayer,
+    imports: &HashMap<ManifestKey, CobImportAlias>,
+    manifest_map: &Mutex<ManifestMap>,
+    resolver: &mut CobResolver,
+)
+{
+    let Some(patch) = &patch_layer.patch else { return };
+    let (target_file, alias) = match get_scene_patch_target(patch, imports, manifest_map) {
+        Ok(target) => target,
+        Err(err) => {
+            tracing::warn!("failed applying scene patch {:?} in {:?}; {}", patch_layer.name.as_str(), file, err);
+            ex.errors.push(CobLoadError::BadImport {
+                file: file.clone(),
+                message: format!("failed applying scene patch {:?}; {}", patch_layer.name.as_str(), err),
+            });
+            return;
+        }
+    };
+    let Some(path) = ScenePath::parse_single(&*patch_layer.name) else {
+        tracing::error!("failed parsing scene patch {:?} in {:?}, scene root ID is a multi-segment path, only \
+            single-segment node ids are allowed in scene definitions", *patch_layer.name, file);
+        return;
+    };
+
+    // Merge the patch into the imported scene.
+    let base_path = match alias.as_str() {
+        "" => patch_layer.name.to_string(),
+        alias => format!("{}::{}", alias, patch_layer.name.as_str()),
+    };
+    let merged = CobSceneLayer::resolve_entries_impl(
+        patch_layer.name.as_str(),
+        &mut patch_layer.entries,
+        resolver,
+        SceneResolveMode::SceneOnly,
+    )
+    .and_then(|_| {
+        resolver
+            .scenes
+            .scene_bases
+            .expand_entries(base_path.as_str(), &patch_layer.entries)
+            .ok_or_else(|| format!("no scene at {:?}", base_path))
+    });
+    let entries = match merged {
+        Ok(entries) => entries,
+        Err(err) => {
+            tracing::warn!("failed applying scene patch {:?} in {:?} to {:?}; {}",
+                patch_layer.name.as_str(), file, target_file, err);
+            return;
+        }
+    };
+    let mut cob_layer = CobSceneLayer {
+        name_fill: CobFill::default(),
+        patch: None,
+        name: patch_layer.name.clone(),
+        extends: None,
+        entries,
+    };
+
+    // Re-extract the patched scene.
+    let scene_ref = SceneRef { file: SceneFile::File(target_file), path };
+    let root = extract_scene_layer(ex, &scene_ref, scene_ref.path.clone(), &mut cob_layer, resolver);
+    extracted.scenes.push((scene_ref, root));
+    extracted.has_patches = true;
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -244,20 +564,30 @@ fn extract_scene_layer(
 // TODO: disallow duplicate node names, excluding anonymous nodes
 pub(super) fn extract_scenes(
     type_registry: &TypeRegistry,
-    c: &mut Commands,
-    scene_buffer: &mut SceneBuffer,
-    scene_builder: &mut SceneBuilderInner,
+    extracted: &mut ExtractedScenes,
     file: &CobFile,
     section: &mut CobScenes,
-    loadables: &LoadableRegistry,
+    imports: &HashMap<ManifestKey, CobImportAlias>,
+    manifest_map: &Mutex<ManifestMap>,
+    loadables: &LoadableNames,
     resolver: &mut CobResolver,
+    errors: &mut Vec<CobLoadError>,
 )
 {
-    let mut scene_registry = scene_builder.take_scene_registry();
-    let mut id_scratch = String::default();
-    let mut seen_shortnames = vec![];
+    let mut ex = SceneExtractor {
+        type_registry,
+        loadables,
+        errors,
+        id_scratch: String::default(),
+        seen_shortnames: vec![],
+    };
 
     for cob_layer in section.scenes.iter_mut() {
+        // Patches are applied after this file's scenes are extracted.
+        if cob_layer.patch.is_some() {
+            continue;
+        }
+
         // Get this scene for editing.
         let Some(path) = ScenePath::parse_single(&*cob_layer.name) else {
             tracing::error!("failed parsing scene {:?} in {:?}, scene root ID is a multi-segment path, only \
@@ -265,26 +595,22 @@ pub(super) fn extract_scenes(
             continue;
         };
         let scene_ref = SceneRef { file: SceneFile::File(file.clone()), path };
-        let scene_layer = scene_registry.get_or_insert(scene_ref.clone());
+
+        // Merge the scene into its base scene.
+        if let Err(err) = cob_layer.resolve_extends(resolver) {
+            tracing::warn!("failed extracting scene {:?} in {:?}; error resolving base scene: {:?}",
+                cob_layer.name.as_str(), file, err.as_str());
+            continue;
+        }
 
         // Parse the scene.
-        id_scratch = extract_scene_layer(
-            id_scratch,
-            &mut seen_shortnames,
-            type_registry,
-            c,
-            scene_buffer,
-            scene_builder,
-            scene_layer,
-            &scene_ref,
-            &scene_ref.path,
-            cob_layer,
-            loadables,
-            resolver,
-        );
+        let root = extract_scene_layer(&mut ex, &scene_ref, scene_ref.path.clone(), cob_layer, resolver);
+        extracted.scenes.push((scene_ref, root));
     }
 
-    scene_builder.return_scene_registry(scene_registry);
+    for cob_layer in section.scenes.iter_mut().filter(|s| s.patch.is_some()) {
+        apply_scene_patch(&mut ex, extracted, file, cob_layer, imports, manifest_map, resolver);
+    }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,54 @@
+use smol_str::SmolStr;
+
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn extract_theme_entry(file: &CobFile, mut entry: CobConstantDef, resolver: &mut CobLoadableResolver)
+{
+    // Resolve the token's internal values.
+    if let Err(err) = entry.value.resolve(resolver) {
+        tracing::warn!("failed extracting theme token {:?} in {:?}; error resolving internal defs: {:?}",
+            entry.name.as_str(), file, err.as_str());
+        return;
+    }
+
+    // Collect theme variants.
+    let CobConstantValue::Value(CobValue::Map(map)) = entry.value else {
+        tracing::warn!("failed extracting theme token {:?} in {:?}; token value must be a map of \
+            {{theme: value}}", entry.name.as_str(), file);
+        return;
+    };
+
+    let mut variants = Vec::with_capacity(map.entries.len());
+    for map_entry in map.entries {
+        let CobMapEntry::KeyValue(CobMapKeyValue { key: CobMapKey::FieldName { name, .. }, value, .. }) = map_entry
+        else {
+            tracing::warn!("failed extracting theme token {:?} in {:?}; token entries must be theme names \
+                followed by values", entry.name.as_str(), file);
+            return;
+        };
+        variants.push((SmolStr::from(name.as_str()), value));
+    }
+
+    if variants.is_empty() {
+        tracing::warn!("failed extracting theme token {:?} in {:?}; token has no theme values",
+            entry.name.as_str(), file);
+        return;
+    }
+
+    // Save the token in the themes buffer.
+    resolver.themes.insert(file, entry.name.name, variants);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Removes all theme tokens and caches them in the theme resolver.
+pub(super) fn extract_theme_section(file: &CobFile, section: &mut CobTheme, resolver: &mut CobResolver)
+{
+    for entry in section.entries.drain(..) {
+        extract_theme_entry(file, entry, &mut resolver.loadables);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
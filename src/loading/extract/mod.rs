@@ -4,6 +4,7 @@ mod extract_defs;
 mod extract_import;
 mod extract_manifest;
 mod extract_scenes;
+mod extract_theme;
 mod reflected_loadable;
 mod utils;
 
@@ -12,6 +13,8 @@ pub(self) use extract_commands::*;
 pub(self) use extract_defs::*;
 pub(self) use extract_import::*;
 pub(self) use extract_manifest::*;
+pub(crate) use extract_scenes::ExtractedScenes;
 pub(self) use extract_scenes::*;
+pub(self) use extract_theme::*;
 pub(crate) use reflected_loadable::*;
 pub(self) use utils::*;
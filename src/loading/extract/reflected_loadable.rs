@@ -22,17 +22,19 @@ pub(crate) enum ReflectedLoadable
 {
     Value(Arc<Box<dyn PartialReflect + 'static>>),
     DeserializationFailed(Arc<CobError>),
+    /// The loadable contains bindings, so it is deserialized when applied to an entity.
+    Bound(Arc<CobBoundLoadable>),
 }
 
 impl ReflectedLoadable
 {
     pub(crate) fn equals(&self, other: &ReflectedLoadable) -> Option<bool>
     {
-        let (Self::Value(this), Self::Value(other)) = (self, other) else {
-            return Some(false);
-        };
-
-        this.reflect_partial_eq(other.as_partial_reflect())
+        match (self, other) {
+            (Self::Value(this), Self::Value(other)) => this.reflect_partial_eq(other.as_partial_reflect()),
+            (Self::Bound(this), Self::Bound(other)) => Some(this.loadable == other.loadable),
+            _ => Some(false),
+        }
     }
 
     pub(crate) fn get_value<T: Loadable>(&self, scene_ref: &SceneRef, registry: &TypeRegistry) -> Option<T>
@@ -55,6 +57,12 @@ impl ReflectedLoadable
                     type_name::<T>(), scene_ref.path.path, scene_ref.file, **err, hint.as_str());
                 None
             }
+            ReflectedLoadable::Bound(_) => {
+                tracing::error!("failed applying loadable {:?} at path {:?} in file {:?}, bindings are only supported \
+                    in loadables applied to scene nodes",
+                    type_name::<T>(), scene_ref.path.path, scene_ref.file);
+                None
+            }
         }
     }
 
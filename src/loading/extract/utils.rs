@@ -14,7 +14,8 @@ pub(super) fn get_loadable_meta<'a>(
     file: &CobFile,
     current_path: &ScenePath,
     short_name: &str,
-    loadables: &LoadableRegistry,
+    loadables: &LoadableNames,
+    errors: &mut Vec<CobLoadError>,
 ) -> Option<(&'static str, &'static str, TypeId, TypedReflectDeserializer<'a>)>
 {
     // Look up the registration.
@@ -24,6 +25,11 @@ pub(super) fn get_loadable_meta<'a>(
             tracing::warn!("failed getting type id for loadable {} at {:?} in {:?}; no loadable with this name was \
                 registered in the app",
                 short_name, current_path, file);
+            errors.push(CobLoadError::UnknownLoadable {
+                file: file.clone(),
+                path: current_path.clone(),
+                name: short_name.into(),
+            });
             return None;
         }
     };
@@ -47,12 +53,36 @@ pub(super) fn get_loadable_meta<'a>(
 
 //-------------------------------------------------------------------------------------------------------------------
 
-pub(super) fn get_loadable_value(deserializer: TypedReflectDeserializer, value: &CobLoadable)
-    -> ReflectedLoadable
+/// Deserializes a loadable value.
+///
+/// Deserialization failures are recorded in `errors`. They are logged when the loadable is applied.
+///
+/// Loadables that contain bindings are not deserialized here, they are deserialized when applied to entities.
+pub(super) fn get_loadable_value(
+    deserializer: TypedReflectDeserializer,
+    type_id: TypeId,
+    value: &CobLoadable,
+    file: &CobFile,
+    current_path: &ScenePath,
+    short_name: &str,
+    errors: &mut Vec<CobLoadError>,
+) -> ReflectedLoadable
 {
+    if let Some(bound) = CobBoundLoadable::try_new(type_id, value) {
+        return ReflectedLoadable::Bound(Arc::new(bound));
+    }
+
     match deserializer.deserialize(value) {
         Ok(value) => ReflectedLoadable::Value(Arc::new(value)),
-        Err(err) => ReflectedLoadable::DeserializationFailed(Arc::new(err)),
+        Err(err) => {
+            errors.push(CobLoadError::InvalidLoadable {
+                file: file.clone(),
+                path: current_path.clone(),
+                name: short_name.into(),
+                message: err.to_string(),
+            });
+            ReflectedLoadable::DeserializationFailed(Arc::new(err))
+        }
     }
 }
 
@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use smol_str::SmolStr;
+
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Checks the `#require` sections of a COB file against registered [`CobLibraries`].
+pub(crate) fn check_cob_requirements(data: &Cob, libraries: &CobLibraries, cob_cache: &mut CobAssetCache)
+{
+    for section in data.sections.iter() {
+        let CobSection::Require(section) = section else { continue };
+
+        for entry in section.entries.iter() {
+            let message = match entry.version_req() {
+                Err(err) => format!("requirement is invalid: {err}"),
+                Ok(req) => match libraries.get(&entry.library) {
+                    None => "library is not registered".into(),
+                    Some(library) if !req.matches(&library.version) => {
+                        format!("found version {}", library.version)
+                    }
+                    Some(_) => continue,
+                },
+            };
+            let error = CobLoadError::UnmetRequirement {
+                file: data.file.clone(),
+                library: entry.library.to_string(),
+                requirement: entry.requirement.to_string(),
+                message,
+            };
+            tracing::error!("{error}");
+            cob_cache.push_error(error);
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A library of COB files (defs, scenes, etc.) shipped by a crate.
+///
+/// See [`load_cob_library`](crate::load_cob_library).
+#[derive(Debug, Clone)]
+pub struct CobLibrary
+{
+    /// Namespace of the library. Usually the name of the crate that ships it.
+    pub name: SmolStr,
+    pub version: semver::Version,
+    pub files: Vec<CobFile>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource that tracks registered [`CobLibraries`](CobLibrary).
+///
+/// COB files can require libraries with a `#require` section. Requirements are checked when the requiring file
+/// loads, and unmet requirements are reported as [`CobLoadError::UnmetRequirement`].
+#[derive(Resource, Default, Debug)]
+pub struct CobLibraries
+{
+    libraries: HashMap<SmolStr, CobLibrary>,
+}
+
+impl CobLibraries
+{
+    /// Gets a registered library.
+    pub fn get(&self, name: &str) -> Option<&CobLibrary>
+    {
+        self.libraries.get(name)
+    }
+
+    /// Iterates registered libraries.
+    pub fn iter(&self) -> impl Iterator<Item = &CobLibrary> + '_
+    {
+        self.libraries.values()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Extends `App` with methods for registering [`CobLibraries`](CobLibrary).
+pub trait CobLibraryAppExt
+{
+    /// Registers a COB library and loads its files with [`LoadedCobAssetFilesAppExt::load`].
+    ///
+    /// The version must be a valid [semver](https://semver.org) version. If a library with the same name is
+    /// already registered, the new registration is ignored.
+    ///
+    /// Libraries shipped by crates are usually registered with [`load_cob_library`](crate::load_cob_library),
+    /// which embeds the files and uses the crate's name and version.
+    fn register_cob_library<T: AsRef<str>>(
+        &mut self,
+        name: impl Into<SmolStr>,
+        version: &str,
+        files: impl IntoIterator<Item = T>,
+    ) -> &mut Self;
+}
+
+impl CobLibraryAppExt for App
+{
+    fn register_cob_library<T: AsRef<str>>(
+        &mut self,
+        name: impl Into<SmolStr>,
+        version: &str,
+        files: impl IntoIterator<Item = T>,
+    ) -> &mut Self
+    {
+        if !self.world().contains_resource::<CobLibraries>() {
+            self.init_resource::<CobLibraries>();
+        }
+
+        let name = name.into();
+        let version = match semver::Version::parse(version) {
+            Ok(version) => version,
+            Err(err) => {
+                tracing::error!("failed registering COB library {name:?}; version {version:?} is invalid: {err}");
+                return self;
+            }
+        };
+        if let Some(prev) = self.world().resource::<CobLibraries>().get(&name) {
+            tracing::warn!("ignoring COB library {name:?} v{version}; v{} is already registered", prev.version);
+            return self;
+        }
+
+        let files: Vec<CobFile> = files
+            .into_iter()
+            .filter_map(|file| {
+                let file = file.as_ref();
+                let cob_file = CobFile::try_new(file);
+                if cob_file.is_none() {
+                    tracing::warn!("failed registering COB file {} in COB library {:?}; does not have '.cob' \
+                        extension", file, name);
+                }
+                cob_file
+            })
+            .collect();
+        for file in files.iter() {
+            self.load(file.as_str());
+        }
+
+        tracing::info!("registered COB library {name:?} v{version}");
+        self.world_mut()
+            .resource_mut::<CobLibraries>()
+            .libraries
+            .insert(name.clone(), CobLibrary { name, version, files });
+        self
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Embeds and registers a crate's COB files as a [`CobLibrary`].
+///
+/// The library is named after the calling crate (`CARGO_CRATE_NAME`) and uses the crate's version
+/// (`CARGO_PKG_VERSION`). Files are embedded with `embedded_asset!` relative to `$source_path`, and loaded from
+/// `embedded://{crate name}/{file}`.
+///
+/// Library files should be namespaced by the crate name with `#manifest` entries, so apps can import them
+/// without conflicts.
+///
+/// Example:
+/**
+```rust
+// In crate `cob_material_icons`:
+load_cob_library!(app, "src/cob", ["icons.cob", "buttons.cob"]);
+
+// In icons.cob:
+#manifest
+self as cob_material_icons.icons
+
+// In an app's COB file:
+#require
+"cob_material_icons" >= 1.2
+
+#import
+cob_material_icons.icons as icons
+```
+*/
+#[macro_export]
+macro_rules! load_cob_library {
+    ($app: ident, $source_path: expr, [$($file: expr),* $(,)?]) => {{
+        use $crate::prelude::CobLibraryAppExt;
+        $(bevy::asset::embedded_asset!($app, $source_path, $file);)*
+        $app.register_cob_library(
+            env!("CARGO_CRATE_NAME"),
+            env!("CARGO_PKG_VERSION"),
+            [$(concat!("embedded://", env!("CARGO_CRATE_NAME"), "/", $file)),*],
+        );
+    }};
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct CobLibrariesPlugin;
+
+impl Plugin for CobLibrariesPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        if !app.world().contains_resource::<CobLibraries>() {
+            app.init_resource::<CobLibraries>();
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
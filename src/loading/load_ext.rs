@@ -1,5 +1,6 @@
-use std::any::TypeId;
+use std::any::{type_name, TypeId};
 use std::collections::HashMap;
+use std::sync::Arc;
 
 use bevy::ecs::system::EntityCommands;
 use bevy::ecs::world::Command;
@@ -44,7 +45,7 @@ fn register_loadable_type<T: Loadable>(app: &mut App) -> Option<(&mut LoadableRe
         .world_mut()
         .get_resource_or_insert_with::<LoadableRegistry>(|| Default::default());
 
-    if let Some(prev) = loadables.loadables.insert(shortname, type_id) {
+    if let Some(prev) = Arc::make_mut(&mut loadables.loadables.names).insert(shortname, type_id) {
         if prev != type_id {
             tracing::warn!("overwriting command loadable registration; new type id: {:?}, old type id: {:?}",
                 type_id, prev);
@@ -75,7 +76,8 @@ fn register_command_loadable<T: Command + Loadable>(app: &mut App)
 fn register_node_loadable<T: Loadable + 'static>(
     app: &mut App,
     callback: fn(&mut World, Entity, ReflectedLoadable, SceneRef),
-    _reverter: fn(Entity, &mut World),
+    reverter: fn(Entity, &mut World),
+    serializer: Option<(TypeId, LoadableSerializer)>,
     register_type: &'static str,
 )
 {
@@ -90,12 +92,16 @@ fn register_node_loadable<T: Loadable + 'static>(
 
     entry.or_insert(callback);
 
+    // Serializer callback.
+    if let Some((component_id, serializer)) = serializer {
+        loadables.serializers.insert(component_id, serializer);
+    }
+
     // Reverter callback.
-    #[cfg(feature = "hot_reload")]
     loadables
         .revert_callbacks
         .entry(type_id)
-        .or_insert(_reverter);
+        .or_insert(reverter);
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -119,9 +125,14 @@ fn bundle_loader<T: Bundle + Loadable>(
 )
 {
     w.resource_scope(|world, registry: Mut<AppTypeRegistry>| {
-        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
-        let Some(bundle) = loadable.get_value::<T>(&scene_ref, &registry.read()) else { return };
-        emut.insert(bundle);
+        if !world.entities().contains(entity) {
+            return;
+        }
+        let Some(bundle) = loadable.get_value::<T>(&scene_ref, &registry.read()) else {
+            report_loadable_error::<T>(world, entity, scene_ref);
+            return;
+        };
+        world.entity_mut(entity).insert(bundle);
     });
 }
 
@@ -136,9 +147,14 @@ fn reactive_loader<T: ReactComponent + Loadable>(
 )
 {
     w.resource_scope(|world, registry: Mut<AppTypeRegistry>| {
-        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
-        let Some(new_val) = loadable.get_value(&scene_ref, &registry.read()) else { return };
-        match emut.get_mut::<React<T>>() {
+        if !world.entities().contains(entity) {
+            return;
+        }
+        let Some(new_val) = loadable.get_value(&scene_ref, &registry.read()) else {
+            report_loadable_error::<T>(world, entity, scene_ref);
+            return;
+        };
+        match world.get_mut::<React<T>>(entity) {
             Some(mut component) => {
                 *component.get_noreact() = new_val;
                 React::<T>::trigger_mutation(entity, world);
@@ -164,12 +180,42 @@ fn instruction_loader<T: Instruction + Loadable>(
         return;
     }
     let registry = w.resource::<AppTypeRegistry>();
-    let Some(value) = loadable.get_value::<T>(&scene_ref, &registry.read()) else { return };
+    let Some(value) = loadable.get_value::<T>(&scene_ref, &registry.read()) else {
+        report_loadable_error::<T>(w, entity, scene_ref);
+        return;
+    };
     value.apply(entity, w);
 }
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Reports a loadable that failed to load on an entity to the nearest [`SceneErrorBoundary`].
+fn report_loadable_error<T: Loadable>(w: &mut World, entity: Entity, scene_ref: SceneRef)
+{
+    let error = format!("failed loading {:?}", type_name::<T>());
+    report_scene_error(w, entity, Some(scene_ref), error);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reads the loadable component `T` from an entity.
+fn serialize_component<T: Component + Loadable>(world: &World, entity: Entity) -> Option<&dyn PartialReflect>
+{
+    world.get::<T>(entity).map(|c| c.as_partial_reflect())
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reads the loadable `React<T>` from an entity.
+fn serialize_reactive<T: ReactComponent + Loadable>(world: &World, entity: Entity) -> Option<&dyn PartialReflect>
+{
+    world
+        .get::<React<T>>(entity)
+        .map(|c| c.get().as_partial_reflect())
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 fn revert_bundle<T: Bundle>(entity: Entity, world: &mut World)
 {
     let Ok(mut emut) = world.get_entity_mut(entity) else { return };
@@ -204,16 +250,43 @@ pub(crate) fn load_queued_from_ref(
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Reads a loadable value stored on an entity.
+pub(crate) type LoadableSerializer = fn(&World, Entity) -> Option<&dyn PartialReflect>;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Snapshot of the short names of registered loadables.
+///
+/// Cheap to clone so it can be sent to COB extraction tasks.
+#[derive(Default, Clone)]
+pub(crate) struct LoadableNames
+{
+    /// [ short name : type id ]
+    names: Arc<HashMap<&'static str, TypeId>>,
+}
+
+impl LoadableNames
+{
+    pub(crate) fn get_type_id(&self, id: impl AsRef<str>) -> Option<TypeId>
+    {
+        self.names.get(id.as_ref()).copied()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 #[derive(Resource, Default)]
 pub(crate) struct LoadableRegistry
 {
-    /// [ short name : type id ]
-    loadables: HashMap<&'static str, TypeId>,
+    loadables: LoadableNames,
 
     command_callbacks: HashMap<TypeId, fn(&mut World, ReflectedLoadable, SceneRef)>,
     node_callbacks: HashMap<TypeId, fn(&mut World, Entity, ReflectedLoadable, SceneRef)>,
-    #[cfg(feature = "hot_reload")]
     revert_callbacks: HashMap<TypeId, fn(Entity, &mut World)>,
+    /// [ component type id : serializer ]
+    serializers: HashMap<TypeId, LoadableSerializer>,
+    /// [ type id : application priority ]
+    priorities: HashMap<TypeId, i32>,
 }
 
 impl LoadableRegistry
@@ -231,7 +304,6 @@ impl LoadableRegistry
         self.node_callbacks.get(&type_id).cloned()
     }
 
-    #[cfg(feature = "hot_reload")]
     pub(crate) fn get_for_revert(&self, type_id: TypeId) -> Option<fn(Entity, &mut World)>
     {
         self.revert_callbacks.get(&type_id).cloned()
@@ -239,7 +311,97 @@ impl LoadableRegistry
 
     pub(crate) fn get_type_id(&self, id: impl AsRef<str>) -> Option<TypeId>
     {
-        self.loadables.get(id.as_ref()).copied()
+        self.loadables.get_type_id(id)
+    }
+
+    /// Gets a snapshot of registered loadable names.
+    pub(crate) fn names(&self) -> LoadableNames
+    {
+        self.loadables.clone()
+    }
+
+    /// Gets the serializer for a component that stores a loadable value.
+    pub(crate) fn get_serializer(&self, component_id: TypeId) -> Option<LoadableSerializer>
+    {
+        self.serializers.get(&component_id).cloned()
+    }
+
+    /// Gets the application priority of a loadable. Defaults to `0`.
+    pub(crate) fn get_priority(&self, type_id: TypeId) -> i32
+    {
+        self.priorities.get(&type_id).copied().unwrap_or_default()
+    }
+
+    /// Returns `true` if any loadables have non-default priorities.
+    pub(crate) fn has_priorities(&self) -> bool
+    {
+        !self.priorities.is_empty()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Command that reverts a node loadable on an entity using the loadable's registered revert callback.
+///
+/// This is used both for hot reloading and for [`RevertLoadableExt::revert_loadable`], so loadables removed at
+/// runtime are cleaned up the same way as loadables removed from COB files.
+pub(crate) struct RevertLoadable
+{
+    pub(crate) entity: Entity,
+    pub(crate) type_id: TypeId,
+}
+
+impl Command for RevertLoadable
+{
+    fn apply(self, world: &mut World)
+    {
+        remove_bound_loadable(world, self.entity, self.type_id);
+        let Some(reverter) = world
+            .get_resource::<LoadableRegistry>()
+            .and_then(|r| r.get_for_revert(self.type_id))
+        else {
+            return;
+        };
+        (reverter)(self.entity, world);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Extension trait for reverting registered loadables on entities.
+pub trait RevertLoadableExt
+{
+    /// Reverts a loadable on the entity.
+    ///
+    /// This uses the same cleanup as when a loadable is removed from a scene node during hot reloading.
+    /// Components and bundles are removed (along with their required components), [`React<T>`] components are
+    /// removed, and instructions are reverted with [`Instruction::revert`].
+    ///
+    /// `T` must be registered with [`CobLoadableRegistrationAppExt`] as a component, bundle, reactive, or
+    /// instruction. Logs a warning if `T` is not registered. Does nothing if the entity doesn't exist.
+    ///
+    /// Note that a reverted loadable will be re-applied if the entity's scene node is hot reloaded.
+    fn revert_loadable<T: Loadable>(&mut self) -> &mut Self;
+}
+
+impl RevertLoadableExt for EntityCommands<'_>
+{
+    fn revert_loadable<T: Loadable>(&mut self) -> &mut Self
+    {
+        let entity = self.id();
+        let type_id = TypeId::of::<T>();
+        self.commands().queue(move |w: &mut World| {
+            let is_registered = w
+                .get_resource::<LoadableRegistry>()
+                .is_some_and(|r| r.get_for_revert(type_id).is_some());
+            if !is_registered {
+                tracing::warn!("failed reverting loadable {} on {entity:?}, the type is not registered as a node \
+                    loadable", type_name::<T>());
+                return;
+            }
+            RevertLoadable { entity, type_id }.apply(w);
+        });
+        self
     }
 }
 
@@ -291,6 +453,27 @@ pub trait CobLoadableRegistrationAppExt
     fn register_instruction_type<T: TypePath + GetTypeRegistration + Instruction + Loadable>(
         &mut self,
     ) -> &mut Self;
+
+    /// Combines [`CobLoadableRegistrationAppExt::register_instruction`] with
+    /// [`CobLoadableRegistrationAppExt::set_loadable_priority`].
+    fn register_instruction_with_priority<T: Instruction + Loadable>(&mut self, priority: i32) -> &mut Self;
+
+    /// Combines [`App::register_type`] with [`CobLoadableRegistrationAppExt::register_instruction_with_priority`].
+    fn register_instruction_type_with_priority<T: TypePath + GetTypeRegistration + Instruction + Loadable>(
+        &mut self,
+        priority: i32,
+    ) -> &mut Self;
+
+    /// Sets the priority used to order loadables of type `T` when they are applied to a scene node.
+    ///
+    /// Loadables with higher priorities are applied before loadables with lower priorities. Loadables with the
+    /// same priority are applied in the order they are written in the scene node. The default priority is `0`.
+    ///
+    /// For example, [`ControlRoot`] and [`ControlMember`] have priority [`CONTROL_LOADABLE_PRIORITY`] so they are
+    /// always applied before attribute loadables on the same node.
+    ///
+    /// Priorities don't affect loadables in `#commands` sections.
+    fn set_loadable_priority<T: Loadable>(&mut self, priority: i32) -> &mut Self;
 }
 
 impl CobLoadableRegistrationAppExt for App
@@ -308,7 +491,14 @@ impl CobLoadableRegistrationAppExt for App
 
     fn register_component<T: Component + Loadable>(&mut self) -> &mut Self
     {
-        register_node_loadable::<T>(self, bundle_loader::<T>, revert_bundle::<T>, "component");
+        let serializer = (TypeId::of::<T>(), serialize_component::<T> as LoadableSerializer);
+        register_node_loadable::<T>(
+            self,
+            bundle_loader::<T>,
+            revert_bundle::<T>,
+            Some(serializer),
+            "component",
+        );
         self
     }
 
@@ -319,7 +509,7 @@ impl CobLoadableRegistrationAppExt for App
 
     fn register_bundle<T: Bundle + Loadable>(&mut self) -> &mut Self
     {
-        register_node_loadable::<T>(self, bundle_loader::<T>, revert_bundle::<T>, "bundle");
+        register_node_loadable::<T>(self, bundle_loader::<T>, revert_bundle::<T>, None, "bundle");
         self
     }
 
@@ -330,7 +520,14 @@ impl CobLoadableRegistrationAppExt for App
 
     fn register_reactive<T: ReactComponent + Loadable>(&mut self) -> &mut Self
     {
-        register_node_loadable::<T>(self, reactive_loader::<T>, revert_reactive::<T>, "reactive");
+        let serializer = (TypeId::of::<React<T>>(), serialize_reactive::<T> as LoadableSerializer);
+        register_node_loadable::<T>(
+            self,
+            reactive_loader::<T>,
+            revert_reactive::<T>,
+            Some(serializer),
+            "reactive",
+        );
         self
     }
 
@@ -343,7 +540,7 @@ impl CobLoadableRegistrationAppExt for App
 
     fn register_instruction<T: Instruction + Loadable>(&mut self) -> &mut Self
     {
-        register_node_loadable::<T>(self, instruction_loader::<T>, T::revert, "instruction");
+        register_node_loadable::<T>(self, instruction_loader::<T>, T::revert, None, "instruction");
         self
     }
 
@@ -353,6 +550,33 @@ impl CobLoadableRegistrationAppExt for App
     {
         self.register_type::<T>().register_instruction::<T>()
     }
+
+    fn register_instruction_with_priority<T: Instruction + Loadable>(&mut self, priority: i32) -> &mut Self
+    {
+        self.register_instruction::<T>()
+            .set_loadable_priority::<T>(priority)
+    }
+
+    fn register_instruction_type_with_priority<T: TypePath + GetTypeRegistration + Instruction + Loadable>(
+        &mut self,
+        priority: i32,
+    ) -> &mut Self
+    {
+        self.register_type::<T>()
+            .register_instruction_with_priority::<T>(priority)
+    }
+
+    fn set_loadable_priority<T: Loadable>(&mut self, priority: i32) -> &mut Self
+    {
+        let mut loadables = self
+            .world_mut()
+            .get_resource_or_insert_with::<LoadableRegistry>(Default::default);
+        match priority {
+            0 => loadables.priorities.remove(&TypeId::of::<T>()),
+            _ => loadables.priorities.insert(TypeId::of::<T>(), priority),
+        };
+        self
+    }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,295 @@
+use std::collections::HashMap;
+
+use bevy::ecs::world::Command;
+use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
+use smol_str::SmolStr;
+
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Starts loading the files of a load group.
+fn load_group_files(
+    In(name): In<SmolStr>,
+    mut groups: ResMut<LoadGroups>,
+    mut files: ResMut<LoadedCobAssetFiles>,
+    mut cob_cache: ResMut<CobAssetCache>,
+    commands_buffer: Option<ResMut<CommandsBuffer>>,
+    asset_server: Res<AssetServer>,
+)
+{
+    let Some(group) = groups.groups.get_mut(&name) else {
+        tracing::warn!("failed loading load group {name:?}; the group was not registered with \
+            LoadedCobAssetFilesAppExt::load_group");
+        return;
+    };
+    if group.state != LoadGroupState::Unloaded {
+        return;
+    }
+    tracing::info!("loading load group {name:?}");
+    group.state = LoadGroupState::Loading;
+
+    // Files that are already registered (e.g. by another group or a manifest) don't need to load again.
+    let new_files: Vec<CobFile> = group
+        .files
+        .iter()
+        .filter(|f| !cob_cache.is_file_registered(f))
+        .cloned()
+        .collect();
+    if new_files.is_empty() {
+        return;
+    }
+
+    // If the app hasn't started loading files yet, the group files are loaded with the other app files.
+    if !files.is_initialized() {
+        for file in new_files {
+            files.add_preset(file);
+        }
+        return;
+    }
+
+    let Some(mut commands_buffer) = commands_buffer else {
+        tracing::error!("failed loading load group {name:?}; the commands buffer is missing (this is a bug)");
+        return;
+    };
+    for file in new_files.iter().cloned() {
+        files.start_loading(file, &mut cob_cache, &asset_server);
+    }
+    commands_buffer.add_root_files(new_files);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Updates the state of load groups that are loading.
+fn update_load_groups(mut groups: ResMut<LoadGroups>, cob_cache: Res<CobAssetCache>)
+{
+    // Files loaded via manifests of group files are tracked as pending files in the cache.
+    let cache_is_busy = cob_cache.num_pending_files() > 0 || cob_cache.num_preprocessed_pending() > 0;
+
+    for (name, group) in groups.groups.iter_mut() {
+        if group.state != LoadGroupState::Loading {
+            continue;
+        }
+
+        group.pending = group
+            .files
+            .iter()
+            .filter(|f| cob_cache.is_file_loading(f))
+            .count();
+        if group.pending > 0 || cache_is_busy {
+            continue;
+        }
+
+        tracing::info!("done loading load group {name:?}");
+        group.state = LoadGroupState::Loaded;
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Run condition that returns `true` when a [load group](LoadedCobAssetFilesAppExt::load_group) is loaded.
+pub fn load_group_loaded(name: impl Into<SmolStr>) -> impl Fn(Res<LoadGroups>) -> bool + Clone
+{
+    let name = name.into();
+    move |groups: Res<LoadGroups>| groups.state(&name) == Some(LoadGroupState::Loaded)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// The loading state of a [load group](LoadedCobAssetFilesAppExt::load_group).
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum LoadGroupState
+{
+    /// The group has not been requested.
+    #[default]
+    Unloaded,
+    /// The group's files are loading.
+    Loading,
+    /// All of the group's files, and files in their manifests, are loaded.
+    Loaded,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct LoadGroup
+{
+    files: Vec<CobFile>,
+    state: LoadGroupState,
+    /// Number of group files that are still loading.
+    pending: usize,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource that tracks [load groups](LoadedCobAssetFilesAppExt::load_group).
+#[derive(Resource, Default, Debug)]
+pub struct LoadGroups
+{
+    groups: HashMap<SmolStr, LoadGroup>,
+}
+
+impl LoadGroups
+{
+    pub(crate) fn add_group(&mut self, name: SmolStr, files: Vec<CobFile>)
+    {
+        tracing::info!("registered load group {name:?} with files {files:?}");
+        let group = LoadGroup { pending: files.len(), files, state: LoadGroupState::Unloaded };
+        if self.groups.insert(name.clone(), group).is_some() {
+            tracing::warn!("replaced load group {name:?}");
+        }
+    }
+
+    /// Returns `true` if no load groups are registered.
+    pub fn is_empty(&self) -> bool
+    {
+        self.groups.is_empty()
+    }
+
+    /// Returns `true` if any load group is loading.
+    pub fn any_loading(&self) -> bool
+    {
+        self.groups
+            .values()
+            .any(|g| g.state == LoadGroupState::Loading)
+    }
+
+    /// Gets the state of a load group.
+    ///
+    /// Returns `None` if the group is not registered.
+    pub fn state(&self, name: &str) -> Option<LoadGroupState>
+    {
+        self.groups.get(name).map(|g| g.state)
+    }
+
+    /// Gets the loading progress of a load group.
+    ///
+    /// Returns `(num pending files, num total files)`. Files recursively loaded via manifests are not counted.
+    ///
+    /// Returns `None` if the group is not registered.
+    pub fn progress(&self, name: &str) -> Option<(usize, usize)>
+    {
+        self.groups.get(name).map(|g| {
+            let pending = match g.state {
+                LoadGroupState::Loaded => 0,
+                _ => g.pending,
+            };
+            (pending, g.files.len())
+        })
+    }
+
+    /// Iterates names of registered load groups.
+    pub fn iter_names(&self) -> impl Iterator<Item = &str> + '_
+    {
+        self.groups.keys().map(|k| k.as_str())
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Command for loading a [load group](LoadedCobAssetFilesAppExt::load_group).
+///
+/// Does nothing if the group is loading or loaded.
+#[derive(Debug, Clone)]
+pub struct LoadCobGroup(pub SmolStr);
+
+impl Command for LoadCobGroup
+{
+    fn apply(self, world: &mut World)
+    {
+        world.syscall(self.0, load_group_files);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Command for unloading a [load group](LoadedCobAssetFilesAppExt::load_group).
+///
+/// The group is marked [`LoadGroupState::Unloaded`], and the group's files are unloaded with [`UnloadCob`]
+/// unless they are also in another group that isn't unloaded. Scenes spawned from the files are not despawned.
+#[derive(Debug, Clone)]
+pub struct UnloadCobGroup(pub SmolStr);
+
+impl Command for UnloadCobGroup
+{
+    fn apply(self, world: &mut World)
+    {
+        let mut groups = world.resource_mut::<LoadGroups>();
+        let Some(group) = groups.groups.get_mut(&self.0) else {
+            tracing::warn!("failed unloading load group {:?}; the group was not registered with \
+                LoadedCobAssetFilesAppExt::load_group", self.0);
+            return;
+        };
+        if group.state == LoadGroupState::Unloaded {
+            return;
+        }
+        tracing::info!("unloading load group {:?}", self.0);
+        group.state = LoadGroupState::Unloaded;
+        group.pending = group.files.len();
+
+        // Unload files that aren't used by other groups.
+        let groups = world.resource::<LoadGroups>();
+        let files: Vec<CobFile> = groups.groups[&self.0]
+            .files
+            .iter()
+            .filter(|file| {
+                !groups
+                    .groups
+                    .values()
+                    .any(|g| g.state != LoadGroupState::Unloaded && g.files.contains(file))
+            })
+            .cloned()
+            .collect();
+        for file in files {
+            world.syscall((file, false), unload_cob_file);
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Extension trait for loading and unloading [load groups](LoadedCobAssetFilesAppExt::load_group).
+pub trait LoadGroupCommandsExt
+{
+    /// Queues [`LoadCobGroup`].
+    fn load_group(&mut self, name: impl Into<SmolStr>);
+    /// Queues [`UnloadCobGroup`].
+    fn unload_group(&mut self, name: impl Into<SmolStr>);
+}
+
+impl LoadGroupCommandsExt for Commands<'_, '_>
+{
+    fn load_group(&mut self, name: impl Into<SmolStr>)
+    {
+        self.queue(LoadCobGroup(name.into()));
+    }
+
+    fn unload_group(&mut self, name: impl Into<SmolStr>)
+    {
+        self.queue(UnloadCobGroup(name.into()));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct LoadGroupsPlugin;
+
+impl Plugin for LoadGroupsPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        if !app.world().contains_resource::<LoadGroups>() {
+            app.init_resource::<LoadGroups>();
+        }
+
+        app.add_systems(
+            First,
+            update_load_groups
+                .after(FileProcessingSet)
+                .run_if(|g: Res<LoadGroups>| g.any_loading()),
+        );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -1,3 +1,6 @@
+use std::any::{type_name, TypeId};
+
+use bevy::asset::{LoadState as AssetLoadState, UntypedAssetId};
 use bevy::prelude::*;
 use bevy_cobweb::prelude::*;
 
@@ -10,22 +13,35 @@ fn clear_asset_progress(mut progress: ResMut<LoadProgress>)
 
 //-------------------------------------------------------------------------------------------------------------------
 
-fn get_asset_progress<T: AssetLoadProgress + Resource>(world: &mut World) -> (usize, usize)
+fn get_asset_progress<T: AssetLoadProgress + Resource>(world: &mut World) -> AssetTrackerCounts
 {
     let Some(res) = world.get_resource::<T>() else {
-        return (0, 0);
+        return AssetTrackerCounts::default();
     };
-    (res.pending_assets(), res.total_assets())
+    AssetTrackerCounts::from_tracker(res)
 }
 
 //-------------------------------------------------------------------------------------------------------------------
 
-fn get_asset_progress_reactive<T: AssetLoadProgress + ReactResource>(world: &mut World) -> (usize, usize)
+fn get_asset_progress_reactive<T: AssetLoadProgress + ReactResource>(world: &mut World) -> AssetTrackerCounts
 {
     let Some(res) = world.get_react_resource::<T>() else {
-        return (0, 0);
+        return AssetTrackerCounts::default();
     };
-    (res.pending_assets(), res.total_assets())
+    AssetTrackerCounts::from_tracker(res)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Returns `true` if an individually-tracked asset is done loading.
+///
+/// Assets that failed to load are considered done so they don't block [`LoadState::Done`].
+fn is_tracked_asset_done(asset_server: &AssetServer, id: UntypedAssetId) -> bool
+{
+    if asset_server.is_loaded_with_dependencies(id) {
+        return true;
+    }
+    matches!(asset_server.get_load_state(id), Some(AssetLoadState::Failed(_)) | None)
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -33,12 +49,29 @@ fn get_asset_progress_reactive<T: AssetLoadProgress + ReactResource>(world: &mut
 fn collect_asset_progress(world: &mut World)
 {
     world.resource_scope(|world, mut progress: Mut<LoadProgress>| {
-        let tracked = std::mem::take(&mut progress.tracked);
-        for tracked_fn in tracked.iter() {
-            let (pending, total) = (tracked_fn)(world);
-            progress.insert(pending, total);
+        let mut trackers = std::mem::take(&mut progress.trackers);
+        for tracker in trackers.iter_mut() {
+            let counts = (tracker.collect)(world);
+            tracker.progress.pending = counts.pending;
+            tracker.progress.total = counts.total;
+            progress.insert(counts, tracker.progress.weight);
+        }
+        progress.trackers = trackers;
+
+        let Some(asset_server) = world.get_resource::<AssetServer>() else { return };
+        let mut assets = std::mem::take(&mut progress.assets);
+        for asset in assets.iter_mut() {
+            asset.done = asset.done || is_tracked_asset_done(asset_server, asset.handle.id());
+            let pending = (!asset.done) as usize;
+            let counts = AssetTrackerCounts {
+                pending,
+                total: 1,
+                weighted_pending: pending as f32,
+                weighted_total: 1.,
+            };
+            progress.insert(counts, asset.weight);
         }
-        progress.tracked = tracked;
+        progress.assets = assets;
     });
 }
 
@@ -58,35 +91,212 @@ fn check_load_progress(progress: Res<LoadProgress>, mut next: ResMut<NextState<L
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Load progress reported by an asset tracker.
+///
+/// See [`LoadProgress::tracker`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct AssetTrackerProgress
+{
+    /// Type name of the tracker resource.
+    pub name: &'static str,
+    /// Number of assets currently loading.
+    pub pending: usize,
+    /// Approximate total number of assets loaded and loading.
+    pub total: usize,
+    /// Weight of the tracker's assets in [`LoadProgress::fraction`].
+    ///
+    /// Multiplies [`AssetLoadProgress::weighted_pending_assets`] and
+    /// [`AssetLoadProgress::weighted_total_assets`].
+    pub weight: f32,
+}
+
+impl AssetTrackerProgress
+{
+    /// Gets the fraction of the tracker's assets that are loaded, from `0.0` to `1.0`.
+    ///
+    /// Returns `1.0` if the tracker has no assets.
+    pub fn fraction(&self) -> f32
+    {
+        if self.total == 0 {
+            return 1.;
+        }
+        1. - (self.pending as f32 / self.total as f32)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Default)]
+struct AssetTrackerCounts
+{
+    pending: usize,
+    total: usize,
+    weighted_pending: f32,
+    weighted_total: f32,
+}
+
+impl AssetTrackerCounts
+{
+    fn from_tracker(tracker: &impl AssetLoadProgress) -> Self
+    {
+        Self {
+            pending: tracker.pending_assets(),
+            total: tracker.total_assets(),
+            weighted_pending: tracker.weighted_pending_assets(),
+            weighted_total: tracker.weighted_total_assets(),
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+struct AssetTracker
+{
+    type_id: TypeId,
+    collect: fn(&mut World) -> AssetTrackerCounts,
+    progress: AssetTrackerProgress,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+struct TrackedAsset
+{
+    handle: UntypedHandle,
+    weight: f32,
+    done: bool,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Tracks the global loading progress of asset trackers.
 ///
 /// Cleared in [`LoadProgressSet::Prepare`], updated in [`LoadProgressSet::Collect`], evaluated in
 /// [`LoadProgressSet::Check`].
+///
+/// Each asset tracker has a weight (default `1.0`) that is applied to its assets when computing the weighted
+/// progress. Individual assets can also be tracked with [`Self::track_asset`], for example to include large
+/// textures or audio files in a loading bar. Assets listed in COB manifests can be weighted with
+/// `asset "path" weight 5`.
+///
+/// Example loading bar:
+/**
+```rust
+fn update_loading_bar(progress: Res<LoadProgress>, mut bar: Query<&mut Node, With<LoadingBar>>)
+{
+    let Ok(mut node) = bar.get_single_mut() else { return };
+    node.width = Val::Percent(progress.fraction() * 100.);
+}
+```
+*/
 #[derive(Resource, Default)]
 pub struct LoadProgress
 {
     pending: usize,
     total: usize,
+    weighted_pending: f32,
+    weighted_total: f32,
 
-    tracked: Vec<fn(&mut World) -> (usize, usize)>,
+    trackers: Vec<AssetTracker>,
+    assets: Vec<TrackedAsset>,
 }
 
 impl LoadProgress
 {
-    fn insert(&mut self, pending: usize, total: usize)
+    fn insert(&mut self, counts: AssetTrackerCounts, weight: f32)
     {
-        self.pending += pending;
-        self.total += total;
+        self.pending += counts.pending;
+        self.total += counts.total;
+        self.weighted_pending += counts.weighted_pending * weight;
+        self.weighted_total += counts.weighted_total * weight;
     }
 
     fn clear(&mut self)
     {
         self.pending = 0;
         self.total = 0;
+        self.weighted_pending = 0.;
+        self.weighted_total = 0.;
+    }
+
+    fn add_tracker<T: 'static>(&mut self, collect: fn(&mut World) -> AssetTrackerCounts)
+    {
+        let type_id = TypeId::of::<T>();
+        if self.trackers.iter().any(|t| t.type_id == type_id) {
+            tracing::warn!("ignoring duplicate asset tracker registration for {}", type_name::<T>());
+            return;
+        }
+        self.trackers.push(AssetTracker {
+            type_id,
+            collect,
+            progress: AssetTrackerProgress { name: type_name::<T>(), pending: 0, total: 0, weight: 1. },
+        });
+    }
+
+    /// Sets the weight of tracker `T`'s assets.
+    ///
+    /// Does nothing if `T` is not a registered asset tracker.
+    pub fn set_tracker_weight<T: AssetLoadProgress + 'static>(&mut self, weight: f32)
+    {
+        let type_id = TypeId::of::<T>();
+        let Some(tracker) = self.trackers.iter_mut().find(|t| t.type_id == type_id) else {
+            tracing::warn!("failed setting asset tracker weight for {}; tracker is not registered",
+                type_name::<T>());
+            return;
+        };
+        tracker.progress.weight = weight.max(0.);
+    }
+
+    /// Adds an asset to load progress tracking.
+    ///
+    /// [`LoadState::Done`] will be postponed until the asset and its dependencies are loaded (or fail to load).
+    /// The asset is counted with `weight` in [`Self::fraction`].
+    pub fn track_asset(&mut self, handle: impl Into<UntypedHandle>, weight: f32)
+    {
+        self.assets.push(TrackedAsset { handle: handle.into(), weight: weight.max(0.), done: false });
+    }
+
+    /// Gets the progress of asset tracker `T`.
+    ///
+    /// Returns `None` if `T` is not a registered asset tracker.
+    pub fn tracker<T: AssetLoadProgress + 'static>(&self) -> Option<AssetTrackerProgress>
+    {
+        let type_id = TypeId::of::<T>();
+        self.trackers
+            .iter()
+            .find(|t| t.type_id == type_id)
+            .map(|t| t.progress)
+    }
+
+    /// Iterates the progress of all registered asset trackers.
+    pub fn iter_trackers(&self) -> impl Iterator<Item = &AssetTrackerProgress> + '_
+    {
+        self.trackers.iter().map(|t| &t.progress)
+    }
+
+    /// Returns (weighted pending assets, weighted total assets).
+    pub fn weighted_progress(&self) -> (f32, f32)
+    {
+        (self.weighted_pending, self.weighted_total)
+    }
+
+    /// Gets the weighted fraction of assets that are loaded, from `0.0` to `1.0`.
+    ///
+    /// Returns `1.0` if there are no assets. Note that the fraction can decrease when new assets start loading.
+    pub fn fraction(&self) -> f32
+    {
+        if self.weighted_total <= 0. {
+            return match self.pending > 0 {
+                true => 0.,
+                false => 1.,
+            };
+        }
+        (1. - (self.weighted_pending / self.weighted_total)).clamp(0., 1.)
     }
 
     /// Returns (num pending assets, num total assets).
     ///
+    /// Includes assets tracked with [`Self::track_asset`].
+    ///
     /// The total number of assets should be considered an approximation, since for efficiency asset managers may
     /// double-count some assets.
     ///
@@ -120,6 +330,23 @@ pub trait AssetLoadProgress
     /// This value does not need to be exact, since for efficiency some asset managers may need to double-count
     /// assets.
     fn total_assets(&self) -> usize;
+
+    /// Gets the weighted number of assets currently loading.
+    ///
+    /// Override this and [`Self::weighted_total_assets`] if assets have different costs (e.g. file sizes). Defaults
+    /// to [`Self::pending_assets`].
+    fn weighted_pending_assets(&self) -> f32
+    {
+        self.pending_assets() as f32
+    }
+
+    /// Gets the weighted total number of assets loaded and loading.
+    ///
+    /// Defaults to [`Self::total_assets`].
+    fn weighted_total_assets(&self) -> f32
+    {
+        self.total_assets() as f32
+    }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -132,6 +359,11 @@ pub trait AssetLoadProgressAppExt
 
     /// Registers a reactive resource that reports asset load progress in [`LoadProgressSet::Collect`].
     fn register_reactive_asset_tracker<T: AssetLoadProgress + ReactResource>(&mut self) -> &mut Self;
+
+    /// Sets the weight of each asset of a registered asset tracker.
+    ///
+    /// See [`LoadProgress::set_tracker_weight`].
+    fn set_asset_tracker_weight<T: AssetLoadProgress + 'static>(&mut self, weight: f32) -> &mut Self;
 }
 
 impl AssetLoadProgressAppExt for App
@@ -140,8 +372,7 @@ impl AssetLoadProgressAppExt for App
     {
         self.world_mut()
             .resource_mut::<LoadProgress>()
-            .tracked
-            .push(get_asset_progress::<T>);
+            .add_tracker::<T>(get_asset_progress::<T>);
         self
     }
 
@@ -149,8 +380,15 @@ impl AssetLoadProgressAppExt for App
     {
         self.world_mut()
             .resource_mut::<LoadProgress>()
-            .tracked
-            .push(get_asset_progress_reactive::<T>);
+            .add_tracker::<T>(get_asset_progress_reactive::<T>);
+        self
+    }
+
+    fn set_asset_tracker_weight<T: AssetLoadProgress + 'static>(&mut self, weight: f32) -> &mut Self
+    {
+        self.world_mut()
+            .resource_mut::<LoadProgress>()
+            .set_tracker_weight::<T>(weight);
         self
     }
 }
@@ -169,6 +407,10 @@ pub enum LoadState
     Loading,
     /// Loading is done.
     Done,
+    /// Loading failed in [strict mode](CobStrictMode).
+    ///
+    /// Errors are recorded in [`CobDiagnostics`].
+    Failed,
 }
 
 //-------------------------------------------------------------------------------------------------------------------
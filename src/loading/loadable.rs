@@ -31,6 +31,11 @@ pub trait Instruction: Loadable
     ///
     /// This should clean up as many of the instruction's side effects as possible.
     ///
+    /// Reverts are used when an instruction is removed from a scene node during hot reloading, before a changed
+    /// instruction value is re-applied during hot reloading, and when reverting manually with
+    /// [`InstructionExt::revert`] or [`RevertLoadableExt::revert_loadable`](crate::prelude::RevertLoadableExt).
+    /// A revert may run on entities where the instruction was never applied, and may run more than once.
+    ///
     /// Assume the entity might not exist. This should not panic unless necessary.
     fn revert(entity: Entity, world: &mut World);
 }
@@ -7,23 +7,39 @@ mod app_load_ext;
 mod cache;
 pub mod cob;
 mod cob_asset_loader;
+mod cob_bindings;
+mod diagnostics;
 mod extract;
+mod libraries;
 mod load_ext;
+mod load_groups;
 mod load_progress;
 mod loadable;
 mod plugin;
 mod references;
 mod scene;
+mod theme;
+mod unload;
+mod validation;
 
 pub use app_load_ext::*;
 pub use cache::*;
 pub use cob::Cob;
+#[cfg(feature = "precompiled_cob")]
+pub use cob::precompile_cob_files;
 pub(crate) use cob::*;
 pub(crate) use cob_asset_loader::*;
+pub use cob_bindings::*;
+pub use diagnostics::*;
 pub(crate) use extract::*;
+pub use libraries::*;
 pub use load_ext::*;
+pub use load_groups::*;
 pub use load_progress::*;
 pub use loadable::*;
 pub(crate) use plugin::*;
 pub use references::*;
 pub use scene::*;
+pub use theme::*;
+pub use unload::*;
+pub use validation::*;
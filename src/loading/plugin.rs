@@ -15,7 +15,15 @@ impl Plugin for LoadingPlugin
             .add_plugins(CobAssetLoaderPlugin)
             .add_plugins(AppLoadExtPlugin)
             .add_plugins(CobAssetCachePlugin)
+            .add_plugins(CobBindingsPlugin)
+            .add_plugins(CobDiagnosticsPlugin)
+            .add_plugins(CobLibrariesPlugin)
+            .add_plugins(LoadGroupsPlugin)
             .add_plugins(SceneBuilderPlugin) // Must be after the COB cache plugin.
+            .add_plugins(SceneErrorBoundaryPlugin)
+            .add_plugins(SceneRefValidationPlugin)
+            .add_plugins(SceneSpawnQueuePlugin)
+            .add_plugins(ThemePlugin)
             ;
     }
 }
@@ -1,7 +1,15 @@
 mod scene_builder;
+mod scene_error_boundary;
 mod scene_handle_error;
+mod scene_pool;
+mod scene_ref_validation;
+mod scene_spawn_queue;
 mod spawn_scene_ext;
 
 pub use scene_builder::*;
+pub use scene_error_boundary::*;
 pub use scene_handle_error::*;
+pub use scene_pool::*;
+pub use scene_ref_validation::*;
+pub use scene_spawn_queue::*;
 pub use spawn_scene_ext::*;
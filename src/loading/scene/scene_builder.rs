@@ -3,10 +3,12 @@ use std::sync::{Arc, Mutex, MutexGuard};
 
 use bevy::ecs::system::{EntityCommands, SystemParam};
 use bevy::prelude::*;
+use bevy::reflect::TypeRegistry;
 #[cfg(feature = "hot_reload")]
 use bevy_cobweb::prelude::*;
 #[cfg(feature = "hot_reload")]
 use smallvec::SmallVec;
+use smol_str::SmolStr;
 
 use crate::prelude::*;
 
@@ -216,11 +218,26 @@ impl SceneRegistry
     {
         self.scenes.get(scene_ref)
     }
+
+    /// Iterates root scene references in a file.
+    pub(crate) fn iter_file<'a>(&'a self, file: &'a CobFile) -> impl Iterator<Item = &'a SceneRef> + 'a
+    {
+        self.scenes
+            .keys()
+            .filter(move |scene_ref| scene_ref.file.file() == Some(file) && scene_ref.path.len() == 1)
+    }
+
+    /// Removes all scenes in a file.
+    pub(crate) fn remove_file(&mut self, file: &CobFile)
+    {
+        self.scenes
+            .retain(|scene_ref, _| scene_ref.file != SceneFile::File(file.clone()));
+    }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) struct SceneInstance
 {
     /// Reference to the scene root.
@@ -231,6 +248,8 @@ pub(crate) struct SceneInstance
     new_node_prep_fn: NodeInitializer,
     // [ scene node path : scene node entity ]
     nodes: HashMap<ScenePath, Entity>,
+    /// Set if the instance should be returned to its scene pool when it is released.
+    return_to_pool: bool,
 }
 
 impl SceneInstance
@@ -242,6 +261,7 @@ impl SceneInstance
             entity: Entity::PLACEHOLDER,
             new_node_prep_fn: NodeInitializer { initializer: |_| {} },
             nodes: HashMap::default(),
+            return_to_pool: false,
         }
     }
 
@@ -259,6 +279,14 @@ impl SceneInstance
         self.new_node_prep_fn = NodeInitializer { initializer: new_node_prep_fn };
         self.nodes.clear();
         self.nodes.reserve(node_count);
+        self.return_to_pool = false;
+    }
+
+    /// Prepares a pooled instance to be reused.
+    fn prepare_reuse(&mut self, new_node_prep_fn: fn(&mut EntityCommands))
+    {
+        self.new_node_prep_fn = NodeInitializer { initializer: new_node_prep_fn };
+        self.return_to_pool = false;
     }
 
     /// Gets the current capacity of the inner map.
@@ -333,6 +361,10 @@ pub struct SceneBuilderInner
     scene_registry: Option<SceneRegistry>,
     /// Entity vector cached for reuse when constructing scene instances.
     scene_parent_stack_cached: Vec<Entity>,
+    /// Scene instances that were despawned to a pool so they can be reused.
+    scene_pools: ScenePools,
+    /// Set if scene spawns should be profiled.
+    profiling: bool,
     /// Tracks scene instances that exist in the world (hierarchies of entities).
     ///
     /// Used to update scene structures (add/remove/rearrange entities) in response to hot reloaded changes.
@@ -353,12 +385,119 @@ impl SceneBuilderInner
         self.manifest_map.lock().unwrap()
     }
 
+    /// Sets whether scene spawns should be profiled.
+    pub(crate) fn set_profiling(&mut self, profiling: bool)
+    {
+        self.profiling = profiling;
+    }
+
+    /// Returns `true` if scene spawns should be profiled.
+    pub(crate) fn is_profiling(&self) -> bool
+    {
+        self.profiling
+    }
+
+    /// Replaces the manifest key in a scene reference with its file.
+    pub(crate) fn resolve_scene_ref(&self, mut scene_ref: SceneRef) -> SceneRef
+    {
+        self.manifest_map().swap_for_file(&mut scene_ref.file);
+        scene_ref
+    }
+
     /// Extracts the scene registry so it can be updated.
     pub(crate) fn take_scene_registry(&mut self) -> SceneRegistry
     {
         self.scene_registry.take().unwrap_or_default()
     }
 
+    /// Checks if a scene can be spawned from a scene reference.
+    ///
+    /// Returns an error message if the reference is not a root scene node or the scene doesn't exist in any
+    /// loaded file.
+    pub(crate) fn check_scene(&self, scene_ref: &SceneRef) -> Result<(), String>
+    {
+        if scene_ref.path.len() != 1 {
+            return Err(format!(
+                "scene path has length {} but only root scene nodes (path length 1) can be spawned",
+                scene_ref.path.len()
+            ));
+        }
+
+        let mut scene_ref = scene_ref.clone();
+        if let SceneFile::ManifestKey(key) = &scene_ref.file {
+            let Some(file) = self.manifest_map().get(key) else {
+                return Err(format!("no file with manifest key {:?} has loaded", &*key.0));
+            };
+            scene_ref.file = SceneFile::File(file);
+        }
+        match self.scene_registry.as_ref().and_then(|r| r.get(&scene_ref)) {
+            Some(_) => Ok(()),
+            None => Err("there is no scene at that location or the scene's file has not loaded".into()),
+        }
+    }
+
+    /// Gets the root scene references in a file, sorted by scene name.
+    ///
+    /// Returns `None` if the file is a manifest key that doesn't map to a loaded file.
+    pub(crate) fn file_scenes(&self, file: &SceneFile) -> Option<Vec<SceneRef>>
+    {
+        let file = match file {
+            SceneFile::File(file) => file.clone(),
+            SceneFile::ManifestKey(key) => self.manifest_map().get(key)?,
+        };
+        let mut scenes: Vec<SceneRef> = self
+            .scene_registry
+            .iter()
+            .flat_map(|r| r.iter_file(&file))
+            .cloned()
+            .collect();
+        scenes.sort_unstable_by(|a, b| a.path.iter().cmp(b.path.iter()));
+        Some(scenes)
+    }
+
+    /// Sets the maximum number of despawned instances of a scene that will be kept for reuse.
+    ///
+    /// Scenes can only be returned to a pool if their capacity is non-zero (the default is zero). Lowering the
+    /// capacity does not remove instances that are already pooled.
+    ///
+    /// See [`SceneHandle::despawn_to_pool`].
+    pub fn set_pool_capacity(&mut self, scene: impl Into<SceneRef>, capacity: usize)
+    {
+        let mut scene_ref = scene.into();
+        self.manifest_map().swap_for_file(&mut scene_ref.file);
+        self.scene_pools.set_capacity(scene_ref, capacity);
+    }
+
+    /// Gets the pool capacity of a scene.
+    pub fn pool_capacity(&self, scene: impl Into<SceneRef>) -> usize
+    {
+        let mut scene_ref = scene.into();
+        self.manifest_map().swap_for_file(&mut scene_ref.file);
+        self.scene_pools.capacity(&scene_ref)
+    }
+
+    /// Gets the number of pooled instances of a scene that are waiting to be reused.
+    pub fn num_pooled(&self, scene: impl Into<SceneRef>) -> usize
+    {
+        let mut scene_ref = scene.into();
+        self.manifest_map().swap_for_file(&mut scene_ref.file);
+        self.scene_pools.num_pooled(&scene_ref)
+    }
+
+    /// Adds a scene instance to its pool.
+    ///
+    /// Returns the instance if the pool is full.
+    pub(crate) fn insert_pooled_scene(&mut self, instance: SceneInstance) -> Result<(), SceneInstance>
+    {
+        self.scene_pools.insert(instance)
+    }
+
+    /// Saves a scene instance for memory reuse.
+    pub(crate) fn recycle_scene_instance(&mut self, instance: SceneInstance)
+    {
+        self.scene_instance_cache.push(instance);
+    }
+
     /// Returns the scene registry after it has been updated.
     pub(crate) fn return_scene_registry(&mut self, registry: SceneRegistry)
     {
@@ -548,6 +687,32 @@ impl SceneBuilderInner
         }
     }
 
+    /// Removes the structures of all scenes in a file, and stops tracking scene instances spawned from the file.
+    ///
+    /// Scene instances are not despawned.
+    pub(crate) fn evict_file(&mut self, file: &CobFile)
+    {
+        if let Some(registry) = self.scene_registry.as_mut() {
+            registry.remove_file(file);
+        }
+        let pooled = self.scene_pools.remove_file(file);
+        self.scene_instance_cache.extend(pooled);
+
+        #[cfg(feature = "hot_reload")]
+        {
+            let scene_file = SceneFile::File(file.clone());
+            let mut evicted = vec![];
+            self.scene_instances.retain(|scene_ref, instances| {
+                if scene_ref.file != scene_file {
+                    return true;
+                }
+                evicted.extend(instances.drain(..));
+                false
+            });
+            self.scene_instance_cache.extend(evicted);
+        }
+    }
+
     /// Cleans up despawned root entities.
     #[cfg(feature = "hot_reload")]
     pub(crate) fn cleanup_dead_entity(&mut self, scene_ref: &SceneRef, dead_entity: Entity)
@@ -627,6 +792,7 @@ impl SceneBuilderInner
 
         // Load the root entity.
         let mut root_ec = c.entity(root_entity);
+        root_ec.insert(TrackedSceneInstance(scene_ref.clone()));
         root_ec.build_with_initializer(scene_ref.clone(), T::initialize_scene_node);
 
         // Spawn hierarchy, loading all child paths.
@@ -678,6 +844,83 @@ impl SceneBuilderInner
         true
     }
 
+    /// Reuses a pooled instance of a scene, which will become the active scene.
+    ///
+    /// The instance's root entity is moved to `parent` and its scene nodes are rebuilt. Returns `None` if no
+    /// instance of the scene is pooled.
+    pub(crate) fn reuse_pooled_scene<T>(
+        &mut self,
+        c: &mut Commands,
+        parent: Option<Entity>,
+        mut scene_ref: SceneRef,
+    ) -> Option<Entity>
+    where
+        T: crate::loading::scene::spawn_scene_ext::scene_traits::SceneNodeBuilder,
+    {
+        if scene_ref.path.len() != 1 {
+            return None;
+        }
+        self.manifest_map().swap_for_file(&mut scene_ref.file);
+
+        // Find a pooled instance that wasn't despawned while in the pool.
+        let mut scene_instance = loop {
+            let instance = self.scene_pools.take(&scene_ref)?;
+            if c.get_entity(instance.root_entity()).is_some() {
+                break instance;
+            }
+            self.scene_instance_cache.push(instance);
+        };
+        let root_entity = scene_instance.root_entity();
+
+        // With hot reloading, the tracked instance may have been updated while the instance was pooled.
+        #[cfg(feature = "hot_reload")]
+        {
+            if let Some(instances) = self.scene_instances.get_mut(&scene_ref) {
+                if let Some(idx) = instances.iter().position(|i| i.root_entity() == root_entity) {
+                    let tracked = instances.swap_remove(idx);
+                    self.scene_instance_cache
+                        .push(std::mem::replace(&mut scene_instance, tracked));
+                }
+            }
+        }
+
+        let Some(root_scene_layer) = self.scene_registry.as_ref().and_then(|r| r.get(&scene_ref)) else {
+            // The scene's file was unloaded.
+            c.entity(root_entity).despawn_recursive();
+            self.scene_instance_cache.push(scene_instance);
+            return None;
+        };
+        scene_instance.prepare_reuse(T::initialize_scene_node);
+
+        // Take the root entity out of the pool.
+        let mut root_ec = c.entity(root_entity);
+        if let Some(parent) = parent {
+            root_ec.set_parent(parent);
+        }
+        root_ec.queue(restore_pooled_scene);
+
+        // Rebuild scene nodes in the same order they were built.
+        rebuild_scene_node(c, root_entity, scene_ref.clone(), T::initialize_scene_node);
+        root_scene_layer.traverse(&mut |scene_node_path| {
+            let Some(node_entity) = scene_instance.get(scene_node_path) else { return };
+            let node_ref = SceneRef { file: scene_ref.file.clone(), path: scene_node_path.clone() };
+            rebuild_scene_node(c, node_entity, node_ref, T::initialize_scene_node);
+        });
+
+        self.active_scene_stack.push(scene_instance);
+        Some(root_entity)
+    }
+
+    /// Marks the active scene to be returned to its pool when it is released.
+    pub(crate) fn return_active_scene_to_pool(&mut self)
+    {
+        let Some(active) = self.active_scene_stack.last_mut() else {
+            tracing::error!("failed returning active scene to pool, no scene is active (this is a bug)");
+            return;
+        };
+        active.return_to_pool = true;
+    }
+
     /// Gets the current active scene (the topmost entry in the active scene stack).
     pub(crate) fn active_scene(&self) -> Option<&SceneInstance>
     {
@@ -688,7 +931,7 @@ impl SceneBuilderInner
     ///
     /// When `hot_reload` is not enabled, the scene hierarchy cache will be discarded here. We assume the scene
     /// hierarchy only needs to be accessed during construction and for hot reloading nodes.
-    pub(crate) fn release_active_scene(&mut self)
+    pub(crate) fn release_active_scene(&mut self, c: &mut Commands)
     {
         // Remove scene stack.
         let Some(released) = self.active_scene_stack.pop() else {
@@ -696,6 +939,17 @@ impl SceneBuilderInner
             return;
         };
 
+        // Save poolable instances on their root entities so they can be returned to their pools later.
+        let root_entity = released.root_entity();
+        if self.scene_pools.capacity(released.scene_ref()) > 0 {
+            if let Some(mut root_ec) = c.get_entity(root_entity) {
+                root_ec.insert(PoolableScene(released.clone()));
+            }
+        }
+        if released.return_to_pool {
+            c.queue(ReturnSceneToPool(root_entity));
+        }
+
         // On hot reload, save so scene entities can be adjusted when the scene file changes.
         #[cfg(feature = "hot_reload")]
         {
@@ -715,6 +969,70 @@ impl SceneBuilderInner
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Converts an entity's [`Name`] to a valid scene node name.
+fn scene_node_name_from(name: &Name) -> String
+{
+    let name: String = name
+        .as_str()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    name.trim_start_matches('_').into()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn serialize_entity_layer(
+    world: &World,
+    loadables: &LoadableRegistry,
+    registry: &TypeRegistry,
+    entity: Entity,
+    default_name: String,
+    indent: usize,
+) -> CobSceneLayer
+{
+    let name = world
+        .get::<Name>(entity)
+        .map(scene_node_name_from)
+        .filter(|n| !n.is_empty())
+        .unwrap_or(default_name);
+    let content_fill = format!("\n{:indent$}", "", indent = indent + 4);
+    let mut entries = vec![];
+
+    // Loadables
+    for info in world.inspect_entity(entity) {
+        let Some(serializer) = info.type_id().and_then(|id| loadables.get_serializer(id)) else { continue };
+        let Some(value) = (serializer)(world, entity) else { continue };
+        match CobLoadable::extract_partial_reflect(value, registry) {
+            Ok(mut loadable) => {
+                loadable.fill = CobFill::new(content_fill.as_str());
+                entries.push(CobSceneLayerEntry::Loadable(loadable));
+            }
+            Err(err) => {
+                tracing::warn!("failed serializing loadable {} on entity {entity:?}: {err:?}", info.name());
+            }
+        }
+    }
+
+    // Children
+    if let Some(children) = world.get::<Children>(entity) {
+        for (idx, child) in children.iter().enumerate() {
+            let layer = serialize_entity_layer(world, loadables, registry, *child, idx.to_string(), indent + 4);
+            entries.push(CobSceneLayerEntry::Layer(layer));
+        }
+    }
+
+    CobSceneLayer {
+        name_fill: CobFill::new(format!("\n{:indent$}", "", indent = indent)),
+        patch: None,
+        name: CobSceneNodeName(SmolStr::from(name)),
+        extends: None,
+        entries,
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// System parameter that is used to spawn scene instances.
 ///
 /// See [`SpawnSceneExt`].
@@ -744,6 +1062,29 @@ impl DerefMut for SceneBuilder<'_>
     }
 }
 
+impl SceneBuilder<'_>
+{
+    /// Serializes an entity and its descendants to a COB scene layer.
+    ///
+    /// Only loadables that are stored on entities are serialized, i.e. loadables registered with
+    /// [`register_component`](CobLoadableRegistrationAppExt::register_component) and
+    /// [`register_reactive`](CobLoadableRegistrationAppExt::register_reactive). Instructions and bundles can't be
+    /// recovered from entities.
+    ///
+    /// Scene node names are taken from [`Name`] components (with invalid characters replaced by `_`). Unnamed
+    /// children are named after their index in their parent's [`Children`], and unnamed roots are named `root`.
+    ///
+    /// Use [`CobSceneLayer::write_to`] with [`DefaultRawSerializer`] to get the layer's COB text.
+    ///
+    /// Panics if the root entity doesn't exist.
+    pub fn serialize_entity_tree(world: &World, root: Entity) -> CobSceneLayer
+    {
+        let loadables = world.resource::<LoadableRegistry>();
+        let registry = world.resource::<AppTypeRegistry>().read();
+        serialize_entity_layer(world, loadables, &registry, root, "root".into(), 0)
+    }
+}
+
 //-------------------------------------------------------------------------------------------------------------------
 
 /// Plugin that enables scene loading.
@@ -0,0 +1,231 @@
+use bevy::asset::{AssetLoadFailedEvent, LoadState as AssetLoadState};
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+use bevy_cobweb::prelude::*;
+
+use crate::prelude::*;
+use crate::sickle::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reports a scene error on an entity.
+///
+/// Logs the error if the entity is not inside a [`SceneErrorBoundary`].
+pub(crate) fn report_scene_error(world: &mut World, entity: Entity, scene_ref: Option<SceneRef>, error: String)
+{
+    // Find the nearest boundary.
+    let mut current = entity;
+    let boundary = loop {
+        if world.get::<SceneErrorBoundary>(current).is_some() {
+            break Some(current);
+        }
+        let Some(parent) = world.get::<Parent>(current) else { break None };
+        current = parent.get();
+    };
+    let Some(boundary) = boundary else {
+        tracing::debug!("scene error on {entity:?} ({scene_ref:?}) is not inside a SceneErrorBoundary: {error}");
+        return;
+    };
+
+    let caught = SceneErrorCaught { source: entity, scene_ref, error };
+    tracing::warn!("scene error boundary {boundary:?} caught error: {caught:?}");
+
+    let mut emut = world.entity_mut(boundary);
+    match emut.get_mut::<SceneErrorBoundaryErrors>() {
+        Some(mut errors) => errors.0.push(caught),
+        None => {
+            emut.insert(SceneErrorBoundaryErrors(vec![caught]));
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reports images that failed to load as scene errors.
+fn detect_failed_images(
+    mut c: Commands,
+    mut errors: EventReader<AssetLoadFailedEvent<Image>>,
+    asset_server: Res<AssetServer>,
+    images: Query<(Entity, Ref<ImageNode>)>,
+)
+{
+    let failed: Vec<AssetId<Image>> = errors.read().map(|e| e.id).collect();
+
+    for (entity, image) in images.iter() {
+        // Images that failed before the node was added won't be in the event list.
+        let is_failed = failed.contains(&image.image.id())
+            || (image.is_changed()
+                && matches!(asset_server.get_load_state(image.image.id()), Some(AssetLoadState::Failed(_))));
+        if !is_failed {
+            continue;
+        }
+
+        let path = image
+            .image
+            .path()
+            .map(|p| p.to_string())
+            .unwrap_or_default();
+        c.entity(entity)
+            .queue(ReportSceneError(format!("failed loading image {path:?}")));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Replaces the contents of error boundaries that caught errors.
+fn handle_caught_scene_errors(
+    mut c: Commands,
+    mut s: SceneBuilder,
+    default_placeholder: Res<DefaultSceneErrorPlaceholder>,
+    mut boundaries: Query<(Entity, &SceneErrorBoundary, &mut SceneErrorBoundaryErrors)>,
+)
+{
+    for (entity, boundary, mut errors) in boundaries.iter_mut() {
+        if errors.0.is_empty() {
+            continue;
+        }
+
+        // Notify listeners.
+        for caught in errors.0.drain(..) {
+            c.react().entity_event(entity, caught);
+        }
+
+        // Replace the subtree with a placeholder.
+        c.entity(entity).despawn_descendants();
+
+        let placeholder = match boundary.scene.is_empty() {
+            true => default_placeholder.0.clone(),
+            false => Some(SceneRef::new(boundary.file.as_str(), boundary.scene.as_str())),
+        };
+        if let Some(placeholder) = placeholder {
+            c.ui_builder(entity).spawn_scene(placeholder, &mut s);
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Records errors caught by an error boundary that need to be handled.
+#[derive(Component, Debug)]
+struct SceneErrorBoundaryErrors(Vec<SceneErrorCaught>);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reactive entity event sent to a [`SceneErrorBoundary`] entity when it catches an error.
+#[derive(Debug, Clone)]
+pub struct SceneErrorCaught
+{
+    /// The entity where the error occurred.
+    pub source: Entity,
+    /// The scene node where the error occurred, if known.
+    pub scene_ref: Option<SceneRef>,
+    /// Description of the error.
+    pub error: String,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Entity command for reporting a scene error on an entity.
+///
+/// Errors are caught by the nearest [`SceneErrorBoundary`] on the entity or its ancestors.
+///
+/// Failures to deserialize loadables and to load [`ImageNode`] images are reported automatically. Custom
+/// instructions can use this to report their own failures.
+#[derive(Debug, Clone)]
+pub struct ReportSceneError(pub String);
+
+impl EntityCommand for ReportSceneError
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        #[cfg(feature = "hot_reload")]
+        let scene_ref = world
+            .resource::<SceneBuffer>()
+            .get_entity_scene_node(entity)
+            .cloned();
+        #[cfg(not(feature = "hot_reload"))]
+        let scene_ref = None;
+
+        report_scene_error(world, entity, scene_ref, self.0);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource with the default placeholder scene spawned by [`SceneErrorBoundaries`](SceneErrorBoundary) that
+/// don't specify a placeholder.
+///
+/// If `None`, then the boundary's contents are removed without a replacement.
+#[derive(Resource, Default, Debug, Clone)]
+pub struct DefaultSceneErrorPlaceholder(pub Option<SceneRef>);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction loadable that marks a node as a scene error boundary.
+///
+/// If applying instructions fails anywhere in the node's subtree (e.g. a loadable fails to deserialize or an image
+/// fails to load), then the node's children are despawned and replaced with a placeholder scene, and a
+/// [`SceneErrorCaught`] entity event is sent to the node. Errors are caught by the nearest boundary.
+///
+/// The placeholder is spawned as a child of the node. If [`Self::scene`] is empty, then
+/// [`DefaultSceneErrorPlaceholder`] is used.
+///
+/// Note that with `hot_reload`, the node's original contents are not restored after the error is fixed. The scene
+/// needs to be respawned.
+///
+/// Example (COB):
+/// ```rust
+/// "menu"
+///     SceneErrorBoundary{file:"ui/errors.cob" scene:"error_placeholder"}
+/// ```
+#[derive(Reflect, Component, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct SceneErrorBoundary
+{
+    /// File of the placeholder scene.
+    #[reflect(default)]
+    pub file: String,
+    /// Placeholder scene.
+    #[reflect(default)]
+    pub scene: String,
+}
+
+impl Instruction for SceneErrorBoundary
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        emut.insert(self);
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        emut.remove::<(Self, SceneErrorBoundaryErrors)>();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct SceneErrorBoundaryPlugin;
+
+impl Plugin for SceneErrorBoundaryPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_resource::<DefaultSceneErrorPlaceholder>()
+            .register_instruction_type::<SceneErrorBoundary>()
+            .add_systems(
+                PostUpdate,
+                (detect_failed_images, handle_caught_scene_errors)
+                    .chain()
+                    .before(UiSystem::Prepare),
+            );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
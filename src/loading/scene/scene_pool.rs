@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+
+use bevy::ecs::system::EntityCommands;
+use bevy::ecs::world::Command;
+use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
+
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn rebuild_from_ref(
+    In((id, scene_ref, initializer)): In<(Entity, SceneRef, NodeInitializer)>,
+    mut c: Commands,
+    loadables: Res<LoadableRegistry>,
+    mut scene_buffer: ResMut<SceneBuffer>,
+    #[cfg(feature = "hot_reload")] commands_buffer: Res<CommandsBuffer>,
+)
+{
+    scene_buffer.rebuild_entity(
+        id,
+        scene_ref,
+        initializer,
+        &loadables,
+        &mut c,
+        #[cfg(feature = "hot_reload")]
+        &commands_buffer,
+    );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Re-applies a scene node's loadables to an entity from a pooled scene instance.
+pub(crate) fn rebuild_scene_node(
+    c: &mut Commands,
+    entity: Entity,
+    scene_ref: SceneRef,
+    initializer: fn(&mut EntityCommands),
+)
+{
+    if c.get_entity(entity).is_none() {
+        return;
+    }
+    c.syscall((entity, scene_ref, NodeInitializer { initializer }), rebuild_from_ref);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Takes a scene root entity out of its pool.
+pub(crate) fn restore_pooled_scene(entity: Entity, world: &mut World)
+{
+    let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+    let Some(pooled) = emut.take::<PooledScene>() else { return };
+    match pooled.visibility {
+        Some(visibility) => emut.insert(visibility),
+        None => emut.remove::<Visibility>(),
+    };
+    update_pooled_scene_count(world, entity, false);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Command for returning a scene instance to its pool.
+pub(crate) struct ReturnSceneToPool(pub(crate) Entity);
+
+impl Command for ReturnSceneToPool
+{
+    fn apply(self, world: &mut World)
+    {
+        let root = self.0;
+        let Ok(mut emut) = world.get_entity_mut(root) else { return };
+        if emut.contains::<PooledScene>() {
+            return;
+        }
+        let Some(PoolableScene(instance)) = emut.take::<PoolableScene>() else {
+            tracing::warn!("despawning {root:?} instead of returning it to a scene pool; it is not the root of a \
+                scene instance with a pool (see SceneBuilderInner::set_pool_capacity)");
+            emut.despawn_recursive();
+            return;
+        };
+        let visibility = emut.get::<Visibility>().copied();
+
+        let mut scene_builder = world.resource_mut::<SceneBuilderInner>();
+        if let Err(instance) = scene_builder.insert_pooled_scene(instance) {
+            // The pool is full.
+            scene_builder.recycle_scene_instance(instance);
+            world.entity_mut(root).despawn_recursive();
+            return;
+        }
+
+        world
+            .entity_mut(root)
+            .remove_parent()
+            .insert((PooledScene { visibility }, Visibility::Hidden));
+        update_pooled_scene_count(world, root, true);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Component on the root entity of scene instances that may be returned to a pool.
+#[derive(Component, Debug)]
+pub(crate) struct PoolableScene(pub(crate) SceneInstance);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Debug, Default)]
+struct ScenePool
+{
+    capacity: usize,
+    instances: Vec<SceneInstance>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Tracks pooled scene instances.
+#[derive(Debug, Default)]
+pub(crate) struct ScenePools
+{
+    /// [ root scene reference : pool ]
+    pools: HashMap<SceneRef, ScenePool>,
+}
+
+impl ScenePools
+{
+    pub(crate) fn set_capacity(&mut self, scene_ref: SceneRef, capacity: usize)
+    {
+        self.pools.entry(scene_ref).or_default().capacity = capacity;
+    }
+
+    pub(crate) fn capacity(&self, scene_ref: &SceneRef) -> usize
+    {
+        self.pools.get(scene_ref).map(|p| p.capacity).unwrap_or_default()
+    }
+
+    pub(crate) fn num_pooled(&self, scene_ref: &SceneRef) -> usize
+    {
+        self.pools
+            .get(scene_ref)
+            .map(|p| p.instances.len())
+            .unwrap_or_default()
+    }
+
+    /// Adds an instance to its scene's pool.
+    ///
+    /// Returns the instance if the pool is full.
+    pub(crate) fn insert(&mut self, instance: SceneInstance) -> Result<(), SceneInstance>
+    {
+        let Some(pool) = self.pools.get_mut(instance.scene_ref()) else { return Err(instance) };
+        if pool.instances.len() >= pool.capacity {
+            return Err(instance);
+        }
+        pool.instances.push(instance);
+        Ok(())
+    }
+
+    /// Takes the most recently pooled instance of a scene.
+    pub(crate) fn take(&mut self, scene_ref: &SceneRef) -> Option<SceneInstance>
+    {
+        self.pools.get_mut(scene_ref)?.instances.pop()
+    }
+
+    /// Removes the pools of all scenes in a file.
+    pub(crate) fn remove_file(&mut self, file: &CobFile) -> Vec<SceneInstance>
+    {
+        let scene_file = SceneFile::File(file.clone());
+        let mut removed = vec![];
+        self.pools.retain(|scene_ref, pool| {
+            if scene_ref.file != scene_file {
+                return true;
+            }
+            removed.append(&mut pool.instances);
+            false
+        });
+        removed
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Component inserted on the root entity of a scene instance while it is in a scene pool.
+///
+/// Pooled scenes are detached from their parents and hidden. Use `Without<PooledScene>` to filter them out of
+/// queries.
+#[derive(Component, Debug)]
+pub struct PooledScene
+{
+    /// The root entity's visibility before it was pooled.
+    visibility: Option<Visibility>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Extension trait for returning scene instances to their pools.
+pub trait ScenePoolExt
+{
+    /// Returns the scene instance rooted at this entity to its scene pool.
+    ///
+    /// See [`SceneHandle::despawn_to_pool`].
+    fn despawn_to_pool(&mut self);
+}
+
+impl ScenePoolExt for EntityCommands<'_>
+{
+    fn despawn_to_pool(&mut self)
+    {
+        let id = self.id();
+        self.commands().queue(ReturnSceneToPool(id));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
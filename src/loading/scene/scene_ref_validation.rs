@@ -0,0 +1,130 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use bevy::prelude::*;
+
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Checks pending scene references and logs a consolidated report of dangling references.
+fn validate_scene_refs(mut validation: ResMut<SceneRefValidation>, scene_builder: SceneBuilder)
+{
+    if validation.pending.is_empty() {
+        return;
+    }
+
+    let validation = validation.as_mut();
+    let mut report = String::default();
+    let mut num_dangling = 0;
+    for (scene_ref, source) in validation.pending.drain(..) {
+        let Err(err) = scene_builder.check_scene(&scene_ref) else { continue };
+        num_dangling += 1;
+        let _ = write!(report, "\n- {scene_ref:?} (used by {source}): {err}");
+        validation.dangling.push((scene_ref, source));
+    }
+
+    if num_dangling == 0 {
+        return;
+    }
+    tracing::warn!("scene reference validation found {num_dangling} dangling scene reference(s); spawning these \
+        scenes will fail:{report}");
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource that tracks scene references that should be validated against loaded COB files.
+///
+/// In debug builds, registered references are checked when entering [`LoadState::Done`], and then every tick
+/// for newly-registered references. Dangling references are logged in a single consolidated warning, instead of
+/// failing one-by-one when scenes are spawned. Nothing is tracked in release builds.
+///
+/// Use [`SceneRefValidationAppExt::validate_scene_ref`] to register scene references used by your Rust code.
+/// Built-in widgets automatically register scene references from their instructions (e.g. [`DataList`]).
+///
+/// References are checked against files that have loaded, so references to files in [load groups](LoadGroups)
+/// that haven't loaded yet will be reported as dangling.
+#[derive(Resource, Default, Debug)]
+pub struct SceneRefValidation
+{
+    registered: HashSet<SceneRef>,
+    pending: Vec<(SceneRef, String)>,
+    dangling: Vec<(SceneRef, String)>,
+}
+
+impl SceneRefValidation
+{
+    /// Registers a scene reference for validation.
+    ///
+    /// The `source` is a description of where the reference is used, which is included in the report.
+    ///
+    /// Does nothing if the reference is already registered, or in release builds.
+    pub fn register(&mut self, scene_ref: impl Into<SceneRef>, source: impl Into<String>)
+    {
+        if !cfg!(debug_assertions) {
+            return;
+        }
+        let scene_ref = scene_ref.into();
+        if !self.registered.insert(scene_ref.clone()) {
+            return;
+        }
+        self.pending.push((scene_ref, source.into()));
+    }
+
+    /// Iterates dangling scene references that have been detected, along with the sources that use them.
+    pub fn iter_dangling(&self) -> impl Iterator<Item = (&SceneRef, &str)> + '_
+    {
+        self.dangling.iter().map(|(r, s)| (r, s.as_str()))
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Extends `App` with scene reference validation.
+pub trait SceneRefValidationAppExt
+{
+    /// Registers a scene reference for validation in debug builds.
+    ///
+    /// See [`SceneRefValidation`].
+    fn validate_scene_ref(&mut self, scene_ref: impl Into<SceneRef>, source: impl Into<String>) -> &mut Self;
+}
+
+impl SceneRefValidationAppExt for App
+{
+    fn validate_scene_ref(&mut self, scene_ref: impl Into<SceneRef>, source: impl Into<String>) -> &mut Self
+    {
+        self.init_resource::<SceneRefValidation>();
+        self.world_mut()
+            .resource_mut::<SceneRefValidation>()
+            .register(scene_ref, source);
+        self
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Registers a scene reference used by an instruction for validation.
+pub(crate) fn validate_instruction_scene_ref<T: TypePath>(world: &mut World, scene_ref: SceneRef)
+{
+    if !cfg!(debug_assertions) {
+        return;
+    }
+    let Some(mut validation) = world.get_resource_mut::<SceneRefValidation>() else { return };
+    validation.register(scene_ref, T::short_type_path());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct SceneRefValidationPlugin;
+
+impl Plugin for SceneRefValidationPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        // Scene refs are only registered in debug builds, so this system is a no-op in release builds.
+        app.init_resource::<SceneRefValidation>()
+            .add_systems(First, validate_scene_refs.run_if(in_state(LoadState::Done)));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,206 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::utils::Instant;
+use bevy_cobweb::prelude::*;
+
+use crate::prelude::*;
+use crate::sickle::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Spawns the next instance of a queued batch.
+///
+/// Returns `None` if the batch should be discarded.
+fn spawn_queued_instance(
+    In(mut batch): In<QueuedSceneBatch>,
+    mut c: Commands,
+    mut s: SceneBuilder,
+) -> Option<QueuedSceneBatch>
+{
+    let idx = batch.next;
+    batch.next += 1;
+    let callback = &mut batch.callback;
+
+    match batch.parent {
+        Some(parent) => {
+            if c.get_entity(parent).is_none() {
+                tracing::debug!("discarding queued scene spawns for {:?}; parent {parent:?} was despawned",
+                    batch.scene);
+                return None;
+            }
+            c.ui_builder(parent)
+                .spawn_scene_and_edit(batch.scene.clone(), &mut s, |h| (callback)(idx, h));
+        }
+        None => {
+            c.ui_root()
+                .spawn_scene_and_edit(batch.scene.clone(), &mut s, |h| (callback)(idx, h));
+        }
+    }
+
+    Some(batch)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Spawns queued scene instances until the frame's budget is used up.
+fn spawn_queued_scenes(world: &mut World)
+{
+    let start = Instant::now();
+
+    loop {
+        let Some(batch) = world.resource_mut::<SceneSpawnQueue>().batches.pop_front() else { break };
+        let batch = world.syscall(batch, spawn_queued_instance);
+
+        let mut queue = world.resource_mut::<SceneSpawnQueue>();
+        if let Some(batch) = batch.filter(|b| b.next < b.count) {
+            queue.batches.push_front(batch);
+        }
+
+        // At least one instance is spawned per frame.
+        if start.elapsed() >= queue.max_time_per_frame {
+            break;
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+type SceneBatchCallback = Box<dyn for<'a> FnMut(usize, &mut UiSceneHandle<'a>) + Send + Sync + 'static>;
+
+struct QueuedSceneBatch
+{
+    parent: Option<Entity>,
+    scene: SceneRef,
+    count: usize,
+    /// Index of the next instance to spawn.
+    next: usize,
+    callback: SceneBatchCallback,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource for spawning many scene instances over multiple frames.
+///
+/// Spawning hundreds of scene instances at once (e.g. inventory grids or leaderboards) can stall a frame. Queued
+/// instances are spawned in order until [`Self::max_time_per_frame`](Self::set_max_time_per_frame) is used up,
+/// and the rest are spawned in later frames. At least one instance is spawned each frame.
+///
+/// Queued scenes are only spawned in [`LoadState::Done`]. Batches are discarded if their parent is despawned.
+///
+/// See [`SpawnSceneExt::spawn_scene_batched`] for spawning a batch immediately.
+///
+/// Example:
+/**
+```rust
+fn spawn_inventory(mut queue: ResMut<SceneSpawnQueue>, grid: Query<Entity, With<InventoryGrid>>)
+{
+    let grid = grid.single();
+    queue.spawn(Some(grid), ("inventory.cob", "slot"), 200, move |idx, h| {
+        h.insert(InventorySlot(idx));
+    });
+}
+```
+*/
+#[derive(Resource)]
+pub struct SceneSpawnQueue
+{
+    max_time_per_frame: Duration,
+    batches: VecDeque<QueuedSceneBatch>,
+}
+
+impl SceneSpawnQueue
+{
+    /// Queues `count` instances of `scene`, which will be spawned as children of `parent` (or as UI roots if
+    /// `parent` is `None`).
+    ///
+    /// `per_instance` is called with each instance's index so the instance can be edited.
+    pub fn spawn(
+        &mut self,
+        parent: Option<Entity>,
+        scene: impl Into<SceneRef>,
+        count: usize,
+        per_instance: impl for<'a> FnMut(usize, &mut UiSceneHandle<'a>) + Send + Sync + 'static,
+    )
+    {
+        if count == 0 {
+            return;
+        }
+        self.batches.push_back(QueuedSceneBatch {
+            parent,
+            scene: scene.into(),
+            count,
+            next: 0,
+            callback: Box::new(per_instance),
+        });
+    }
+
+    /// Sets the maximum time spent spawning queued scenes each frame.
+    ///
+    /// Defaults to 4ms.
+    pub fn set_max_time_per_frame(&mut self, max_time: Duration)
+    {
+        self.max_time_per_frame = max_time;
+    }
+
+    /// Gets the maximum time spent spawning queued scenes each frame.
+    pub fn max_time_per_frame(&self) -> Duration
+    {
+        self.max_time_per_frame
+    }
+
+    /// Gets the number of instances waiting to be spawned.
+    pub fn num_pending(&self) -> usize
+    {
+        self.batches.iter().map(|b| b.count - b.next).sum()
+    }
+
+    /// Returns `true` if there are no instances waiting to be spawned.
+    pub fn is_empty(&self) -> bool
+    {
+        self.batches.is_empty()
+    }
+
+    /// Removes queued instances that would be spawned as children of `parent`.
+    pub fn cancel(&mut self, parent: Entity)
+    {
+        self.batches.retain(|b| b.parent != Some(parent));
+    }
+
+    /// Removes all queued instances.
+    pub fn clear(&mut self)
+    {
+        self.batches.clear();
+    }
+}
+
+impl Default for SceneSpawnQueue
+{
+    fn default() -> Self
+    {
+        Self {
+            max_time_per_frame: Duration::from_millis(4),
+            batches: VecDeque::default(),
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct SceneSpawnQueuePlugin;
+
+impl Plugin for SceneSpawnQueuePlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_resource::<SceneSpawnQueue>().add_systems(
+            Update,
+            spawn_queued_scenes
+                .run_if(in_state(LoadState::Done))
+                .run_if(|q: Res<SceneSpawnQueue>| !q.is_empty()),
+        );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
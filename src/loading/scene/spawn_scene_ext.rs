@@ -35,8 +35,51 @@ fn build_from_ref(
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Moves the child at index `from` of `parent` to index `to` (clamped to the last index) without changing the
+/// relative order of other children.
+fn move_child_impl(world: &mut World, parent: Entity, from: usize, to: usize)
+{
+    let Some(mut children) = world.get_mut::<Children>(parent) else {
+        tracing::warn!("failed moving child {from} to {to} on {parent:?}, entity has no children");
+        return;
+    };
+    let len = children.len();
+    if from >= len {
+        tracing::warn!("failed moving child {from} to {to} on {parent:?}, entity only has {len} children");
+        return;
+    }
+    let to = to.min(len - 1);
+
+    if from < to {
+        for i in from..to {
+            children.swap(i, i + 1);
+        }
+    } else {
+        for i in (to..from).rev() {
+            children.swap(i, i + 1);
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Moves `child` to index `to` in its parent's children.
+fn move_child_to(world: &mut World, parent: Entity, child: Entity, to: usize)
+{
+    let Some(from) = world
+        .get::<Children>(parent)
+        .and_then(|c| c.iter().position(|e| *e == child))
+    else {
+        return;
+    };
+    move_child_impl(world, parent, from, to);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 fn spawn_scene_impl<'b, T, C, R>(
     builder: &'b mut T,
+    index: Option<usize>,
     path: impl Into<SceneRef>,
     scene_builder: &'b mut SceneBuilderInner,
     callback: C,
@@ -48,11 +91,19 @@ where
 {
     let path = path.into();
 
+    // Reuse a pooled instance of the scene if possible.
+    let parent = builder.scene_parent_entity();
+    let pooled_root = match parent.map(|p| builder.commands().get_entity(p).is_some()) {
+        Some(false) => None,
+        _ => scene_builder.reuse_pooled_scene::<T>(&mut builder.commands(), parent, path.clone()),
+    };
+
     // Spawn either a child or a raw entity to be the scene's root node.
-    let root_entity = builder
-        .scene_parent_entity()
-        .map(|parent| builder.commands().spawn_empty().set_parent(parent).id())
-        .unwrap_or_else(|| builder.commands().spawn_empty().id());
+    let root_entity = pooled_root.unwrap_or_else(|| {
+        parent
+            .map(|parent| builder.commands().spawn_empty().set_parent(parent).id())
+            .unwrap_or_else(|| builder.commands().spawn_empty().id())
+    });
 
     // Avoid panicking if the parent is invalid.
     if builder.commands().get_entity(root_entity).is_none() {
@@ -60,11 +111,26 @@ where
         return builder;
     }
 
+    // Move the root entity to its requested position among its siblings.
+    if let (Some(parent), Some(index)) = (builder.scene_parent_entity(), index) {
+        builder
+            .commands()
+            .queue(move |w: &mut World| move_child_to(w, parent, root_entity, index));
+    }
+
     // Load the scene into the root entity.
     let mut commands = builder.commands();
-    if !scene_builder.build_scene::<T>(&mut commands, root_entity, path.clone()) {
+    let profiling = scene_builder.is_profiling();
+    if profiling {
+        commands.queue(StartProfilingTimer);
+    }
+    if pooled_root.is_none() && !scene_builder.build_scene::<T>(&mut commands, root_entity, path.clone()) {
+        if profiling {
+            commands.queue(RecordSceneSpawn(None));
+        }
         return builder;
     }
+    let profiled_scene = profiling.then(|| scene_builder.resolve_scene_ref(path.clone()));
 
     // Allow editing the scene via callback.
     let result = {
@@ -83,7 +149,10 @@ where
     }
 
     // Cleanup
-    scene_builder.release_active_scene();
+    scene_builder.release_active_scene(&mut commands);
+    if profiling {
+        commands.queue(RecordSceneSpawn(profiled_scene));
+    }
 
     builder
 }
@@ -299,7 +368,66 @@ where
         C: for<'c> FnOnce(&mut SceneHandle<'c, <T as scene_traits::SceneNodeBuilder>::Builder<'c>>) -> R,
         R: CobwebResult,
     {
-        spawn_scene_impl(&mut self.builder, path, self.scene_builder, callback);
+        spawn_scene_impl(&mut self.builder, None, path, self.scene_builder, callback);
+        self
+    }
+
+    /// Equivalent to [`Self::insert_child_scene_at_and_edit`] with no callback.
+    pub fn insert_child_scene_at(&mut self, index: usize, path: impl Into<SceneRef>) -> &mut Self
+    {
+        self.insert_child_scene_at_and_edit(index, path, |_| {})
+    }
+
+    /// Spawns a scene as a child of the current node and moves it to `index` among its siblings, then calls
+    /// `callback` on the scene's root node.
+    ///
+    /// If `index` is greater than the number of existing children, the scene is appended.
+    ///
+    /// See [`SpawnSceneExt::spawn_scene_and_edit`].
+    pub fn insert_child_scene_at_and_edit<C, R>(
+        &mut self,
+        index: usize,
+        path: impl Into<SceneRef>,
+        callback: C,
+    ) -> &mut Self
+    where
+        C: for<'c> FnOnce(&mut SceneHandle<'c, <T as scene_traits::SceneNodeBuilder>::Builder<'c>>) -> R,
+        R: CobwebResult,
+    {
+        spawn_scene_impl(&mut self.builder, Some(index), path, self.scene_builder, callback);
+        self
+    }
+
+    /// Moves the child of the current node at index `from` to index `to`, shifting the children in between.
+    ///
+    /// The child entity is not despawned, so its state is preserved. If `to` is past the last child, the child is
+    /// moved to the end. Logs a warning and does nothing if there is no child at index `from`.
+    ///
+    /// The move is applied when commands are applied, so it can be combined with
+    /// [`Self::insert_child_scene_at`].
+    pub fn move_child(&mut self, from: usize, to: usize) -> &mut Self
+    {
+        let Some(parent) = self.builder.scene_parent_entity() else { return self };
+        self.builder
+            .commands()
+            .queue(move |w: &mut World| move_child_impl(w, parent, from, to));
+        self
+    }
+
+    /// Returns the scene instance being edited to its scene pool after the scene is built.
+    ///
+    /// Pooled instances are detached from their parents and hidden, then reused the next time the scene is
+    /// spawned. Reused instances keep their entities, but all scene nodes have their loadables re-applied. Changes
+    /// made outside of loadables (e.g. components inserted in the scene's spawn callback) are not reverted.
+    ///
+    /// If the scene's pool is full or the scene has no pool capacity, then the instance is despawned. See
+    /// [`SceneBuilderInner::set_pool_capacity`].
+    ///
+    /// To return an instance to its pool later, use [`ScenePoolExt::despawn_to_pool`] on the instance's root
+    /// entity.
+    pub fn despawn_to_pool(&mut self) -> &mut Self
+    {
+        self.scene_builder.return_active_scene_to_pool();
         self
     }
 
@@ -370,6 +498,21 @@ pub trait SpawnSceneExt: scene_traits::SceneNodeBuilder
     where
         C: for<'a> FnOnce(&mut SceneHandle<'a, <Self as scene_traits::SceneNodeBuilder>::Builder<'a>>) -> R,
         R: CobwebResult;
+
+    /// Spawns `count` instances of the scene at `path`, calling `per_instance` with each instance's index so it
+    /// can be edited.
+    ///
+    /// All instances are spawned immediately. Use [`SceneSpawnQueue`] to spread large batches across frames.
+    fn spawn_scene_batched<'b, C, R>(
+        &'b mut self,
+        path: impl Into<SceneRef>,
+        count: usize,
+        scene_builder: &'b mut SceneBuilderInner,
+        per_instance: C,
+    ) -> &'b mut Self
+    where
+        C: for<'a> FnMut(usize, &mut SceneHandle<'a, <Self as scene_traits::SceneNodeBuilder>::Builder<'a>>) -> R,
+        R: CobwebResult;
 }
 
 impl<T> SpawnSceneExt for T
@@ -395,7 +538,25 @@ where
         C: for<'a> FnOnce(&mut SceneHandle<'a, <T as scene_traits::SceneNodeBuilder>::Builder<'a>>) -> R,
         R: CobwebResult,
     {
-        spawn_scene_impl(self, path, scene_builder, callback)
+        spawn_scene_impl(self, None, path, scene_builder, callback)
+    }
+
+    fn spawn_scene_batched<'b, C, R>(
+        &'b mut self,
+        path: impl Into<SceneRef>,
+        count: usize,
+        scene_builder: &'b mut SceneBuilderInner,
+        mut per_instance: C,
+    ) -> &'b mut Self
+    where
+        C: for<'a> FnMut(usize, &mut SceneHandle<'a, <T as scene_traits::SceneNodeBuilder>::Builder<'a>>) -> R,
+        R: CobwebResult,
+    {
+        let path = path.into();
+        for idx in 0..count {
+            spawn_scene_impl(self, None, path.clone(), scene_builder, |h| (per_instance)(idx, h));
+        }
+        self
     }
 }
 
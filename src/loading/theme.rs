@@ -0,0 +1,43 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use smol_str::SmolStr;
+
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Loadable command for setting the active theme variant used to resolve `ThemeToken("name")` values.
+///
+/// Theme tokens are defined in `#theme` sections. Tokens without a value for the active theme fall back to their
+/// first value.
+///
+/// Scene loadables that use theme tokens are reapplied when the theme changes. Commands that use theme tokens are
+/// only re-applied if the `hot_reload` feature is enabled.
+#[derive(Reflect, Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SetActiveTheme(pub String);
+
+impl Command for SetActiveTheme
+{
+    fn apply(self, world: &mut World)
+    {
+        world.resource_scope::<CobAssetCache, ()>(|world: &mut World, mut cob_cache: Mut<CobAssetCache>| {
+            // The commands buffer is removed after loading if `hot_reload` is disabled.
+            let commands_buffer = world.get_resource_mut::<CommandsBuffer>();
+            cob_cache.set_active_theme(SmolStr::from(self.0), commands_buffer.map(|b| b.into_inner()));
+        });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct ThemePlugin;
+
+impl Plugin for ThemePlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.register_command_type::<SetActiveTheme>();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
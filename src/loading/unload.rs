@@ -0,0 +1,137 @@
+use bevy::ecs::world::Command;
+use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
+
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Evicts a loaded file from all caches.
+pub(crate) fn unload_cob_file(
+    In((file, despawn_scenes)): In<(CobFile, bool)>,
+    mut c: Commands,
+    mut files: ResMut<LoadedCobAssetFiles>,
+    mut cob_cache: ResMut<CobAssetCache>,
+    commands_buffer: Option<ResMut<CommandsBuffer>>,
+    mut scene_buffer: ResMut<SceneBuffer>,
+    mut scene_builder: SceneBuilder,
+    mut def_values: ResMut<CobDefValues>,
+    scenes: Query<(Entity, &TrackedSceneInstance, Has<PooledScene>)>,
+)
+{
+    // Files that haven't started loading only need to be forgotten.
+    if files.remove_preset(&file) {
+        tracing::info!("removed COB file {:?} before it started loading", file.as_str());
+        return;
+    }
+
+    if !cob_cache.is_file_registered(&file) {
+        tracing::warn!("failed unloading COB file {:?}; the file is not loaded", file.as_str());
+        return;
+    }
+    if cob_cache.is_file_loading(&file) {
+        tracing::warn!("failed unloading COB file {:?}; the file is still loading", file.as_str());
+        return;
+    }
+
+    tracing::info!("unloading COB file {:?}", file.as_str());
+
+    // Despawn scenes before their structures are evicted.
+    // - Pooled scenes are always despawned since they can't be reused.
+    let scene_file = SceneFile::File(file.clone());
+    for (entity, instance, is_pooled) in scenes.iter() {
+        if instance.0.file != scene_file || !(despawn_scenes || is_pooled) {
+            continue;
+        }
+        let Some(ec) = c.get_entity(entity) else { continue };
+        ec.despawn_recursive();
+    }
+
+    cob_cache.evict_file(&file);
+    scene_buffer.evict_file(&file);
+    scene_builder.evict_file(&file);
+    def_values.evict_file(&file);
+    if let Some(mut commands_buffer) = commands_buffer {
+        commands_buffer.evict_file(&file);
+    }
+    files.release_file(&file);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Command for unloading a COB file.
+///
+/// Cached data extracted from the file (scene structures, loadables, defs, and commands) is discarded, and
+/// non-COB assets requested by the file's manifest are released unless other files requested them too. The file
+/// can be loaded again with a [load group](LoadedCobAssetFilesAppExt::load_group).
+///
+/// Scenes already spawned from the file are kept unless [`Self::despawn_scenes`] is set. Commands that were
+/// applied from the file are not reverted, and assets loaded by those commands (e.g. with
+/// [`LoadImages`]) are not released.
+///
+/// Files in the file's manifest are not unloaded. Files that import the file should be unloaded too, otherwise
+/// they will fail to resolve their imports if they reload.
+///
+/// Files that are still loading can't be unloaded.
+#[derive(Debug, Clone)]
+pub struct UnloadCob
+{
+    /// The file to unload.
+    pub file: String,
+    /// Despawns scenes spawned from the file.
+    pub despawn_scenes: bool,
+}
+
+impl UnloadCob
+{
+    /// Makes a command that unloads `file` without despawning its scenes.
+    pub fn new(file: impl Into<String>) -> Self
+    {
+        Self { file: file.into(), despawn_scenes: false }
+    }
+
+    /// Despawns scenes spawned from the file when unloading it.
+    pub fn despawn_scenes(mut self) -> Self
+    {
+        self.despawn_scenes = true;
+        self
+    }
+}
+
+impl Command for UnloadCob
+{
+    fn apply(self, world: &mut World)
+    {
+        let Some(file) = CobFile::try_new(&self.file) else {
+            tracing::warn!("failed unloading COB file {}; does not have '.cob' extension", self.file);
+            return;
+        };
+        world.syscall((file, self.despawn_scenes), unload_cob_file);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Extension trait for unloading COB files.
+pub trait UnloadCobCommandsExt
+{
+    /// Queues [`UnloadCob`] for a file. Scenes spawned from the file are not despawned.
+    fn unload_cob(&mut self, file: impl Into<String>);
+    /// Queues [`UnloadCob`] for a file and despawns scenes spawned from the file.
+    fn unload_cob_and_despawn(&mut self, file: impl Into<String>);
+}
+
+impl UnloadCobCommandsExt for Commands<'_, '_>
+{
+    fn unload_cob(&mut self, file: impl Into<String>)
+    {
+        self.queue(UnloadCob::new(file));
+    }
+
+    fn unload_cob_and_despawn(&mut self, file: impl Into<String>)
+    {
+        self.queue(UnloadCob::new(file).despawn_scenes());
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,205 @@
+use std::any::TypeId;
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::reflect::serde::TypedReflectDeserializer;
+use bevy::reflect::TypeRegistry;
+use serde::de::DeserializeSeed;
+
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+struct CobValidation<'a, F>
+{
+    file: CobFile,
+    type_registry: &'a TypeRegistry,
+    /// Looks up the type id of a loadable by short name.
+    lookup: F,
+    /// Set if the file has imports. Imported definitions can't be resolved, so def resolution failures are
+    /// ignored.
+    has_imports: bool,
+    errors: Vec<CobLoadError>,
+}
+
+impl<F: Fn(&str) -> Option<TypeId>> CobValidation<'_, F>
+{
+    fn unresolved(&mut self, path: &ScenePath, message: String)
+    {
+        if self.has_imports {
+            return;
+        }
+        self.errors
+            .push(CobLoadError::UnresolvedDefs { file: self.file.clone(), path: path.clone(), message });
+    }
+
+    fn validate_loadable(&mut self, path: &ScenePath, loadable: &mut CobLoadable, resolver: &CobResolver)
+    {
+        let name = loadable.id.to_canonical(None);
+        let Some(registration) = (self.lookup)(&name).and_then(|id| self.type_registry.get(id)) else {
+            self.errors
+                .push(CobLoadError::UnknownLoadable { file: self.file.clone(), path: path.clone(), name });
+            return;
+        };
+
+        if let Err(err) = loadable.resolve(&resolver.loadables) {
+            self.unresolved(path, format!("failed resolving loadable {name:?}; {err}"));
+            return;
+        }
+
+        let deserializer = TypedReflectDeserializer::new(registration, self.type_registry);
+        if let Err(err) = deserializer.deserialize(&*loadable) {
+            self.errors.push(CobLoadError::InvalidLoadable {
+                file: self.file.clone(),
+                path: path.clone(),
+                name,
+                message: err.to_string(),
+            });
+        }
+    }
+
+    fn validate_scene_layer(&mut self, path: &ScenePath, layer: &mut CobSceneLayer, resolver: &mut CobResolver)
+    {
+        if let Err(err) = layer.resolve(resolver, SceneResolveMode::OneLayerSceneOnly) {
+            self.unresolved(path, format!("failed resolving scene layer; {err}"));
+            return;
+        }
+
+        let mut anonymous_count = 0;
+        for entry in layer.entries.iter_mut() {
+            match entry {
+                CobSceneLayerEntry::Loadable(loadable) => self.validate_loadable(path, loadable, resolver),
+                CobSceneLayerEntry::Layer(child) => {
+                    let name = match child.name.as_str() {
+                        "" => {
+                            anonymous_count += 1;
+                            format!("_{}", anonymous_count - 1)
+                        }
+                        name => name.into(),
+                    };
+                    let Some(child_path) = path.extend_single(name) else { continue };
+                    self.validate_scene_layer(&child_path, child, resolver);
+                }
+                _ => (),
+            }
+        }
+    }
+
+    fn validate(mut self, mut data: Cob) -> Result<(), Vec<CobLoadError>>
+    {
+        self.has_imports = data
+            .sections
+            .iter()
+            .any(|s| matches!(s, CobSection::Import(_)));
+
+        let mut resolver = CobResolver::default();
+        extract_cob_importables(self.file.clone(), &mut data, &mut resolver);
+
+        for section in data.sections.iter_mut() {
+            match section {
+                CobSection::Commands(section) => {
+                    let path = ScenePath::new("#commands");
+                    for CobCommandEntry(loadable) in section.entries.iter_mut() {
+                        self.validate_loadable(&path, loadable, &resolver);
+                    }
+                }
+                // Scene patches target imported files, so they can't be validated.
+                CobSection::Scenes(section) => {
+                    for layer in section.scenes.iter_mut().filter(|s| s.patch.is_none()) {
+                        let Some(path) = ScenePath::parse_single(&*layer.name) else { continue };
+                        if let Err(err) = layer.resolve_extends(&mut resolver) {
+                            self.unresolved(&path, format!("failed resolving base scene; {err}"));
+                            continue;
+                        }
+                        self.validate_scene_layer(&path, layer, &mut resolver);
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        match self.errors.is_empty() {
+            true => Ok(()),
+            false => Err(self.errors),
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn validate_cob_impl(
+    file: &str,
+    content: &str,
+    type_registry: &TypeRegistry,
+    lookup: impl Fn(&str) -> Option<TypeId>,
+) -> Result<(), Vec<CobLoadError>>
+{
+    let data = Cob::parse(Span::new_extra(content, CobLocationMetadata { file }))
+        .map_err(|err| vec![CobLoadError::from_parse_error(file, err)])?;
+    let validation = CobValidation {
+        file: data.file.clone(),
+        type_registry,
+        lookup,
+        has_imports: false,
+        errors: vec![],
+    };
+    validation.validate(data)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Validates COB file content without loading it into an app.
+///
+/// The content is parsed, definitions in the content are resolved, and loadables in `#commands` and `#scenes`
+/// sections are deserialized. Loadables are looked up by short type path in `type_registry`, so any reflected
+/// type is accepted even if it isn't registered as a loadable. Use [`CobValidator`] to check against
+/// loadables registered in an app.
+///
+/// Imported files aren't available, so if the content has an `#import` section then definition resolution
+/// failures are ignored. Scene patches are not validated.
+///
+/// Errors refer to the file as `inline.cob`.
+///
+/// Example:
+/**
+```rust
+// In an integration test:
+let mut app = App::new();
+app.add_plugins((MinimalPlugins, AssetPlugin::default(), CobwebUiPlugin));
+let registry = app.world().resource::<AppTypeRegistry>().read();
+let content = std::fs::read_to_string("assets/main.cob").unwrap();
+validate_cob_str(&content, &registry).unwrap();
+```
+*/
+pub fn validate_cob_str(content: &str, type_registry: &TypeRegistry) -> Result<(), Vec<CobLoadError>>
+{
+    validate_cob_impl("inline.cob", content, type_registry, |name| {
+        type_registry
+            .get_with_short_type_path(name)
+            .map(|r| r.type_id())
+    })
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// System parameter for validating COB file content against the loadables registered in the app.
+///
+/// See [`validate_cob_str`].
+#[derive(SystemParam)]
+pub struct CobValidator<'w>
+{
+    types: Res<'w, AppTypeRegistry>,
+    loadables: Res<'w, LoadableRegistry>,
+}
+
+impl CobValidator<'_>
+{
+    /// Validates COB file content. `file` is the name of the file (with `.cob` extension), used in errors.
+    pub fn validate(&self, file: &str, content: &str) -> Result<(), Vec<CobLoadError>>
+    {
+        let type_registry = self.types.read();
+        validate_cob_impl(file, content, &type_registry, |name| self.loadables.get_type_id(name))
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
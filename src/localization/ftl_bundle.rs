@@ -13,6 +13,8 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use unic_langid::LanguageIdentifier;
 
+use crate::prelude::*;
+
 //-------------------------------------------------------------------------------------------------------------------
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -58,6 +60,10 @@ async fn load_ftl_bundle_contents(
     // - https://docs.rs/fluent-bundle/0.15.3/fluent_bundle/bundle/struct.FluentBundle.html#method.set_use_isolating
     // - https://unicode.org/reports/tr9/#Explicit_Directional_Isolates
     bundle.set_use_isolating(true);
+    bundle.set_formatter(Some(format_fluent_value));
+    if let Err(err) = bundle.add_function("NUMBER", fluent_number_function) {
+        warn!("failed adding NUMBER function to fluent bundle: {err}");
+    }
 
     Ok(FtlBundle(bundle))
 }
@@ -17,6 +17,7 @@ fn relocalize_text(
     localizer: Res<TextLocalizer>,
     fonts: Res<FontMap>,
     mut localized_text: Query<(Entity, &mut LocalizedText)>,
+    markup_spans: Query<(), With<TextMarkupSpan>>,
     mut writer: TextUiWriter,
 )
 {
@@ -24,7 +25,11 @@ fn relocalize_text(
     for (entity, mut localized) in localized_text.iter_mut() {
         let mut idx = 0;
 
-        writer.for_each(entity, |_, _, mut text, mut font, _| {
+        writer.for_each(entity, |span_entity, _, mut text, mut font, _| {
+            // Markup spans are regenerated when the root text changes.
+            if markup_spans.contains(span_entity) {
+                return;
+            }
             localized.localize_span(&localizer, &fonts, &mut *text, &mut font.font, idx);
             idx += 1;
         });
@@ -39,6 +44,7 @@ fn relocalize_text(
 fn handle_font_refresh(
     fonts: Res<FontMap>,
     mut localized_text: Query<(Entity, &mut LocalizedText)>,
+    markup_spans: Query<(), With<TextMarkupSpan>>,
     mut writer: TextUiWriter,
 )
 {
@@ -46,7 +52,10 @@ fn handle_font_refresh(
     for (entity, mut localized) in localized_text.iter_mut() {
         let mut idx = 0;
 
-        writer.for_each_font(entity, |mut font| {
+        writer.for_each(entity, |span_entity, _, _, mut font, _| {
+            if markup_spans.contains(span_entity) {
+                return;
+            }
             let this_idx = idx;
             idx += 1;
             let Some(loc_span) = localized.localization_for_span_mut(this_idx) else { return };
@@ -65,13 +74,17 @@ fn handle_new_localized_text(
     localizer: Res<TextLocalizer>,
     fonts: Res<FontMap>,
     mut localized_text: Query<(Entity, &mut LocalizedText), Added<LocalizedText>>,
+    markup_spans: Query<(), With<TextMarkupSpan>>,
     mut writer: TextUiWriter,
 )
 {
     for (entity, mut localized) in localized_text.iter_mut() {
         let mut idx = 0;
 
-        writer.for_each(entity, |_, _, mut text, mut font, _| {
+        writer.for_each(entity, |span_entity, _, mut text, mut font, _| {
+            if markup_spans.contains(span_entity) {
+                return;
+            }
             let this_idx = idx;
             idx += 1;
 
@@ -128,6 +141,11 @@ pub struct LocalizedTextspan
     /// The localization template that will be used to generate localized text strings.
     #[reflect(ignore)]
     pub template: String,
+    /// Structured args passed to `fluent` along with the template.
+    ///
+    /// See [`TextEditor::write_with_args`].
+    #[reflect(ignore)]
+    pub args: TextArgs,
 }
 
 impl LocalizedTextspan
@@ -155,7 +173,9 @@ impl LocalizedTextspan
     /// Localizes this text span.
     pub fn localize(&mut self, localizer: &TextLocalizer, target: &mut String) -> TextLocalizationResult
     {
-        let Some(lang) = localizer.localize(&self.template, target) else { return TextLocalizationResult::Fail };
+        let Some(lang) = localizer.localize_with_args(&self.template, &self.args, target) else {
+            return TextLocalizationResult::Fail;
+        };
         if self.id.as_ref() == Some(lang) {
             return TextLocalizationResult::SameLang;
         }
@@ -240,6 +260,7 @@ impl LocalizedText
         let localized_span = &mut self.localization[span];
         localized_span.template.clear();
         localized_span.template.push_str(data.as_ref());
+        localized_span.args.clear();
     }
 
     /// Gets a reference to the cached localization template for the first span in the entity's [`Text`].
@@ -349,7 +370,12 @@ impl Plugin for LocalizedTextPlugin
             .react(|rc| rc.on_persistent(broadcast::<TextLocalizerLoaded>(), relocalize_text))
             .react(|rc| rc.on_persistent(broadcast::<FontMapLoaded>(), handle_font_refresh))
             .configure_sets(PostUpdate, LocalizationSet::Update.before(UiSystem::Prepare))
-            .add_systems(PostUpdate, handle_new_localized_text.in_set(LocalizationSet::Update));
+            .add_systems(
+                PostUpdate,
+                (handle_new_localized_text, apply_text_markup)
+                    .chain()
+                    .in_set(LocalizationSet::Update),
+            );
     }
 }
 
@@ -9,7 +9,9 @@ mod localization_set;
 mod localized_text;
 mod plugin;
 mod relocalize_tracker;
+mod text_args;
 mod text_localizer;
+mod text_markup;
 
 pub(crate) use ftl_bundle::*;
 pub use locale::*;
@@ -18,4 +20,6 @@ pub use localization_set::*;
 pub use localized_text::*;
 pub(crate) use plugin::*;
 pub use relocalize_tracker::*;
+pub use text_args::*;
 pub use text_localizer::*;
+pub use text_markup::*;
@@ -0,0 +1,319 @@
+use std::borrow::Cow;
+
+use fluent::types::{FluentNumber, FluentNumberOptions, FluentNumberStyle};
+use fluent::{FluentArgs, FluentValue};
+use intl_memoizer::concurrent::IntlLangMemoizer;
+use intl_memoizer::Memoizable;
+use unic_langid::LanguageIdentifier;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Locale-specific number separators, cached in fluent bundles.
+#[derive(Debug)]
+struct NumberSeparators
+{
+    decimal: char,
+    group: char,
+    /// Minimum number of integer digits before grouping is used.
+    min_grouping: usize,
+}
+
+impl NumberSeparators
+{
+    fn format(&self, number: &FluentNumber) -> String
+    {
+        let options = &number.options;
+        let mut raw = match options.maximum_fraction_digits {
+            Some(max) => {
+                let mut raw = format!("{:.*}", max, number.value);
+                if raw.contains('.') {
+                    let min = options.minimum_fraction_digits.unwrap_or(0).min(max);
+                    let frac_len = raw.len() - raw.find('.').unwrap() - 1;
+                    let trim = raw[(raw.len() - frac_len + min)..]
+                        .chars()
+                        .rev()
+                        .take_while(|c| *c == '0')
+                        .count();
+                    raw.truncate(raw.len() - trim);
+                    raw = raw.trim_end_matches('.').into();
+                }
+                raw
+            }
+            None => number.value.to_string(),
+        };
+        if let Some(min) = options.minimum_fraction_digits {
+            let frac_len = raw.find('.').map(|pos| raw.len() - pos - 1);
+            match frac_len {
+                Some(len) => raw.extend(std::iter::repeat_n('0', min.saturating_sub(len))),
+                None if min > 0 => {
+                    raw.push('.');
+                    raw.extend(std::iter::repeat_n('0', min));
+                }
+                None => (),
+            }
+        }
+
+        let (sign, unsigned) = match raw.strip_prefix('-') {
+            Some(unsigned) => ("-", unsigned),
+            None => ("", raw.as_str()),
+        };
+        let (integer, fraction) = match unsigned.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (unsigned, None),
+        };
+        let padding = options
+            .minimum_integer_digits
+            .unwrap_or(0)
+            .saturating_sub(integer.len());
+        let integer: Cow<str> = match padding {
+            0 => integer.into(),
+            _ => format!("{}{integer}", "0".repeat(padding)).into(),
+        };
+
+        let mut result = String::with_capacity(raw.len() + integer.len() / 3);
+        result.push_str(sign);
+        let group = options.use_grouping && integer.len() >= self.min_grouping;
+        for (idx, digit) in integer.chars().enumerate() {
+            if group && idx > 0 && (integer.len() - idx).is_multiple_of(3) {
+                result.push(self.group);
+            }
+            result.push(digit);
+        }
+        if let Some(fraction) = fraction {
+            result.push(self.decimal);
+            result.push_str(fraction);
+        }
+        result
+    }
+}
+
+impl Memoizable for NumberSeparators
+{
+    type Args = ();
+    type Error = ();
+
+    fn construct(lang: LanguageIdentifier, _: ()) -> Result<Self, ()>
+    {
+        let (decimal, group, min_grouping) = match lang.language.as_str() {
+            "de" | "da" | "el" | "id" | "it" | "nl" | "ro" | "sl" | "sr" | "tr" | "hr" => (',', '.', 4),
+            "es" => (',', '.', 5),
+            "pt" if lang.region.is_some_and(|r| r.as_str() == "PT") => (',', '\u{a0}', 5),
+            "pt" => (',', '.', 4),
+            "fr" => (',', '\u{202f}', 4),
+            "pl" => (',', '\u{a0}', 5),
+            "bg" | "cs" | "et" | "fi" | "hu" | "lt" | "lv" | "nb" | "no" | "ru" | "sk" | "sv" | "uk" => {
+                (',', '\u{a0}', 4)
+            }
+            _ => ('.', ',', 4),
+        };
+        Ok(Self { decimal, group, min_grouping })
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Formats numbers in localized text with locale-specific separators.
+///
+/// Only decimal numbers are formatted. Other values use the default fluent formatting.
+pub(crate) fn format_fluent_value(value: &FluentValue, intls: &IntlLangMemoizer) -> Option<String>
+{
+    let FluentValue::Number(number) = value else { return None };
+    if number.options.style != FluentNumberStyle::Decimal || !number.value.is_finite() {
+        return None;
+    }
+    intls
+        .with_try_get::<NumberSeparators, _, _>((), |separators| separators.format(number))
+        .ok()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Implementation of the fluent `NUMBER` builtin.
+///
+/// Supports the `minimumIntegerDigits`, `minimumFractionDigits`, `maximumFractionDigits`, and `useGrouping`
+/// options.
+pub(crate) fn fluent_number_function<'a>(positional: &[FluentValue<'a>], named: &FluentArgs) -> FluentValue<'a>
+{
+    let mut number = match positional.first() {
+        Some(FluentValue::Number(number)) => number.clone(),
+        Some(FluentValue::String(string)) => match string.parse::<FluentNumber>() {
+            Ok(number) => number,
+            Err(_) => return FluentValue::Error,
+        },
+        _ => return FluentValue::Error,
+    };
+    number.options.merge(named);
+    FluentValue::Number(number)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Formatting options for numbers passed to localized text in [`TextArgs`].
+///
+/// Translators can override these options in `fluent` files with the `NUMBER` function, e.g.
+/// `{ NUMBER($score, maximumFractionDigits: 0) }`.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct TextNumberFormat
+{
+    /// Defaults to `None`, which means no padding.
+    pub min_fraction_digits: Option<usize>,
+    /// Defaults to `None`, which means the shortest representation of the number is used.
+    pub max_fraction_digits: Option<usize>,
+    /// Whether to disable locale-specific digit grouping (e.g. `1,000` in English).
+    ///
+    /// Defaults to `false`.
+    pub no_grouping: bool,
+}
+
+impl TextNumberFormat
+{
+    fn to_fluent(self) -> FluentNumberOptions
+    {
+        FluentNumberOptions {
+            minimum_fraction_digits: self.min_fraction_digits,
+            maximum_fraction_digits: self.max_fraction_digits,
+            use_grouping: !self.no_grouping,
+            ..Default::default()
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A structured argument for localized text.
+///
+/// See [`TextArgs`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum TextArg
+{
+    String(String),
+    /// Numbers are formatted with locale-specific separators, and can be used for plural selection in `fluent`
+    /// files.
+    Number(f64, TextNumberFormat),
+}
+
+impl TextArg
+{
+    /// Makes a number argument with custom formatting.
+    pub fn number(value: impl Into<f64>, format: TextNumberFormat) -> Self
+    {
+        Self::Number(value.into(), format)
+    }
+
+    fn to_fluent(&self) -> FluentValue<'_>
+    {
+        match self {
+            Self::String(string) => FluentValue::String(string.as_str().into()),
+            Self::Number(value, format) => FluentValue::Number(FluentNumber::new(*value, format.to_fluent())),
+        }
+    }
+}
+
+impl From<&str> for TextArg
+{
+    fn from(value: &str) -> Self
+    {
+        Self::String(value.into())
+    }
+}
+
+impl From<String> for TextArg
+{
+    fn from(value: String) -> Self
+    {
+        Self::String(value)
+    }
+}
+
+macro_rules! impl_text_arg_from_number {
+    ($($type: ty),*) => {
+        $(
+            impl From<$type> for TextArg
+            {
+                fn from(value: $type) -> Self
+                {
+                    Self::Number(value as f64, TextNumberFormat::default())
+                }
+            }
+        )*
+    };
+}
+
+impl_text_arg_from_number!(f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Structured arguments for localized text.
+///
+/// Arguments are passed directly to `fluent`, so numbers are formatted per-locale and can be used for plural
+/// selection. Args are stored in [`LocalizedTextspan::args`](crate::prelude::LocalizedTextspan::args) so text is
+/// re-formatted when the language changes.
+///
+/// Use [`write_text_args`](crate::write_text_args) or [`TextEditor::write_with_args`] to write localized text
+/// with args.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TextArgs
+{
+    args: Vec<(String, TextArg)>,
+}
+
+impl TextArgs
+{
+    /// Makes an empty set of args.
+    pub fn new() -> Self
+    {
+        Self::default()
+    }
+
+    /// Adds an arg.
+    pub fn with(mut self, key: impl AsRef<str>, value: impl Into<TextArg>) -> Self
+    {
+        self.set(key, value);
+        self
+    }
+
+    /// Sets an arg, replacing any existing arg with the same key.
+    pub fn set(&mut self, key: impl AsRef<str>, value: impl Into<TextArg>)
+    {
+        let key = key.as_ref();
+        let value = value.into();
+        match self.args.iter_mut().find(|(k, _)| k == key) {
+            Some((_, arg)) => *arg = value,
+            None => self.args.push((key.into(), value)),
+        }
+    }
+
+    /// Gets an arg.
+    pub fn get(&self, key: &str) -> Option<&TextArg>
+    {
+        self.args.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+    }
+
+    /// Removes all args.
+    pub fn clear(&mut self)
+    {
+        self.args.clear();
+    }
+
+    /// Returns `true` if there are no args.
+    pub fn is_empty(&self) -> bool
+    {
+        self.args.is_empty()
+    }
+
+    /// Iterates the args.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &TextArg)> + '_
+    {
+        self.args.iter().map(|(k, v)| (k.as_str(), v))
+    }
+
+    /// Adds the args to a set of fluent args, overwriting existing args with the same keys.
+    pub(crate) fn add_to_fluent<'a>(&'a self, fluent_args: &mut FluentArgs<'a>)
+    {
+        for (key, value) in self.args.iter() {
+            fluent_args.set(key.as_str(), value.to_fluent());
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -77,6 +77,7 @@ fn get_localization_data(
     let started_loading = localizer.is_loading();
     let mut fail_count = 0;
     let mut refresh_count = 0;
+    let mut reload_count = 0;
 
     // Handle errors.
     for error in errors.read() {
@@ -106,8 +107,13 @@ fn get_localization_data(
         //   have multiple languages in them (e.g. localized text and a date/time formatted with a different
         //   language). This is because each font section can only have one font, and fonts aren't valid for all
         //   languages.
-        if localizer.try_set(*id, ftl_bundle) {
-            refresh_count += 1;
+        match localizer.try_set(*id, ftl_bundle) {
+            Some(true) => {
+                refresh_count += 1;
+                reload_count += 1;
+            }
+            Some(false) => refresh_count += 1,
+            None => (),
         }
     }
 
@@ -116,6 +122,13 @@ fn get_localization_data(
         localizer.update_is_loading();
     }
 
+    // Relocalize the app if localization files were hot reloaded.
+    // - We don't emit `TextLocalizerLoaded` in this case since `RelocalizeApp` will relocalize all text.
+    if reload_count > 0 && !localizer.is_loading() && !localizer.is_awaiting_renegotiation {
+        c.react().broadcast(RelocalizeApp);
+        return;
+    }
+
     // Check if the text localizer updated and is fully loaded.
     if started_loading || refresh_count > 0 {
         localizer.try_emit_load_event(&mut c);
@@ -141,18 +154,23 @@ enum TextLocalization
 
 impl TextLocalization
 {
-    fn try_set(&mut self, id: &AssetId<FtlBundle>, asset: FtlBundle) -> Result<(), FtlBundle>
+    /// Returns `Ok(true)` if the localization was already loaded (i.e. it was hot reloaded).
+    fn try_set(&mut self, id: &AssetId<FtlBundle>, asset: FtlBundle) -> Result<bool, FtlBundle>
     {
         if self.handle().id() != *id {
             return Err(asset);
         }
+        let reloaded = !self.is_loading();
+        if reloaded {
+            tracing::info!("reloaded localization data for {:?}", self.lang_id());
+        }
 
         *self = Self::Loaded {
             id: self.lang_id().clone(),
             asset,
             handle: self.handle().clone(),
         };
-        Ok(())
+        Ok(reloaded)
     }
 
     fn lang_id(&self) -> &LanguageIdentifier
@@ -189,6 +207,9 @@ impl TextLocalization
 ///
 /// Is *not* emitted if `TextLocalizer` updates due to a [`LanguagesNegotiated`] event. Use the [`RelocalizeApp`]
 /// event instead for that case.
+///
+/// Is also *not* emitted when already-loaded localization files are hot reloaded (e.g. when editing a `.ftl` file
+/// with the `hot_reload` feature). [`RelocalizeApp`] is emitted instead so all localized content is refreshed.
 pub struct TextLocalizerLoaded;
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -235,11 +256,46 @@ impl TextLocalizer
     ///
     /// Always returns `None` if `self.is_loading()` is true.
     pub fn localize(&self, template: &str, target: &mut String) -> Option<&LanguageIdentifier>
+    {
+        self.localize_with_args(template, &TextArgs::default(), target)
+    }
+
+    /// Localizes a string containing a localization template, with additional structured args.
+    ///
+    /// Structured args are passed to `fluent` after any args in the template, overwriting template args with the
+    /// same keys.
+    ///
+    /// See [`Self::localize`].
+    pub fn localize_with_args(
+        &self,
+        template: &str,
+        args: &TextArgs,
+        target: &mut String,
+    ) -> Option<&LanguageIdentifier>
     {
         if self.is_loading() {
             return None;
         }
 
+        if !args.is_empty() {
+            let mut request = Request::<FluentArgs>::from(template);
+            let mut fluent_args = request.args.take().unwrap_or_default();
+            args.add_to_fluent(&mut fluent_args);
+            let request = Request { id: request.id, attr: request.attr, args: Some(&fluent_args) };
+
+            return self
+                .localizations
+                .iter()
+                .filter_map(TextLocalization::asset)
+                .find_map(|(lang, bundle)| {
+                    if set_content(bundle, request, template, target) {
+                        Some(lang)
+                    } else {
+                        None
+                    }
+                });
+        }
+
         self.localizations
             .iter()
             .filter_map(TextLocalization::asset)
@@ -320,11 +376,12 @@ impl TextLocalizer
         removed_id
     }
 
-    fn try_set(&mut self, id: AssetId<FtlBundle>, mut asset: FtlBundle) -> bool
+    /// Returns `Some(true)` if an existing localization was hot reloaded, and `None` if the asset is stale.
+    fn try_set(&mut self, id: AssetId<FtlBundle>, mut asset: FtlBundle) -> Option<bool>
     {
         for localization in self.localizations.iter_mut() {
             match localization.try_set(&id, asset) {
-                Ok(()) => return true,
+                Ok(reloaded) => return Some(reloaded),
                 Err(returned_asset) => {
                     asset = returned_asset;
                 }
@@ -333,7 +390,7 @@ impl TextLocalizer
 
         // This shouldn't print unless the user is very rapidly changing language settings.
         tracing::warn!("ignoring stale localization bundle {:?}", asset.locale().to_string());
-        false
+        None
     }
 }
 
@@ -0,0 +1,327 @@
+use bevy::ecs::component::Tick;
+use bevy::ecs::system::SystemChangeTick;
+use bevy::prelude::*;
+use fluent_langneg::LanguageIdentifier;
+use smol_str::SmolStr;
+
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Parses a markup tag body (the text between `<` and `>`).
+fn parse_markup_tag(tag: &str) -> Option<MarkupTag>
+{
+    match tag {
+        "b" => return Some(MarkupTag::Open(MarkupStyle::Bold)),
+        "i" => return Some(MarkupTag::Open(MarkupStyle::Italic)),
+        "/b" => return Some(MarkupTag::Close(MarkupKind::Bold)),
+        "/i" => return Some(MarkupTag::Close(MarkupKind::Italic)),
+        "/color" => return Some(MarkupTag::Close(MarkupKind::Color)),
+        "/size" => return Some(MarkupTag::Close(MarkupKind::Size)),
+        "/link" => return Some(MarkupTag::Close(MarkupKind::Link)),
+        _ => (),
+    }
+
+    if let Some(hex) = tag.strip_prefix("color=") {
+        let color = Srgba::hex(hex).ok()?;
+        return Some(MarkupTag::Open(MarkupStyle::Color(color.into())));
+    }
+    if let Some(size) = tag.strip_prefix("size=") {
+        let size = size.parse::<f32>().ok().filter(|s| *s > 0.)?;
+        return Some(MarkupTag::Open(MarkupStyle::Size(size)));
+    }
+    if let Some(id) = tag.strip_prefix("link id=") {
+        let id = id.strip_prefix('"').and_then(|id| id.strip_suffix('"')).unwrap_or(id);
+        if id.is_empty() || id.contains('"') {
+            return None;
+        }
+        return Some(MarkupTag::Open(MarkupStyle::Link(SmolStr::from(id))));
+    }
+
+    None
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Gets the font for a markup segment.
+fn get_markup_font(
+    fonts: &FontMap,
+    main_font: &Handle<Font>,
+    localized_font: &Handle<Font>,
+    lang: Option<&LanguageIdentifier>,
+    segment: &TextMarkupSegment,
+) -> Handle<Font>
+{
+    if !segment.bold && !segment.italic {
+        return localized_font.clone();
+    }
+    let Some(mut request) = fonts.get_request(main_font.id()) else {
+        tracing::warn!("failed getting bold/italic font for text markup; main font {:?} is not a registered font",
+            main_font.path());
+        return localized_font.clone();
+    };
+    if segment.bold {
+        request.weight = FontWeight::Bold;
+    }
+    if segment.italic {
+        request.style = FontStyle::Italic;
+    }
+    let font = fonts.get(&request);
+    lang.and_then(|lang| fonts.get_localized(lang, font.id()))
+        .unwrap_or(font)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Converts markup in localized text into text spans.
+pub(crate) fn apply_text_markup(
+    mut c: Commands,
+    ticks: SystemChangeTick,
+    fonts: Res<FontMap>,
+    mut texts: Query<
+        (
+            Entity,
+            Mut<Text>,
+            &TextFont,
+            &TextColor,
+            &LocalizedText,
+            Option<&mut TextMarkupSpans>,
+        ),
+        Changed<Text>,
+    >,
+)
+{
+    for (entity, mut text, text_font, text_color, localized, markup_spans) in texts.iter_mut() {
+        // Ignore our own changes.
+        if markup_spans
+            .as_ref()
+            .is_some_and(|m| m.last_write == text.last_changed())
+        {
+            continue;
+        }
+
+        // Clean up previous spans.
+        if let Some(mut markup_spans) = markup_spans {
+            for span in markup_spans.spans.drain(..) {
+                c.entity(span).despawn_recursive();
+            }
+        }
+
+        let Some(segments) = parse_text_markup(text.as_str()) else { continue };
+
+        // The first unstyled segment stays in the root span so the root's font and color are preserved.
+        let mut segments = segments.into_iter().peekable();
+        let root_text = segments
+            .next_if(|s| s.is_plain())
+            .map(|s| s.text)
+            .unwrap_or_default();
+
+        let loc_span = localized.localization();
+        let main_font = loc_span
+            .font_backup()
+            .clone()
+            .unwrap_or_else(|| text_font.font.clone());
+        let lang = loc_span.lang().as_ref();
+        let spans: Vec<Entity> = segments
+            .map(|segment| {
+                let font = get_markup_font(&fonts, &main_font, &text_font.font, lang, &segment);
+                let mut span = c.spawn((
+                    TextSpan(segment.text),
+                    TextFont {
+                        font,
+                        font_size: segment.size.unwrap_or(text_font.font_size),
+                        ..text_font.clone()
+                    },
+                    TextColor(segment.color.unwrap_or(text_color.0)),
+                    TextMarkupSpan,
+                ));
+                if let Some(link) = segment.link {
+                    span.insert(TextLink { id: link });
+                }
+                span.id()
+            })
+            .collect();
+        c.entity(entity).insert_children(0, &spans);
+
+        // Write the root text.
+        text.0 = root_text;
+        c.entity(entity)
+            .insert(TextMarkupSpans { spans, last_write: ticks.this_run() });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq)]
+enum MarkupStyle
+{
+    Bold,
+    Italic,
+    Color(Color),
+    Size(f32),
+    Link(SmolStr),
+}
+
+impl MarkupStyle
+{
+    fn kind(&self) -> MarkupKind
+    {
+        match self {
+            Self::Bold => MarkupKind::Bold,
+            Self::Italic => MarkupKind::Italic,
+            Self::Color(_) => MarkupKind::Color,
+            Self::Size(_) => MarkupKind::Size,
+            Self::Link(_) => MarkupKind::Link,
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum MarkupKind
+{
+    Bold,
+    Italic,
+    Color,
+    Size,
+    Link,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq)]
+enum MarkupTag
+{
+    Open(MarkupStyle),
+    Close(MarkupKind),
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Records markup spans spawned for a [`LocalizedText`] entity.
+#[derive(Component, Debug)]
+pub(crate) struct TextMarkupSpans
+{
+    spans: Vec<Entity>,
+    /// Tick when the root text was last written by markup processing.
+    last_write: Tick,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Marker component for text spans generated from text markup.
+///
+/// See [`parse_text_markup`].
+#[derive(Component, Debug)]
+pub struct TextMarkupSpan;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A segment of text with a markup style.
+///
+/// See [`parse_text_markup`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TextMarkupSegment
+{
+    pub text: String,
+    pub bold: bool,
+    pub italic: bool,
+    pub color: Option<Color>,
+    pub size: Option<f32>,
+    /// See [`TextLink`].
+    pub link: Option<SmolStr>,
+}
+
+impl TextMarkupSegment
+{
+    /// Returns `true` if the segment has no style.
+    pub fn is_plain(&self) -> bool
+    {
+        !self.bold && !self.italic && self.color.is_none() && self.size.is_none() && self.link.is_none()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Parses rich-text markup into styled segments.
+///
+/// Supported tags:
+/// - `<b>..</b>`: bold
+/// - `<i>..</i>`: italic
+/// - `<color=#FF0000>..</color>`: color in hex (`RGB`, `RGBA`, `RRGGBB`, or `RRGGBBAA`)
+/// - `<size=20>..</size>`: font size
+/// - `<link id="wiki">..</link>`: hyperlink (see [`TextLink`])
+///
+/// Tags can be nested. Unrecognized or malformed tags are kept as literal text, and closing tags without
+/// matching opening tags are ignored.
+///
+/// Returns `None` if the text has no markup tags.
+///
+/// When a [`LocalizedText`] entity's text is localized to a string with markup, the markup is automatically
+/// converted into [`TextSpans`](TextSpan) (marked with [`TextMarkupSpan`]). Leading unstyled text stays in the
+/// root [`Text`]. Link segments get [`TextLink`] components. Bold and italic fonts are looked up in [`FontMap`] from the span's main font. Markup is only
+/// supported in text blocks that don't have other spans.
+pub fn parse_text_markup(text: &str) -> Option<Vec<TextMarkupSegment>>
+{
+    let mut segments = vec![];
+    let mut styles: Vec<MarkupStyle> = vec![];
+    let mut current = String::default();
+    let mut found_tag = false;
+    let mut remaining = text;
+
+    while let Some(start) = remaining.find('<') {
+        let Some(end) = remaining[start..].find('>').map(|e| e + start) else { break };
+        let Some(tag) = parse_markup_tag(&remaining[(start + 1)..end]) else {
+            // Keep unknown tags as literal text.
+            current.push_str(&remaining[..=start]);
+            remaining = &remaining[(start + 1)..];
+            continue;
+        };
+        found_tag = true;
+        current.push_str(&remaining[..start]);
+        remaining = &remaining[(end + 1)..];
+
+        // Flush text written with the previous styles.
+        if !current.is_empty() {
+            segments.push(make_markup_segment(std::mem::take(&mut current), &styles));
+        }
+
+        match tag {
+            MarkupTag::Open(style) => styles.push(style),
+            MarkupTag::Close(kind) => {
+                if let Some(pos) = styles.iter().rposition(|s| s.kind() == kind) {
+                    styles.remove(pos);
+                }
+            }
+        }
+    }
+
+    if !found_tag {
+        return None;
+    }
+
+    current.push_str(remaining);
+    if !current.is_empty() {
+        segments.push(make_markup_segment(current, &styles));
+    }
+
+    Some(segments)
+}
+
+fn make_markup_segment(text: String, styles: &[MarkupStyle]) -> TextMarkupSegment
+{
+    let mut segment = TextMarkupSegment { text, ..default() };
+    for style in styles {
+        match style {
+            MarkupStyle::Bold => segment.bold = true,
+            MarkupStyle::Italic => segment.italic = true,
+            MarkupStyle::Color(color) => segment.color = Some(*color),
+            MarkupStyle::Size(size) => segment.size = Some(*size),
+            MarkupStyle::Link(id) => segment.link = Some(id.clone()),
+        }
+    }
+    segment
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -10,6 +10,22 @@ use crate::prelude::*;
 /// Plugin that sets up `bevy_cobweb_ui` in an app.
 pub struct CobwebUiPlugin;
 
+impl CobwebUiPlugin
+{
+    /// Makes the plugin with [`CobStrictMode`] enabled.
+    ///
+    /// Example:
+    /**
+    ```rust
+    app.add_plugins(CobwebUiPlugin::strict());
+    ```
+    */
+    pub fn strict() -> (Self, CobStrictModePlugin)
+    {
+        (Self, CobStrictModePlugin)
+    }
+}
+
 impl Plugin for CobwebUiPlugin
 {
     fn build(&self, app: &mut App)
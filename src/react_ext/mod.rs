@@ -1,7 +1,9 @@
 mod plugin;
 mod reactor_ext;
+mod timers;
 mod utils;
 
 pub(crate) use plugin::*;
 pub use reactor_ext::*;
+pub use timers::*;
 pub use utils::*;
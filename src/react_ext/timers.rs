@@ -0,0 +1,184 @@
+use std::time::Duration;
+
+use bevy::ecs::system::EntityCommand;
+use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
+use smol_str::SmolStr;
+
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn tick_repeating_timers(
+    mut c: Commands,
+    time: Res<Time>,
+    mut timers: Query<(Entity, &RepeatingTimer, &mut RepeatingTimerState)>,
+)
+{
+    for (entity, timer, mut state) in timers.iter_mut() {
+        state.0.tick(time.delta());
+        for _ in 0..state.0.times_finished_this_tick() {
+            c.react()
+                .entity_event(entity, TimerElapsed { name: SmolStr::from(timer.event.as_str()) });
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn tick_one_shot_timers(
+    mut c: Commands,
+    time: Res<Time>,
+    mut timers: Query<(Entity, &OneShotTimer, &mut OneShotTimerState)>,
+)
+{
+    for (entity, timer, mut state) in timers.iter_mut() {
+        if state.0.finished() {
+            continue;
+        }
+        state.0.tick(time.delta());
+        if state.0.just_finished() {
+            c.react()
+                .entity_event(entity, TimerElapsed { name: SmolStr::from(timer.event.as_str()) });
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Component, Debug)]
+struct RepeatingTimerState(Timer);
+
+#[derive(Component, Debug)]
+struct OneShotTimerState(Timer);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Entity event emitted by [`RepeatingTimer`] and [`OneShotTimer`].
+#[derive(Debug, Clone)]
+pub struct TimerElapsed
+{
+    /// The timer's `event` name.
+    pub name: SmolStr,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction that emits a [`TimerElapsed`] entity event every `period_ms` milliseconds.
+///
+/// Timers use [`Time`], so they are paused when virtual time is paused. Re-applying the instruction restarts
+/// the timer.
+#[derive(Component, Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct RepeatingTimer
+{
+    /// Time between events.
+    pub period_ms: u64,
+    /// Name included in [`TimerElapsed`] events, for distinguishing between timers.
+    #[reflect(default)]
+    pub event: String,
+}
+
+impl Instruction for RepeatingTimer
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        let timer = Timer::new(Duration::from_millis(self.period_ms.max(1)), TimerMode::Repeating);
+        emut.insert((self, RepeatingTimerState(timer)));
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.remove::<(Self, RepeatingTimerState)>();
+        });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction that emits a [`TimerElapsed`] entity event once after `delay_ms` milliseconds.
+///
+/// Use [`ResetTimers`] to start the timer again. Re-applying the instruction also restarts the timer.
+#[derive(Component, Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct OneShotTimer
+{
+    /// Time until the event is emitted.
+    pub delay_ms: u64,
+    /// Name included in the [`TimerElapsed`] event, for distinguishing between timers.
+    #[reflect(default)]
+    pub event: String,
+}
+
+impl Instruction for OneShotTimer
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        let timer = Timer::new(Duration::from_millis(self.delay_ms), TimerMode::Once);
+        emut.insert((self, OneShotTimerState(timer)));
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.remove::<(Self, OneShotTimerState)>();
+        });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Entity command that restarts the entity's [`RepeatingTimer`] and [`OneShotTimer`].
+///
+/// Useful for delaying an event while something keeps happening, e.g. hiding a scrollbar after scrolling stops.
+pub struct ResetTimers;
+
+impl EntityCommand for ResetTimers
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        if let Some(mut state) = emut.get_mut::<RepeatingTimerState>() {
+            state.0.reset();
+        }
+        if let Some(mut state) = emut.get_mut::<OneShotTimerState>() {
+            state.0.reset();
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// System set in `Update` where [`RepeatingTimer`] and [`OneShotTimer`] are ticked.
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
+pub struct SceneTimersUpdateSet;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct SceneTimersPlugin;
+
+impl Plugin for SceneTimersPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.register_instruction_type::<RepeatingTimer>()
+            .register_instruction_type::<OneShotTimer>()
+            .add_systems(
+                Update,
+                (tick_repeating_timers, tick_one_shot_timers).in_set(SceneTimersUpdateSet),
+            );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -1,14 +1,25 @@
 use bevy::prelude::*;
 use bevy_cobweb::prelude::*;
 
+use crate::prelude::*;
+
 //-------------------------------------------------------------------------------------------------------------------
 
 /// Despawns the `token`'s reactor when `entity` is despawned.
+///
+/// The reactor is counted in [`CobwebUiMetrics::reactors`] until it is despawned.
 pub fn cleanup_reactor_on_despawn(c: &mut Commands, entity: Entity, token: RevokeToken)
 {
-    c.react().on(despawn(entity), move |mut c: Commands| {
-        c.react().revoke(token.clone());
+    c.queue(|world: &mut World| {
+        let Some(mut metrics) = world.get_resource_mut::<CobwebUiMetrics>() else { return };
+        metrics.bypass_change_detection().update_reactor_count(true);
     });
+    c.react()
+        .on(despawn(entity), move |mut c: Commands, metrics: Option<ResMut<CobwebUiMetrics>>| {
+            c.react().revoke(token.clone());
+            let Some(mut metrics) = metrics else { return };
+            metrics.bypass_change_detection().update_reactor_count(false);
+        });
 }
 
 //-------------------------------------------------------------------------------------------------------------------
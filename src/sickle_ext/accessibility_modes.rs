@@ -0,0 +1,94 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+use crate::sickle::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn sync_high_contrast(
+    high_contrast: Res<HighContrast>,
+    mut cob_cache: ResMut<CobAssetCache>,
+    commands_buffer: Option<ResMut<CommandsBuffer>>,
+)
+{
+    cob_cache.set_high_contrast(high_contrast.0, commands_buffer.map(|b| b.into_inner()));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource that controls whether theme tokens resolve their high-contrast variant.
+///
+/// When enabled, `ThemeToken("name")` values use the token's `high_contrast` variant (see
+/// [`HIGH_CONTRAST_THEME`]) if it has one, instead of the variant for the active theme.
+///
+/// Scene loadables that use theme tokens are reapplied when this changes. See [`SetActiveTheme`].
+#[derive(Resource, Default, Debug, Copy, Clone, PartialEq, Eq)]
+pub struct HighContrast(pub bool);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Loadable command for setting the [`HighContrast`] resource.
+///
+/// Example:
+/**
+```rust
+#theme
+$text = {light:#333333 dark:#CCCCCC high_contrast:#FFFFFF}
+
+#commands
+SetHighContrast(true)
+```
+*/
+#[derive(Reflect, Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SetHighContrast(pub bool);
+
+impl Command for SetHighContrast
+{
+    fn apply(self, world: &mut World)
+    {
+        world
+            .resource_mut::<HighContrast>()
+            .set_if_neq(HighContrast(self.0));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Loadable command for setting the [`ReducedMotion`] resource.
+///
+/// When reduced motion is enabled, `Animated` attributes snap to their end values instead of tweening.
+#[derive(Reflect, Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SetReducedMotion(pub bool);
+
+impl Command for SetReducedMotion
+{
+    fn apply(self, world: &mut World)
+    {
+        world
+            .resource_mut::<ReducedMotion>()
+            .set_if_neq(ReducedMotion(self.0));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct AccessibilityModesPlugin;
+
+impl Plugin for AccessibilityModesPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_resource::<HighContrast>()
+            .register_command_type::<SetHighContrast>()
+            .register_command_type::<SetReducedMotion>()
+            .add_systems(
+                First,
+                sync_high_contrast
+                    .run_if(resource_changed::<HighContrast>)
+                    .before(FileProcessingSet),
+            );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,190 @@
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use crate::prelude::*;
+use crate::sickle::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn breakpoint_state(name: &str) -> PseudoState
+{
+    PseudoState::Custom(name.into())
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn update_breakpoints(
+    mut c: Commands,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    nodes: Query<&ComputedNode>,
+    breakpoints: Query<(Entity, &Breakpoints, Option<&Parent>, Option<&PseudoStates>)>,
+)
+{
+    let window_width = windows.get_single().ok().map(|w| w.width());
+
+    for (entity, breakpoints, maybe_parent, maybe_states) in breakpoints.iter() {
+        let width = match breakpoints.source {
+            BreakpointSource::Window => window_width,
+            BreakpointSource::Parent => maybe_parent
+                .and_then(|p| nodes.get(p.get()).ok())
+                .map(|n| n.size().x * n.inverse_scale_factor()),
+        };
+        let Some(width) = width else { continue };
+
+        for breakpoint in breakpoints.breakpoints.iter() {
+            let state = breakpoint_state(breakpoint.name.as_str());
+            let is_active = maybe_states.is_some_and(|s| s.has(&state));
+            match (breakpoint.contains(width), is_active) {
+                (true, false) => {
+                    c.entity(entity).add_pseudo_state(state);
+                }
+                (false, true) => {
+                    c.entity(entity).remove_pseudo_state(state);
+                }
+                _ => (),
+            }
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// The width measured by [`Breakpoints`].
+#[derive(Reflect, Default, Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum BreakpointSource
+{
+    /// The logical width of the primary window.
+    #[default]
+    Window,
+    /// The logical width of the entity's parent node.
+    Parent,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A named width range for [`Breakpoints`].
+#[derive(Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct Breakpoint
+{
+    /// Name of the breakpoint's [`PseudoState::Custom`] state.
+    pub name: String,
+    /// Minimum width of the range in logical pixels (inclusive).
+    ///
+    /// Defaults to no minimum.
+    #[reflect(default)]
+    pub min_width: Option<f32>,
+    /// Maximum width of the range in logical pixels (exclusive).
+    ///
+    /// Defaults to no maximum.
+    #[reflect(default)]
+    pub max_width: Option<f32>,
+}
+
+impl Breakpoint
+{
+    /// Returns `true` if `width` is in the breakpoint's range.
+    pub fn contains(&self, width: f32) -> bool
+    {
+        self.min_width.is_none_or(|min| width >= min) && self.max_width.is_none_or(|max| width < max)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction that sets [`PseudoState::Custom`] states on an entity depending on the window or parent width.
+///
+/// Each [`Breakpoint`] whose range contains the current width has its state set. States are re-evaluated every
+/// tick, so they update when the window is resized.
+///
+/// Breakpoint states can be used like any other pseudo state. Place `Breakpoints` on a [`ControlRoot`] and add
+/// state-dependent attributes to the root and its members to override loadables at different sizes.
+///
+/// Example:
+/**
+```rust
+#scenes
+"menu"
+    ControlRoot
+    Breakpoints{breakpoints:[{name:"small" max_width:900} {name:"large" min_width:900}]}
+    Multi<Static<SetFlexDirection>>[{value:Row} {state:[Custom("small")] value:Column}]
+
+    "sidebar"
+        ControlMember
+        Multi<Static<Width>>[{value:300px} {state:[Custom("small")] value:100%}]
+```
+*/
+#[derive(Component, Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct Breakpoints
+{
+    /// The width to compare against breakpoints.
+    ///
+    /// Defaults to [`BreakpointSource::Window`].
+    #[reflect(default)]
+    pub source: BreakpointSource,
+    /// Breakpoint ranges. Ranges can overlap.
+    pub breakpoints: Vec<Breakpoint>,
+}
+
+impl Breakpoints
+{
+    /// Iterates the names of breakpoints that contain `width`.
+    pub fn active(&self, width: f32) -> impl Iterator<Item = &str> + '_
+    {
+        self.breakpoints
+            .iter()
+            .filter(move |b| b.contains(width))
+            .map(|b| b.name.as_str())
+    }
+}
+
+impl Instruction for Breakpoints
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        // Clean up states from the previous breakpoints in case they changed.
+        Self::revert(entity, world);
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        emut.insert(self);
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        let Some(breakpoints) = emut.take::<Breakpoints>() else { return };
+        for breakpoint in breakpoints.breakpoints.iter() {
+            emut.remove_pseudo_state(breakpoint_state(breakpoint.name.as_str()));
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct BreakpointsPlugin;
+
+impl Plugin for BreakpointsPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.register_type::<BreakpointSource>()
+            .register_type::<Breakpoint>()
+            .register_instruction_type::<Breakpoints>()
+            .add_systems(Update, update_breakpoints);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
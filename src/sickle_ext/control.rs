@@ -1,3 +1,4 @@
+use bevy::ecs::component::ComponentId;
 use bevy::prelude::*;
 use smol_str::SmolStr;
 
@@ -6,6 +7,13 @@ use crate::sickle::*;
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// The loadable priority of [`ControlRoot`] and [`ControlMember`].
+///
+/// See [`CobLoadableRegistrationAppExt::set_loadable_priority`].
+pub const CONTROL_LOADABLE_PRIORITY: i32 = 1000;
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Instruction loadable for setting up the root entity of a multi-entity widget.
 ///
 /// Applies a [`ControlMember`] instruction and inserts an internal `ControlMap` component to the entity.
@@ -13,8 +21,8 @@ use crate::sickle::*;
 /// Children of the root node can be accessed through their [`ControlMembers`](ControlMember) using
 /// [`ControlBuilderExt::edit_control_child`].
 ///
-/// It is recommended to apply this instruction before `Static`/`Responsive`/`Animated` instructions for optimal
-/// performance.
+/// This instruction is applied before other loadables on the same node (including `Static`/`Responsive`/`Animated`
+/// attributes), regardless of where it is written in the node. See [`CONTROL_LOADABLE_PRIORITY`].
 #[derive(Reflect, Default, Clone, Debug, Eq, PartialEq)]
 pub struct ControlRoot;
 
@@ -109,8 +117,8 @@ impl Instruction for ControlRoot
 /// Values in a multi-entity widget can be controlled with the [`Static`], [`Responsive`], and [`Animated`]
 /// loadables.
 ///
-/// It is recommended to apply this instruction before `Static`/`Responsive`/`Animated` instructions for optimal
-/// performance.
+/// This instruction is applied before other loadables on the same node (including `Static`/`Responsive`/`Animated`
+/// attributes), regardless of where it is written in the node. See [`CONTROL_LOADABLE_PRIORITY`].
 #[derive(Component, Reflect, Default, Clone, Debug, Deref, DerefMut, Eq, PartialEq)]
 pub struct ControlMember
 {
@@ -216,14 +224,151 @@ impl Instruction for ControlMember
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Selects a family of members in a control group.
+///
+/// Used by [`Static::targets`], [`Responsive::targets`], and [`Animated::targets`] to apply one attribute to
+/// many members (e.g. dynamically spawned children) without naming each member.
+///
+/// Example:
+/**
+```rust
+#scenes
+"inventory"
+    ControlRoot
+    Responsive<BackgroundColor>{targets:Glob("slot_*") idle:#222222 hover:#444444}
+    Static<BorderColor>{targets:Marker("InventorySlot") value:#888888}
+```
+*/
+#[derive(Reflect, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum ControlSelector
+{
+    /// Selects members whose [`ControlMember::id`] matches a glob pattern.
+    ///
+    /// `*` matches any sequence of characters (including none). For example, `"item_*"` matches `"item_0"` and
+    /// `"item_12"`.
+    Glob(SmolStr),
+    /// Selects members that have a component, identified by its short type path (e.g. `"InventorySlot"`).
+    ///
+    /// The component type must be registered with `App::register_type`.
+    ///
+    /// Members are selected when the control group refreshes its styles, so the component should be inserted
+    /// before or alongside the member's [`ControlMember`]. If it is inserted later, then the member won't be
+    /// selected until the group refreshes again (e.g. when the group's pseudo states change).
+    Marker(SmolStr),
+}
+
+impl ControlSelector
+{
+    /// Returns `true` if the control group member `entity` with ID `id` is selected.
+    ///
+    /// Use [`Self::matcher`] when matching many members.
+    pub fn matches(&self, id: &str, entity: Entity, world: &World) -> bool
+    {
+        self.matcher(world).matches(id, entity, world)
+    }
+
+    /// Prepares the selector for matching members.
+    ///
+    /// For [`Self::Marker`], this looks up the marker component once.
+    pub fn matcher(&self, world: &World) -> ControlSelectorMatcher<'_>
+    {
+        match self {
+            Self::Glob(pattern) => ControlSelectorMatcher::Glob(pattern.as_bytes()),
+            Self::Marker(marker) => {
+                let Some(registry) = world.get_resource::<AppTypeRegistry>() else {
+                    return ControlSelectorMatcher::Marker(None);
+                };
+                let registry = registry.read();
+                let Some(registration) = registry.get_with_short_type_path(marker.as_str()) else {
+                    warn_once!("failed selecting control group members with Marker({marker}); the type is not \
+                        registered or its short type path is ambiguous");
+                    return ControlSelectorMatcher::Marker(None);
+                };
+                ControlSelectorMatcher::Marker(world.components().get_id(registration.type_id()))
+            }
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A [`ControlSelector`] prepared for matching control group members.
+///
+/// See [`ControlSelector::matcher`].
+#[derive(Debug, Copy, Clone)]
+pub enum ControlSelectorMatcher<'a>
+{
+    /// See [`ControlSelector::Glob`].
+    Glob(&'a [u8]),
+    /// See [`ControlSelector::Marker`].
+    ///
+    /// `None` if the marker component is unknown, in which case no members are selected.
+    Marker(Option<ComponentId>),
+}
+
+impl ControlSelectorMatcher<'_>
+{
+    /// Returns `true` if the control group member `entity` with ID `id` is selected.
+    pub fn matches(&self, id: &str, entity: Entity, world: &World) -> bool
+    {
+        match self {
+            Self::Glob(pattern) => glob_matches(pattern, id.as_bytes()),
+            Self::Marker(component_id) => {
+                let Some(component_id) = component_id else { return false };
+                world
+                    .get_entity(entity)
+                    .is_ok_and(|e| e.contains_id(*component_id))
+            }
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn glob_matches(pattern: &[u8], text: &[u8]) -> bool
+{
+    // Backtrack to the most recent `*` on mismatch.
+    let (mut p, mut t) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some(b'*') => {
+                star = Some((p, t));
+                p += 1;
+            }
+            Some(c) if *c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => {
+                let Some((star_p, star_t)) = star else { return false };
+                star = Some((star_p, star_t + 1));
+                p = star_p + 1;
+                t = star_t + 1;
+            }
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == b'*')
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 pub(crate) struct ControlPlugin;
 
 impl Plugin for ControlPlugin
 {
     fn build(&self, app: &mut App)
     {
-        app.register_instruction_type::<ControlRoot>()
-            .register_instruction_type::<ControlMember>();
+        app.register_instruction_type_with_priority::<ControlRoot>(CONTROL_LOADABLE_PRIORITY)
+            .register_instruction_type_with_priority::<ControlMember>(CONTROL_LOADABLE_PRIORITY)
+            .register_type::<ControlSelector>();
     }
 }
 
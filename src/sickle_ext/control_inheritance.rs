@@ -0,0 +1,219 @@
+use bevy::prelude::*;
+
+use super::*;
+use crate::prelude::*;
+use crate::sickle::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Finds the root entity of the nearest non-anonymous control group above `entity`.
+fn find_parent_control_root(
+    entity: Entity,
+    parents: &Query<&Parent>,
+    maps: &Query<&mut ControlMap>,
+) -> Option<Entity>
+{
+    parents
+        .iter_ancestors(entity)
+        .find(|ancestor| maps.get(*ancestor).is_ok_and(|m| !m.is_anonymous()))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// See [`find_parent_control_root`].
+fn find_parent_control_root_in_world(world: &World, entity: Entity) -> Option<Entity>
+{
+    let mut current = entity;
+    loop {
+        current = world.get::<Parent>(current)?.get();
+        if world.get::<ControlMap>(current).is_some_and(|m| !m.is_anonymous()) {
+            return Some(current);
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Adds pseudo states inherited from parent control groups to `states`.
+///
+/// `states` should contain the entity's own pseudo states.
+pub(crate) fn collect_inherited_pseudo_states(world: &World, entity: Entity, states: &mut Vec<PseudoState>)
+{
+    let mut current = entity;
+    while let Some(inherit) = world.get::<InheritControl>(current) {
+        let Some(parent_root) = find_parent_control_root_in_world(world, current) else { break };
+
+        if let Some(parent_states) = world.get::<PseudoStates>(parent_root) {
+            for state in parent_states.get() {
+                if !inherit.inherits(state) || states.contains(state) {
+                    continue;
+                }
+                // A disabled parent group disables its descendants.
+                if *state == PseudoState::Disabled {
+                    states.retain(|s| *s != PseudoState::Enabled);
+                }
+                states.push(state.clone());
+            }
+        }
+
+        current = parent_root;
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Marks control maps that inherit from parent groups as changed when their parents' pseudo states change, or
+/// when they are moved to a new parent.
+pub(crate) fn refresh_inheriting_control_maps(
+    mut changed: Local<Vec<Entity>>,
+    mut inheriting: Local<Vec<(Entity, Entity)>>,
+    changed_states: Query<Entity, (With<ControlMap>, Changed<PseudoStates>)>,
+    reparented: Query<(), Changed<Parent>>,
+    inheritors: Query<Entity, (With<InheritControl>, With<ControlMap>)>,
+    parents: Query<&Parent>,
+    mut maps: Query<&mut ControlMap>,
+)
+{
+    if inheritors.is_empty() {
+        return;
+    }
+    if changed_states.is_empty() && reparented.is_empty() {
+        return;
+    }
+
+    changed.clear();
+    changed.extend(changed_states.iter());
+    let num_roots = changed.len();
+
+    // Collect [ inheriting entity : parent root ].
+    inheriting.clear();
+    inheriting.extend(
+        inheritors
+            .iter()
+            .filter_map(|e| find_parent_control_root(e, &parents, &maps).map(|p| (e, p))),
+    );
+
+    // Groups that were moved (or whose ancestors were moved) may have a new parent group.
+    if !reparented.is_empty() {
+        inheriting.retain(|(entity, _)| {
+            let moved = std::iter::once(*entity)
+                .chain(parents.iter_ancestors(*entity))
+                .any(|e| reparented.contains(e));
+            if moved {
+                changed.push(*entity);
+            }
+            !moved
+        });
+    }
+
+    // Propagate changes down chains of inheriting groups.
+    loop {
+        let num_changed = changed.len();
+        inheriting.retain(|(entity, parent_root)| {
+            if !changed.contains(parent_root) {
+                return true;
+            }
+            changed.push(*entity);
+            false
+        });
+        if changed.len() == num_changed {
+            break;
+        }
+    }
+
+    // Parent roots with changed states are already refreshed.
+    for entity in changed.drain(..).skip(num_roots) {
+        let Ok(mut map) = maps.get_mut(entity) else { continue };
+        map.set_changed();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction loadable that makes a control group inherit [`PseudoStates`](PseudoState) from the nearest parent
+/// control group.
+///
+/// Inherited states are added to the group's own states when selecting which `Static`/`Responsive`/`Animated`
+/// attributes are active. This lets nested widgets react to the state of the widget they are composed into
+/// without re-declaring those states at each level. If the parent group is [`PseudoState::Disabled`], then
+/// [`PseudoState::Enabled`] is removed from the inheriting group's states.
+///
+/// If the parent group also has `InheritControl`, then states are inherited through it from further ancestors.
+///
+/// Add this to the root of a control group (an entity with [`ControlRoot`] or an anonymous control group).
+///
+/// Example:
+/**
+```rust
+#scenes
+"row"
+    ControlRoot
+
+    "button"
+        ControlRoot
+        InheritControl{states:[Disabled Selected]}
+        Multi<Static<BackgroundColor>>[
+            {value:#444444}
+            {state:[Selected] value:#446644}
+            {state:[Disabled] value:#222222}
+        ]
+```
+*/
+#[derive(Component, Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct InheritControl
+{
+    /// The states to inherit from the parent group.
+    ///
+    /// If empty, then all of the parent group's states are inherited.
+    #[reflect(default)]
+    pub states: Vec<PseudoState>,
+}
+
+impl InheritControl
+{
+    /// Returns `true` if `state` is inherited.
+    pub fn inherits(&self, state: &PseudoState) -> bool
+    {
+        self.states.is_empty() || self.states.contains(state)
+    }
+}
+
+impl Instruction for InheritControl
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        emut.insert(self);
+        if let Some(mut map) = emut.get_mut::<ControlMap>() {
+            map.set_changed();
+        }
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        emut.remove::<Self>();
+        if let Some(mut map) = emut.get_mut::<ControlMap>() {
+            map.set_changed();
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct ControlInheritancePlugin;
+
+impl Plugin for ControlInheritancePlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.register_instruction_type::<InheritControl>();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
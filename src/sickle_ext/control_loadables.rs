@@ -95,6 +95,12 @@ pub struct Static<T: StaticAttribute>
     #[reflect(default)]
     pub state: Option<SmallVec<[PseudoState; 3]>>,
 
+    /// Selects other members of the control group to apply this attribute to instead of the current entity.
+    ///
+    /// Useful for controlling families of members from the [`ControlRoot`] entity. See [`ControlSelector`].
+    #[reflect(default)]
+    pub targets: Option<ControlSelector>,
+
     /// The value that will be applied to the entity with `T`.
     pub value: T::Value,
 }
@@ -106,7 +112,7 @@ impl<T: StaticAttribute> Instruction for Static<T>
         let Ok(mut emut) = world.get_entity_mut(entity) else { return };
 
         // Add attribute.
-        let attr = NodeAttribute::new_static::<T>(self.name, self.value);
+        let attr = NodeAttribute::new_static::<T>(self.name, self.value).with_targets(self.targets);
 
         if let Some(mut attrs) = emut.get_mut::<NodeAttributes>() {
             if let Some(_) = attrs.insert(self.state, attr) {
@@ -156,6 +162,12 @@ pub struct Responsive<T: ResponsiveAttribute>
     #[reflect(default)]
     pub state: Option<SmallVec<[PseudoState; 3]>>,
 
+    /// Selects other members of the control group to apply this attribute to instead of the current entity.
+    ///
+    /// Useful for controlling families of members from the [`ControlRoot`] entity. See [`ControlSelector`].
+    #[reflect(default)]
+    pub targets: Option<ControlSelector>,
+
     /// The [`ControlMember`] of a non-root entity in the current widget. This attribute responds to interactions
     /// on that entity.
     ///
@@ -197,7 +209,8 @@ impl<T: ResponsiveAttribute> Instruction for Responsive<T>
         let needs_interactive = emut.get::<ControlMember>().map(|m| &m.id) == self.respond_to.as_ref();
 
         // Add attribute.
-        let attr = NodeAttribute::new_responsive::<T>(self.name, self.respond_to, ref_vals);
+        let attr =
+            NodeAttribute::new_responsive::<T>(self.name, self.respond_to, ref_vals).with_targets(self.targets);
 
         if let Some(mut attrs) = emut.get_mut::<NodeAttributes>() {
             if let Some(_) = attrs.insert(self.state, attr) {
@@ -252,6 +265,12 @@ pub struct Animated<T: AnimatedAttribute>
     #[reflect(default)]
     pub state: Option<SmallVec<[PseudoState; 3]>>,
 
+    /// Selects other members of the control group to apply this attribute to instead of the current entity.
+    ///
+    /// Useful for controlling families of members from the [`ControlRoot`] entity. See [`ControlSelector`].
+    #[reflect(default)]
+    pub targets: Option<ControlSelector>,
+
     /// The [`ControlMember`] of a non-root entity in the current widget. Interactions on that entity will control
     /// this value.
     ///
@@ -366,7 +385,8 @@ impl<T: AnimatedAttribute> Instruction for Animated<T>
         let needs_interactive = emut.get::<ControlMember>().map(|m| &m.id) == self.respond_to.as_ref();
 
         // Add attribute.
-        let attr = NodeAttribute::new_animated::<T>(self.name, self.respond_to, ref_vals, settings);
+        let attr = NodeAttribute::new_animated::<T>(self.name, self.respond_to, ref_vals, settings)
+            .with_targets(self.targets);
 
         if let Some(mut attrs) = emut.get_mut::<NodeAttributes>() {
             if let Some(_) = attrs.insert(self.state, attr) {
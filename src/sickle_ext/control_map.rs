@@ -48,6 +48,7 @@ struct ControlRefreshCache
     dynamic_style_buffers: Vec<Vec<ContextStyleAttribute>>,
     recovered_dynamic_styles: Vec<DynamicStyle>,
     ps_buffer: PseudoThemeBuffer,
+    inherited_states: Vec<PseudoState>,
 }
 
 impl ControlRefreshCache
@@ -60,6 +61,7 @@ impl ControlRefreshCache
         dynamic_style_buffers: Vec<Vec<ContextStyleAttribute>>,
         recovered_dynamic_styles: Vec<DynamicStyle>,
         ps_buffer: PseudoThemeBuffer,
+        inherited_states: Vec<PseudoState>,
     )
     {
         self.collected_styles = collected_styles;
@@ -68,6 +70,7 @@ impl ControlRefreshCache
         self.dynamic_style_buffers = dynamic_style_buffers;
         self.recovered_dynamic_styles = recovered_dynamic_styles;
         self.ps_buffer = ps_buffer;
+        self.inherited_states = inherited_states;
     }
 }
 
@@ -179,7 +182,7 @@ impl ControlMap
         self.entities.retain(|(_, e)| entities.contains(*e));
     }
 
-    fn iter_entities(&self) -> impl Iterator<Item = &(SmolStr, Entity)> + '_
+    pub(crate) fn iter_entities(&self) -> impl Iterator<Item = &(SmolStr, Entity)> + '_
     {
         self.entities.iter()
     }
@@ -363,17 +366,26 @@ impl EntityCommand for RefreshControlledStyles
         let mut dynamic_style_buffers = std::mem::take(&mut cache.dynamic_style_buffers);
         let mut recovered_dynamic_styles = std::mem::take(&mut cache.recovered_dynamic_styles);
         let mut ps_buffer = std::mem::take(&mut cache.ps_buffer);
+        let mut inherited_states = std::mem::take(&mut cache.inherited_states);
         let mut pseudo_themes = ps_buffer.take();
 
         let control_map = world.get::<ControlMap>(entity).unwrap();
 
         // Get the entity's PseudoStates.
         let empty_pseudo_state = Vec::default();
-        let pseudo_states = world
+        let mut pseudo_states = world
             .get::<PseudoStates>(entity)
             .map(|p| p.get())
             .unwrap_or(&empty_pseudo_state);
 
+        // Add states inherited from parent control groups.
+        if world.get::<InheritControl>(entity).is_some() {
+            inherited_states.clear();
+            inherited_states.extend_from_slice(pseudo_states);
+            collect_inherited_pseudo_states(world, entity, &mut inherited_states);
+            pseudo_states = &inherited_states;
+        }
+
         // Collect eligible pseudo themes.
         // - We store an index for use during sorting to avoid hard-to-debug order inconsistencies.
         let mut idx = 0;
@@ -398,7 +410,7 @@ impl EntityCommand for RefreshControlledStyles
 
         for (_, _, label, pseudo_theme) in pseudo_themes.iter() {
             style_builder.clear();
-            pseudo_theme.build(label, &mut style_builder);
+            pseudo_theme.build(label, control_map, world, &mut style_builder);
             let styles_iter = style_builder
                 .convert_to_iter_with_buffers(control_map, || dynamic_style_buffers.pop().unwrap_or_default());
             collected_styles = styles_iter.fold(collected_styles, |mut collected, (placement, mut style)| {
@@ -486,6 +498,7 @@ impl EntityCommand for RefreshControlledStyles
             dynamic_style_buffers,
             recovered_dynamic_styles,
             ps_buffer,
+            inherited_states,
         );
     }
 }
@@ -518,6 +531,7 @@ impl Plugin for ControlMapPlugin
                 (
                     cleanup_control_maps,
                     handle_node_attr_changes,
+                    refresh_inheriting_control_maps,
                     refresh_controlled_styles,
                 )
                     .chain()
@@ -0,0 +1,116 @@
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
+
+use crate::prelude::*;
+use crate::sickle::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Component)]
+struct BubbleEventsReactor<T: Send + Sync + 'static>
+{
+    token: RevokeToken,
+    _p: PhantomData<T>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction loadable that forwards `T` entity events received by the entity to its parent.
+///
+/// Events are forwarded by sending a clone of the event to the parent entity. If the parent also has
+/// `BubbleEvents<T>`, then the event continues up the hierarchy. The event is consumed by the first ancestor
+/// without `BubbleEvents<T>`. This is useful for reacting to interactions on a scene's root when the interaction
+/// lands on an inner node (e.g. an icon inside a button).
+///
+/// Bubbling is opt-in per hop: each node that should pass events further up needs its own `BubbleEvents<T>`, and
+/// a node consumes the event by not having it. Events are not forwarded to a parent with
+/// [`PseudoState::Disabled`](crate::sickle::PseudoState::Disabled), matching how interaction events are not
+/// emitted for disabled entities.
+///
+/// Bubbling is registered for [`Pressed`], [`Released`], [`PointerEnter`], [`PointerLeave`], and
+/// [`PressCanceled`]. Other entity events can be bubbled by registering
+/// `app.register_instruction_type::<BubbleEvents<MyEvent>>()`.
+///
+/// Example:
+/**
+```rust
+#scenes
+"button"
+    Interactive
+    "icon"
+        Interactive
+        BubbleEvents<Pressed>
+```
+*/
+#[derive(Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BubbleEvents<T>
+where
+    T: Default + std::fmt::Debug + Clone + PartialEq + Send + Sync + 'static,
+{
+    #[cfg_attr(feature = "serde", serde(skip))]
+    #[reflect(ignore)]
+    _p: PhantomData<T>,
+}
+
+impl<T> Instruction for BubbleEvents<T>
+where
+    T: Default + TypePath + std::fmt::Debug + Clone + PartialEq + Send + Sync + 'static,
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let Ok(emut) = world.get_entity_mut(entity) else { return };
+        if emut.contains::<BubbleEventsReactor<T>>() {
+            return;
+        }
+
+        let token = world.react(|rc| {
+            rc.on_revokable(
+                entity_event::<T>(entity),
+                move |mut c: Commands,
+                      event: EntityEvent<T>,
+                      parents: Query<&Parent>,
+                      states: Query<&PseudoStates>| {
+                    let Ok(parent) = parents.get(entity) else { return };
+                    let parent = parent.get();
+                    if states.get(parent).is_ok_and(|s| s.has(&PseudoState::Disabled)) {
+                        return;
+                    }
+                    let (_, event) = event.read();
+                    c.react().entity_event(parent, event.clone());
+                },
+            )
+        });
+        world
+            .entity_mut(entity)
+            .insert(BubbleEventsReactor::<T> { token, _p: PhantomData });
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        if let Some(reactor) = emut.take::<BubbleEventsReactor<T>>() {
+            world.react(move |rc| rc.revoke(reactor.token));
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct EventBubblingPlugin;
+
+impl Plugin for EventBubblingPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.register_instruction_type::<BubbleEvents<Pressed>>()
+            .register_instruction_type::<BubbleEvents<Released>>()
+            .register_instruction_type::<BubbleEvents<PointerEnter>>()
+            .register_instruction_type::<BubbleEvents<PointerLeave>>()
+            .register_instruction_type::<BubbleEvents<PressCanceled>>();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,183 @@
+use std::time::Duration;
+
+use bevy::input::gamepad::{GamepadRumbleIntensity, GamepadRumbleRequest};
+use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
+
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn add_haptic_reactor<E: Send + Sync + 'static>(app: &mut App, trigger: HapticTrigger)
+{
+    app.add_reactor(
+        any_entity_event::<E>(),
+        move |event: EntityEvent<E>,
+              settings: Res<HapticSettings>,
+              feedback: Query<&HapticFeedback>,
+              gamepads: Query<Entity, With<Gamepad>>,
+              mut rumble: EventWriter<GamepadRumbleRequest>| {
+            if !settings.enabled {
+                return;
+            }
+            let Ok(feedback) = feedback.get(event.entity()) else { return };
+            if feedback.on != trigger {
+                return;
+            }
+            let intensity = feedback.intensity(&settings);
+            let duration = Duration::from_millis(feedback.duration_ms);
+            for gamepad in gamepads.iter() {
+                rumble.send(GamepadRumbleRequest::Add { gamepad, intensity, duration });
+            }
+        },
+    );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource for controlling [`HapticFeedback`] globally, e.g. from an accessibility setting.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct HapticSettings
+{
+    /// Defaults to `true`.
+    pub enabled: bool,
+    /// Multiplier applied to all rumble intensities.
+    ///
+    /// Defaults to `1.0`.
+    pub intensity_scale: f32,
+}
+
+impl Default for HapticSettings
+{
+    fn default() -> Self
+    {
+        Self { enabled: true, intensity_scale: 1. }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Interaction events that can trigger [`HapticFeedback`].
+#[derive(Reflect, Default, Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum HapticTrigger
+{
+    /// The entity received a [`Pressed`] entity event.
+    #[default]
+    Pressed,
+    /// The entity received a [`Released`] entity event.
+    Released,
+    /// The entity received a [`PointerEnter`] entity event.
+    PointerEnter,
+    /// The entity received a [`Select`] entity event.
+    Select,
+}
+
+impl HapticTrigger
+{
+    fn needs_interaction(&self) -> bool
+    {
+        !matches!(self, Self::Select)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction that rumbles all connected gamepads when the entity receives an interaction event.
+///
+/// Rumble is sent with [`GamepadRumbleRequest`], which requires the `bevy_gilrs` feature. Use [`HapticSettings`]
+/// to disable or scale rumble globally.
+///
+/// Also inserts [`Interactive`] if the trigger is an interaction.
+///
+/// Example:
+/**
+```rust
+#scenes
+"button"
+    HapticFeedback{on:Pressed strong_motor:0.6 duration_ms:80}
+```
+*/
+#[derive(Component, Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct HapticFeedback
+{
+    /// The event that triggers rumble.
+    ///
+    /// Defaults to [`HapticTrigger::Pressed`].
+    #[reflect(default)]
+    pub on: HapticTrigger,
+    /// Intensity of the low-frequency motor, between `0.0` and `1.0`.
+    ///
+    /// Defaults to `0.0`.
+    #[reflect(default)]
+    pub strong_motor: f32,
+    /// Intensity of the high-frequency motor, between `0.0` and `1.0`.
+    ///
+    /// Defaults to `0.0`.
+    #[reflect(default)]
+    pub weak_motor: f32,
+    /// How long the rumble lasts.
+    pub duration_ms: u64,
+}
+
+impl HapticFeedback
+{
+    /// Gets the rumble intensity after applying [`HapticSettings::intensity_scale`].
+    pub fn intensity(&self, settings: &HapticSettings) -> GamepadRumbleIntensity
+    {
+        let scale = settings.intensity_scale.max(0.);
+        GamepadRumbleIntensity {
+            strong_motor: (self.strong_motor * scale).clamp(0., 1.),
+            weak_motor: (self.weak_motor * scale).clamp(0., 1.),
+        }
+    }
+}
+
+impl Instruction for HapticFeedback
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        if self.on.needs_interaction() {
+            Interactive.apply(entity, world);
+        }
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        emut.insert(self);
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.remove::<Self>();
+        });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct HapticsPlugin;
+
+impl Plugin for HapticsPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_resource::<HapticSettings>()
+            .register_type::<HapticTrigger>()
+            .register_instruction_type::<HapticFeedback>();
+
+        add_haptic_reactor::<Pressed>(app, HapticTrigger::Pressed);
+        add_haptic_reactor::<Released>(app, HapticTrigger::Released);
+        add_haptic_reactor::<PointerEnter>(app, HapticTrigger::PointerEnter);
+        add_haptic_reactor::<Select>(app, HapticTrigger::Select);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,273 @@
+use bevy::prelude::*;
+
+#[cfg(feature = "widgets")]
+use crate::builtin::widgets::text_input::FocusedTextInput;
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn parse_key(key: &str) -> Option<KeyCode>
+{
+    let upper = key.to_ascii_uppercase();
+    let mut chars = upper.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        let code = match c {
+            'A' => KeyCode::KeyA,
+            'B' => KeyCode::KeyB,
+            'C' => KeyCode::KeyC,
+            'D' => KeyCode::KeyD,
+            'E' => KeyCode::KeyE,
+            'F' => KeyCode::KeyF,
+            'G' => KeyCode::KeyG,
+            'H' => KeyCode::KeyH,
+            'I' => KeyCode::KeyI,
+            'J' => KeyCode::KeyJ,
+            'K' => KeyCode::KeyK,
+            'L' => KeyCode::KeyL,
+            'M' => KeyCode::KeyM,
+            'N' => KeyCode::KeyN,
+            'O' => KeyCode::KeyO,
+            'P' => KeyCode::KeyP,
+            'Q' => KeyCode::KeyQ,
+            'R' => KeyCode::KeyR,
+            'S' => KeyCode::KeyS,
+            'T' => KeyCode::KeyT,
+            'U' => KeyCode::KeyU,
+            'V' => KeyCode::KeyV,
+            'W' => KeyCode::KeyW,
+            'X' => KeyCode::KeyX,
+            'Y' => KeyCode::KeyY,
+            'Z' => KeyCode::KeyZ,
+            '0' => KeyCode::Digit0,
+            '1' => KeyCode::Digit1,
+            '2' => KeyCode::Digit2,
+            '3' => KeyCode::Digit3,
+            '4' => KeyCode::Digit4,
+            '5' => KeyCode::Digit5,
+            '6' => KeyCode::Digit6,
+            '7' => KeyCode::Digit7,
+            '8' => KeyCode::Digit8,
+            '9' => KeyCode::Digit9,
+            '-' => KeyCode::Minus,
+            '=' => KeyCode::Equal,
+            ',' => KeyCode::Comma,
+            '.' => KeyCode::Period,
+            '/' => KeyCode::Slash,
+            ';' => KeyCode::Semicolon,
+            '[' => KeyCode::BracketLeft,
+            ']' => KeyCode::BracketRight,
+            '`' => KeyCode::Backquote,
+            _ => return None,
+        };
+        return Some(code);
+    }
+
+    let code = match upper.as_str() {
+        "ESC" | "ESCAPE" => KeyCode::Escape,
+        "ENTER" | "RETURN" => KeyCode::Enter,
+        "SPACE" => KeyCode::Space,
+        "TAB" => KeyCode::Tab,
+        "BACKSPACE" => KeyCode::Backspace,
+        "DEL" | "DELETE" => KeyCode::Delete,
+        "INSERT" => KeyCode::Insert,
+        "HOME" => KeyCode::Home,
+        "END" => KeyCode::End,
+        "PAGEUP" => KeyCode::PageUp,
+        "PAGEDOWN" => KeyCode::PageDown,
+        "UP" => KeyCode::ArrowUp,
+        "DOWN" => KeyCode::ArrowDown,
+        "LEFT" => KeyCode::ArrowLeft,
+        "RIGHT" => KeyCode::ArrowRight,
+        "F1" => KeyCode::F1,
+        "F2" => KeyCode::F2,
+        "F3" => KeyCode::F3,
+        "F4" => KeyCode::F4,
+        "F5" => KeyCode::F5,
+        "F6" => KeyCode::F6,
+        "F7" => KeyCode::F7,
+        "F8" => KeyCode::F8,
+        "F9" => KeyCode::F9,
+        "F10" => KeyCode::F10,
+        "F11" => KeyCode::F11,
+        "F12" => KeyCode::F12,
+        _ => return None,
+    };
+    Some(code)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn run_hotkeys(
+    mut c: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    #[cfg(feature = "widgets")] focused_input: Option<Res<FocusedTextInput>>,
+    blocking: InputBlockingParam,
+    hotkeys: Query<(Entity, &Hotkey, &HotkeyChord, Option<&InheritedVisibility>)>,
+    parents: Query<&Parent>,
+    names: Query<&Name>,
+)
+{
+    if keys.get_just_pressed().next().is_none() {
+        return;
+    }
+
+    #[cfg(feature = "widgets")]
+    let typing = focused_input.is_some_and(|f| f.get().is_some());
+    #[cfg(not(feature = "widgets"))]
+    let typing = false;
+
+    for (entity, hotkey, chord, visibility) in hotkeys.iter() {
+        if !chord.0.just_pressed(&keys) {
+            continue;
+        }
+        // Plain keys are reserved for text input while typing.
+        if typing && !chord.0.has_command_modifier() {
+            continue;
+        }
+        if visibility.is_some_and(|v| !v.get()) {
+            continue;
+        }
+        if !blocking.keyboard_allowed(entity) {
+            continue;
+        }
+        hotkey.1.run(&mut c, entity, &parents, &names);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Component, Debug)]
+struct HotkeyChord(KeyChord);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A key plus modifiers, e.g. `Ctrl+Shift+S`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct KeyChord
+{
+    pub ctrl: bool,
+    pub shift: bool,
+    pub alt: bool,
+    /// The platform 'command' key (e.g. Cmd on macOS, Windows key on Windows).
+    pub super_key: bool,
+    pub key: KeyCode,
+}
+
+impl KeyChord
+{
+    /// Parses a chord from `+`-separated key names.
+    ///
+    /// Modifiers are `Ctrl`/`Control`, `Shift`, `Alt`, and `Super`/`Cmd`/`Meta`. Keys are letters, digits,
+    /// common punctuation, `F1`-`F12`, and named keys like `Escape`, `Enter`, `Space`, `Tab`, `Delete`, `Up`,
+    /// `PageDown`. Names are case-insensitive.
+    ///
+    /// Returns `None` if the chord doesn't contain exactly one non-modifier key, or if a key is unknown.
+    pub fn parse(chord: &str) -> Option<Self>
+    {
+        let mut ctrl = false;
+        let mut shift = false;
+        let mut alt = false;
+        let mut super_key = false;
+        let mut key = None;
+
+        for part in chord.split('+').map(str::trim) {
+            match part.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => ctrl = true,
+                "shift" => shift = true,
+                "alt" => alt = true,
+                "super" | "cmd" | "meta" => super_key = true,
+                _ => {
+                    if key.is_some() {
+                        return None;
+                    }
+                    key = Some(parse_key(part)?);
+                }
+            }
+        }
+
+        Some(Self { ctrl, shift, alt, super_key, key: key? })
+    }
+
+    /// Returns `true` if the chord uses Ctrl, Alt, or Super.
+    pub fn has_command_modifier(&self) -> bool
+    {
+        self.ctrl || self.alt || self.super_key
+    }
+
+    /// Returns `true` if the chord's key was just pressed while exactly its modifiers are held.
+    pub fn just_pressed(&self, keys: &ButtonInput<KeyCode>) -> bool
+    {
+        keys.just_pressed(self.key)
+            && self.ctrl == keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight])
+            && self.shift == keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight])
+            && self.alt == keys.any_pressed([KeyCode::AltLeft, KeyCode::AltRight])
+            && self.super_key == keys.any_pressed([KeyCode::SuperLeft, KeyCode::SuperRight])
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction that runs an [`InteractionAction`] when a key chord is pressed.
+///
+/// See [`KeyChord::parse`] for the chord format. Actions run relative to the entity with the hotkey, so
+/// `EntityEvent("self" ..)` targets the hotkey entity.
+///
+/// Hotkeys are ignored when:
+/// - The entity is hidden.
+/// - Keyboard input is blocked for the entity by an [`InputRoot`].
+/// - A text input is focused and the chord doesn't use Ctrl, Alt, or Super.
+///
+/// Example:
+/**
+```rust
+#scenes
+"menu"
+    Hotkey("Escape" EntityEvent("self" Close))
+
+    "save_button"
+        Hotkey("Ctrl+S" Broadcast("save"))
+```
+*/
+#[derive(Component, Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct Hotkey(pub String, pub InteractionAction);
+
+impl Instruction for Hotkey
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let Some(chord) = KeyChord::parse(self.0.as_str()) else {
+            tracing::warn!("failed applying Hotkey to {entity:?}, {:?} is not a valid key chord", self.0);
+            Self::revert(entity, world);
+            return;
+        };
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        emut.insert((self, HotkeyChord(chord)));
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.remove::<(Self, HotkeyChord)>();
+        });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct HotkeysPlugin;
+
+impl Plugin for HotkeysPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.register_instruction_type::<Hotkey>()
+            .add_systems(Update, run_hotkeys);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,250 @@
+use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
+use smol_str::SmolStr;
+
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Broadcast event sent by [`InteractionAction::Broadcast`].
+///
+/// Listen for it with `broadcast::<SceneBroadcast>()` and filter by name.
+#[derive(Debug, Clone)]
+pub struct SceneBroadcast
+{
+    /// The name specified in the scene, e.g. `"open_settings"` in `OnPressed(Broadcast("open_settings"))`.
+    pub name: SmolStr,
+    /// The entity that was interacted with.
+    pub source: Entity,
+}
+
+impl SceneBroadcast
+{
+    /// Returns `true` if the broadcast has the requested name.
+    pub fn is(&self, name: &str) -> bool
+    {
+        self.name == name
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Entity events that can be sent with [`InteractionAction::EntityEvent`].
+///
+/// Each variant sends the pseudo-state entity event with the same name (e.g. [`Select`]).
+#[derive(Reflect, Default, Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum ActionEntityEvent
+{
+    Enable,
+    Disable,
+    #[default]
+    Select,
+    Deselect,
+    Check,
+    Uncheck,
+    ToggleCheck,
+    Open,
+    Close,
+    Fold,
+    Unfold,
+}
+
+impl ActionEntityEvent
+{
+    fn send(self, c: &mut Commands, entity: Entity)
+    {
+        let mut rc = c.react();
+        match self {
+            Self::Enable => rc.entity_event(entity, Enable),
+            Self::Disable => rc.entity_event(entity, Disable),
+            Self::Select => rc.entity_event(entity, Select),
+            Self::Deselect => rc.entity_event(entity, Deselect),
+            Self::Check => rc.entity_event(entity, Check),
+            Self::Uncheck => rc.entity_event(entity, Uncheck),
+            Self::ToggleCheck => rc.entity_event(entity, ToggleCheck),
+            Self::Open => rc.entity_event(entity, Open),
+            Self::Close => rc.entity_event(entity, Close),
+            Self::Fold => rc.entity_event(entity, Fold),
+            Self::Unfold => rc.entity_event(entity, Unfold),
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// An action that runs in response to an interaction. See [`OnPressed`] and [`OnReleased`].
+#[derive(Reflect, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum InteractionAction
+{
+    /// Broadcasts a [`SceneBroadcast`] event with the given name.
+    Broadcast(String),
+    /// Sends an entity event to a target entity.
+    ///
+    /// The target can be `"self"`, `"parent"`, or the [`Name`] of an ancestor. The nearest ancestor with a
+    /// matching name will be used.
+    EntityEvent(String, ActionEntityEvent),
+}
+
+impl InteractionAction
+{
+    fn find_target(source: Entity, target: &str, parents: &Query<&Parent>, names: &Query<&Name>)
+        -> Option<Entity>
+    {
+        match target {
+            "self" => Some(source),
+            "parent" => parents.get(source).ok().map(|p| p.get()),
+            _ => parents
+                .iter_ancestors(source)
+                .find(|ancestor| names.get(*ancestor).is_ok_and(|n| n.as_str() == target)),
+        }
+    }
+
+    pub(crate) fn run(&self, c: &mut Commands, source: Entity, parents: &Query<&Parent>, names: &Query<&Name>)
+    {
+        match self {
+            Self::Broadcast(name) => {
+                c.react()
+                    .broadcast(SceneBroadcast { name: SmolStr::from(name.as_str()), source });
+            }
+            Self::EntityEvent(target, event) => {
+                let Some(target_entity) = Self::find_target(source, target.as_str(), parents, names) else {
+                    tracing::warn!("failed sending {event:?} from {source:?}, target {target:?} could not be found");
+                    return;
+                };
+                event.send(c, target_entity);
+            }
+        }
+    }
+}
+
+impl Default for InteractionAction
+{
+    fn default() -> Self
+    {
+        Self::Broadcast(String::default())
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn on_pressed_reactor(
+    event: EntityEvent<Pressed>,
+    mut c: Commands,
+    actions: Query<&OnPressed>,
+    parents: Query<&Parent>,
+    names: Query<&Name>,
+)
+{
+    let entity = event.entity();
+    let Ok(action) = actions.get(entity) else { return };
+    action.0.run(&mut c, entity, &parents, &names);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn on_released_reactor(
+    event: EntityEvent<Released>,
+    mut c: Commands,
+    actions: Query<&OnReleased>,
+    parents: Query<&Parent>,
+    names: Query<&Name>,
+)
+{
+    let entity = event.entity();
+    let Ok(action) = actions.get(entity) else { return };
+    action.0.run(&mut c, entity, &parents, &names);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction that runs an [`InteractionAction`] when the entity receives a [`Pressed`] entity event.
+///
+/// Lets simple interactions be wired up directly in scenes, e.g. `OnPressed(Broadcast("open_settings"))` or
+/// `OnPressed(EntityEvent("self" Select))`.
+///
+/// Also inserts [`Interactive`].
+#[derive(Component, Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct OnPressed(pub InteractionAction);
+
+impl Instruction for OnPressed
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        Interactive.apply(entity, world);
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        emut.insert(self);
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.remove::<Self>();
+        });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction that runs an [`InteractionAction`] when the entity receives a [`Released`] entity event.
+///
+/// See [`OnPressed`].
+///
+/// Also inserts [`Interactive`].
+#[derive(Component, Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct OnReleased(pub InteractionAction);
+
+impl Instruction for OnReleased
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        Interactive.apply(entity, world);
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        emut.insert(self);
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.remove::<Self>();
+        });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct InteractionActionsPlugin;
+
+impl Plugin for InteractionActionsPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.register_type::<InteractionAction>()
+            .register_type::<ActionEntityEvent>()
+            .register_instruction_type::<OnPressed>()
+            .register_instruction_type::<OnReleased>()
+            .add_reactor(any_entity_event::<Pressed>(), on_pressed_reactor)
+            .add_reactor(any_entity_event::<Released>(), on_released_reactor);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,191 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::prelude::*;
+use crate::sickle::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Filters [`FluxInteraction`] changes on entities with [`InteractionDebounce`].
+///
+/// Suppressed changes are replaced with the last visible interaction so animations don't change state, and
+/// [`flux_ui_events`](super::flux_ui_events) ignores them.
+pub(crate) fn debounce_flux_interactions(
+    time: Res<Time<Real>>,
+    mut nodes: Query<(
+        &InteractionDebounce,
+        &Interaction,
+        &mut FluxInteraction,
+        &mut InteractionDebounceState,
+    )>,
+)
+{
+    let now = time.elapsed();
+
+    for (debounce, interaction, mut flux, mut state) in nodes.iter_mut() {
+        state.suppressed = false;
+
+        if !flux.is_changed() {
+            // Commit pending hovers once the pointer has stayed long enough.
+            let Some(start) = state.pending_enter else { continue };
+            if *interaction != Interaction::Hovered {
+                state.pending_enter = None;
+                continue;
+            }
+            if now.saturating_sub(start) < debounce.hover_delay() {
+                continue;
+            }
+            state.pending_enter = None;
+            *flux = FluxInteraction::PointerEnter;
+            state.visible = *flux;
+            continue;
+        }
+
+        let suppress = match *flux {
+            FluxInteraction::PointerEnter if debounce.hover_delay_secs > 0. => {
+                state.pending_enter = Some(now);
+                true
+            }
+            // The pointer left before the hover was committed.
+            FluxInteraction::PointerLeave => state.pending_enter.take().is_some(),
+            FluxInteraction::Pressed => {
+                // Pressing commits pending hovers.
+                state.pending_enter = None;
+                let throttled = state
+                    .last_press
+                    .is_some_and(|last| now.saturating_sub(last) < debounce.press_interval());
+                if !throttled {
+                    state.last_press = Some(now);
+                }
+                state.suppressed_press = throttled;
+                throttled
+            }
+            FluxInteraction::Released | FluxInteraction::PressCanceled => {
+                std::mem::take(&mut state.suppressed_press)
+            }
+            _ => false,
+        };
+
+        if suppress {
+            state.suppressed = true;
+            *flux = state.visible;
+        } else {
+            state.visible = *flux;
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn add_debounce_state(trigger: Trigger<OnAdd, InteractionDebounce>, mut c: Commands)
+{
+    c.entity(trigger.entity())
+        .try_insert(InteractionDebounceState::default());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn remove_debounce_state(trigger: Trigger<OnRemove, InteractionDebounce>, mut c: Commands)
+{
+    if let Some(mut ec) = c.get_entity(trigger.entity()) {
+        ec.remove::<InteractionDebounceState>();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Component, Debug, Default)]
+pub(crate) struct InteractionDebounceState
+{
+    /// The last interaction that wasn't suppressed.
+    visible: FluxInteraction,
+    /// Time when the pointer entered, if the hover hasn't been committed yet.
+    pending_enter: Option<Duration>,
+    /// Time of the last press that wasn't throttled.
+    last_press: Option<Duration>,
+    /// Set if the current press was throttled, so the matching release is also suppressed.
+    suppressed_press: bool,
+    /// Set if a flux change was suppressed this tick.
+    pub(crate) suppressed: bool,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction for debouncing and throttling pointer interactions on an entity.
+///
+/// Useful for dense lists and grids, where skimming the cursor across many items would otherwise trigger hover
+/// sounds and animations on every item.
+///
+/// Suppressed interactions are hidden from both [`FluxInteraction`] (so interaction-based animations don't
+/// change) and the [`PointerEnter`], [`PointerLeave`], [`Pressed`], [`Released`], and [`PressCanceled`]
+/// entity events.
+///
+/// Also inserts [`Interactive`].
+#[derive(Component, Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct InteractionDebounce
+{
+    /// Minimum time the pointer needs to hover the entity before [`PointerEnter`] is emitted. If the pointer
+    /// leaves sooner, the hover is ignored entirely.
+    ///
+    /// Defaults to `0.0`.
+    #[reflect(default)]
+    pub hover_delay_secs: f32,
+    /// Minimum time between [`Pressed`] events. Presses that happen sooner are ignored, along with their
+    /// release.
+    ///
+    /// Defaults to `0.0`.
+    #[reflect(default)]
+    pub press_interval_secs: f32,
+}
+
+impl InteractionDebounce
+{
+    fn hover_delay(&self) -> Duration
+    {
+        Duration::from_secs_f32(self.hover_delay_secs.max(0.))
+    }
+
+    fn press_interval(&self) -> Duration
+    {
+        Duration::from_secs_f32(self.press_interval_secs.max(0.))
+    }
+}
+
+impl Instruction for InteractionDebounce
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        Interactive.apply(entity, world);
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        emut.insert(self);
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.remove::<InteractionDebounce>();
+        });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct InteractionDebouncePlugin;
+
+impl Plugin for InteractionDebouncePlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.register_instruction_type::<InteractionDebounce>()
+            .add_observer(add_debounce_state)
+            .add_observer(remove_debounce_state);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -12,12 +12,26 @@ use crate::sickle::*;
 /// can be immediately handled.
 //todo: better to have these in PreUpdate - note that state transitions occur between PreUpdate and Update, so
 // any states set in reaction to these events will be applied 1 frame late
-fn flux_ui_events(
+pub(crate) fn flux_ui_events(
     mut c: Commands,
-    fluxes: Query<(Entity, &FluxInteraction, Option<&PseudoStates>), Changed<FluxInteraction>>,
+    fluxes: Query<
+        (
+            Entity,
+            &FluxInteraction,
+            Option<&PseudoStates>,
+            Option<&InteractionDebounceState>,
+            Has<PickingEvents>,
+        ),
+        Changed<FluxInteraction>,
+    >,
 )
 {
-    for (entity, flux, maybe_pseudo_states) in fluxes.iter() {
+    for (entity, flux, maybe_pseudo_states, maybe_debounce, has_picking_events) in fluxes.iter() {
+        // Ignore debounced interactions.
+        if maybe_debounce.is_some_and(|d| d.suppressed) {
+            continue;
+        }
+
         // Ignore disabled entities.
         if let Some(pseudo_states) = maybe_pseudo_states {
             if pseudo_states.has(&PseudoState::Disabled) {
@@ -27,6 +41,12 @@ fn flux_ui_events(
 
         match *flux {
             FluxInteraction::None => (),
+            // These are forwarded from picking events instead, unless debounced. See `PickingEvents`.
+            FluxInteraction::PointerEnter
+            | FluxInteraction::PointerLeave
+            | FluxInteraction::Pressed
+            | FluxInteraction::Released
+                if cfg!(feature = "picking_interactions") && has_picking_events && maybe_debounce.is_none() => {}
             FluxInteraction::PointerEnter => {
                 c.react().entity_event(entity, PointerEnter);
             }
@@ -54,22 +74,27 @@ fn flux_ui_events(
 /// Entity event emitted when [`FluxInteraction::PointerEnter`] is set on an entity.
 ///
 /// Not emitted if the entity has [`PseudoState::Disabled`].
+#[derive(TypePath, Default, Debug, Copy, Clone, PartialEq)]
 pub struct PointerEnter;
 /// Entity event emitted when [`FluxInteraction::PointerLeave`] is set on an entity.
 ///
 /// Not emitted if the entity has [`PseudoState::Disabled`].
+#[derive(TypePath, Default, Debug, Copy, Clone, PartialEq)]
 pub struct PointerLeave;
 /// Entity event emitted when [`FluxInteraction::Pressed`] is set on an entity.
 ///
 /// Not emitted if the entity has [`PseudoState::Disabled`].
+#[derive(TypePath, Default, Debug, Copy, Clone, PartialEq)]
 pub struct Pressed;
 /// Entity event emitted when [`FluxInteraction::Released`] is set on an entity.
 ///
 /// Not emitted if the entity has [`PseudoState::Disabled`].
+#[derive(TypePath, Default, Debug, Copy, Clone, PartialEq)]
 pub struct Released;
 /// Entity event emitted when [`FluxInteraction::PressCanceled`] is set on an entity.
 ///
 /// Not emitted if the entity has [`PseudoState::Disabled`].
+#[derive(TypePath, Default, Debug, Copy, Clone, PartialEq)]
 pub struct PressCanceled;
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -177,6 +202,8 @@ impl UiInteractionExt for UiBuilder<'_, Entity>
 
 /// Causes [`Interaction`] and [`TrackedInteraction`] to be inserted on a node.
 ///
+/// If the `picking_interactions` feature is enabled, also inserts [`PickingEvents`].
+///
 /// It is typically not necessary to add this to your scenes, since we try to add it automatically wherever
 /// needed.
 #[derive(Reflect, Default, Debug, Clone, PartialEq)]
@@ -188,6 +215,8 @@ impl Instruction for Interactive
     {
         let _ = world.get_entity_mut(entity).map(|mut e| {
             e.insert((Interaction::default(), TrackedInteraction::default()));
+            #[cfg(feature = "picking_interactions")]
+            e.insert(PickingEvents);
         });
     }
 
@@ -195,6 +224,8 @@ impl Instruction for Interactive
     {
         let _ = world.get_entity_mut(entity).map(|mut e| {
             e.remove::<(Interaction, TrackedInteraction)>();
+            #[cfg(feature = "picking_interactions")]
+            e.remove::<PickingEvents>();
         });
     }
 }
@@ -209,7 +240,8 @@ impl Plugin for UiInteractionExtPlugin
     {
         app.register_instruction_type::<Interactive>().add_systems(
             Update,
-            flux_ui_events
+            (debounce_flux_interactions, flux_ui_events)
+                .chain()
                 .after(FluxInteractionUpdate)
                 .before(ApplyFluxChanges),
         );
@@ -2,26 +2,50 @@
 #[allow(unused_imports)]
 use crate as bevy_cobweb_ui;
 
+mod accessibility_modes;
+mod breakpoints;
 mod builder_ext;
 mod control;
+mod control_inheritance;
 mod control_loadable_registration;
 mod control_loadables;
 mod control_map;
 mod control_traits;
+mod event_bubbling;
+mod haptics;
+mod hotkeys;
+mod interaction_actions;
+mod interaction_debounce;
 mod interaction_ext;
 mod node_attributes;
+mod picking_events;
 mod plugin;
+mod press_timing;
+mod pseudo_state_machine;
 mod pseudo_states_ext;
 mod react_ext;
+mod touch_gestures;
 
+pub use accessibility_modes::*;
+pub use breakpoints::*;
 pub use builder_ext::*;
 pub use control::*;
+pub use control_inheritance::*;
 pub use control_loadable_registration::*;
 pub use control_loadables::*;
 pub(crate) use control_map::*;
 pub use control_traits::*;
+pub use event_bubbling::*;
+pub use haptics::*;
+pub use hotkeys::*;
+pub use interaction_actions::*;
+pub use interaction_debounce::*;
 pub use interaction_ext::*;
 pub use node_attributes::*;
+pub use picking_events::*;
 pub(crate) use plugin::*;
+pub use press_timing::*;
+pub use pseudo_state_machine::*;
 pub use pseudo_states_ext::*;
 pub use react_ext::*;
+pub use touch_gestures::*;
@@ -1,8 +1,10 @@
 use std::any::{type_name, Any, TypeId};
 use std::fmt::Debug;
+use std::marker::PhantomData;
 use std::sync::Arc;
 
 use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
 use smallvec::SmallVec;
 use smol_str::SmolStr;
 
@@ -185,6 +187,64 @@ impl<T: AnimatedAttribute> AnimatedAttributeObject for CachedAnimatedAttribute<T
         let value = T::extract(entity, world, &self.vals, &state);
         T::update(entity, world, value);
     }
+
+    fn on_complete(&self, entity: Entity, world: &mut World, style: InteractionStyle)
+    {
+        world.react(|rc| rc.entity_event(entity, AnimationComplete::<T>::new(style)));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Entity event emitted when an [`Animated`] attribute of type `T` finishes a transition.
+///
+/// The event is emitted on the attribute's target entity after the final value of the transition has been
+/// applied. It is not emitted if a transition is interrupted by another transition (e.g. if the entity is hovered
+/// again before the unhover animation completes). Looping animations only emit the event if they are stopped.
+///
+/// Use this to drive follow-up logic like despawning an entity after it fades out, or chaining animations.
+///
+/// ```rust
+/// h.on_event::<AnimationComplete<BgColor>>().r(
+///     |mut c: Commands, event: EntityEvent<AnimationComplete<BgColor>>| {
+///         let (entity, complete) = event.read();
+///         if complete.style() == InteractionStyle::Idle {
+///             c.entity(entity).despawn_recursive();
+///         }
+///     }
+/// );
+/// ```
+pub struct AnimationComplete<T: AnimatedAttribute>
+{
+    style: InteractionStyle,
+    _p: PhantomData<T>,
+}
+
+impl<T: AnimatedAttribute> AnimationComplete<T>
+{
+    fn new(style: InteractionStyle) -> Self
+    {
+        Self { style, _p: PhantomData }
+    }
+
+    /// Gets the interaction style the animation settled on.
+    pub fn style(&self) -> InteractionStyle
+    {
+        self.style
+    }
+}
+
+impl<T: AnimatedAttribute> Debug for AnimationComplete<T>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        f.write_str("AnimationComplete<")?;
+        f.write_str(type_name::<T>())?;
+        f.write_str(">(")?;
+        self.style.fmt(f)?;
+        f.write_str(")")?;
+        Ok(())
+    }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -242,7 +302,13 @@ impl PseudoTheme
     }
 
     /// Adds all attributes to the style builder.
-    pub(super) fn build(&self, label: &SmolStr, style_builder: &mut StyleBuilder)
+    pub(super) fn build(
+        &self,
+        label: &SmolStr,
+        control_map: &ControlMap,
+        world: &World,
+        style_builder: &mut StyleBuilder,
+    )
     {
         for attribute in self.style.iter() {
             // Clear source if it points to self.
@@ -261,10 +327,23 @@ impl PseudoTheme
             }
 
             // Set the target to self.
-            style_builder.switch_target_with(label.clone());
-
-            // Insert attribute.
-            style_builder.add(attribute.dynamic_style_attribute());
+            let Some(targets) = attribute.targets() else {
+                style_builder.switch_target_with(label.clone());
+
+                // Insert attribute.
+                style_builder.add(attribute.dynamic_style_attribute());
+                continue;
+            };
+
+            // Insert attribute to all selected members.
+            let matcher = targets.matcher(world);
+            for (member_label, member_entity) in control_map.iter_entities() {
+                if !matcher.matches(member_label, *member_entity, world) {
+                    continue;
+                }
+                style_builder.switch_target_with(member_label.clone());
+                style_builder.add(attribute.dynamic_style_attribute());
+            }
         }
     }
 
@@ -361,6 +440,7 @@ pub struct NodeAttribute
     type_id: TypeId,
 
     respond_to: Option<SmolStr>,
+    targets: Option<ControlSelector>,
 
     cached: CachedAttribute,
     settings: Option<AnimationSettings>,
@@ -377,6 +457,7 @@ impl NodeAttribute
             attribute_type: AttributeType::Static,
             type_id,
             respond_to: None,
+            targets: None,
             cached: CachedAttribute::Static(Arc::new(CachedStaticAttribute::<T> { value })),
             settings: None,
         }
@@ -395,6 +476,7 @@ impl NodeAttribute
             attribute_type: AttributeType::Responsive,
             type_id,
             respond_to,
+            targets: None,
             cached: CachedAttribute::Responsive(Arc::new(CachedResponsiveAttribute::<T> { vals })),
             settings: None,
         }
@@ -414,11 +496,21 @@ impl NodeAttribute
             attribute_type: AttributeType::Animated,
             type_id,
             respond_to,
+            targets: None,
             cached: CachedAttribute::Animated(Arc::new(CachedAnimatedAttribute::<T> { vals })),
             settings: Some(settings),
         }
     }
 
+    /// Sets the control group members this attribute is applied to.
+    ///
+    /// If `None`, then the attribute is applied to the entity that has it.
+    pub fn with_targets(mut self, targets: Option<ControlSelector>) -> Self
+    {
+        self.targets = targets;
+        self
+    }
+
     /// Gets the attribute name (if any).
     pub fn name(&self) -> Option<&str>
     {
@@ -441,6 +533,12 @@ impl NodeAttribute
         self.respond_to.as_ref()
     }
 
+    /// Gets the control group members this attribute is applied to (if any).
+    pub fn targets(&self) -> Option<&ControlSelector>
+    {
+        self.targets.as_ref()
+    }
+
     /// Gets the inner static reference value.
     ///
     /// Returns `None` if self is not `AttributeType::Static` or the requested type doesn't match.
@@ -563,7 +661,7 @@ impl LogicalEq for NodeAttribute
 {
     fn logical_eq(&self, other: &Self) -> bool
     {
-        self.respond_to == other.respond_to && self.type_id == other.type_id
+        self.respond_to == other.respond_to && self.targets == other.targets && self.type_id == other.type_id
     }
 }
 
@@ -0,0 +1,233 @@
+use std::fmt::Debug;
+
+use bevy::picking::PickSet;
+use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
+
+use crate::prelude::*;
+use crate::sickle::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Makes a system that converts a `bevy_picking` pointer event to a reactive entity event on
+/// [`PickingEvents`] entities.
+///
+/// Pointer events are read as buffered events, which are only sent to the original target.
+fn forward_picking_events<E, T>(
+    make: fn(&Pointer<E>) -> Option<T>,
+) -> impl FnMut(Commands, EventReader<Pointer<E>>, Query<Option<&PseudoStates>, With<PickingEvents>>)
+where
+    E: Debug + Clone + Reflect,
+    T: Send + Sync + 'static,
+{
+    move |mut c: Commands,
+          mut events: EventReader<Pointer<E>>,
+          targets: Query<Option<&PseudoStates>, With<PickingEvents>>| {
+        for event in events.read() {
+            let Ok(maybe_states) = targets.get(event.target) else { continue };
+            if maybe_states.is_some_and(|s| s.has(&PseudoState::Disabled)) {
+                continue;
+            }
+            let Some(forwarded) = make(event) else { continue };
+            c.react().entity_event(event.target, forwarded);
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// [`PickingEvents`] entities that receive interaction events from `bevy_picking`.
+#[cfg(feature = "picking_interactions")]
+type InteractionTargets<'w, 's> = Query<
+    'w,
+    's,
+    (Option<&'static PseudoStates>, Has<PickingPressed>),
+    (With<PickingEvents>, Without<InteractionDebounce>),
+>;
+
+/// Makes a system that converts a `bevy_picking` pointer event to an interaction entity event on
+/// [`PickingEvents`] entities.
+///
+/// `make` can read and update whether the entity is pressed by the primary pointer button.
+///
+/// Entities with [`InteractionDebounce`] are skipped, their interaction events are derived from
+/// [`FluxInteraction`] so debouncing applies.
+#[cfg(feature = "picking_interactions")]
+fn forward_interaction_events<E, T>(
+    make: fn(&Pointer<E>, &mut bool) -> Option<T>,
+) -> impl FnMut(Commands, EventReader<Pointer<E>>, InteractionTargets)
+where
+    E: Debug + Clone + Reflect,
+    T: Send + Sync + 'static,
+{
+    move |mut c: Commands,
+          mut events: EventReader<Pointer<E>>,
+          targets: InteractionTargets| {
+        for event in events.read() {
+            let Ok((maybe_states, was_pressed)) = targets.get(event.target) else { continue };
+            if maybe_states.is_some_and(|s| s.has(&PseudoState::Disabled)) {
+                continue;
+            }
+
+            let mut pressed = was_pressed;
+            let forwarded = make(event, &mut pressed);
+            match (was_pressed, pressed) {
+                (false, true) => {
+                    c.entity(event.target).try_insert(PickingPressed);
+                }
+                (true, false) => {
+                    c.entity(event.target).remove::<PickingPressed>();
+                }
+                _ => (),
+            }
+
+            let Some(forwarded) = forwarded else { continue };
+            c.react().entity_event(event.target, forwarded);
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Marks [`PickingEvents`] entities that were pressed by the primary pointer button, so [`Released`] is only
+/// emitted for presses that started on the entity.
+#[cfg(feature = "picking_interactions")]
+#[derive(Component)]
+struct PickingPressed;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Entity event emitted when a [`PickingEvents`] entity receives a `Pointer<Click>` event.
+///
+/// Not emitted if the entity has [`PseudoState::Disabled`].
+#[derive(Debug, Clone)]
+pub struct Clicked
+{
+    pub button: PointerButton,
+}
+
+/// Entity event emitted when a [`PickingEvents`] entity receives a `Pointer<DragStart>` event.
+///
+/// Not emitted if the entity has [`PseudoState::Disabled`].
+#[derive(Debug, Clone)]
+pub struct DragStarted
+{
+    pub button: PointerButton,
+}
+
+/// Entity event emitted when a [`PickingEvents`] entity receives a `Pointer<Drag>` event.
+///
+/// Not emitted if the entity has [`PseudoState::Disabled`].
+#[derive(Debug, Clone)]
+pub struct Dragged
+{
+    pub button: PointerButton,
+    /// Pointer movement since the last drag event, in screen pixels.
+    pub delta: Vec2,
+    /// Pointer movement since the drag started, in screen pixels.
+    pub distance: Vec2,
+}
+
+/// Entity event emitted when a [`PickingEvents`] entity receives a `Pointer<DragEnd>` event.
+///
+/// Not emitted if the entity has [`PseudoState::Disabled`].
+#[derive(Debug, Clone)]
+pub struct DragEnded
+{
+    pub button: PointerButton,
+    /// Pointer movement over the whole drag, in screen pixels.
+    pub distance: Vec2,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction that forwards `bevy_picking` pointer events on the entity to reactive entity events (see
+/// [`ReactCommand::entity_event`]).
+///
+/// Forwards `Click`, `DragStart`, `Drag`, and `DragEnd` as [`Clicked`], [`DragStarted`], [`Dragged`], and
+/// [`DragEnded`]. If the `picking_interactions` feature is enabled, then `Over`, `Out`, and primary-button
+/// `Down`/`Up` are also forwarded as [`PointerEnter`], [`PointerLeave`], [`Pressed`], and [`Released`] instead of
+/// being derived from [`Interaction`], and [`Interactive`] inserts this component automatically. As with
+/// [`FluxInteraction::Released`], `Up` is only forwarded if the press started on the entity and the pointer didn't
+/// leave it in the meantime. Entities with [`InteractionDebounce`] still derive these events from [`Interaction`]
+/// so they can be debounced.
+///
+/// Since it uses picking, this also works for world-space entities with a picking backend (e.g. sprites or
+/// meshes).
+#[derive(Component, Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct PickingEvents;
+
+impl Instruction for PickingEvents
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.insert(self);
+        });
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.remove::<Self>();
+        });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct PickingEventsPlugin;
+
+impl Plugin for PickingEventsPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.register_instruction_type::<PickingEvents>().add_systems(
+            PreUpdate,
+            (
+                forward_picking_events::<Click, _>(|e| Some(Clicked { button: e.button })),
+                forward_picking_events::<DragStart, _>(|e| Some(DragStarted { button: e.button })),
+                forward_picking_events::<Drag, _>(|e| {
+                    Some(Dragged { button: e.button, delta: e.delta, distance: e.distance })
+                }),
+                forward_picking_events::<DragEnd, _>(|e| {
+                    Some(DragEnded { button: e.button, distance: e.distance })
+                }),
+            )
+                .after(PickSet::Focus),
+        );
+
+        // Chained in the order `bevy_picking` sends them, so press tracking sees earlier events in the same tick.
+        #[cfg(feature = "picking_interactions")]
+        app.add_systems(
+            PreUpdate,
+            (
+                forward_interaction_events::<Over, _>(|_, _| Some(PointerEnter)),
+                forward_interaction_events::<Down, _>(|e, pressed| {
+                    if e.button != PointerButton::Primary {
+                        return None;
+                    }
+                    *pressed = true;
+                    Some(Pressed)
+                }),
+                forward_interaction_events::<Up, _>(|e, pressed| {
+                    (e.button == PointerButton::Primary && std::mem::take(pressed)).then_some(Released)
+                }),
+                forward_interaction_events::<Out, _>(|_, pressed| {
+                    // Leaving the entity cancels the press, like `FluxInteraction::PressCanceled`.
+                    *pressed = false;
+                    Some(PointerLeave)
+                }),
+            )
+                .chain()
+                .after(PickSet::Focus),
+        );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -19,8 +19,20 @@ impl Plugin for SickleExtPlugin
             .register_type::<AnimationLoop>()
             .add_plugins(ControlPlugin)
             .add_plugins(ControlMapPlugin)
+            .add_plugins(ControlInheritancePlugin)
             .add_plugins(UiInteractionExtPlugin)
-            .add_plugins(PseudoStatesExtPlugin);
+            .add_plugins(EventBubblingPlugin)
+            .add_plugins(PickingEventsPlugin)
+            .add_plugins(TouchGesturesPlugin)
+            .add_plugins(PressTimingPlugin)
+            .add_plugins(HotkeysPlugin)
+            .add_plugins(InteractionActionsPlugin)
+            .add_plugins(InteractionDebouncePlugin)
+            .add_plugins(PseudoStatesExtPlugin)
+            .add_plugins(PseudoStateMachinePlugin)
+            .add_plugins(BreakpointsPlugin)
+            .add_plugins(HapticsPlugin)
+            .add_plugins(AccessibilityModesPlugin);
     }
 }
 
@@ -0,0 +1,235 @@
+use std::time::Duration;
+
+use bevy::ecs::entity::EntityHashMap;
+use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
+
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Default, Debug)]
+struct PressRecord
+{
+    /// Time when the current press started.
+    press_start: Option<Duration>,
+    /// Whether a long press was sent for the current press.
+    long_press_sent: bool,
+    /// Time when the last click (press and release that wasn't a long press) ended.
+    last_click: Option<Duration>,
+}
+
+/// Tracks presses for detecting [`DoubleClicked`] and [`LongPressed`].
+#[derive(Resource, Default)]
+struct PressRecords(EntityHashMap<PressRecord>);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn press_timing_pressed_reactor(
+    event: EntityEvent<Pressed>,
+    time: Res<Time<Real>>,
+    mut records: ResMut<PressRecords>,
+)
+{
+    let record = records.0.entry(event.entity()).or_default();
+    record.press_start = Some(time.elapsed());
+    record.long_press_sent = false;
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn press_timing_released_reactor(
+    event: EntityEvent<Released>,
+    mut c: Commands,
+    time: Res<Time<Real>>,
+    settings: Res<PressTimingSettings>,
+    overrides: Query<&PressTiming>,
+    mut records: ResMut<PressRecords>,
+)
+{
+    let entity = event.entity();
+    let Some(record) = records.0.get_mut(&entity) else { return };
+    if record.press_start.take().is_none() || record.long_press_sent {
+        return;
+    }
+
+    let now = time.elapsed();
+    let (double_click, _) = settings.thresholds(overrides.get(entity).ok());
+    match record.last_click {
+        Some(last) if now.saturating_sub(last) <= double_click => {
+            record.last_click = None;
+            c.react().entity_event(entity, DoubleClicked);
+        }
+        _ => {
+            record.last_click = Some(now);
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn press_timing_canceled_reactor(event: EntityEvent<PressCanceled>, mut records: ResMut<PressRecords>)
+{
+    let Some(record) = records.0.get_mut(&event.entity()) else { return };
+    record.press_start = None;
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Sends [`LongPressed`] events and cleans up stale records.
+fn update_press_records(
+    mut c: Commands,
+    time: Res<Time<Real>>,
+    settings: Res<PressTimingSettings>,
+    overrides: Query<&PressTiming>,
+    mut records: ResMut<PressRecords>,
+)
+{
+    let now = time.elapsed();
+    records.0.retain(|entity, record| {
+        let (double_click, long_press) = settings.thresholds(overrides.get(*entity).ok());
+
+        if let Some(start) = record.press_start {
+            if !record.long_press_sent && now.saturating_sub(start) >= long_press {
+                record.long_press_sent = true;
+                c.react().entity_event(*entity, LongPressed);
+            }
+        }
+        if record
+            .last_click
+            .is_some_and(|last| now.saturating_sub(last) > double_click)
+        {
+            record.last_click = None;
+        }
+
+        record.press_start.is_some() || record.last_click.is_some()
+    });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Entity event emitted when an entity is clicked twice within the double-click threshold.
+///
+/// The first click emits [`Pressed`] and [`Released`] as normal. Presses that end in a [`LongPressed`] are not
+/// counted as clicks.
+///
+/// See [`PressTimingSettings`] and [`PressTiming`].
+pub struct DoubleClicked;
+
+/// Entity event emitted when an entity is pressed for longer than the long-press threshold.
+///
+/// Emitted while the press is held, before [`Released`].
+///
+/// See [`PressTimingSettings`] and [`PressTiming`].
+pub struct LongPressed;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource with the default thresholds for [`DoubleClicked`] and [`LongPressed`].
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct PressTimingSettings
+{
+    /// Max time between the end of one click and the end of the next for them to count as a double-click.
+    ///
+    /// Defaults to 400ms.
+    pub double_click: Duration,
+    /// Time a press must be held to count as a long press.
+    ///
+    /// Defaults to 500ms.
+    pub long_press: Duration,
+}
+
+impl PressTimingSettings
+{
+    /// Gets the double-click and long-press thresholds, applying an entity's overrides.
+    pub fn thresholds(&self, overrides: Option<&PressTiming>) -> (Duration, Duration)
+    {
+        let double_click = overrides
+            .and_then(|o| o.double_click_ms)
+            .map(Duration::from_millis)
+            .unwrap_or(self.double_click);
+        let long_press = overrides
+            .and_then(|o| o.long_press_ms)
+            .map(Duration::from_millis)
+            .unwrap_or(self.long_press);
+        (double_click, long_press)
+    }
+}
+
+impl Default for PressTimingSettings
+{
+    fn default() -> Self
+    {
+        Self {
+            double_click: Duration::from_millis(400),
+            long_press: Duration::from_millis(500),
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction that overrides [`PressTimingSettings`] for an entity.
+///
+/// Also inserts [`Interactive`].
+///
+/// Example:
+/**
+```rust
+#scenes
+"inventory_slot"
+    PressTiming{double_click_ms:300 long_press_ms:800}
+```
+*/
+#[derive(Component, Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct PressTiming
+{
+    /// Overrides [`PressTimingSettings::double_click`].
+    #[reflect(default)]
+    pub double_click_ms: Option<u64>,
+    /// Overrides [`PressTimingSettings::long_press`].
+    #[reflect(default)]
+    pub long_press_ms: Option<u64>,
+}
+
+impl Instruction for PressTiming
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        Interactive.apply(entity, world);
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        emut.insert(self);
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.remove::<Self>();
+        });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct PressTimingPlugin;
+
+impl Plugin for PressTimingPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_resource::<PressTimingSettings>()
+            .init_resource::<PressRecords>()
+            .register_instruction_type::<PressTiming>()
+            .add_reactor(any_entity_event::<Pressed>(), press_timing_pressed_reactor)
+            .add_reactor(any_entity_event::<Released>(), press_timing_released_reactor)
+            .add_reactor(any_entity_event::<PressCanceled>(), press_timing_canceled_reactor)
+            .add_systems(Update, update_press_records);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,314 @@
+use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
+
+use crate::prelude::*;
+use crate::sickle::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn enter_machine_state(c: &mut Commands, entity: Entity, prev: Option<PseudoState>, next: PseudoState)
+{
+    let Some(mut ec) = c.get_entity(entity) else { return };
+    if let Some(prev) = prev {
+        ec.remove_pseudo_state(prev);
+    }
+    ec.add_pseudo_state(next);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn handle_machine_trigger(
+    c: &mut Commands,
+    time: &Time,
+    entity: Entity,
+    trigger: StateTrigger,
+    machines: &mut Query<(&PseudoStateMachine, &mut PseudoStateMachineState)>,
+)
+{
+    let Ok((machine, mut state)) = machines.get_mut(entity) else { return };
+    let Some(next) = machine.next_state(&state.current, &trigger) else { return };
+    let next = next.clone();
+    let prev = std::mem::replace(&mut state.current, next.clone());
+    state.entered_at = time.elapsed_secs();
+    enter_machine_state(c, entity, Some(prev), next);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn add_trigger_reactor<E: Send + Sync + 'static>(app: &mut App, trigger: StateTrigger)
+{
+    app.add_reactor(
+        any_entity_event::<E>(),
+        move |event: EntityEvent<E>,
+              mut c: Commands,
+              time: Res<Time>,
+              mut machines: Query<(&PseudoStateMachine, &mut PseudoStateMachineState)>| {
+            handle_machine_trigger(&mut c, &time, event.entity(), trigger.clone(), &mut machines);
+        },
+    );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn machine_timer_elapsed_reactor(
+    event: EntityEvent<TimerElapsed>,
+    mut c: Commands,
+    time: Res<Time>,
+    mut machines: Query<(&PseudoStateMachine, &mut PseudoStateMachineState)>,
+)
+{
+    let Ok((entity, elapsed)) = event.try_read() else { return };
+    let trigger = StateTrigger::TimerElapsed(elapsed.name.to_string());
+    handle_machine_trigger(&mut c, &time, entity, trigger, &mut machines);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn machine_broadcast_reactor(
+    event: BroadcastEvent<SceneBroadcast>,
+    mut c: Commands,
+    time: Res<Time>,
+    mut machines: Query<(Entity, &PseudoStateMachine, &mut PseudoStateMachineState)>,
+)
+{
+    let Ok(broadcast) = event.try_read() else { return };
+    let trigger = StateTrigger::Broadcast(broadcast.name.to_string());
+    for (entity, machine, mut state) in machines.iter_mut() {
+        let Some(next) = machine.next_state(&state.current, &trigger) else { continue };
+        let next = next.clone();
+        let prev = std::mem::replace(&mut state.current, next.clone());
+        state.entered_at = time.elapsed_secs();
+        enter_machine_state(&mut c, entity, Some(prev), next);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn update_machine_timers(
+    mut c: Commands,
+    time: Res<Time>,
+    mut machines: Query<(Entity, &PseudoStateMachine, &mut PseudoStateMachineState)>,
+)
+{
+    let now = time.elapsed_secs();
+    for (entity, machine, mut state) in machines.iter_mut() {
+        let trigger = StateTrigger::Timer(now - state.entered_at);
+        let Some(next) = machine.next_state(&state.current, &trigger) else { continue };
+        let next = next.clone();
+        let prev = std::mem::replace(&mut state.current, next.clone());
+        state.entered_at = now;
+        enter_machine_state(&mut c, entity, Some(prev), next);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Tracks the current state of a [`PseudoStateMachine`].
+#[derive(Component, Debug)]
+struct PseudoStateMachineState
+{
+    current: PseudoState,
+    /// Time when the current state was entered, in seconds.
+    entered_at: f32,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Triggers for [`PseudoStateMachine`] transitions.
+#[derive(Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum StateTrigger
+{
+    /// The entity received a [`Pressed`] entity event.
+    #[default]
+    Pressed,
+    /// The entity received a [`Released`] entity event.
+    Released,
+    /// The entity received a [`PointerEnter`] entity event.
+    PointerEnter,
+    /// The entity received a [`PointerLeave`] entity event.
+    PointerLeave,
+    /// The machine has been in its current state for this many seconds.
+    Timer(f32),
+    /// The entity received a pseudo-state entity event (e.g. [`Select`]).
+    Event(ActionEntityEvent),
+    /// A [`SceneBroadcast`] with this name was broadcast.
+    Broadcast(String),
+    /// The entity received a [`TimerElapsed`] entity event with this name.
+    TimerElapsed(String),
+}
+
+impl StateTrigger
+{
+    /// Returns `true` if `event` should fire a transition with this trigger.
+    ///
+    /// For timers, `event` should contain the time spent in the current state.
+    pub fn matches(&self, event: &StateTrigger) -> bool
+    {
+        match (self, event) {
+            (Self::Timer(duration), Self::Timer(elapsed)) => *elapsed >= *duration,
+            _ => self == event,
+        }
+    }
+
+    fn needs_interaction(&self) -> bool
+    {
+        matches!(self, Self::Pressed | Self::Released | Self::PointerEnter | Self::PointerLeave)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A transition in a [`PseudoStateMachine`].
+#[derive(Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct StateTransition
+{
+    /// States this transition can start from. If empty, the transition can start from any state.
+    #[reflect(default)]
+    pub from: Vec<PseudoState>,
+    /// The state to enter.
+    pub to: PseudoState,
+    /// The trigger that fires this transition.
+    pub on: StateTrigger,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction that drives an entity's [`PseudoStates`] with a simple state machine.
+///
+/// Only one of the machine's states is set on the entity at a time. When a trigger fires, the first
+/// transition that starts from the current state and matches the trigger is taken.
+///
+/// Also inserts [`Interactive`] if any transitions use interaction triggers.
+///
+/// Example:
+/**
+```rust
+#scenes
+"button"
+    PseudoStateMachine{
+        initial:Custom("idle")
+        transitions:[
+            {from:[Custom("idle")] to:Custom("armed") on:Pressed}
+            {from:[Custom("armed")] to:Custom("fired") on:Released}
+            {from:[Custom("armed")] to:Custom("idle") on:PointerLeave}
+            {from:[Custom("fired")] to:Custom("idle") on:Timer(0.5)}
+        ]
+    }
+```
+*/
+#[derive(Component, Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct PseudoStateMachine
+{
+    /// The state that is set when the machine is applied.
+    pub initial: PseudoState,
+    /// Transitions between states. Earlier transitions take priority.
+    #[reflect(default)]
+    pub transitions: Vec<StateTransition>,
+}
+
+impl PseudoStateMachine
+{
+    /// Gets the state to enter when `event` fires in the `current` state.
+    ///
+    /// Returns `None` if no transition is taken.
+    pub fn next_state(&self, current: &PseudoState, event: &StateTrigger) -> Option<&PseudoState>
+    {
+        self.transitions
+            .iter()
+            .filter(|t| t.from.is_empty() || t.from.contains(current))
+            .find(|t| t.on.matches(event))
+            .map(|t| &t.to)
+            .filter(|to| *to != current)
+    }
+
+    /// Iterates all states used by the machine.
+    fn states(&self) -> impl Iterator<Item = &PseudoState> + '_
+    {
+        std::iter::once(&self.initial).chain(
+            self.transitions
+                .iter()
+                .flat_map(|t| t.from.iter().chain(std::iter::once(&t.to))),
+        )
+    }
+}
+
+impl Instruction for PseudoStateMachine
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        // Clean up the previous machine in case the states changed.
+        Self::revert(entity, world);
+
+        if self.transitions.iter().any(|t| t.on.needs_interaction()) {
+            Interactive.apply(entity, world);
+        }
+
+        let entered_at = world.resource::<Time>().elapsed_secs();
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        emut.add_pseudo_state(self.initial.clone());
+        emut.insert((
+            PseudoStateMachineState { current: self.initial.clone(), entered_at },
+            self,
+        ));
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        let Some(machine) = emut.take::<PseudoStateMachine>() else { return };
+        emut.remove::<PseudoStateMachineState>();
+        for state in machine.states() {
+            emut.remove_pseudo_state(state.clone());
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct PseudoStateMachinePlugin;
+
+impl Plugin for PseudoStateMachinePlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.register_type::<StateTrigger>()
+            .register_type::<StateTransition>()
+            .register_instruction_type::<PseudoStateMachine>()
+            .add_reactor(broadcast::<SceneBroadcast>(), machine_broadcast_reactor)
+            .add_reactor(any_entity_event::<TimerElapsed>(), machine_timer_elapsed_reactor)
+            .add_systems(Update, update_machine_timers);
+
+        add_trigger_reactor::<Pressed>(app, StateTrigger::Pressed);
+        add_trigger_reactor::<Released>(app, StateTrigger::Released);
+        add_trigger_reactor::<PointerEnter>(app, StateTrigger::PointerEnter);
+        add_trigger_reactor::<PointerLeave>(app, StateTrigger::PointerLeave);
+        add_trigger_reactor::<Enable>(app, StateTrigger::Event(ActionEntityEvent::Enable));
+        add_trigger_reactor::<Disable>(app, StateTrigger::Event(ActionEntityEvent::Disable));
+        add_trigger_reactor::<Select>(app, StateTrigger::Event(ActionEntityEvent::Select));
+        add_trigger_reactor::<Deselect>(app, StateTrigger::Event(ActionEntityEvent::Deselect));
+        add_trigger_reactor::<Check>(app, StateTrigger::Event(ActionEntityEvent::Check));
+        add_trigger_reactor::<Uncheck>(app, StateTrigger::Event(ActionEntityEvent::Uncheck));
+        add_trigger_reactor::<ToggleCheck>(app, StateTrigger::Event(ActionEntityEvent::ToggleCheck));
+        add_trigger_reactor::<Open>(app, StateTrigger::Event(ActionEntityEvent::Open));
+        add_trigger_reactor::<Close>(app, StateTrigger::Event(ActionEntityEvent::Close));
+        add_trigger_reactor::<Fold>(app, StateTrigger::Event(ActionEntityEvent::Fold));
+        add_trigger_reactor::<Unfold>(app, StateTrigger::Event(ActionEntityEvent::Unfold));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
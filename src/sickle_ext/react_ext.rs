@@ -29,6 +29,20 @@ impl InstructionExt for UiBuilder<'_, Entity>
 
 //-------------------------------------------------------------------------------------------------------------------
 
+impl RevertLoadableExt for UiBuilder<'_, Entity>
+{
+    fn revert_loadable<T: Loadable>(&mut self) -> &mut Self
+    {
+        let id = self.id();
+        if let Some(mut ec) = self.commands().get_entity(id) {
+            ec.revert_loadable::<T>();
+        }
+        self
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Helper trait for registering reactors for node entities using [`UiBuilder`].
 pub trait UiBuilderReactExt
 {
@@ -0,0 +1,175 @@
+use bevy::input::touch::Touches;
+use bevy::picking::focus::HoverMap;
+use bevy::picking::pointer::PointerId;
+use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
+
+use crate::prelude::*;
+use crate::sickle::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Detects two-finger gestures and sends them to the nearest [`TouchGestures`] entity under the touches.
+fn detect_touch_gestures(
+    mut c: Commands,
+    touches: Res<Touches>,
+    hover_map: Res<HoverMap>,
+    parents: Query<&Parent>,
+    targets: Query<Option<&PseudoStates>, With<TouchGestures>>,
+)
+{
+    let mut active = touches.iter();
+    let (Some(a), Some(b), None) = (active.next(), active.next(), active.next()) else { return };
+
+    let gesture = TwoFingerGesture::new(
+        (a.previous_position(), b.previous_position()),
+        (a.position(), b.position()),
+    );
+    if gesture.scale == 1. && gesture.delta == Vec2::ZERO {
+        return;
+    }
+
+    // Find the top-most entity hovered by the first touch.
+    let Some(hovered) = hover_map.get(&PointerId::Touch(a.id())) else { return };
+    let Some((hit, _)) = hovered
+        .iter()
+        .min_by(|(_, x), (_, y)| x.depth.total_cmp(&y.depth))
+    else {
+        return;
+    };
+
+    // Find the nearest gesture target.
+    let Some(target) = std::iter::once(*hit)
+        .chain(parents.iter_ancestors(*hit))
+        .find(|e| targets.contains(*e))
+    else {
+        return;
+    };
+    let Ok(maybe_states) = targets.get(target) else { return };
+    if maybe_states.is_some_and(|s| s.has(&PseudoState::Disabled)) {
+        return;
+    }
+
+    if gesture.scale != 1. {
+        c.react()
+            .entity_event(target, Pinch { scale: gesture.scale, center: gesture.center });
+    }
+    if gesture.delta != Vec2::ZERO {
+        c.react()
+            .entity_event(target, TouchScroll { delta: gesture.delta });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Movement of two touches between two ticks.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TwoFingerGesture
+{
+    /// Ratio between the current and previous distance between touches.
+    ///
+    /// Greater than `1.0` when the touches move apart.
+    pub scale: f32,
+    /// Movement of the center point between touches, in screen pixels.
+    pub delta: Vec2,
+    /// The current center point between touches, in screen pixels.
+    pub center: Vec2,
+}
+
+impl TwoFingerGesture
+{
+    /// Makes a gesture from the previous and current positions of two touches.
+    pub fn new(previous: (Vec2, Vec2), current: (Vec2, Vec2)) -> Self
+    {
+        let prev_distance = previous.0.distance(previous.1);
+        let distance = current.0.distance(current.1);
+        let scale = match prev_distance > 0. {
+            true => distance / prev_distance,
+            false => 1.,
+        };
+        let prev_center = previous.0.midpoint(previous.1);
+        let center = current.0.midpoint(current.1);
+
+        Self { scale, delta: center - prev_center, center }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Entity event emitted on a [`TouchGestures`] entity when two touches move closer together or farther apart.
+///
+/// Not emitted if the entity has [`PseudoState::Disabled`].
+#[derive(Debug, Clone)]
+pub struct Pinch
+{
+    /// See [`TwoFingerGesture::scale`].
+    pub scale: f32,
+    /// See [`TwoFingerGesture::center`].
+    pub center: Vec2,
+}
+
+/// Entity event emitted on a [`TouchGestures`] entity when two touches move together.
+///
+/// Not emitted if the entity has [`PseudoState::Disabled`].
+#[derive(Debug, Clone)]
+pub struct TouchScroll
+{
+    /// See [`TwoFingerGesture::delta`].
+    pub delta: Vec2,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction that makes an entity receive [`Pinch`] and [`TouchScroll`] entity events when exactly two touches
+/// move over it or its descendants.
+///
+/// Gestures are sent to the nearest `TouchGestures` entity above the top-most entity hovered by the first touch.
+///
+/// Example:
+/**
+```rust
+#scenes
+"map_view"
+    FlexNode{width:100% height:100%}
+    TouchGestures
+```
+*/
+#[derive(Component, Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct TouchGestures;
+
+impl Instruction for TouchGestures
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.insert(self);
+        });
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.remove::<Self>();
+        });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct TouchGesturesPlugin;
+
+impl Plugin for TouchGesturesPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.register_instruction_type::<TouchGestures>()
+            .add_systems(Update, detect_touch_gestures);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
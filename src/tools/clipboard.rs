@@ -0,0 +1,257 @@
+use std::sync::{Arc, Mutex};
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+type ClipboardCallback = Box<dyn FnOnce(&mut World, Option<String>) + Send + Sync + 'static>;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Runs callbacks for clipboard reads that have finished.
+fn poll_clipboard_reads(world: &mut World)
+{
+    let mut clipboard = world.resource_mut::<Clipboard>();
+    if clipboard.pending.is_empty() {
+        return;
+    }
+
+    let mut finished = Vec::default();
+    let mut idx = 0;
+    while idx < clipboard.pending.len() {
+        let Some(text) = clipboard.pending[idx].0.take() else {
+            idx += 1;
+            continue;
+        };
+        let (_, callback) = clipboard.pending.swap_remove(idx);
+        finished.push((text, callback));
+    }
+
+    for (text, callback) in finished {
+        callback(world, text);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// The result of reading text from a [`ClipboardProvider`].
+pub enum ClipboardRead
+{
+    /// The read finished immediately. Contains `None` if the clipboard doesn't contain text.
+    Ready(Option<String>),
+    /// The read will finish later.
+    Pending(PendingClipboardRead),
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Handle for an asynchronous clipboard read.
+///
+/// Providers should call [`Self::finish`] when the read completes.
+#[derive(Clone, Default)]
+pub struct PendingClipboardRead(Arc<Mutex<Option<Option<String>>>>);
+
+impl PendingClipboardRead
+{
+    /// Sets the result of the read.
+    pub fn finish(&self, text: Option<String>)
+    {
+        *self.0.lock().unwrap() = Some(text);
+    }
+
+    fn take(&self) -> Option<Option<String>>
+    {
+        self.0.lock().unwrap().take()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Trait for clipboard backends used by [`Clipboard`].
+pub trait ClipboardProvider: Send + Sync + 'static
+{
+    /// Writes text to the clipboard.
+    fn set_text(&mut self, text: String);
+    /// Reads text from the clipboard.
+    fn get_text(&mut self) -> ClipboardRead;
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Clipboard provider that stores text in memory.
+///
+/// Text can only be copied and pasted within the app.
+#[derive(Default, Debug)]
+pub struct LocalClipboard
+{
+    text: Option<String>,
+}
+
+impl ClipboardProvider for LocalClipboard
+{
+    fn set_text(&mut self, text: String)
+    {
+        self.text = Some(text);
+    }
+
+    fn get_text(&mut self) -> ClipboardRead
+    {
+        ClipboardRead::Ready(self.text.clone())
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Clipboard provider that uses the browser's asynchronous clipboard API.
+///
+/// Browsers may ask the user for permission to read the clipboard.
+#[cfg(all(target_family = "wasm", target_os = "unknown"))]
+#[derive(Default, Debug)]
+pub struct WebClipboard;
+
+#[cfg(all(target_family = "wasm", target_os = "unknown"))]
+impl ClipboardProvider for WebClipboard
+{
+    fn set_text(&mut self, text: String)
+    {
+        let Some(window) = web_sys::window() else { return };
+        let promise = window.navigator().clipboard().write_text(&text);
+        wasm_bindgen_futures::spawn_local(async move {
+            if let Err(err) = wasm_bindgen_futures::JsFuture::from(promise).await {
+                tracing::warn!("failed writing to clipboard: {err:?}");
+            }
+        });
+    }
+
+    fn get_text(&mut self) -> ClipboardRead
+    {
+        let Some(window) = web_sys::window() else { return ClipboardRead::Ready(None) };
+        let promise = window.navigator().clipboard().read_text();
+        let pending = PendingClipboardRead::default();
+        let result = pending.clone();
+        wasm_bindgen_futures::spawn_local(async move {
+            let text = match wasm_bindgen_futures::JsFuture::from(promise).await {
+                Ok(value) => value.as_string(),
+                Err(err) => {
+                    tracing::warn!("failed reading from clipboard: {err:?}");
+                    None
+                }
+            };
+            result.finish(text);
+        });
+        ClipboardRead::Pending(pending)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource that provides access to the clipboard.
+///
+/// Use [`ClipboardCommands`] to read and write text from systems.
+///
+/// On WASM targets the browser clipboard is used by default ([`WebClipboard`]). On other targets a
+/// [`LocalClipboard`] is used by default, which only works within the app. To use the system clipboard on
+/// native targets, implement [`ClipboardProvider`] with a platform clipboard crate (e.g. `arboard`) and
+/// set it with [`Self::set_provider`].
+#[derive(Resource)]
+pub struct Clipboard
+{
+    provider: Box<dyn ClipboardProvider>,
+    pending: Vec<(PendingClipboardRead, ClipboardCallback)>,
+}
+
+impl Clipboard
+{
+    /// Makes a new clipboard with the given provider.
+    pub fn new(provider: impl ClipboardProvider) -> Self
+    {
+        Self { provider: Box::new(provider), pending: Vec::default() }
+    }
+
+    /// Replaces the clipboard provider.
+    pub fn set_provider(&mut self, provider: impl ClipboardProvider)
+    {
+        self.provider = Box::new(provider);
+    }
+
+    /// Writes text to the clipboard.
+    pub fn set_text(&mut self, text: impl Into<String>)
+    {
+        self.provider.set_text(text.into());
+    }
+
+    fn get_text(&mut self, world: &mut World, callback: ClipboardCallback)
+    {
+        match self.provider.get_text() {
+            ClipboardRead::Ready(text) => callback(world, text),
+            ClipboardRead::Pending(pending) => self.pending.push((pending, callback)),
+        }
+    }
+}
+
+impl Default for Clipboard
+{
+    fn default() -> Self
+    {
+        #[cfg(all(target_family = "wasm", target_os = "unknown"))]
+        {
+            Self::new(WebClipboard)
+        }
+
+        #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+        {
+            Self::new(LocalClipboard::default())
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// System parameter for reading and writing clipboard text.
+///
+/// Clipboard reads may be asynchronous (e.g. on WASM), so text is passed to a callback.
+#[derive(SystemParam)]
+pub struct ClipboardCommands<'w, 's>
+{
+    c: Commands<'w, 's>,
+}
+
+impl ClipboardCommands<'_, '_>
+{
+    /// Writes text to the clipboard.
+    pub fn set_text(&mut self, text: impl Into<String>)
+    {
+        let text = text.into();
+        self.c.queue(move |world: &mut World| {
+            world.resource_mut::<Clipboard>().set_text(text);
+        });
+    }
+
+    /// Reads text from the clipboard.
+    ///
+    /// The callback receives `None` if the clipboard doesn't contain text or couldn't be read.
+    pub fn get_text(&mut self, callback: impl FnOnce(&mut World, Option<String>) + Send + Sync + 'static)
+    {
+        self.c.queue(move |world: &mut World| {
+            world.resource_scope(|world, mut clipboard: Mut<Clipboard>| {
+                clipboard.get_text(world, Box::new(callback));
+            });
+        });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct ClipboardPlugin;
+
+impl Plugin for ClipboardPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_resource::<Clipboard>()
+            .add_systems(First, poll_clipboard_reads);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,198 @@
+use std::collections::HashMap;
+
+use bevy::ecs::component::ComponentId;
+use bevy::ecs::world::DeferredWorld;
+use bevy::prelude::*;
+
+use crate::prelude::*;
+use crate::sickle::DynamicStyleStopwatch;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn on_insert_tracked_scene(world: DeferredWorld, entity: Entity, _: ComponentId)
+{
+    // Pooled scenes aren't live instances.
+    if world.get::<PooledScene>(entity).is_some() {
+        return;
+    }
+    update_scene_count(world, entity, true);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn on_replace_tracked_scene(world: DeferredWorld, entity: Entity, _: ComponentId)
+{
+    // Pooled scenes were already removed from the counts when they were pooled.
+    if world.get::<PooledScene>(entity).is_some() {
+        return;
+    }
+    update_scene_count(world, entity, false);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn update_scene_count(mut world: DeferredWorld, entity: Entity, added: bool)
+{
+    let Some(scene_ref) = world
+        .get::<TrackedSceneInstance>(entity)
+        .map(|t| t.0.clone())
+    else {
+        return;
+    };
+    let Some(mut metrics) = world.get_resource_mut::<CobwebUiMetrics>() else { return };
+    let metrics = metrics.as_mut();
+
+    if added {
+        *metrics.scene_instances.entry(scene_ref).or_default() += 1;
+        metrics.total_scene_instances += 1;
+    } else {
+        let Some(count) = metrics.scene_instances.get_mut(&scene_ref) else { return };
+        *count = count.saturating_sub(1);
+        if *count == 0 {
+            metrics.scene_instances.remove(&scene_ref);
+        }
+        metrics.total_scene_instances = metrics.total_scene_instances.saturating_sub(1);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Updates scene instance counts when a scene instance is returned to (`pooled = true`) or taken from its scene
+/// pool.
+pub(crate) fn update_pooled_scene_count(world: &mut World, entity: Entity, pooled: bool)
+{
+    update_scene_count(world.into(), entity, !pooled);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn update_cobweb_ui_metrics(
+    nodes: Query<(), With<Node>>,
+    loaded: Query<(), With<HasLoadables>>,
+    animating: Query<(), With<DynamicStyleStopwatch>>,
+    mut metrics: ResMut<CobwebUiMetrics>,
+)
+{
+    let metrics = metrics.bypass_change_detection();
+    metrics.total_nodes = nodes.iter().len();
+    metrics.loaded_nodes = loaded.iter().len();
+    metrics.active_attribute_animations = animating.iter().len();
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Component inserted on the root entity of spawned scenes so live scene instances can be counted.
+#[derive(Component, Debug)]
+#[component(on_insert = on_insert_tracked_scene, on_replace = on_replace_tracked_scene)]
+pub(crate) struct TrackedSceneInstance(pub(crate) SceneRef);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource with live counts of UI objects, for use in debug overlays and tests to catch leaks and runaway
+/// spawns.
+///
+/// This resource is inserted by [`CobwebUiPlugin`]. Metrics are only collected while it exists, so remove it to
+/// disable collection.
+///
+/// Scene instance and reactor counts are updated immediately when scenes and reactors are spawned and despawned.
+/// Other counts are updated in [`Last`] every frame.
+#[derive(Resource, Default, Debug)]
+pub struct CobwebUiMetrics
+{
+    scene_instances: HashMap<SceneRef, usize>,
+    total_scene_instances: usize,
+    total_nodes: usize,
+    loaded_nodes: usize,
+    reactors: usize,
+    active_attribute_animations: usize,
+}
+
+impl CobwebUiMetrics
+{
+    /// Gets the number of live instances of a scene.
+    ///
+    /// Scene instances in a scene pool (see [`PooledScene`]) are not counted.
+    ///
+    /// The scene ref must use a file path, not a manifest key.
+    pub fn scene_instances(&self, scene: &SceneRef) -> usize
+    {
+        self.scene_instances.get(scene).copied().unwrap_or_default()
+    }
+
+    /// Gets the number of live instances of all scenes in a file.
+    pub fn file_scene_instances(&self, file: &CobFile) -> usize
+    {
+        self.scene_instances
+            .iter()
+            .filter(|(scene, _)| matches!(&scene.file, SceneFile::File(f) if f == file))
+            .map(|(_, count)| *count)
+            .sum()
+    }
+
+    /// Iterates the number of live instances of each scene that has at least one instance.
+    pub fn iter_scene_instances(&self) -> impl Iterator<Item = (&SceneRef, usize)> + '_
+    {
+        self.scene_instances
+            .iter()
+            .map(|(scene, count)| (scene, *count))
+    }
+
+    /// Gets the total number of live scene instances.
+    pub fn total_scene_instances(&self) -> usize
+    {
+        self.total_scene_instances
+    }
+
+    /// Gets the total number of entities with [`Node`].
+    pub fn total_nodes(&self) -> usize
+    {
+        self.total_nodes
+    }
+
+    /// Gets the number of entities that have loaded scene node content.
+    pub fn loaded_nodes(&self) -> usize
+    {
+        self.loaded_nodes
+    }
+
+    /// Updates the reactor count when a reactor is attached to (`added = true`) or cleaned up from an entity.
+    pub(crate) fn update_reactor_count(&mut self, added: bool)
+    {
+        if added {
+            self.reactors += 1;
+        } else {
+            self.reactors = self.reactors.saturating_sub(1);
+        }
+    }
+
+    /// Gets the number of reactors attached to entities.
+    ///
+    /// This counts reactors registered with [`UiReactEntityCommandsExt::reactor`] and
+    /// [`UiReactEntityCommandsExt::update_on`], and any other reactors passed to [`cleanup_reactor_on_despawn`].
+    /// Reactors registered directly with `bevy_cobweb` are not counted.
+    pub fn reactors(&self) -> usize
+    {
+        self.reactors
+    }
+
+    /// Gets the number of entities with attribute animations in progress.
+    pub fn active_attribute_animations(&self) -> usize
+    {
+        self.active_attribute_animations
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct CobwebUiMetricsPlugin;
+
+impl Plugin for CobwebUiMetricsPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_resource::<CobwebUiMetrics>()
+            .add_systems(Last, update_cobweb_ui_metrics.run_if(resource_exists::<CobwebUiMetrics>));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
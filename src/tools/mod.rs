@@ -1,10 +1,18 @@
+mod clipboard;
 mod hierarchy_utils;
+mod metrics;
 mod plugin;
+mod profiling;
+mod scene_export;
 mod text_editor;
 mod type_name;
 
+pub use clipboard::*;
 pub use hierarchy_utils::*;
+pub use metrics::*;
 pub(crate) use plugin::*;
+pub use profiling::*;
+pub use scene_export::*;
 pub use text_editor::*;
 pub use type_name::*;
 
@@ -10,7 +10,10 @@ impl Plugin for ToolsPlugin
 {
     fn build(&self, app: &mut App)
     {
-        app.init_resource::<IterChildren>();
+        app.init_resource::<IterChildren>()
+            .add_plugins(ClipboardPlugin)
+            .add_plugins(CobwebUiMetricsPlugin)
+            .add_plugins(SceneExportPlugin);
     }
 }
 
@@ -0,0 +1,399 @@
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::time::Duration;
+
+use bevy::diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+use bevy::ecs::world::Command;
+use bevy::prelude::*;
+use bevy::utils::Instant;
+
+use crate::load_embedded_scene_file;
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Records a loadable being applied to an entity.
+pub(crate) fn record_loadable_apply(world: &mut World, type_id: TypeId, time: Duration)
+{
+    if !world.contains_resource::<CobwebUiProfile>() {
+        return;
+    }
+    let name = world
+        .get_resource::<AppTypeRegistry>()
+        .and_then(|r| r.read().get(type_id).map(|r| r.type_info().type_path_table().short_path()))
+        .unwrap_or("unknown");
+
+    let mut profile = world.resource_mut::<CobwebUiProfile>();
+    let entry = profile
+        .loadables
+        .entry(type_id)
+        .or_insert_with(|| LoadableProfile { name, ..default() });
+    entry.apply_count += 1;
+    entry.total_time += time;
+    entry.max_time = entry.max_time.max(time);
+    profile.frame.loadable_applies += 1;
+    profile.frame.loadable_apply_time += time;
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn update_profiling_diagnostics(mut profile: ResMut<CobwebUiProfile>, mut diagnostics: Diagnostics)
+{
+    let profile = profile.as_mut();
+    profile.last_frame = std::mem::take(&mut profile.frame);
+    let frame = &profile.last_frame;
+
+    diagnostics.add_measurement(&CobwebUiProfilingPlugin::LOADABLE_APPLIES, || frame.loadable_applies as f64);
+    diagnostics.add_measurement(&CobwebUiProfilingPlugin::LOADABLE_APPLY_TIME, || {
+        frame.loadable_apply_time.as_secs_f64() * 1000.
+    });
+    diagnostics.add_measurement(&CobwebUiProfilingPlugin::SCENE_SPAWNS, || frame.scene_spawns as f64);
+    diagnostics.add_measurement(&CobwebUiProfilingPlugin::SCENE_SPAWN_TIME, || {
+        frame.scene_spawn_time.as_secs_f64() * 1000.
+    });
+    diagnostics.add_measurement(&CobwebUiProfilingPlugin::HOT_RELOAD_NODES, || frame.hot_reload_nodes as f64);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn spawn_profiling_overlay(mut c: Commands, mut s: SceneBuilder)
+{
+    c.ui_root()
+        .spawn_scene_and_edit(("builtin.profiling", "overlay"), &mut s, |h| {
+            let text = h.get_entity("text")?;
+            h.insert(ProfilingOverlay { text });
+            OK
+        });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn update_profiling_overlay(
+    mut timer: Local<Duration>,
+    time: Res<Time<Real>>,
+    profile: Res<CobwebUiProfile>,
+    overlays: Query<&ProfilingOverlay>,
+    mut e: TextEditor,
+)
+{
+    // Refresh twice per second so the text is readable.
+    *timer += time.delta();
+    if *timer < Duration::from_millis(500) {
+        return;
+    }
+    *timer = Duration::default();
+
+    let mut text = String::default();
+    let frame = profile.last_frame();
+    let _ = writeln!(
+        text,
+        "loadables: {} ({:.2}ms)\nscenes: {} ({:.2}ms)",
+        frame.loadable_applies,
+        frame.loadable_apply_time.as_secs_f64() * 1000.,
+        frame.scene_spawns,
+        frame.scene_spawn_time.as_secs_f64() * 1000.
+    );
+    #[cfg(feature = "hot_reload")]
+    {
+        let hot_reload = profile.hot_reload();
+        let _ = writeln!(
+            text,
+            "hot reload: {} nodes ({:.2}ms)",
+            hot_reload.reapplied_nodes,
+            hot_reload.total_time.as_secs_f64() * 1000.
+        );
+    }
+
+    let mut loadables: Vec<_> = profile.iter_loadables().map(|(_, l)| l).collect();
+    loadables.sort_unstable_by_key(|l| std::cmp::Reverse(l.total_time));
+    for loadable in loadables.iter().take(ProfilingOverlay::MAX_LOADABLES) {
+        let _ = writeln!(
+            text,
+            "{}: {}x {:.2}ms",
+            loadable.name,
+            loadable.apply_count,
+            loadable.total_time.as_secs_f64() * 1000.
+        );
+    }
+    let text = text.trim_end();
+
+    for overlay in overlays.iter() {
+        write_text!(e, overlay.text, "{}", text);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Command that starts a profiling timer.
+pub(crate) struct StartProfilingTimer;
+
+impl Command for StartProfilingTimer
+{
+    fn apply(self, world: &mut World)
+    {
+        let Some(mut profile) = world.get_resource_mut::<CobwebUiProfile>() else { return };
+        profile.timers.push(Instant::now());
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Command that records a scene spawn, using the timer started by the last [`StartProfilingTimer`].
+///
+/// The scene is `None` if the spawn failed.
+pub(crate) struct RecordSceneSpawn(pub(crate) Option<SceneRef>);
+
+impl Command for RecordSceneSpawn
+{
+    fn apply(self, world: &mut World)
+    {
+        let Some(mut profile) = world.get_resource_mut::<CobwebUiProfile>() else { return };
+        let Some(start) = profile.timers.pop() else { return };
+        let Some(scene) = self.0 else { return };
+        let time = start.elapsed();
+
+        let entry = profile.scenes.entry(scene).or_default();
+        entry.spawn_count += 1;
+        entry.total_time += time;
+        entry.max_time = entry.max_time.max(time);
+        profile.frame.scene_spawns += 1;
+        profile.frame.scene_spawn_time += time;
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Command that records hot-reload reapplications, using the timer started by the last [`StartProfilingTimer`].
+#[cfg(feature = "hot_reload")]
+pub(crate) struct RecordHotReload
+{
+    pub(crate) nodes: usize,
+    pub(crate) reverts: usize,
+}
+
+#[cfg(feature = "hot_reload")]
+impl Command for RecordHotReload
+{
+    fn apply(self, world: &mut World)
+    {
+        let Some(mut profile) = world.get_resource_mut::<CobwebUiProfile>() else { return };
+        let Some(start) = profile.timers.pop() else { return };
+
+        profile.hot_reload.refreshes += 1;
+        profile.hot_reload.reapplied_nodes += self.nodes as u64;
+        profile.hot_reload.reverted_loadables += self.reverts as u64;
+        profile.hot_reload.total_time += start.elapsed();
+        profile.frame.hot_reload_nodes += self.nodes;
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Component on the profiling overlay's root node.
+#[derive(Component, Debug)]
+struct ProfilingOverlay
+{
+    text: Entity,
+}
+
+impl ProfilingOverlay
+{
+    /// Number of loadables displayed in the overlay.
+    const MAX_LOADABLES: usize = 10;
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Profiling stats for a loadable type.
+#[derive(Debug, Clone, Default)]
+pub struct LoadableProfile
+{
+    /// The loadable's short type name.
+    pub name: &'static str,
+    /// The number of times the loadable was applied to an entity.
+    pub apply_count: u64,
+    /// Total time spent applying the loadable.
+    pub total_time: Duration,
+    /// The longest time spent applying the loadable once.
+    pub max_time: Duration,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Profiling stats for spawns of a scene.
+///
+/// Spawn times include building the scene hierarchy, applying loadables to scene nodes, and running spawn
+/// callbacks (which includes spawning nested scenes).
+#[derive(Debug, Clone, Default)]
+pub struct SceneSpawnProfile
+{
+    /// The number of times the scene was spawned.
+    pub spawn_count: u64,
+    /// Total time spent spawning the scene.
+    pub total_time: Duration,
+    /// The longest time spent spawning the scene once.
+    pub max_time: Duration,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Profiling stats for hot-reloaded changes.
+#[derive(Debug, Clone, Default)]
+pub struct HotReloadProfile
+{
+    /// The number of times scene nodes were refreshed due to hot-reloaded changes.
+    pub refreshes: u64,
+    /// The number of times loadables were reapplied to scene node entities.
+    pub reapplied_nodes: u64,
+    /// The number of loadables that were reverted.
+    pub reverted_loadables: u64,
+    /// Total time spent reverting and reapplying loadables.
+    pub total_time: Duration,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Profiling stats for a single frame.
+#[derive(Debug, Clone, Default)]
+pub struct FrameProfile
+{
+    /// The number of loadables applied.
+    pub loadable_applies: usize,
+    /// Time spent applying loadables.
+    pub loadable_apply_time: Duration,
+    /// The number of scenes spawned.
+    pub scene_spawns: usize,
+    /// Time spent spawning scenes.
+    pub scene_spawn_time: Duration,
+    /// The number of scene nodes that had loadables reapplied due to hot-reloaded changes.
+    pub hot_reload_nodes: usize,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource with profiling stats for loadables and scenes.
+///
+/// Only available if [`CobwebUiProfilingPlugin`] is added to the app. Stats accumulate until
+/// [`Self::reset`] is called.
+#[derive(Resource, Debug, Default)]
+pub struct CobwebUiProfile
+{
+    loadables: HashMap<TypeId, LoadableProfile>,
+    scenes: HashMap<SceneRef, SceneSpawnProfile>,
+    hot_reload: HotReloadProfile,
+    frame: FrameProfile,
+    last_frame: FrameProfile,
+    /// Start times of in-progress measurements.
+    timers: Vec<Instant>,
+}
+
+impl CobwebUiProfile
+{
+    /// Gets the profile of a loadable type.
+    pub fn loadable(&self, type_id: TypeId) -> Option<&LoadableProfile>
+    {
+        self.loadables.get(&type_id)
+    }
+
+    /// Iterates the profiles of all loadable types that have been applied.
+    pub fn iter_loadables(&self) -> impl Iterator<Item = (TypeId, &LoadableProfile)> + '_
+    {
+        self.loadables.iter().map(|(id, p)| (*id, p))
+    }
+
+    /// Gets the spawn profile of a scene.
+    ///
+    /// The scene ref must use a file path, not a manifest key.
+    pub fn scene(&self, scene: &SceneRef) -> Option<&SceneSpawnProfile>
+    {
+        self.scenes.get(scene)
+    }
+
+    /// Iterates the spawn profiles of all scenes that have been spawned.
+    pub fn iter_scenes(&self) -> impl Iterator<Item = (&SceneRef, &SceneSpawnProfile)> + '_
+    {
+        self.scenes.iter()
+    }
+
+    /// Gets hot-reload stats.
+    pub fn hot_reload(&self) -> &HotReloadProfile
+    {
+        &self.hot_reload
+    }
+
+    /// Gets the stats of the previous frame.
+    pub fn last_frame(&self) -> &FrameProfile
+    {
+        &self.last_frame
+    }
+
+    /// Clears accumulated stats.
+    pub fn reset(&mut self)
+    {
+        self.loadables.clear();
+        self.scenes.clear();
+        self.hot_reload = HotReloadProfile::default();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Plugin that records profiling stats for loadables and scenes in [`CobwebUiProfile`].
+///
+/// Per-frame stats are also recorded as bevy diagnostics (see the `DiagnosticPath` constants on this type). Enable
+/// [`Self::overlay`] to display stats in a built-in overlay scene.
+///
+/// Must be added after [`CobwebUiPlugin`].
+#[derive(Debug, Default)]
+pub struct CobwebUiProfilingPlugin
+{
+    /// Spawns an overlay that displays profiling stats when entering [`LoadState::Done`].
+    pub overlay: bool,
+}
+
+impl CobwebUiProfilingPlugin
+{
+    /// Number of loadables applied each frame.
+    pub const LOADABLE_APPLIES: DiagnosticPath = DiagnosticPath::const_new("cobweb_ui/loadable_applies");
+    /// Time spent applying loadables each frame, in milliseconds.
+    pub const LOADABLE_APPLY_TIME: DiagnosticPath = DiagnosticPath::const_new("cobweb_ui/loadable_apply_time");
+    /// Number of scenes spawned each frame.
+    pub const SCENE_SPAWNS: DiagnosticPath = DiagnosticPath::const_new("cobweb_ui/scene_spawns");
+    /// Time spent spawning scenes each frame, in milliseconds.
+    pub const SCENE_SPAWN_TIME: DiagnosticPath = DiagnosticPath::const_new("cobweb_ui/scene_spawn_time");
+    /// Number of scene nodes that had loadables reapplied due to hot-reloaded changes each frame.
+    pub const HOT_RELOAD_NODES: DiagnosticPath = DiagnosticPath::const_new("cobweb_ui/hot_reload_nodes");
+
+    /// Makes a plugin that spawns the profiling overlay.
+    pub fn with_overlay() -> Self
+    {
+        Self { overlay: true }
+    }
+}
+
+impl Plugin for CobwebUiProfilingPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.world_mut()
+            .resource_mut::<SceneBuilderInner>()
+            .set_profiling(true);
+
+        app.init_resource::<CobwebUiProfile>()
+            .register_diagnostic(Diagnostic::new(Self::LOADABLE_APPLIES))
+            .register_diagnostic(Diagnostic::new(Self::LOADABLE_APPLY_TIME).with_suffix("ms"))
+            .register_diagnostic(Diagnostic::new(Self::SCENE_SPAWNS))
+            .register_diagnostic(Diagnostic::new(Self::SCENE_SPAWN_TIME).with_suffix("ms"))
+            .register_diagnostic(Diagnostic::new(Self::HOT_RELOAD_NODES))
+            .add_systems(Last, update_profiling_diagnostics);
+
+        if self.overlay {
+            load_embedded_scene_file!(app, "bevy_cobweb_ui", "src/tools", "profiling.cob");
+            app.add_systems(OnEnter(LoadState::Done), spawn_profiling_overlay)
+                .add_systems(Update, update_profiling_overlay.run_if(in_state(LoadState::Done)));
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
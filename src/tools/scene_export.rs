@@ -0,0 +1,402 @@
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use bevy::ecs::world::Command;
+use bevy::prelude::*;
+use bevy::render::camera::RenderTarget;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat, TextureUsages};
+use bevy::render::view::screenshot::{Screenshot, ScreenshotCaptured};
+use bevy_cobweb::prelude::*;
+use serde::Serialize;
+
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Replaces characters that aren't safe in file names.
+fn sanitize_file_name(name: &str) -> String
+{
+    name.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Gets the image path of a scene relative to the export directory.
+///
+/// For example, scene `"menu"` in `ui/main.cob` is exported to `ui/main/menu.png`.
+fn scene_image_path(file: &CobFile, scene: &str) -> PathBuf
+{
+    let file = file.as_str();
+    let file = file.strip_suffix(".cob").unwrap_or(file);
+    let mut path: PathBuf = file.split('/').map(sanitize_file_name).collect();
+    path.push(format!("{}.png", sanitize_file_name(scene)));
+    path
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn save_captured_image(image: &Image, path: &Path) -> Result<(), String>
+{
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|err| format!("failed creating directory: {err}"))?;
+    }
+    let image = image
+        .clone()
+        .try_into_dynamic()
+        .map_err(|err| format!("failed converting image: {err}"))?;
+    image
+        .to_rgba8()
+        .save(path)
+        .map_err(|err| format!("failed saving image: {err}"))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn write_scene_image_index(output_dir: &Path, entries: &[SceneImageIndexEntry]) -> Result<(), String>
+{
+    std::fs::create_dir_all(output_dir).map_err(|err| format!("failed creating directory: {err}"))?;
+    let index = serde_json::to_string_pretty(entries).map_err(|err| format!("failed serializing index: {err}"))?;
+    std::fs::write(output_dir.join("index.json"), index).map_err(|err| format!("failed writing index: {err}"))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn start_scene_capture(
+    c: &mut Commands,
+    scene_builder: &mut SceneBuilder,
+    images: &mut Assets<Image>,
+    job: &ExportSceneImages,
+    scene_ref: SceneRef,
+    file: &CobFile,
+) -> SceneCapture
+{
+    let size = job.size.max(UVec2::ONE);
+    let mut image = Image::new_fill(
+        Extent3d { width: size.x, height: size.y, depth_or_array_layers: 1 },
+        TextureDimension::D2,
+        &[0, 0, 0, 0],
+        TextureFormat::Bgra8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    image.texture_descriptor.usage = TextureUsages::TEXTURE_BINDING
+        | TextureUsages::COPY_DST
+        | TextureUsages::COPY_SRC
+        | TextureUsages::RENDER_ATTACHMENT;
+    let image = images.add(image);
+
+    let camera = c
+        .spawn((
+            Camera2d,
+            Camera {
+                target: RenderTarget::Image(image.clone()),
+                clear_color: ClearColorConfig::Custom(Color::NONE),
+                ..default()
+            },
+        ))
+        .id();
+
+    // The scene is spawned into a root node with the target size so percentage-based layouts work.
+    let scene = scene_ref.path.iter().next().unwrap_or_default().to_string();
+    let root = c
+        .spawn((
+            Node {
+                width: Val::Px(size.x as f32),
+                height: Val::Px(size.y as f32),
+                ..default()
+            },
+            TargetCamera(camera),
+        ))
+        .id();
+    c.entity(root).spawn_scene(scene_ref, scene_builder);
+
+    SceneCapture {
+        path: scene_image_path(file, &scene),
+        file: file.as_str().into(),
+        scene,
+        size,
+        camera,
+        root,
+        image,
+        frames_remaining: job.settle_frames.max(1),
+        result: Arc::default(),
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn request_scene_capture(c: &mut Commands, output_dir: &Path, capture: &SceneCapture)
+{
+    let path = output_dir.join(&capture.path);
+    let result = capture.result.clone();
+    c.spawn(Screenshot::image(capture.image.clone()))
+        .observe(move |trigger: Trigger<ScreenshotCaptured>| {
+            *result.lock().unwrap() = Some(save_captured_image(&trigger.event().0, &path));
+        });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn collect_export_scenes(job: &ExportSceneImages, scene_builder: &SceneBuilder) -> VecDeque<(SceneRef, CobFile)>
+{
+    let mut scenes = VecDeque::default();
+    for file in job.files.iter() {
+        let Some(file_scenes) = scene_builder.file_scenes(&SceneFile::new(file)) else {
+            tracing::warn!("failed exporting scene images for {file:?}; the file has not loaded");
+            continue;
+        };
+        if file_scenes.is_empty() {
+            tracing::warn!("failed exporting scene images for {file:?}; no scenes found");
+            continue;
+        }
+        for scene_ref in file_scenes {
+            let Some(cob_file) = scene_ref.file.file().cloned() else { continue };
+            scenes.push_back((scene_ref, cob_file));
+        }
+    }
+    scenes
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn update_scene_image_exports(
+    mut c: Commands,
+    mut exports: ResMut<SceneImageExports>,
+    mut scene_builder: SceneBuilder,
+    mut images: ResMut<Assets<Image>>,
+)
+{
+    let exports = exports.as_mut();
+
+    if exports.active.is_none() {
+        let Some(job) = exports.jobs.pop_front() else { return };
+        tracing::info!("exporting scene images to {:?}", job.output_dir);
+        let queue = collect_export_scenes(&job, &scene_builder);
+        exports.active = Some(ActiveSceneExport { job, queue, entries: Vec::default(), capture: None });
+    }
+    let Some(active) = exports.active.as_mut() else { return };
+
+    // Start capturing the next scene.
+    let Some(capture) = active.capture.as_mut() else {
+        let Some((scene_ref, file)) = active.queue.pop_front() else {
+            let active = exports.active.take().unwrap();
+            if let Err(err) = write_scene_image_index(&active.job.output_dir, &active.entries) {
+                tracing::warn!("failed exporting scene images to {:?}; {err}", active.job.output_dir);
+            }
+            c.react()
+                .broadcast(SceneImagesExported { output_dir: active.job.output_dir, entries: active.entries });
+            return;
+        };
+        let capture = start_scene_capture(&mut c, &mut scene_builder, &mut images, &active.job, scene_ref, &file);
+        active.capture = Some(capture);
+        return;
+    };
+
+    // Wait for the scene to finish layout and load its assets.
+    if capture.frames_remaining > 0 {
+        capture.frames_remaining -= 1;
+        if capture.frames_remaining == 0 {
+            request_scene_capture(&mut c, &active.job.output_dir, capture);
+        }
+        return;
+    }
+
+    // Wait for the screenshot to be saved.
+    let Some(result) = capture.result.lock().unwrap().take() else { return };
+    let capture = active.capture.take().unwrap();
+    c.entity(capture.camera).despawn_recursive();
+    c.entity(capture.root).despawn_recursive();
+    images.remove(&capture.image);
+
+    match result {
+        Ok(()) => {
+            active.entries.push(SceneImageIndexEntry {
+                file: capture.file,
+                scene: capture.scene,
+                path: capture.path.to_string_lossy().replace('\\', "/"),
+                size: capture.size,
+            });
+        }
+        Err(err) => {
+            tracing::warn!("failed exporting image for scene {:?} in {:?}; {err}", capture.scene, capture.file);
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+struct SceneCapture
+{
+    /// Image path relative to the export directory.
+    path: PathBuf,
+    file: String,
+    scene: String,
+    size: UVec2,
+    camera: Entity,
+    root: Entity,
+    image: Handle<Image>,
+    frames_remaining: u32,
+    /// Set when the screenshot is captured and saved.
+    result: Arc<Mutex<Option<Result<(), String>>>>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+struct ActiveSceneExport
+{
+    job: ExportSceneImages,
+    queue: VecDeque<(SceneRef, CobFile)>,
+    entries: Vec<SceneImageIndexEntry>,
+    capture: Option<SceneCapture>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource, Default)]
+struct SceneImageExports
+{
+    jobs: VecDeque<ExportSceneImages>,
+    active: Option<ActiveSceneExport>,
+}
+
+impl SceneImageExports
+{
+    fn is_running(&self) -> bool
+    {
+        self.active.is_some() || !self.jobs.is_empty()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// An entry in the `index.json` file written by [`ExportSceneImages`].
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct SceneImageIndexEntry
+{
+    /// The COB file that contains the scene.
+    pub file: String,
+    /// The scene name.
+    pub scene: String,
+    /// The image path relative to the export directory, with `/` separators.
+    pub path: String,
+    /// The image size in pixels.
+    pub size: UVec2,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reactive event broadcast when [`ExportSceneImages`] finishes.
+#[derive(Debug, Clone)]
+pub struct SceneImagesExported
+{
+    /// The directory images were exported to.
+    pub output_dir: PathBuf,
+    /// Scenes that were exported successfully.
+    pub entries: Vec<SceneImageIndexEntry>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Command for rendering every scene in a set of COB files to PNG images.
+///
+/// Useful for keeping design documentation and galleries in sync with assets, e.g. from a dev command or a test
+/// app with rendering enabled.
+///
+/// Scenes are spawned one at a time into a root node of [`Self::size`] that renders to an offscreen image, and
+/// captured after [`Self::settle_frames`] frames. Images are saved to `{output_dir}/{file}/{scene}.png` (without
+/// the file's `.cob` extension). When all scenes have been exported, an `index.json` file is written to
+/// `output_dir` with a list of [`SceneImageIndexEntry`], and [`SceneImagesExported`] is broadcast.
+///
+/// Exports start once [`LoadState::Done`] is entered. Only one export runs at a time, additional exports are
+/// queued.
+#[derive(Debug, Clone)]
+pub struct ExportSceneImages
+{
+    /// The directory images are saved to.
+    pub output_dir: PathBuf,
+    /// Files to export scenes from. Can be file paths or manifest keys.
+    pub files: Vec<String>,
+    /// The size of exported images.
+    ///
+    /// Defaults to `800x600`.
+    pub size: UVec2,
+    /// The number of frames to wait after spawning a scene before capturing it.
+    ///
+    /// Increase this if scenes use images or fonts that haven't loaded yet, or animations that need time to
+    /// settle. Defaults to `10`.
+    pub settle_frames: u32,
+}
+
+impl ExportSceneImages
+{
+    /// Makes a command that exports images to `output_dir`.
+    pub fn new(output_dir: impl Into<PathBuf>) -> Self
+    {
+        Self {
+            output_dir: output_dir.into(),
+            files: Vec::default(),
+            size: UVec2::new(800, 600),
+            settle_frames: 10,
+        }
+    }
+
+    /// Adds a file to export scenes from.
+    pub fn with_file(mut self, file: impl Into<String>) -> Self
+    {
+        self.files.push(file.into());
+        self
+    }
+
+    /// Sets [`Self::size`].
+    pub fn with_size(mut self, size: UVec2) -> Self
+    {
+        self.size = size;
+        self
+    }
+
+    /// Sets [`Self::settle_frames`].
+    pub fn with_settle_frames(mut self, frames: u32) -> Self
+    {
+        self.settle_frames = frames;
+        self
+    }
+}
+
+impl Command for ExportSceneImages
+{
+    fn apply(self, world: &mut World)
+    {
+        world
+            .resource_mut::<SceneImageExports>()
+            .jobs
+            .push_back(self);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct SceneExportPlugin;
+
+impl Plugin for SceneExportPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_resource::<SceneImageExports>().add_systems(
+            Update,
+            update_scene_image_exports
+                .run_if(in_state(LoadState::Done))
+                .run_if(|exports: Res<SceneImageExports>| exports.is_running()),
+        );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -5,7 +5,7 @@ use std::ops::DerefMut;
 use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 
-use crate::prelude::{FontMap, FontRequest, LocalizedText, TextLocalizer};
+use crate::prelude::{FontMap, FontRequest, LocalizedText, TextArgs, TextLocalizer};
 
 //-------------------------------------------------------------------------------------------------------------------
 
@@ -108,6 +108,55 @@ impl<'w, 's> TextEditor<'w, 's>
         }
     }
 
+    /// Overwrites the text on the first text span in a text block with a localization template and structured
+    /// args.
+    ///
+    /// See [`Self::write_span_with_args`].
+    ///
+    /// This is used in the [`write_text_args`](crate::write_text_args) helper macro.
+    pub fn write_with_args(
+        &mut self,
+        root_entity: Entity,
+        template: &str,
+        args: impl FnOnce(&mut TextArgs),
+    ) -> bool
+    {
+        self.write_span_with_args(root_entity, 0, template, args)
+    }
+
+    /// Overwrites the text on a text span in a text block with a localization template and structured args.
+    ///
+    /// Unlike args embedded in templates (e.g. `game-score?score=10`), structured args are passed to `fluent`
+    /// directly. Numbers are formatted with locale-specific separators and can be used for plural selection.
+    /// See [`TextArgs`].
+    ///
+    /// Returns `false` if the text span could not be accessed, if the entity doesn't have [`LocalizedText`], or
+    /// if localization fails.
+    ///
+    /// This is used in the [`write_text_span_args`](crate::write_text_span_args) helper macro.
+    pub fn write_span_with_args(
+        &mut self,
+        root_entity: Entity,
+        span: usize,
+        template: &str,
+        args: impl FnOnce(&mut TextArgs),
+    ) -> bool
+    {
+        let Some((_, _, mut text, mut text_font, _)) = self.writer.get(root_entity, span) else {
+            tracing::warn!("failed writing to text span {span} of text block {root_entity:?}, entity not found");
+            return false;
+        };
+        let Ok(mut localized) = self.localized.get_mut(root_entity) else {
+            tracing::warn!("failed writing to text span {span} of text block {root_entity:?} with args, entity \
+                doesn't have LocalizedText");
+            return false;
+        };
+
+        localized.set_localization_for_span(template, span);
+        (args)(&mut localized.localization_for_span_mut(span).unwrap().args);
+        localized.localize_span(&self.localizer, &self.fonts, &mut text, &mut text_font.font, span)
+    }
+
     /// Sets the font on the first text span of a text block.
     ///
     /// See [`Self::set_span_font`].
@@ -254,3 +303,62 @@ macro_rules! write_text_span {
 }
 
 //-------------------------------------------------------------------------------------------------------------------
+
+/// Helper for writing localized text with structured args with a [`TextEditor`].
+///
+/// Arg names must be identifiers. Use [`TextEditor::write_with_args`] directly for names that contain dashes.
+///
+/// Example
+/*
+```rust
+fn example(mut commands: Commands, mut text_editor: TextEditor)
+{
+    let entity = commands.spawn((Text::default(), LocalizedText::default())).id();
+
+    // Macro call:
+    write_text_args!(text_editor, entity, "inbox-count", count = 42, name = "Bob");
+
+    // Expands to:
+    text_editor.write_with_args(entity, "inbox-count", |args| {
+        args.set("count", 42);
+        args.set("name", "Bob");
+    });
+}
+```
+*/
+#[macro_export]
+macro_rules! write_text_args {
+    ($editor: ident, $entity: expr, $template: expr $(, $key: ident = $value: expr)* $(,)?) => {{
+        $editor.write_with_args($entity, $template, |_args| { $(_args.set(stringify!($key), $value);)* })
+    }};
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Helper for writing localized text with structured args to a text span with a [`TextEditor`].
+///
+/// Example
+/*
+```rust
+fn example(mut commands: Commands, mut text_editor: TextEditor)
+{
+    let entity = commands.spawn((Text::default(), LocalizedText::default())).id();
+
+    // Macro call:
+    write_text_span_args!(text_editor, entity, 0, "inbox-count", count = 42);
+
+    // Expands to:
+    text_editor.write_span_with_args(entity, 0, "inbox-count", |args| {
+        args.set("count", 42);
+    });
+}
+```
+*/
+#[macro_export]
+macro_rules! write_text_span_args {
+    ($editor: ident, $entity: expr, $span: expr, $template: expr $(, $key: ident = $value: expr)* $(,)?) => {{
+        $editor.write_span_with_args($entity, $span, $template, |_args| { $(_args.set(stringify!($key), $value);)* })
+    }};
+}
+
+//-------------------------------------------------------------------------------------------------------------------
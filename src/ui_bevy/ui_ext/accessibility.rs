@@ -0,0 +1,365 @@
+use accesskit::{Role, Toggled};
+use bevy::a11y::{AccessibilityNode, AccessibilitySystem};
+use bevy::prelude::*;
+
+use crate::prelude::*;
+use crate::sickle::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Publishes [`AccessibleRole`], [`AccessibleLabel`], [`AccessibleValue`], and pseudo states to
+/// [`AccessibilityNode`] components.
+///
+/// Nodes are updated in-place so bounds computed by `bevy_ui` are preserved. Changes made to a node by other
+/// systems (e.g. `bevy_ui` inserting a label for [`Button`]) are overwritten on the next update.
+fn update_accessibility_nodes(
+    mut c: Commands,
+    localizer: Res<TextLocalizer>,
+    mut nodes: Query<
+        (
+            Entity,
+            Option<Ref<AccessibleRole>>,
+            Option<Ref<AccessibleLabel>>,
+            Option<Ref<AccessibleValue>>,
+            Option<Ref<PseudoStates>>,
+            Option<&mut AccessibilityNode>,
+        ),
+        Or<(With<AccessibleRole>, With<AccessibleLabel>)>,
+    >,
+    mut buffer: Local<String>,
+)
+{
+    for (entity, role, label, value, states, node) in nodes.iter_mut() {
+        let refresh = localizer.is_changed()
+            || role.as_ref().is_some_and(|r| r.is_changed())
+            || label.as_ref().is_some_and(|l| l.is_changed())
+            || value.as_ref().is_some_and(|v| v.is_changed())
+            || states.as_ref().is_some_and(|s| s.is_changed())
+            || node.as_ref().is_none_or(|n| n.is_changed());
+        if !refresh {
+            continue;
+        }
+
+        let label = label.map(|l| {
+            buffer.clear();
+            if localizer.localize(&l.0, &mut buffer).is_none() {
+                buffer.clone_from(&l.0);
+            }
+            buffer.as_str()
+        });
+        let role = role.map(|r| *r);
+        let value = value.as_deref();
+        let states = states.as_deref();
+
+        match (role, node) {
+            (Some(role), Some(mut node)) => {
+                role.update_node(&mut node.0, label, value, states);
+            }
+            // Only the label is managed for nodes inserted by other systems (e.g. `bevy_ui` buttons).
+            (None, Some(mut node)) => {
+                if let Some(label) = label {
+                    node.set_label(label);
+                }
+            }
+            (role, None) => {
+                let mut node = accesskit::Node::new(Role::Unknown);
+                role.unwrap_or_default()
+                    .update_node(&mut node, label, value, states);
+                c.entity(entity).try_insert(AccessibilityNode(node));
+            }
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Marks an [`AccessibleRole`] that was inserted by a built-in widget instead of the `AccessibleRole`
+/// instruction.
+#[derive(Component, Debug)]
+struct DefaultAccessibleRole;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction that publishes a role for an entity to screen readers via [`AccessibilityNode`].
+///
+/// The node's state is derived from the entity's pseudo states:
+/// - [`PseudoState::Checked`] marks checkboxes, radio buttons, and switches as checked. Radio buttons are also
+///   checked by [`PseudoState::Selected`].
+/// - [`PseudoState::Selected`] marks other roles as selected (e.g. list items and tabs).
+/// - [`PseudoState::Disabled`] marks the node as disabled.
+///
+/// Values are published from the [`AccessibleValue`] component. Labels are published from
+/// [`AccessibleLabel`].
+///
+/// Built-in widgets like [`Checkbox`](crate::prelude::Checkbox) insert a default role, which is overridden by
+/// this instruction.
+///
+/// Example:
+/**
+```rust
+#scenes
+"save_button"
+    AccessibleRole::Button
+    AccessibleLabel("save-button-label")
+```
+*/
+#[derive(Component, Reflect, Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum AccessibleRole
+{
+    /// A group of related nodes.
+    #[default]
+    Group,
+    Button,
+    Checkbox,
+    RadioButton,
+    /// A toggle switch.
+    Switch,
+    Slider,
+    TextInput,
+    MultilineTextInput,
+    /// A text input with hidden text.
+    PasswordInput,
+    Label,
+    Heading,
+    Image,
+    Link,
+    List,
+    ListItem,
+    Menu,
+    MenuItem,
+    Tab,
+    TabList,
+    TabPanel,
+    Dialog,
+    Tooltip,
+    ProgressBar,
+    ScrollView,
+}
+
+impl AccessibleRole
+{
+    /// Gets the `accesskit` role.
+    pub fn role(&self) -> Role
+    {
+        match self {
+            Self::Group => Role::Group,
+            Self::Button => Role::Button,
+            Self::Checkbox => Role::CheckBox,
+            Self::RadioButton => Role::RadioButton,
+            Self::Switch => Role::Switch,
+            Self::Slider => Role::Slider,
+            Self::TextInput => Role::TextInput,
+            Self::MultilineTextInput => Role::MultilineTextInput,
+            Self::PasswordInput => Role::PasswordInput,
+            Self::Label => Role::Label,
+            Self::Heading => Role::Heading,
+            Self::Image => Role::Image,
+            Self::Link => Role::Link,
+            Self::List => Role::List,
+            Self::ListItem => Role::ListItem,
+            Self::Menu => Role::Menu,
+            Self::MenuItem => Role::MenuItem,
+            Self::Tab => Role::Tab,
+            Self::TabList => Role::TabList,
+            Self::TabPanel => Role::TabPanel,
+            Self::Dialog => Role::Dialog,
+            Self::Tooltip => Role::Tooltip,
+            Self::ProgressBar => Role::ProgressIndicator,
+            Self::ScrollView => Role::ScrollView,
+        }
+    }
+
+    /// Returns `true` if the role's checked state is published as 'toggled'.
+    pub fn is_toggleable(&self) -> bool
+    {
+        matches!(self, Self::Checkbox | Self::RadioButton | Self::Switch)
+    }
+
+    /// Updates an `accesskit` node with this role, an optional label, an optional value, and state derived from
+    /// pseudo states.
+    ///
+    /// If `label` is `None`, the node's existing label is not changed.
+    pub fn update_node(
+        &self,
+        node: &mut accesskit::Node,
+        label: Option<&str>,
+        value: Option<&AccessibleValue>,
+        states: Option<&PseudoStates>,
+    )
+    {
+        let has = |state: PseudoState| states.is_some_and(|s| s.has(&state));
+
+        node.set_role(self.role());
+        if let Some(label) = label {
+            node.set_label(label);
+        }
+
+        if self.is_toggleable() {
+            let checked = has(PseudoState::Checked) || (*self == Self::RadioButton && has(PseudoState::Selected));
+            node.set_toggled(if checked {
+                Toggled::True
+            } else {
+                Toggled::False
+            });
+            node.clear_selected();
+        } else {
+            node.clear_toggled();
+            match has(PseudoState::Selected) {
+                true => node.set_selected(true),
+                false => node.clear_selected(),
+            }
+        }
+
+        match has(PseudoState::Disabled) {
+            true => node.set_disabled(),
+            false => node.clear_disabled(),
+        }
+
+        match value {
+            Some(AccessibleValue::Number { value, min, max }) => {
+                node.set_numeric_value(*value);
+                node.set_min_numeric_value(*min);
+                node.set_max_numeric_value(*max);
+                node.clear_value();
+            }
+            Some(AccessibleValue::Text(text)) => {
+                node.set_value(text.as_str());
+                node.clear_numeric_value();
+                node.clear_min_numeric_value();
+                node.clear_max_numeric_value();
+            }
+            None => {
+                node.clear_value();
+                node.clear_numeric_value();
+                node.clear_min_numeric_value();
+                node.clear_max_numeric_value();
+            }
+        }
+    }
+
+    #[cfg(feature = "widgets")]
+    /// Inserts this role on a built-in widget unless a role was set with the `AccessibleRole` instruction.
+    pub(crate) fn apply_default(self, emut: &mut EntityWorldMut)
+    {
+        if emut.contains::<AccessibleRole>() && !emut.contains::<DefaultAccessibleRole>() {
+            return;
+        }
+        emut.insert((self, DefaultAccessibleRole));
+    }
+
+    #[cfg(feature = "widgets")]
+    /// Removes a role inserted with [`Self::apply_default`].
+    pub(crate) fn revert_default(emut: &mut EntityWorldMut)
+    {
+        if emut.take::<DefaultAccessibleRole>().is_none() {
+            return;
+        }
+        emut.remove::<(AccessibleRole, AccessibleValue, AccessibilityNode)>();
+    }
+}
+
+impl Instruction for AccessibleRole
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.remove::<DefaultAccessibleRole>();
+            e.insert(self);
+        });
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.remove::<(Self, AccessibilityNode)>();
+        });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction that publishes a label for an entity to screen readers via [`AccessibilityNode`].
+///
+/// The label is localized with [`TextLocalizer`] if possible, and is relocalized when languages change.
+///
+/// If the entity doesn't have an [`AccessibleRole`], then the label is added to its existing
+/// [`AccessibilityNode`] (e.g. the node `bevy_ui` inserts for [`Button`]), or a node with
+/// [`AccessibleRole::Group`] is inserted.
+///
+/// Example:
+/**
+```rust
+#scenes
+"volume"
+    Slider
+    AccessibleLabel("volume-label")
+```
+*/
+#[derive(Component, Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct AccessibleLabel(pub String);
+
+impl Instruction for AccessibleLabel
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.insert(self);
+        });
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.remove::<Self>();
+            if let Some(mut node) = e.get_mut::<AccessibilityNode>() {
+                node.clear_label();
+            }
+        });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Component with the value of an accessible entity, published to screen readers via [`AccessibilityNode`].
+///
+/// Built-in widgets like [`Slider`](crate::prelude::Slider) keep this up to date automatically.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub enum AccessibleValue
+{
+    /// A number in a range (e.g. for sliders and progress bars).
+    Number
+    {
+        value: f64, min: f64, max: f64
+    },
+    /// A text value (e.g. for text inputs).
+    Text(String),
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct AccessibilityExtPlugin;
+
+impl Plugin for AccessibilityExtPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.register_instruction_type::<AccessibleRole>()
+            .register_instruction_type::<AccessibleLabel>()
+            .add_systems(
+                PostUpdate,
+                update_accessibility_nodes.before(AccessibilitySystem::Update),
+            );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
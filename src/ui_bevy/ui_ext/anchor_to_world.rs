@@ -0,0 +1,404 @@
+use bevy::prelude::*;
+use bevy::transform::helper::TransformHelper;
+use bevy::ui::UiSystem;
+
+use crate::prelude::*;
+use crate::sickle::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Finds an entity from a path of [`Name`]s separated by `::`.
+///
+/// The first segment can match any named entity. Subsequent segments match children of the previous entity.
+fn find_named_entity(
+    path: &str,
+    names: &Query<(Entity, &Name)>,
+    named: &Query<&Name>,
+    children: &Query<&Children>,
+) -> Option<Entity>
+{
+    let mut segments = path.split("::").map(|s| s.trim());
+    let first = segments.next().filter(|s| !s.is_empty())?;
+    let mut current = names
+        .iter()
+        .find(|(_, name)| name.as_str() == first)
+        .map(|(e, _)| e)?;
+
+    for segment in segments {
+        current = children.get(current).ok()?.iter().copied().find(|child| {
+            named
+                .get(*child)
+                .map(|name| name.as_str() == segment)
+                .unwrap_or(false)
+        })?;
+    }
+
+    Some(current)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resolves the target of each anchored node and computes the node's viewport position.
+fn compute_world_anchors(
+    ui_camera: DefaultUiCamera,
+    cameras: Query<&Camera>,
+    names: Query<(Entity, &Name)>,
+    named: Query<&Name>,
+    children: Query<&Children>,
+    transforms: TransformHelper,
+    mut nodes: Query<(
+        Entity,
+        &AnchorToWorld,
+        &mut WorldAnchorState,
+        Option<&TargetCamera>,
+        &ComputedNode,
+    )>,
+) -> Vec<(Entity, Option<WorldAnchorUpdate>)>
+{
+    let mut updates = Vec::with_capacity(nodes.iter().len());
+
+    for (entity, anchor, mut state, maybe_camera, computed) in nodes.iter_mut() {
+        // Resolve the target.
+        let target = match &anchor.target {
+            WorldAnchorTarget::Entity(target) => Some(*target),
+            WorldAnchorTarget::Path(path) => {
+                let cached = state
+                    .resolved
+                    .filter(|e| transforms.compute_global_transform(*e).is_ok());
+                let resolved = cached.or_else(|| find_named_entity(path, &names, &named, &children));
+                state.resolved = resolved;
+                resolved
+            }
+        };
+
+        // Project the target into the camera's viewport.
+        let update = (|| {
+            let target_transform = transforms.compute_global_transform(target?).ok()?;
+            let camera_entity = maybe_camera
+                .map(|c| c.entity())
+                .or_else(|| ui_camera.get())?;
+            let camera = cameras.get(camera_entity).ok()?;
+            let camera_transform = transforms.compute_global_transform(camera_entity).ok()?;
+            let world_pos = target_transform.translation() + anchor.offset;
+            let viewport_pos = camera
+                .world_to_viewport(&camera_transform, world_pos)
+                .ok()?;
+            let viewport_size = camera.logical_viewport_size()?;
+
+            let scale = anchor
+                .scale_reference_distance
+                .filter(|d| *d > 0.)
+                .map(|reference| {
+                    let distance = camera_transform
+                        .translation()
+                        .distance(world_pos)
+                        .max(f32::EPSILON);
+                    (reference / distance).clamp(anchor.min_scale, anchor.max_scale.max(anchor.min_scale))
+                })
+                .unwrap_or(1.);
+
+            // Center the node on the projected point.
+            let size = computed.size() * computed.inverse_scale_factor() * scale;
+            let mut center = viewport_pos;
+            let margin = Vec2::splat(anchor.screen_margin);
+            let min = margin + size * 0.5;
+            let max = viewport_size - margin - size * 0.5;
+            let on_screen = viewport_pos.cmpge(min).all() && viewport_pos.cmple(max).all();
+            if !on_screen {
+                if !anchor.clamp_to_screen {
+                    return None;
+                }
+                center = center.clamp(min, max.max(min));
+            }
+
+            // Layout positions the unscaled node, and scaling happens around the node's center.
+            let unscaled_size = computed.size() * computed.inverse_scale_factor();
+            Some(WorldAnchorUpdate { position: center - unscaled_size * 0.5, scale })
+        })();
+
+        updates.push((entity, update));
+    }
+
+    updates
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn apply_world_anchors(
+    In(updates): In<Vec<(Entity, Option<WorldAnchorUpdate>)>>,
+    mut nodes: Query<(&mut Node, &mut Transform, &mut Visibility, &mut WorldAnchorState), With<AnchorToWorld>>,
+)
+{
+    for (entity, update) in updates {
+        let Ok((mut node, mut transform, mut visibility, mut state)) = nodes.get_mut(entity) else { continue };
+
+        // Only visibility changed by this system is restored, so users can still hide anchored nodes.
+        let Some(update) = update else {
+            if !state.hidden && *visibility != Visibility::Hidden {
+                *visibility = Visibility::Hidden;
+                state.hidden = true;
+            }
+            continue;
+        };
+        if state.hidden {
+            *visibility = Visibility::Inherited;
+            state.hidden = false;
+        }
+
+        let left = Val::Px(update.position.x);
+        let top = Val::Px(update.position.y);
+        if node.position_type != PositionType::Absolute || node.left != left || node.top != top {
+            node.position_type = PositionType::Absolute;
+            node.left = left;
+            node.top = top;
+        }
+        let scale = Vec3::new(update.scale, update.scale, 1.);
+        if transform.scale != scale {
+            transform.scale = scale;
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Debug, Copy, Clone)]
+struct WorldAnchorUpdate
+{
+    /// Logical position of the node's top-left corner.
+    position: Vec2,
+    scale: f32,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Component, Default, Debug)]
+struct WorldAnchorState
+{
+    /// Cached target entity for [`WorldAnchorTarget::Path`].
+    resolved: Option<Entity>,
+    /// Whether the node was hidden by [`apply_world_anchors`].
+    hidden: bool,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// The target of an [`AnchorToWorld`] instruction.
+#[derive(Reflect, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum WorldAnchorTarget
+{
+    /// A specific entity.
+    Entity(Entity),
+    /// A path of [`Name`]s separated by `::`, e.g. `"player::head"`.
+    ///
+    /// The first segment can match any named entity, and subsequent segments match children of the previous
+    /// entity. The target is cached once found, and looked up again if it is despawned.
+    Path(String),
+}
+
+impl Default for WorldAnchorTarget
+{
+    fn default() -> Self
+    {
+        Self::Path(String::default())
+    }
+}
+
+impl From<Entity> for WorldAnchorTarget
+{
+    fn from(entity: Entity) -> Self
+    {
+        Self::Entity(entity)
+    }
+}
+
+impl From<&str> for WorldAnchorTarget
+{
+    fn from(path: &str) -> Self
+    {
+        Self::Path(path.into())
+    }
+}
+
+impl From<String> for WorldAnchorTarget
+{
+    fn from(path: String) -> Self
+    {
+        Self::Path(path)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction that keeps a UI node positioned over a world-space entity (e.g. for health bars and nameplates).
+///
+/// Each frame the target's position (plus [`Self::offset`]) is projected into the viewport of the node's
+/// [`TargetCamera`] (or the default UI camera), and the node is centered on that point with absolute positioning.
+/// The node should be a root node or inside a parent that covers the camera's viewport.
+///
+/// The node is hidden while the target is missing or behind the camera. Off-screen targets are clamped to the
+/// edge of the viewport if [`Self::clamp_to_screen`] is set, otherwise the node is hidden.
+///
+/// Example:
+/**
+```rust
+#scenes
+"nameplate"
+    AnchorToWorld{ target: Path("enemy::head") offset: (0.0 0.5 0.0) scale_reference_distance: 10.0 }
+    FlexNode{ padding: {left: 5px right: 5px} }
+    BackgroundColor(#80000000)
+
+    "text"
+        TextLine{ text: "Goblin" }
+```
+*/
+#[derive(Component, Reflect, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct AnchorToWorld
+{
+    /// The world-space entity to follow.
+    pub target: WorldAnchorTarget,
+    /// Offset from the target's position in world space.
+    ///
+    /// Defaults to zero.
+    #[reflect(default)]
+    pub offset: Vec3,
+    /// Whether to clamp the node to the viewport edges when the target is off-screen.
+    ///
+    /// Defaults to `true`.
+    #[reflect(default = "AnchorToWorld::default_clamp_to_screen")]
+    pub clamp_to_screen: bool,
+    /// Minimum distance in logical pixels between the node and viewport edges.
+    ///
+    /// Defaults to `0.0`.
+    #[reflect(default)]
+    pub screen_margin: f32,
+    /// If set, the node is scaled by `scale_reference_distance / distance` where `distance` is the distance
+    /// between the camera and the anchor point.
+    ///
+    /// Defaults to `None` (no scaling).
+    #[reflect(default)]
+    pub scale_reference_distance: Option<f32>,
+    /// Minimum scale when scaling with distance.
+    ///
+    /// Defaults to `0.25`.
+    #[reflect(default = "AnchorToWorld::default_min_scale")]
+    pub min_scale: f32,
+    /// Maximum scale when scaling with distance.
+    ///
+    /// Defaults to `1.0`.
+    #[reflect(default = "AnchorToWorld::default_max_scale")]
+    pub max_scale: f32,
+}
+
+impl AnchorToWorld
+{
+    pub fn new(target: impl Into<WorldAnchorTarget>) -> Self
+    {
+        Self { target: target.into(), ..default() }
+    }
+
+    pub fn with_offset(mut self, offset: Vec3) -> Self
+    {
+        self.offset = offset;
+        self
+    }
+
+    pub fn with_scale_reference_distance(mut self, distance: f32) -> Self
+    {
+        self.scale_reference_distance = Some(distance);
+        self
+    }
+
+    fn default_clamp_to_screen() -> bool
+    {
+        true
+    }
+
+    fn default_min_scale() -> f32
+    {
+        0.25
+    }
+
+    fn default_max_scale() -> f32
+    {
+        1.
+    }
+}
+
+impl Default for AnchorToWorld
+{
+    fn default() -> Self
+    {
+        Self {
+            target: WorldAnchorTarget::default(),
+            offset: Vec3::ZERO,
+            clamp_to_screen: Self::default_clamp_to_screen(),
+            screen_margin: 0.,
+            scale_reference_distance: None,
+            min_scale: Self::default_min_scale(),
+            max_scale: Self::default_max_scale(),
+        }
+    }
+}
+
+impl Instruction for AnchorToWorld
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.insert((self, WorldAnchorState::default()));
+        });
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            let hidden = e.take::<WorldAnchorState>().is_some_and(|s| s.hidden);
+            e.remove::<Self>();
+            if let Some(mut node) = e.get_mut::<Node>() {
+                node.left = Val::Auto;
+                node.top = Val::Auto;
+            }
+            if let Some(mut transform) = e.get_mut::<Transform>() {
+                transform.scale = Vec3::ONE;
+            }
+            if hidden {
+                if let Some(mut visibility) = e.get_mut::<Visibility>() {
+                    *visibility = Visibility::Inherited;
+                }
+            }
+        });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct AnchorToWorldPlugin;
+
+impl Plugin for AnchorToWorldPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.register_type::<WorldAnchorTarget>()
+            .register_instruction_type::<AnchorToWorld>()
+            .add_systems(
+                PostUpdate,
+                compute_world_anchors
+                    .pipe(apply_world_anchors)
+                    .after(ControlSet)
+                    .after(DynamicStylePostUpdate)
+                    .before(UiSystem::Prepare),
+            );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,224 @@
+use bevy::prelude::*;
+use bevy::transform::TransformSystem;
+use bevy::ui::UiSystem;
+use bevy::utils::HashMap;
+
+use crate::prelude::*;
+use crate::sickle::{Ease, *};
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Layout position changes smaller than this (in physical pixels) are ignored.
+const LAYOUT_EPSILON: f32 = 0.5;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Offsets children of [`AnimateLayout`] containers from their new layout positions.
+///
+/// Runs after layout each frame. Layout resets a node's `Transform::translation` whenever it differs from the
+/// computed layout position, so the offset applied here is always relative to a freshly-computed position.
+fn animate_layout(
+    time: Res<Time>,
+    mut containers: Query<(
+        &AnimateLayout,
+        &mut AnimateLayoutState,
+        &Children,
+        &ComputedNode,
+        Option<&ScrollPosition>,
+    )>,
+    mut nodes: Query<&mut Transform, With<ComputedNode>>,
+)
+{
+    let delta = time.delta_secs();
+
+    for (settings, mut state, children, computed, maybe_scroll) in containers.iter_mut() {
+        // Scrolling moves all children, so positions are tracked without the scroll offset.
+        let scroll = maybe_scroll
+            .map(|s| (Vec2::new(s.offset_x, s.offset_y) / computed.inverse_scale_factor()).round())
+            .unwrap_or_default();
+
+        let state = &mut *state;
+        state.seen.clear();
+
+        for child in children.iter() {
+            let Ok(mut transform) = nodes.get_mut(*child) else { continue };
+            let position = transform.translation.truncate() + scroll;
+            state.seen.push(*child);
+
+            // Newly-inserted children appear at their layout position.
+            let Some(node) = state.nodes.get_mut(child) else {
+                state.nodes.insert(*child, AnimatedLayoutNode::new(position));
+                continue;
+            };
+
+            // Start a new animation from the node's current visual position if its layout position changed.
+            if node.position.distance_squared(position) > LAYOUT_EPSILON * LAYOUT_EPSILON {
+                let visual_position = node.position + node.offset(settings);
+                node.start_offset = visual_position - position;
+                node.elapsed = 0.;
+                node.position = position;
+            } else {
+                node.elapsed += delta;
+            }
+
+            let offset = node.offset(settings);
+            if offset != Vec2::ZERO {
+                transform.translation += offset.extend(0.);
+            }
+        }
+
+        // Forget removed children.
+        if state.nodes.len() != state.seen.len() {
+            let AnimateLayoutState { nodes, seen } = state;
+            nodes.retain(|entity, _| seen.contains(entity));
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Debug, Copy, Clone)]
+struct AnimatedLayoutNode
+{
+    /// The node's last layout position relative to the container (without scroll), in physical pixels.
+    position: Vec2,
+    /// Offset from `position` when the current animation started.
+    start_offset: Vec2,
+    /// Time since the current animation started.
+    elapsed: f32,
+}
+
+impl AnimatedLayoutNode
+{
+    fn new(position: Vec2) -> Self
+    {
+        Self { position, start_offset: Vec2::ZERO, elapsed: 0. }
+    }
+
+    fn offset(&self, settings: &AnimateLayout) -> Vec2
+    {
+        if self.start_offset == Vec2::ZERO || self.elapsed >= settings.duration {
+            return Vec2::ZERO;
+        }
+        let progress = (self.elapsed / settings.duration).ease(settings.ease);
+        self.start_offset * (1. - progress)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Component, Default, Debug)]
+struct AnimateLayoutState
+{
+    nodes: HashMap<Entity, AnimatedLayoutNode>,
+    /// Buffer for tracking which children still exist.
+    seen: Vec<Entity>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction that animates a container's children to their new positions when the layout changes.
+///
+/// When children are reordered, inserted, removed, or resized, their siblings snap to new positions. With this
+/// instruction, each child whose position relative to the container changed is moved back to where it was
+/// and then tweened to its new position (the 'FLIP' technique). If the layout changes again mid-animation, the
+/// child animates from wherever it currently is.
+///
+/// Only the positions of direct children are animated. Newly-inserted children appear at their layout position,
+/// and size changes are not animated (only the position changes they cause). Scrolling the container does not
+/// trigger animations.
+///
+/// Animated offsets are visual only: they are applied to children's `Transform`s after layout, so they don't
+/// affect the layout of other nodes.
+///
+/// Example:
+/**
+```rust
+#scenes
+"inventory_list"
+    FlexNode{flex_direction:Column}
+    AnimateLayout{duration:0.2 ease:OutCubic}
+```
+*/
+#[derive(Component, Reflect, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct AnimateLayout
+{
+    /// Duration of each animation in seconds.
+    ///
+    /// Defaults to `0.25`.
+    #[reflect(default = "AnimateLayout::default_duration")]
+    pub duration: f32,
+    /// Easing of each animation.
+    ///
+    /// Defaults to [`Ease::OutCubic`].
+    #[reflect(default = "AnimateLayout::default_ease")]
+    pub ease: Ease,
+}
+
+impl AnimateLayout
+{
+    fn default_duration() -> f32
+    {
+        0.25
+    }
+
+    fn default_ease() -> Ease
+    {
+        Ease::OutCubic
+    }
+}
+
+impl Default for AnimateLayout
+{
+    fn default() -> Self
+    {
+        Self {
+            duration: Self::default_duration(),
+            ease: Self::default_ease(),
+        }
+    }
+}
+
+impl Instruction for AnimateLayout
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        emut.insert(self);
+        if !emut.contains::<AnimateLayoutState>() {
+            emut.insert(AnimateLayoutState::default());
+        }
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        // Children's transforms are reset by layout once offsets stop being applied.
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.remove::<(Self, AnimateLayoutState)>();
+        });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct AnimateLayoutPlugin;
+
+impl Plugin for AnimateLayoutPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.register_instruction_type::<AnimateLayout>().add_systems(
+            PostUpdate,
+            animate_layout
+                .after(UiSystem::Layout)
+                .before(TransformSystem::TransformPropagate),
+        );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
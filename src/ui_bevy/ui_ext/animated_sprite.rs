@@ -0,0 +1,310 @@
+use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
+
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn play_animated_sprite_reactor(
+    event: EntityEvent<PlayAnimatedSprite>,
+    mut states: Query<&mut AnimatedSpriteState>,
+)
+{
+    let Ok(mut state) = states.get_mut(event.entity()) else { return };
+
+    // Finished animations restart from the beginning.
+    if state.finished {
+        state.position = 0;
+        state.elapsed = 0.;
+        state.finished = false;
+    }
+    state.playing = true;
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn pause_animated_sprite_reactor(
+    event: EntityEvent<PauseAnimatedSprite>,
+    mut states: Query<&mut AnimatedSpriteState>,
+)
+{
+    let Ok(mut state) = states.get_mut(event.entity()) else { return };
+    state.playing = false;
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn update_animated_sprites(
+    mut c: Commands,
+    time: Res<Time>,
+    asset_server: Res<AssetServer>,
+    layout_map: Res<TextureAtlasLayoutMap>,
+    layouts: Res<Assets<TextureAtlasLayout>>,
+    mut sprites: Query<(Entity, &AnimatedSprite, &mut AnimatedSpriteState, &mut ImageNode)>,
+)
+{
+    for (entity, sprite, mut state, mut img) in sprites.iter_mut() {
+        // Set the atlas layout when the instruction changes.
+        if state.needs_layout {
+            state.needs_layout = false;
+            if let Some(alias) = sprite.atlas.as_ref() {
+                let Some(path) = asset_server.get_path(img.image.id()) else {
+                    tracing::warn!("failed setting atlas {alias:?} for AnimatedSprite on {entity:?}; the entity's \
+                        ImageNode does not have a loaded image");
+                    continue;
+                };
+                let layout = layout_map.get(path.to_string(), alias);
+                match img.texture_atlas.as_mut() {
+                    Some(atlas) => atlas.layout = layout,
+                    None => img.texture_atlas = Some(TextureAtlas { layout, index: 0 }),
+                }
+            }
+        }
+
+        let Some((layout, current_index)) = img.texture_atlas.as_ref().map(|a| (a.layout.id(), a.index)) else {
+            continue;
+        };
+        let Some(layout_len) = layouts.get(layout).map(|l| l.textures.len()) else { continue };
+        let num_frames = sprite.frames.len(layout_len);
+        if num_frames == 0 {
+            continue;
+        }
+        state.position = state.position.min(num_frames - 1);
+
+        // Advance frames.
+        if state.playing && sprite.fps > 0. {
+            let frame_time = 1. / sprite.fps;
+            state.elapsed += time.delta_secs();
+            while state.elapsed >= frame_time {
+                state.elapsed -= frame_time;
+                if state.position + 1 < num_frames {
+                    state.position += 1;
+                } else if sprite.looped {
+                    state.position = 0;
+                } else {
+                    state.elapsed = 0.;
+                    state.playing = false;
+                    state.finished = true;
+                    c.react().entity_event(entity, AnimatedSpriteFinished);
+                    break;
+                }
+            }
+        }
+
+        // Avoid triggering change detection if the frame didn't change.
+        let Some(index) = sprite.frames.index(state.position, layout_len) else { continue };
+        if current_index != index {
+            if let Some(atlas) = img.texture_atlas.as_mut() {
+                atlas.index = index;
+            }
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Playback state of an [`AnimatedSprite`].
+///
+/// Preserved when the instruction is re-applied (e.g. on hot reload), so animations continue where they left off.
+#[derive(Component, Debug)]
+struct AnimatedSpriteState
+{
+    position: usize,
+    elapsed: f32,
+    playing: bool,
+    finished: bool,
+    needs_layout: bool,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Entity event that can be sent to play an [`AnimatedSprite`].
+///
+/// Animations that finished (see [`AnimatedSprite::looped`]) restart from the first frame.
+pub struct PlayAnimatedSprite;
+/// Entity event that can be sent to pause an [`AnimatedSprite`].
+pub struct PauseAnimatedSprite;
+/// Entity event sent when an [`AnimatedSprite`] that isn't looped reaches its last frame.
+pub struct AnimatedSpriteFinished;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// The frames played by an [`AnimatedSprite`].
+#[derive(Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum AnimatedSpriteFrames
+{
+    /// All frames in the atlas layout, in order.
+    #[default]
+    All,
+    /// Atlas indices in the range `[start, end)`.
+    Range(usize, usize),
+    /// A list of atlas indices.
+    List(Vec<usize>),
+}
+
+impl AnimatedSpriteFrames
+{
+    /// Gets the number of frames in an atlas layout with `layout_len` textures.
+    ///
+    /// Frames outside the layout are ignored.
+    pub fn len(&self, layout_len: usize) -> usize
+    {
+        match self {
+            Self::All => layout_len,
+            Self::Range(start, end) => (*end).min(layout_len).saturating_sub(*start),
+            Self::List(frames) => frames.iter().filter(|i| **i < layout_len).count(),
+        }
+    }
+
+    /// Gets the atlas index of the frame at `position`, for an atlas layout with `layout_len` textures.
+    pub fn index(&self, position: usize, layout_len: usize) -> Option<usize>
+    {
+        if position >= self.len(layout_len) {
+            return None;
+        }
+        match self {
+            Self::All => Some(position),
+            Self::Range(start, _) => Some(start + position),
+            Self::List(frames) => frames
+                .iter()
+                .filter(|i| **i < layout_len)
+                .nth(position)
+                .copied(),
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction that animates the [`TextureAtlas`] of an [`ImageNode`] on a timer.
+///
+/// Use this for animated icons and loading spinners. For animations driven by interactions, use
+/// [`Animated<ImageNodeIndex>`] instead.
+///
+/// The animation can be controlled with the [`PlayAnimatedSprite`] and [`PauseAnimatedSprite`] entity events.
+/// [`AnimatedSpriteFinished`] is sent when an animation that isn't looped ends.
+///
+/// Playback state is preserved when the instruction is re-applied, so hot-reloading the instruction doesn't
+/// restart the animation.
+///
+/// Example:
+/**
+```rust
+#scenes
+"spinner"
+    LoadedImageNode{image:"images/spinner.png"}
+    AnimatedSprite{atlas:"spinner" fps:20}
+```
+*/
+#[derive(Component, Reflect, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct AnimatedSprite
+{
+    /// Alias of the atlas layout to animate, for the entity's image.
+    ///
+    /// The layout should be loaded into [`TextureAtlasLayoutMap`]. If `None`, the [`TextureAtlas`] already on the
+    /// [`ImageNode`] is used (e.g. from [`LoadedImageNode::atlas`]).
+    #[reflect(default)]
+    pub atlas: Option<String>,
+    /// Frames per second.
+    ///
+    /// Defaults to `12.0`.
+    #[reflect(default = "AnimatedSprite::default_fps")]
+    pub fps: f32,
+    /// The frames to play.
+    ///
+    /// Defaults to [`AnimatedSpriteFrames::All`].
+    #[reflect(default)]
+    pub frames: AnimatedSpriteFrames,
+    /// Whether the animation restarts after the last frame.
+    ///
+    /// Defaults to `true`.
+    #[reflect(default = "AnimatedSprite::default_looped")]
+    pub looped: bool,
+    /// Whether the animation starts paused.
+    #[reflect(default)]
+    pub paused: bool,
+}
+
+impl AnimatedSprite
+{
+    fn default_fps() -> f32
+    {
+        12.
+    }
+
+    fn default_looped() -> bool
+    {
+        true
+    }
+}
+
+impl Default for AnimatedSprite
+{
+    fn default() -> Self
+    {
+        Self {
+            atlas: None,
+            fps: Self::default_fps(),
+            frames: AnimatedSpriteFrames::default(),
+            looped: Self::default_looped(),
+            paused: false,
+        }
+    }
+}
+
+impl Instruction for AnimatedSprite
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        match emut.get_mut::<AnimatedSpriteState>() {
+            Some(mut state) => {
+                state.needs_layout = true;
+            }
+            None => {
+                emut.insert(AnimatedSpriteState {
+                    position: 0,
+                    elapsed: 0.,
+                    playing: !self.paused,
+                    finished: false,
+                    needs_layout: true,
+                });
+            }
+        }
+        emut.insert(self);
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.remove::<(Self, AnimatedSpriteState)>();
+        });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct AnimatedSpritePlugin;
+
+impl Plugin for AnimatedSpritePlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.register_instruction_type::<AnimatedSprite>()
+            .add_reactor(any_entity_event::<PlayAnimatedSprite>(), play_animated_sprite_reactor)
+            .add_reactor(any_entity_event::<PauseAnimatedSprite>(), pause_animated_sprite_reactor)
+            .add_systems(Update, update_animated_sprites);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
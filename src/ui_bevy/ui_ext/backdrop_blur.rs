@@ -0,0 +1,503 @@
+use bevy::core_pipeline::blit::{BlitPipeline, BlitPipelineKey};
+use bevy::core_pipeline::core_2d::graph::{Core2d, Node2d};
+use bevy::core_pipeline::core_3d::graph::{Core3d, Node3d};
+use bevy::ecs::query::QueryItem;
+use bevy::image::ImageSampler;
+use bevy::prelude::*;
+use bevy::render::extract_component::{ExtractComponent, ExtractComponentPlugin};
+use bevy::render::render_asset::{RenderAssetUsages, RenderAssets};
+use bevy::render::render_graph::{
+    NodeRunError, RenderGraphApp, RenderGraphContext, RenderLabel, ViewNode, ViewNodeRunner,
+};
+use bevy::render::render_resource::{
+    AsBindGroup, BindGroupEntries, CachedRenderPipelineId, Extent3d, LoadOp, Operations, PipelineCache,
+    RenderPassColorAttachment, RenderPassDescriptor, ShaderRef, SpecializedRenderPipelines, StoreOp,
+    TextureDimension, TextureFormat, TextureUsages,
+};
+use bevy::render::renderer::{RenderAdapterInfo, RenderContext};
+use bevy::render::texture::GpuImage;
+use bevy::render::view::ViewTarget;
+use bevy::render::{Render, RenderApp, RenderSet};
+use bevy::ui::graph::NodeUi;
+use bevy::ui::widget::text_system;
+
+use crate::prelude::*;
+use crate::sickle::DynamicStylePostUpdate;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+const BACKDROP_BLUR_SHADER: &str = "embedded://bevy_cobweb_ui/shaders/backdrop_blur.wgsl";
+
+/// Backdrop textures are captured at a fraction of the render target's resolution, which also softens them.
+const BACKDROP_DOWNSCALE: u32 = 4;
+
+const BACKDROP_FORMAT: TextureFormat = TextureFormat::Rgba16Float;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Gets the camera that renders a UI node.
+fn get_ui_camera(
+    entity: Entity,
+    default_camera: &DefaultUiCamera,
+    parents: &Query<&Parent>,
+    target_cameras: &Query<&TargetCamera>,
+) -> Option<Entity>
+{
+    let root = parents.iter_ancestors(entity).last().unwrap_or(entity);
+    match target_cameras.get(root) {
+        Ok(target) => Some(target.entity()),
+        Err(_) => default_camera.get(),
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Adds backdrop textures to cameras that render backdrop-blurred nodes, and keeps them sized to the cameras'
+/// render targets.
+fn prepare_backdrop_textures(
+    mut c: Commands,
+    settings: Res<BackdropBlurSettings>,
+    mut images: ResMut<Assets<Image>>,
+    default_camera: DefaultUiCamera,
+    parents: Query<&Parent>,
+    target_cameras: Query<&TargetCamera>,
+    blurred: Query<Entity, With<BackdropBlur>>,
+    cameras: Query<(Entity, &Camera, Option<&BackdropTexture>)>,
+    mut used: Local<Vec<Entity>>,
+)
+{
+    used.clear();
+    if settings.enabled {
+        for entity in blurred.iter() {
+            let Some(camera) = get_ui_camera(entity, &default_camera, &parents, &target_cameras) else { continue };
+            if !used.contains(&camera) {
+                used.push(camera);
+            }
+        }
+    }
+
+    for (camera_entity, camera, backdrop) in cameras.iter() {
+        if !used.contains(&camera_entity) {
+            if backdrop.is_some() {
+                c.entity(camera_entity).remove::<BackdropTexture>();
+            }
+            continue;
+        }
+        let Some(target_size) = camera.physical_target_size() else { continue };
+        let size = Extent3d {
+            width: (target_size.x / BACKDROP_DOWNSCALE).max(1),
+            height: (target_size.y / BACKDROP_DOWNSCALE).max(1),
+            depth_or_array_layers: 1,
+        };
+
+        match backdrop.and_then(|b| images.get_mut(&b.texture).map(|i| (b, i))) {
+            Some((backdrop, image)) => {
+                if image.texture_descriptor.size != size {
+                    image.resize(size);
+                }
+                if backdrop.target_size != target_size {
+                    c.entity(camera_entity)
+                        .insert(BackdropTexture { texture: backdrop.texture.clone(), target_size });
+                }
+            }
+            None => {
+                let mut image = Image::new_fill(
+                    size,
+                    TextureDimension::D2,
+                    &[0; 8],
+                    BACKDROP_FORMAT,
+                    RenderAssetUsages::default(),
+                );
+                image.texture_descriptor.usage =
+                    TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST | TextureUsages::RENDER_ATTACHMENT;
+                image.sampler = ImageSampler::linear();
+                let texture = images.add(image);
+                c.entity(camera_entity)
+                    .insert(BackdropTexture { texture, target_size });
+            }
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Updates backdrop blur materials, and hides the background color of blurred nodes so only the material is
+/// rendered.
+fn update_backdrop_blurs(
+    mut c: Commands,
+    settings: Res<BackdropBlurSettings>,
+    mut materials: ResMut<Assets<BackdropBlurMaterial>>,
+    default_camera: DefaultUiCamera,
+    parents: Query<&Parent>,
+    target_cameras: Query<&TargetCamera>,
+    backdrops: Query<&BackdropTexture>,
+    mut nodes: Query<(
+        Entity,
+        &BackdropBlur,
+        &ComputedNode,
+        Option<&mut BackgroundColor>,
+        Option<&mut BackdropBlurState>,
+    )>,
+    removed: Query<Entity, (With<BackdropBlurState>, Without<BackdropBlur>)>,
+)
+{
+    for entity in removed.iter() {
+        c.entity(entity)
+            .remove::<(BackdropBlurState, MaterialNode<BackdropBlurMaterial>)>();
+    }
+
+    for (entity, blur, computed, bg_color, state) in nodes.iter_mut() {
+        // Fall back to the node's background color if the backdrop isn't available.
+        let backdrop = match settings.enabled {
+            true => get_ui_camera(entity, &default_camera, &parents, &target_cameras)
+                .and_then(|camera| backdrops.get(camera).ok()),
+            false => None,
+        };
+        let Some(backdrop) = backdrop else {
+            if state.is_some() {
+                c.entity(entity)
+                    .remove::<(BackdropBlurState, MaterialNode<BackdropBlurMaterial>)>();
+            }
+            continue;
+        };
+
+        // Hide the background. Its color is restored after rendering.
+        let bg_color = bg_color.map(|mut bg| {
+            let color = bg.0.to_linear();
+            bg.0.set_alpha(0.);
+            color
+        });
+
+        let material = BackdropBlurMaterial {
+            tint: bg_color.unwrap_or(LinearRgba::NONE),
+            blur: Vec4::new(
+                blur.0.max(0.) / computed.inverse_scale_factor(),
+                0.,
+                backdrop.target_size.x as f32,
+                backdrop.target_size.y as f32,
+            ),
+            backdrop: backdrop.texture.clone(),
+        };
+        let bg_alpha = bg_color.map(|c| c.alpha);
+
+        match state {
+            Some(mut state) => {
+                state.bg_alpha = bg_alpha;
+                if let Some(prev) = materials.get(&state.material) {
+                    if *prev != material {
+                        materials.insert(&state.material, material);
+                    }
+                }
+            }
+            None => {
+                let handle = materials.add(material);
+                c.entity(entity).insert((
+                    BackdropBlurState { material: handle.clone(), bg_alpha },
+                    MaterialNode(handle),
+                ));
+            }
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Restores the background alpha of blurred nodes after rendering.
+fn restore_blurred_backgrounds(mut nodes: Query<(&BackdropBlurState, &mut BackgroundColor)>)
+{
+    for (state, mut bg) in nodes.iter_mut() {
+        let Some(alpha) = state.bg_alpha else { continue };
+        if bg.0.alpha() != alpha {
+            bg.0.set_alpha(alpha);
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn prepare_backdrop_blit_pipeline(
+    mut c: Commands,
+    pipeline_cache: Res<PipelineCache>,
+    mut pipelines: ResMut<SpecializedRenderPipelines<BlitPipeline>>,
+    blit_pipeline: Res<BlitPipeline>,
+    existing: Option<Res<BackdropBlitPipeline>>,
+)
+{
+    if existing.is_some() {
+        return;
+    }
+    let id = pipelines.specialize(
+        &pipeline_cache,
+        &blit_pipeline,
+        BlitPipelineKey {
+            texture_format: BACKDROP_FORMAT,
+            blend_state: None,
+            samples: 1,
+        },
+    );
+    c.insert_resource(BackdropBlitPipeline(id));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Component, Debug)]
+struct BackdropBlurState
+{
+    material: Handle<BackdropBlurMaterial>,
+    /// Alpha of the [`BackgroundColor`] before it was hidden.
+    bg_alpha: Option<f32>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Texture on a camera that the camera's rendered content is copied into before UI is rendered.
+#[derive(Component, ExtractComponent, Debug, Clone)]
+struct BackdropTexture
+{
+    texture: Handle<Image>,
+    /// Physical size of the camera's render target.
+    target_size: UVec2,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource)]
+struct BackdropBlitPipeline(CachedRenderPipelineId);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, RenderLabel)]
+struct BackdropCaptureLabel;
+
+/// Render graph node that copies a camera's rendered content into its [`BackdropTexture`].
+#[derive(Default)]
+struct BackdropCaptureNode;
+
+impl ViewNode for BackdropCaptureNode
+{
+    type ViewQuery = (&'static ViewTarget, &'static BackdropTexture);
+
+    fn run(
+        &self,
+        _graph: &mut RenderGraphContext,
+        render_context: &mut RenderContext,
+        (target, backdrop): QueryItem<Self::ViewQuery>,
+        world: &World,
+    ) -> Result<(), NodeRunError>
+    {
+        let Some(BackdropBlitPipeline(pipeline_id)) = world.get_resource::<BackdropBlitPipeline>() else {
+            return Ok(());
+        };
+        let Some(pipeline) = world
+            .resource::<PipelineCache>()
+            .get_render_pipeline(*pipeline_id)
+        else {
+            return Ok(());
+        };
+        let Some(gpu_image) = world
+            .resource::<RenderAssets<GpuImage>>()
+            .get(&backdrop.texture)
+        else {
+            return Ok(());
+        };
+
+        let blit_pipeline = world.resource::<BlitPipeline>();
+        let bind_group = render_context.render_device().create_bind_group(
+            "backdrop_capture_bind_group",
+            &blit_pipeline.texture_bind_group,
+            &BindGroupEntries::sequential((target.main_texture_view(), &blit_pipeline.sampler)),
+        );
+
+        let mut render_pass = render_context
+            .command_encoder()
+            .begin_render_pass(&RenderPassDescriptor {
+                label: Some("backdrop_capture_pass"),
+                color_attachments: &[Some(RenderPassColorAttachment {
+                    view: &gpu_image.texture_view,
+                    resolve_target: None,
+                    ops: Operations { load: LoadOp::Load, store: StoreOp::Store },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+        render_pass.set_pipeline(pipeline);
+        render_pass.set_bind_group(0, &bind_group, &[]);
+        render_pass.draw(0..3, 0..1);
+
+        Ok(())
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Built-in [`UiMaterial`] for rendering nodes with a [`BackdropBlur`].
+///
+/// Inserted automatically to nodes with `BackdropBlur`.
+#[derive(Asset, TypePath, AsBindGroup, Debug, Clone, PartialEq)]
+pub struct BackdropBlurMaterial
+{
+    /// Mixed with the blurred backdrop according to its alpha.
+    #[uniform(0)]
+    pub tint: LinearRgba,
+    /// Blur radius in physical pixels (`x`), and the physical size of the render target (`zw`).
+    #[uniform(1)]
+    pub blur: Vec4,
+    #[texture(2)]
+    #[sampler(3)]
+    pub backdrop: Handle<Image>,
+}
+
+impl UiMaterial for BackdropBlurMaterial
+{
+    fn fragment_shader() -> ShaderRef
+    {
+        BACKDROP_BLUR_SHADER.into()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource that controls whether [`BackdropBlur`] is rendered.
+///
+/// Backdrop blur is disabled by default on WebGL. When disabled, blurred nodes render their [`BackgroundColor`]
+/// normally, so a translucent background color can be used as a fallback.
+#[derive(Resource, Debug, Clone)]
+pub struct BackdropBlurSettings
+{
+    pub enabled: bool,
+}
+
+impl Default for BackdropBlurSettings
+{
+    fn default() -> Self
+    {
+        Self { enabled: true }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction that blurs content behind a node (e.g. for frosted-glass panels). The value is the blur radius
+/// in logical pixels.
+///
+/// Content rendered by the node's camera before UI (i.e. the game world) is blurred, and the node's
+/// [`BackgroundColor`] is mixed with the blurred content according to its alpha. Other UI nodes behind the node
+/// are not blurred.
+///
+/// If backdrop blur is disabled (see [`BackdropBlurSettings`]), the node's background color is rendered normally.
+///
+/// Blurred nodes are rendered with [`BackdropBlurMaterial`], and ignore border radius.
+///
+/// Example:
+/**
+```rust
+#scenes
+"pause_menu"
+    FlexNode{width:400px height:600px}
+    BackgroundColor(#A0202030)
+    BackdropBlur(12)
+```
+*/
+#[derive(Component, Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct BackdropBlur(pub f32);
+
+impl Instruction for BackdropBlur
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.insert(self);
+        });
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.remove::<Self>();
+        });
+    }
+}
+
+impl StaticAttribute for BackdropBlur
+{
+    type Value = f32;
+    fn construct(value: Self::Value) -> Self
+    {
+        Self(value)
+    }
+}
+
+impl ResponsiveAttribute for BackdropBlur {}
+impl AnimatedAttribute for BackdropBlur
+{
+    fn get_value(entity: Entity, world: &World) -> Option<Self::Value>
+    {
+        world.get::<BackdropBlur>(entity).map(|b| b.0)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct BackdropBlurPlugin;
+
+impl Plugin for BackdropBlurPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        if !app.is_plugin_added::<UiMaterialPlugin<BackdropBlurMaterial>>() {
+            app.add_plugins(UiMaterialPlugin::<BackdropBlurMaterial>::default());
+        }
+
+        app.init_resource::<BackdropBlurSettings>()
+            .register_animatable::<BackdropBlur>()
+            .add_plugins(ExtractComponentPlugin::<BackdropTexture>::default())
+            .add_systems(
+                PostUpdate,
+                (prepare_backdrop_textures, update_backdrop_blurs)
+                    .chain()
+                    .after(bevy::render::camera::CameraUpdateSystem)
+                    .after(ControlSet)
+                    .after(DynamicStylePostUpdate)
+                    .after(propagate_opacity_values)
+                    .before(text_system),
+            )
+            // After rendering.
+            .add_systems(First, restore_blurred_backgrounds.before(restore_opacity));
+
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else { return };
+        render_app
+            .add_systems(Render, prepare_backdrop_blit_pipeline.in_set(RenderSet::Prepare))
+            .add_render_graph_node::<ViewNodeRunner<BackdropCaptureNode>>(Core2d, BackdropCaptureLabel)
+            .add_render_graph_edges(
+                Core2d,
+                (Node2d::EndMainPassPostProcessing, BackdropCaptureLabel, NodeUi::UiPass),
+            )
+            .add_render_graph_node::<ViewNodeRunner<BackdropCaptureNode>>(Core3d, BackdropCaptureLabel)
+            .add_render_graph_edges(
+                Core3d,
+                (Node3d::EndMainPassPostProcessing, BackdropCaptureLabel, NodeUi::UiPass),
+            );
+    }
+
+    fn finish(&self, app: &mut App)
+    {
+        // WebGL2 doesn't guarantee that float textures can be rendered to.
+        let is_gl = app
+            .world()
+            .get_resource::<RenderAdapterInfo>()
+            .is_some_and(|info| info.backend.to_str() == "gl");
+        if is_gl {
+            app.world_mut()
+                .resource_mut::<BackdropBlurSettings>()
+                .enabled = false;
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
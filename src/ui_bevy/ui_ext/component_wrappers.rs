@@ -472,6 +472,8 @@ impl AnimatedAttribute for NodeOutline
 //-------------------------------------------------------------------------------------------------------------------
 
 /// Mirrors [`BoxShadow`], can be loaded as an instruction.
+///
+/// See [`NodeGlow`] for glows.
 #[derive(Reflect, Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NodeShadow
@@ -601,6 +603,136 @@ impl AnimatedAttribute for NodeShadow
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Inserts a [`BoxShadow`] with no offset, which renders as a glow around the node.
+///
+/// Nodes only have one `BoxShadow`, so this replaces [`NodeShadow`] if both are applied.
+///
+/// Example:
+/**
+```rust
+#scenes
+"button"
+    Animated<NodeGlow>{
+        idle:{color:#00000000 blur_radius:12px}
+        hover:{color:#8844AAFF blur_radius:12px}
+        hover_with:{duration:0.15 ease:Linear}
+    }
+```
+*/
+#[derive(Reflect, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NodeGlow
+{
+    /// The glow's color.
+    ///
+    /// Defaults to white.
+    #[reflect(default = "NodeGlow::default_color")]
+    pub color: Color,
+    /// How much the glow should spread outward.
+    #[reflect(default)]
+    pub spread_radius: Val,
+    /// Blurriness of the glow.
+    #[reflect(default)]
+    pub blur_radius: Val,
+}
+
+impl NodeGlow
+{
+    fn default_color() -> Color
+    {
+        Color::WHITE
+    }
+}
+
+impl Default for NodeGlow
+{
+    fn default() -> Self
+    {
+        Self {
+            color: NodeGlow::default_color(),
+            spread_radius: Default::default(),
+            blur_radius: Default::default(),
+        }
+    }
+}
+
+impl Into<BoxShadow> for NodeGlow
+{
+    fn into(self) -> BoxShadow
+    {
+        BoxShadow {
+            color: self.color,
+            x_offset: Val::Px(0.),
+            y_offset: Val::Px(0.),
+            spread_radius: self.spread_radius,
+            blur_radius: self.blur_radius,
+        }
+    }
+}
+
+impl From<BoxShadow> for NodeGlow
+{
+    fn from(shadow: BoxShadow) -> Self
+    {
+        Self {
+            color: shadow.color,
+            spread_radius: shadow.spread_radius,
+            blur_radius: shadow.blur_radius,
+        }
+    }
+}
+
+impl Lerp for NodeGlow
+{
+    fn lerp(&self, to: Self, t: f32) -> Self
+    {
+        Self {
+            color: self.color.lerp(to.color, t),
+            spread_radius: self.spread_radius.lerp(to.spread_radius, t),
+            blur_radius: self.blur_radius.lerp(to.blur_radius, t),
+        }
+    }
+}
+
+impl Instruction for NodeGlow
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let shadow: BoxShadow = self.into();
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.insert(shadow);
+        });
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.remove::<BoxShadow>();
+        });
+    }
+}
+
+impl StaticAttribute for NodeGlow
+{
+    type Value = Self;
+    fn construct(value: Self::Value) -> Self
+    {
+        value
+    }
+}
+
+impl ResponsiveAttribute for NodeGlow {}
+impl AnimatedAttribute for NodeGlow
+{
+    fn get_value(entity: Entity, world: &World) -> Option<Self::Value>
+    {
+        let shadow = world.get::<BoxShadow>(entity).copied()?;
+        Some(shadow.into())
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 impl Instruction for FocusPolicy
 {
     fn apply(self, entity: Entity, world: &mut World)
@@ -734,6 +866,7 @@ impl Plugin for UiComponentWrappersPlugin
             .register_animatable::<BrRadiusBottomRight>()
             .register_animatable::<NodeOutline>()
             .register_animatable::<NodeShadow>()
+            .register_animatable::<NodeGlow>()
             .register_responsive::<FocusPolicy>()
             .register_responsive::<ZIndex>()
             .register_responsive::<GlobalZIndex>()
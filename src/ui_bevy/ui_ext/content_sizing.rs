@@ -0,0 +1,196 @@
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Axis of a node that should be sized to its content.
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum FitAxis
+{
+    Width,
+    Height,
+}
+
+/// Adjusts a node so its size along `axis` is not stretched by its parent's layout.
+///
+/// Returns `true` if the node was changed.
+fn fit_content(node: &mut Node, parent: Option<&Node>, axis: FitAxis) -> bool
+{
+    let mut changed = false;
+    let size = match axis {
+        FitAxis::Width => &mut node.width,
+        FitAxis::Height => &mut node.height,
+    };
+    if *size != Val::Auto {
+        *size = Val::Auto;
+        changed = true;
+    }
+
+    let Some(parent) = parent else { return changed };
+    if node.position_type == PositionType::Absolute {
+        return changed;
+    }
+
+    match parent.display {
+        Display::Flex => {
+            let is_row = matches!(parent.flex_direction, FlexDirection::Row | FlexDirection::RowReverse);
+            let is_main_axis = is_row == (axis == FitAxis::Width);
+            if is_main_axis {
+                // Don't grow along the main axis.
+                if node.flex_grow != 0. {
+                    node.flex_grow = 0.;
+                    changed = true;
+                }
+            } else if matches!(node.align_self, AlignSelf::Auto | AlignSelf::Stretch) {
+                // Don't stretch along the cross axis.
+                node.align_self = AlignSelf::Start;
+                changed = true;
+            }
+        }
+        Display::Grid => match axis {
+            FitAxis::Width => {
+                if matches!(node.justify_self, JustifySelf::Auto | JustifySelf::Stretch) {
+                    node.justify_self = JustifySelf::Start;
+                    changed = true;
+                }
+            }
+            FitAxis::Height => {
+                if matches!(node.align_self, AlignSelf::Auto | AlignSelf::Stretch) {
+                    node.align_self = AlignSelf::Start;
+                    changed = true;
+                }
+            }
+        },
+        _ => (),
+    }
+
+    changed
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn update_fit_content(
+    nodes: Query<
+        (Entity, Option<&Parent>, Has<FitContentWidth>, Has<FitContentHeight>),
+        Or<(With<FitContentWidth>, With<FitContentHeight>)>,
+    >,
+    changed: Query<(), Or<(Changed<FitContentWidth>, Changed<FitContentHeight>, Changed<Parent>)>>,
+    mut node_query: Query<&mut Node>,
+)
+{
+    // Node changes are read from `node_query` because a `Changed<Node>` filter would conflict with it.
+    let node_changed = |q: &mut Query<&mut Node>, entity: Entity| q.get_mut(entity).is_ok_and(|n| n.is_changed());
+
+    for (entity, maybe_parent, fit_width, fit_height) in nodes.iter() {
+        let parent_entity = maybe_parent.map(|p| p.get());
+        let needs_update = changed.contains(entity)
+            || node_changed(&mut node_query, entity)
+            || parent_entity.is_some_and(|p| node_changed(&mut node_query, p));
+        if !needs_update {
+            continue;
+        }
+
+        let parent = parent_entity.and_then(|p| node_query.get(p).ok()).cloned();
+        let Ok(mut node) = node_query.get_mut(entity) else { continue };
+
+        // Edit a copy so change detection is only triggered if something changed.
+        let mut new_node = node.clone();
+        let mut changed = false;
+        if fit_width {
+            changed |= fit_content(&mut new_node, parent.as_ref(), FitAxis::Width);
+        }
+        if fit_height {
+            changed |= fit_content(&mut new_node, parent.as_ref(), FitAxis::Height);
+        }
+        if changed {
+            *node = new_node;
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction that sizes a node's width to its content.
+///
+/// Sets [`Node::width`] to [`Val::Auto`] and prevents the parent's layout from stretching the node: in a flex
+/// row the node won't grow, in a flex column it is aligned to the start of the cross axis (unless
+/// [`Node::align_self`] is set to something other than auto/stretch), and in a grid it is aligned to the start of
+/// its cell.
+///
+/// Absolutely-positioned nodes, like the content node of a scroll view
+/// ([`ScrollShim`](crate::builtin::widgets::scroll::ScrollShim)), are already content-sized so only their width is
+/// changed.
+///
+/// Combine with [`AspectRatio`] (or [`Dims::aspect_ratio`]) to size the other axis proportionally.
+#[derive(Component, Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct FitContentWidth;
+
+impl Instruction for FitContentWidth
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        emut.insert(self);
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.remove::<Self>();
+        });
+        remove_node(entity, world);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction that sizes a node's height to its content.
+///
+/// See [`FitContentWidth`].
+#[derive(Component, Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct FitContentHeight;
+
+impl Instruction for FitContentHeight
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        emut.insert(self);
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.remove::<Self>();
+        });
+        remove_node(entity, world);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct ContentSizingPlugin;
+
+impl Plugin for ContentSizingPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.register_instruction_type::<FitContentWidth>()
+            .register_instruction_type::<FitContentHeight>()
+            .add_systems(PostUpdate, update_fit_content.before(UiSystem::Layout));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
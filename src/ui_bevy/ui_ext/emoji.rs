@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bevy::render::sync_world::TemporaryRenderEntity;
+use bevy::render::{Extract, ExtractSchedule, RenderApp};
+use bevy::ui::{ExtractedGlyph, ExtractedUiItem, ExtractedUiNode, ExtractedUiNodes, RenderUiSystem};
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Returns `true` if a glyph in a font atlas has colored pixels.
+///
+/// Mask glyphs are written to font atlases as white pixels with coverage in the alpha channel, while color glyphs
+/// (e.g. emoji from COLR or CBDT tables) keep their own colors.
+fn is_color_glyph(image: &Image, rect: Rect) -> bool
+{
+    let width = image.width() as usize;
+    let min = rect.min.as_uvec2();
+    let max = rect.max.as_uvec2();
+    for y in min.y..max.y {
+        for x in min.x..max.x {
+            let idx = (y as usize * width + x as usize) * 4;
+            let Some(&[r, g, b, a]) = image.data.get(idx..(idx + 4)) else { return false };
+            if a > 0 && (r, g, b) != (255, 255, 255) {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Caches whether glyphs in font atlases are color glyphs, keyed by atlas image and glyph rect.
+#[derive(Default)]
+struct ColorGlyphCache(HashMap<(AssetId<Image>, [u32; 4]), bool>);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Splits extracted text glyph batches so color glyphs are rendered without the text color.
+///
+/// The UI renderer multiplies glyphs by their span's [`TextColor`], which would tint emoji. Color glyphs are moved
+/// to their own batches that only keep the text color's alpha.
+fn extract_color_glyphs(
+    mut c: Commands,
+    mut extracted: ResMut<ExtractedUiNodes>,
+    images: Extract<Res<Assets<Image>>>,
+    mut cache: Local<ColorGlyphCache>,
+)
+{
+    let extracted = extracted.as_mut();
+    let mut new_nodes = Vec::new();
+
+    for node in extracted.uinodes.values_mut() {
+        let ExtractedUiItem::Glyphs { atlas_scaling, range } = &mut node.item else { continue };
+        let Some(image) = images.get(node.image) else { continue };
+
+        let mut is_color = |glyph: &ExtractedGlyph| -> bool {
+            let key = [glyph.rect.min.x, glyph.rect.min.y, glyph.rect.max.x, glyph.rect.max.y].map(|v| v as u32);
+            *cache
+                .0
+                .entry((node.image, key))
+                .or_insert_with(|| is_color_glyph(image, glyph.rect))
+        };
+
+        // Collect runs of glyphs that are all color or all mask glyphs.
+        let mut runs: Vec<(std::ops::Range<usize>, bool)> = Vec::new();
+        for idx in range.clone() {
+            let color = is_color(&extracted.glyphs[idx]);
+            match runs.last_mut() {
+                Some((run, run_color)) if *run_color == color => run.end = idx + 1,
+                _ => runs.push((idx..(idx + 1), color)),
+            }
+        }
+        if !runs.iter().any(|(_, color)| *color) {
+            continue;
+        }
+
+        // The existing node takes the first run, and new nodes are added for the others.
+        let text_color = node.color;
+        let color_glyph_color = LinearRgba::WHITE.with_alpha(text_color.alpha);
+        let atlas_scaling = *atlas_scaling;
+        let mut runs = runs.into_iter();
+        let (first, first_color) = runs.next().unwrap();
+        *range = first;
+        if first_color {
+            node.color = color_glyph_color;
+        }
+
+        for (run, color) in runs {
+            new_nodes.push(ExtractedUiNode {
+                stack_index: node.stack_index,
+                color: if color { color_glyph_color } else { text_color },
+                image: node.image,
+                clip: node.clip,
+                camera_entity: node.camera_entity,
+                rect: node.rect,
+                item: ExtractedUiItem::Glyphs { atlas_scaling, range: run },
+                main_entity: node.main_entity,
+            });
+        }
+    }
+
+    for node in new_nodes {
+        let id = c.spawn(TemporaryRenderEntity).id();
+        extracted.uinodes.insert(id, node);
+    }
+
+    // Font atlases are removed when their fonts are unloaded.
+    cache.0.retain(|(image, _), _| images.contains(*image));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Renders color glyphs in UI text (e.g. emoji) with their own colors.
+///
+/// See [`RegisterEmojiFonts`](crate::prelude::RegisterEmojiFonts).
+pub(crate) struct EmojiPlugin;
+
+impl Plugin for EmojiPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else { return };
+        render_app.add_systems(ExtractSchedule, extract_color_glyphs.after(RenderUiSystem::ExtractText));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
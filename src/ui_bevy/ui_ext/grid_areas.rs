@@ -0,0 +1,183 @@
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+use bevy::utils::HashSet;
+
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Places [`GridArea`] nodes in the named areas of their parents' [`GridAreas`].
+fn update_grid_areas(
+    changed_grids: Query<&Children, Changed<GridAreas>>,
+    grids: Query<&GridAreas>,
+    mut items: Query<(Entity, Ref<GridArea>, Ref<Parent>, Mut<Node>)>,
+)
+{
+    // Changes are checked manually because a `Changed<Node>` filter would conflict with writing to nodes.
+    let mut updates: HashSet<Entity> = items
+        .iter()
+        .filter(|(_, area, parent, node)| area.is_changed() || parent.is_changed() || node.is_changed())
+        .map(|(entity, ..)| entity)
+        .collect();
+    updates.extend(changed_grids.iter().flat_map(|c| c.iter().copied()));
+
+    for entity in updates {
+        let Ok((_, area, parent, mut node)) = items.get_mut(entity) else { continue };
+        let Ok(grid) = grids.get(parent.get()) else {
+            tracing::warn!("failed placing {entity:?} in grid area {:?}, parent {:?} doesn't have GridAreas",
+                area.0, parent.get());
+            continue;
+        };
+        let Some((row, column)) = grid.placement(area.0.as_str()) else {
+            tracing::warn!("failed placing {entity:?} in grid area {:?}, the area is missing from GridAreas on \
+                {:?} or is not rectangular", area.0, parent.get());
+            continue;
+        };
+
+        // Only write on change so `Changed<Node>` doesn't re-trigger every tick.
+        if node.grid_row != row {
+            node.grid_row = row;
+        }
+        if node.grid_column != column {
+            node.grid_column = column;
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction for naming the areas of a grid node, similar to CSS `grid-template-areas`.
+///
+/// Each inner list is a row of cells. An area is the set of cells with the same name, and must be rectangular.
+/// Cells named `"."` are not part of any area.
+///
+/// Children can be placed in an area with [`GridArea`]. Note that `GridAreas` does not set the grid's tracks,
+/// use [`GridTemplateRows`] and [`GridTemplateColumns`] or the [`GridNode`] loadable for that.
+///
+/// Example:
+/**
+```rust
+#scenes
+"layout"
+    GridNode{grid_template_columns:[(Count(1) Px(150.0)) (Count(1) Flex(1.0))]}
+    GridAreas([["header" "header"] ["nav" "main"]])
+
+    "header"
+        GridArea("header")
+    "nav"
+        GridArea("nav")
+    "main"
+        GridArea("main")
+```
+*/
+#[derive(Component, Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct GridAreas(pub Vec<Vec<String>>);
+
+impl GridAreas
+{
+    /// Gets the row and column placements of a named area.
+    ///
+    /// Returns `None` if the area doesn't exist or isn't rectangular.
+    pub fn placement(&self, name: &str) -> Option<(GridPlacement, GridPlacement)>
+    {
+        if name == "." {
+            return None;
+        }
+
+        // Find the area's bounds.
+        let mut rows = (usize::MAX, 0);
+        let mut columns = (usize::MAX, 0);
+        let mut count = 0;
+        for (r, row) in self.0.iter().enumerate() {
+            for (c, cell) in row.iter().enumerate() {
+                if cell != name {
+                    continue;
+                }
+                rows = (rows.0.min(r), rows.1.max(r + 1));
+                columns = (columns.0.min(c), columns.1.max(c + 1));
+                count += 1;
+            }
+        }
+        if count == 0 {
+            return None;
+        }
+
+        // The area must fill its bounds.
+        if count != (rows.1 - rows.0) * (columns.1 - columns.0) {
+            return None;
+        }
+
+        // Grid lines are 1-indexed.
+        let row = GridPlacement::start_end(rows.0 as i16 + 1, rows.1 as i16 + 1);
+        let column = GridPlacement::start_end(columns.0 as i16 + 1, columns.1 as i16 + 1);
+        Some((row, column))
+    }
+}
+
+impl Instruction for GridAreas
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        emut.insert(self);
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.remove::<Self>();
+        });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction for placing a node in a named area of its parent's [`GridAreas`].
+///
+/// Sets the node's [`Node::grid_row`] and [`Node::grid_column`]. Reverting it resets them to their defaults.
+#[derive(Component, Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct GridArea(pub String);
+
+impl Instruction for GridArea
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        emut.insert(self);
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        emut.remove::<Self>();
+        let Some(mut node) = emut.get_mut::<Node>() else { return };
+        node.grid_row = GridPlacement::default();
+        node.grid_column = GridPlacement::default();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct GridAreasPlugin;
+
+impl Plugin for GridAreasPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.register_instruction_type::<GridAreas>()
+            .register_instruction_type::<GridArea>()
+            .add_systems(PostUpdate, update_grid_areas.before(UiSystem::Layout));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,498 @@
+use bevy::prelude::*;
+use bevy::render::render_resource::{AsBindGroup, ShaderRef};
+use bevy::ui::widget::text_system;
+
+use crate::prelude::*;
+use crate::sickle::DynamicStylePostUpdate;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+const IMAGE_FILTER_SHADER: &str = "embedded://bevy_cobweb_ui/shaders/image_filter.wgsl";
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn edit_image_filter(entity: Entity, world: &mut World, callback: impl FnOnce(&mut ImageFilter))
+{
+    let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+    match emut.get_mut::<ImageFilter>() {
+        Some(mut filter) => callback(&mut filter),
+        None => {
+            let mut filter = ImageFilter::default();
+            callback(&mut filter);
+            emut.insert(filter);
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn revert_image_filter(entity: Entity, world: &mut World, callback: impl FnOnce(&mut ImageFilter))
+{
+    let Some(mut filter) = world.get_mut::<ImageFilter>(entity) else { return };
+    callback(&mut filter);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Computes the uv rect of an image node's texture region.
+fn get_uv_rect(img: &ImageNode, images: &Assets<Image>, layouts: &Assets<TextureAtlasLayout>) -> Vec4
+{
+    let (rect, size) = match (&img.texture_atlas, img.rect) {
+        (Some(atlas), _) => layouts
+            .get(&atlas.layout)
+            .and_then(|layout| Some((layout.textures.get(atlas.index)?.as_rect(), layout.size.as_vec2())))
+            .unzip(),
+        (None, Some(rect)) => (Some(rect), images.get(&img.image).map(|i| i.size_f32())),
+        (None, None) => (None, None),
+    };
+    let (mut min, mut max) = match rect
+        .zip(size)
+        .filter(|(_, size)| size.x > 0. && size.y > 0.)
+    {
+        Some((rect, size)) => (rect.min / size, rect.max / size),
+        None => (Vec2::ZERO, Vec2::ONE),
+    };
+    if img.flip_x {
+        std::mem::swap(&mut min.x, &mut max.x);
+    }
+    if img.flip_y {
+        std::mem::swap(&mut min.y, &mut max.y);
+    }
+    Vec4::new(min.x, min.y, max.x, max.y)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Updates image filter materials, and hides the filtered [`ImageNode`] so only the material is rendered.
+fn update_image_filters(
+    mut c: Commands,
+    time: Res<Time>,
+    mut materials: ResMut<Assets<ImageFilterMaterial>>,
+    images: Res<Assets<Image>>,
+    layouts: Res<Assets<TextureAtlasLayout>>,
+    mut nodes: Query<
+        (
+            Entity,
+            Option<&ImageFilter>,
+            Option<&ImageUvTransform>,
+            &mut ImageNode,
+            Option<&mut ImageFilterState>,
+        ),
+        Or<(With<ImageFilter>, With<ImageUvTransform>)>,
+    >,
+    removed: Query<
+        Entity,
+        (
+            With<ImageFilterState>,
+            Or<(Without<ImageNode>, (Without<ImageFilter>, Without<ImageUvTransform>))>,
+        ),
+    >,
+)
+{
+    for entity in removed.iter() {
+        c.entity(entity)
+            .remove::<(ImageFilterState, MaterialNode<ImageFilterMaterial>)>();
+    }
+
+    for (entity, filter, uv_transform, mut img, state) in nodes.iter_mut() {
+        let filter = filter.cloned().unwrap_or_default();
+        let uv_transform = uv_transform.cloned().unwrap_or_default();
+        if filter.is_identity() && uv_transform.is_identity() {
+            if state.is_some() {
+                c.entity(entity)
+                    .remove::<(ImageFilterState, MaterialNode<ImageFilterMaterial>)>();
+            }
+            continue;
+        }
+
+        // Hide the image. Its color is restored after rendering.
+        let img_color = img.color.to_linear();
+        img.color.set_alpha(0.);
+
+        // Accumulate scrolling.
+        let prev_scroll = state.as_ref().map(|s| s.scroll).unwrap_or_default();
+        let scroll = (prev_scroll + uv_transform.scroll * time.delta_secs()).rem_euclid(Vec2::ONE);
+        let offset = uv_transform.offset + scroll;
+
+        let img_tint = filter.tint.to_linear();
+        let material = ImageFilterMaterial {
+            tint: LinearRgba::new(
+                img_color.red * img_tint.red,
+                img_color.green * img_tint.green,
+                img_color.blue * img_tint.blue,
+                img_color.alpha * img_tint.alpha,
+            ),
+            adjustments: Vec4::new(filter.saturation, filter.brightness, filter.contrast, 0.),
+            uv_rect: get_uv_rect(&img, &images, &layouts),
+            uv_transform: Vec4::new(uv_transform.tiling.x, uv_transform.tiling.y, offset.x, offset.y),
+            uv_rotation: Vec4::new(uv_transform.rotation, 0., 0., 0.),
+            texture: img.image.clone(),
+        };
+
+        match state {
+            Some(mut state) => {
+                state.image_alpha = img_color.alpha;
+                state.scroll = scroll;
+                if let Some(prev) = materials.get(&state.material) {
+                    if *prev != material {
+                        materials.insert(&state.material, material);
+                    }
+                }
+            }
+            None => {
+                let handle = materials.add(material);
+                c.entity(entity).insert((
+                    ImageFilterState {
+                        material: handle.clone(),
+                        image_alpha: img_color.alpha,
+                        scroll,
+                    },
+                    MaterialNode(handle),
+                ));
+            }
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Restores the alpha of filtered images after rendering.
+fn restore_filtered_images(mut nodes: Query<(&ImageFilterState, &mut ImageNode)>)
+{
+    for (state, mut img) in nodes.iter_mut() {
+        if img.color.alpha() != state.image_alpha {
+            img.color.set_alpha(state.image_alpha);
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Component, Debug)]
+struct ImageFilterState
+{
+    material: Handle<ImageFilterMaterial>,
+    /// Alpha of the [`ImageNode`] color before it was hidden.
+    image_alpha: f32,
+    /// Accumulated [`ImageUvTransform::scroll`] offset.
+    scroll: Vec2,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Built-in [`UiMaterial`] for rendering images with an [`ImageFilter`] and/or [`ImageUvTransform`].
+///
+/// Inserted automatically to image nodes with non-default filters or UV transforms.
+#[derive(Asset, TypePath, AsBindGroup, Debug, Clone, PartialEq)]
+pub struct ImageFilterMaterial
+{
+    /// Multiplied with the image's color.
+    #[uniform(0)]
+    pub tint: LinearRgba,
+    /// Saturation, brightness, contrast.
+    #[uniform(1)]
+    pub adjustments: Vec4,
+    /// Texture coordinates of the top-left and bottom-right corners of the rendered region.
+    #[uniform(2)]
+    pub uv_rect: Vec4,
+    /// Tiling and offset within the rendered region.
+    #[uniform(5)]
+    pub uv_transform: Vec4,
+    /// Rotation of the rendered region in radians (stored in `x`).
+    #[uniform(6)]
+    pub uv_rotation: Vec4,
+    #[texture(3)]
+    #[sampler(4)]
+    pub texture: Handle<Image>,
+}
+
+impl UiMaterial for ImageFilterMaterial
+{
+    fn fragment_shader() -> ShaderRef
+    {
+        IMAGE_FILTER_SHADER.into()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Component with post-process filters applied to an [`ImageNode`] on the same entity.
+///
+/// Usually set with the [`ImageTint`], [`ImageSaturation`], [`ImageBrightness`], and [`ImageContrast`]
+/// instructions, which can be animated.
+///
+/// When the filter is not the default, the image is rendered with [`ImageFilterMaterial`] instead of as a normal
+/// image. Filtered images don't support [`NodeImageMode::Sliced`] or [`NodeImageMode::Tiled`], and ignore
+/// border radius.
+#[derive(Component, Reflect, Debug, Clone, PartialEq)]
+pub struct ImageFilter
+{
+    /// Multiplied with the image's color.
+    ///
+    /// Defaults to white.
+    pub tint: Color,
+    /// Color saturation. `0.0` is grayscale, `1.0` is unchanged, and values above `1.0` oversaturate.
+    ///
+    /// Defaults to `1.0`.
+    pub saturation: f32,
+    /// Added to each color channel after saturation is applied.
+    ///
+    /// Defaults to `0.0`.
+    pub brightness: f32,
+    /// Scales the distance of each color channel from `0.5`.
+    ///
+    /// Defaults to `1.0`.
+    pub contrast: f32,
+}
+
+impl ImageFilter
+{
+    /// Returns `true` if the filter doesn't change the image.
+    pub fn is_identity(&self) -> bool
+    {
+        *self == Self::default()
+    }
+}
+
+impl Default for ImageFilter
+{
+    fn default() -> Self
+    {
+        Self {
+            tint: Color::WHITE,
+            saturation: 1.,
+            brightness: 0.,
+            contrast: 1.,
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Sets [`ImageFilter::tint`] on an image node.
+#[derive(Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct ImageTint(pub Color);
+
+impl Instruction for ImageTint
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        edit_image_filter(entity, world, |f| f.tint = self.0);
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        revert_image_filter(entity, world, |f| f.tint = ImageFilter::default().tint);
+    }
+}
+
+impl StaticAttribute for ImageTint
+{
+    type Value = Color;
+    fn construct(value: Self::Value) -> Self
+    {
+        Self(value)
+    }
+}
+
+impl ResponsiveAttribute for ImageTint {}
+impl AnimatedAttribute for ImageTint
+{
+    fn get_value(entity: Entity, world: &World) -> Option<Self::Value>
+    {
+        world.get_entity(entity).ok()?;
+        Some(
+            world
+                .get::<ImageFilter>(entity)
+                .cloned()
+                .unwrap_or_default()
+                .tint,
+        )
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Sets [`ImageFilter::saturation`] on an image node.
+///
+/// Use `ImageSaturation(0.0)` to display an image in grayscale (e.g. for disabled buttons).
+#[derive(Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct ImageSaturation(pub f32);
+
+impl Instruction for ImageSaturation
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        edit_image_filter(entity, world, |f| f.saturation = self.0);
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        revert_image_filter(entity, world, |f| f.saturation = ImageFilter::default().saturation);
+    }
+}
+
+impl StaticAttribute for ImageSaturation
+{
+    type Value = f32;
+    fn construct(value: Self::Value) -> Self
+    {
+        Self(value)
+    }
+}
+
+impl ResponsiveAttribute for ImageSaturation {}
+impl AnimatedAttribute for ImageSaturation
+{
+    fn get_value(entity: Entity, world: &World) -> Option<Self::Value>
+    {
+        world.get_entity(entity).ok()?;
+        Some(
+            world
+                .get::<ImageFilter>(entity)
+                .cloned()
+                .unwrap_or_default()
+                .saturation,
+        )
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Sets [`ImageFilter::brightness`] on an image node.
+#[derive(Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct ImageBrightness(pub f32);
+
+impl Instruction for ImageBrightness
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        edit_image_filter(entity, world, |f| f.brightness = self.0);
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        revert_image_filter(entity, world, |f| f.brightness = ImageFilter::default().brightness);
+    }
+}
+
+impl StaticAttribute for ImageBrightness
+{
+    type Value = f32;
+    fn construct(value: Self::Value) -> Self
+    {
+        Self(value)
+    }
+}
+
+impl ResponsiveAttribute for ImageBrightness {}
+impl AnimatedAttribute for ImageBrightness
+{
+    fn get_value(entity: Entity, world: &World) -> Option<Self::Value>
+    {
+        world.get_entity(entity).ok()?;
+        Some(
+            world
+                .get::<ImageFilter>(entity)
+                .cloned()
+                .unwrap_or_default()
+                .brightness,
+        )
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Sets [`ImageFilter::contrast`] on an image node.
+#[derive(Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct ImageContrast(pub f32);
+
+impl Instruction for ImageContrast
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        edit_image_filter(entity, world, |f| f.contrast = self.0);
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        revert_image_filter(entity, world, |f| f.contrast = ImageFilter::default().contrast);
+    }
+}
+
+impl StaticAttribute for ImageContrast
+{
+    type Value = f32;
+    fn construct(value: Self::Value) -> Self
+    {
+        Self(value)
+    }
+}
+
+impl ResponsiveAttribute for ImageContrast {}
+impl AnimatedAttribute for ImageContrast
+{
+    fn get_value(entity: Entity, world: &World) -> Option<Self::Value>
+    {
+        world.get_entity(entity).ok()?;
+        Some(
+            world
+                .get::<ImageFilter>(entity)
+                .cloned()
+                .unwrap_or_default()
+                .contrast,
+        )
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct ImageFilterPlugin;
+
+impl Plugin for ImageFilterPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        if !app.is_plugin_added::<UiMaterialPlugin<ImageFilterMaterial>>() {
+            app.add_plugins(UiMaterialPlugin::<ImageFilterMaterial>::default());
+        }
+
+        app.register_type::<ImageFilter>()
+            .register_animatable::<ImageTint>()
+            .register_animatable::<ImageSaturation>()
+            .register_animatable::<ImageBrightness>()
+            .register_animatable::<ImageContrast>()
+            .add_systems(
+                PostUpdate,
+                update_image_filters
+                    .after(ControlSet)
+                    .after(DynamicStylePostUpdate)
+                    .after(propagate_opacity_values)
+                    .before(text_system),
+            )
+            // After rendering.
+            .add_systems(First, restore_filtered_images.before(restore_opacity));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -88,10 +88,7 @@ impl LoadedImageNode
                     the image texture is None");
                 return None;
             };
-            Some(TextureAtlas {
-                layout: layout_map.get(img, &a.alias),
-                index: a.index,
-            })
+            Some(layout_map.get_atlas(img, &a))
         });
         ImageNode {
             color: self.color,
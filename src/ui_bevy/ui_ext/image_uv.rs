@@ -0,0 +1,299 @@
+use bevy::prelude::*;
+
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn edit_uv_transform(entity: Entity, world: &mut World, callback: impl FnOnce(&mut ImageUvTransform))
+{
+    let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+    match emut.get_mut::<ImageUvTransform>() {
+        Some(mut transform) => callback(&mut transform),
+        None => {
+            let mut transform = ImageUvTransform::default();
+            callback(&mut transform);
+            emut.insert(transform);
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn revert_uv_transform(entity: Entity, world: &mut World, callback: impl FnOnce(&mut ImageUvTransform))
+{
+    let Some(mut transform) = world.get_mut::<ImageUvTransform>(entity) else { return };
+    callback(&mut transform);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Component with a texture-coordinate transform applied to an [`ImageNode`] on the same entity.
+///
+/// Usually set with the [`ImageTiling`], [`ImageUvOffset`], [`ImageUvScroll`], and [`ImageUvRotation`]
+/// instructions, which can be animated.
+///
+/// The transform is applied within the image's texture region, so it works with texture atlases (e.g. a
+/// scrolling atlas frame) independently of [`ImageNodeIndex`] flipbook animations. Coordinates outside the
+/// region wrap around.
+///
+/// When the transform is not the default, the image is rendered with [`ImageFilterMaterial`] (see
+/// [`ImageFilter`] for limitations).
+#[derive(Component, Reflect, Debug, Clone, PartialEq)]
+pub struct ImageUvTransform
+{
+    /// Number of times the image repeats along each axis.
+    ///
+    /// Defaults to `(1.0, 1.0)`.
+    pub tiling: Vec2,
+    /// Offset of the image in texture coordinates (`1.0` is one full image).
+    ///
+    /// Defaults to `(0.0, 0.0)`.
+    pub offset: Vec2,
+    /// Scroll speed in texture coordinates per second. Scrolling accumulates on top of [`Self::offset`].
+    ///
+    /// Defaults to `(0.0, 0.0)`.
+    pub scroll: Vec2,
+    /// Rotation of the image around the node's center, in radians.
+    ///
+    /// Defaults to `0.0`.
+    pub rotation: f32,
+}
+
+impl ImageUvTransform
+{
+    /// Returns `true` if the transform doesn't change the image.
+    pub fn is_identity(&self) -> bool
+    {
+        *self == Self::default()
+    }
+}
+
+impl Default for ImageUvTransform
+{
+    fn default() -> Self
+    {
+        Self {
+            tiling: Vec2::ONE,
+            offset: Vec2::ZERO,
+            scroll: Vec2::ZERO,
+            rotation: 0.,
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Sets [`ImageUvTransform::tiling`] on an image node.
+#[derive(Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct ImageTiling(pub Vec2);
+
+impl Instruction for ImageTiling
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        edit_uv_transform(entity, world, |t| t.tiling = self.0);
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        revert_uv_transform(entity, world, |t| t.tiling = ImageUvTransform::default().tiling);
+    }
+}
+
+impl StaticAttribute for ImageTiling
+{
+    type Value = Vec2;
+    fn construct(value: Self::Value) -> Self
+    {
+        Self(value)
+    }
+}
+
+impl ResponsiveAttribute for ImageTiling {}
+impl AnimatedAttribute for ImageTiling
+{
+    fn get_value(entity: Entity, world: &World) -> Option<Self::Value>
+    {
+        world.get_entity(entity).ok()?;
+        Some(
+            world
+                .get::<ImageUvTransform>(entity)
+                .cloned()
+                .unwrap_or_default()
+                .tiling,
+        )
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Sets [`ImageUvTransform::offset`] on an image node.
+#[derive(Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct ImageUvOffset(pub Vec2);
+
+impl Instruction for ImageUvOffset
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        edit_uv_transform(entity, world, |t| t.offset = self.0);
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        revert_uv_transform(entity, world, |t| t.offset = ImageUvTransform::default().offset);
+    }
+}
+
+impl StaticAttribute for ImageUvOffset
+{
+    type Value = Vec2;
+    fn construct(value: Self::Value) -> Self
+    {
+        Self(value)
+    }
+}
+
+impl ResponsiveAttribute for ImageUvOffset {}
+impl AnimatedAttribute for ImageUvOffset
+{
+    fn get_value(entity: Entity, world: &World) -> Option<Self::Value>
+    {
+        world.get_entity(entity).ok()?;
+        Some(
+            world
+                .get::<ImageUvTransform>(entity)
+                .cloned()
+                .unwrap_or_default()
+                .offset,
+        )
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Sets [`ImageUvTransform::scroll`] on an image node.
+///
+/// Useful for animated backgrounds and marquee effects. For example, `ImageUvScroll(Vec2::new(0.25, 0.))` scrolls
+/// one full image width every four seconds.
+#[derive(Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct ImageUvScroll(pub Vec2);
+
+impl Instruction for ImageUvScroll
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        edit_uv_transform(entity, world, |t| t.scroll = self.0);
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        revert_uv_transform(entity, world, |t| t.scroll = ImageUvTransform::default().scroll);
+    }
+}
+
+impl StaticAttribute for ImageUvScroll
+{
+    type Value = Vec2;
+    fn construct(value: Self::Value) -> Self
+    {
+        Self(value)
+    }
+}
+
+impl ResponsiveAttribute for ImageUvScroll {}
+impl AnimatedAttribute for ImageUvScroll
+{
+    fn get_value(entity: Entity, world: &World) -> Option<Self::Value>
+    {
+        world.get_entity(entity).ok()?;
+        Some(
+            world
+                .get::<ImageUvTransform>(entity)
+                .cloned()
+                .unwrap_or_default()
+                .scroll,
+        )
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Sets [`ImageUvTransform::rotation`] on an image node.
+#[derive(Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct ImageUvRotation(pub f32);
+
+impl Instruction for ImageUvRotation
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        edit_uv_transform(entity, world, |t| t.rotation = self.0);
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        revert_uv_transform(entity, world, |t| t.rotation = ImageUvTransform::default().rotation);
+    }
+}
+
+impl StaticAttribute for ImageUvRotation
+{
+    type Value = f32;
+    fn construct(value: Self::Value) -> Self
+    {
+        Self(value)
+    }
+}
+
+impl ResponsiveAttribute for ImageUvRotation {}
+impl AnimatedAttribute for ImageUvRotation
+{
+    fn get_value(entity: Entity, world: &World) -> Option<Self::Value>
+    {
+        world.get_entity(entity).ok()?;
+        Some(
+            world
+                .get::<ImageUvTransform>(entity)
+                .cloned()
+                .unwrap_or_default()
+                .rotation,
+        )
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct ImageUvPlugin;
+
+impl Plugin for ImageUvPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.register_type::<ImageUvTransform>()
+            .register_animatable::<ImageTiling>()
+            .register_animatable::<ImageUvOffset>()
+            .register_animatable::<ImageUvScroll>()
+            .register_animatable::<ImageUvRotation>();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
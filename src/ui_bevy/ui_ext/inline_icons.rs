@@ -0,0 +1,443 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bevy::text::{ComputedTextBlock, TextLayoutInfo};
+use bevy::transform::TransformSystem;
+use bevy::ui::UiSystem;
+use bevy_cobweb::prelude::*;
+
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Placeholder text that reserves space for inline icons. An em space is one font-size wide.
+const INLINE_ICON_PLACEHOLDER: &str = "\u{2003}";
+
+/// Offset of an icon's center above the text baseline, as a fraction of the font size.
+const INLINE_ICON_BASELINE_OFFSET: f32 = 0.35;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn register_inline_icons(
+    In(icons): In<Vec<InlineIconDef>>,
+    asset_server: Res<AssetServer>,
+    mut images: ResMut<ImageMap>,
+    mut icon_map: ResMut<InlineIconMap>,
+)
+{
+    for icon in icons {
+        // Make sure the image is loaded.
+        images.get_or_load(&icon.image, &asset_server);
+        icon_map.insert(icon);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn despawn_inline_icon_entities(c: &mut Commands, spans: &mut InlineIconSpans)
+{
+    for span in spans.spans.drain(..) {
+        c.entity(span).try_despawn_recursive();
+    }
+    for (_, icon) in spans.icons.drain(..) {
+        c.entity(icon).try_despawn_recursive();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn cleanup_inline_icons(
+    trigger: Trigger<OnRemove, InlineIconSpans>,
+    mut c: Commands,
+    mut spans: Query<&mut InlineIconSpans>,
+)
+{
+    let Ok(mut spans) = spans.get_mut(trigger.entity()) else { return };
+    despawn_inline_icon_entities(&mut c, &mut spans);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Converts inline icon tags in text into placeholder spans and icon nodes.
+fn apply_inline_icons(
+    mut c: Commands,
+    icon_map: Res<InlineIconMap>,
+    images: Res<ImageMap>,
+    layouts: Res<TextureAtlasLayoutMap>,
+    mut texts: Query<
+        (
+            Entity,
+            Mut<Text>,
+            &TextFont,
+            &TextColor,
+            Option<&Parent>,
+            Option<&mut InlineIconSpans>,
+        ),
+        Changed<Text>,
+    >,
+)
+{
+    for (entity, mut text, text_font, text_color, maybe_parent, maybe_spans) in texts.iter_mut() {
+        let had_spans = maybe_spans.is_some();
+        if let Some(mut spans) = maybe_spans {
+            despawn_inline_icon_entities(&mut c, &mut spans);
+        }
+
+        let Some((root_text, segments)) = parse_inline_icons(text.as_str()) else {
+            if had_spans {
+                c.entity(entity).remove::<InlineIconSpans>();
+            }
+            continue;
+        };
+
+        let mut spans = Vec::with_capacity(segments.len() * 2);
+        let mut icons = Vec::with_capacity(segments.len());
+        for segment in segments {
+            match icon_map.get(&segment.icon) {
+                Some(def) => {
+                    let placeholder = c
+                        .spawn((
+                            TextSpan(INLINE_ICON_PLACEHOLDER.into()),
+                            TextFont {
+                                font_size: text_font.font_size * def.scale,
+                                ..text_font.clone()
+                            },
+                            TextColor(Color::NONE),
+                        ))
+                        .id();
+                    let icon = c
+                        .spawn((
+                            Node { position_type: PositionType::Absolute, ..default() },
+                            def.to_image_node(&images, &layouts),
+                            Visibility::Hidden,
+                            PickingBehavior::IGNORE,
+                            InlineIconNode,
+                        ))
+                        .id();
+                    if let Some(parent) = maybe_parent {
+                        c.entity(parent.get()).add_child(icon);
+                    }
+                    spans.push(placeholder);
+                    icons.push((placeholder, icon));
+                }
+                None => {
+                    tracing::warn!("failed adding inline icon {:?} to text on entity {entity:?}; the icon is not \
+                        registered in InlineIconMap", segment.icon);
+                }
+            }
+
+            if segment.text.is_empty() {
+                continue;
+            }
+            spans.push(
+                c.spawn((TextSpan(segment.text), text_font.clone(), *text_color))
+                    .id(),
+            );
+        }
+        c.entity(entity).insert_children(0, &spans);
+
+        // Spans are inserted in the same tick, so the text block will be recomputed without needing to mark the
+        // root text as changed. This avoids re-running text systems that react to text changes.
+        text.bypass_change_detection().0 = root_text;
+        c.entity(entity).insert(InlineIconSpans { spans, icons });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Positions inline icons over their placeholder glyphs.
+///
+/// Icons are positioned after layout, so they are displayed with one frame of delay when text moves.
+fn update_inline_icon_positions(
+    texts: Query<(
+        &InlineIconSpans,
+        &ComputedNode,
+        &GlobalTransform,
+        &TextLayoutInfo,
+        &ComputedTextBlock,
+        &InheritedVisibility,
+        Option<&Parent>,
+    )>,
+    parents: Query<(&ComputedNode, &GlobalTransform, Option<&ScrollPosition>)>,
+    fonts: Query<&TextFont>,
+    mut icons: Query<(&mut Node, &mut Visibility), With<InlineIconNode>>,
+)
+{
+    for (spans, node, transform, layout, block, inherited_visibility, maybe_parent) in texts.iter() {
+        let inverse_scale = node.inverse_scale_factor();
+        let text_origin = transform.translation().truncate() - node.size() / 2.;
+
+        // Icons are absolute-positioned relative to the parent's padding box.
+        let (parent_origin, scroll) = maybe_parent
+            .and_then(|p| parents.get(p.get()).ok())
+            .map(|(parent_node, parent_transform, maybe_scroll)| {
+                let border = parent_node.border();
+                let origin = parent_transform.translation().truncate() - parent_node.size() / 2.
+                    + Vec2::new(border.left, border.top);
+                let scroll = maybe_scroll
+                    .map(|s| Vec2::new(s.offset_x, s.offset_y))
+                    .unwrap_or_default();
+                (origin, scroll)
+            })
+            .unwrap_or_default();
+
+        for (placeholder, icon) in spans.icons.iter() {
+            let Ok((mut icon_node, mut visibility)) = icons.get_mut(*icon) else { continue };
+
+            let glyph = block
+                .entities()
+                .iter()
+                .position(|e| e.entity == *placeholder)
+                .and_then(|span_index| layout.glyphs.iter().find(|g| g.span_index == span_index));
+            let (Some(glyph), Ok(font)) = (glyph, fonts.get(*placeholder)) else {
+                visibility.set_if_neq(Visibility::Hidden);
+                continue;
+            };
+
+            // Glyph positions are the glyph's center, and the bottom of the glyph is the baseline.
+            let size = font.font_size / inverse_scale;
+            let left = glyph.position.x - glyph.size.x / 2.;
+            let baseline = glyph.position.y + glyph.size.y / 2.;
+            let center = Vec2::new(left + size / 2., baseline - size * INLINE_ICON_BASELINE_OFFSET);
+            let top_left = (text_origin + center - size / 2. - parent_origin) * inverse_scale + scroll;
+
+            let mut new_node = icon_node.clone();
+            new_node.left = Val::Px(top_left.x);
+            new_node.top = Val::Px(top_left.y);
+            new_node.width = Val::Px(font.font_size);
+            new_node.height = Val::Px(font.font_size);
+            icon_node.set_if_neq(new_node);
+
+            let new_visibility = match inherited_visibility.get() {
+                true => Visibility::Inherited,
+                false => Visibility::Hidden,
+            };
+            visibility.set_if_neq(new_visibility);
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Tracks text spans and icon nodes spawned for inline icons.
+#[derive(Component, Debug)]
+struct InlineIconSpans
+{
+    spans: Vec<Entity>,
+    /// [ (placeholder span, icon node) ]
+    icons: Vec<(Entity, Entity)>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Marker component for icon nodes spawned for inline icons.
+///
+/// See [`parse_inline_icons`].
+#[derive(Component, Debug)]
+pub struct InlineIconNode;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A segment of text that starts with an inline icon.
+///
+/// See [`parse_inline_icons`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InlineIconSegment
+{
+    /// The name of the icon in [`InlineIconMap`].
+    pub icon: String,
+    /// Text after the icon.
+    pub text: String,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Parses `<icon=name>` tags in text.
+///
+/// Returns the text before the first icon and the segments that start with an icon, or `None` if the text has
+/// no icon tags. Icon names are trimmed, and tags with empty names are kept as literal text.
+///
+/// Icon tags in a [`Text`] are automatically converted into placeholder [`TextSpans`](TextSpan) and
+/// absolute-positioned [`ImageNodes`](ImageNode) (marked with [`InlineIconNode`]) that flow with the text. Icons
+/// are square and sized to the text's font size times [`InlineIconDef::scale`]. Icons are only supported in a
+/// text's root span, and text after an icon uses the root span's font and color.
+///
+/// Icon nodes are spawned as siblings of the text entity, so texts with icons should have a parent node.
+pub fn parse_inline_icons(text: &str) -> Option<(String, Vec<InlineIconSegment>)>
+{
+    const TAG_START: &str = "<icon=";
+
+    let mut root = String::default();
+    let mut segments: Vec<InlineIconSegment> = vec![];
+    let mut remaining = text;
+
+    while let Some(start) = remaining.find(TAG_START) {
+        let name_start = start + TAG_START.len();
+        let Some(end) = remaining[name_start..].find('>').map(|e| e + name_start) else { break };
+        let name = remaining[name_start..end].trim();
+
+        let current = segments
+            .last_mut()
+            .map(|s| &mut s.text)
+            .unwrap_or(&mut root);
+        if name.is_empty() {
+            // Keep invalid tags as literal text.
+            current.push_str(&remaining[..name_start]);
+            remaining = &remaining[name_start..];
+            continue;
+        }
+        current.push_str(&remaining[..start]);
+        segments.push(InlineIconSegment { icon: name.into(), text: String::default() });
+        remaining = &remaining[(end + 1)..];
+    }
+
+    if segments.is_empty() {
+        return None;
+    }
+
+    segments.last_mut().unwrap().text.push_str(remaining);
+    Some((root, segments))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// The definition of an inline icon that can be embedded in text with `<icon=name>`.
+///
+/// See [`RegisterInlineIcons`].
+#[derive(Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InlineIconDef
+{
+    /// The icon's name.
+    pub name: String,
+    /// The icon's image.
+    pub image: String,
+    /// A reference to a [`TextureAtlas`] in the icon's image, for icons stored in sprite sheets.
+    ///
+    /// The atlas's layout should be loaded into [`TextureAtlasLayoutMap`].
+    #[reflect(default)]
+    pub atlas: Option<TextureAtlasReference>,
+    /// The icon's size relative to the text's font size.
+    ///
+    /// Defaults to `1.0`.
+    #[reflect(default = "InlineIconDef::default_scale")]
+    pub scale: f32,
+    /// The icon's color.
+    ///
+    /// Defaults to white.
+    #[reflect(default = "InlineIconDef::default_color")]
+    pub color: Color,
+}
+
+impl InlineIconDef
+{
+    /// Converts to an [`ImageNode`].
+    pub fn to_image_node(&self, images: &ImageMap, layouts: &TextureAtlasLayoutMap) -> ImageNode
+    {
+        ImageNode {
+            color: self.color,
+            image: images.get(&self.image),
+            texture_atlas: self
+                .atlas
+                .as_ref()
+                .map(|a| layouts.get_atlas(&self.image, a)),
+            ..default()
+        }
+    }
+
+    fn default_scale() -> f32
+    {
+        1.
+    }
+
+    fn default_color() -> Color
+    {
+        Color::WHITE
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource that stores icons that can be embedded in text with `<icon=name>`.
+///
+/// See [`parse_inline_icons`].
+#[derive(Resource, Default, Debug)]
+pub struct InlineIconMap
+{
+    icons: HashMap<String, InlineIconDef>,
+}
+
+impl InlineIconMap
+{
+    /// Inserts an icon. Replaces any existing icon with the same name.
+    ///
+    /// The icon's image should be loaded into [`ImageMap`].
+    pub fn insert(&mut self, icon: InlineIconDef)
+    {
+        self.icons.insert(icon.name.clone(), icon);
+    }
+
+    /// Gets an icon.
+    pub fn get(&self, name: impl AsRef<str>) -> Option<&InlineIconDef>
+    {
+        self.icons.get(name.as_ref())
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Loadable command for registering icons that can be embedded in text with `<icon=name>`.
+///
+/// Icon images are loaded into [`ImageMap`]. Texts that were already spawned won't be updated if an icon
+/// changes.
+///
+/// Example:
+/**
+```rust
+#commands
+RegisterInlineIcons[
+    {name: "button_a" image: "icons/button_a.png"}
+    {name: "button_b" image: "icons/buttons.png" atlas: {alias: "buttons" index: 1}}
+]
+
+#scenes
+"hint"
+    TextLine{ text: "Press <icon=button_a> to continue" }
+```
+*/
+#[derive(Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RegisterInlineIcons(pub Vec<InlineIconDef>);
+
+impl Command for RegisterInlineIcons
+{
+    fn apply(self, world: &mut World)
+    {
+        world.syscall(self.0, register_inline_icons);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct InlineIconsPlugin;
+
+impl Plugin for InlineIconsPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_resource::<InlineIconMap>()
+            .register_command_type::<RegisterInlineIcons>()
+            .add_observer(cleanup_inline_icons)
+            .add_systems(
+                PostUpdate,
+                (
+                    apply_inline_icons
+                        .after(LocalizationSet::Update)
+                        .before(UiSystem::Prepare),
+                    update_inline_icon_positions.after(TransformSystem::TransformPropagate),
+                ),
+            );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,355 @@
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+use bevy_cobweb::prelude::*;
+use fluent_langneg::LanguageIdentifier;
+
+use crate::prelude::*;
+use crate::sickle::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Languages written right-to-left by default.
+const RTL_LANGUAGES: &[&str] =
+    &["ar", "arc", "ckb", "dv", "fa", "ha", "he", "iw", "ks", "ps", "sd", "ug", "ur", "yi"];
+
+/// Scripts written right-to-left.
+const RTL_SCRIPTS: &[&str] = &["Adlm", "Arab", "Hebr", "Nkoo", "Rohg", "Syrc", "Thaa"];
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn mirror_justify_content(justify: &mut JustifyContent)
+{
+    *justify = match *justify {
+        JustifyContent::Start => JustifyContent::End,
+        JustifyContent::End => JustifyContent::Start,
+        other => other,
+    };
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn mirror_grid_justify_content(justify: &mut JustifyContent)
+{
+    *justify = match *justify {
+        JustifyContent::FlexStart => JustifyContent::FlexEnd,
+        JustifyContent::FlexEnd => JustifyContent::FlexStart,
+        other => other,
+    };
+    mirror_justify_content(justify);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn mirror_justify_items(justify: &mut JustifyItems)
+{
+    *justify = match *justify {
+        JustifyItems::Start => JustifyItems::End,
+        JustifyItems::End => JustifyItems::Start,
+        other => other,
+    };
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn mirror_align_items(align: &mut AlignItems)
+{
+    *align = match *align {
+        AlignItems::Start => AlignItems::End,
+        AlignItems::End => AlignItems::Start,
+        AlignItems::FlexStart => AlignItems::FlexEnd,
+        AlignItems::FlexEnd => AlignItems::FlexStart,
+        other => other,
+    };
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn mirror_align_content(align: &mut AlignContent)
+{
+    *align = match *align {
+        AlignContent::Start => AlignContent::End,
+        AlignContent::End => AlignContent::Start,
+        AlignContent::FlexStart => AlignContent::FlexEnd,
+        AlignContent::FlexEnd => AlignContent::FlexStart,
+        other => other,
+    };
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn swap_horizontal(rect: &mut UiRect)
+{
+    std::mem::swap(&mut rect.left, &mut rect.right);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Mirrors a node horizontally.
+///
+/// This is an involution, so applying it twice restores the original node.
+fn mirror_node(node: &mut Node)
+{
+    std::mem::swap(&mut node.left, &mut node.right);
+    swap_horizontal(&mut node.margin);
+    swap_horizontal(&mut node.padding);
+    swap_horizontal(&mut node.border);
+
+    node.flex_direction = match node.flex_direction {
+        FlexDirection::Row => FlexDirection::RowReverse,
+        FlexDirection::RowReverse => FlexDirection::Row,
+        other => other,
+    };
+
+    match (node.display, node.flex_direction) {
+        (Display::Grid, _) => {
+            mirror_grid_justify_content(&mut node.justify_content);
+            mirror_justify_items(&mut node.justify_items);
+        }
+        // Reversing the row already mirrors flex-relative alignment.
+        (_, FlexDirection::Row | FlexDirection::RowReverse) => {
+            mirror_justify_content(&mut node.justify_content);
+        }
+        // The cross axis of columns is horizontal.
+        _ => {
+            mirror_align_items(&mut node.align_items);
+            mirror_align_content(&mut node.align_content);
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn mirror_text_layout(layout: &mut TextLayout)
+{
+    layout.justify = match layout.justify {
+        JustifyText::Left => JustifyText::Right,
+        JustifyText::Right => JustifyText::Left,
+        other => other,
+    };
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn update_layout_direction(manifest: Res<LocalizationManifest>, mut direction: ResMut<UiLayoutDirection>)
+{
+    let locale = match manifest.negotiated().first() {
+        Some(lang) if is_rtl_language(lang) => LayoutDirection::Rtl,
+        _ => LayoutDirection::Ltr,
+    };
+    if direction.locale != locale {
+        direction.locale = locale;
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Mirrors direction-aware nodes before layout.
+///
+/// Nodes are mirrored in-place and restored in `restore_direction_aware_nodes`, so user code and instructions
+/// always see the authored (left-to-right) values.
+pub(crate) fn mirror_direction_aware_nodes(
+    direction: Res<UiLayoutDirection>,
+    mut nodes: Query<(
+        Mut<Node>,
+        Ref<DirectionAware>,
+        &mut DirectionAwareState,
+        Option<Mut<TextLayout>>,
+    )>,
+)
+{
+    let rtl = direction.is_rtl();
+    let direction_changed = direction.is_changed();
+
+    for (mut node, aware, mut state, maybe_layout) in nodes.iter_mut() {
+        // Nodes are restored every tick, so we only need to refresh layout when the direction changes.
+        if !rtl {
+            if direction_changed {
+                node.set_changed();
+                if let Some(mut layout) = maybe_layout {
+                    layout.set_changed();
+                }
+            }
+            continue;
+        }
+
+        // Avoid triggering layout every tick when nothing changed. Layout caches the previously-mirrored node.
+        let refresh = direction_changed || aware.is_added();
+        let refresh_node = refresh || node.is_changed();
+        mirror_node(node.bypass_change_detection());
+        if refresh_node {
+            node.set_changed();
+        }
+        if let Some(mut layout) = maybe_layout {
+            let refresh_layout = refresh || layout.is_changed();
+            mirror_text_layout(layout.bypass_change_detection());
+            if refresh_layout {
+                layout.set_changed();
+            }
+        }
+        state.mirrored = true;
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Un-mirrors nodes that were mirrored for layout.
+pub(crate) fn restore_direction_aware_nodes(
+    mut nodes: Query<(&mut Node, &mut DirectionAwareState, Option<&mut TextLayout>), With<DirectionAware>>,
+)
+{
+    for (mut node, mut state, maybe_layout) in nodes.iter_mut() {
+        if !state.mirrored {
+            continue;
+        }
+        state.mirrored = false;
+        mirror_node(node.bypass_change_detection());
+        if let Some(mut layout) = maybe_layout {
+            mirror_text_layout(layout.bypass_change_detection());
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Component, Default, Debug)]
+pub(crate) struct DirectionAwareState
+{
+    mirrored: bool,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Returns `true` if a language is written right-to-left.
+///
+/// Uses the language's script if it is specified (e.g. `az-Arab`), otherwise falls back to a built-in list of
+/// right-to-left languages.
+pub fn is_rtl_language(lang: &LanguageIdentifier) -> bool
+{
+    if let Some(script) = lang.script {
+        return RTL_SCRIPTS.contains(&script.as_str());
+    }
+    RTL_LANGUAGES.contains(&lang.language.as_str())
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// The horizontal direction of UI layout.
+#[derive(Reflect, Default, Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum LayoutDirection
+{
+    /// Left-to-right.
+    #[default]
+    Ltr,
+    /// Right-to-left.
+    Rtl,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource that controls the layout direction of [`DirectionAware`] nodes.
+///
+/// By default the direction is detected from the first negotiated language in [`LocalizationManifest`] whenever
+/// the app is [relocalized](RelocalizeApp). See [`is_rtl_language`].
+#[derive(Resource, Default, Debug)]
+pub struct UiLayoutDirection
+{
+    /// Overrides the direction detected from the current locale.
+    ///
+    /// Defaults to `None`.
+    pub force: Option<LayoutDirection>,
+    locale: LayoutDirection,
+}
+
+impl UiLayoutDirection
+{
+    /// Gets the current layout direction.
+    pub fn get(&self) -> LayoutDirection
+    {
+        self.force.unwrap_or(self.locale)
+    }
+
+    /// Returns `true` if the current layout direction is right-to-left.
+    pub fn is_rtl(&self) -> bool
+    {
+        self.get() == LayoutDirection::Rtl
+    }
+
+    /// Gets the layout direction detected from the current locale.
+    pub fn locale_direction(&self) -> LayoutDirection
+    {
+        self.locale
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction that marks a node for horizontal mirroring when [`UiLayoutDirection`] is right-to-left.
+///
+/// When mirrored:
+/// - `FlexDirection::Row` and `FlexDirection::RowReverse` are swapped.
+/// - Left/right margins, padding, borders, and absolute offsets are swapped.
+/// - Horizontal alignment is mirrored: `JustifyContent::Start/End` in rows, `AlignItems` and `AlignContent` in
+///   columns, and `JustifyContent`/`JustifyItems` in grids.
+/// - `JustifyText::Left` and `JustifyText::Right` are swapped if the node has [`TextLayout`].
+///
+/// Mirroring is only applied for layout, so the node's components always contain the authored left-to-right
+/// values. Grid track order and `AlignSelf`/`JustifySelf` are not mirrored.
+///
+/// Add this to every node that should be mirrored, e.g. by bundling it into a COB constant with your row layouts.
+#[derive(Component, Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct DirectionAware;
+
+impl Instruction for DirectionAware
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.insert((self, DirectionAwareState::default()));
+        });
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.remove::<(Self, DirectionAwareState)>();
+        });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct LayoutDirectionPlugin;
+
+impl Plugin for LayoutDirectionPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.register_type::<LayoutDirection>()
+            .init_resource::<UiLayoutDirection>()
+            .register_instruction_type::<DirectionAware>()
+            .react(|rc| rc.on_persistent(broadcast::<RelocalizeApp>(), update_layout_direction))
+            .add_systems(
+                PostUpdate,
+                mirror_direction_aware_nodes
+                    .after(ControlSet)
+                    .after(DynamicStylePostUpdate)
+                    // Editor changes are applied in PostUpdate.
+                    .after(FileProcessingSet)
+                    .before(UiSystem::Prepare),
+            )
+            // After rendering. Loadables applied while processing files must not be un-mirrored.
+            .add_systems(First, restore_direction_aware_nodes.before(FileProcessingSet));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
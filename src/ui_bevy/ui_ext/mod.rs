@@ -1,15 +1,65 @@
+mod accessibility;
+mod animate_layout;
+mod anchor_to_world;
+mod animated_sprite;
+mod backdrop_blur;
 mod component_wrappers;
+mod content_sizing;
+mod emoji;
+mod grid_areas;
+mod image_filter;
 mod image_node;
+mod image_uv;
+mod inline_icons;
+mod layout_direction;
 mod opacity;
+mod other_node;
 mod plugin;
+mod render_target_scene;
+mod safe_area;
+mod sliced_text;
+mod spawn_transitions;
 mod style_field_wrappers;
 mod style_wrappers;
+#[cfg(feature = "svg")]
+mod svg;
 mod text;
+mod text_fit;
+mod text_links;
+mod ui_root_camera;
+mod ui_scale;
+mod ui_shader_material;
+mod viewport_anchor;
 
+pub use accessibility::*;
+pub use animate_layout::*;
+pub use anchor_to_world::*;
+pub use animated_sprite::*;
+pub use backdrop_blur::*;
 pub use component_wrappers::*;
+pub use content_sizing::*;
+pub(crate) use emoji::*;
+pub use grid_areas::*;
+pub use image_filter::*;
 pub use image_node::*;
+pub use image_uv::*;
+pub use inline_icons::*;
+pub use layout_direction::*;
 pub use opacity::*;
+pub use other_node::*;
 pub use plugin::*;
+pub use render_target_scene::*;
+pub use safe_area::*;
+pub use sliced_text::*;
+pub use spawn_transitions::*;
 pub use style_field_wrappers::*;
 pub use style_wrappers::*;
+#[cfg(feature = "svg")]
+pub use svg::*;
 pub use text::*;
+pub use text_fit::*;
+pub use text_links::*;
+pub use ui_root_camera::*;
+pub use ui_scale::*;
+pub use ui_shader_material::*;
+pub use viewport_anchor::*;
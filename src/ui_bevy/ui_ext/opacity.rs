@@ -1,14 +1,15 @@
 use bevy::ecs::entity::{EntityHashMap, EntityHashSet};
+use bevy::picking::events::pointer_events;
+use bevy::picking::focus::{update_focus, HoverMap};
+use bevy::picking::PickSet;
 use bevy::prelude::*;
 use bevy::ui::widget::text_system;
+use bevy::ui::UiSystem;
 use smallvec::SmallVec;
 
 use crate::prelude::*;
 use crate::sickle::DynamicStylePostUpdate;
 
-// TODO: consider adding IgnorePropagateOpacity so child nodes can opt-out. This would allow you to for example
-// fade in ancestor nodes while keeping a segment of the node tree the same opacity.
-
 //-------------------------------------------------------------------------------------------------------------------
 
 const ALPHA_ROUNDING_ERROR: f32 = 0.0000001;
@@ -58,7 +59,7 @@ fn set_color_alpha(color: &mut Color, new_alpha: f32)
 /// Used to restore alpha values after rendering.
 // TODO: consider a better design that's more flexible for user-defined components?
 #[derive(Component, Clone, Debug, Default)]
-struct RestorableOpacity
+pub(crate) struct RestorableOpacity
 {
     ui_image: f32,
     // Record for each span.
@@ -70,17 +71,62 @@ struct RestorableOpacity
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Returns `true` if the entity or one of its ancestors is an [`OpacityGroup`] with interactions disabled.
+fn in_faded_group(entity: Entity, faded: &FadedOpacityGroups, parents: &Query<&Parent>) -> bool
+{
+    faded.0.contains(&entity) || parents.iter_ancestors(entity).any(|e| faded.0.contains(&e))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Removes hovered entities in faded opacity groups, so pointer events aren't sent to them.
+fn filter_faded_hovers(mut hover_map: ResMut<HoverMap>, faded: Res<FadedOpacityGroups>, parents: Query<&Parent>)
+{
+    if faded.0.is_empty() {
+        return;
+    }
+
+    for hovered in hover_map.values_mut() {
+        hovered.retain(|entity, _| !in_faded_group(*entity, &faded, &parents));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resets the [`Interaction`] of entities in faded opacity groups.
+fn reset_faded_interactions(
+    faded: Res<FadedOpacityGroups>,
+    parents: Query<&Parent>,
+    mut interactions: Query<(Entity, &mut Interaction)>,
+)
+{
+    if faded.0.is_empty() {
+        return;
+    }
+
+    for (entity, mut interaction) in interactions.iter_mut() {
+        if *interaction == Interaction::None || !in_faded_group(entity, &faded, &parents) {
+            continue;
+        }
+        *interaction = Interaction::None;
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 fn recursively_propagate_opacity_value(
     mut first_traversal: bool,
     mut accumulated_opacity: f32,
     seen_propagators: &mut EntityHashSet,
     insertion_first_traversal_vals: &mut EntityHashMap<RestorableOpacity>,
+    faded: &mut FadedOpacityGroups,
     c: &mut Commands,
     writer: &mut TextUiWriter,
     children_query: &Query<&Children>,
     nodes: &mut Query<
         (
             Option<&PropagateOpacity>,
+            Option<&OpacityGroup>,
             Option<&mut RestorableOpacity>,
             Option<&mut ImageNode>,
             Has<Text>,
@@ -95,6 +141,7 @@ fn recursively_propagate_opacity_value(
 {
     let Ok((
         maybe_propagator,
+        maybe_group,
         maybe_restorable,
         maybe_img,
         has_text,
@@ -106,8 +153,8 @@ fn recursively_propagate_opacity_value(
         return;
     };
 
-    // Handle the case that this node has `PropagateOpacity`.
-    if let Some(PropagateOpacity(value)) = maybe_propagator {
+    // Handle the case that this node is an opacity group root.
+    if maybe_propagator.is_some() || maybe_group.is_some() {
         // Track seen.
         if !seen_propagators.insert(entity) {
             // If we've already seen this propagator, then this node and its children must have already
@@ -115,11 +162,29 @@ fn recursively_propagate_opacity_value(
             first_traversal = false;
         }
 
+        // Isolated groups ignore ancestor opacities.
+        if maybe_group.is_some_and(|g| g.isolate) {
+            accumulated_opacity = 1.0;
+        }
+
         // Accumulate this value.
         // - Ignoring 1.0 hopefully avoids weird floating point issues that would invalidate the 1.0 check down
         //   below.
-        if !value.is_nan() && *value != 1.0 {
-            accumulated_opacity *= *value;
+        if let Some(PropagateOpacity(value)) = maybe_propagator {
+            if !value.is_nan() && *value != 1.0 {
+                accumulated_opacity *= *value;
+            }
+        }
+
+        // Update interaction cutoff.
+        // - Groups may be traversed multiple times, the last traversal includes all ancestor opacities.
+        match maybe_group.and_then(|g| g.interaction_cutoff) {
+            Some(cutoff) if accumulated_opacity < cutoff => {
+                faded.0.insert(entity);
+            }
+            _ => {
+                faded.0.remove(&entity);
+            }
         }
     }
 
@@ -217,6 +282,7 @@ fn recursively_propagate_opacity_value(
             accumulated_opacity,
             seen_propagators,
             insertion_first_traversal_vals,
+            faded,
             c,
             writer,
             children_query,
@@ -230,18 +296,20 @@ fn recursively_propagate_opacity_value(
 
 /// Applies all opacity modifiers throughout the hierarchy, and caches the original opacity values for
 /// restoration after rendering.
-fn propagate_opacity_values(
+pub(crate) fn propagate_opacity_values(
     // Optimization to reduce reduntant traversals by 50%.
     mut seen_propagators: Local<EntityHashSet>,
     mut insertion_first_traversal_vals: Local<EntityHashMap<RestorableOpacity>>,
+    mut faded: ResMut<FadedOpacityGroups>,
     mut c: Commands,
     mut writer: TextUiWriter,
-    propagators: Query<Entity, With<PropagateOpacity>>,
+    propagators: Query<Entity, Or<(With<PropagateOpacity>, With<OpacityGroup>)>>,
     children: Query<&Children>,
     mut nodes: Query<
         (
             // Include this in case we need to merge modifiers.
             Option<&PropagateOpacity>,
+            Option<&OpacityGroup>,
             Option<&mut RestorableOpacity>,
             Option<&mut ImageNode>,
             Has<Text>,
@@ -255,6 +323,7 @@ fn propagate_opacity_values(
 {
     seen_propagators.clear();
     insertion_first_traversal_vals.clear();
+    faded.0.clear();
 
     for propagator in propagators.iter() {
         // Only do this in the base level so ancestor opacities properly reach all children.
@@ -267,6 +336,7 @@ fn propagate_opacity_values(
             1.0,
             &mut *seen_propagators,
             &mut *insertion_first_traversal_vals,
+            &mut faded,
             &mut c,
             &mut writer,
             &children,
@@ -282,7 +352,7 @@ fn propagate_opacity_values(
 ///
 /// Note: For simpliciy we filter for `Changed<RestorableOpacity>`, which indicates values need to be fixed
 /// in this system. If `RestorableOpacity` doesn't get modified, then it will simply be an inert component.
-fn restore_opacity(
+pub(crate) fn restore_opacity(
     mut nodes: Query<
         (
             Entity,
@@ -340,7 +410,8 @@ fn restore_opacity(
 
 /// Component for setting an opacity multiplier on a hierarchy of nodes.
 ///
-/// The propagated value will stack with other opacity multipliers in the same hierarchy.
+/// The entity becomes the root of an opacity group. The propagated value is multiplied with the opacities of
+/// ancestor groups (unless the entity has an [`OpacityGroup`] with `isolate` set).
 ///
 /// ## Limitations
 ///
@@ -365,13 +436,90 @@ pub struct PropagateOpacity(pub f32);
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Records [`OpacityGroup`] roots whose interactions are disabled.
+#[derive(Resource, Default, Debug)]
+pub(crate) struct FadedOpacityGroups(EntityHashSet);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction that configures the root of an opacity group.
+///
+/// An opacity group is a node hierarchy that fades together. The group's opacity is set with
+/// [`PropagateOpacity`] on the same entity (defaults to `1.0`), and is multiplied with the opacities of ancestor
+/// groups. Nested groups multiply their own opacity with the accumulated opacity of the groups above them.
+///
+/// - `isolate`: Ignores the opacities of ancestor groups. Use this to keep part of a fading hierarchy opaque.
+/// - `interaction_cutoff`: Disables interactions for the group while its accumulated opacity is below this value.
+///   Hovered entities in the group are filtered from `bevy_picking`, and their [`Interaction`] is reset to
+///   [`Interaction::None`].
+///
+/// Example:
+/**
+```rust
+#scenes
+"popup"
+    OpacityGroup{interaction_cutoff:0.1}
+    Animated<PropagateOpacity>{idle:0 hover:1}
+
+    "badge"
+        // Not faded with the popup.
+        OpacityGroup{isolate:true}
+```
+*/
+#[derive(Component, Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct OpacityGroup
+{
+    /// Ignores the opacities of ancestor groups.
+    #[reflect(default)]
+    pub isolate: bool,
+    /// Interactions are disabled while the group's opacity is below this value.
+    #[reflect(default)]
+    pub interaction_cutoff: Option<f32>,
+}
+
+impl Instruction for OpacityGroup
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.insert(self);
+        });
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.remove::<Self>();
+        });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 pub(crate) struct UiOpacityPlugin;
 
 impl Plugin for UiOpacityPlugin
 {
     fn build(&self, app: &mut App)
     {
-        app.register_animatable::<PropagateOpacity>()
+        app.init_resource::<FadedOpacityGroups>()
+            .register_animatable::<PropagateOpacity>()
+            .register_instruction_type::<OpacityGroup>()
+            .add_systems(
+                PreUpdate,
+                (
+                    reset_faded_interactions.after(UiSystem::Focus),
+                    filter_faded_hovers
+                        .after(update_focus)
+                        .before(pointer_events)
+                        .in_set(PickSet::Focus),
+                ),
+            )
             .add_systems(
                 PostUpdate,
                 propagate_opacity_values
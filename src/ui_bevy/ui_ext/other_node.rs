@@ -0,0 +1,222 @@
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+use smallvec::SmallVec;
+
+use crate::prelude::*;
+use crate::sickle_ext::ControlMap;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn find_control_map_entity(entity: Entity, parents: &Query<&Parent>, maps: &Query<&ControlMap>) -> Option<Entity>
+{
+    let mut current = entity;
+    loop {
+        if maps.contains(current) {
+            return Some(current);
+        }
+        current = **parents.get(current).ok()?;
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn update_other_node_dims(
+    mut targets: Local<SmallVec<[Entity; 8]>>,
+    dims: Query<(Entity, &OtherNodeDims)>,
+    parents: Query<&Parent>,
+    children: Query<&Children>,
+    maps: Query<&ControlMap>,
+    computed: Query<&ComputedNode>,
+    mut nodes: Query<&mut Node>,
+)
+{
+    for (entity, other_dims) in dims.iter() {
+        let mut compute = |other: &Option<OtherNode>| -> Option<Val> {
+            let other = other.as_ref()?;
+            targets.clear();
+            other.collect_targets(entity, &parents, &children, &maps, &mut targets);
+            if targets.is_empty() {
+                tracing::debug!("failed resolving OtherNode path {:?} for {:?}; no nodes found", other.0, entity);
+                return None;
+            }
+            let size = targets
+                .iter()
+                .filter_map(|target| computed.get(*target).ok())
+                .map(|c| other.1.compute(c.size() * c.inverse_scale_factor()))
+                .fold(0.0f32, f32::max);
+            Some(Val::Px(size))
+        };
+
+        let width = compute(&other_dims.width);
+        let height = compute(&other_dims.height);
+        let min_width = compute(&other_dims.min_width);
+        let min_height = compute(&other_dims.min_height);
+        let max_width = compute(&other_dims.max_width);
+        let max_height = compute(&other_dims.max_height);
+
+        let Ok(mut node) = nodes.get_mut(entity) else { continue };
+
+        // Only write changed values to avoid spurious layout recomputes.
+        let mut set = |field: fn(&mut Node) -> &mut Val, val: Option<Val>| {
+            let Some(val) = val else { return };
+            if *field(node.bypass_change_detection()) != val {
+                *field(&mut node) = val;
+            }
+        };
+        set(|n| &mut n.width, width);
+        set(|n| &mut n.height, height);
+        set(|n| &mut n.min_width, min_width);
+        set(|n| &mut n.min_height, min_height);
+        set(|n| &mut n.max_width, max_width);
+        set(|n| &mut n.max_height, max_height);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Part of another node's computed size, used by [`OtherNode`].
+#[derive(Reflect, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum OtherNodeSize
+{
+    /// Percentage of the other node's computed width.
+    WidthPct(f32),
+    /// Percentage of the other node's computed height.
+    HeightPct(f32),
+}
+
+impl OtherNodeSize
+{
+    /// Computes the value in logical pixels from another node's size in logical pixels.
+    pub fn compute(&self, size: Vec2) -> f32
+    {
+        match *self {
+            Self::WidthPct(pct) => size.x * pct / 100.,
+            Self::HeightPct(pct) => size.y * pct / 100.,
+        }
+    }
+}
+
+impl Default for OtherNodeSize
+{
+    fn default() -> Self
+    {
+        Self::WidthPct(100.)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A size computed from other nodes, e.g. `OtherNode("label", WidthPct(50))`.
+///
+/// The path can be:
+/// - `".."`: The node's parent.
+/// - `"*"`: All of the node's siblings. The largest computed value is used, which is useful for matching the size
+///   of the widest/tallest sibling. Note that if siblings reference each other, then they can grow but never shrink.
+/// - Any other string: A [`ControlMember`] label in the node's control group. The node must be in the control
+///   group of a [`ControlRoot`] (or be the root).
+#[derive(Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct OtherNode(pub String, pub OtherNodeSize);
+
+impl OtherNode
+{
+    fn collect_targets(
+        &self,
+        entity: Entity,
+        parents: &Query<&Parent>,
+        children: &Query<&Children>,
+        maps: &Query<&ControlMap>,
+        targets: &mut SmallVec<[Entity; 8]>,
+    )
+    {
+        match self.0.as_str() {
+            ".." => {
+                if let Ok(parent) = parents.get(entity) {
+                    targets.push(**parent);
+                }
+            }
+            "*" => {
+                let Ok(parent) = parents.get(entity) else { return };
+                let Ok(siblings) = children.get(**parent) else { return };
+                targets.extend(siblings.iter().copied().filter(|e| *e != entity));
+            }
+            label => {
+                let Some(map_entity) = find_control_map_entity(entity, parents, maps) else { return };
+                let Ok(map) = maps.get(map_entity) else { return };
+                if let Some(target) = map.get_entity(label) {
+                    targets.push(target);
+                }
+            }
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction loadable that sets a node's [`Dims`] fields from other nodes' computed sizes.
+///
+/// Fields set to `None` are not modified. Values are recomputed after layout every frame, so a change in another
+/// node's size will take effect on the next layout pass.
+///
+/// This will override the corresponding fields set by [`FlexNode`], [`Width`], etc.
+///
+/// Example (COB):
+/// ```rust
+/// "row"
+///     FlexNode{flex_direction:Column}
+///     "a"
+///         OtherNodeDims{width:OtherNode("*", WidthPct(100))}
+///     "b"
+///         OtherNodeDims{width:OtherNode("*", WidthPct(100))}
+/// ```
+#[derive(Component, Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct OtherNodeDims
+{
+    /// See [`Dims::width`].
+    #[reflect(default)]
+    pub width: Option<OtherNode>,
+    /// See [`Dims::height`].
+    #[reflect(default)]
+    pub height: Option<OtherNode>,
+    /// See [`Dims::min_width`].
+    #[reflect(default)]
+    pub min_width: Option<OtherNode>,
+    /// See [`Dims::min_height`].
+    #[reflect(default)]
+    pub min_height: Option<OtherNode>,
+    /// See [`Dims::max_width`].
+    #[reflect(default)]
+    pub max_width: Option<OtherNode>,
+    /// See [`Dims::max_height`].
+    #[reflect(default)]
+    pub max_height: Option<OtherNode>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct OtherNodePlugin;
+
+impl Plugin for OtherNodePlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.register_component_type::<OtherNodeDims>()
+            .add_systems(PostUpdate, update_other_node_dims.in_set(UiSystem::PostLayout));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
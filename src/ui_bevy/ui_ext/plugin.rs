@@ -18,8 +18,34 @@ impl Plugin for StyleExtPlugin
             // IMPORTANT: These plugins must be added after StyleWrappersPlugin so the loadables defined here will
             // overwrite style fields correctly.
             .add_plugins(UiStyleFieldWrappersPlugin)
+            .add_plugins(GridAreasPlugin)
+            .add_plugins(ContentSizingPlugin)
             .add_plugins(UiTextExtPlugin)
-            .add_plugins(ImageNodeExtPlugin);
+            .add_plugins(SlicedTextPlugin)
+            .add_plugins(TextFitPlugin)
+            .add_plugins(TextLinksPlugin)
+            .add_plugins(EmojiPlugin)
+            .add_plugins(OtherNodePlugin)
+            .add_plugins(ImageNodeExtPlugin)
+            .add_plugins(AnimatedSpritePlugin)
+            .add_plugins(ImageFilterPlugin)
+            .add_plugins(BackdropBlurPlugin)
+            .add_plugins(ImageUvPlugin)
+            .add_plugins(UiShaderMaterialPlugin)
+            .add_plugins(RenderTargetScenePlugin)
+            .add_plugins(LayoutDirectionPlugin)
+            .add_plugins(SafeAreaPlugin)
+            .add_plugins(UiScalePlugin)
+            .add_plugins(InlineIconsPlugin)
+            .add_plugins(AnchorToWorldPlugin)
+            .add_plugins(AnimateLayoutPlugin)
+            .add_plugins(SpawnTransitionsPlugin)
+            .add_plugins(UiRootCameraPlugin)
+            .add_plugins(ViewportAnchorPlugin)
+            .add_plugins(AccessibilityExtPlugin);
+
+        #[cfg(feature = "svg")]
+        app.add_plugins(SvgPlugin);
     }
 }
 
@@ -0,0 +1,493 @@
+use bevy::picking::pointer::{Location, PointerAction, PointerId, PointerInput, PressDirection};
+use bevy::picking::PickSet;
+use bevy::prelude::*;
+use bevy::render::camera::{NormalizedRenderTarget, RenderTarget};
+use bevy::render::primitives::Aabb;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat, TextureUsages};
+use smallvec::SmallVec;
+use uuid::Uuid;
+
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn make_target_image(size: UVec2) -> Image
+{
+    let mut image = Image::new_fill(
+        target_extent(size),
+        TextureDimension::D2,
+        &[0, 0, 0, 0],
+        TextureFormat::Bgra8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    image.texture_descriptor.usage =
+        TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST | TextureUsages::RENDER_ATTACHMENT;
+    image
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn target_extent(size: UVec2) -> Extent3d
+{
+    let size = size.max(UVec2::ONE);
+    Extent3d { width: size.x, height: size.y, depth_or_array_layers: 1 }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Inserts the target texture to the display entity.
+fn insert_target_display(
+    c: &mut Commands,
+    materials: &mut Assets<StandardMaterial>,
+    entity: Entity,
+    image: Handle<Image>,
+    is_node: bool,
+    is_mesh: bool,
+)
+{
+    if is_node {
+        c.entity(entity).try_insert(ImageNode::new(image));
+    } else if is_mesh {
+        let material = materials.add(StandardMaterial {
+            base_color_texture: Some(image),
+            alpha_mode: AlphaMode::Blend,
+            unlit: true,
+            ..default()
+        });
+        c.entity(entity).try_insert(MeshMaterial3d(material));
+    } else {
+        tracing::warn!("failed displaying RenderTargetScene on {entity:?}; the entity is not a UI node or a mesh");
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Spawns and updates render targets when [`RenderTargetScene`] changes.
+fn update_render_target_scenes(
+    mut c: Commands,
+    mut s: SceneBuilder,
+    mut images: ResMut<Assets<Image>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut cameras: Query<&mut Camera>,
+    mut nodes: Query<&mut Node>,
+    mut targets: Query<
+        (
+            Entity,
+            &RenderTargetScene,
+            Option<&mut RenderTargetSceneTexture>,
+            Has<Node>,
+            Has<Mesh3d>,
+        ),
+        Changed<RenderTargetScene>,
+    >,
+)
+{
+    for (entity, config, maybe_texture, is_node, is_mesh) in targets.iter_mut() {
+        // Update the existing target in-place if the scene didn't change.
+        if let Some(mut texture) = maybe_texture {
+            if texture.file == config.file && texture.scene == config.scene {
+                if let Some(image) = images.get_mut(&texture.image) {
+                    image.resize(target_extent(config.size));
+                }
+                if let Ok(mut camera) = cameras.get_mut(texture.camera) {
+                    camera.clear_color = ClearColorConfig::Custom(config.clear_color);
+                }
+                if let Ok(mut node) = nodes.get_mut(texture.root) {
+                    node.width = Val::Px(config.size.x as f32);
+                    node.height = Val::Px(config.size.y as f32);
+                }
+                match (config.forward_interactions, texture.pointer) {
+                    (true, None) => {
+                        texture.pointer = Some(c.spawn(virtual_pointer_id(entity)).id());
+                    }
+                    (false, Some(pointer)) => {
+                        c.entity(pointer).despawn();
+                        texture.pointer = None;
+                    }
+                    _ => (),
+                }
+                continue;
+            }
+        }
+
+        // Spawn a new target. The old target is cleaned up when its texture component is replaced.
+        let image = images.add(make_target_image(config.size));
+        let camera = c
+            .spawn((
+                Camera2d,
+                Camera {
+                    target: RenderTarget::Image(image.clone()),
+                    clear_color: ClearColorConfig::Custom(config.clear_color),
+                    // Render before cameras that display the texture.
+                    order: -1,
+                    ..default()
+                },
+            ))
+            .id();
+
+        // The scene is spawned into a root node with the target size so percentage-based layouts work.
+        let root = c
+            .spawn((
+                Node {
+                    width: Val::Px(config.size.x as f32),
+                    height: Val::Px(config.size.y as f32),
+                    ..default()
+                },
+                TargetCamera(camera),
+            ))
+            .id();
+        c.entity(root)
+            .spawn_scene(SceneRef::new(&config.file, &config.scene), &mut s);
+
+        let pointer = config
+            .forward_interactions
+            .then(|| c.spawn(virtual_pointer_id(entity)).id());
+
+        insert_target_display(&mut c, &mut materials, entity, image.clone(), is_node, is_mesh);
+        c.entity(entity).try_insert(RenderTargetSceneTexture {
+            file: config.file.clone(),
+            scene: config.scene.clone(),
+            image,
+            camera,
+            root,
+            pointer,
+            pressed: SmallVec::default(),
+            last_position: None,
+        });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn cleanup_render_target_scene(
+    trigger: Trigger<OnReplace, RenderTargetSceneTexture>,
+    mut c: Commands,
+    textures: Query<&RenderTargetSceneTexture>,
+)
+{
+    let Ok(texture) = textures.get(trigger.entity()) else { return };
+    c.entity(texture.root).try_despawn_recursive();
+    c.entity(texture.camera).try_despawn_recursive();
+    if let Some(pointer) = texture.pointer {
+        c.entity(pointer).try_despawn_recursive();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Each display entity gets its own pointer so interactions with separate textures don't interfere.
+fn virtual_pointer_id(entity: Entity) -> PointerId
+{
+    PointerId::Custom(Uuid::from_u128(entity.to_bits() as u128))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Converts a pointer event on a display entity to a normalized position on the target texture.
+///
+/// Returns `None` if the position can't be computed or is outside the texture.
+fn display_uv(
+    location: &Location,
+    hit_position: Option<Vec3>,
+    transform: &GlobalTransform,
+    maybe_node: Option<&ComputedNode>,
+    maybe_aabb: Option<&Aabb>,
+) -> Option<Vec2>
+{
+    let uv = if let Some(node) = maybe_node {
+        // UI nodes: the pointer position is in logical pixels, the node is in physical pixels.
+        let size = node.size();
+        let top_left = transform.translation().truncate() - size / 2.;
+        let position = location.position / node.inverse_scale_factor();
+        (position - top_left) / size
+    } else {
+        // Meshes: assume a quad in the local XY plane facing +Z, such as a `Rectangle`.
+        let aabb = maybe_aabb?;
+        let local = transform.affine().inverse().transform_point3(hit_position?);
+        let min = Vec3::from(aabb.min());
+        let size = Vec3::from(aabb.half_extents * 2.).truncate();
+        let uv = (local - min).truncate() / size;
+        Vec2::new(uv.x, 1. - uv.y)
+    };
+
+    (uv.is_finite() && uv.cmpge(Vec2::ZERO).all() && uv.cmple(Vec2::ONE).all()).then_some(uv)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Forwards picking events on display entities to their virtual pointers.
+fn forward_render_target_interactions(
+    mut moves: EventReader<Pointer<Move>>,
+    mut downs: EventReader<Pointer<Down>>,
+    mut ups: EventReader<Pointer<Up>>,
+    mut outs: EventReader<Pointer<Out>>,
+    mut inputs: EventWriter<PointerInput>,
+    mut displays: Query<(
+        &RenderTargetScene,
+        &mut RenderTargetSceneTexture,
+        &GlobalTransform,
+        Option<&ComputedNode>,
+        Option<&Aabb>,
+    )>,
+)
+{
+    let mut forward =
+        |event_target: Entity, location: &Location, hit: Option<Vec3>, action: Option<PointerAction>| {
+            let Ok((config, mut texture, transform, maybe_node, maybe_aabb)) = displays.get_mut(event_target)
+            else {
+                return;
+            };
+            if texture.pointer.is_none() {
+                return;
+            }
+            let Some(uv) = display_uv(location, hit, transform, maybe_node, maybe_aabb) else { return };
+            let position = uv * config.size.as_vec2();
+            let pointer_id = virtual_pointer_id(event_target);
+
+            let location = Location {
+                target: NormalizedRenderTarget::Image(texture.image.clone()),
+                position,
+            };
+            let delta = position - texture.last_position.unwrap_or(position);
+            texture.last_position = Some(position);
+            inputs.send(PointerInput::new(
+                pointer_id,
+                location.clone(),
+                PointerAction::Moved { delta },
+            ));
+
+            let Some(action) = action else { return };
+            if let PointerAction::Pressed { direction, button } = action {
+                match direction {
+                    PressDirection::Down if !texture.pressed.contains(&button) => texture.pressed.push(button),
+                    PressDirection::Up => texture.pressed.retain(|b| *b != button),
+                    _ => (),
+                }
+            }
+            inputs.send(PointerInput::new(pointer_id, location, action));
+        };
+
+    for event in moves.read() {
+        forward(event.target, &event.pointer_location, event.event.hit.position, None);
+    }
+    for event in downs.read() {
+        let action = PointerAction::Pressed { direction: PressDirection::Down, button: event.event.button };
+        forward(
+            event.target,
+            &event.pointer_location,
+            event.event.hit.position,
+            Some(action),
+        );
+    }
+    for event in ups.read() {
+        let action = PointerAction::Pressed { direction: PressDirection::Up, button: event.event.button };
+        forward(
+            event.target,
+            &event.pointer_location,
+            event.event.hit.position,
+            Some(action),
+        );
+    }
+
+    // When the pointer leaves a display, release held buttons and move the virtual pointer off the texture.
+    for event in outs.read() {
+        let Ok((_, mut texture, ..)) = displays.get_mut(event.target) else { continue };
+        if texture.pointer.is_none() || texture.last_position.is_none() {
+            continue;
+        }
+        let pointer_id = virtual_pointer_id(event.target);
+        let location = Location {
+            target: NormalizedRenderTarget::Image(texture.image.clone()),
+            position: Vec2::splat(-1.),
+        };
+        for button in texture.pressed.drain(..) {
+            inputs.send(PointerInput::new(
+                pointer_id,
+                location.clone(),
+                PointerAction::Pressed { direction: PressDirection::Up, button },
+            ));
+        }
+        inputs.send(PointerInput::new(
+            pointer_id,
+            location,
+            PointerAction::Moved { delta: Vec2::ZERO },
+        ));
+        texture.last_position = None;
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Component inserted on entities with [`RenderTargetScene`].
+///
+/// Holds the texture the scene is rendered to, along with the camera and root node of the scene.
+#[derive(Component, Debug)]
+pub struct RenderTargetSceneTexture
+{
+    file: String,
+    scene: String,
+    image: Handle<Image>,
+    camera: Entity,
+    root: Entity,
+    pointer: Option<Entity>,
+    pressed: SmallVec<[PointerButton; 2]>,
+    last_position: Option<Vec2>,
+}
+
+impl RenderTargetSceneTexture
+{
+    /// Gets the texture the scene is rendered to.
+    pub fn image(&self) -> &Handle<Image>
+    {
+        &self.image
+    }
+
+    /// Gets the camera that renders the scene.
+    pub fn camera(&self) -> Entity
+    {
+        self.camera
+    }
+
+    /// Gets the root node of the scene.
+    ///
+    /// The scene is spawned as a child of this node.
+    pub fn root(&self) -> Entity
+    {
+        self.root
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction that renders a UI scene to a texture and displays it on the entity.
+///
+/// The scene is spawned as a new UI root with its own camera. If the entity is a UI node the texture is displayed
+/// with an [`ImageNode`], and if the entity has a [`Mesh3d`] the texture is displayed with an unlit
+/// [`StandardMaterial`]. Use [`RenderTargetSceneTexture`] to access the texture, e.g. to display it somewhere
+/// else.
+///
+/// If [`Self::forward_interactions`] is set, `bevy_picking` pointer events on the entity are forwarded to a
+/// virtual pointer in the scene, so widgets in the scene can be hovered and pressed. Meshes must be quads in their
+/// local XY plane (such as `Rectangle` meshes) and need a picking backend (e.g. `MeshPickingPlugin`). Forwarded
+/// interactions are only received by scene entities that use picking events, such as [`PickingEvents`] or
+/// widgets with the `picking_interactions` feature.
+///
+/// Example:
+/**
+```rust
+#scenes
+"minimap"
+    FlexNode{width:200px height:200px}
+    RenderTargetScene{file:"minimap.cob" scene:"map" size:(400, 400)}
+```
+*/
+#[derive(Component, Reflect, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct RenderTargetScene
+{
+    /// File of the scene.
+    pub file: String,
+    /// Scene rendered to the texture.
+    pub scene: String,
+    /// Size of the texture in pixels.
+    ///
+    /// The scene's root node has this size.
+    pub size: UVec2,
+    /// Color the texture is cleared to before rendering the scene.
+    ///
+    /// Defaults to transparent.
+    #[reflect(default = "RenderTargetScene::default_clear_color")]
+    pub clear_color: Color,
+    /// Whether pointer events on the entity should be forwarded to the scene.
+    ///
+    /// Defaults to `true`.
+    #[reflect(default = "RenderTargetScene::default_forward_interactions")]
+    pub forward_interactions: bool,
+}
+
+impl RenderTargetScene
+{
+    fn default_clear_color() -> Color
+    {
+        Color::NONE
+    }
+
+    fn default_forward_interactions() -> bool
+    {
+        true
+    }
+}
+
+impl Default for RenderTargetScene
+{
+    fn default() -> Self
+    {
+        Self {
+            file: String::default(),
+            scene: String::default(),
+            size: UVec2::default(),
+            clear_color: Self::default_clear_color(),
+            forward_interactions: Self::default_forward_interactions(),
+        }
+    }
+}
+
+impl Instruction for RenderTargetScene
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        validate_instruction_scene_ref::<Self>(world, SceneRef::new(&self.file, &self.scene));
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.insert(self);
+        });
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let Some(image) = world
+            .get::<RenderTargetSceneTexture>(entity)
+            .map(|t| t.image.clone())
+        else {
+            let _ = world.get_entity_mut(entity).map(|mut e| {
+                e.remove::<Self>();
+            });
+            return;
+        };
+
+        // Only remove the display if it still shows the texture.
+        let shows_material = world
+            .get::<MeshMaterial3d<StandardMaterial>>(entity)
+            .and_then(|m| world.resource::<Assets<StandardMaterial>>().get(&m.0))
+            .is_some_and(|m| m.base_color_texture.as_ref() == Some(&image));
+        let Ok(mut e) = world.get_entity_mut(entity) else { return };
+        e.remove::<(Self, RenderTargetSceneTexture)>();
+        if e.get::<ImageNode>().is_some_and(|i| i.image == image) {
+            e.remove::<ImageNode>();
+        }
+        if shows_material {
+            e.remove::<MeshMaterial3d<StandardMaterial>>();
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct RenderTargetScenePlugin;
+
+impl Plugin for RenderTargetScenePlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.register_instruction_type::<RenderTargetScene>()
+            .add_observer(cleanup_render_target_scene)
+            .add_systems(Update, update_render_target_scenes)
+            .add_systems(PreUpdate, forward_render_target_interactions.after(PickSet::Focus));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,226 @@
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+
+use crate::prelude::*;
+use crate::sickle::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn add_inset(val: &mut Val, inset: f32)
+{
+    *val = match *val {
+        Val::Px(px) => Val::Px(px + inset),
+        _ => Val::Px(inset),
+    };
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Adds safe-area insets to nodes before layout.
+///
+/// Nodes are restored in `restore_safe_area_nodes`, so user code and instructions always see the authored values.
+pub(crate) fn apply_safe_area_nodes(
+    insets: Res<SafeAreaInsets>,
+    mut nodes: Query<(Mut<Node>, Ref<SafeAreaPadding>, &mut SafeAreaPaddingState)>,
+)
+{
+    let insets_changed = insets.is_changed();
+
+    for (mut node, safe_area, mut state) in nodes.iter_mut() {
+        // Avoid triggering layout every tick when nothing changed. Layout caches the previously-adjusted node.
+        let refresh = insets_changed || safe_area.is_changed() || node.is_changed();
+        let node_mut = node.bypass_change_detection();
+        let rect = match safe_area.margin {
+            false => &mut node_mut.padding,
+            true => &mut node_mut.margin,
+        };
+        state.authored = Some(*rect);
+        safe_area.apply_insets(&insets, rect);
+        if refresh {
+            node.set_changed();
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Restores nodes that were adjusted for layout.
+pub(crate) fn restore_safe_area_nodes(mut nodes: Query<(&mut Node, &SafeAreaPadding, &mut SafeAreaPaddingState)>)
+{
+    for (mut node, safe_area, mut state) in nodes.iter_mut() {
+        let Some(authored) = state.authored.take() else { continue };
+        let node = node.bypass_change_detection();
+        match safe_area.margin {
+            false => node.padding = authored,
+            true => node.margin = authored,
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Component, Default, Debug)]
+pub(crate) struct SafeAreaPaddingState
+{
+    authored: Option<UiRect>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource with the platform's safe-area insets in logical pixels.
+///
+/// These are the edges of the window that are obscured by hardware or system UI, such as notches, camera cutouts,
+/// and home indicators.
+///
+/// Bevy does not currently expose safe-area insets, so this resource must be updated by the app (e.g. from
+/// platform APIs on startup and when the device orientation changes). Nodes with [`SafeAreaPadding`] will update
+/// automatically when it changes.
+#[derive(Resource, Reflect, Default, Debug, Copy, Clone, PartialEq)]
+pub struct SafeAreaInsets
+{
+    pub top: f32,
+    pub bottom: f32,
+    pub left: f32,
+    pub right: f32,
+}
+
+impl SafeAreaInsets
+{
+    /// Gets the inset on an edge.
+    pub fn get(&self, edge: SafeAreaEdge) -> f32
+    {
+        match edge {
+            SafeAreaEdge::Top => self.top,
+            SafeAreaEdge::Bottom => self.bottom,
+            SafeAreaEdge::Left => self.left,
+            SafeAreaEdge::Right => self.right,
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// An edge of the window for [`SafeAreaPadding`].
+#[derive(Reflect, Default, Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum SafeAreaEdge
+{
+    #[default]
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction that adds the current [`SafeAreaInsets`] to a node's padding (or margin).
+///
+/// Insets are added to the authored padding if it uses [`Val::Px`], and replace it otherwise. Like
+/// [`DirectionAware`], the insets are only applied for layout, so the node's components always contain the
+/// authored values.
+///
+/// Insets are applied to physical window edges. This is usually placed on a full-screen root node.
+///
+/// Example:
+/**
+```rust
+#scenes
+"hud"
+    FlexNode{width:100vw height:100vh}
+    // Keep the top and sides clear of notches.
+    SafeAreaPadding{edges:[Top Left Right]}
+```
+*/
+#[derive(Component, Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct SafeAreaPadding
+{
+    /// Edges to apply insets to. If empty, all edges are used.
+    ///
+    /// Defaults to empty.
+    #[reflect(default)]
+    pub edges: Vec<SafeAreaEdge>,
+    /// Apply insets to [`Node::margin`] instead of [`Node::padding`].
+    ///
+    /// Defaults to `false`.
+    #[reflect(default)]
+    pub margin: bool,
+}
+
+impl SafeAreaPadding
+{
+    /// Adds insets to the selected edges of `rect`.
+    pub fn apply_insets(&self, insets: &SafeAreaInsets, rect: &mut UiRect)
+    {
+        for (edge, val) in [
+            (SafeAreaEdge::Top, &mut rect.top),
+            (SafeAreaEdge::Bottom, &mut rect.bottom),
+            (SafeAreaEdge::Left, &mut rect.left),
+            (SafeAreaEdge::Right, &mut rect.right),
+        ] {
+            if !self.edges.is_empty() && !self.edges.contains(&edge) {
+                continue;
+            }
+            add_inset(val, insets.get(edge));
+        }
+    }
+}
+
+impl Instruction for SafeAreaPadding
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.insert((self, SafeAreaPaddingState::default()));
+        });
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.remove::<(Self, SafeAreaPaddingState)>();
+        });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct SafeAreaPlugin;
+
+impl Plugin for SafeAreaPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.register_type::<SafeAreaInsets>()
+            .register_type::<SafeAreaEdge>()
+            .init_resource::<SafeAreaInsets>()
+            .register_instruction_type::<SafeAreaPadding>()
+            .add_systems(
+                PostUpdate,
+                apply_safe_area_nodes
+                    .after(ControlSet)
+                    .after(DynamicStylePostUpdate)
+                    // Insets apply to physical edges, so they are added after mirroring.
+                    .after(mirror_direction_aware_nodes)
+                    .before(UiSystem::Prepare),
+            )
+            // After rendering. Loadables applied while processing files must not be overwritten.
+            .add_systems(
+                First,
+                restore_safe_area_nodes
+                    .before(restore_direction_aware_nodes)
+                    .before(FileProcessingSet),
+            );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,270 @@
+use bevy::prelude::*;
+use bevy::text::LineBreak;
+use bevy_cobweb::prelude::*;
+
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Finds the end of the next text slice starting at byte `start`.
+///
+/// Slices end after line breaks so the sliced text has the same layout as the full text. Lines longer than
+/// `max_chars` are not split.
+fn next_slice_end(text: &str, start: usize, max_chars: usize) -> usize
+{
+    let mut end = start;
+    let mut num_chars = 0;
+    for line in text[start..].split_inclusive('\n') {
+        let line_chars = line.chars().count();
+        if num_chars > 0 && num_chars + line_chars > max_chars {
+            break;
+        }
+        end += line.len();
+        num_chars += line_chars;
+    }
+    end
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Makes a text node for a [`SlicedText`].
+fn sliced_text_bundle(
+    world: &World,
+    entity: Entity,
+    config: &SlicedText,
+    text: &str,
+    linebreak: LineBreak,
+) -> impl Bundle
+{
+    let font = config
+        .font
+        .as_ref()
+        .map(|f| world.resource::<FontMap>().get(f))
+        .unwrap_or_default();
+    let color = world
+        .get::<TextLineColor>(entity)
+        .map(|c| c.0)
+        .unwrap_or(Color::WHITE);
+
+    (
+        Text(text.into()),
+        TextFont { font, font_size: config.size, ..default() },
+        TextColor(color),
+        TextLayout { justify: config.justify, linebreak },
+    )
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Spawns one slice per frame for each pending [`SlicedText`].
+fn spawn_text_slices(world: &mut World, mut pending: Local<Vec<Entity>>)
+{
+    pending.extend(
+        world
+            .query::<(Entity, &SlicedTextProgress)>()
+            .iter(world)
+            .filter(|(_, p)| !p.done)
+            .map(|(e, _)| e),
+    );
+
+    for entity in pending.drain(..) {
+        let Some(config) = world.get::<SlicedText>(entity) else { continue };
+        let Some(progress) = world.get::<SlicedTextProgress>(entity) else { continue };
+
+        // Shape the next slice.
+        // - Trailing newlines are replaced by the node break. Empty lines need a space to keep their height.
+        let start = progress.next;
+        let end = next_slice_end(&config.text, start, config.chars_per_frame.max(1));
+        let slice = config.text[start..end]
+            .strip_suffix('\n')
+            .unwrap_or(&config.text[start..end]);
+        let slice = if slice.is_empty() { " " } else { slice };
+        let done = end >= config.text.len();
+        let bundle = sliced_text_bundle(world, entity, config, slice, LineBreak::WordBoundary);
+        let slice_entity = world.spawn(bundle).id();
+
+        let mut progress = world.get_mut::<SlicedTextProgress>(entity).unwrap();
+        let index = progress.slices.len();
+        progress.slices.push(slice_entity);
+        progress.next = end;
+        progress.done = done;
+        let placeholder = progress.placeholder;
+
+        // Slices are inserted before the placeholder.
+        world
+            .entity_mut(entity)
+            .insert_children(index, &[slice_entity]);
+
+        if !done {
+            continue;
+        }
+        if let Some(placeholder) = placeholder {
+            world.entity_mut(placeholder).despawn_recursive();
+            world
+                .get_mut::<SlicedTextProgress>(entity)
+                .unwrap()
+                .placeholder = None;
+        }
+        world.react(|rc| rc.entity_event(entity, SlicedTextReady));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Tracks text slices spawned for a [`SlicedText`].
+#[derive(Component, Debug)]
+struct SlicedTextProgress
+{
+    /// Byte offset of the next slice.
+    next: usize,
+    done: bool,
+    slices: Vec<Entity>,
+    placeholder: Option<Entity>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reactive entity event sent to a [`SlicedText`] entity when all its text has been spawned.
+pub struct SlicedTextReady;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction for displaying large texts (e.g. credits or changelogs) without stalling the frame when they
+/// spawn.
+///
+/// The text is split into slices at line breaks, and one slice of up to [`Self::chars_per_frame`] characters is
+/// spawned per frame as a child text node. Each slice is shaped separately, so shaping is spread across frames.
+/// The optional [`Self::placeholder`] is displayed after the spawned slices until all slices are spawned, then
+/// [`SlicedTextReady`] is sent to the entity.
+///
+/// The entity should be a column (e.g. `FlexNode{flex_direction:Column}`) without other children. Text color can
+/// be set with [`TextLineColor`].
+///
+/// Example (COB):
+/**
+```rust
+#scenes
+"credits"
+    FlexNode{width:100% flex_direction:Column}
+    SlicedText{text:"..." size:18 placeholder:"Loading..."}
+```
+*/
+#[derive(Reflect, Component, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct SlicedText
+{
+    /// The text to display.
+    #[reflect(default)]
+    pub text: String,
+    /// The font handle.
+    ///
+    /// Defaults to the built-in "Fira Sans Medium" font.
+    #[reflect(default = "SlicedText::default_font")]
+    pub font: Option<FontRequest>,
+    /// The desired font size.
+    ///
+    /// Defaults to `25.0`.
+    #[reflect(default = "SlicedText::default_font_size")]
+    pub size: f32,
+    /// The text's [`JustifyText`] behavior.
+    ///
+    /// Defaults to [`JustifyText::Left`].
+    #[reflect(default)]
+    pub justify: JustifyText,
+    /// Maximum number of characters shaped per frame. Lines longer than this are shaped in one frame.
+    ///
+    /// Defaults to `2000`.
+    #[reflect(default = "SlicedText::default_chars_per_frame")]
+    pub chars_per_frame: usize,
+    /// Text displayed while slices are pending.
+    #[reflect(default)]
+    pub placeholder: Option<String>,
+}
+
+impl SlicedText
+{
+    fn default_font() -> Option<FontRequest>
+    {
+        Some(FontRequest::new_static("Fira Sans").medium())
+    }
+
+    fn default_font_size() -> f32
+    {
+        25.
+    }
+
+    fn default_chars_per_frame() -> usize
+    {
+        2000
+    }
+}
+
+impl Default for SlicedText
+{
+    fn default() -> Self
+    {
+        Self {
+            text: String::default(),
+            font: Self::default_font(),
+            size: Self::default_font_size(),
+            justify: JustifyText::Left,
+            chars_per_frame: Self::default_chars_per_frame(),
+            placeholder: None,
+        }
+    }
+}
+
+impl Instruction for SlicedText
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        Self::revert(entity, world);
+
+        let placeholder = self.placeholder.as_ref().map(|placeholder| {
+            let bundle = sliced_text_bundle(world, entity, &self, placeholder, LineBreak::WordBoundary);
+            world.spawn(bundle).set_parent(entity).id()
+        });
+        let done = self.text.is_empty();
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        emut.insert((self, SlicedTextProgress { next: 0, done, slices: vec![], placeholder }));
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        emut.remove::<Self>();
+        let Some(progress) = emut.take::<SlicedTextProgress>() else { return };
+        for slice in progress.slices.into_iter().chain(progress.placeholder) {
+            let Ok(emut) = world.get_entity_mut(slice) else { continue };
+            emut.despawn_recursive();
+        }
+    }
+}
+
+impl StaticAttribute for SlicedText
+{
+    type Value = Self;
+    fn construct(value: Self::Value) -> Self
+    {
+        value
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct SlicedTextPlugin;
+
+impl Plugin for SlicedTextPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.register_static::<SlicedText>()
+            .add_systems(PostUpdate, spawn_text_slices.before(bevy::ui::UiSystem::Prepare));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,549 @@
+use bevy::ecs::system::EntityCommands;
+use bevy::prelude::*;
+use bevy::transform::TransformSystem;
+use bevy::ui::UiSystem;
+use bevy_cobweb::prelude::*;
+use smallvec::SmallVec;
+use smol_str::SmolStr;
+
+use crate::prelude::*;
+use crate::sickle::{Ease, *};
+
+//-------------------------------------------------------------------------------------------------------------------
+
+const ENTER_OPACITY: &str = "OnSpawnAnimate::opacity";
+const ENTER_OFFSET: &str = "OnSpawnAnimate::offset";
+const ENTER_SCALE: &str = "OnSpawnAnimate::scale";
+const EXIT_OPACITY: &str = "OnDespawnAnimate::opacity";
+const EXIT_OFFSET: &str = "OnDespawnAnimate::offset";
+const EXIT_SCALE: &str = "OnDespawnAnimate::scale";
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Applies transition offsets and scales to node transforms.
+///
+/// Runs after layout, which resets `Transform::translation` to the node's layout position whenever they differ.
+fn apply_transition_transforms(mut nodes: Query<(&TransitionTransform, &ComputedNode, &mut Transform)>)
+{
+    for (transition, computed, mut transform) in nodes.iter_mut() {
+        let scale = Vec3::new(transition.scale, transition.scale, transform.scale.z);
+        if transform.scale != scale {
+            transform.scale = scale;
+        }
+        if transition.offset == Vec2::ZERO {
+            continue;
+        }
+        // Offsets are in logical pixels.
+        transform.translation += (transition.offset / computed.inverse_scale_factor()).extend(0.);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Despawns [`OnDespawnAnimate`] nodes when their [`PseudoState::Dying`] animations complete.
+fn despawn_after_exit_transition<T: AnimatedAttribute>(
+    mut c: Commands,
+    event: EntityEvent<AnimationComplete<T>>,
+    nodes: Query<&PseudoStates, With<OnDespawnAnimate>>,
+)
+{
+    let (entity, _) = event.read();
+    let Ok(states) = nodes.get(entity) else { return };
+    if !states.has(&PseudoState::Dying) {
+        return;
+    }
+    if let Some(ec) = c.get_entity(entity) {
+        ec.despawn_recursive();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Starts the exit transition of an entity, or despawns it if it has no [`OnDespawnAnimate`] values.
+fn start_exit_transition(entity: Entity, world: &mut World)
+{
+    let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+    if !emut
+        .get::<OnDespawnAnimate>()
+        .is_some_and(|a| a.to.has_values())
+    {
+        emut.despawn_recursive();
+        return;
+    }
+
+    // Does nothing if already dying.
+    emut.add_pseudo_state(PseudoState::Dying);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Adds an animated attribute for a transition value.
+fn apply_transition_attribute<T: AnimatedAttribute>(
+    entity: Entity,
+    world: &mut World,
+    name: &'static str,
+    state: Option<PseudoState>,
+    enter_ref: Option<T::Value>,
+    value: T::Value,
+    config: AnimationConfig,
+)
+{
+    Animated::<T> {
+        name: Some(SmolStr::new_static(name)),
+        state: state.map(|s| SmallVec::from_elem(s, 1)),
+        enter_ref_override: enter_ref,
+        idle: value,
+        enter_idle_with: Some(config),
+        ..default()
+    }
+    .apply(entity, world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Removes transition attributes by name.
+fn remove_transition_attributes(entity: Entity, world: &mut World, names: &[&str])
+{
+    let Some(mut attrs) = world.get_mut::<NodeAttributes>(entity) else { return };
+    for name in names {
+        while attrs.remove(name).is_some() {}
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn edit_transition_transform(entity: Entity, world: &mut World, callback: impl FnOnce(&mut TransitionTransform))
+{
+    let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+    match emut.get_mut::<TransitionTransform>() {
+        Some(mut transform) => callback(&mut transform),
+        None => {
+            let mut transform = TransitionTransform::default();
+            callback(&mut transform);
+            emut.insert(transform);
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Component with a visual offset and scale applied to a node's transform after layout.
+///
+/// Usually set with the [`TransitionOffset`] and [`TransitionScale`] instructions, which are animated by
+/// [`OnSpawnAnimate`] and [`OnDespawnAnimate`].
+#[derive(Component, Reflect, Debug, Copy, Clone, PartialEq)]
+pub struct TransitionTransform
+{
+    /// Offset from the node's layout position in logical pixels.
+    pub offset: Vec2,
+    /// Scale of the node around its center.
+    pub scale: f32,
+}
+
+impl Default for TransitionTransform
+{
+    fn default() -> Self
+    {
+        Self { offset: Vec2::ZERO, scale: 1. }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Sets [`TransitionTransform::offset`] on a node.
+#[derive(Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct TransitionOffset(pub Vec2);
+
+impl Instruction for TransitionOffset
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        edit_transition_transform(entity, world, |t| t.offset = self.0);
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let Some(mut transform) = world.get_mut::<TransitionTransform>(entity) else { return };
+        transform.offset = Vec2::ZERO;
+    }
+}
+
+impl StaticAttribute for TransitionOffset
+{
+    type Value = Vec2;
+    fn construct(value: Self::Value) -> Self
+    {
+        Self(value)
+    }
+}
+
+impl ResponsiveAttribute for TransitionOffset {}
+impl AnimatedAttribute for TransitionOffset
+{
+    fn get_value(entity: Entity, world: &World) -> Option<Self::Value>
+    {
+        world.get_entity(entity).ok()?;
+        Some(
+            world
+                .get::<TransitionTransform>(entity)
+                .cloned()
+                .unwrap_or_default()
+                .offset,
+        )
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Sets [`TransitionTransform::scale`] on a node.
+#[derive(Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct TransitionScale(pub f32);
+
+impl Instruction for TransitionScale
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        edit_transition_transform(entity, world, |t| t.scale = self.0);
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let Some(mut transform) = world.get_mut::<TransitionTransform>(entity) else { return };
+        transform.scale = 1.;
+    }
+}
+
+impl StaticAttribute for TransitionScale
+{
+    type Value = f32;
+    fn construct(value: Self::Value) -> Self
+    {
+        Self(value)
+    }
+}
+
+impl ResponsiveAttribute for TransitionScale {}
+impl AnimatedAttribute for TransitionScale
+{
+    fn get_value(entity: Entity, world: &World) -> Option<Self::Value>
+    {
+        world.get_entity(entity).ok()?;
+        Some(
+            world
+                .get::<TransitionTransform>(entity)
+                .cloned()
+                .unwrap_or_default()
+                .scale,
+        )
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Values animated by [`OnSpawnAnimate`] and [`OnDespawnAnimate`].
+///
+/// Values that aren't set are not animated.
+#[derive(Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct TransitionValues
+{
+    /// Opacity of the node's hierarchy, applied with [`PropagateOpacity`].
+    #[reflect(default)]
+    pub opacity: Option<f32>,
+    /// Offset from the node's layout position in logical pixels.
+    #[reflect(default)]
+    pub offset: Option<Vec2>,
+    /// Scale of the node around its center.
+    #[reflect(default)]
+    pub scale: Option<f32>,
+}
+
+impl TransitionValues
+{
+    /// Returns `true` if any value is set.
+    pub fn has_values(&self) -> bool
+    {
+        self.opacity.is_some() || self.offset.is_some() || self.scale.is_some()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction that animates a node in from [`Self::from`] when it is spawned.
+///
+/// Values animate to a scale of `1.0`, no offset, and the node's [`PropagateOpacity`] (or `1.0` if it has none).
+/// Offsets and scales are visual only and don't affect layout (see [`TransitionTransform`]).
+///
+/// Each value is added to the node as an [`Animated`] attribute that enters from the `from` value. The animation
+/// does not restart when the instruction is re-applied with the same values (e.g. on hot reload).
+///
+/// Example:
+/**
+```rust
+#scenes
+"menu"
+    OnSpawnAnimate{from:{opacity:0 offset:(0 -20)} duration:0.2 ease:OutCubic}
+    OnDespawnAnimate{to:{opacity:0 scale:0.9} duration:0.15}
+```
+*/
+#[derive(Component, Reflect, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct OnSpawnAnimate
+{
+    /// The values the node starts with.
+    pub from: TransitionValues,
+    /// Duration of the animation in seconds.
+    ///
+    /// Defaults to `0.25`.
+    #[reflect(default = "default_transition_duration")]
+    pub duration: f32,
+    /// Easing of the animation.
+    ///
+    /// Defaults to [`Ease::OutCubic`].
+    #[reflect(default = "default_transition_ease")]
+    pub ease: Ease,
+}
+
+impl Default for OnSpawnAnimate
+{
+    fn default() -> Self
+    {
+        Self {
+            from: TransitionValues::default(),
+            duration: default_transition_duration(),
+            ease: default_transition_ease(),
+        }
+    }
+}
+
+impl Instruction for OnSpawnAnimate
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        let base_opacity = emut.get::<PropagateOpacity>().map(|o| o.0).unwrap_or(1.);
+        emut.insert(self.clone());
+
+        let config = AnimationConfig::new(self.duration, self.ease, 0.);
+        if let Some(opacity) = self.from.opacity {
+            apply_transition_attribute::<PropagateOpacity>(
+                entity,
+                world,
+                ENTER_OPACITY,
+                None,
+                Some(opacity),
+                base_opacity,
+                config.clone(),
+            );
+        }
+        if let Some(offset) = self.from.offset {
+            apply_transition_attribute::<TransitionOffset>(
+                entity,
+                world,
+                ENTER_OFFSET,
+                None,
+                Some(offset),
+                Vec2::ZERO,
+                config.clone(),
+            );
+        }
+        if let Some(scale) = self.from.scale {
+            apply_transition_attribute::<TransitionScale>(
+                entity,
+                world,
+                ENTER_SCALE,
+                None,
+                Some(scale),
+                1.,
+                config,
+            );
+        }
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        remove_transition_attributes(entity, world, &[ENTER_OPACITY, ENTER_OFFSET, ENTER_SCALE]);
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.remove::<Self>();
+        });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction that animates a node out to [`Self::to`] when it gets [`PseudoState::Dying`], and despawns the node
+/// recursively when the animation completes.
+///
+/// Each value is added to the node as an [`Animated`] attribute for the `Dying` state, so other attributes can
+/// also select the state (e.g. `Static<BackgroundColor>{state:[Dying] value:#444444}`). Use
+/// [`DespawnAnimatedExt::despawn_animated`] to add the state, or add it with a pseudo state machine. Despawning
+/// the node directly skips the animation.
+///
+/// See [`OnSpawnAnimate`] for an example.
+#[derive(Component, Reflect, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct OnDespawnAnimate
+{
+    /// The values the node ends with.
+    pub to: TransitionValues,
+    /// Duration of the animation in seconds.
+    ///
+    /// Defaults to `0.25`.
+    #[reflect(default = "default_transition_duration")]
+    pub duration: f32,
+    /// Easing of the animation.
+    ///
+    /// Defaults to [`Ease::OutCubic`].
+    #[reflect(default = "default_transition_ease")]
+    pub ease: Ease,
+}
+
+impl Default for OnDespawnAnimate
+{
+    fn default() -> Self
+    {
+        Self {
+            to: TransitionValues::default(),
+            duration: default_transition_duration(),
+            ease: default_transition_ease(),
+        }
+    }
+}
+
+impl Instruction for OnDespawnAnimate
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        emut.insert(self.clone());
+
+        // Exit values replace the node's other values while it has `PseudoState::Dying`.
+        let config = AnimationConfig::new(self.duration, self.ease, 0.);
+        let dying = Some(PseudoState::Dying);
+        if let Some(opacity) = self.to.opacity {
+            apply_transition_attribute::<PropagateOpacity>(
+                entity,
+                world,
+                EXIT_OPACITY,
+                dying.clone(),
+                None,
+                opacity,
+                config.clone(),
+            );
+        }
+        if let Some(offset) = self.to.offset {
+            apply_transition_attribute::<TransitionOffset>(
+                entity,
+                world,
+                EXIT_OFFSET,
+                dying.clone(),
+                None,
+                offset,
+                config.clone(),
+            );
+        }
+        if let Some(scale) = self.to.scale {
+            apply_transition_attribute::<TransitionScale>(entity, world, EXIT_SCALE, dying, None, scale, config);
+        }
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        remove_transition_attributes(entity, world, &[EXIT_OPACITY, EXIT_OFFSET, EXIT_SCALE]);
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.remove::<Self>();
+        });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn default_transition_duration() -> f32
+{
+    0.25
+}
+
+fn default_transition_ease() -> Ease
+{
+    Ease::OutCubic
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Extension trait for despawning entities after their [`OnDespawnAnimate`] animations.
+pub trait DespawnAnimatedExt
+{
+    /// Adds [`PseudoState::Dying`] to the entity, which starts its [`OnDespawnAnimate`] animation. The entity is
+    /// despawned recursively when the animation completes.
+    ///
+    /// Despawns the entity immediately if it doesn't have [`OnDespawnAnimate`] values. Does nothing if the entity
+    /// is already animating out.
+    fn despawn_animated(&mut self);
+}
+
+impl DespawnAnimatedExt for EntityCommands<'_>
+{
+    fn despawn_animated(&mut self)
+    {
+        self.queue(start_exit_transition);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct SpawnTransitionsPlugin;
+
+impl Plugin for SpawnTransitionsPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.register_type::<TransitionValues>()
+            .register_type::<TransitionTransform>()
+            .register_animatable::<TransitionOffset>()
+            .register_animatable::<TransitionScale>()
+            .register_instruction_type::<OnSpawnAnimate>()
+            .register_instruction_type::<OnDespawnAnimate>()
+            .add_reactor(
+                any_entity_event::<AnimationComplete<PropagateOpacity>>(),
+                despawn_after_exit_transition::<PropagateOpacity>,
+            )
+            .add_reactor(
+                any_entity_event::<AnimationComplete<TransitionOffset>>(),
+                despawn_after_exit_transition::<TransitionOffset>,
+            )
+            .add_reactor(
+                any_entity_event::<AnimationComplete<TransitionScale>>(),
+                despawn_after_exit_transition::<TransitionScale>,
+            )
+            .add_systems(
+                PostUpdate,
+                apply_transition_transforms
+                    .after(UiSystem::Layout)
+                    .before(TransformSystem::TransformPropagate),
+            );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -37,7 +37,7 @@ fn get_node_value<T>(entity: Entity, world: &World, callback: impl FnOnce(&Node)
 
 //-------------------------------------------------------------------------------------------------------------------
 
-fn remove_node(entity: Entity, world: &mut World)
+pub(crate) fn remove_node(entity: Entity, world: &mut World)
 {
     let _ = world.get_entity_mut(entity).map(|mut e| {
         e.remove_with_requires::<Node>();
@@ -0,0 +1,262 @@
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::prelude::*;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use bevy::ui::widget::NodeImageMode;
+use bevy::ui::{ContentSize, UiSystem};
+use bevy::utils::HashSet;
+use resvg::{tiny_skia, usvg};
+use thiserror::Error;
+
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Rasterizes SVG nodes at their computed physical size.
+fn rasterize_svg_nodes(
+    mut events: EventReader<AssetEvent<Svg>>,
+    svgs: Res<Assets<Svg>>,
+    mut images: ResMut<Assets<Image>>,
+    mut nodes: Query<(&mut SvgNodeState, &ComputedNode, &mut ImageNode, &mut ContentSize)>,
+)
+{
+    let mut modified = HashSet::default();
+    for event in events.read() {
+        match event {
+            AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id } => {
+                modified.insert(*id);
+            }
+            _ => (),
+        }
+    }
+
+    for (mut state, computed, mut img, mut content_size) in nodes.iter_mut() {
+        let Some(svg) = svgs.get(&state.svg) else { continue };
+        let svg_changed = modified.contains(&state.svg.id());
+
+        // Nodes without an explicit size are sized to the SVG's intrinsic size, in logical pixels.
+        let scale = computed.inverse_scale_factor().recip();
+        if svg_changed || state.scale != scale {
+            state.scale = scale;
+            *content_size = ContentSize::fixed_size(svg.size() * scale);
+        }
+
+        // Re-rasterize when the node's physical size changes, which includes scale factor changes.
+        let size = computed.size().round().as_uvec2();
+        if size.cmpeq(UVec2::ZERO).any() || (!svg_changed && state.size == size) {
+            continue;
+        }
+        state.size = size;
+
+        let image = svg.rasterize(size);
+        match state.image.as_ref() {
+            Some(handle) => {
+                images.insert(handle, image);
+            }
+            None => {
+                state.image = Some(images.add(image));
+            }
+        }
+        if let Some(handle) = state.image.as_ref().filter(|h| img.image != **h) {
+            img.image = handle.clone();
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Default)]
+struct SvgLoader;
+
+impl AssetLoader for SvgLoader
+{
+    type Asset = Svg;
+    type Settings = ();
+    type Error = SvgLoadError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _: &Self::Settings,
+        _: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error>
+    {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Svg::from_data(&bytes)
+    }
+
+    fn extensions(&self) -> &[&str]
+    {
+        &["svg"]
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Component, Debug)]
+struct SvgNodeState
+{
+    svg: Handle<Svg>,
+    image: Option<Handle<Image>>,
+    size: UVec2,
+    scale: f32,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Possible errors that can be produced when loading an [`Svg`].
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum SvgLoadError
+{
+    /// An [IO Error](std::io::Error).
+    #[error("Could not read the SVG file: {0}")]
+    Io(#[from] std::io::Error),
+    /// A parsing error.
+    #[error("Could not parse the SVG file: {0}")]
+    Parse(#[from] usvg::Error),
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A parsed SVG file.
+///
+/// Loaded from `.svg` files by the asset server when the `svg` feature is enabled.
+#[derive(Asset, TypePath, Debug)]
+pub struct Svg
+{
+    tree: usvg::Tree,
+}
+
+impl Svg
+{
+    /// Parses an SVG from raw file data.
+    pub fn from_data(data: &[u8]) -> Result<Self, SvgLoadError>
+    {
+        Ok(Self {
+            tree: usvg::Tree::from_data(data, &usvg::Options::default())?,
+        })
+    }
+
+    /// Gets the intrinsic size of the SVG.
+    pub fn size(&self) -> Vec2
+    {
+        let size = self.tree.size();
+        Vec2::new(size.width(), size.height())
+    }
+
+    /// Rasterizes the SVG to an image with the requested size in pixels.
+    ///
+    /// The SVG is scaled uniformly to fit in the image, and centered.
+    pub fn rasterize(&self, size: UVec2) -> Image
+    {
+        let size = size.max(UVec2::ONE);
+        let mut data = Vec::with_capacity((size.x * size.y * 4) as usize);
+        if let Some(mut pixmap) = tiny_skia::Pixmap::new(size.x, size.y) {
+            let svg_size = self.size();
+            let scale = (size.as_vec2() / svg_size).min_element();
+            let offset = (size.as_vec2() - svg_size * scale) / 2.;
+            let transform = tiny_skia::Transform::from_row(scale, 0., 0., scale, offset.x, offset.y);
+            resvg::render(&self.tree, transform, &mut pixmap.as_mut());
+
+            // Pixmaps use premultiplied alpha.
+            for pixel in pixmap.pixels() {
+                let color = pixel.demultiply();
+                data.extend_from_slice(&[color.red(), color.green(), color.blue(), color.alpha()]);
+            }
+        } else {
+            data.resize((size.x * size.y * 4) as usize, 0);
+        }
+
+        Image::new(
+            Extent3d { width: size.x, height: size.y, depth_or_array_layers: 1 },
+            TextureDimension::D2,
+            data,
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        )
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction that displays an SVG file in an [`ImageNode`].
+///
+/// The SVG is rasterized at the node's computed size in physical pixels, and re-rasterized whenever the size
+/// changes (including when the window scale factor or [`UiScale`] changes), so it stays crisp at any resolution.
+/// Nodes without an explicit size are sized to the SVG's intrinsic size. The SVG is scaled uniformly to fit the
+/// node.
+///
+/// Inserts an [`ImageNode`] if the entity doesn't have one, so other image instructions like [`ImageNodeColor`]
+/// can be used to tint the SVG.
+///
+/// Requires the `svg` feature.
+///
+/// Example:
+/**
+```rust
+#scenes
+"icon"
+    FlexNode{width:24px height:24px}
+    LoadedSvg("icons/settings.svg")
+    ImageNodeColor(#FFFFFF)
+```
+*/
+#[derive(Component, Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct LoadedSvg(pub String);
+
+impl Instruction for LoadedSvg
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let svg = world.resource::<AssetServer>().load(&self.0);
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+
+        // The image is sized by the node, so it shouldn't be measured.
+        match emut.get_mut::<ImageNode>() {
+            Some(mut img) => {
+                img.image_mode = NodeImageMode::Stretch;
+            }
+            None => {
+                emut.insert(ImageNode { image_mode: NodeImageMode::Stretch, ..default() });
+            }
+        }
+
+        if emut.get::<SvgNodeState>().is_none_or(|s| s.svg != svg) {
+            emut.insert(SvgNodeState { svg, image: None, size: UVec2::ZERO, scale: 0. });
+        }
+        emut.insert(self);
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.remove::<(Self, SvgNodeState)>();
+            e.remove_with_requires::<(ImageNode, ContentSize)>();
+        });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct SvgPlugin;
+
+impl Plugin for SvgPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_asset::<Svg>()
+            .register_asset_loader(SvgLoader)
+            .register_instruction_type::<LoadedSvg>()
+            .add_systems(PostUpdate, rasterize_svg_nodes.after(UiSystem::Layout));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
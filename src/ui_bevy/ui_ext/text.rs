@@ -170,6 +170,258 @@ impl StaticAttribute for TextLine
 
 //-------------------------------------------------------------------------------------------------------------------
 
+fn insert_text_spans(
+    In((entity, spans)): In<(Entity, TextSpans)>,
+    mut commands: Commands,
+    localizer: Res<TextLocalizer>,
+    font_map: Res<FontMap>,
+    color: Query<&TextLineColor>,
+    mut localized: Query<&mut LocalizedText>,
+    mut prev_spans: Query<&mut StyledTextSpanEntities>,
+)
+{
+    // Clean up spans from a previous insertion.
+    if let Ok(mut prev_spans) = prev_spans.get_mut(entity) {
+        for span in prev_spans.0.drain(..) {
+            commands.entity(span).try_despawn_recursive();
+        }
+    }
+
+    // Prep defaults.
+    let default_color = color
+        .get(entity)
+        .map(|c| c.0)
+        .unwrap_or_else(|_| TextLine::default_color());
+    let default_font = spans.font.map(|f| font_map.get(&f)).unwrap_or_default();
+
+    // Prep spans.
+    // - We need to manually localize inserted text in case the spans are hot reloaded into an entity that already
+    //   has Text (i.e. because auto-localization won't occur).
+    let mut maybe_localized = localized.get_mut(entity).ok();
+    let mut styled: Vec<(String, TextFont, TextColor)> = spans
+        .spans
+        .into_iter()
+        .enumerate()
+        .map(|(idx, span)| {
+            let mut text = span.text;
+            let mut font = span
+                .font
+                .map(|f| font_map.get(&f))
+                .unwrap_or_else(|| default_font.clone());
+            if let Some(localized) = maybe_localized.as_mut() {
+                localized.set_localization_for_span(text.as_str(), idx);
+                localized
+                    .localization_for_span_mut(idx)
+                    .unwrap()
+                    .set_font_backup(font.clone());
+                localized.localize_span(&localizer, &font_map, &mut text, &mut font, idx);
+            }
+            let text_font = TextFont {
+                font,
+                font_size: span.size.unwrap_or(spans.size),
+                ..default()
+            };
+            (text, text_font, TextColor(span.color.unwrap_or(default_color)))
+        })
+        .collect();
+    if styled.is_empty() {
+        styled.push((
+            String::default(),
+            TextFont { font: default_font, font_size: spans.size, ..default() },
+            TextColor(default_color),
+        ));
+    }
+    let mut styled = styled.into_iter();
+    let (root_text, root_font, root_color) = styled.next().unwrap();
+
+    // Add text to entity.
+    let span_entities: Vec<Entity> = styled
+        .map(|(text, font, color)| commands.spawn((TextSpan(text), font, color)).id())
+        .collect();
+    let Some(mut ec) = commands.get_entity(entity) else { return };
+    ec.try_insert((
+        Text(root_text),
+        TextLayout { justify: spans.justify, linebreak: spans.linebreak },
+        root_font,
+        root_color,
+    ));
+    ec.insert_children(0, &span_entities);
+    ec.try_insert(StyledTextSpanEntities(span_entities));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Tracks span entities spawned by [`TextSpans`].
+#[derive(Component, Debug)]
+struct StyledTextSpanEntities(Vec<Entity>);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A text span in [`TextSpans`].
+#[derive(Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StyledTextSpan
+{
+    /// The span's text.
+    #[reflect(default)]
+    pub text: String,
+    /// The span's font.
+    ///
+    /// Defaults to [`TextSpans::font`].
+    #[reflect(default)]
+    pub font: Option<FontRequest>,
+    /// The span's font size.
+    ///
+    /// Defaults to [`TextSpans::size`].
+    #[reflect(default)]
+    pub size: Option<f32>,
+    /// The span's color.
+    ///
+    /// Defaults to the entity's [`TextLineColor`] if it has one, otherwise white.
+    #[reflect(default)]
+    pub color: Option<Color>,
+}
+
+impl StyledTextSpan
+{
+    pub fn new(text: impl Into<String>) -> Self
+    {
+        Self { text: text.into(), ..default() }
+    }
+
+    pub fn with_font(mut self, font: impl Into<FontRequest>) -> Self
+    {
+        self.font = Some(font.into());
+        self
+    }
+
+    pub fn with_size(mut self, size: f32) -> Self
+    {
+        self.size = Some(size);
+        self
+    }
+
+    pub fn with_color(mut self, color: impl Into<Color>) -> Self
+    {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Sets up an entity with a [`Text`] component and multiple styled text spans.
+///
+/// The first span is written to the entity's [`Text`], and other spans are spawned as child [`TextSpan`]
+/// entities. Each span can override the default font, size, and color. Spans are localized individually if the
+/// entity has [`LocalizedText`].
+///
+/// Example:
+/**
+```rust
+#scenes
+"health"
+    TextSpans{
+        spans: [
+            {text: "Health is "}
+            {text: "low" font: {family: "Fira Sans" weight: Bold} color: #FF0000}
+            {text: "!"}
+        ]
+    }
+```
+*/
+#[derive(Reflect, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextSpans
+{
+    /// The text spans.
+    #[reflect(default)]
+    pub spans: Vec<StyledTextSpan>,
+    /// The default font of spans.
+    ///
+    /// Defaults to the built-in "Fira Sans Medium" font.
+    #[reflect(default = "TextLine::default_font")]
+    pub font: Option<FontRequest>,
+    /// The default font size of spans.
+    ///
+    /// Defaults to `25.0`.
+    #[reflect(default = "TextLine::default_font_size")]
+    pub size: f32,
+    /// The text's [`LineBreak`] behavior.
+    ///
+    /// Defaults to [`LineBreak::WordBoundary`].
+    #[reflect(default = "TextSpans::default_line_break")]
+    pub linebreak: LineBreak,
+    /// The text's [`JustifyText`] behavior.
+    ///
+    /// Defaults to [`JustifyText::Left`].
+    #[reflect(default = "TextLine::default_justify_text")]
+    pub justify: JustifyText,
+}
+
+impl TextSpans
+{
+    pub fn new(spans: impl IntoIterator<Item = StyledTextSpan>) -> Self
+    {
+        Self { spans: spans.into_iter().collect(), ..default() }
+    }
+
+    pub fn with_font(mut self, font: impl Into<FontRequest>) -> Self
+    {
+        self.font = Some(font.into());
+        self
+    }
+
+    fn default_line_break() -> LineBreak
+    {
+        LineBreak::WordBoundary
+    }
+}
+
+impl Instruction for TextSpans
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        world.syscall((entity, self), insert_text_spans);
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        let spans = emut.take::<StyledTextSpanEntities>();
+        emut.remove_with_requires::<(Text, ContentSize)>();
+        for span in spans.into_iter().flat_map(|s| s.0) {
+            let Ok(span) = world.get_entity_mut(span) else { continue };
+            span.despawn_recursive();
+        }
+    }
+}
+
+impl Default for TextSpans
+{
+    fn default() -> Self
+    {
+        Self {
+            spans: Vec::default(),
+            font: TextLine::default_font(),
+            size: TextLine::default_font_size(),
+            linebreak: Self::default_line_break(),
+            justify: TextLine::default_justify_text(),
+        }
+    }
+}
+
+impl StaticAttribute for TextSpans
+{
+    type Value = Self;
+    fn construct(value: Self::Value) -> Self
+    {
+        value
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Instruction for setting the font size of a [`TextLine`] on an entity.
 //todo: hook this up to TextLine or find a better abstraction
 #[derive(Reflect, Component, Default, Debug, Copy, Clone, PartialEq)]
@@ -281,6 +533,7 @@ impl Plugin for UiTextExtPlugin
     fn build(&self, app: &mut App)
     {
         app.register_static::<TextLine>()
+            .register_static::<TextSpans>()
             .register_static::<TextLineSize>()
             .register_animatable::<TextLineColor>();
     }
@@ -0,0 +1,241 @@
+use bevy::prelude::*;
+use bevy::text::{LineBreak, TextLayoutInfo};
+use bevy::ui::UiSystem;
+
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Tracks the authored font size and line break of a [`TextFit`] entity so they can be restored.
+#[derive(Component, Debug, Default)]
+struct TextFitState
+{
+    /// The font size before fitting.
+    authored: f32,
+    /// The font size last set by fitting. Used to detect when the authored size changes (e.g. on hot reload).
+    applied: Option<f32>,
+    /// The line break before [`TextFitMode::Wrap`] was applied.
+    authored_linebreak: Option<LineBreak>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Adjusts the font size of [`TextFit`] entities after text is laid out.
+///
+/// Fitting re-runs every tick, so text is refit when it changes (e.g. when localized), when the
+/// authored font size changes (e.g. on hot reload), and when the parent node is resized.
+fn update_text_fit(
+    mut texts: Query<(
+        &TextFit,
+        &mut TextFitState,
+        &mut TextFont,
+        &mut TextLayout,
+        &TextLayoutInfo,
+        &Parent,
+    )>,
+    parents: Query<&ComputedNode>,
+)
+{
+    for (fit, mut state, mut font, mut layout, info, parent) in texts.iter_mut() {
+        if state.applied != Some(font.font_size) {
+            state.authored = font.font_size;
+        }
+
+        if fit.mode == TextFitMode::Wrap {
+            if layout.linebreak != LineBreak::WordBoundary {
+                state.authored_linebreak = Some(layout.linebreak);
+                layout.linebreak = LineBreak::WordBoundary;
+            }
+        } else if let Some(linebreak) = state.authored_linebreak.take() {
+            layout.linebreak = linebreak;
+        }
+
+        let Ok(parent_node) = parents.get(parent.get()) else { continue };
+        let inset = parent_node.content_inset();
+        let available = parent_node.size() - Vec2::new(inset.left + inset.right, inset.top + inset.bottom);
+
+        let new_size = fit.fit_font_size(font.font_size, state.authored, info.size, available);
+        if new_size != font.font_size {
+            font.font_size = new_size;
+        }
+        state.applied = Some(font.font_size);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// How [`TextFit`] adjusts font size.
+#[derive(Reflect, Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum TextFitMode
+{
+    /// Shrinks the font below its authored size (down to `min_size`) until the text fits.
+    #[default]
+    Shrink,
+    /// Grows the font above its authored size (up to `max_size`) until the text fills the available space.
+    Grow,
+    /// Wraps the text at word boundaries, then shrinks the font like [`Self::Shrink`] until the wrapped text
+    /// fits the available height.
+    Wrap,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction that adjusts the font size of a text entity so its text fits in the parent node.
+///
+/// The available space is the parent's size minus its padding and border. The authored font size (e.g. from
+/// [`TextLine`]) is the starting point, and the fitted size is always in the range `[min_size, max_size]`.
+/// Fitting takes a tick or two to settle, because the text must be laid out again after each adjustment.
+///
+/// Fitting is re-evaluated every tick, so it reacts to localization changes, hot reloading, and resizing.
+///
+/// Example:
+/**
+```rust
+#scenes
+"button"
+    FlexNode{width:150px height:40px justify_main:Center justify_cross:Center}
+
+    "text"
+        TextLine{text:"confirm-button"}
+        TextFit{min_size:12 max_size:25 mode:Shrink}
+```
+*/
+#[derive(Component, Reflect, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct TextFit
+{
+    /// The minimum font size.
+    ///
+    /// Defaults to `8.0`.
+    #[reflect(default = "TextFit::default_min_size")]
+    pub min_size: f32,
+    /// The maximum font size.
+    ///
+    /// Defaults to `100.0`.
+    #[reflect(default = "TextFit::default_max_size")]
+    pub max_size: f32,
+    /// Defaults to [`TextFitMode::Shrink`].
+    #[reflect(default)]
+    pub mode: TextFitMode,
+}
+
+impl TextFit
+{
+    /// Font sizes closer than this are considered equal, to avoid refitting on rounding noise.
+    const TOLERANCE: f32 = 0.25;
+    /// Growing stops slightly short of the exact fit, since glyph metrics don't scale perfectly linearly.
+    const GROW_MARGIN: f32 = 0.98;
+
+    /// Computes the fitted font size.
+    ///
+    /// Text width is assumed to scale linearly with font size. For [`TextFitMode::Wrap`], text height is assumed
+    /// to scale quadratically (lines get taller and there are more of them).
+    ///
+    /// `measured` is the laid-out size of the text at `current` font size, and `available` is the space it
+    /// should fit in. Returns `current` if the text can't be measured.
+    pub fn fit_font_size(&self, current: f32, authored: f32, measured: Vec2, available: Vec2) -> f32
+    {
+        if current <= 0. || measured.x <= 0. || measured.y <= 0. || available.x <= 0. || available.y <= 0. {
+            return current;
+        }
+
+        let (low, high) = match self.mode {
+            TextFitMode::Shrink | TextFitMode::Wrap => (self.min_size, authored.min(self.max_size)),
+            TextFitMode::Grow => (authored.max(self.min_size), self.max_size),
+        };
+        let high = high.max(low);
+
+        let ratio_x = available.x / measured.x;
+        let ratio_y = available.y / measured.y;
+        let ratio = match self.mode {
+            TextFitMode::Shrink | TextFitMode::Grow => ratio_x.min(ratio_y),
+            TextFitMode::Wrap => ratio_x.min(ratio_y.sqrt()),
+        };
+        let ratio = if ratio > 1. {
+            (ratio * Self::GROW_MARGIN).max(1.)
+        } else {
+            ratio
+        };
+
+        let target = (current * ratio).clamp(low, high);
+        if (target - current).abs() < Self::TOLERANCE && (low..=high).contains(&current) {
+            return current;
+        }
+        target
+    }
+
+    fn default_min_size() -> f32
+    {
+        8.
+    }
+
+    fn default_max_size() -> f32
+    {
+        100.
+    }
+}
+
+impl Default for TextFit
+{
+    fn default() -> Self
+    {
+        Self {
+            min_size: Self::default_min_size(),
+            max_size: Self::default_max_size(),
+            mode: TextFitMode::default(),
+        }
+    }
+}
+
+impl Instruction for TextFit
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        if !emut.contains::<TextFitState>() {
+            emut.insert(TextFitState::default());
+        }
+        emut.insert(self);
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        emut.remove::<Self>();
+        let Some(state) = emut.take::<TextFitState>() else { return };
+        if state.applied.is_some() {
+            if let Some(mut font) = emut.get_mut::<TextFont>() {
+                font.font_size = state.authored;
+            }
+        }
+        if let Some(linebreak) = state.authored_linebreak {
+            if let Some(mut layout) = emut.get_mut::<TextLayout>() {
+                layout.linebreak = linebreak;
+            }
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct TextFitPlugin;
+
+impl Plugin for TextFitPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.register_instruction_type::<TextFit>()
+            .add_systems(PostUpdate, update_text_fit.after(UiSystem::PostLayout));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,256 @@
+use bevy::prelude::*;
+use bevy::text::{ComputedTextBlock, TextLayoutInfo};
+use bevy::ui::{RelativeCursorPosition, UiSystem};
+use bevy_cobweb::prelude::*;
+use smol_str::SmolStr;
+
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Prepares the text blocks of new links for hit-testing.
+fn setup_text_links(
+    mut c: Commands,
+    links: Query<Entity, Added<TextLink>>,
+    parents: Query<&Parent>,
+    texts: Query<Has<TextLinkState>, With<Text>>,
+)
+{
+    for entity in links.iter() {
+        // Links can be on the root text entity or on one of its spans.
+        let root = match texts.contains(entity) {
+            true => entity,
+            false => {
+                let Ok(parent) = parents.get(entity) else { continue };
+                parent.get()
+            }
+        };
+        let Ok(has_state) = texts.get(root) else { continue };
+        if has_state {
+            continue;
+        }
+        c.entity(root).insert((
+            TextLinkState::default(),
+            Interaction::default(),
+            RelativeCursorPosition::default(),
+        ));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Gets the link span under the cursor.
+fn get_hovered_link(
+    cursor: &RelativeCursorPosition,
+    node: &ComputedNode,
+    layout: &TextLayoutInfo,
+    block: &ComputedTextBlock,
+    links: &Query<(&TextLink, &mut TextColor)>,
+) -> Option<Entity>
+{
+    if !cursor.mouse_over() {
+        return None;
+    }
+
+    // Glyphs are positioned in the node's content box, inside its padding and border.
+    let inset = node.content_inset();
+    let position = cursor.normalized? * node.size() - Vec2::new(inset.left, inset.top);
+    layout
+        .glyphs
+        .iter()
+        .find(|glyph| Rect::from_center_size(glyph.position, glyph.size).contains(position))
+        .and_then(|glyph| block.entities().get(glyph.span_index))
+        .map(|span| span.entity)
+        .filter(|span| links.contains(*span))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Updates hovered links, applies link styles, and sends [`LinkActivated`] events for clicked links.
+fn update_text_links(
+    mut c: Commands,
+    mut texts: Query<(
+        Entity,
+        &mut TextLinkState,
+        &Interaction,
+        &RelativeCursorPosition,
+        &ComputedNode,
+        &TextLayoutInfo,
+        &ComputedTextBlock,
+        Option<&TextLinkStyle>,
+    )>,
+    mut links: Query<(&TextLink, &mut TextColor)>,
+)
+{
+    for (entity, mut state, interaction, cursor, node, layout, block, maybe_style) in texts.iter_mut() {
+        let hovered = get_hovered_link(cursor, node, layout, block, &links);
+
+        // Links are activated when pressed and released over the same link.
+        let pressed = *interaction == Interaction::Pressed;
+        match (pressed, state.was_pressed) {
+            (true, false) => state.pressed = hovered,
+            (false, true) => {
+                if let Some(span) = state.pressed.take().filter(|p| Some(*p) == hovered) {
+                    if let Ok((link, _)) = links.get(span) {
+                        c.react()
+                            .entity_event(entity, LinkActivated { id: link.id.clone(), span });
+                    }
+                }
+            }
+            _ => (),
+        }
+        state.was_pressed = pressed;
+
+        // Style links.
+        let default_style = TextLinkStyle::default();
+        let style = maybe_style.unwrap_or(&default_style);
+        for span in block.entities().iter() {
+            let Ok((_, mut color)) = links.get_mut(span.entity) else { continue };
+            let new_color = match Some(span.entity) == hovered {
+                true => style.hover_color.or(style.color),
+                false => style.color,
+            };
+            let Some(new_color) = new_color.filter(|c| *c != color.0) else { continue };
+            color.0 = new_color;
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Tracks link interactions on a text block that contains [`TextLink`] spans.
+#[derive(Component, Debug, Default)]
+struct TextLinkState
+{
+    /// The link that was hovered when the text was pressed.
+    pressed: Option<Entity>,
+    was_pressed: bool,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Component for text spans that are hyperlinks.
+///
+/// Links can be added to [`TextSpans`](TextSpan) or to the root [`Text`] entity, and are created automatically from
+/// `<link id="wiki">..</link>` tags in localized text (see [`parse_text_markup`]). Links are styled with the text
+/// entity's [`TextLinkStyle`], and clicking a link sends a [`LinkActivated`] entity event to the text entity.
+///
+/// The text entity gets an [`Interaction`] component so links can be hovered and clicked, which means it will
+/// block interactions with nodes below it.
+#[derive(Component, Reflect, Debug, Clone, PartialEq)]
+pub struct TextLink
+{
+    pub id: SmolStr,
+}
+
+impl TextLink
+{
+    pub fn new(id: impl Into<SmolStr>) -> Self
+    {
+        Self { id: id.into() }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Entity event sent to a text entity when one of its [`TextLink`] spans is clicked.
+///
+/// A link is clicked when it is pressed and released without moving the cursor off the link.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinkActivated
+{
+    /// The link's id.
+    pub id: SmolStr,
+    /// The span entity with the [`TextLink`].
+    pub span: Entity,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction for styling the [`TextLink`] spans of a text entity.
+///
+/// Colors override the spans' [`TextColor`]. If no style is set, [`TextLinkStyle::default`] is used.
+///
+/// Example:
+/**
+```rust
+#scenes
+"credits"
+    TextLine{text:"credits-text"}
+    TextLinkStyle{color:#4A9EFF hover_color:#8CC2FF}
+```
+*/
+#[derive(Component, Reflect, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct TextLinkStyle
+{
+    /// Color of links.
+    ///
+    /// Defaults to `#4A9EFF`. If set to `None`, links keep their own [`TextColor`].
+    #[reflect(default = "TextLinkStyle::default_color")]
+    pub color: Option<Color>,
+    /// Color of hovered links.
+    ///
+    /// Defaults to `#8CC2FF`. If set to `None`, hovered links use [`Self::color`].
+    #[reflect(default = "TextLinkStyle::default_hover_color")]
+    pub hover_color: Option<Color>,
+}
+
+impl TextLinkStyle
+{
+    fn default_color() -> Option<Color>
+    {
+        Some(Color::srgb_u8(74, 158, 255))
+    }
+
+    fn default_hover_color() -> Option<Color>
+    {
+        Some(Color::srgb_u8(140, 194, 255))
+    }
+}
+
+impl Default for TextLinkStyle
+{
+    fn default() -> Self
+    {
+        Self {
+            color: Self::default_color(),
+            hover_color: Self::default_hover_color(),
+        }
+    }
+}
+
+impl Instruction for TextLinkStyle
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        emut.insert(self);
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        emut.remove::<Self>();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct TextLinksPlugin;
+
+impl Plugin for TextLinksPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.register_type::<TextLink>()
+            .register_instruction_type::<TextLinkStyle>()
+            .add_systems(PreUpdate, (setup_text_links, update_text_links).chain().after(UiSystem::Focus));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
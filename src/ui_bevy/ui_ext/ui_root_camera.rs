@@ -0,0 +1,139 @@
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+use smol_str::SmolStr;
+
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Updates [`TargetCamera`] on UI roots when [`UiRootForCamera`] or cameras change.
+fn update_ui_root_cameras(
+    mut c: Commands,
+    mut removed_cameras: RemovedComponents<Camera>,
+    changed_cameras: Query<(), (With<Camera>, Changed<Name>)>,
+    cameras: Query<(Entity, &Name), With<Camera>>,
+    roots: Query<(Entity, Ref<UiRootForCamera>, Option<&TargetCamera>)>,
+)
+{
+    // Named cameras may be spawned, renamed, or despawned after the root's camera was selected.
+    let cameras_changed = removed_cameras.read().count() > 0 || !changed_cameras.is_empty();
+
+    for (entity, root, maybe_target) in roots.iter() {
+        if !cameras_changed && !root.is_changed() {
+            continue;
+        }
+
+        let camera = root.resolve(cameras.iter());
+        if maybe_target.map(TargetCamera::entity) == camera {
+            continue;
+        }
+        match camera {
+            Some(camera) => {
+                c.entity(entity).try_insert(TargetCamera(camera));
+            }
+            None => {
+                c.entity(entity).remove::<TargetCamera>();
+            }
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction loadable that selects the camera that renders a UI root node and its descendants.
+///
+/// Inserts bevy's [`TargetCamera`] to the entity, which controls which camera (and therefore which window, render
+/// target, and viewport) the UI tree is rendered to. Interactions are hit-tested against the target camera's
+/// viewport, so multiple cameras (split-screen, picture-in-picture, secondary windows) can each own a distinct UI
+/// tree.
+///
+/// Cameras can be selected by [`Name`], which is the only option available in COB files. If no camera has the
+/// requested name, then the UI tree falls back to the default UI camera until a matching camera is spawned or
+/// renamed.
+///
+/// This only has an effect on root nodes (nodes without a parent node). Use it on scene roots spawned with
+/// `commands.ui_root()`.
+///
+/// Example:
+/**
+```rust
+#scenes
+"player2_hud"
+    UiRootForCamera::Name("player2_camera")
+    FlexNode{width:100% height:100%}
+```
+*/
+///
+/// In rust, a camera entity can be selected directly:
+/**
+```rust
+c.ui_root().spawn_scene_and_edit(("hud.cob", "player2_hud"), &mut s, |h| {
+    h.apply(UiRootForCamera::Entity(camera));
+});
+```
+*/
+#[derive(Component, Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum UiRootForCamera
+{
+    /// Render to the default UI camera (see bevy's `IsDefaultUiCamera`).
+    #[default]
+    DefaultCamera,
+    /// Render to the first camera with this [`Name`].
+    Name(SmolStr),
+    /// Render to this camera entity.
+    Entity(Entity),
+}
+
+impl UiRootForCamera
+{
+    /// Finds the camera that should render the UI root, given all named cameras.
+    ///
+    /// Returns `None` if the default UI camera should be used.
+    pub fn resolve<'a>(&self, cameras: impl IntoIterator<Item = (Entity, &'a Name)>) -> Option<Entity>
+    {
+        match self {
+            Self::DefaultCamera => None,
+            Self::Name(name) => cameras
+                .into_iter()
+                .find(|(_, camera_name)| camera_name.as_str() == name.as_str())
+                .map(|(camera, _)| camera),
+            Self::Entity(camera) => Some(*camera),
+        }
+    }
+}
+
+impl Instruction for UiRootForCamera
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let Ok(mut emut) = world.get_entity_mut(entity) else { return };
+        emut.insert(self);
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.remove::<(Self, TargetCamera)>();
+        });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct UiRootCameraPlugin;
+
+impl Plugin for UiRootCameraPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.register_instruction_type::<UiRootForCamera>()
+            .add_systems(PostUpdate, update_ui_root_cameras.before(UiSystem::Prepare));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
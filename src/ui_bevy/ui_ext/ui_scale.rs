@@ -0,0 +1,350 @@
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+
+use crate::prelude::*;
+use crate::sickle::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn scale_val(val: &mut Val, scale: f32)
+{
+    if let Val::Px(px) = val {
+        *px *= scale;
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn scale_rect(rect: &mut UiRect, scale: f32)
+{
+    scale_val(&mut rect.left, scale);
+    scale_val(&mut rect.right, scale);
+    scale_val(&mut rect.top, scale);
+    scale_val(&mut rect.bottom, scale);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Multiplies all [`Val::Px`] dimensions of a node by `scale`.
+fn scale_node(node: &mut Node, scale: f32)
+{
+    scale_val(&mut node.left, scale);
+    scale_val(&mut node.right, scale);
+    scale_val(&mut node.top, scale);
+    scale_val(&mut node.bottom, scale);
+    scale_val(&mut node.width, scale);
+    scale_val(&mut node.height, scale);
+    scale_val(&mut node.min_width, scale);
+    scale_val(&mut node.min_height, scale);
+    scale_val(&mut node.max_width, scale);
+    scale_val(&mut node.max_height, scale);
+    scale_val(&mut node.flex_basis, scale);
+    scale_val(&mut node.row_gap, scale);
+    scale_val(&mut node.column_gap, scale);
+    scale_rect(&mut node.margin, scale);
+    scale_rect(&mut node.padding, scale);
+    scale_rect(&mut node.border, scale);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Authored values of the [`Node`] fields changed by [`scale_node`].
+#[derive(Debug, Copy, Clone)]
+struct AuthoredNodeDimensions
+{
+    left: Val,
+    right: Val,
+    top: Val,
+    bottom: Val,
+    width: Val,
+    height: Val,
+    min_width: Val,
+    min_height: Val,
+    max_width: Val,
+    max_height: Val,
+    flex_basis: Val,
+    row_gap: Val,
+    column_gap: Val,
+    margin: UiRect,
+    padding: UiRect,
+    border: UiRect,
+}
+
+impl AuthoredNodeDimensions
+{
+    fn new(node: &Node) -> Self
+    {
+        Self {
+            left: node.left,
+            right: node.right,
+            top: node.top,
+            bottom: node.bottom,
+            width: node.width,
+            height: node.height,
+            min_width: node.min_width,
+            min_height: node.min_height,
+            max_width: node.max_width,
+            max_height: node.max_height,
+            flex_basis: node.flex_basis,
+            row_gap: node.row_gap,
+            column_gap: node.column_gap,
+            margin: node.margin,
+            padding: node.padding,
+            border: node.border,
+        }
+    }
+
+    fn restore(&self, node: &mut Node)
+    {
+        node.left = self.left;
+        node.right = self.right;
+        node.top = self.top;
+        node.bottom = self.bottom;
+        node.width = self.width;
+        node.height = self.height;
+        node.min_width = self.min_width;
+        node.min_height = self.min_height;
+        node.max_width = self.max_width;
+        node.max_height = self.max_height;
+        node.flex_basis = self.flex_basis;
+        node.row_gap = self.row_gap;
+        node.column_gap = self.column_gap;
+        node.margin = self.margin;
+        node.padding = self.padding;
+        node.border = self.border;
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Scales nodes in [`UiRootScale`] hierarchies before layout.
+///
+/// Nodes are restored in `restore_root_scaled_nodes`, so user code and instructions always see the authored
+/// values.
+fn apply_root_scales(
+    mut scaled: ResMut<RootScaledNodes>,
+    mut removed: RemovedComponents<UiRootScale>,
+    roots: Query<(Entity, Ref<UiRootScale>)>,
+    scales: Query<(), With<UiRootScale>>,
+    parents: Query<&Parent>,
+    children: Query<&Children>,
+    mut nodes: Query<(Mut<Node>, Option<Mut<TextFont>>)>,
+)
+{
+    // Refresh hierarchies that are no longer scaled.
+    let mut unscaled = Vec::default();
+    for root in removed.read() {
+        unscaled.push(root);
+        while let Some(entity) = unscaled.pop() {
+            if let Ok(c) = children.get(entity) {
+                unscaled.extend(c.iter().copied());
+            }
+            let Ok((mut node, maybe_font)) = nodes.get_mut(entity) else { continue };
+            node.set_changed();
+            if let Some(mut font) = maybe_font {
+                font.set_changed();
+            }
+        }
+    }
+
+    let mut stack = Vec::default();
+    for (root, _) in roots.iter() {
+        // Nested roots are handled when traversing their outermost root.
+        if parents.iter_ancestors(root).any(|a| scales.contains(a)) {
+            continue;
+        }
+        stack.push((root, 1.0f32, false));
+
+        while let Some((entity, parent_scale, parent_refresh)) = stack.pop() {
+            let (scale, refresh) = match roots.get(entity) {
+                Ok((_, s)) => (parent_scale * s.0, parent_refresh || s.is_changed()),
+                Err(_) => (parent_scale, parent_refresh),
+            };
+            if let Ok(c) = children.get(entity) {
+                stack.extend(c.iter().map(|c| (*c, scale, refresh)));
+            }
+
+            if scale == 1. {
+                if refresh {
+                    if let Ok((mut node, maybe_font)) = nodes.get_mut(entity) {
+                        node.set_changed();
+                        if let Some(mut font) = maybe_font {
+                            font.set_changed();
+                        }
+                    }
+                }
+                continue;
+            }
+            let Ok((mut node, maybe_font)) = nodes.get_mut(entity) else { continue };
+
+            // Avoid triggering layout every tick when nothing changed. Layout caches the previously-scaled node.
+            let refresh_node = refresh || node.is_changed();
+            let authored_node = AuthoredNodeDimensions::new(&node);
+            scale_node(node.bypass_change_detection(), scale);
+            if refresh_node {
+                node.set_changed();
+            }
+
+            let authored_font_size = maybe_font.map(|mut font| {
+                let refresh_font = refresh || font.is_changed();
+                let authored = font.font_size;
+                font.bypass_change_detection().font_size *= scale;
+                if refresh_font {
+                    font.set_changed();
+                }
+                authored
+            });
+
+            scaled.0.push((entity, authored_node, authored_font_size));
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Restores nodes that were scaled for layout.
+fn restore_root_scaled_nodes(
+    mut scaled: ResMut<RootScaledNodes>,
+    mut nodes: Query<(&mut Node, Option<&mut TextFont>)>,
+)
+{
+    for (entity, authored_node, authored_font_size) in scaled.0.drain(..) {
+        let Ok((mut node, maybe_font)) = nodes.get_mut(entity) else { continue };
+        authored_node.restore(node.bypass_change_detection());
+        if let (Some(mut font), Some(size)) = (maybe_font, authored_font_size) {
+            font.bypass_change_detection().font_size = size;
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Authored values of nodes scaled by [`UiRootScale`].
+#[derive(Resource, Default)]
+struct RootScaledNodes(Vec<(Entity, AuthoredNodeDimensions, Option<f32>)>);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Loadable command for setting the global UI scale.
+///
+/// Sets Bevy's [`UiScale`] resource, which multiplies all [`Val::Px`] values and font sizes in the UI at layout
+/// time. Changing it causes the UI to be re-laid out, so it can be hooked up to a 'UI size' setting.
+///
+/// Example:
+/**
+```rust
+#commands
+ScaleFactor(1.25)
+```
+*/
+#[derive(Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct ScaleFactor(pub f32);
+
+impl Command for ScaleFactor
+{
+    fn apply(self, world: &mut World)
+    {
+        let mut ui_scale = world.resource_mut::<UiScale>();
+        if ui_scale.0 != self.0 {
+            ui_scale.0 = self.0;
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction that scales a UI hierarchy relative to the global [`UiScale`].
+///
+/// All [`Val::Px`] dimensions of [`Node`] and font sizes in [`TextFont`] are multiplied by the scale factor for
+/// the node and its descendants. Nested `UiRootScale` factors are multiplied together. Grid track sizes are not
+/// scaled.
+///
+/// Like [`DirectionAware`], scaling is only applied for layout, so the node's components always contain the
+/// authored values. The scale can be changed at any time (e.g. from a settings menu) and the hierarchy will be
+/// re-laid out.
+///
+/// Example:
+/**
+```rust
+#scenes
+"hud"
+    FlexNode{width:100vw height:100vh}
+    UiRootScale(1.5)
+
+    "health"
+        FlexNode{width:200px height:20px}
+```
+*/
+#[derive(Component, Reflect, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct UiRootScale(pub f32);
+
+impl Default for UiRootScale
+{
+    fn default() -> Self
+    {
+        Self(1.)
+    }
+}
+
+impl Instruction for UiRootScale
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.insert(self);
+        });
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.remove::<Self>();
+        });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct UiScalePlugin;
+
+impl Plugin for UiScalePlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_resource::<RootScaledNodes>()
+            .register_command_type::<ScaleFactor>()
+            .register_instruction_type::<UiRootScale>()
+            .add_systems(
+                PostUpdate,
+                apply_root_scales
+                    .after(ControlSet)
+                    .after(DynamicStylePostUpdate)
+                    // Authored values are restored before un-mirroring.
+                    .after(mirror_direction_aware_nodes)
+                    // Safe-area insets are in window space, so they should not be scaled.
+                    .before(apply_safe_area_nodes)
+                    .before(UiSystem::Prepare),
+            )
+            // After rendering.
+            .add_systems(
+                First,
+                restore_root_scaled_nodes
+                    .after(restore_safe_area_nodes)
+                    .before(restore_direction_aware_nodes)
+                    // Loadables applied while processing files must not be overwritten.
+                    .before(FileProcessingSet),
+            );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,324 @@
+use std::hash::Hash;
+
+use bevy::prelude::*;
+use bevy::reflect::GetTypeRegistration;
+use bevy::render::render_resource::{AsBindGroup, RenderPipelineDescriptor};
+use bevy::ui::UiMaterialKey;
+
+use crate::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Inserts or updates the [`MaterialNode`] of an entity with material params.
+fn apply_ui_material<P: UiMaterialParams>(params: P, entity: Entity, world: &mut World)
+{
+    if !world.entities().contains(entity) {
+        return;
+    }
+
+    let material = params.to_material(world.resource::<AssetServer>());
+    let existing = world
+        .get::<MaterialNode<P::Material>>(entity)
+        .map(|m| m.0.clone());
+    let mut materials = world.resource_mut::<Assets<P::Material>>();
+    match existing {
+        Some(handle) => {
+            materials.insert(&handle, material);
+        }
+        None => {
+            let handle = materials.add(material);
+            world.entity_mut(entity).insert(MaterialNode(handle));
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Trait for loadable params that are converted to a [`UiMaterial`] on scene nodes.
+///
+/// Types that implement this trait are [`Instruction`]s that insert a [`MaterialNode`] with their material.
+/// Register them with [`register_ui_material`](UiMaterialRegistrationExt::register_ui_material).
+///
+/// Example:
+/**
+```rust
+#[derive(Asset, TypePath, AsBindGroup, Debug, Clone)]
+struct DissolveMaterial
+{
+    #[uniform(0)]
+    amount: f32,
+}
+
+impl UiMaterial for DissolveMaterial
+{
+    fn fragment_shader() -> ShaderRef
+    {
+        "shaders/dissolve.wgsl".into()
+    }
+}
+
+#[derive(Reflect, Default, Debug, Clone, PartialEq)]
+struct Dissolve
+{
+    amount: f32,
+}
+
+impl UiMaterialParams for Dissolve
+{
+    type Material = DissolveMaterial;
+
+    fn to_material(&self, _: &AssetServer) -> DissolveMaterial
+    {
+        DissolveMaterial { amount: self.amount }
+    }
+}
+
+app.register_ui_material::<Dissolve>();
+```
+*/
+///
+/// Then in a COB file:
+/**
+```rust
+#scenes
+"panel"
+    Dissolve{amount:0.5}
+```
+*/
+pub trait UiMaterialParams: Loadable + TypePath + GetTypeRegistration
+{
+    /// The material type produced by these params.
+    type Material: UiMaterial;
+
+    /// Makes a material from these params.
+    ///
+    /// Assets like shaders and images can be loaded with the asset server.
+    fn to_material(&self, asset_server: &AssetServer) -> Self::Material;
+}
+
+impl<P: UiMaterialParams> Instruction for P
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        apply_ui_material(self, entity, world);
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.remove::<MaterialNode<P::Material>>();
+        });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Helper methods for registering [`UiMaterialParams`].
+pub trait UiMaterialRegistrationExt
+{
+    /// Registers material params as a loadable instruction, and adds a [`UiMaterialPlugin`] for the material type
+    /// if it wasn't added yet.
+    fn register_ui_material<P: UiMaterialParams>(&mut self) -> &mut Self
+    where
+        <P::Material as AsBindGroup>::Data: PartialEq + Eq + Hash + Clone;
+}
+
+impl UiMaterialRegistrationExt for App
+{
+    fn register_ui_material<P: UiMaterialParams>(&mut self) -> &mut Self
+    where
+        <P::Material as AsBindGroup>::Data: PartialEq + Eq + Hash + Clone,
+    {
+        if !self.is_plugin_added::<UiMaterialPlugin<P::Material>>() {
+            self.add_plugins(UiMaterialPlugin::<P::Material>::default());
+        }
+        self.register_instruction_type::<P>()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Pipeline key for [`ShaderUiMaterial`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ShaderUiMaterialKey
+{
+    shader: Handle<Shader>,
+}
+
+impl From<&ShaderUiMaterial> for ShaderUiMaterialKey
+{
+    fn from(material: &ShaderUiMaterial) -> Self
+    {
+        Self { shader: material.shader.clone() }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Built-in [`UiMaterial`] for rendering nodes with a custom fragment shader.
+///
+/// Inserted by the [`UiShaderMaterial`] instruction.
+///
+/// The shader can use these bindings:
+/**
+```wgsl
+#import bevy_render::globals::Globals
+#import bevy_ui::ui_vertex_output::UiVertexOutput
+
+@group(0) @binding(1) var<uniform> globals: Globals;
+
+@group(1) @binding(0) var<uniform> color: vec4<f32>;
+@group(1) @binding(1) var<uniform> params: array<vec4<f32>, 4>;
+@group(1) @binding(2) var material_texture: texture_2d<f32>;
+@group(1) @binding(3) var material_sampler: sampler;
+
+@fragment
+fn fragment(in: UiVertexOutput) -> @location(0) vec4<f32> {
+    // Use `globals.time` for animations.
+    ...
+}
+```
+*/
+#[derive(Asset, TypePath, AsBindGroup, Debug, Clone)]
+#[bind_group_data(ShaderUiMaterialKey)]
+pub struct ShaderUiMaterial
+{
+    /// The fragment shader.
+    pub shader: Handle<Shader>,
+    /// A color for the shader to use.
+    #[uniform(0)]
+    pub color: LinearRgba,
+    /// Up to 16 float params, packed into vectors.
+    #[uniform(1)]
+    pub params: [Vec4; 4],
+    /// An optional texture. If `None`, a white texture is bound.
+    #[texture(2)]
+    #[sampler(3)]
+    pub texture: Option<Handle<Image>>,
+}
+
+impl UiMaterial for ShaderUiMaterial
+{
+    fn specialize(descriptor: &mut RenderPipelineDescriptor, key: UiMaterialKey<Self>)
+    {
+        if let Some(fragment) = descriptor.fragment.as_mut() {
+            fragment.shader = key.bind_group_data.shader;
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction that renders a node with a custom fragment shader.
+///
+/// Inserts a [`ShaderUiMaterial`] to the entity (see its docs for the shader bindings). Shaders are reloaded
+/// automatically by bevy when their files change if the `hot_reload` feature is enabled.
+///
+/// Use [`UiMaterialParams`] for materials with typed params.
+///
+/// Example:
+/**
+```rust
+#scenes
+"scanlines"
+    FlexNode{width:100% height:100%}
+    UiShaderMaterial{shader:"shaders/scanlines.wgsl" color:#00FF00 params:[120 0.25]}
+```
+*/
+#[derive(Reflect, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct UiShaderMaterial
+{
+    /// Asset path of the fragment shader.
+    pub shader: String,
+    /// Passed to the `color` binding.
+    ///
+    /// Defaults to white.
+    #[reflect(default = "UiShaderMaterial::default_color")]
+    pub color: Color,
+    /// Passed to the `params` binding.
+    ///
+    /// Up to 16 params are supported. They are packed into four vectors, so `params[5]` is `params[1].y` in the
+    /// shader. Missing params are set to zero.
+    #[reflect(default)]
+    pub params: Vec<f32>,
+    /// Asset path of an optional texture, passed to the `material_texture` binding.
+    #[reflect(default)]
+    pub texture: Option<String>,
+}
+
+impl UiShaderMaterial
+{
+    /// The maximum number of params.
+    pub const MAX_PARAMS: usize = 16;
+
+    /// Packs [`Self::params`] into vectors.
+    pub fn packed_params(&self) -> [Vec4; 4]
+    {
+        if self.params.len() > Self::MAX_PARAMS {
+            tracing::warn!(
+                "ignoring {} extra params in UiShaderMaterial for {:?}; only {} are supported",
+                self.params.len() - Self::MAX_PARAMS,
+                self.shader,
+                Self::MAX_PARAMS
+            );
+        }
+
+        let mut packed = [Vec4::ZERO; 4];
+        for (idx, param) in self.params.iter().take(Self::MAX_PARAMS).enumerate() {
+            packed[idx / 4][idx % 4] = *param;
+        }
+        packed
+    }
+
+    fn default_color() -> Color
+    {
+        Color::WHITE
+    }
+}
+
+impl Default for UiShaderMaterial
+{
+    fn default() -> Self
+    {
+        Self {
+            shader: String::default(),
+            color: Self::default_color(),
+            params: Vec::default(),
+            texture: None,
+        }
+    }
+}
+
+impl UiMaterialParams for UiShaderMaterial
+{
+    type Material = ShaderUiMaterial;
+
+    fn to_material(&self, asset_server: &AssetServer) -> ShaderUiMaterial
+    {
+        ShaderUiMaterial {
+            shader: asset_server.load(&self.shader),
+            color: self.color.to_linear(),
+            params: self.packed_params(),
+            texture: self.texture.as_ref().map(|t| asset_server.load(t)),
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct UiShaderMaterialPlugin;
+
+impl Plugin for UiShaderMaterialPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.register_ui_material::<UiShaderMaterial>();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,259 @@
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+
+use crate::prelude::*;
+use crate::sickle::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Positions nodes with [`ViewportAnchor`] relative to their camera's viewport.
+///
+/// Runs every frame so nodes follow viewport changes (e.g. when the window is resized or split-screen viewports
+/// are rearranged). Nodes are only changed when their position changes.
+fn update_viewport_anchors(
+    mut c: Commands,
+    ui_camera: DefaultUiCamera,
+    ui_scale: Res<UiScale>,
+    cameras: Query<(Entity, &Camera, Option<&Name>)>,
+    mut nodes: Query<(
+        Entity,
+        &ViewportAnchor,
+        Option<&TargetCamera>,
+        &ComputedNode,
+        &mut Node,
+        &mut Visibility,
+        Has<ViewportAnchorHidden>,
+    )>,
+)
+{
+    for (entity, anchor, maybe_target, computed, mut node, mut visibility, hidden) in nodes.iter_mut() {
+        let update = (|| {
+            let ui_camera_entity = maybe_target.map(|c| c.entity()).or_else(|| ui_camera.get())?;
+            let (_, ui_camera, _) = cameras.get(ui_camera_entity).ok()?;
+
+            let (_, camera, _) = match anchor.camera.as_str() {
+                "" => cameras.get(ui_camera_entity).ok()?,
+                name => cameras
+                    .iter()
+                    .find(|(_, _, n)| n.is_some_and(|n| n.as_str() == name))?,
+            };
+            if !camera.is_active {
+                return None;
+            }
+
+            // Positions are relative to the viewport that the node is rendered in.
+            let viewport = camera.logical_viewport_rect()?;
+            let ui_origin = ui_camera
+                .logical_viewport_rect()
+                .map(|r| r.min)
+                .unwrap_or_default();
+            let viewport = Rect::from_corners(viewport.min - ui_origin, viewport.max - ui_origin);
+            let size = computed.size() * computed.inverse_scale_factor() * ui_scale.0;
+            Some(anchor.node_position(viewport, size, ui_scale.0))
+        })();
+
+        // Only visibility changed by this system is restored, so users can still hide anchored nodes.
+        let Some(position) = update else {
+            if !hidden && *visibility != Visibility::Hidden {
+                *visibility = Visibility::Hidden;
+                c.entity(entity).insert(ViewportAnchorHidden);
+            }
+            continue;
+        };
+        if hidden {
+            *visibility = Visibility::Inherited;
+            c.entity(entity).remove::<ViewportAnchorHidden>();
+        }
+
+        let left = Val::Px(position.x);
+        let top = Val::Px(position.y);
+        if node.position_type != PositionType::Absolute || node.left != left || node.top != top {
+            node.position_type = PositionType::Absolute;
+            node.left = left;
+            node.top = top;
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Marks [`ViewportAnchor`] nodes that were hidden because their camera is missing or inactive.
+#[derive(Component)]
+struct ViewportAnchorHidden;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Anchor point on a camera's viewport where a [`ViewportAnchor`] node should be positioned.
+///
+/// The same point on the node is placed on the anchor point. For example, with `BottomCenter` the bottom-center
+/// of the node is placed on the bottom-center of the viewport.
+#[derive(Reflect, Default, Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum ViewportAnchorPoint
+{
+    /// Top-left corner of the viewport.
+    #[default]
+    TopLeft,
+    /// Top-center point on the viewport.
+    TopCenter,
+    /// Top-right corner of the viewport.
+    TopRight,
+    /// Left-center point on the viewport.
+    LeftCenter,
+    /// The center of the viewport.
+    Center,
+    /// Right-center point on the viewport.
+    RightCenter,
+    /// Bottom-left corner of the viewport.
+    BottomLeft,
+    /// Bottom-center point on the viewport.
+    BottomCenter,
+    /// Bottom-right corner of the viewport.
+    BottomRight,
+}
+
+impl ViewportAnchorPoint
+{
+    /// Returns the anchor point as a fraction of a rect's size, measured from its top-left corner.
+    pub fn fraction(&self) -> Vec2
+    {
+        match self {
+            Self::TopLeft => Vec2::new(0., 0.),
+            Self::TopCenter => Vec2::new(0.5, 0.),
+            Self::TopRight => Vec2::new(1., 0.),
+            Self::LeftCenter => Vec2::new(0., 0.5),
+            Self::Center => Vec2::new(0.5, 0.5),
+            Self::RightCenter => Vec2::new(1., 0.5),
+            Self::BottomLeft => Vec2::new(0., 1.),
+            Self::BottomCenter => Vec2::new(0.5, 1.),
+            Self::BottomRight => Vec2::new(1., 1.),
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Instruction that positions a UI node relative to a specific camera's viewport (e.g. for split-screen HUDs).
+///
+/// The node is placed with absolute positioning so that its [`Self::anchor`] point lines up with the same point
+/// on the viewport of the camera named [`Self::camera`], then shifted by [`Self::offset`]. The position is
+/// recomputed when the viewport or the node's size changes.
+///
+/// The node is positioned relative to the viewport of the camera it is rendered with (its [`TargetCamera`] or the
+/// default UI camera), so it should be a root node or inside a parent that covers that viewport. Both cameras
+/// should render to the same window. The node is hidden while the anchor camera is missing or inactive.
+///
+/// Example:
+/**
+```rust
+#scenes
+"p2_hud"
+    ViewportAnchor{ camera: "p2" anchor: BottomCenter offset: (0 -20) }
+    FlexNode{ padding: {left: 10px right: 10px} }
+    BackgroundColor(#80000000)
+
+    "ammo"
+        TextLine{ text: "30/90" }
+```
+*/
+#[derive(Component, Reflect, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct ViewportAnchor
+{
+    /// [`Name`] of the camera whose viewport the node is anchored to.
+    ///
+    /// Defaults to empty, which anchors to the viewport of the camera the node is rendered with.
+    #[reflect(default)]
+    pub camera: String,
+    /// The point on the viewport and on the node that are lined up.
+    ///
+    /// Defaults to [`ViewportAnchorPoint::TopLeft`].
+    #[reflect(default)]
+    pub anchor: ViewportAnchorPoint,
+    /// Offset from the anchored position in logical pixels.
+    ///
+    /// Defaults to zero.
+    #[reflect(default)]
+    pub offset: Vec2,
+}
+
+impl ViewportAnchor
+{
+    pub fn new(camera: impl Into<String>, anchor: ViewportAnchorPoint) -> Self
+    {
+        Self { camera: camera.into(), anchor, ..default() }
+    }
+
+    pub fn with_offset(mut self, offset: Vec2) -> Self
+    {
+        self.offset = offset;
+        self
+    }
+
+    /// Computes the position of a node's top-left corner when anchored to `viewport`, for use in
+    /// `Node::left`/`Node::top`.
+    ///
+    /// `viewport` and `node_size` are in logical pixels. The result is divided by `ui_scale` because `Val::Px`
+    /// values are multiplied by [`UiScale`].
+    pub fn node_position(&self, viewport: Rect, node_size: Vec2, ui_scale: f32) -> Vec2
+    {
+        let fraction = self.anchor.fraction();
+        (viewport.min + viewport.size() * fraction - node_size * fraction + self.offset) / ui_scale
+    }
+}
+
+impl Instruction for ViewportAnchor
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.insert(self);
+        });
+    }
+
+    fn revert(entity: Entity, world: &mut World)
+    {
+        let _ = world.get_entity_mut(entity).map(|mut e| {
+            e.remove::<Self>();
+            if let Some(mut node) = e.get_mut::<Node>() {
+                node.left = Val::Auto;
+                node.top = Val::Auto;
+            }
+            if e.take::<ViewportAnchorHidden>().is_some() {
+                if let Some(mut visibility) = e.get_mut::<Visibility>() {
+                    *visibility = Visibility::Inherited;
+                }
+            }
+        });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct ViewportAnchorPlugin;
+
+impl Plugin for ViewportAnchorPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.register_type::<ViewportAnchorPoint>()
+            .register_instruction_type::<ViewportAnchor>()
+            .add_systems(
+                PostUpdate,
+                update_viewport_anchors
+                    .after(ControlSet)
+                    .after(DynamicStylePostUpdate)
+                    .before(UiSystem::Prepare),
+            );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
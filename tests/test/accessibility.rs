@@ -0,0 +1,52 @@
+use accesskit::{Role, Toggled};
+use bevy_cobweb_ui::prelude::*;
+use bevy_cobweb_ui::sickle::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn accessible_role_state()
+{
+    let mut node = accesskit::Node::new(Role::Unknown);
+    let states = PseudoStates::from(vec![PseudoState::Checked, PseudoState::Disabled]);
+    AccessibleRole::Checkbox.update_node(&mut node, Some("Mute"), None, Some(&states));
+    assert_eq!(node.role(), Role::CheckBox);
+    assert_eq!(node.label(), Some("Mute"));
+    assert_eq!(node.toggled(), Some(Toggled::True));
+    assert!(node.is_disabled());
+
+    // Radio buttons are checked when selected.
+    let states = PseudoStates::from(vec![PseudoState::Selected]);
+    AccessibleRole::RadioButton.update_node(&mut node, None, None, Some(&states));
+    assert_eq!(node.label(), Some("Mute"));
+    assert_eq!(node.toggled(), Some(Toggled::True));
+    assert_eq!(node.is_selected(), None);
+    assert!(!node.is_disabled());
+
+    // Other roles are selected.
+    AccessibleRole::Tab.update_node(&mut node, None, None, Some(&states));
+    assert_eq!(node.toggled(), None);
+    assert_eq!(node.is_selected(), Some(true));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn accessible_role_value()
+{
+    let mut node = accesskit::Node::new(Role::Unknown);
+    let value = AccessibleValue::Number { value: 0.25, min: 0., max: 1. };
+    AccessibleRole::Slider.update_node(&mut node, None, Some(&value), None);
+    assert_eq!(node.numeric_value(), Some(0.25));
+    assert_eq!(node.max_numeric_value(), Some(1.));
+
+    let value = AccessibleValue::Text("hello".into());
+    AccessibleRole::TextInput.update_node(&mut node, None, Some(&value), None);
+    assert_eq!(node.numeric_value(), None);
+    assert_eq!(node.value(), Some("hello"));
+
+    AccessibleRole::PasswordInput.update_node(&mut node, None, None, None);
+    assert_eq!(node.value(), None);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
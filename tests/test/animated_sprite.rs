@@ -0,0 +1,30 @@
+use bevy_cobweb_ui::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn animated_sprite_frames()
+{
+    // All frames in the layout.
+    assert_eq!(AnimatedSpriteFrames::All.len(4), 4);
+    assert_eq!(AnimatedSpriteFrames::All.index(3, 4), Some(3));
+    assert_eq!(AnimatedSpriteFrames::All.index(4, 4), None);
+
+    // Ranges are clamped to the layout.
+    let range = AnimatedSpriteFrames::Range(2, 6);
+    assert_eq!(range.len(4), 2);
+    assert_eq!(range.index(0, 4), Some(2));
+    assert_eq!(range.index(1, 4), Some(3));
+    assert_eq!(range.index(2, 4), None);
+    assert_eq!(AnimatedSpriteFrames::Range(5, 2).len(8), 0);
+
+    // Lists skip indices outside the layout.
+    let list = AnimatedSpriteFrames::List(vec![3, 9, 0, 3]);
+    assert_eq!(list.len(4), 3);
+    assert_eq!(list.index(0, 4), Some(3));
+    assert_eq!(list.index(1, 4), Some(0));
+    assert_eq!(list.index(2, 4), Some(3));
+    assert_eq!(list.index(3, 4), None);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,66 @@
+use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
+use bevy_cobweb_ui::prelude::*;
+use bevy_cobweb_ui::sickle::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource, Default)]
+struct Completions(Vec<InteractionStyle>);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn animation_complete_fires_once_on_hold()
+{
+    let mut app = cobweb_test_app(&[]);
+    app.init_resource::<Completions>();
+    wait_for_load(&mut app);
+
+    let world = app.world_mut();
+    let entity = world.spawn(Node::default()).id();
+    world.react(|rc| {
+        rc.on_persistent(
+            entity_event::<AnimationComplete<Width>>(entity),
+            |event: EntityEvent<AnimationComplete<Width>>, mut completions: ResMut<Completions>| {
+                let (_, complete) = event.read();
+                completions.0.push(complete.style());
+            },
+        )
+    });
+    let fade = AnimationConfig { duration: 0.05, ..default() };
+    Instruction::apply(
+        Animated::<Width> {
+            enter_ref_override: Some(Val::Px(0.)),
+            idle: Val::Px(100.),
+            enter_idle_with: Some(fade.clone()),
+            hover: Some(Val::Px(200.)),
+            hover_with: Some(fade),
+            ..default()
+        },
+        entity,
+        world,
+    );
+
+    // The enter animation completes once when it settles on the idle value.
+    update_frames(&mut app, 10);
+    assert_eq!(app.world().get::<Node>(entity).unwrap().width, Val::Px(100.));
+    assert_eq!(app.world().resource::<Completions>().0, vec![InteractionStyle::Idle]);
+
+    // Holding the value doesn't emit more events.
+    update_frames(&mut app, 10);
+    assert_eq!(app.world().resource::<Completions>().0.len(), 1);
+
+    // Transitions to other values complete once too.
+    *app.world_mut().get_mut::<FluxInteraction>(entity).unwrap() = FluxInteraction::PointerEnter;
+    update_frames(&mut app, 20);
+    assert_eq!(app.world().get::<Node>(entity).unwrap().width, Val::Px(200.));
+    assert_eq!(
+        app.world().resource::<Completions>().0,
+        vec![InteractionStyle::Idle, InteractionStyle::Hover]
+    );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
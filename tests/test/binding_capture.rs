@@ -0,0 +1,16 @@
+use bevy::prelude::*;
+use bevy_cobweb_ui::builtin::widgets::binding_capture::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn captured_input_labels()
+{
+    assert_eq!(CapturedInput::Key(KeyCode::KeyA).label(), "A");
+    assert_eq!(CapturedInput::Key(KeyCode::Digit1).label(), "1");
+    assert_eq!(CapturedInput::Key(KeyCode::Escape).label(), "Escape");
+    assert_eq!(CapturedInput::Key(KeyCode::ShiftLeft).label(), "ShiftLeft");
+    assert_eq!(CapturedInput::GamepadButton(GamepadButton::South).label(), "South");
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,24 @@
+use bevy_cobweb_ui::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn breakpoint_ranges()
+{
+    let breakpoints = Breakpoints {
+        source: BreakpointSource::Window,
+        breakpoints: vec![
+            Breakpoint { name: "small".into(), min_width: None, max_width: Some(600.) },
+            Breakpoint { name: "medium".into(), min_width: Some(600.), max_width: Some(1200.) },
+            Breakpoint { name: "large".into(), min_width: Some(1200.), max_width: None },
+            Breakpoint { name: "any".into(), min_width: None, max_width: None },
+        ],
+    };
+
+    assert_eq!(breakpoints.active(0.).collect::<Vec<_>>(), vec!["small", "any"]);
+    assert_eq!(breakpoints.active(599.9).collect::<Vec<_>>(), vec!["small", "any"]);
+    assert_eq!(breakpoints.active(600.).collect::<Vec<_>>(), vec!["medium", "any"]);
+    assert_eq!(breakpoints.active(1200.).collect::<Vec<_>>(), vec!["large", "any"]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,52 @@
+use bevy_cobweb_ui::prelude::cob::*;
+
+use super::helpers::test_cob;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn binary_round_trip()
+{
+    let cob = test_cob(
+        b"#manifest
+self as a
+\"ui/**\" as ui
+
+#import
+b as b
+
+#defs
+$c = 10
++m = \\
+    BorderColor(#FF0000)
+    Width(10px)
+\\
+
+#commands
+RegisterThing{x:1.5 y:-3 z:\"text {x}\" w:[1 2 3] v:(1, true) u:{a: none}}
+
+#scenes
+\"scene\" // comment
+    FlexNode{width:Px(100) justify_main:Center}
+    \"child\"
+        +m{}
+",
+    );
+
+    let bytes = cob.to_binary().unwrap();
+    assert_eq!(&bytes[..4], b"COBB");
+    assert_eq!(Cob::from_binary(&bytes).unwrap(), cob);
+
+    // Invalid data
+    assert!(matches!(Cob::from_binary(b"COB"), Err(CobBinaryError::InvalidHeader)));
+    assert!(matches!(Cob::from_binary(b"COBB"), Err(CobBinaryError::InvalidHeader)));
+    assert!(Cob::from_binary(&bytes[..(bytes.len() - 1)]).is_err());
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert!(matches!(Cob::from_binary(&trailing), Err(CobBinaryError::Bincode(_))));
+    let mut old_version = bytes.clone();
+    old_version[4] = 0;
+    assert!(matches!(Cob::from_binary(&old_version), Err(CobBinaryError::VersionMismatch { .. })));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,54 @@
+use bevy_cobweb_ui::prelude::cob::*;
+
+use super::helpers::{test_cob, test_cob_fail};
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn bind_values()
+{
+    let res = test_cob(
+        b"
+#scenes
+\"a\"
+    TextLine{ text: @bind(PlayerName) }
+    TextLineColor(@bind(ui::HealthColor))
+",
+    );
+    let CobSection::Scenes(scenes) = &res.sections[0] else { unreachable!() };
+    let CobSceneLayerEntry::Loadable(loadable) = &scenes.scenes[0].entries[0] else { unreachable!() };
+    let CobLoadableVariant::Map(map) = &loadable.variant else { unreachable!() };
+    let CobMapEntry::KeyValue(keyvalue) = &map.entries[0] else { unreachable!() };
+    let CobValue::Bind(bind) = &keyvalue.value else { unreachable!() };
+    assert_eq!(bind.source.as_str(), "PlayerName");
+
+    let CobSceneLayerEntry::Loadable(loadable) = &scenes.scenes[0].entries[1] else { unreachable!() };
+    let CobLoadableVariant::Tuple(tuple) = &loadable.variant else { unreachable!() };
+    let CobValue::Bind(bind) = &tuple.entries[0] else { unreachable!() };
+    assert_eq!(bind.source.as_str(), "ui::HealthColor");
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn bind_errors()
+{
+    // Missing source
+    test_cob_fail(
+        b"#scenes
+\"a\"
+    A(@bind())
+",
+        b"@bind())\n",
+    );
+    // Missing closing paren
+    test_cob_fail(
+        b"#scenes
+\"a\"
+    A(@bind(a b))
+",
+        b"@bind(a b))\n",
+    );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -46,6 +46,26 @@ self as a.b
     assert_eq!(manifest.entries[0].key, ManifestKey(Arc::from("a.b")));
     assert_eq!(manifest.entries[1].file, CobManifestFile::File(CobFile::try_new("path/to/b.cob").unwrap()));
     assert_eq!(manifest.entries[1].key, ManifestKey(Arc::from("a.b.c")));
+
+    let res = test_cob(
+        b"#manifest
+self as a
+\"b.cob\" as b priority 10
+\"c.cob\" as c priority -2
+asset \"textures/logo.png\"
+asset \"audio/theme.ogg\" weight 2.5
+",
+    );
+    let CobSection::Manifest(manifest) = &res.sections[0] else { unreachable!() };
+    assert_eq!(manifest.entries.len(), 3);
+    assert_eq!(manifest.entries[0].priority(), 0);
+    assert_eq!(manifest.entries[1].priority(), 10);
+    assert_eq!(manifest.entries[2].priority(), -2);
+    assert_eq!(manifest.assets.len(), 2);
+    assert_eq!(&*manifest.assets[0].path, "textures/logo.png");
+    assert_eq!(&*manifest.assets[1].path, "audio/theme.ogg");
+    assert_eq!(manifest.assets[0].weight(), 1.);
+    assert_eq!(manifest.assets[1].weight(), 2.5);
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -115,6 +135,72 @@ self as .a",
 self as a..b",
         b"..b",
     );
+    // Priority on new line
+    test_cob_fail(
+        b"#manifest
+self as a
+priority 1",
+        b"priority 1",
+    );
+    // Asset dependency that is a COB file
+    test_cob_fail(
+        b"#manifest
+asset \"a.cob\"",
+        b"\"a.cob\"",
+    );
+    // Negative asset weight
+    test_cob_fail(
+        b"#manifest
+asset \"a.png\" weight -1",
+        b"-1",
+    );
+    // File entry after asset entry
+    test_cob_fail(
+        b"#manifest
+asset \"a.png\"
+self as a",
+        b"self as a",
+    );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn manifest_dirs()
+{
+    let res = test_cob(
+        b"#manifest
+\"ui/**\" as ui
+\"menus/*\" as menus
+\"**\" as root
+",
+    );
+    let CobSection::Manifest(manifest) = &res.sections[0] else { unreachable!() };
+    assert_eq!(manifest.entries.len(), 3);
+    assert_eq!(manifest.entries[0].file, CobManifestFile::Dir(CobDirPattern::new("ui", true)));
+    assert_eq!(manifest.entries[1].file, CobManifestFile::Dir(CobDirPattern::new("menus", false)));
+    assert_eq!(manifest.entries[2].file, CobManifestFile::Dir(CobDirPattern::new("", true)));
+
+    // Manifest keys of files in directories
+    let pattern = CobDirPattern::new("ui", true);
+    let prefix = ManifestKey::new("ui");
+    let key = |file: &str| pattern.manifest_key(&prefix, &CobFile::try_new(file).unwrap());
+    assert_eq!(key("ui/main.cob"), Some(ManifestKey::new("ui.main")));
+    assert_eq!(key("ui/menus/Main-Menu.cob"), Some(ManifestKey::new("ui.menus.main_menu")));
+    assert_eq!(key("ui/2d/main.cob"), None);
+    assert_eq!(key("other/main.cob"), None);
+
+    // Directory patterns
+    assert_eq!(CobDirPattern::try_from_pattern("ui"), None);
+    assert_eq!(CobDirPattern::try_from_pattern("ui*"), None);
+    assert_eq!(CobDirPattern::try_from_pattern("ui/*/a/*"), None);
+
+    // Directory pattern without a manifest key
+    test_cob_fail(
+        b"#manifest
+\"ui/**\"",
+        b"",
+    );
 }
 
 //-------------------------------------------------------------------------------------------------------------------
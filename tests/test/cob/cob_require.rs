@@ -0,0 +1,74 @@
+use bevy_cobweb_ui::prelude::cob::*;
+use smol_str::SmolStr;
+
+use super::helpers::{test_cob, test_cob_fail};
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn require_section()
+{
+    let res = test_cob(
+        b"#require
+",
+    );
+    let CobSection::Require(require) = &res.sections[0] else { unreachable!() };
+    assert_eq!(require.entries.len(), 0);
+
+    let res = test_cob(
+        b"#require
+\"cob_material_icons\" >= 1.2
+\"a\" ^0.3 // comment
+\"b\"   >=1.2, <2
+",
+    );
+    let CobSection::Require(require) = &res.sections[0] else { unreachable!() };
+    assert_eq!(require.entries.len(), 3);
+    assert_eq!(require.entries[0].library, SmolStr::from("cob_material_icons"));
+    assert_eq!(require.entries[0].requirement, SmolStr::from(">= 1.2"));
+    assert!(require.entries[0]
+        .version_req()
+        .unwrap()
+        .matches(&semver::Version::new(1, 3, 0)));
+    assert_eq!(require.entries[1].library, SmolStr::from("a"));
+    assert_eq!(require.entries[1].requirement, SmolStr::from("^0.3"));
+    assert_eq!(require.entries[2].library, SmolStr::from("b"));
+    assert_eq!(require.entries[2].requirement, SmolStr::from(">=1.2, <2"));
+    assert!(!require.entries[2]
+        .version_req()
+        .unwrap()
+        .matches(&semver::Version::new(2, 0, 0)));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn require_errors()
+{
+    // Entry not starting with newline
+    test_cob_fail(
+        b"#require
+ \"a\" >= 1.2",
+        b"\"a\" >= 1.2",
+    );
+    // No fill before requirement
+    test_cob_fail(
+        b"#require
+\"a\">=1.2",
+        b">=1.2",
+    );
+    // Invalid library name
+    test_cob_fail(
+        b"#require
+\"a b\" >= 1.2",
+        b"\"a b\" >= 1.2",
+    );
+    // Invalid requirement
+    test_cob_fail(
+        b"#require
+\"a\" >= x",
+        b">= x",
+    );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -1,6 +1,8 @@
+use bevy::prelude::*;
 use bevy_cobweb_ui::prelude::cob::*;
+use bevy_cobweb_ui::prelude::*;
 
-use super::helpers::{test_cob, test_cob_fail};
+use super::helpers::{test_cob, test_cob_fail, PlainStruct, StringStruct};
 
 //-------------------------------------------------------------------------------------------------------------------
 
@@ -79,6 +81,75 @@ fn scenes_section()
 
 //-------------------------------------------------------------------------------------------------------------------
 
+#[test]
+fn scenes_extends()
+{
+    let res = test_cob(
+        b"#scenes
+\"a\"
+    A
+    \"x\"
+\"b\" extends \"a\"
+    B
+    -A
+    \"x\"
+        C
+\"c\"  extends  widgets::buttons::\"b\"
+",
+    );
+    let CobSection::Scenes(scenes) = &res.sections[0] else { unreachable!() };
+    assert_eq!(scenes.scenes.len(), 3);
+    assert!(scenes.scenes[0].extends.is_none());
+
+    let scene = &scenes.scenes[1];
+    assert_eq!(scene.name.as_str(), "b");
+    assert_eq!(scene.extends.as_ref().unwrap().path.as_str(), "a");
+    assert_eq!(scene.entries.len(), 3);
+    let CobSceneLayerEntry::SceneMacroCommand(command) = &scene.entries[1] else { unreachable!() };
+    assert_eq!(command.command_type, CobSceneMacroCommandType::Remove);
+
+    let scene = &scenes.scenes[2];
+    assert_eq!(scene.name.as_str(), "c");
+    assert_eq!(scene.extends.as_ref().unwrap().path.as_str(), "widgets::buttons::b");
+    assert_eq!(scene.entries.len(), 0);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn scenes_patch()
+{
+    let res = test_cob(
+        b"#scenes
+\"a\"
+    A
+patch some.file::\"a\"
+    B
+    \"x\"
+        \"y\"
+            C
+patch  other::\"b\"
+",
+    );
+    let CobSection::Scenes(scenes) = &res.sections[0] else { unreachable!() };
+    assert_eq!(scenes.scenes.len(), 3);
+    assert!(scenes.scenes[0].patch.is_none());
+
+    let scene = &scenes.scenes[1];
+    assert_eq!(scene.name.as_str(), "a");
+    assert_eq!(scene.patch.as_ref().unwrap().key.as_str(), "some.file");
+    assert_eq!(scene.entries.len(), 2);
+    let CobSceneLayerEntry::Layer(layer) = &scene.entries[1] else { unreachable!() };
+    assert_eq!(layer.name.as_str(), "x");
+
+    let scene = &scenes.scenes[2];
+    assert_eq!(scene.name.as_str(), "b");
+    assert_eq!(scene.patch.as_ref().unwrap().key.as_str(), "other");
+    assert_eq!(scene.entries.len(), 0);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 #[test]
 fn scenes_errors()
 {
@@ -113,6 +184,44 @@ A
 ",
         b"A\n",
     );
+    // Extending scene from a non-root node
+    test_cob_fail(
+        b"#scenes
+\"a\"
+    \"b\" extends \"c\"
+",
+        b"\"b\" extends \"c\"\n",
+    );
+    // Base scene on a separate line
+    test_cob_fail(
+        b"#scenes
+\"a\" extends
+\"c\"
+",
+        b"\"c\"\n",
+    );
+    // Patching from a non-root node
+    test_cob_fail(
+        b"#scenes
+\"a\"
+    patch some.file::\"b\"
+",
+        b"patch some.file::\"b\"\n",
+    );
+    // Patch without a scene name
+    test_cob_fail(
+        b"#scenes
+patch some.file::
+",
+        b"\n",
+    );
+    // Patch that extends another scene
+    test_cob_fail(
+        b"#scenes
+patch some.file::\"a\" extends \"b\"
+",
+        b"patch some.file::\"a\" extends \"b\"\n",
+    );
     // Entries stacked up each other
     test_cob_fail(
         b"#scenes
@@ -124,3 +233,32 @@ A
 }
 
 //-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn scenes_serialize_entity_tree()
+{
+    let mut app = App::new();
+    app.register_component_type::<PlainStruct>()
+        .register_component_type::<StringStruct>();
+
+    let world = app.world_mut();
+    let root = world
+        .spawn((Name::new("menu"), PlainStruct { boolean: true }))
+        .id();
+    let child_a = world.spawn(StringStruct("hi".into())).id();
+    let child_b = world.spawn(Name::new("my button")).id();
+    world
+        .entity_mut(root)
+        .add_children(&[child_a, child_b]);
+
+    let layer = SceneBuilder::serialize_entity_tree(app.world(), root);
+    let mut buff = Vec::<u8>::default();
+    let mut serializer = DefaultRawSerializer::new(&mut buff);
+    layer.write_to(&mut serializer).unwrap();
+    assert_eq!(
+        String::from_utf8(buff).unwrap(),
+        "\n\"menu\"\n    PlainStruct{boolean:true}\n    \"0\"\n        StringStruct(\"hi\")\n    \"my_button\""
+    );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
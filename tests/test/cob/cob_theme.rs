@@ -0,0 +1,61 @@
+use bevy_cobweb_ui::prelude::cob::*;
+
+use super::helpers::{test_cob, test_cob_fail};
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn theme_section()
+{
+    let res = test_cob(
+        b"#theme
+",
+    );
+    let CobSection::Theme(theme) = &res.sections[0] else { unreachable!() };
+    assert_eq!(theme.entries.len(), 0);
+
+    let res = test_cob(
+        b"
+#theme
+$bg = {light:#FFFFFF dark:#111111}
+$gap = {light:10px dark:12px}
+
+#scenes
+\"a\"
+    BackgroundColor(ThemeToken(\"bg\"))
+",
+    );
+    let CobSection::Theme(theme) = &res.sections[0] else { unreachable!() };
+    assert_eq!(theme.entries.len(), 2);
+    assert_eq!(theme.entries[0].name.as_str(), "bg");
+    let CobConstantValue::Value(CobValue::Map(map)) = &theme.entries[0].value else { unreachable!() };
+    assert_eq!(map.entries.len(), 2);
+    assert_eq!(theme.entries[1].name.as_str(), "gap");
+
+    let CobSection::Scenes(scenes) = &res.sections[1] else { unreachable!() };
+    let CobSceneLayerEntry::Loadable(loadable) = &scenes.scenes[0].entries[0] else { unreachable!() };
+    let CobLoadableVariant::Tuple(tuple) = &loadable.variant else { unreachable!() };
+    let CobValue::Enum(token) = &tuple.entries[0] else { unreachable!() };
+    assert_eq!(token.theme_token_path(), Some("bg"));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn theme_errors()
+{
+    // Section not starting on newline
+    test_cob_fail(
+        b" #theme
+",
+        b"#theme\n",
+    );
+    // Entry not starting on newline
+    test_cob_fail(
+        b"#theme $a = {light:1}
+",
+        b"$a = {light:1}\n",
+    );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
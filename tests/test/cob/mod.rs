@@ -1,10 +1,15 @@
 pub mod helpers;
 
+#[cfg(feature = "precompiled_cob")]
+mod cob_binary;
+mod cob_bind;
 mod cob_commands;
 mod cob_constants;
 mod cob_fill;
 mod cob_import;
 mod cob_manifest;
+mod cob_require;
 mod cob_scene_macros;
 mod cob_scenes;
+mod cob_theme;
 mod serde;
@@ -0,0 +1,101 @@
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
+use bevy_cobweb_ui::prelude::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(ReactResource, Default)]
+struct Grow(f32);
+
+#[derive(ReactComponent)]
+struct Shrink(f32);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn spawn_item(app: &mut App, parent: Entity) -> Entity
+{
+    app.world_mut()
+        .run_system_once(move |mut c: Commands, mut s: SceneBuilder| {
+            let mut item = Entity::PLACEHOLDER;
+            c.entity(parent)
+                .spawn_scene_and_edit(("cob_bindings.cob", "item"), &mut s, |h| {
+                    item = h.id();
+                });
+            item
+        })
+        .unwrap()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn flex(app: &App, entity: Entity) -> Option<(f32, f32)>
+{
+    let node = app.world().get::<Node>(entity)?;
+    Some((node.flex_grow, node.flex_shrink))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn bindings_refresh_on_source_changes()
+{
+    let mut app = cobweb_test_app(&["cob_bindings.cob"]);
+    app.init_react_resource::<Grow>()
+        .register_resource_binding("Grow", |g: &Grow| g.0)
+        .register_component_binding("Shrink", |s: &Shrink| s.0);
+    wait_for_load(&mut app);
+
+    let first_parent = app.world_mut().spawn(Node::default()).id();
+    let second_parent = app.world_mut().spawn(Node::default()).id();
+    let first = spawn_item(&mut app, first_parent);
+    let second = spawn_item(&mut app, second_parent);
+    app.update();
+
+    // Loadables wait until all of their sources have data.
+    assert_eq!(flex(&app, first), None);
+    app.world_mut()
+        .run_system_once(move |mut c: Commands| {
+            c.react().insert(first_parent, Shrink(2.));
+            c.react().insert(second_parent, Shrink(3.));
+        })
+        .unwrap();
+    app.update();
+    assert_eq!(flex(&app, first), Some((0., 2.)));
+    assert_eq!(flex(&app, second), Some((0., 3.)));
+
+    // Resource mutations refresh all bound entities.
+    app.world_mut()
+        .run_system_once(|mut c: Commands, mut grow: ReactResMut<Grow>| {
+            grow.get_mut(&mut c).0 = 1.;
+        })
+        .unwrap();
+    app.update();
+    assert_eq!(flex(&app, first), Some((1., 2.)));
+    assert_eq!(flex(&app, second), Some((1., 3.)));
+
+    // Component mutations only refresh entities that get data from the mutated component.
+    app.world_mut().get_mut::<Node>(second).unwrap().flex_grow = 5.;
+    app.world_mut()
+        .run_system_once(move |mut c: Commands, mut shrink: Query<&mut React<Shrink>>| {
+            shrink.get_mut(first_parent).unwrap().get_mut(&mut c).0 = 4.;
+        })
+        .unwrap();
+    app.update();
+    assert_eq!(flex(&app, first), Some((1., 4.)));
+    assert_eq!(flex(&app, second), Some((5., 3.)));
+
+    // Despawned entities are no longer refreshed.
+    app.world_mut().entity_mut(first).despawn_recursive();
+    app.world_mut()
+        .run_system_once(|mut c: Commands, mut grow: ReactResMut<Grow>| {
+            grow.get_mut(&mut c).0 = 2.;
+        })
+        .unwrap();
+    app.update();
+    assert_eq!(flex(&app, second), Some((2., 3.)));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
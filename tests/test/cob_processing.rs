@@ -0,0 +1,82 @@
+use std::time::Duration;
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy_cobweb_ui::prelude::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Returns `(pending files, total files)` reported for COB files.
+fn cob_progress(app: &App) -> (usize, usize)
+{
+    let progress = app.world().resource::<LoadProgress>();
+    let tracker = progress
+        .iter_trackers()
+        .find(|t| t.name.ends_with("CobAssetCache"))
+        .unwrap();
+    (tracker.pending, tracker.total)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn load_spread_over_frames_reports_progress()
+{
+    // `c.cob` imports `b.cob`, which is loaded by the manifest of `a.cob`.
+    let mut app = cobweb_test_app(&["processing/a.cob", "processing/c.cob"]);
+
+    // A zero budget applies at most one extracted file per frame.
+    app.insert_resource(CobProcessingBudget { max_time_per_frame: Some(Duration::ZERO) });
+
+    let mut loaded_counts = vec![];
+    for _ in 0..500 {
+        app.update();
+        let (pending, total) = cob_progress(&app);
+        assert!(pending <= total);
+        let loaded = total - pending;
+
+        // Files finish one at a time, and progress never goes backwards.
+        let prev = loaded_counts.last().copied().unwrap_or_default();
+        assert!(loaded >= prev);
+        assert!(loaded <= prev + 1);
+        if loaded != prev {
+            loaded_counts.push(loaded);
+        }
+
+        let state = *app.world().resource::<State<LoadState>>().get();
+        if pending > 0 {
+            assert_eq!(state, LoadState::Loading);
+        }
+        if state == LoadState::Done {
+            break;
+        }
+        std::thread::sleep(Duration::from_millis(5));
+    }
+    assert_eq!(*app.world().resource::<State<LoadState>>().get(), LoadState::Done);
+
+    // Builtin files are also counted.
+    let (pending, total) = cob_progress(&app);
+    assert_eq!(pending, 0);
+    assert!(total >= 3);
+    assert_eq!(loaded_counts, (1..=total).collect::<Vec<_>>());
+
+    // Scenes were extracted with the imported constant.
+    let (root, child) = app
+        .world_mut()
+        .run_system_once(|mut c: Commands, mut s: SceneBuilder| {
+            let mut spawned = (Entity::PLACEHOLDER, Entity::PLACEHOLDER);
+            c.spawn_scene_and_edit(("processing/c.cob", "c"), &mut s, |h| {
+                spawned = (h.id(), h.get_entity("child")?);
+                OK
+            });
+            spawned
+        })
+        .unwrap();
+    app.update();
+    assert_eq!(app.world().get::<Node>(root).unwrap().width, Val::Px(20.));
+    assert_eq!(app.world().get::<Node>(child).unwrap().height, Val::Px(20.));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,61 @@
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistry;
+use bevy_cobweb_ui::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Reflect, Default, Debug, Clone, PartialEq)]
+struct TestWidth
+{
+    value: f32,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn validate_cob_content()
+{
+    let mut registry = TypeRegistry::default();
+    registry.register::<TestWidth>();
+
+    // Valid content, including resolved constants.
+    assert_eq!(
+        validate_cob_str(
+            "#defs
+$width = 10
+
+#scenes
+\"a\"
+    TestWidth{value:$width}
+    \"b\"
+        TestWidth{value:5}
+",
+            &registry,
+        ),
+        Ok(())
+    );
+
+    // Parse errors.
+    let errors = validate_cob_str("#scenes\n\"a\"\n TestWidth{value:5", &registry).unwrap_err();
+    assert!(matches!(errors[..], [CobLoadError::Parse { .. }]));
+
+    // Unknown loadables, invalid loadables, and unresolved constants.
+    let errors = validate_cob_str(
+        "#scenes
+\"a\"
+    Unknown
+    \"b\"
+        TestWidth{value:\"x\"}
+    \"c\"
+        TestWidth{value:$missing}
+",
+        &registry,
+    )
+    .unwrap_err();
+    assert_eq!(errors.len(), 3);
+    assert!(matches!(&errors[0], CobLoadError::UnknownLoadable { name, .. } if name == "Unknown"));
+    assert!(matches!(&errors[1], CobLoadError::InvalidLoadable { path, .. } if *path == ScenePath::new("a::b")));
+    assert!(matches!(&errors[2], CobLoadError::UnresolvedDefs { path, .. } if *path == ScenePath::new("a::c")));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
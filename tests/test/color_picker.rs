@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+use bevy_cobweb_ui::builtin::widgets::color_picker::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn color_value_components()
+{
+    let mut value = ColorValue::new(Color::srgb(1., 0., 0.));
+    assert_eq!(value.hue_fraction(), 0.);
+    assert_eq!(value.saturation_value(), Vec2::ONE);
+    assert_eq!(value.alpha(), 1.);
+
+    value.set_hue_fraction(0.5);
+    assert_eq!(value.0.hue, 180.);
+
+    // Hue and saturation are preserved when the color is black.
+    value.set_saturation_value(Vec2::new(0.75, 0.));
+    assert_eq!(value.hue_fraction(), 0.5);
+    assert_eq!(value.saturation_value(), Vec2::new(0.75, 0.));
+
+    // Values are clamped.
+    value.set_alpha(2.);
+    assert_eq!(value.alpha(), 1.);
+    value.set_hue_fraction(-1.);
+    assert_eq!(value.hue_fraction(), 0.);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,72 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_cobweb_ui::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Time that passes in each update of a test app.
+pub const TEST_FRAME_TIME: Duration = Duration::from_millis(10);
+
+/// Max number of updates to wait for COB files to load.
+const MAX_LOAD_FRAMES: usize = 10_000;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Makes a headless app with [`CobwebUiPlugin`] that loads COB files from `tests/assets`.
+///
+/// Time advances by [`TEST_FRAME_TIME`] in each update, so animations are deterministic.
+pub fn cobweb_test_app(files: &[&'static str]) -> App
+{
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin { file_path: "tests/assets".into(), ..default() },
+        bevy::state::app::StatesPlugin,
+        bevy::text::TextPlugin,
+        bevy::input::InputPlugin,
+        HierarchyPlugin,
+        TransformPlugin,
+        WindowPlugin { primary_window: None, ..default() },
+        bevy::picking::DefaultPickingPlugins,
+    ))
+    .init_asset::<Shader>()
+    .init_asset::<StandardMaterial>()
+    .init_asset::<Mesh>()
+    .init_asset::<Image>()
+    .init_asset::<TextureAtlasLayout>()
+    .init_asset::<AudioSource>()
+    .insert_resource(TimeUpdateStrategy::ManualDuration(TEST_FRAME_TIME))
+    .add_plugins(CobwebUiPlugin);
+    for file in files {
+        app.load(*file);
+    }
+    app
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Updates the app until all COB files are loaded.
+pub fn wait_for_load(app: &mut App)
+{
+    for _ in 0..MAX_LOAD_FRAMES {
+        app.update();
+        if *app.world().resource::<State<LoadState>>().get() == LoadState::Done {
+            return;
+        }
+    }
+    panic!("timed out waiting for COB files to load");
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Updates the app `frames` times.
+pub fn update_frames(app: &mut App, frames: usize)
+{
+    for _ in 0..frames {
+        app.update();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
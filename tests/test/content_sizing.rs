@@ -0,0 +1,39 @@
+use bevy::prelude::*;
+use bevy_cobweb_ui::prelude::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn fit_content_apply_and_revert()
+{
+    let mut app = cobweb_test_app(&[]);
+    wait_for_load(&mut app);
+    let world = app.world_mut();
+    let parent = world
+        .spawn(Node { flex_direction: FlexDirection::Column, ..default() })
+        .id();
+    let child = world.spawn_empty().set_parent(parent).id();
+    Instruction::apply(Width(Val::Px(50.)), child, world);
+    Instruction::apply(FitContentWidth, child, world);
+    app.update();
+
+    // The node is sized to its content and isn't stretched along the parent's cross axis.
+    let node = app.world().get::<Node>(child).unwrap();
+    assert_eq!(node.width, Val::Auto);
+    assert_eq!(node.align_self, AlignSelf::Start);
+
+    // Reverting restores the node, and remaining loadables are reapplied (as when hot reloading).
+    let world = app.world_mut();
+    <FitContentWidth as Instruction>::revert(child, world);
+    Instruction::apply(Width(Val::Px(50.)), child, world);
+    app.update();
+
+    let node = app.world().get::<Node>(child).unwrap();
+    assert_eq!(node.width, Val::Px(50.));
+    assert_eq!(node.align_self, AlignSelf::Auto);
+    assert!(app.world().get::<FitContentWidth>(child).is_none());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,95 @@
+use bevy::prelude::*;
+use bevy_cobweb_ui::prelude::*;
+use bevy_cobweb_ui::sickle::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn inherit_control_state_filter()
+{
+    // All states are inherited by default.
+    let inherit = InheritControl::default();
+    assert!(inherit.inherits(&PseudoState::Disabled));
+    assert!(inherit.inherits(&PseudoState::Custom("Highlighted".into())));
+
+    let inherit = InheritControl { states: vec![PseudoState::Disabled, PseudoState::Selected] };
+    assert!(inherit.inherits(&PseudoState::Disabled));
+    assert!(inherit.inherits(&PseudoState::Selected));
+    assert!(!inherit.inherits(&PseudoState::FirstChild));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn background(app: &App, entity: Entity) -> Color
+{
+    app.world().get::<BackgroundColor>(entity).unwrap().0
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn nested_group_inherits_parent_states()
+{
+    let mut app = cobweb_test_app(&[]);
+    wait_for_load(&mut app);
+
+    let world = app.world_mut();
+    let row = world.spawn(Node::default()).id();
+    let other_row = world.spawn(Node::default()).id();
+    let button = world.spawn(Node::default()).set_parent(row).id();
+    ControlRoot.apply(row, world);
+    ControlRoot.apply(other_row, world);
+    ControlRoot.apply(button, world);
+    InheritControl { states: vec![PseudoState::Disabled, PseudoState::Selected] }.apply(button, world);
+    for (state, value) in [
+        (None, Color::BLACK),
+        (Some(PseudoState::Selected), Color::WHITE),
+        (Some(PseudoState::Disabled), Color::srgb(1., 0., 0.)),
+    ] {
+        Instruction::apply(
+            Static::<BackgroundColor> { state: state.map(|s| smallvec::smallvec![s]), value, ..default() },
+            button,
+            world,
+        );
+    }
+    app.update();
+    assert_eq!(background(&app, button), Color::BLACK);
+
+    // The nested group picks up the parent group's states.
+    app.world_mut()
+        .entity_mut(row)
+        .add_pseudo_state(PseudoState::Selected);
+    app.update();
+    assert_eq!(background(&app, button), Color::WHITE);
+
+    app.world_mut()
+        .entity_mut(row)
+        .remove_pseudo_state(PseudoState::Selected)
+        .add_pseudo_state(PseudoState::Disabled);
+    app.update();
+    assert_eq!(background(&app, button), Color::srgb(1., 0., 0.));
+
+    // States that aren't inherited are ignored.
+    app.world_mut()
+        .entity_mut(row)
+        .remove_pseudo_state(PseudoState::Disabled)
+        .add_pseudo_state(PseudoState::Checked);
+    app.update();
+    assert_eq!(background(&app, button), Color::BLACK);
+
+    // Moving the nested group to a new parent group inherits the new parent's states.
+    app.world_mut()
+        .entity_mut(other_row)
+        .add_pseudo_state(PseudoState::Selected);
+    app.update();
+    assert_eq!(background(&app, button), Color::BLACK);
+    app.world_mut()
+        .entity_mut(button)
+        .set_parent(other_row);
+    app.update();
+    assert_eq!(background(&app, button), Color::WHITE);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,99 @@
+use bevy::prelude::*;
+use bevy_cobweb_ui::prelude::*;
+use bevy_cobweb_ui::sickle::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource, Default)]
+struct RefreshCount(usize);
+
+/// Static attribute that counts how many times the control group's styles were re-applied.
+#[derive(Reflect, Default, Debug, Clone, PartialEq)]
+struct CountRefreshes;
+
+impl Instruction for CountRefreshes
+{
+    fn apply(self, _: Entity, world: &mut World)
+    {
+        world.resource_mut::<RefreshCount>().0 += 1;
+    }
+
+    fn revert(_: Entity, _: &mut World) {}
+}
+
+impl StaticAttribute for CountRefreshes
+{
+    type Value = Self;
+    fn construct(value: Self::Value) -> Self
+    {
+        value
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn refreshes(app: &App) -> usize
+{
+    app.world().resource::<RefreshCount>().0
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn same_frame_triggers_refresh_once()
+{
+    let mut app = cobweb_test_app(&[]);
+    app.init_resource::<RefreshCount>()
+        .register_static::<CountRefreshes>();
+    wait_for_load(&mut app);
+
+    let world = app.world_mut();
+    let root = world.spawn(Node::default()).id();
+    let child = world.spawn(Node::default()).set_parent(root).id();
+    ControlRoot.apply(root, world);
+    Instruction::apply(ControlMember::from("child"), child, world);
+    Instruction::apply(
+        Static::<CountRefreshes> { value: CountRefreshes, ..default() },
+        child,
+        world,
+    );
+    app.update();
+    assert_eq!(refreshes(&app), 1);
+
+    // Nothing changed, so the control group is not refreshed.
+    app.update();
+    assert_eq!(refreshes(&app), 1);
+
+    // Several triggers in one frame: attributes changed on the root and a member, and multiple pseudo state
+    // changes on the root. Change detection folds these into one refresh of the control map.
+    let world = app.world_mut();
+    Instruction::apply(
+        Static::<BackgroundColor> { value: Color::BLACK, ..default() },
+        root,
+        world,
+    );
+    Instruction::apply(
+        Static::<BackgroundColor> {
+            state: Some(smallvec::smallvec![PseudoState::Selected]),
+            value: Color::WHITE,
+            ..default()
+        },
+        child,
+        world,
+    );
+    world
+        .entity_mut(root)
+        .add_pseudo_state(PseudoState::Selected);
+    world
+        .entity_mut(root)
+        .add_pseudo_state(PseudoState::Checked);
+    app.update();
+    assert_eq!(refreshes(&app), 2);
+
+    app.update();
+    assert_eq!(refreshes(&app), 2);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
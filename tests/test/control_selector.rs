@@ -0,0 +1,53 @@
+use bevy::prelude::*;
+use bevy_cobweb_ui::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Component, Reflect)]
+struct InventorySlot;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn control_selector_glob()
+{
+    let world = World::new();
+    let entity = Entity::PLACEHOLDER;
+    let matches = |pattern: &str, id: &str| ControlSelector::Glob(pattern.into()).matches(id, entity, &world);
+
+    assert!(matches("item_*", "item_0"));
+    assert!(matches("item_*", "item_12"));
+    assert!(matches("item_*", "item_"));
+    assert!(!matches("item_*", "items"));
+    assert!(matches("*_icon", "item_3_icon"));
+    assert!(matches("item_*_icon", "item_3_icon"));
+    assert!(!matches("item_*_icon", "item_3_label"));
+    assert!(matches("*", ""));
+    assert!(matches("a*b*c", "aXbYbZc"));
+    assert!(matches("label", "label"));
+    assert!(!matches("label", "label2"));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn control_selector_marker()
+{
+    let mut world = World::new();
+    world.init_resource::<AppTypeRegistry>();
+    world.resource::<AppTypeRegistry>().write().register::<InventorySlot>();
+    let slot = world.spawn(InventorySlot).id();
+    let other = world.spawn_empty().id();
+
+    let selector = ControlSelector::Marker("InventorySlot".into());
+    assert!(selector.matches("slot", slot, &world));
+    assert!(!selector.matches("other", other, &world));
+    assert!(!ControlSelector::Marker("Unregistered".into()).matches("slot", slot, &world));
+
+    // Matchers look up the marker once and can be reused.
+    let matcher = selector.matcher(&world);
+    assert!(matcher.matches("slot", slot, &world));
+    assert!(!matcher.matches("other", other, &world));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
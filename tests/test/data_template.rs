@@ -0,0 +1,44 @@
+use bevy::prelude::*;
+use bevy_cobweb_ui::builtin::widgets::data_template::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Reflect)]
+struct ItemStat
+{
+    name: String,
+    value: i32,
+}
+
+#[derive(Reflect)]
+struct ItemCard
+{
+    title: String,
+    stats: Vec<ItemStat>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn template_data_fields()
+{
+    let data = TemplateData::new(ItemCard {
+        title: "Sword".into(),
+        stats: vec![
+            ItemStat { name: "Strength".into(), value: 5 },
+            ItemStat { name: "Speed".into(), value: -2 },
+        ],
+    });
+
+    let title = data.get_field("title").unwrap();
+    assert_eq!(title.try_downcast_ref::<String>().unwrap(), "Sword");
+    let value = data.get_field("stats[1].value").unwrap();
+    assert_eq!(*value.try_downcast_ref::<i32>().unwrap(), -2);
+    let name = data.get_field("stats[0].name").unwrap();
+    assert_eq!(name.try_downcast_ref::<String>().unwrap(), "Strength");
+    assert!(data.get_field("stats[2]").is_none());
+    assert!(data.get_field("missing").is_none());
+    assert!(data.get_field("").unwrap().try_downcast_ref::<ItemCard>().is_some());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
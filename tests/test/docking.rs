@@ -0,0 +1,100 @@
+use bevy::prelude::*;
+use bevy_cobweb_ui::builtin::widgets::docking::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn layout_with(ids: &[&str]) -> DockLayout
+{
+    let mut layout = DockLayout::default();
+    for id in ids {
+        layout.insert_missing(*id);
+    }
+    layout
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn dock_insert_missing()
+{
+    let mut layout = layout_with(&["a", "b"]);
+    layout.insert_missing("a");
+    assert_eq!(layout.panels(), vec!["a", "b"]);
+    assert_eq!(layout.find_panel("b"), Some(DockTarget::Docked(vec![])));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn dock_split_and_collapse()
+{
+    let mut layout = layout_with(&["a", "b"]);
+    assert!(layout.dock_panel("b", &DockTarget::Docked(vec![]), DockZone::Right));
+    let DockNode::Split { axis, entries } = &layout.root else { panic!("expected split") };
+    assert_eq!(*axis, DockAxis::Horizontal);
+    assert_eq!(entries.len(), 2);
+    assert_eq!(layout.find_panel("a"), Some(DockTarget::Docked(vec![0])));
+    assert_eq!(layout.find_panel("b"), Some(DockTarget::Docked(vec![1])));
+
+    // Moving the panel back collapses the split.
+    assert!(layout.dock_panel("b", &DockTarget::Docked(vec![0]), DockZone::Center));
+    assert!(matches!(layout.root, DockNode::Tabs(_)));
+    assert_eq!(layout.panels(), vec!["a", "b"]);
+
+    // Splitting a single-tab stack with its own panel is a no-op.
+    let mut layout = layout_with(&["a"]);
+    assert!(layout.dock_panel("a", &DockTarget::Docked(vec![]), DockZone::Top));
+    assert!(matches!(layout.root, DockNode::Tabs(_)));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn dock_float_and_redock()
+{
+    let mut layout = layout_with(&["a", "b"]);
+    layout.float_panel("b", Vec2::new(10., 20.), Vec2::new(100., 100.));
+    assert_eq!(layout.find_panel("b"), Some(DockTarget::Floating(0)));
+    assert_eq!(layout.floating[0].position, Vec2::new(10., 20.));
+
+    // Floating windows can't be split.
+    assert!(layout.dock_panel("a", &DockTarget::Floating(0), DockZone::Left));
+    assert_eq!(layout.get_tabs(&DockTarget::Floating(0)).unwrap().tabs, vec!["b", "a"]);
+    assert!(matches!(&layout.root, DockNode::Tabs(tabs) if tabs.tabs.is_empty()));
+
+    // Empty floating windows are removed.
+    assert!(layout.remove_panel("a"));
+    assert!(layout.dock_panel("b", &DockTarget::Docked(vec![]), DockZone::Center));
+    assert!(layout.floating.is_empty());
+    assert_eq!(layout.panels(), vec!["b"]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn dock_active_tab()
+{
+    let mut layout = layout_with(&["a", "b", "c"]);
+    assert!(layout.set_active("c"));
+    assert!(!layout.set_active("d"));
+    let target = layout.find_panel("c").unwrap();
+    assert_eq!(layout.get_tabs(&target).unwrap().active_tab().map(|t| t.as_str()), Some("c"));
+
+    // Removing the active tab keeps a valid active index.
+    assert!(layout.remove_panel("c"));
+    assert!(layout.get_tabs(&target).unwrap().active_tab().is_some());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn dock_zone_from_normalized()
+{
+    assert_eq!(DockZone::from_normalized(Vec2::new(0.5, 0.5)), DockZone::Center);
+    assert_eq!(DockZone::from_normalized(Vec2::new(0.1, 0.5)), DockZone::Left);
+    assert_eq!(DockZone::from_normalized(Vec2::new(0.9, 0.4)), DockZone::Right);
+    assert_eq!(DockZone::from_normalized(Vec2::new(0.5, 0.05)), DockZone::Top);
+    assert_eq!(DockZone::from_normalized(Vec2::new(0.45, 0.95)), DockZone::Bottom);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,104 @@
+use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
+use bevy_cobweb_ui::prelude::*;
+use bevy_cobweb_ui::sickle::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource, Default)]
+struct ReceivedPresses(Vec<Entity>);
+
+impl ReceivedPresses
+{
+    fn take_sorted(&mut self) -> Vec<Entity>
+    {
+        let mut received = std::mem::take(&mut self.0);
+        received.sort();
+        received
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn listen_for_presses(world: &mut World, entity: Entity)
+{
+    world.react(|rc| {
+        rc.on_persistent(entity_event::<Pressed>(entity), move |mut received: ResMut<ReceivedPresses>| {
+            received.0.push(entity);
+        })
+    });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn bubble_events_to_ancestors()
+{
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin).init_resource::<ReceivedPresses>();
+    let world = app.world_mut();
+
+    // root <- middle (bubbles) <- leaf (bubbles)
+    let root = world.spawn_empty().id();
+    let middle = world.spawn_empty().set_parent(root).id();
+    let leaf = world.spawn_empty().set_parent(middle).id();
+    BubbleEvents::<Pressed>::default().apply(middle, world);
+    BubbleEvents::<Pressed>::default().apply(leaf, world);
+    for entity in [root, middle, leaf] {
+        listen_for_presses(world, entity);
+    }
+
+    // Events bubble until an ancestor without `BubbleEvents`.
+    world.react(|rc| rc.entity_event(leaf, Pressed));
+    world.flush();
+    assert_eq!(world.resource_mut::<ReceivedPresses>().take_sorted(), vec![root, middle, leaf]);
+
+    // Reverting stops forwarding from that entity.
+    BubbleEvents::<Pressed>::revert(middle, world);
+    world.react(|rc| rc.entity_event(leaf, Pressed));
+    world.flush();
+    assert_eq!(world.resource_mut::<ReceivedPresses>().take_sorted(), vec![middle, leaf]);
+
+    // Other event types are not forwarded.
+    world.react(|rc| rc.entity_event(leaf, Released));
+    world.flush();
+    assert!(world.resource::<ReceivedPresses>().0.is_empty());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn disabled_ancestors_stop_bubbling()
+{
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin).init_resource::<ReceivedPresses>();
+    let world = app.world_mut();
+
+    // root <- button (bubbles, disabled) <- icon (bubbles)
+    let root = world.spawn_empty().id();
+    let button = world.spawn_empty().set_parent(root).id();
+    let icon = world.spawn_empty().set_parent(button).id();
+    BubbleEvents::<Pressed>::default().apply(button, world);
+    BubbleEvents::<Pressed>::default().apply(icon, world);
+    for entity in [root, button, icon] {
+        listen_for_presses(world, entity);
+    }
+
+    // Disabled parents don't receive bubbled events, so the event doesn't reach the root either.
+    world
+        .entity_mut(button)
+        .add_pseudo_state(PseudoState::Disabled);
+    world.react(|rc| rc.entity_event(icon, Pressed));
+    world.flush();
+    assert_eq!(world.resource_mut::<ReceivedPresses>().take_sorted(), vec![icon]);
+
+    // Re-enabled parents receive events again.
+    world
+        .entity_mut(button)
+        .remove_pseudo_state(PseudoState::Disabled);
+    world.react(|rc| rc.entity_event(icon, Pressed));
+    world.flush();
+    assert_eq!(world.resource_mut::<ReceivedPresses>().take_sorted(), vec![root, button, icon]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,30 @@
+use bevy::prelude::*;
+use bevy_cobweb_ui::builtin::widgets::floating_panel::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn floating_panel_order()
+{
+    let a = Entity::from_raw(0);
+    let b = Entity::from_raw(1);
+    let c = Entity::from_raw(2);
+
+    let mut manager = FloatingPanelManager::default();
+    assert_eq!(manager.front(), None);
+
+    manager.bring_to_front(a);
+    manager.bring_to_front(b);
+    manager.bring_to_front(c);
+    assert_eq!(manager.order(), &[a, b, c]);
+
+    // Pressed panels move to the front.
+    manager.bring_to_front(a);
+    assert_eq!(manager.order(), &[b, c, a]);
+    assert_eq!(manager.front(), Some(a));
+
+    manager.remove(c);
+    assert_eq!(manager.order(), &[b, a]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
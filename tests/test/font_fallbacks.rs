@@ -0,0 +1,21 @@
+use bevy_cobweb_ui::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn font_script_of_char()
+{
+    assert_eq!(FontScript::of('a'), Some(FontScript::Latin));
+    assert_eq!(FontScript::of('é'), Some(FontScript::Latin));
+    assert_eq!(FontScript::of('Ж'), Some(FontScript::Cyrillic));
+    assert_eq!(FontScript::of('ש'), Some(FontScript::Hebrew));
+    assert_eq!(FontScript::of('한'), Some(FontScript::Hangul));
+    assert_eq!(FontScript::of('か'), Some(FontScript::Japanese));
+    assert_eq!(FontScript::of('字'), Some(FontScript::Cjk));
+    assert_eq!(FontScript::of('😀'), Some(FontScript::Emoji));
+    assert_eq!(FontScript::of('1'), None);
+    assert_eq!(FontScript::of(' '), None);
+    assert_eq!(FontScript::of('!'), None);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
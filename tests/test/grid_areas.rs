@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+use bevy_cobweb_ui::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn grid_area_placement()
+{
+    let areas = GridAreas(vec![
+        vec!["header".into(), "header".into(), "header".into()],
+        vec!["nav".into(), "main".into(), "main".into()],
+        vec!["nav".into(), "main".into(), "main".into()],
+        vec![".".into(), "footer".into(), "nav".into()],
+    ]);
+
+    assert_eq!(
+        areas.placement("header"),
+        Some((GridPlacement::start_end(1, 2), GridPlacement::start_end(1, 4)))
+    );
+    assert_eq!(
+        areas.placement("main"),
+        Some((GridPlacement::start_end(2, 4), GridPlacement::start_end(2, 4)))
+    );
+    assert_eq!(
+        areas.placement("footer"),
+        Some((GridPlacement::start_end(4, 5), GridPlacement::start_end(2, 3)))
+    );
+
+    // Non-rectangular, missing, and empty areas.
+    assert_eq!(areas.placement("nav"), None);
+    assert_eq!(areas.placement("sidebar"), None);
+    assert_eq!(areas.placement("."), None);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,25 @@
+use bevy_cobweb_ui::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn haptic_intensity_scaling()
+{
+    let feedback = HapticFeedback {
+        strong_motor: 0.6,
+        weak_motor: 0.2,
+        duration_ms: 100,
+        ..Default::default()
+    };
+
+    let intensity = feedback.intensity(&HapticSettings::default());
+    assert_eq!(intensity.strong_motor, 0.6);
+    assert_eq!(intensity.weak_motor, 0.2);
+
+    // Scaled intensities are clamped.
+    let intensity = feedback.intensity(&HapticSettings { enabled: true, intensity_scale: 2. });
+    assert_eq!(intensity.strong_motor, 1.);
+    assert_eq!(intensity.weak_motor, 0.4);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
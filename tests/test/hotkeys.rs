@@ -0,0 +1,26 @@
+use bevy::prelude::*;
+use bevy_cobweb_ui::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn key_chord_parsing()
+{
+    let chord = KeyChord::parse("Ctrl+Shift+S").unwrap();
+    assert!(chord.ctrl && chord.shift && !chord.alt && !chord.super_key);
+    assert_eq!(chord.key, KeyCode::KeyS);
+    assert!(chord.has_command_modifier());
+
+    let chord = KeyChord::parse("escape").unwrap();
+    assert_eq!(chord.key, KeyCode::Escape);
+    assert!(!chord.has_command_modifier());
+
+    assert_eq!(KeyChord::parse("Cmd + F5").unwrap().key, KeyCode::F5);
+
+    // Invalid chords.
+    assert!(KeyChord::parse("Ctrl+Shift").is_none());
+    assert!(KeyChord::parse("A+B").is_none());
+    assert!(KeyChord::parse("Ctrl+Banana").is_none());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,30 @@
+use bevy::prelude::*;
+use bevy_cobweb_ui::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn image_uv_instructions()
+{
+    let mut world = World::new();
+    let entity = world.spawn_empty().id();
+
+    // Applying an instruction inserts the transform.
+    Instruction::apply(ImageUvScroll(Vec2::new(0.5, 0.)), entity, &mut world);
+    Instruction::apply(ImageTiling(Vec2::new(2., 3.)), entity, &mut world);
+    let transform = world.get::<ImageUvTransform>(entity).unwrap();
+    assert_eq!(transform.scroll, Vec2::new(0.5, 0.));
+    assert_eq!(transform.tiling, Vec2::new(2., 3.));
+    assert!(!transform.is_identity());
+    assert_eq!(
+        <ImageUvRotation as AnimatedAttribute>::get_value(entity, &world),
+        Some(0.)
+    );
+
+    // Reverting restores defaults.
+    <ImageUvScroll as Instruction>::revert(entity, &mut world);
+    <ImageTiling as Instruction>::revert(entity, &mut world);
+    assert!(world.get::<ImageUvTransform>(entity).unwrap().is_identity());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
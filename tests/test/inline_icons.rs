@@ -0,0 +1,40 @@
+use bevy_cobweb_ui::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn segment(icon: &str, text: &str) -> InlineIconSegment
+{
+    InlineIconSegment { icon: icon.into(), text: text.into() }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn inline_icons()
+{
+    // No icons.
+    assert_eq!(parse_inline_icons("hello world"), None);
+    assert_eq!(parse_inline_icons("<b>bold</b>"), None);
+    assert_eq!(parse_inline_icons("<icon=>"), None);
+    assert_eq!(parse_inline_icons("<icon=a"), None);
+
+    // Single icon.
+    assert_eq!(
+        parse_inline_icons("Press <icon=button_a> to continue"),
+        Some(("Press ".into(), vec![segment("button_a", " to continue")]))
+    );
+
+    // Leading, adjacent, and trailing icons.
+    assert_eq!(
+        parse_inline_icons("<icon=a><icon= b >x<icon=c>"),
+        Some((String::default(), vec![segment("a", ""), segment("b", "x"), segment("c", "")]))
+    );
+
+    // Invalid tags are kept as literal text.
+    assert_eq!(
+        parse_inline_icons("<icon=> <icon=a> <icon="),
+        Some(("<icon=> ".into(), vec![segment("a", " <icon=")]))
+    );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,22 @@
+use bevy_cobweb_ui::builtin::widgets::keybinding_hint::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn keymap_remapping()
+{
+    let mut keymap = Keymap::new(KeymapTable::default().with("save", ["Ctrl", "S"]));
+    assert_eq!(keymap.get_keys("save"), Some(vec!["Ctrl".into(), "S".into()]));
+    assert_eq!(keymap.get_keys("load"), None);
+
+    let table = keymap.provider_mut::<KeymapTable>().unwrap();
+    table.set("save", ["F5"]);
+    table.set("load", ["F9"]);
+    assert_eq!(keymap.get_keys("save"), Some(vec!["F5".into()]));
+    assert_eq!(keymap.get_keys("load"), Some(vec!["F9".into()]));
+
+    keymap.provider_mut::<KeymapTable>().unwrap().remove("load");
+    assert_eq!(keymap.get_keys("load"), None);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
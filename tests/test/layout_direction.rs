@@ -0,0 +1,27 @@
+use std::str::FromStr;
+
+use bevy_cobweb_ui::prelude::*;
+use fluent_langneg::LanguageIdentifier;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn rtl_languages()
+{
+    let lang = |id: &str| LanguageIdentifier::from_str(id).unwrap();
+
+    assert!(is_rtl_language(&lang("ar")));
+    assert!(is_rtl_language(&lang("he-IL")));
+    assert!(is_rtl_language(&lang("az-Arab")));
+    assert!(!is_rtl_language(&lang("en-US")));
+    assert!(!is_rtl_language(&lang("ur-Latn")));
+
+    // Forcing a direction overrides the locale.
+    let mut direction = UiLayoutDirection::default();
+    assert!(!direction.is_rtl());
+    direction.force = Some(LayoutDirection::Rtl);
+    assert!(direction.is_rtl());
+    assert_eq!(direction.locale_direction(), LayoutDirection::Ltr);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+use bevy_cobweb_ui::prelude::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource, Default)]
+struct RecordedCommands(Vec<String>);
+
+#[derive(Reflect, Default, PartialEq)]
+struct RecordCommand(String);
+
+impl Command for RecordCommand
+{
+    fn apply(self, world: &mut World)
+    {
+        world.resource_mut::<RecordedCommands>().0.push(self.0);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn load_dir_applies_commands_in_registration_order()
+{
+    // Directories are registered in reverse lexicographic order, and scans can finish in any order.
+    let mut app = cobweb_test_app(&[]);
+    app.init_resource::<RecordedCommands>()
+        .register_command_type::<RecordCommand>()
+        .load_dir("load_dir/z")
+        .load_dir("load_dir/a");
+    wait_for_load(&mut app);
+
+    assert_eq!(app.world().resource::<RecordedCommands>().0, vec!["z/x", "z/y", "a/x"]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
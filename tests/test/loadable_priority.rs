@@ -0,0 +1,91 @@
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy_cobweb_ui::prelude::*;
+use bevy_cobweb_ui::sickle::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Records which loadables were applied, and whether [`ControlRoot`] was applied before them.
+#[derive(Resource, Default)]
+struct AppliedLoadables(Vec<(String, bool)>);
+
+fn record(entity: Entity, world: &mut World, name: String)
+{
+    let has_control = world.get::<ControlMember>(entity).is_some();
+    world
+        .resource_mut::<AppliedLoadables>()
+        .0
+        .push((name, has_control));
+}
+
+#[derive(Reflect, Default, PartialEq)]
+struct RecordLow(String);
+
+impl Instruction for RecordLow
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        record(entity, world, self.0);
+    }
+
+    fn revert(_: Entity, _: &mut World) {}
+}
+
+#[derive(Reflect, Default, PartialEq)]
+struct RecordHigh(String);
+
+impl Instruction for RecordHigh
+{
+    fn apply(self, entity: Entity, world: &mut World)
+    {
+        record(entity, world, self.0);
+    }
+
+    fn revert(_: Entity, _: &mut World) {}
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn loadables_apply_in_priority_order()
+{
+    let mut app = cobweb_test_app(&["loadable_priority.cob"]);
+    app.init_resource::<AppliedLoadables>()
+        .register_instruction_type::<RecordLow>()
+        .register_instruction_type_with_priority::<RecordHigh>(10);
+    wait_for_load(&mut app);
+
+    // `ControlRoot` and the loadables are written after the `Responsive` attribute.
+    let entity = app
+        .world_mut()
+        .run_system_once(|mut c: Commands, mut s: SceneBuilder| {
+            let mut spawned = Entity::PLACEHOLDER;
+            c.ui_root()
+                .spawn_scene_and_edit(("loadable_priority.cob", "node"), &mut s, |h| {
+                    spawned = h.id();
+                });
+            spawned
+        })
+        .unwrap();
+    update_frames(&mut app, 2);
+
+    // `ControlRoot` is applied first, then higher priorities, and equal priorities in declaration order.
+    let applied: Vec<(&str, bool)> = app
+        .world()
+        .resource::<AppliedLoadables>()
+        .0
+        .iter()
+        .map(|(name, has_control)| (name.as_str(), *has_control))
+        .collect();
+    assert_eq!(applied, vec![("b", true), ("d", true), ("a", true), ("c", true)]);
+
+    // The `Responsive` attribute was added to the control group set up by `ControlRoot`.
+    assert_eq!(
+        app.world().get::<BackgroundColor>(entity).unwrap().0,
+        Color::srgb(1., 0., 0.)
+    );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,110 @@
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
+use bevy_cobweb_ui::prelude::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+struct Ping;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Spawns a "row" scene instance under `parent`, returning the root entity.
+fn spawn_row(app: &mut App, parent: Entity) -> Entity
+{
+    app.world_mut()
+        .run_system_once(move |mut c: Commands, mut s: SceneBuilder| {
+            let mut root = Entity::PLACEHOLDER;
+            c.entity(parent)
+                .spawn_scene_and_edit(("scene_pool.cob", "row"), &mut s, |h| {
+                    root = h.id();
+                    OK
+                });
+            root
+        })
+        .unwrap()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn metrics(app: &App) -> &CobwebUiMetrics
+{
+    app.world().resource::<CobwebUiMetrics>()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn metrics_count_scene_instances_and_nodes()
+{
+    let mut app = cobweb_test_app(&["scene_pool.cob"]);
+    wait_for_load(&mut app);
+    app.world_mut()
+        .resource_mut::<SceneBuilderInner>()
+        .set_pool_capacity(("scene_pool.cob", "row"), 1);
+    let row = SceneRef::from(("scene_pool.cob", "row"));
+    let file = CobFile::try_new("scene_pool.cob").unwrap();
+
+    let parent = app.world_mut().spawn(Node::default()).id();
+    app.update();
+    let base_nodes = metrics(&app).total_nodes();
+    let base_loaded = metrics(&app).loaded_nodes();
+    let base_reactors = metrics(&app).reactors();
+
+    // Each instance has a root and a label node.
+    let first = spawn_row(&mut app, parent);
+    let second = spawn_row(&mut app, parent);
+    app.world_mut()
+        .commands()
+        .entity(first)
+        .reactor(broadcast::<Ping>(), |_: TargetId| {});
+    app.update();
+    let m = metrics(&app);
+    assert_eq!(m.scene_instances(&row), 2);
+    assert_eq!(m.file_scene_instances(&file), 2);
+    assert_eq!(m.total_scene_instances(), 2);
+    assert_eq!(m.iter_scene_instances().count(), 1);
+    assert_eq!(m.total_nodes(), base_nodes + 4);
+    assert_eq!(m.loaded_nodes(), base_loaded + 4);
+    assert_eq!(m.reactors(), base_reactors + 1);
+
+    // Despawning an instance removes its scene instance, nodes, and reactors.
+    app.world_mut().entity_mut(first).despawn_recursive();
+    app.update();
+    app.update();
+    let m = metrics(&app);
+    assert_eq!(m.scene_instances(&row), 1);
+    assert_eq!(m.file_scene_instances(&file), 1);
+    assert_eq!(m.total_scene_instances(), 1);
+    assert_eq!(m.total_nodes(), base_nodes + 2);
+    assert_eq!(m.loaded_nodes(), base_loaded + 2);
+    assert_eq!(m.reactors(), base_reactors);
+
+    // Pooled instances are not live scene instances.
+    app.world_mut().commands().entity(second).despawn_to_pool();
+    app.update();
+    let m = metrics(&app);
+    assert_eq!(m.scene_instances(&row), 0);
+    assert_eq!(m.total_scene_instances(), 0);
+    assert_eq!(m.iter_scene_instances().count(), 0);
+
+    // Reusing a pooled instance counts it again.
+    let reused = spawn_row(&mut app, parent);
+    app.update();
+    assert_eq!(reused, second);
+    assert_eq!(metrics(&app).scene_instances(&row), 1);
+
+    // Despawning a pooled instance doesn't change the counts.
+    app.world_mut().commands().entity(second).despawn_to_pool();
+    app.update();
+    app.world_mut().entity_mut(second).despawn_recursive();
+    app.update();
+    let m = metrics(&app);
+    assert_eq!(m.scene_instances(&row), 0);
+    assert_eq!(m.total_scene_instances(), 0);
+    assert_eq!(m.total_nodes(), base_nodes);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
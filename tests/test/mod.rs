@@ -1,5 +1,56 @@
+mod accessibility;
+mod animated_sprite;
+mod animation_complete;
+mod binding_capture;
+mod breakpoints;
 mod cob;
-//mod common;
+mod cob_bindings;
+mod cob_processing;
+mod cob_validation;
+mod color_picker;
+mod common;
+mod content_sizing;
+mod control_inheritance;
+mod control_refresh;
+mod control_selector;
+mod data_template;
+mod docking;
+mod event_bubbling;
+mod floating_panel;
+mod font_fallbacks;
+mod grid_areas;
+mod haptics;
+mod hotkeys;
+mod image_uv;
+mod inline_icons;
+mod keybinding_hint;
+mod layout_direction;
+mod load_dir;
+mod loadable_priority;
+mod metrics;
+mod press_timing;
+mod profiling;
+mod pseudo_state_machine;
+mod safe_area;
+mod scene_pool;
+mod scene_spawn_queue;
+mod slider;
+mod spawn_transitions;
+mod stat_bar;
+#[cfg(feature = "svg")]
+mod svg;
+mod text_args;
+mod text_fit;
+mod text_input;
+mod text_links;
+mod text_markup;
+mod texture_atlases;
+mod theme_switching;
+mod tooltip;
+mod touch_gestures;
 mod type_name;
+mod ui_root_camera;
+mod ui_shader_material;
+mod viewport_anchor;
 
-//pub use common::*;
+pub use common::*;
@@ -0,0 +1,20 @@
+use std::time::Duration;
+
+use bevy_cobweb_ui::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn press_timing_overrides()
+{
+    let settings = PressTimingSettings::default();
+    assert_eq!(settings.thresholds(None), (settings.double_click, settings.long_press));
+
+    let overrides = PressTiming { double_click_ms: Some(250), long_press_ms: None };
+    assert_eq!(
+        settings.thresholds(Some(&overrides)),
+        (Duration::from_millis(250), settings.long_press)
+    );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,53 @@
+use std::any::TypeId;
+
+use bevy::diagnostic::{DiagnosticsPlugin, DiagnosticsStore};
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy_cobweb_ui::prelude::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn profiling_records_scene_spawns()
+{
+    let mut app = cobweb_test_app(&["scene_pool.cob"]);
+    app.add_plugins((DiagnosticsPlugin, CobwebUiProfilingPlugin::default()));
+    wait_for_load(&mut app);
+    app.world_mut().resource_mut::<CobwebUiProfile>().reset();
+
+    app.world_mut()
+        .run_system_once(|mut c: Commands, mut s: SceneBuilder| {
+            c.ui_root().spawn_scene(("scene_pool.cob", "row"), &mut s);
+            c.ui_root().spawn_scene(("scene_pool.cob", "row"), &mut s);
+        })
+        .unwrap();
+    app.update();
+
+    // Each instance applies `FlexNode` to the root and label nodes.
+    let profile = app.world().resource::<CobwebUiProfile>();
+    let scene = profile.scene(&SceneRef::from(("scene_pool.cob", "row"))).unwrap();
+    assert_eq!(scene.spawn_count, 2);
+    assert!(scene.max_time <= scene.total_time);
+    let flex_node = profile.loadable(TypeId::of::<FlexNode>()).unwrap();
+    assert_eq!(flex_node.apply_count, 4);
+    assert_eq!(flex_node.name, "FlexNode");
+    assert_eq!(profile.last_frame().scene_spawns, 2);
+    assert!(profile.last_frame().loadable_applies >= 4);
+
+    // Per-frame stats are recorded as diagnostics.
+    let diagnostics = app.world().resource::<DiagnosticsStore>();
+    let spawns = diagnostics.get(&CobwebUiProfilingPlugin::SCENE_SPAWNS).unwrap();
+    assert_eq!(spawns.value(), Some(2.));
+
+    // Stats are cleared on reset, and frame stats only cover the latest frame.
+    app.update();
+    let mut profile = app.world_mut().resource_mut::<CobwebUiProfile>();
+    assert_eq!(profile.last_frame().scene_spawns, 0);
+    profile.reset();
+    assert_eq!(profile.iter_scenes().count(), 0);
+    assert_eq!(profile.iter_loadables().count(), 0);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
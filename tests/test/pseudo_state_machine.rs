@@ -0,0 +1,36 @@
+use bevy_cobweb_ui::prelude::*;
+use bevy_cobweb_ui::sickle::PseudoState;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn pseudo_state_machine_transitions()
+{
+    let idle = PseudoState::Custom("idle".into());
+    let armed = PseudoState::Custom("armed".into());
+    let fired = PseudoState::Custom("fired".into());
+
+    let machine = PseudoStateMachine {
+        initial: idle.clone(),
+        transitions: vec![
+            StateTransition { from: vec![idle.clone()], to: armed.clone(), on: StateTrigger::Pressed },
+            StateTransition { from: vec![armed.clone()], to: fired.clone(), on: StateTrigger::Released },
+            StateTransition { from: vec![fired.clone()], to: idle.clone(), on: StateTrigger::Timer(0.5) },
+            StateTransition { from: vec![], to: idle.clone(), on: StateTrigger::Broadcast("reset".into()) },
+        ],
+    };
+
+    assert_eq!(machine.next_state(&idle, &StateTrigger::Pressed), Some(&armed));
+    assert_eq!(machine.next_state(&idle, &StateTrigger::Released), None);
+    assert_eq!(machine.next_state(&armed, &StateTrigger::Released), Some(&fired));
+
+    // Timers fire once enough time has elapsed.
+    assert_eq!(machine.next_state(&fired, &StateTrigger::Timer(0.2)), None);
+    assert_eq!(machine.next_state(&fired, &StateTrigger::Timer(0.5)), Some(&idle));
+
+    // Transitions without `from` states apply to all states, except the target state.
+    assert_eq!(machine.next_state(&armed, &StateTrigger::Broadcast("reset".into())), Some(&idle));
+    assert_eq!(machine.next_state(&idle, &StateTrigger::Broadcast("reset".into())), None);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
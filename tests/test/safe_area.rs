@@ -0,0 +1,22 @@
+use bevy::prelude::*;
+use bevy_cobweb_ui::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn safe_area_insets()
+{
+    let insets = SafeAreaInsets { top: 40., bottom: 20., left: 0., right: 0. };
+
+    // All edges by default.
+    let mut rect = UiRect::all(Val::Px(5.));
+    SafeAreaPadding::default().apply_insets(&insets, &mut rect);
+    assert_eq!(rect, UiRect::new(Val::Px(5.), Val::Px(5.), Val::Px(45.), Val::Px(25.)));
+
+    // Only selected edges, and non-pixel values are replaced.
+    let mut rect = UiRect::all(Val::Percent(10.));
+    SafeAreaPadding { edges: vec![SafeAreaEdge::Top], margin: false }.apply_insets(&insets, &mut rect);
+    assert_eq!(rect, UiRect::new(Val::Percent(10.), Val::Percent(10.), Val::Px(40.), Val::Percent(10.)));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,106 @@
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy_cobweb_ui::prelude::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Spawns a "row" scene instance under `parent`, returning the root and label entities.
+fn spawn_row(app: &mut App, parent: Entity) -> (Entity, Entity)
+{
+    app.world_mut()
+        .run_system_once(move |mut c: Commands, mut s: SceneBuilder| {
+            let mut spawned = (Entity::PLACEHOLDER, Entity::PLACEHOLDER);
+            c.entity(parent)
+                .spawn_scene_and_edit(("scene_pool.cob", "row"), &mut s, |h| {
+                    spawned = (h.id(), h.get_entity("label")?);
+                    OK
+                });
+            spawned
+        })
+        .unwrap()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn label_height(app: &App, label: Entity) -> Val
+{
+    app.world().get::<Node>(label).unwrap().height
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn scene_pool_capacity()
+{
+    let mut builder = SceneBuilderInner::default();
+    assert_eq!(builder.pool_capacity(("chat.cob", "row")), 0);
+
+    builder.set_pool_capacity(("chat.cob", "row"), 32);
+    builder.set_pool_capacity(("chat.cob", "popup"), 8);
+    assert_eq!(builder.pool_capacity(("chat.cob", "row")), 32);
+    assert_eq!(builder.pool_capacity(("chat.cob", "popup")), 8);
+    assert_eq!(builder.pool_capacity(("other.cob", "row")), 0);
+    assert_eq!(builder.num_pooled(("chat.cob", "row")), 0);
+
+    builder.set_pool_capacity(("chat.cob", "row"), 0);
+    assert_eq!(builder.pool_capacity(("chat.cob", "row")), 0);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn scene_pool_reuses_instances()
+{
+    let mut app = cobweb_test_app(&["scene_pool.cob"]);
+    wait_for_load(&mut app);
+    app.world_mut()
+        .resource_mut::<SceneBuilderInner>()
+        .set_pool_capacity(("scene_pool.cob", "row"), 1);
+
+    let first_parent = app.world_mut().spawn(Node::default()).id();
+    let second_parent = app.world_mut().spawn(Node::default()).id();
+    let (row, label) = spawn_row(&mut app, first_parent);
+    app.update();
+    assert_eq!(label_height(&app, label), Val::Px(5.));
+
+    // Modify the instance so re-initialization can be observed.
+    app.world_mut().get_mut::<Node>(label).unwrap().height = Val::Px(99.);
+    app.world_mut().entity_mut(row).insert(Visibility::Visible);
+
+    // Pooled instances are detached and hidden.
+    app.world_mut().commands().entity(row).despawn_to_pool();
+    app.update();
+    let builder = app.world().resource::<SceneBuilderInner>();
+    assert_eq!(builder.num_pooled(("scene_pool.cob", "row")), 1);
+    assert!(app.world().get::<PooledScene>(row).is_some());
+    assert!(app.world().get::<Parent>(row).is_none());
+    assert_eq!(app.world().get::<Visibility>(row), Some(&Visibility::Hidden));
+
+    // Respawning reuses the pooled entities and re-applies their loadables.
+    let (reused_row, reused_label) = spawn_row(&mut app, second_parent);
+    app.update();
+    assert_eq!((reused_row, reused_label), (row, label));
+    let builder = app.world().resource::<SceneBuilderInner>();
+    assert_eq!(builder.num_pooled(("scene_pool.cob", "row")), 0);
+    assert!(app.world().get::<PooledScene>(row).is_none());
+    assert_eq!(app.world().get::<Parent>(row).map(Parent::get), Some(second_parent));
+    assert_eq!(app.world().get::<Visibility>(row), Some(&Visibility::Visible));
+    assert_eq!(label_height(&app, label), Val::Px(5.));
+
+    // Instances are despawned when the pool is full.
+    let (other_row, other_label) = spawn_row(&mut app, first_parent);
+    app.update();
+    assert_ne!(other_row, row);
+    app.world_mut().commands().entity(row).despawn_to_pool();
+    app.world_mut().commands().entity(other_row).despawn_to_pool();
+    app.update();
+    let builder = app.world().resource::<SceneBuilderInner>();
+    assert_eq!(builder.num_pooled(("scene_pool.cob", "row")), 1);
+    assert!(app.world().get::<PooledScene>(row).is_some());
+    assert!(app.world().get_entity(other_row).is_err());
+    assert!(app.world().get_entity(other_label).is_err());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,28 @@
+use bevy::prelude::*;
+use bevy_cobweb_ui::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn scene_spawn_queue_tracking()
+{
+    let mut world = World::new();
+    let parent_a = world.spawn_empty().id();
+    let parent_b = world.spawn_empty().id();
+
+    let mut queue = SceneSpawnQueue::default();
+    assert!(queue.is_empty());
+
+    queue.spawn(Some(parent_a), ("a.cob", "slot"), 10, |_, _| {});
+    queue.spawn(Some(parent_b), ("a.cob", "slot"), 5, |_, _| {});
+    queue.spawn(None, ("a.cob", "row"), 0, |_, _| {});
+    assert_eq!(queue.num_pending(), 15);
+
+    queue.cancel(parent_a);
+    assert_eq!(queue.num_pending(), 5);
+
+    queue.clear();
+    assert!(queue.is_empty());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,27 @@
+use bevy::prelude::*;
+use bevy_cobweb_ui::builtin::widgets::slider::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn slider_value_snap()
+{
+    let mut value = SliderValue::Single(0.33);
+    value.snap(20);
+    assert_eq!(value, SliderValue::Single(0.35));
+
+    let mut value = SliderValue::Single(0.96);
+    value.snap(4);
+    assert_eq!(value, SliderValue::Single(1.));
+
+    let mut value = SliderValue::Planar(Vec2::new(0.12, 0.62));
+    value.snap(4);
+    assert_eq!(value, SliderValue::Planar(Vec2::new(0., 0.5)));
+
+    // Zero steps does nothing.
+    let mut value = SliderValue::Single(0.33);
+    value.snap(0);
+    assert_eq!(value, SliderValue::Single(0.33));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,79 @@
+use bevy::prelude::*;
+use bevy_cobweb_ui::prelude::*;
+use bevy_cobweb_ui::sickle::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn opacity(app: &App, entity: Entity) -> f32
+{
+    app.world().get::<PropagateOpacity>(entity).unwrap().0
+}
+
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn exit_transition_delays_despawn()
+{
+    let mut app = cobweb_test_app(&[]);
+    wait_for_load(&mut app);
+
+    let world = app.world_mut();
+    let entity = world.spawn(Node::default()).id();
+    Instruction::apply(
+        OnSpawnAnimate {
+            from: TransitionValues { opacity: Some(0.), scale: Some(0.5), ..default() },
+            duration: 0.05,
+            ..default()
+        },
+        entity,
+        world,
+    );
+    Instruction::apply(
+        OnDespawnAnimate {
+            to: TransitionValues { opacity: Some(0.), ..default() },
+            duration: 0.05,
+            ..default()
+        },
+        entity,
+        world,
+    );
+    app.update();
+
+    // The node animates in over 50ms.
+    assert!(opacity(&app, entity) < 1.);
+    update_frames(&mut app, 10);
+    assert_eq!(opacity(&app, entity), 1.);
+    assert_eq!(app.world().get::<TransitionTransform>(entity).unwrap().scale, 1.);
+
+    // The node survives until its exit transition completes.
+    app.world_mut().commands().entity(entity).despawn_animated();
+    app.update();
+    app.update();
+    assert!(app.world().get_entity(entity).is_ok());
+    let states = app.world().get::<PseudoStates>(entity).unwrap();
+    assert!(states.has(&PseudoState::Dying));
+    assert!(opacity(&app, entity) > 0.);
+
+    // The 50ms exit transition finishes within the next 5 frames.
+    update_frames(&mut app, 5);
+    assert!(app.world().get_entity(entity).is_err());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn despawn_animated_without_exit_transition()
+{
+    let mut app = cobweb_test_app(&[]);
+    wait_for_load(&mut app);
+
+    let entity = app.world_mut().spawn(Node::default()).id();
+    app.world_mut().commands().entity(entity).despawn_animated();
+    app.update();
+    assert!(app.world().get_entity(entity).is_err());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+use bevy_cobweb_ui::builtin::widgets::stat_bar::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn stat_bar_layout()
+{
+    assert_eq!(StatBarValue::new(25., 100.).fraction(), 0.25);
+    assert_eq!(StatBarValue::new(150., 100.).fraction(), 1.);
+    assert_eq!(StatBarValue::new(-5., 100.).fraction(), 0.);
+    assert_eq!(StatBarValue::new(5., 0.).fraction(), 0.);
+
+    let ticks = StatBarTicks { interval: 100., ..default() };
+    assert_eq!(ticks.count(1000.), 9);
+    assert_eq!(ticks.count(950.), 9);
+    assert_eq!(ticks.count(100.), 0);
+    assert_eq!(ticks.count(0.), 0);
+
+    let bar = StatBar {
+        thresholds: vec![
+            StatBarThreshold { below: 1.0, color: Color::WHITE },
+            StatBarThreshold { below: 0.25, color: Color::BLACK },
+        ],
+        ..default()
+    };
+    assert_eq!(bar.threshold_color(0.8), Some(Color::WHITE));
+    assert_eq!(bar.threshold_color(0.25), Some(Color::BLACK));
+    assert_eq!(bar.threshold_color(0.1), Some(Color::BLACK));
+
+    let mut node = Node::default();
+    StatBarDirection::RightToLeft.set_fill(&mut node, 0.5);
+    assert_eq!(node.width, Val::Percent(50.));
+    assert_eq!(node.right, Val::Px(0.));
+    assert_eq!(node.left, Val::Auto);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,31 @@
+use bevy::prelude::*;
+use bevy_cobweb_ui::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn svg_rasterize()
+{
+    let svg = Svg::from_data(
+        br##"<svg xmlns="http://www.w3.org/2000/svg" width="4" height="2">
+            <rect x="0" y="0" width="2" height="2" fill="#FF0000"/>
+        </svg>"##,
+    )
+    .unwrap();
+    assert_eq!(svg.size(), Vec2::new(4., 2.));
+
+    // The SVG is scaled uniformly and centered, so the left quarter of an 8x8 image is red.
+    let image = svg.rasterize(UVec2::new(8, 8));
+    assert_eq!(image.size(), UVec2::new(8, 8));
+    let pixel = |x: u32, y: u32| {
+        let idx = ((y * 8 + x) * 4) as usize;
+        image.data[idx..idx + 4].to_vec()
+    };
+    assert_eq!(pixel(1, 4), vec![255, 0, 0, 255]);
+    assert_eq!(pixel(6, 4), vec![0, 0, 0, 0]);
+    assert_eq!(pixel(1, 0), vec![0, 0, 0, 0]);
+
+    assert!(Svg::from_data(b"not an svg").is_err());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
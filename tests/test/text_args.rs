@@ -0,0 +1,26 @@
+use bevy_cobweb_ui::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn text_args()
+{
+    let format = TextNumberFormat { min_fraction_digits: Some(2), ..Default::default() };
+    let mut args = TextArgs::new()
+        .with("count", 3usize)
+        .with("name", "Bob")
+        .with("price", TextArg::number(2.5, format));
+    assert_eq!(args.get("count"), Some(&TextArg::Number(3., TextNumberFormat::default())));
+    assert_eq!(args.get("name"), Some(&TextArg::String("Bob".into())));
+    assert_eq!(args.get("price"), Some(&TextArg::Number(2.5, format)));
+
+    // Setting an existing key replaces it.
+    args.set("count", -1i32);
+    assert_eq!(args.get("count"), Some(&TextArg::Number(-1., TextNumberFormat::default())));
+    assert_eq!(args.iter().count(), 3);
+
+    args.clear();
+    assert!(args.is_empty());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+use bevy_cobweb_ui::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn text_fit_font_size()
+{
+    let shrink = TextFit { min_size: 10., max_size: 40., mode: TextFitMode::Shrink };
+
+    // Overflowing text shrinks proportionally.
+    assert_eq!(shrink.fit_font_size(20., 20., Vec2::new(200., 20.), Vec2::new(100., 50.)), 10.);
+    // Shrinking stops at the minimum size.
+    assert_eq!(shrink.fit_font_size(20., 20., Vec2::new(400., 20.), Vec2::new(100., 50.)), 10.);
+    // Text that fits is not grown past its authored size.
+    assert_eq!(shrink.fit_font_size(20., 20., Vec2::new(50., 20.), Vec2::new(100., 50.)), 20.);
+    // Previously-shrunk text grows back toward its authored size.
+    assert_eq!(shrink.fit_font_size(10., 20., Vec2::new(25., 10.), Vec2::new(100., 50.)), 20.);
+
+    // Growing fills the available space up to the maximum size.
+    let grow = TextFit { mode: TextFitMode::Grow, ..shrink.clone() };
+    let size = grow.fit_font_size(20., 20., Vec2::new(50., 20.), Vec2::new(100., 50.));
+    assert!(size > 38. && size <= 40.);
+    // Growing never shrinks below the authored size.
+    assert_eq!(grow.fit_font_size(20., 20., Vec2::new(200., 20.), Vec2::new(100., 50.)), 20.);
+
+    // Wrapped text shrinks according to its height.
+    let wrap = TextFit { mode: TextFitMode::Wrap, ..shrink };
+    assert_eq!(wrap.fit_font_size(20., 20., Vec2::new(100., 200.), Vec2::new(100., 50.)), 10.);
+
+    // Unmeasured text is left alone.
+    assert_eq!(wrap.fit_font_size(20., 20., Vec2::ZERO, Vec2::new(100., 50.)), 20.);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,48 @@
+use bevy_cobweb_ui::builtin::widgets::text_input::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn text_input_editing()
+{
+    let mut text = String::from("ab");
+    let mut cursor = TextInputCursor::new(1);
+    assert!(cursor.insert(&mut text, "é", None));
+    assert_eq!(text, "aéb");
+    assert_eq!(cursor.get(), 3);
+
+    // Max length truncates insertions.
+    assert!(cursor.insert(&mut text, "xyz", Some(5)));
+    assert_eq!(text, "aéxyb");
+    assert!(!cursor.insert(&mut text, "z", Some(5)));
+
+    cursor.move_left(&text);
+    cursor.move_left(&text);
+    cursor.move_left(&text);
+    assert_eq!(cursor.get(), 1);
+    assert!(cursor.delete_forward(&mut text));
+    assert_eq!(text, "axyb");
+    assert!(cursor.delete_backward(&mut text));
+    assert!(!cursor.delete_backward(&mut text));
+    assert_eq!(text, "xyb");
+
+    // Line navigation.
+    let text = String::from("abc\nd\nefgh");
+    let mut cursor = TextInputCursor::new(2);
+    cursor.move_down(&text);
+    assert_eq!(cursor.get(), 5);
+    cursor.move_down(&text);
+    assert_eq!(cursor.get(), 7);
+    cursor.move_line_end(&text);
+    assert!(cursor.is_at_end(&text));
+    cursor.move_up(&text);
+    assert_eq!(cursor.get(), 5);
+    cursor.move_line_start(&text);
+    assert_eq!(cursor.get(), 4);
+    cursor.move_up(&text);
+    assert_eq!(cursor.get(), 0);
+    cursor.move_up(&text);
+    assert_eq!(cursor.get(), 0);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,83 @@
+use bevy::prelude::*;
+use bevy::reflect::{PartialReflect, Struct};
+use bevy::text::{ComputedTextBlock, GlyphAtlasInfo, GlyphAtlasLocation, PositionedGlyph, TextEntity, TextLayoutInfo};
+use bevy::ui::RelativeCursorPosition;
+use bevy_cobweb_ui::prelude::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Sets a private field of a layout component, which is normally computed by `bevy_ui`.
+fn set_field(target: &mut dyn Struct, field: &str, value: impl PartialReflect)
+{
+    target.field_mut(field).unwrap().apply(&value);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn glyph(center: Vec2, span_index: usize) -> PositionedGlyph
+{
+    let atlas_info = GlyphAtlasInfo {
+        texture: Handle::default(),
+        texture_atlas: Handle::default(),
+        location: GlyphAtlasLocation { glyph_index: 0, offset: IVec2::ZERO },
+    };
+    PositionedGlyph::new(center, Vec2::splat(10.), atlas_info, span_index)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn text_links_hit_test_padded_text()
+{
+    let mut app = cobweb_test_app(&[]);
+    wait_for_load(&mut app);
+
+    let world = app.world_mut();
+    let text = world.spawn(Text::new("a")).id();
+    let link = world
+        .spawn((TextSpan::new("b"), TextLink::new("wiki")))
+        .set_parent(text)
+        .id();
+    app.update();
+
+    // Text with 10px left padding and 5px top padding. The link glyph covers x = 20..30 and y = 0..10 in the
+    // content box.
+    let mut node = ComputedNode::default();
+    set_field(&mut node, "size", Vec2::new(100., 20.));
+    set_field(
+        &mut node,
+        "padding",
+        BorderRect { left: 10., right: 0., top: 5., bottom: 0. },
+    );
+    let mut block = ComputedTextBlock::default();
+    let entities: smallvec::SmallVec<[TextEntity; 1]> = smallvec::smallvec![
+        TextEntity { entity: text, depth: 0 },
+        TextEntity { entity: link, depth: 1 },
+    ];
+    set_field(&mut block, "entities", entities);
+    let layout = TextLayoutInfo {
+        glyphs: vec![glyph(Vec2::new(5., 5.), 0), glyph(Vec2::new(25., 5.), 1)],
+        size: Vec2::new(30., 10.),
+    };
+    app.world_mut().entity_mut(text).insert((node, block, layout));
+
+    let hover_at = |app: &mut App, position: Vec2| {
+        app.world_mut()
+            .entity_mut(text)
+            .insert(RelativeCursorPosition {
+                normalized_visible_node_rect: Rect::new(0., 0., 1., 1.),
+                normalized: Some(position / Vec2::new(100., 20.)),
+            });
+        app.update();
+        app.world().get::<TextColor>(link).unwrap().0
+    };
+    let style = TextLinkStyle::default();
+
+    // The cursor is offset by the padding when hit-testing glyphs.
+    assert_eq!(hover_at(&mut app, Vec2::new(35., 10.)), style.hover_color.unwrap());
+    assert_eq!(hover_at(&mut app, Vec2::new(25., 5.)), style.color.unwrap());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,69 @@
+use bevy::prelude::*;
+use bevy_cobweb_ui::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn segment(text: &str) -> TextMarkupSegment
+{
+    TextMarkupSegment { text: text.into(), ..default() }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn text_markup()
+{
+    // No markup.
+    assert_eq!(parse_text_markup("hello world"), None);
+    assert_eq!(parse_text_markup("a < b > c"), None);
+
+    // Simple tags.
+    assert_eq!(
+        parse_text_markup("Health is <b>low</b>!"),
+        Some(vec![
+            segment("Health is "),
+            TextMarkupSegment { bold: true, ..segment("low") },
+            segment("!"),
+        ])
+    );
+
+    // Nested tags.
+    assert_eq!(
+        parse_text_markup("<color=#FF0000><i>a<size=20>b</size></i>c</color>"),
+        Some(vec![
+            TextMarkupSegment { italic: true, color: Some(Srgba::RED.into()), ..segment("a") },
+            TextMarkupSegment {
+                italic: true,
+                color: Some(Srgba::RED.into()),
+                size: Some(20.),
+                ..segment("b")
+            },
+            TextMarkupSegment { color: Some(Srgba::RED.into()), ..segment("c") },
+        ])
+    );
+
+    // Links.
+    assert_eq!(
+        parse_text_markup("See <link id=\"wiki\">the <b>wiki</b></link> or <link id=faq>FAQ</link>."),
+        Some(vec![
+            segment("See "),
+            TextMarkupSegment { link: Some("wiki".into()), ..segment("the ") },
+            TextMarkupSegment { link: Some("wiki".into()), bold: true, ..segment("wiki") },
+            segment(" or "),
+            TextMarkupSegment { link: Some("faq".into()), ..segment("FAQ") },
+            segment("."),
+        ])
+    );
+    assert_eq!(
+        parse_text_markup("<link id=\"\">a</link>"),
+        Some(vec![segment("<link id=\"\">a")])
+    );
+
+    // Unknown and malformed tags are literal, unmatched closing tags are ignored.
+    assert_eq!(
+        parse_text_markup("<u>a</u> <color=red>b</b>"),
+        Some(vec![segment("<u>a</u> <color=red>b")])
+    );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
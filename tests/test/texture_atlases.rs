@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+use bevy_cobweb_ui::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn texture_atlas_names()
+{
+    let mut assets = Assets::<TextureAtlasLayout>::default();
+    let mut map = TextureAtlasLayoutMap::default();
+    map.insert_named(
+        "icons.png".into(),
+        "icons".into(),
+        &mut assets,
+        TextureAtlasLayout::from_grid(UVec2::splat(32), 3, 1, None, None),
+        vec!["play".into(), "".into(), "settings".into(), "extra".into()],
+    );
+
+    assert_eq!(map.index("icons.png", "icons", "play"), Some(0));
+    assert_eq!(map.index("icons.png", "icons", "settings"), Some(2));
+    assert_eq!(map.index("icons.png", "icons", ""), None);
+    assert_eq!(map.index("icons.png", "icons", "extra"), None);
+    assert_eq!(map.index("other.png", "icons", "play"), None);
+
+    // Named references use the registered index, and fall back to the reference's index.
+    let mut reference = TextureAtlasReference {
+        index: 1,
+        alias: "icons".into(),
+        name: Some("settings".into()),
+    };
+    let atlas = map.get_atlas("icons.png", &reference);
+    assert_eq!(atlas.index, 2);
+    assert_eq!(atlas.layout, map.get("icons.png", "icons"));
+    reference.name = Some("missing".into());
+    assert_eq!(map.get_atlas("icons.png", &reference).index, 1);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,79 @@
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy_cobweb_ui::prelude::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn spawn_panel(app: &mut App) -> (Entity, Entity)
+{
+    app.world_mut()
+        .run_system_once(|mut c: Commands, mut s: SceneBuilder| {
+            let mut spawned = (Entity::PLACEHOLDER, Entity::PLACEHOLDER);
+            c.ui_root()
+                .spawn_scene_and_edit(("theme_switching.cob", "panel"), &mut s, |h| {
+                    spawned = (h.id(), h.get_entity("child")?);
+                    OK
+                });
+            spawned
+        })
+        .unwrap()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn flex(app: &App, entity: Entity) -> (f32, f32)
+{
+    let node = app.world().get::<Node>(entity).unwrap();
+    (node.flex_grow, node.flex_shrink)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn theme_switching_reapplies_loadables()
+{
+    let mut app = cobweb_test_app(&["theme_switching.cob"]);
+    wait_for_load(&mut app);
+    let (panel, child) = spawn_panel(&mut app);
+    app.update();
+    assert_eq!(flex(&app, panel), (1., 7.));
+
+    // Switching the theme after loading reapplies themed loadables.
+    app.world_mut().commands().queue(SetActiveTheme("dark".into()));
+    app.update();
+    app.update();
+    assert_eq!(flex(&app, panel), (2., 7.));
+    assert_eq!(flex(&app, child), (5., 0.));
+    assert!(app.world().get::<BackgroundColor>(panel).is_some());
+
+    // Scenes spawned after switching use the new theme.
+    let (other_panel, _) = spawn_panel(&mut app);
+    app.update();
+    assert_eq!(flex(&app, other_panel), (2., 7.));
+
+    // High contrast takes priority over the active theme.
+    app.world_mut().resource_mut::<HighContrast>().0 = true;
+    app.update();
+    app.update();
+    assert_eq!(flex(&app, panel), (3., 7.));
+    assert_eq!(flex(&app, other_panel), (3., 7.));
+
+    // Themes without a value for a token use the token's first value.
+    app.world_mut().resource_mut::<HighContrast>().0 = false;
+    app.world_mut().commands().queue(SetActiveTheme("sepia".into()));
+    app.update();
+    app.update();
+    assert_eq!(flex(&app, panel), (1., 7.));
+
+    // Despawned entities are no longer refreshed.
+    app.world_mut().entity_mut(panel).despawn_recursive();
+    app.update();
+    app.world_mut().commands().queue(SetActiveTheme("dark".into()));
+    app.update();
+    app.update();
+    assert_eq!(flex(&app, other_panel), (2., 7.));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
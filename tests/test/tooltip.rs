@@ -0,0 +1,124 @@
+use bevy::prelude::*;
+use bevy_cobweb_ui::builtin::widgets::data_template::*;
+use bevy_cobweb_ui::builtin::widgets::tooltip::*;
+use bevy_cobweb_ui::prelude::*;
+use bevy_cobweb_ui::sickle::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Reflect, Clone)]
+struct ItemTooltip
+{
+    title: String,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn tooltip_position()
+{
+    let tooltip = WithTooltip { offset: Vec2::new(0., -5.), ..default() };
+    let view = Rect::new(0., 0., 1000., 1000.);
+    let size = Vec2::new(50., 20.);
+
+    // Centered above the source.
+    let source = Rect::new(100., 100., 200., 150.);
+    let position = tooltip.compute_position(source, size, view, 1., None);
+    assert_eq!(position, Vec2::new(125., 75.));
+
+    // Offsets are scaled.
+    let position = tooltip.compute_position(source, size, view, 2., None);
+    assert_eq!(position, Vec2::new(125., 70.));
+
+    // Flipped below the source when there is no room above it.
+    let top_source = Rect::new(100., 0., 200., 50.);
+    let position = tooltip.compute_position(top_source, size, view, 1., None);
+    assert_eq!(position, Vec2::new(125., 55.));
+
+    // Pushed away from the edge of the view.
+    let left_source = Rect::new(0., 100., 20., 150.);
+    let position = tooltip.compute_position(left_source, size, view, 1., None);
+    assert_eq!(position, Vec2::new(0., 75.));
+
+    // Moved away from the cursor.
+    let cursor = Rect::new(120., 70., 140., 90.);
+    let position = tooltip.compute_position(source, size, view, 1., Some(cursor));
+    assert_eq!(position, Vec2::new(125., 50.));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn tooltip_despawns_after_fading_out()
+{
+    let mut app = cobweb_test_app(&["tooltip.cob"]);
+    wait_for_load(&mut app);
+
+    let fade = AnimationConfig { duration: 0.05, ..default() };
+    let world = app.world_mut();
+    let source = world.spawn(Node::default()).id();
+    Instruction::apply(
+        WithTooltip {
+            file: "tooltip.cob".into(),
+            scene: "tip".into(),
+            fade_in: Some(fade.clone()),
+            fade_out: Some(fade),
+            ..default()
+        },
+        source,
+        world,
+    );
+    app.update();
+
+    // Hovering the source spawns the tooltip.
+    *app.world_mut().get_mut::<FluxInteraction>(source).unwrap() = FluxInteraction::PointerEnter;
+    app.update();
+    let mut tooltips = app.world_mut().query_filtered::<Entity, With<PropagateOpacity>>();
+    let tooltip = tooltips.single(app.world());
+
+    // The tooltip stays alive while it fades out, and is despawned when the fade-out completes.
+    *app.world_mut().get_mut::<FluxInteraction>(source).unwrap() = FluxInteraction::PointerLeave;
+    app.update();
+    app.update();
+    assert!(app.world().get_entity(tooltip).is_ok());
+    let opacity = app.world().get::<PropagateOpacity>(tooltip).unwrap().0;
+    assert!(opacity > 0.);
+
+    // The 50ms fade-out finishes within the next 5 frames.
+    update_frames(&mut app, 5);
+    assert!(app.world().get_entity(tooltip).is_err());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn tooltip_displays_tooltip_data()
+{
+    let mut app = cobweb_test_app(&["tooltip.cob"]);
+    wait_for_load(&mut app);
+
+    let world = app.world_mut();
+    let source = world
+        .spawn((Node::default(), TooltipData::new(ItemTooltip { title: "Sword".into() })))
+        .id();
+    Instruction::apply(WithTooltip::new("tooltip.cob", "data_tip"), source, world);
+    app.update();
+
+    // The tooltip's bindings read from the source's data.
+    *app.world_mut().get_mut::<FluxInteraction>(source).unwrap() = FluxInteraction::PointerEnter;
+    app.update();
+    let mut texts = app.world_mut().query_filtered::<&Text, With<DataBinding>>();
+    assert_eq!(texts.single(app.world()).0, "Sword");
+
+    // Changing the data updates the displayed tooltip.
+    app.world_mut()
+        .get_mut::<TooltipData>(source)
+        .unwrap()
+        .set(ItemTooltip { title: "Axe".into() });
+    app.update();
+    assert_eq!(texts.single(app.world()).0, "Axe");
+}
+
+//-------------------------------------------------------------------------------------------------------------------
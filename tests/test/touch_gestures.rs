@@ -0,0 +1,31 @@
+use bevy::prelude::*;
+use bevy_cobweb_ui::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn two_finger_gestures()
+{
+    // Touches move apart.
+    let gesture = TwoFingerGesture::new(
+        (Vec2::new(0., 0.), Vec2::new(10., 0.)),
+        (Vec2::new(-5., 0.), Vec2::new(15., 0.)),
+    );
+    assert_eq!(gesture.scale, 2.);
+    assert_eq!(gesture.delta, Vec2::ZERO);
+    assert_eq!(gesture.center, Vec2::new(5., 0.));
+
+    // Touches move together.
+    let gesture = TwoFingerGesture::new(
+        (Vec2::new(0., 0.), Vec2::new(10., 0.)),
+        (Vec2::new(0., 4.), Vec2::new(10., 4.)),
+    );
+    assert_eq!(gesture.scale, 1.);
+    assert_eq!(gesture.delta, Vec2::new(0., 4.));
+
+    // Overlapping touches don't produce a scale.
+    let gesture = TwoFingerGesture::new((Vec2::ZERO, Vec2::ZERO), (Vec2::ZERO, Vec2::new(10., 0.)));
+    assert_eq!(gesture.scale, 1.);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
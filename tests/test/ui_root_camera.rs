@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+use bevy_cobweb_ui::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn ui_root_camera_resolve()
+{
+    let main = Entity::from_raw(1);
+    let minimap = Entity::from_raw(2);
+    let names = [Name::new("main"), Name::new("minimap")];
+    let cameras = || [(main, &names[0]), (minimap, &names[1])];
+
+    assert_eq!(UiRootForCamera::DefaultCamera.resolve(cameras()), None);
+    assert_eq!(UiRootForCamera::Name("minimap".into()).resolve(cameras()), Some(minimap));
+    assert_eq!(UiRootForCamera::Name("missing".into()).resolve(cameras()), None);
+    assert_eq!(UiRootForCamera::Entity(main).resolve(cameras()), Some(main));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
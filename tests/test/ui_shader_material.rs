@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+use bevy_cobweb_ui::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn shader_material_params()
+{
+    let material = UiShaderMaterial { params: vec![1., 2., 3., 4., 5.], ..default() };
+    assert_eq!(
+        material.packed_params(),
+        [Vec4::new(1., 2., 3., 4.), Vec4::new(5., 0., 0., 0.), Vec4::ZERO, Vec4::ZERO]
+    );
+
+    // Extra params are ignored.
+    let material = UiShaderMaterial { params: (0..20).map(|i| i as f32).collect(), ..default() };
+    assert_eq!(material.packed_params()[3], Vec4::new(12., 13., 14., 15.));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+use bevy_cobweb_ui::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn viewport_anchor_node_position()
+{
+    // Right half of a 1000x500 window.
+    let viewport = Rect::new(500., 0., 1000., 500.);
+    let size = Vec2::new(100., 40.);
+
+    let anchor = ViewportAnchor::new("p2", ViewportAnchorPoint::BottomCenter);
+    assert_eq!(anchor.node_position(viewport, size, 1.), Vec2::new(700., 460.));
+
+    let anchor = ViewportAnchor::new("p2", ViewportAnchorPoint::TopLeft).with_offset(Vec2::new(10., 10.));
+    assert_eq!(anchor.node_position(viewport, size, 1.), Vec2::new(510., 10.));
+
+    let anchor = ViewportAnchor::new("p2", ViewportAnchorPoint::RightCenter);
+    assert_eq!(anchor.node_position(viewport, size, 1.), Vec2::new(900., 230.));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn viewport_anchor_node_position_with_ui_scale()
+{
+    // `Val::Px` positions are scaled by `UiScale`, so the position is divided by it.
+    let viewport = Rect::new(500., 0., 1000., 500.);
+    let size = Vec2::new(100., 40.);
+
+    let anchor = ViewportAnchor::new("p2", ViewportAnchorPoint::BottomRight).with_offset(Vec2::new(-20., -10.));
+    let position = anchor.node_position(viewport, size, 1.25);
+    assert_eq!(position, Vec2::new(880., 450.) / 1.25);
+    assert_eq!(position * 1.25 + size, Vec2::new(980., 490.));
+}
+
+//-------------------------------------------------------------------------------------------------------------------